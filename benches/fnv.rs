@@ -0,0 +1,118 @@
+#[macro_use]
+extern crate criterion;
+extern crate lz_fnv;
+
+use criterion::{BenchmarkId, Criterion, Throughput};
+use lz_fnv::{hash_many, Fnv1, Fnv1a, FnvHasher, FnvParameters, U1024, U256, U512};
+use std::hint::black_box;
+
+const INPUT_LENS: &[usize] = &[16, 1024];
+
+/// FNV-1a with a full multiply by the prime for each byte, the baseline for
+/// the multiplies by the sparse primes used by the hashers.
+fn naive_fnv1a<T: FnvParameters>(input: &[u8]) -> T {
+    let mut hash = T::OFFSET_BASIS;
+
+    for byte in input {
+        hash = hash.xor_byte(*byte);
+        hash = hash.wrapping_mul(T::PRIME);
+    }
+
+    hash
+}
+
+macro_rules! bench_width {
+    ($name: ident, $type: ty, $group: expr) => {
+        fn $name(c: &mut Criterion) {
+            let mut group = c.benchmark_group($group);
+
+            for len in INPUT_LENS {
+                let input = vec![0x5a; *len];
+
+                group.throughput(Throughput::Bytes(*len as u64));
+                group.bench_with_input(BenchmarkId::new("fnv1", len), &input, |b, input| {
+                    b.iter(|| {
+                        let mut hasher = Fnv1::<$type>::new();
+                        hasher.write(black_box(input));
+                        hasher.finish()
+                    })
+                });
+                group.bench_with_input(BenchmarkId::new("fnv1a", len), &input, |b, input| {
+                    b.iter(|| {
+                        let mut hasher = Fnv1a::<$type>::new();
+                        hasher.write(black_box(input));
+                        hasher.finish()
+                    })
+                });
+                group.bench_with_input(BenchmarkId::new("fnv1a naive", len), &input, |b, input| {
+                    b.iter(|| naive_fnv1a::<$type>(black_box(input)))
+                });
+            }
+
+            group.finish();
+        }
+    };
+}
+
+bench_width!(bench_32, u32, "32-bit");
+bench_width!(bench_64, u64, "64-bit");
+bench_width!(bench_128, u128, "128-bit");
+bench_width!(bench_256, U256, "256-bit");
+bench_width!(bench_512, U512, "512-bit");
+bench_width!(bench_1024, U1024, "1024-bit");
+
+const KEY_LENS: &[usize] = &[16, 64];
+
+macro_rules! bench_many {
+    ($name: ident, $type: ty, $group: expr) => {
+        fn $name(c: &mut Criterion) {
+            let mut group = c.benchmark_group($group);
+
+            for len in KEY_LENS {
+                let keys: Vec<Vec<u8>> = (0..10_000u32)
+                    .map(|i| {
+                        let mut key = format!("key-{:08}", i).into_bytes();
+                        key.resize(*len, b'-');
+                        key
+                    })
+                    .collect();
+                let keys: Vec<&[u8]> = keys.iter().map(Vec::as_slice).collect();
+
+                group.throughput(Throughput::Elements(keys.len() as u64));
+                group.bench_with_input(BenchmarkId::new("scalar", len), &keys, |b, keys| {
+                    b.iter(|| {
+                        black_box(keys)
+                            .iter()
+                            .map(|key| {
+                                let mut hasher = Fnv1a::<$type>::new();
+                                hasher.write(key);
+                                hasher.finish()
+                            })
+                            .collect::<Vec<_>>()
+                    })
+                });
+                group.bench_with_input(BenchmarkId::new("hash_many", len), &keys, |b, keys| {
+                    b.iter(|| hash_many::<Fnv1a<$type>>(black_box(keys)))
+                });
+            }
+
+            group.finish();
+        }
+    };
+}
+
+bench_many!(bench_many_32, u32, "hash_many 32-bit");
+bench_many!(bench_many_64, u64, "hash_many 64-bit");
+
+criterion_group!(
+    benches,
+    bench_32,
+    bench_64,
+    bench_128,
+    bench_256,
+    bench_512,
+    bench_1024,
+    bench_many_32,
+    bench_many_64
+);
+criterion_main!(benches);
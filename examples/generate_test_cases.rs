@@ -0,0 +1,352 @@
+//! Generates `src/fnv_test_cases.rs` from the inputs of the reference
+//! test_fnv.c, run with:
+//!
+//! ```text
+//! cargo run --example generate_test_cases > src/fnv_test_cases.rs
+//! ```
+//!
+//! The hashes are computed with the independent FNV implementation of the
+//! `lz_fnv_macros` crate rather than the hashers under test.
+#[allow(dead_code)]
+#[path = "../lz_fnv_macros/src/fnv.rs"]
+mod fnv;
+
+use fnv::{Variant, Width};
+use std::ffi::CString;
+
+/// An input from test_fnv.c, the strings are written exactly as they appear in
+/// test_fnv.c.
+enum Input {
+    /// The bytes of the string.
+    Test(&'static str),
+    /// The bytes of the string followed by a nul byte.
+    Test0(&'static str),
+    /// The bytes of the string repeated 10 times.
+    R10(&'static str),
+    /// The bytes of the string repeated 500 times.
+    R500(&'static str),
+}
+
+use Input::{Test, Test0, R10, R500};
+
+#[rustfmt::skip]
+const INPUTS: &[Input] = &[
+    Test(r""),
+    Test(r"a"),
+    Test(r"b"),
+    Test(r"c"),
+    Test(r"d"),
+    Test(r"e"),
+    Test(r"f"),
+    Test(r"fo"),
+    Test(r"foo"),
+    Test(r"foob"),
+    Test(r"fooba"),
+    Test(r"foobar"),
+    Test0(r""),
+    Test0(r"a"),
+    Test0(r"b"),
+    Test0(r"c"),
+    Test0(r"d"),
+    Test0(r"e"),
+    Test0(r"f"),
+    Test0(r"fo"),
+    Test0(r"foo"),
+    Test0(r"foob"),
+    Test0(r"fooba"),
+    Test0(r"foobar"),
+    Test(r"ch"),
+    Test(r"cho"),
+    Test(r"chon"),
+    Test(r"chong"),
+    Test(r"chongo"),
+    Test(r"chongo "),
+    Test(r"chongo w"),
+    Test(r"chongo wa"),
+    Test(r"chongo was"),
+    Test(r"chongo was "),
+    Test(r"chongo was h"),
+    Test(r"chongo was he"),
+    Test(r"chongo was her"),
+    Test(r"chongo was here"),
+    Test(r"chongo was here!"),
+    Test(r"chongo was here!\n"),
+    Test0(r"ch"),
+    Test0(r"cho"),
+    Test0(r"chon"),
+    Test0(r"chong"),
+    Test0(r"chongo"),
+    Test0(r"chongo "),
+    Test0(r"chongo w"),
+    Test0(r"chongo wa"),
+    Test0(r"chongo was"),
+    Test0(r"chongo was "),
+    Test0(r"chongo was h"),
+    Test0(r"chongo was he"),
+    Test0(r"chongo was her"),
+    Test0(r"chongo was here"),
+    Test0(r"chongo was here!"),
+    Test0(r"chongo was here!\n"),
+    Test(r"cu"),
+    Test(r"cur"),
+    Test(r"curd"),
+    Test(r"curds"),
+    Test(r"curds "),
+    Test(r"curds a"),
+    Test(r"curds an"),
+    Test(r"curds and"),
+    Test(r"curds and "),
+    Test(r"curds and w"),
+    Test(r"curds and wh"),
+    Test(r"curds and whe"),
+    Test(r"curds and whey"),
+    Test(r"curds and whey\n"),
+    Test0(r"cu"),
+    Test0(r"cur"),
+    Test0(r"curd"),
+    Test0(r"curds"),
+    Test0(r"curds "),
+    Test0(r"curds a"),
+    Test0(r"curds an"),
+    Test0(r"curds and"),
+    Test0(r"curds and "),
+    Test0(r"curds and w"),
+    Test0(r"curds and wh"),
+    Test0(r"curds and whe"),
+    Test0(r"curds and whey"),
+    Test0(r"curds and whey\n"),
+    Test(r"hi"),
+    Test0(r"hi"),
+    Test(r"hello"),
+    Test0(r"hello"),
+    Test(r"\xff\x00\x00\x01"),
+    Test(r"\x01\x00\x00\xff"),
+    Test(r"\xff\x00\x00\x02"),
+    Test(r"\x02\x00\x00\xff"),
+    Test(r"\xff\x00\x00\x03"),
+    Test(r"\x03\x00\x00\xff"),
+    Test(r"\xff\x00\x00\x04"),
+    Test(r"\x04\x00\x00\xff"),
+    Test(r"\x40\x51\x4e\x44"),
+    Test(r"\x44\x4e\x51\x40"),
+    Test(r"\x40\x51\x4e\x4a"),
+    Test(r"\x4a\x4e\x51\x40"),
+    Test(r"\x40\x51\x4e\x54"),
+    Test(r"\x54\x4e\x51\x40"),
+    Test(r"127.0.0.1"),
+    Test0(r"127.0.0.1"),
+    Test(r"127.0.0.2"),
+    Test0(r"127.0.0.2"),
+    Test(r"127.0.0.3"),
+    Test0(r"127.0.0.3"),
+    Test(r"64.81.78.68"),
+    Test0(r"64.81.78.68"),
+    Test(r"64.81.78.74"),
+    Test0(r"64.81.78.74"),
+    Test(r"64.81.78.84"),
+    Test0(r"64.81.78.84"),
+    Test(r"feedface"),
+    Test0(r"feedface"),
+    Test(r"feedfacedaffdeed"),
+    Test0(r"feedfacedaffdeed"),
+    Test(r"feedfacedeadbeef"),
+    Test0(r"feedfacedeadbeef"),
+    Test(r"line 1\nline 2\nline 3"),
+    Test(r"chongo <Landon Curt Noll> /\\../\\"),
+    Test0(r"chongo <Landon Curt Noll> /\\../\\"),
+    Test(r"chongo (Landon Curt Noll) /\\../\\"),
+    Test0(r"chongo (Landon Curt Noll) /\\../\\"),
+    Test(r"http://antwrp.gsfc.nasa.gov/apod/astropix.html"),
+    Test(r"http://en.wikipedia.org/wiki/Fowler_Noll_Vo_hash"),
+    Test(r"http://epod.usra.edu/"),
+    Test(r"http://exoplanet.eu/"),
+    Test(r"http://hvo.wr.usgs.gov/cam3/"),
+    Test(r"http://hvo.wr.usgs.gov/cams/HMcam/"),
+    Test(r"http://hvo.wr.usgs.gov/kilauea/update/deformation.html"),
+    Test(r"http://hvo.wr.usgs.gov/kilauea/update/images.html"),
+    Test(r"http://hvo.wr.usgs.gov/kilauea/update/maps.html"),
+    Test(r"http://hvo.wr.usgs.gov/volcanowatch/current_issue.html"),
+    Test(r"http://neo.jpl.nasa.gov/risk/"),
+    Test(r"http://norvig.com/21-days.html"),
+    Test(r"http://primes.utm.edu/curios/home.php"),
+    Test(r"http://slashdot.org/"),
+    Test(r"http://tux.wr.usgs.gov/Maps/155.25-19.5.html"),
+    Test(r"http://volcano.wr.usgs.gov/kilaueastatus.php"),
+    Test(r"http://www.avo.alaska.edu/activity/Redoubt.php"),
+    Test(r"http://www.dilbert.com/fast/"),
+    Test(r"http://www.fourmilab.ch/gravitation/orbits/"),
+    Test(r"http://www.fpoa.net/"),
+    Test(r"http://www.ioccc.org/index.html"),
+    Test(r"http://www.isthe.com/cgi-bin/number.cgi"),
+    Test(r"http://www.isthe.com/chongo/bio.html"),
+    Test(r"http://www.isthe.com/chongo/index.html"),
+    Test(r"http://www.isthe.com/chongo/src/calc/lucas-calc"),
+    Test(r"http://www.isthe.com/chongo/tech/astro/venus2004.html"),
+    Test(r"http://www.isthe.com/chongo/tech/astro/vita.html"),
+    Test(r"http://www.isthe.com/chongo/tech/comp/c/expert.html"),
+    Test(r"http://www.isthe.com/chongo/tech/comp/calc/index.html"),
+    Test(r"http://www.isthe.com/chongo/tech/comp/fnv/index.html"),
+    Test(r"http://www.isthe.com/chongo/tech/math/number/howhigh.html"),
+    Test(r"http://www.isthe.com/chongo/tech/math/number/number.html"),
+    Test(r"http://www.isthe.com/chongo/tech/math/prime/mersenne.html"),
+    Test(r"http://www.isthe.com/chongo/tech/math/prime/mersenne.html#largest"),
+    Test(r"http://www.lavarnd.org/cgi-bin/corpspeak.cgi"),
+    Test(r"http://www.lavarnd.org/cgi-bin/haiku.cgi"),
+    Test(r"http://www.lavarnd.org/cgi-bin/rand-none.cgi"),
+    Test(r"http://www.lavarnd.org/cgi-bin/randdist.cgi"),
+    Test(r"http://www.lavarnd.org/index.html"),
+    Test(r"http://www.lavarnd.org/what/nist-test.html"),
+    Test(r"http://www.macosxhints.com/"),
+    Test(r"http://www.mellis.com/"),
+    Test(r"http://www.nature.nps.gov/air/webcams/parks/havoso2alert/havoalert.cfm"),
+    Test(r"http://www.nature.nps.gov/air/webcams/parks/havoso2alert/timelines_24.cfm"),
+    Test(r"http://www.paulnoll.com/"),
+    Test(r"http://www.pepysdiary.com/"),
+    Test(r"http://www.sciencenews.org/index/home/activity/view"),
+    Test(r"http://www.skyandtelescope.com/"),
+    Test(r"http://www.sput.nl/~rob/sirius.html"),
+    Test(r"http://www.systemexperts.com/"),
+    Test(r"http://www.tq-international.com/phpBB3/index.php"),
+    Test(r"http://www.travelquesttours.com/index.htm"),
+    Test(r"http://www.wunderground.com/global/stations/89606.html"),
+    R10(r"21701"),
+    R10(r"M21701"),
+    R10(r"2^21701-1"),
+    R10(r"\x54\xc5"),
+    R10(r"\xc5\x54"),
+    R10(r"23209"),
+    R10(r"M23209"),
+    R10(r"2^23209-1"),
+    R10(r"\x5a\xa9"),
+    R10(r"\xa9\x5a"),
+    R10(r"391581216093"),
+    R10(r"391581*2^216093-1"),
+    R10(r"\x05\xf9\x9d\x03\x4c\x81"),
+    R10(r"FEDCBA9876543210"),
+    R10(r"\xfe\xdc\xba\x98\x76\x54\x32\x10"),
+    R10(r"EFCDAB8967452301"),
+    R10(r"\xef\xcd\xab\x89\x67\x45\x23\x01"),
+    R10(r"0123456789ABCDEF"),
+    R10(r"\x01\x23\x45\x67\x89\xab\xcd\xef"),
+    R10(r"1032547698BADCFE"),
+    R10(r"\x10\x32\x54\x76\x98\xba\xdc\xfe"),
+    R500(r"\x00"),
+    R500(r"\x07"),
+    R500(r"~"),
+    R500(r"\x7f"),
+];
+
+const VARIANTS: &[(Variant, &str)] = &[
+    (Variant::Fnv0, "fnv0"),
+    (Variant::Fnv1, "fnv1"),
+    (Variant::Fnv1a, "fnv1a"),
+];
+
+const WIDTHS: &[(Width, &str)] = &[
+    (Width::U32, "u32"),
+    (Width::U64, "u64"),
+    (Width::U128, "u128"),
+    (Width::U256, "U256"),
+    (Width::U512, "U512"),
+    (Width::U1024, "U1024"),
+];
+
+/// Unescapes a string as it is written in source code.
+fn unescape(escaped: &str) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    let mut chars = escaped.bytes();
+
+    while let Some(byte) = chars.next() {
+        if byte != b'\\' {
+            bytes.push(byte);
+            continue;
+        }
+
+        match chars.next() {
+            Some(b'n') => bytes.push(b'\n'),
+            Some(b't') => bytes.push(b'\t'),
+            Some(b'r') => bytes.push(b'\r'),
+            Some(b'0') => bytes.push(0),
+            Some(b'x') => {
+                let hex = [chars.next().unwrap(), chars.next().unwrap()];
+                let hex = std::str::from_utf8(&hex).unwrap();
+                bytes.push(u8::from_str_radix(hex, 16).unwrap());
+            }
+            Some(byte) => bytes.push(byte),
+            None => panic!("incomplete escape in {:?}", escaped),
+        }
+    }
+
+    bytes
+}
+
+impl Input {
+    fn bytes(&self) -> Vec<u8> {
+        match *self {
+            Test(escaped) => unescape(escaped),
+            Test0(escaped) => CString::new(unescape(escaped))
+                .unwrap()
+                .into_bytes_with_nul(),
+            R10(escaped) => unescape(escaped).repeat(10),
+            R500(escaped) => unescape(escaped).repeat(500),
+        }
+    }
+
+    fn source(&self) -> String {
+        match *self {
+            Test(escaped) => format!("b\"{}\"", escaped),
+            Test0(escaped) => format!(
+                "::std::ffi::CString::new(\"{}\").unwrap().as_bytes_with_nul()",
+                escaped
+            ),
+            R10(escaped) => format!("&repeat(b\"{}\", 10)", escaped),
+            R500(escaped) => format!("&repeat(b\"{}\", 500)", escaped),
+        }
+    }
+}
+
+/// Formats the little-endian words of a hash as a Rust expression.
+fn hash_source(width: Width, type_name: &str, words: &[u64]) -> String {
+    match width {
+        Width::U32 => format!("0x{:08x}", words[0]),
+        Width::U64 => format!("0x{:016x}", words[0]),
+        Width::U128 => format!("0x{:016x}{:016x}", words[1], words[0]),
+        Width::U256 | Width::U512 | Width::U1024 => {
+            let words: Vec<String> = words
+                .iter()
+                .rev()
+                .map(|word| format!("0x{:016x}", word))
+                .collect();
+
+            format!("{}::from_words([{}])", type_name, words.join(", "))
+        }
+    }
+}
+
+fn main() {
+    println!(
+        "// Test cases generated using the data from http://www.isthe.com/chongo/src/fnv/test_fnv.c"
+    );
+
+    for &(variant, variant_name) in VARIANTS {
+        println!("{}_tests!{{", variant_name);
+
+        for &(width, type_name) in WIDTHS {
+            for (i, input) in INPUTS.iter().enumerate() {
+                let words = fnv::hash(variant, width, &input.bytes());
+
+                println!(
+                    "  {}_{}_test_{}: {}, {}, {},",
+                    variant_name,
+                    width.bits(),
+                    i,
+                    type_name,
+                    input.source(),
+                    hash_source(width, type_name, &words)
+                );
+            }
+        }
+
+        println!("}}");
+    }
+}
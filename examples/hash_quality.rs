@@ -0,0 +1,19 @@
+//! Measures the quality of every FNV hash and prints a report for each, run
+//! with:
+//!
+//! ```text
+//! cargo run --release --example hash_quality
+//! ```
+extern crate lz_fnv;
+
+use lz_fnv::analysis::{self, Config};
+
+fn main() {
+    let config = Config::default();
+
+    println!("{:?}", config);
+
+    for (name, report) in analysis::analyze_all(&config) {
+        println!("{:<12} {}", name, report);
+    }
+}
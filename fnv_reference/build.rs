@@ -0,0 +1,17 @@
+extern crate cc;
+
+const SOURCES: &[&str] = &[
+    "fnv/hash_32.c",
+    "fnv/hash_32a.c",
+    "fnv/hash_64.c",
+    "fnv/hash_64a.c",
+];
+
+fn main() {
+    cc::Build::new()
+        .files(SOURCES)
+        .include("fnv")
+        .compile("fnv");
+
+    println!("cargo:rerun-if-changed=fnv");
+}
@@ -0,0 +1,14 @@
+/*
+ * DO NOT EDIT -- generated by the Makefile
+ *
+ * Vendored for the build script, which targets compilers with a 64 bit
+ * unsigned long long.
+ */
+
+#if !defined(__LONGLONG_H__)
+#define __LONGLONG_H__
+
+/* do we have/want to use a long long type? */
+#define HAVE_64BIT_LONG_LONG	/* yes */
+
+#endif /* !__LONGLONG_H__ */
@@ -0,0 +1,51 @@
+//! Bindings to the reference FNV implementation by Landon Curt Noll, vendored
+//! in the `fnv` directory.
+//!
+//! The reference implementation is compiled by the build script, this crate
+//! exists only for the differential tests comparing it with lz_fnv.
+#![deny(missing_docs)]
+
+use std::ffi::CStr;
+use std::os::raw::{c_char, c_void};
+
+/// The initial hash of the 32-bit FNV-1 and FNV-1a hashes.
+pub const FNV1_32_INIT: u32 = 0x811c_9dc5;
+
+/// The initial hash of the 64-bit FNV-1 and FNV-1a hashes.
+pub const FNV1_64_INIT: u64 = 0xcbf2_9ce4_8422_2325;
+
+mod ffi {
+    use std::os::raw::{c_char, c_void};
+
+    extern "C" {
+        pub fn fnv_32_buf(buf: *mut c_void, len: usize, hval: u32) -> u32;
+        pub fn fnv_32_str(buf: *mut c_char, hval: u32) -> u32;
+        pub fn fnv_32a_buf(buf: *mut c_void, len: usize, hval: u32) -> u32;
+        pub fn fnv_32a_str(buf: *mut c_char, hval: u32) -> u32;
+        pub fn fnv_64_buf(buf: *mut c_void, len: usize, hval: u64) -> u64;
+        pub fn fnv_64_str(buf: *mut c_char, hval: u64) -> u64;
+        pub fn fnv_64a_buf(buf: *mut c_void, len: usize, hval: u64) -> u64;
+        pub fn fnv_64a_str(buf: *mut c_char, hval: u64) -> u64;
+    }
+}
+
+macro_rules! reference_fns {
+    ($hash: ty, $buf: ident, $str: ident, $name: expr) => {
+        #[doc = concat!("Continues the ", $name, " hash `hval` over `buf`.")]
+        pub fn $buf(buf: &[u8], hval: $hash) -> $hash {
+            // The reference implementation only reads through the pointer.
+            unsafe { ffi::$buf(buf.as_ptr() as *mut c_void, buf.len(), hval) }
+        }
+
+        #[doc = concat!("Continues the ", $name, " hash `hval` over `str` up to its nul.")]
+        pub fn $str(str: &CStr, hval: $hash) -> $hash {
+            // The reference implementation only reads through the pointer.
+            unsafe { ffi::$str(str.as_ptr() as *mut c_char, hval) }
+        }
+    };
+}
+
+reference_fns!(u32, fnv_32_buf, fnv_32_str, "32-bit FNV-1");
+reference_fns!(u32, fnv_32a_buf, fnv_32a_str, "32-bit FNV-1a");
+reference_fns!(u64, fnv_64_buf, fnv_64_str, "64-bit FNV-1");
+reference_fns!(u64, fnv_64a_buf, fnv_64a_str, "64-bit FNV-1a");
@@ -0,0 +1,145 @@
+//! Randomized differential tests of lz_fnv against the reference FNV
+//! implementation.
+//!
+//! The inputs are generated from a fixed seed, set `FNV_REFERENCE_SEED` to test
+//! other inputs. Failures report the seed and input so they can be reproduced.
+extern crate fnv_reference;
+extern crate lz_fnv;
+
+use lz_fnv::{Fnv1, Fnv1a, FnvHasher};
+use std::env;
+use std::ffi::CStr;
+
+const DEFAULT_SEED: u64 = 0x5eed_f00d_cafe_b0ba;
+
+const ITERATIONS: usize = 10_000;
+
+/// The SplitMix64 pseudo-random number generator.
+struct Rng(u64);
+
+impl Rng {
+    fn from_env() -> (u64, Self) {
+        let seed = env::var("FNV_REFERENCE_SEED")
+            .ok()
+            .map(|seed| {
+                seed.parse()
+                    .expect("FNV_REFERENCE_SEED is not a 64-bit integer")
+            })
+            .unwrap_or(DEFAULT_SEED);
+
+        (seed, Rng(seed))
+    }
+
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    fn below(&mut self, n: usize) -> usize {
+        (self.next() % n as u64) as usize
+    }
+
+    /// Generates an input, mostly short, of bytes which are mostly ASCII.
+    fn input(&mut self) -> Vec<u8> {
+        let max_len = [4, 16, 64, 1024][self.below(4)];
+        let len = self.below(max_len + 1);
+        let high_bytes = self.below(2) == 0;
+
+        (0..len)
+            .map(|_| {
+                let byte = self.next() as u8;
+
+                if high_bytes {
+                    byte
+                } else {
+                    byte & 0x7f
+                }
+            })
+            .collect()
+    }
+}
+
+macro_rules! differential_tests {
+    ($($buf_name: ident, $str_name: ident: $hasher: ty, $buf: ident, $str: ident, $init: expr,)*) => {
+        $(
+            #[test]
+            fn $buf_name() {
+                let (seed, mut rng) = Rng::from_env();
+
+                for _ in 0..ITERATIONS {
+                    let input = rng.input();
+                    let split = rng.below(input.len() + 1);
+                    let (first, second) = input.split_at(split);
+
+                    let mut hasher = <$hasher>::new();
+                    hasher.write(first);
+                    let reference = fnv_reference::$buf(first, $init);
+
+                    assert_eq!(hasher.finish(), reference, "seed {}, input {:?}", seed, first);
+
+                    hasher.write(second);
+                    let reference = fnv_reference::$buf(second, reference);
+
+                    assert_eq!(hasher.finish(), reference, "seed {}, input {:?}", seed, input);
+                }
+            }
+
+            #[test]
+            fn $str_name() {
+                let (seed, mut rng) = Rng::from_env();
+
+                for _ in 0..ITERATIONS {
+                    let mut input = rng.input();
+                    input.push(0);
+
+                    let str = CStr::from_bytes_until_nul(&input).unwrap();
+                    let mut hasher = <$hasher>::new();
+                    hasher.write(str.to_bytes());
+
+                    assert_eq!(
+                        hasher.finish(),
+                        fnv_reference::$str(str, $init),
+                        "seed {}, input {:?}",
+                        seed,
+                        input
+                    );
+                }
+            }
+        )*
+    };
+}
+
+differential_tests! {
+    fnv1_32_matches_reference, fnv1_32_str_matches_reference:
+        Fnv1<u32>, fnv_32_buf, fnv_32_str, fnv_reference::FNV1_32_INIT,
+    fnv1a_32_matches_reference, fnv1a_32_str_matches_reference:
+        Fnv1a<u32>, fnv_32a_buf, fnv_32a_str, fnv_reference::FNV1_32_INIT,
+    fnv1_64_matches_reference, fnv1_64_str_matches_reference:
+        Fnv1<u64>, fnv_64_buf, fnv_64_str, fnv_reference::FNV1_64_INIT,
+    fnv1a_64_matches_reference, fnv1a_64_str_matches_reference:
+        Fnv1a<u64>, fnv_64a_buf, fnv_64a_str, fnv_reference::FNV1_64_INIT,
+}
+
+#[test]
+fn reference_matches_published_hashes() {
+    assert_eq!(
+        fnv_reference::fnv_32_buf(b"foobar", fnv_reference::FNV1_32_INIT),
+        0x31f0_b262
+    );
+    assert_eq!(
+        fnv_reference::fnv_32a_buf(b"foobar", fnv_reference::FNV1_32_INIT),
+        0xbf9c_f968
+    );
+    assert_eq!(
+        fnv_reference::fnv_64_buf(b"foobar", fnv_reference::FNV1_64_INIT),
+        0x340d_8765_a4dd_a9c2
+    );
+    assert_eq!(
+        fnv_reference::fnv_64a_buf(b"foobar", fnv_reference::FNV1_64_INIT),
+        0x8594_4171_f739_67e8
+    );
+}
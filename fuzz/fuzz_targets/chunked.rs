@@ -0,0 +1,32 @@
+//! Compares every hasher against the naive implementation when the input is
+//! written in chunks.
+//!
+//! The first byte gives the number of chunks, and each of the following as
+//! many bytes gives the length of a chunk, modulo the remaining input.
+#![no_main]
+#[macro_use]
+extern crate libfuzzer_sys;
+extern crate lz_fnv_fuzz;
+
+fuzz_target!(|data: &[u8]| {
+    let (count, data) = match data.split_first() {
+        Some((count, data)) => (usize::from(*count), data),
+        None => return,
+    };
+
+    if data.len() < count {
+        return;
+    }
+
+    let (lengths, mut input) = data.split_at(count);
+    let mut chunks = Vec::new();
+
+    for length in lengths {
+        let (chunk, rest) = input.split_at(usize::from(*length) % (input.len() + 1));
+        chunks.push(chunk);
+        input = rest;
+    }
+
+    chunks.push(input);
+    lz_fnv_fuzz::check(&chunks);
+});
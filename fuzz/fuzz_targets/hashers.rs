@@ -0,0 +1,9 @@
+//! Compares every hasher against the naive implementation for a single write.
+#![no_main]
+#[macro_use]
+extern crate libfuzzer_sys;
+extern crate lz_fnv_fuzz;
+
+fuzz_target!(|data: &[u8]| {
+    lz_fnv_fuzz::check(&[data]);
+});
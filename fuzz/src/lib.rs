@@ -0,0 +1,123 @@
+//! Compares every hasher against the naive FNV implementation of the
+//! `lz_fnv_macros` crate, which computes hashes over little-endian 64-bit
+//! words for all widths.
+//!
+//! The fuzz targets are run from the root of the repository with:
+//!
+//! ```text
+//! cargo +nightly fuzz run hashers
+//! cargo +nightly fuzz run chunked
+//! ```
+extern crate lz_fnv;
+
+#[allow(dead_code)]
+#[path = "../../lz_fnv_macros/src/fnv.rs"]
+mod fnv;
+
+use fnv::{Variant, Width};
+use lz_fnv::{Fnv0, Fnv1, Fnv1a, Fnv1a16, Fnv1a8, FnvHasher, U1024, U256, U512};
+
+/// Converts a hash to its little-endian 64-bit words.
+trait Words {
+    fn words(self) -> Vec<u64>;
+}
+
+impl Words for u32 {
+    fn words(self) -> Vec<u64> {
+        vec![u64::from(self)]
+    }
+}
+
+impl Words for u64 {
+    fn words(self) -> Vec<u64> {
+        vec![self]
+    }
+}
+
+impl Words for u128 {
+    fn words(self) -> Vec<u64> {
+        vec![self as u64, (self >> 64) as u64]
+    }
+}
+
+macro_rules! wide_words {
+    ($($type: ident,)*) => {
+        $(
+            impl Words for $type {
+                fn words(self) -> Vec<u64> {
+                    self.to_words().iter().rev().cloned().collect()
+                }
+            }
+        )*
+    };
+}
+
+wide_words! {
+    U256,
+    U512,
+    U1024,
+}
+
+fn hash_chunks<H: FnvHasher + Default>(chunks: &[&[u8]]) -> H::Hash {
+    let mut hasher = H::default();
+
+    for chunk in chunks {
+        hasher.write(chunk);
+    }
+
+    hasher.finish()
+}
+
+macro_rules! check_hashers {
+    ($chunks: expr, $bytes: expr, $($hasher: ident<$type: ident>: $variant: ident, $width: ident,)*) => {
+        $(
+            assert_eq!(
+                hash_chunks::<$hasher<$type>>($chunks).words(),
+                fnv::hash(Variant::$variant, Width::$width, $bytes),
+                "{}<{}> of {:?}",
+                stringify!($hasher),
+                stringify!($type),
+                $chunks
+            );
+        )*
+    };
+}
+
+/// Asserts that writing `chunks` to every hasher produces the same hash as
+/// the naive implementation does for the concatenated chunks.
+pub fn check(chunks: &[&[u8]]) {
+    let bytes = chunks.concat();
+
+    check_hashers!(chunks, &bytes,
+        Fnv0<u32>: Fnv0, U32,
+        Fnv1<u32>: Fnv1, U32,
+        Fnv1a<u32>: Fnv1a, U32,
+        Fnv0<u64>: Fnv0, U64,
+        Fnv1<u64>: Fnv1, U64,
+        Fnv1a<u64>: Fnv1a, U64,
+        Fnv0<u128>: Fnv0, U128,
+        Fnv1<u128>: Fnv1, U128,
+        Fnv1a<u128>: Fnv1a, U128,
+        Fnv0<U256>: Fnv0, U256,
+        Fnv1<U256>: Fnv1, U256,
+        Fnv1a<U256>: Fnv1a, U256,
+        Fnv0<U512>: Fnv0, U512,
+        Fnv1<U512>: Fnv1, U512,
+        Fnv1a<U512>: Fnv1a, U512,
+        Fnv0<U1024>: Fnv0, U1024,
+        Fnv1<U1024>: Fnv1, U1024,
+        Fnv1a<U1024>: Fnv1a, U1024,
+    );
+
+    // The folded hashers are xor-folded from the 32-bit FNV-1a hash.
+    let fnv1a32 = fnv::hash(Variant::Fnv1a, Width::U32, &bytes)[0];
+
+    assert_eq!(
+        u64::from(hash_chunks::<Fnv1a16>(chunks)),
+        ((fnv1a32 >> 16) ^ fnv1a32) & 0xffff
+    );
+    assert_eq!(
+        u64::from(hash_chunks::<Fnv1a8>(chunks)),
+        ((fnv1a32 >> 8) ^ fnv1a32) & 0xff
+    );
+}
@@ -0,0 +1,131 @@
+//! A minimal FNV implementation for computing hashes at compile time.
+//!
+//! The `lz_fnv` crate depends upon this crate so it can not be used here, the
+//! hashes are instead computed over little-endian 64-bit words for all widths.
+
+/// The signature used to compute the FNV offset basis.
+const OFFSET_SIGNATURE: &[u8] = b"chongo <Landon Curt Noll> /\\../\\";
+
+/// The supported FNV hash variants.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Variant {
+    Fnv0,
+    Fnv1,
+    Fnv1a,
+}
+
+/// The supported FNV hash widths.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Width {
+    U32,
+    U64,
+    U128,
+    U256,
+    U512,
+    U1024,
+}
+
+impl Width {
+    /// Gets the width for the name of the hash type.
+    pub fn from_type_name(name: &str) -> Option<Width> {
+        match name {
+            "u32" => Some(Width::U32),
+            "u64" => Some(Width::U64),
+            "u128" => Some(Width::U128),
+            "U256" => Some(Width::U256),
+            "U512" => Some(Width::U512),
+            "U1024" => Some(Width::U1024),
+            _ => None,
+        }
+    }
+
+    /// Gets the number of bits in the hash.
+    pub fn bits(self) -> usize {
+        match self {
+            Width::U32 => 32,
+            Width::U64 => 64,
+            Width::U128 => 128,
+            Width::U256 => 256,
+            Width::U512 => 512,
+            Width::U1024 => 1024,
+        }
+    }
+
+    fn words(self) -> usize {
+        self.bits().div_ceil(64)
+    }
+
+    /// The FNV prime, this is always of the form 2^shift + low.
+    fn prime(self) -> Vec<u64> {
+        let (shift, low) = match self {
+            Width::U32 => (24, 0x193),
+            Width::U64 => (40, 0x1B3),
+            Width::U128 => (88, 0x13B),
+            Width::U256 => (168, 0x163),
+            Width::U512 => (344, 0x157),
+            Width::U1024 => (680, 0x18D),
+        };
+
+        let mut prime = vec![0; self.words()];
+        prime[0] = low;
+        prime[shift / 64] |= 1 << (shift % 64);
+        prime
+    }
+
+    fn wrapping_mul(self, lhs: &[u64], rhs: &[u64]) -> Vec<u64> {
+        let words = self.words();
+        let mut result = vec![0u64; words];
+
+        for i in 0..words {
+            let mut carry = 0u128;
+
+            for j in 0..(words - i) {
+                let product =
+                    u128::from(lhs[i]) * u128::from(rhs[j]) + u128::from(result[i + j]) + carry;
+
+                result[i + j] = product as u64;
+                carry = product >> 64;
+            }
+        }
+
+        if self.bits() < 64 {
+            result[0] &= (1 << self.bits()) - 1;
+        }
+
+        result
+    }
+
+    fn fnv0_from(self, mut hash: Vec<u64>, bytes: &[u8]) -> Vec<u64> {
+        let prime = self.prime();
+
+        for byte in bytes {
+            hash = self.wrapping_mul(&hash, &prime);
+            hash[0] ^= u64::from(*byte);
+        }
+
+        hash
+    }
+
+    fn offset_basis(self) -> Vec<u64> {
+        self.fnv0_from(vec![0; self.words()], OFFSET_SIGNATURE)
+    }
+}
+
+/// Hashes the bytes, producing the little-endian 64-bit words of the hash.
+pub fn hash(variant: Variant, width: Width, bytes: &[u8]) -> Vec<u64> {
+    match variant {
+        Variant::Fnv0 => width.fnv0_from(vec![0; width.words()], bytes),
+        Variant::Fnv1 => width.fnv0_from(width.offset_basis(), bytes),
+        Variant::Fnv1a => {
+            let prime = width.prime();
+            let mut hash = width.offset_basis();
+
+            for byte in bytes {
+                hash[0] ^= u64::from(*byte);
+                hash = width.wrapping_mul(&hash, &prime);
+            }
+
+            hash
+        }
+    }
+}
@@ -0,0 +1,318 @@
+//! Procedural macros for the lz_fnv crate.
+//!
+//! These macros are re-exported by `lz_fnv` and should be used through it.
+#![deny(missing_docs)]
+
+extern crate proc_macro;
+extern crate proc_macro2;
+#[macro_use]
+extern crate quote;
+extern crate syn;
+
+mod fnv;
+
+use fnv::{Variant, Width};
+use proc_macro::TokenStream;
+use proc_macro2::{Literal, Span, TokenStream as TokenStream2};
+use std::collections::HashMap;
+use syn::parse::{Parse, ParseStream};
+use syn::spanned::Spanned;
+use syn::{ExprLit, ExprMatch, Ident, Lit, LitStr, Pat, Token};
+
+/// The input to the FNV literal macros, `width, literal` followed by an
+/// optional `nul` flag.
+struct FnvInput {
+    width: Width,
+    bytes: Vec<u8>,
+}
+
+impl Parse for FnvInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let width_ident: Ident = input.parse()?;
+        let width = Width::from_type_name(&width_ident.to_string()).ok_or_else(|| {
+            syn::Error::new(
+                width_ident.span(),
+                "unsupported FNV width, expected one of u32, u64, u128, U256, U512 or U1024",
+            )
+        })?;
+
+        input.parse::<Token![,]>()?;
+
+        if !input.peek(Lit) {
+            return Err(input.error(
+                "FNV macros can only hash a string or byte string literal, use the const fn \
+                 hash functions such as `lz_fnv::fnv1a_64` for other expressions",
+            ));
+        }
+
+        let mut bytes = match input.parse()? {
+            Lit::Str(lit) => lit.value().into_bytes(),
+            Lit::ByteStr(lit) => lit.value(),
+            lit => {
+                return Err(syn::Error::new(
+                    lit.span(),
+                    "FNV macros can only hash a string or byte string literal",
+                ))
+            }
+        };
+
+        if input.parse::<Option<Token![,]>>()?.is_some() && !input.is_empty() {
+            let flag: Ident = input.parse()?;
+
+            if flag != "nul" {
+                return Err(syn::Error::new(flag.span(), "expected `nul`"));
+            }
+
+            bytes.push(0);
+
+            input.parse::<Option<Token![,]>>()?;
+        }
+
+        Ok(FnvInput { width, bytes })
+    }
+}
+
+/// Converts an error into a `compile_error!` invocation.
+///
+/// `syn::Error::to_compile_error` refers to `::core` which is not available in
+/// Rust 2015 crates which link `std`.
+fn compile_error(err: &syn::Error) -> TokenStream {
+    let message = err.to_string();
+
+    quote_spanned!(err.span()=> compile_error!(#message)).into()
+}
+
+/// Converts the words of a hash into the tokens of its value.
+fn hash_tokens(width: Width, words: &[u64]) -> TokenStream2 {
+    match width {
+        Width::U32 => {
+            let literal = Literal::u32_suffixed(words[0] as u32);
+            quote!(#literal)
+        }
+        Width::U64 => {
+            let literal = Literal::u64_suffixed(words[0]);
+            quote!(#literal)
+        }
+        Width::U128 => {
+            let literal = Literal::u128_suffixed(u128::from(words[1]) << 64 | u128::from(words[0]));
+            quote!(#literal)
+        }
+        Width::U256 | Width::U512 | Width::U1024 => {
+            let type_name = Ident::new(&format!("U{}", width.bits()), Span::call_site());
+            let words = words.iter().rev().map(|word| Literal::u64_suffixed(*word));
+
+            quote!(::lz_fnv::#type_name::from_words([#(#words),*]))
+        }
+    }
+}
+
+fn hash_literal(variant: Variant, input: TokenStream) -> TokenStream {
+    let input = match syn::parse::<FnvInput>(input) {
+        Ok(input) => input,
+        Err(err) => return compile_error(&err),
+    };
+
+    let words = fnv::hash(variant, input.width, &input.bytes);
+
+    hash_tokens(input.width, &words).into()
+}
+
+/// Computes the FNV-0 hash of a string or byte string literal at compile
+/// time.
+///
+/// The first argument is the hash type, one of `u32`, `u64`, `u128`, `U256`,
+/// `U512` or `U1024`, the second is the literal to hash. A trailing `nul`
+/// argument appends a nul byte to the hashed bytes, matching
+/// `CString::as_bytes_with_nul`.
+///
+/// The `u32`, `u64` and `u128` hashes expand to an integer literal so they can
+/// be used as `match` patterns.
+#[proc_macro]
+pub fn fnv0(input: TokenStream) -> TokenStream {
+    hash_literal(Variant::Fnv0, input)
+}
+
+/// Computes the FNV-1 hash of a string or byte string literal at compile
+/// time.
+///
+/// The first argument is the hash type, one of `u32`, `u64`, `u128`, `U256`,
+/// `U512` or `U1024`, the second is the literal to hash. A trailing `nul`
+/// argument appends a nul byte to the hashed bytes, matching
+/// `CString::as_bytes_with_nul`.
+///
+/// The `u32`, `u64` and `u128` hashes expand to an integer literal so they can
+/// be used as `match` patterns.
+#[proc_macro]
+pub fn fnv1(input: TokenStream) -> TokenStream {
+    hash_literal(Variant::Fnv1, input)
+}
+
+/// Computes the FNV-1a hash of a string or byte string literal at compile
+/// time.
+///
+/// The first argument is the hash type, one of `u32`, `u64`, `u128`, `U256`,
+/// `U512` or `U1024`, the second is the literal to hash. A trailing `nul`
+/// argument appends a nul byte to the hashed bytes, matching
+/// `CString::as_bytes_with_nul`.
+///
+/// The `u32`, `u64` and `u128` hashes expand to an integer literal so they can
+/// be used as `match` patterns.
+#[proc_macro]
+pub fn fnv1a(input: TokenStream) -> TokenStream {
+    hash_literal(Variant::Fnv1a, input)
+}
+
+/// The input to `fnv_match!`, an optional `width;` followed by a `match`
+/// expression.
+struct MatchInput {
+    width: Width,
+    expr_match: ExprMatch,
+}
+
+impl Parse for MatchInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let width = if input.peek(Ident) && input.peek2(Token![;]) {
+            let width_ident: Ident = input.parse()?;
+            input.parse::<Token![;]>()?;
+
+            match Width::from_type_name(&width_ident.to_string()) {
+                Some(width @ Width::U32) | Some(width @ Width::U64) | Some(width @ Width::U128) => {
+                    width
+                }
+                _ => {
+                    return Err(syn::Error::new(
+                        width_ident.span(),
+                        "unsupported fnv_match! width, expected one of u32, u64 or u128",
+                    ))
+                }
+            }
+        } else {
+            Width::U64
+        };
+
+        Ok(MatchInput {
+            width,
+            expr_match: input.parse()?,
+        })
+    }
+}
+
+/// Collects the string literals of a pattern, which may be an or-pattern.
+fn pattern_literals(pat: &Pat) -> syn::Result<Vec<LitStr>> {
+    match pat {
+        Pat::Lit(ExprLit {
+            lit: Lit::Str(lit), ..
+        }) => Ok(vec![lit.clone()]),
+        Pat::Or(pat_or) => {
+            let mut literals = Vec::new();
+
+            for case in &pat_or.cases {
+                literals.extend(pattern_literals(case)?);
+            }
+
+            Ok(literals)
+        }
+        pat => Err(syn::Error::new(
+            pat.span(),
+            "fnv_match! patterns must be string literals or `_`",
+        )),
+    }
+}
+
+fn expand_match(input: MatchInput) -> syn::Result<TokenStream2> {
+    let scrutinee_ref = Ident::new("__lz_fnv_scrutinee", Span::call_site());
+    let value = Ident::new("__lz_fnv_value", Span::call_site());
+    let scrutinee = &input.expr_match.expr;
+    let mut hashes: HashMap<Vec<u64>, LitStr> = HashMap::new();
+    let mut arms = Vec::new();
+    let mut has_wildcard = false;
+
+    for arm in &input.expr_match.arms {
+        let body = &arm.body;
+        let guard = arm.guard.as_ref().map(|(_, guard)| quote!(&& (#guard)));
+
+        if let Pat::Wild(_) = arm.pat {
+            has_wildcard = true;
+
+            let guard = arm
+                .guard
+                .as_ref()
+                .map(|(if_token, guard)| quote!(#if_token #guard));
+            arms.push(quote!(_ #guard => #body,));
+            continue;
+        }
+
+        let literals = pattern_literals(&arm.pat)?;
+        let mut patterns = Vec::new();
+
+        for literal in &literals {
+            let value = literal.value();
+            let words = fnv::hash(Variant::Fnv1a, input.width, value.as_bytes());
+
+            if let Some(existing) = hashes.get(&words) {
+                if existing.value() != value {
+                    return Err(syn::Error::new(
+                        literal.span(),
+                        format!(
+                            "the FNV-1a hash of {:?} collides with {:?}, use a wider hash",
+                            value,
+                            existing.value()
+                        ),
+                    ));
+                }
+            }
+
+            patterns.push(hash_tokens(input.width, &words));
+            hashes.insert(words, literal.clone());
+        }
+
+        arms.push(quote! {
+            #(#patterns)|* if (#(#value == #literals)||*) #guard => #body,
+        });
+    }
+
+    if !has_wildcard {
+        return Err(syn::Error::new(
+            input.expr_match.match_token.span,
+            "fnv_match! requires a `_` arm",
+        ));
+    }
+
+    let hash_fn = Ident::new(&format!("fnv1a_{}", input.width.bits()), Span::call_site());
+
+    Ok(quote! {
+        {
+            // Borrowing in the `let` extends the lifetime of a temporary
+            // scrutinee to the end of the block.
+            let #scrutinee_ref = &(#scrutinee);
+            let #value: &str = AsRef::<str>::as_ref(#scrutinee_ref);
+
+            match ::lz_fnv::#hash_fn(#value.as_bytes()) {
+                #(#arms)*
+            }
+        }
+    })
+}
+
+/// Matches a string against string literal patterns by their FNV-1a hash.
+///
+/// The input is a `match` expression whose patterns are string literals, or
+/// or-patterns of string literals, with a final `_` arm. The patterns are
+/// hashed at compile time and the match is performed on the hash of the
+/// string, each arm then compares the string to guard against collisions.
+///
+/// The 64-bit FNV-1a hash is used by default, `u32;` or `u128;` may precede
+/// the `match` to use another width. Patterns whose hashes collide are
+/// rejected at compile time.
+#[proc_macro]
+pub fn fnv_match(input: TokenStream) -> TokenStream {
+    let input = match syn::parse::<MatchInput>(input) {
+        Ok(input) => input,
+        Err(err) => return compile_error(&err),
+    };
+
+    match expand_match(input) {
+        Ok(expanded) => expanded.into(),
+        Err(err) => compile_error(&err),
+    }
+}
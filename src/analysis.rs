@@ -0,0 +1,680 @@
+//! Measurements of the quality of FNV hashes.
+//!
+//! Four measurements can be made of any `FnvHasher`:
+//!
+//! - The strict avalanche criterion, that flipping any bit of the input flips
+//!   each bit of the hash with a probability of one half.
+//! - The bit independence criterion, that the bits of the hash flipped by
+//!   flipping a bit of the input are not correlated with each other.
+//! - The chi-squared statistic of the distribution of keys into buckets.
+//! - The number of birthday collisions between keys, compared with the number
+//!   expected of a random function.
+//!
+//! The avalanche and bit independence criteria are measured over pseudo-random
+//! inputs, whereas the keys are the integers `0..keys` as little-endian bytes,
+//! which are typical of the structured keys found in practice. Every
+//! measurement is deterministic, so results can be compared between versions.
+//!
+//! ```
+//! use lz_fnv::analysis::{self, Config};
+//! use lz_fnv::Fnv1a;
+//!
+//! let config = Config {
+//!     samples: 100,
+//!     keys: 10_000,
+//!     ..Config::default()
+//! };
+//! let report = analysis::analyze::<Fnv1a<u64>>(&config);
+//!
+//! println!("{}", report);
+//! assert!(report.chi_squared.z_score < 3.0);
+//! ```
+use std::collections::HashSet;
+use std::fmt;
+use std::hash::Hash;
+use variants::{Fnv1aYt, Jesteress, Meiyan};
+use {Fnv0, Fnv1, Fnv1a, FnvHasher, U1024, U256, U512};
+
+/// The seed of the pseudo-random inputs.
+const SEED: u64 = 0x2545_f491_4f6c_dd1d;
+
+/// The number of lowest bits of the hash the bit independence criterion is
+/// measured over.
+const BIC_BITS: u32 = 64;
+
+/// The largest number of bits which select a bucket, limiting the buckets to
+/// about a million.
+const MAX_BUCKET_BITS: u32 = 20;
+
+/// A hash whose individual bits can be measured.
+pub trait HashBits: Copy + Eq + Hash {
+    /// The width of the hash in bits.
+    const BITS: u32;
+
+    /// Gets the bit at `index`, where bit 0 is the least significant.
+    fn bit(&self, index: u32) -> bool;
+
+    /// Gets the lowest 64 bits of the hash, zero-extended if the hash is
+    /// narrower.
+    fn low_u64(&self) -> u64;
+}
+
+macro_rules! hash_bits_impl {
+    ($type: ty) => {
+        impl HashBits for $type {
+            const BITS: u32 = <$type>::BITS;
+
+            fn bit(&self, index: u32) -> bool {
+                (*self >> index) & 1 == 1
+            }
+
+            fn low_u64(&self) -> u64 {
+                *self as u64
+            }
+        }
+    };
+}
+
+hash_bits_impl!(u8);
+hash_bits_impl!(u16);
+hash_bits_impl!(u32);
+hash_bits_impl!(u64);
+hash_bits_impl!(u128);
+
+macro_rules! wide_hash_bits_impl {
+    ($type: ty, $words: expr) => {
+        impl HashBits for $type {
+            const BITS: u32 = 64 * $words;
+
+            fn bit(&self, index: u32) -> bool {
+                let word = self.to_words()[$words - 1 - (index / 64) as usize];
+
+                (word >> (index % 64)) & 1 == 1
+            }
+
+            fn low_u64(&self) -> u64 {
+                self.to_words()[$words - 1]
+            }
+        }
+    };
+}
+
+wide_hash_bits_impl!(U256, 4);
+wide_hash_bits_impl!(U512, 8);
+wide_hash_bits_impl!(U1024, 16);
+
+/// The parameters of an analysis.
+#[derive(Clone, Copy, Debug)]
+pub struct Config {
+    /// The length in bytes of the inputs and keys.
+    pub input_len: usize,
+    /// The number of pseudo-random inputs the avalanche and bit independence
+    /// criteria are measured over.
+    pub samples: usize,
+    /// The number of keys hashed to measure the bucket distribution and
+    /// birthday collisions.
+    pub keys: usize,
+    /// The number of lowest bits of the hash which select a bucket, there are
+    /// `2^bucket_bits` buckets. This is clamped to between 1 and 20, and to
+    /// the width of the hash.
+    pub bucket_bits: u32,
+    /// The number of lowest bits of the hash which birthday collisions are
+    /// counted in. This is clamped to between 1 and 64, and to the width of
+    /// the hash.
+    pub birthday_bits: u32,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            input_len: 8,
+            samples: 1000,
+            keys: 100_000,
+            bucket_bits: 10,
+            birthday_bits: 32,
+        }
+    }
+}
+
+/// The strict avalanche criterion of a hash.
+#[derive(Clone, Copy, Debug)]
+pub struct Avalanche {
+    /// The mean distance from one half of the probability that flipping an
+    /// input bit flips a hash bit, over every pair of input and hash bits.
+    pub mean_bias: f64,
+    /// The largest distance from one half of the probability that flipping an
+    /// input bit flips a hash bit.
+    pub max_bias: f64,
+}
+
+/// The bit independence criterion of a hash.
+#[derive(Clone, Copy, Debug)]
+pub struct BitIndependence {
+    /// The number of lowest bits of the hash which were measured, at most 64.
+    pub bits: u32,
+    /// The mean absolute correlation between the flips of two hash bits, over
+    /// every input bit and pair of hash bits.
+    pub mean_correlation: f64,
+    /// The largest absolute correlation between the flips of two hash bits.
+    pub max_correlation: f64,
+}
+
+/// The distribution of keys into buckets.
+#[derive(Clone, Copy, Debug)]
+pub struct ChiSquared {
+    /// The chi-squared statistic of the bucket counts.
+    pub statistic: f64,
+    /// The degrees of freedom, one less than the number of buckets.
+    pub degrees_of_freedom: u64,
+    /// The statistic normalized to a standard normal distribution. Values
+    /// above ~3 suggest the keys are not uniformly distributed, values far
+    /// below zero that they are spread more evenly than at random.
+    pub z_score: f64,
+}
+
+/// The birthday collisions between keys.
+#[derive(Clone, Copy, Debug)]
+pub struct Birthday {
+    /// The number of lowest bits of the hash which were compared.
+    pub bits: u32,
+    /// The number of keys which have the same hash as an earlier key.
+    pub collisions: usize,
+    /// The number of collisions expected of a random function.
+    pub expected: f64,
+}
+
+/// The results of every measurement of a hash.
+#[derive(Clone, Copy, Debug)]
+pub struct Report {
+    /// The strict avalanche criterion.
+    pub avalanche: Avalanche,
+    /// The bit independence criterion.
+    pub bit_independence: BitIndependence,
+    /// The distribution of keys into buckets.
+    pub chi_squared: ChiSquared,
+    /// The birthday collisions between keys.
+    pub birthday: Birthday,
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "SAC bias {:.4} (max {:.4}), BIC |r| {:.4} (max {:.4}), chi-squared z {:+.2}, \
+             {} collisions in {} bits (expected {:.2})",
+            self.avalanche.mean_bias,
+            self.avalanche.max_bias,
+            self.bit_independence.mean_correlation,
+            self.bit_independence.max_correlation,
+            self.chi_squared.z_score,
+            self.birthday.collisions,
+            self.birthday.bits,
+            self.birthday.expected
+        )
+    }
+}
+
+/// Makes every measurement of the hasher `H`.
+pub fn analyze<H>(config: &Config) -> Report
+where
+    H: FnvHasher + Default,
+    H::Hash: HashBits,
+{
+    Report {
+        avalanche: avalanche::<H>(config),
+        bit_independence: bit_independence::<H>(config),
+        chi_squared: chi_squared::<H>(config),
+        birthday: birthday::<H>(config),
+    }
+}
+
+/// Makes every measurement of FNV-0, FNV-1 and FNV-1a for every width,
+/// followed by the 32-bit hashes of the `variants` module.
+pub fn analyze_all(config: &Config) -> Vec<(&'static str, Report)> {
+    vec![
+        ("FNV-0 32", analyze::<Fnv0<u32>>(config)),
+        ("FNV-1 32", analyze::<Fnv1<u32>>(config)),
+        ("FNV-1a 32", analyze::<Fnv1a<u32>>(config)),
+        ("FNV-0 64", analyze::<Fnv0<u64>>(config)),
+        ("FNV-1 64", analyze::<Fnv1<u64>>(config)),
+        ("FNV-1a 64", analyze::<Fnv1a<u64>>(config)),
+        ("FNV-0 128", analyze::<Fnv0<u128>>(config)),
+        ("FNV-1 128", analyze::<Fnv1<u128>>(config)),
+        ("FNV-1a 128", analyze::<Fnv1a<u128>>(config)),
+        ("FNV-0 256", analyze::<Fnv0<U256>>(config)),
+        ("FNV-1 256", analyze::<Fnv1<U256>>(config)),
+        ("FNV-1a 256", analyze::<Fnv1a<U256>>(config)),
+        ("FNV-0 512", analyze::<Fnv0<U512>>(config)),
+        ("FNV-1 512", analyze::<Fnv1<U512>>(config)),
+        ("FNV-1a 512", analyze::<Fnv1a<U512>>(config)),
+        ("FNV-0 1024", analyze::<Fnv0<U1024>>(config)),
+        ("FNV-1 1024", analyze::<Fnv1<U1024>>(config)),
+        ("FNV-1a 1024", analyze::<Fnv1a<U1024>>(config)),
+        ("FNV-1a-YT 32", analyze::<Fnv1aYt>(config)),
+        ("Jesteress 32", analyze::<Jesteress>(config)),
+        ("Meiyan 32", analyze::<Meiyan>(config)),
+    ]
+}
+
+/// Measures the strict avalanche criterion of the hasher `H`.
+pub fn avalanche<H>(config: &Config) -> Avalanche
+where
+    H: FnvHasher + Default,
+    H::Hash: HashBits,
+{
+    let hash_bits = H::Hash::BITS as usize;
+    let mut flips = vec![0usize; config.input_len * 8 * hash_bits];
+
+    for_each_flip::<H, _>(config, |input_bit, hash, flipped| {
+        let row = &mut flips[input_bit * hash_bits..][..hash_bits];
+
+        for (hash_bit, count) in row.iter_mut().enumerate() {
+            if hash.bit(hash_bit as u32) != flipped.bit(hash_bit as u32) {
+                *count += 1;
+            }
+        }
+    });
+
+    let biases: Vec<f64> = flips
+        .iter()
+        .map(|count| (*count as f64 / config.samples as f64 - 0.5).abs())
+        .collect();
+
+    Avalanche {
+        mean_bias: mean(&biases),
+        max_bias: biases.iter().cloned().fold(0.0, f64::max),
+    }
+}
+
+/// Measures the bit independence criterion of the lowest 64 bits of the
+/// hasher `H`.
+///
+/// Hash bits which always or never flip are fully dependent on the input bit,
+/// their correlation with any other hash bit is taken to be one.
+pub fn bit_independence<H>(config: &Config) -> BitIndependence
+where
+    H: FnvHasher + Default,
+    H::Hash: HashBits,
+{
+    let bits = H::Hash::BITS.min(BIC_BITS);
+    let hash_bits = bits as usize;
+    let mask = low_bits_mask(bits);
+    let input_bits = config.input_len * 8;
+    let mut flips = vec![0usize; input_bits * hash_bits];
+    let mut joint_flips = vec![0usize; input_bits * hash_bits * hash_bits];
+
+    for_each_flip::<H, _>(config, |input_bit, hash, flipped| {
+        let flips = &mut flips[input_bit * hash_bits..][..hash_bits];
+        let joint_flips = &mut joint_flips[input_bit * hash_bits * hash_bits..];
+        let mut flipped_bits = (hash.low_u64() ^ flipped.low_u64()) & mask;
+
+        while flipped_bits != 0 {
+            let first = flipped_bits.trailing_zeros() as usize;
+            flipped_bits &= flipped_bits - 1;
+            flips[first] += 1;
+
+            let mut later_bits = flipped_bits;
+
+            while later_bits != 0 {
+                let second = later_bits.trailing_zeros() as usize;
+                later_bits &= later_bits - 1;
+                joint_flips[first * hash_bits + second] += 1;
+            }
+        }
+    });
+
+    let samples = config.samples as f64;
+    let mut correlations = Vec::new();
+
+    for input_bit in 0..input_bits {
+        let flips = &flips[input_bit * hash_bits..][..hash_bits];
+        let joint_flips = &joint_flips[input_bit * hash_bits * hash_bits..];
+
+        for first in 0..hash_bits {
+            for second in first + 1..hash_bits {
+                let first_flips = flips[first] as f64;
+                let second_flips = flips[second] as f64;
+                let covariance = samples * joint_flips[first * hash_bits + second] as f64
+                    - first_flips * second_flips;
+                let variance =
+                    first_flips * (samples - first_flips) * second_flips * (samples - second_flips);
+
+                correlations.push(if variance == 0.0 {
+                    1.0
+                } else {
+                    (covariance / variance.sqrt()).abs()
+                });
+            }
+        }
+    }
+
+    BitIndependence {
+        bits,
+        mean_correlation: mean(&correlations),
+        max_correlation: correlations.iter().cloned().fold(0.0, f64::max),
+    }
+}
+
+/// Measures the distribution of keys into buckets by the hasher `H`.
+///
+/// # Panics
+///
+/// Panics if `config.input_len` is too short for `config.keys` distinct keys.
+pub fn chi_squared<H>(config: &Config) -> ChiSquared
+where
+    H: FnvHasher + Default,
+    H::Hash: HashBits,
+{
+    let bucket_bits = config
+        .bucket_bits
+        .clamp(1, H::Hash::BITS.min(MAX_BUCKET_BITS));
+    let buckets = 1usize << bucket_bits;
+    let mut counts = vec![0usize; buckets];
+
+    for hash in key_hashes::<H>(config) {
+        counts[(hash.low_u64() as usize) & (buckets - 1)] += 1;
+    }
+
+    let expected = config.keys as f64 / buckets as f64;
+    let statistic = counts
+        .iter()
+        .map(|count| (*count as f64 - expected).powi(2) / expected)
+        .sum();
+    let degrees_of_freedom = buckets as u64 - 1;
+
+    ChiSquared {
+        statistic,
+        degrees_of_freedom,
+        z_score: wilson_hilferty(statistic, degrees_of_freedom as f64),
+    }
+}
+
+/// Counts the birthday collisions between keys hashed by the hasher `H`.
+///
+/// # Panics
+///
+/// Panics if `config.input_len` is too short for `config.keys` distinct keys.
+pub fn birthday<H>(config: &Config) -> Birthday
+where
+    H: FnvHasher + Default,
+    H::Hash: HashBits,
+{
+    let bits = config.birthday_bits.clamp(1, H::Hash::BITS.min(64));
+    let mask = low_bits_mask(bits);
+    let mut hashes = HashSet::new();
+    let collisions = key_hashes::<H>(config)
+        .filter(|hash| !hashes.insert(hash.low_u64() & mask))
+        .count();
+
+    Birthday {
+        bits,
+        collisions,
+        expected: expected_collisions(config.keys, bits),
+    }
+}
+
+/// The number of collisions expected between `keys` values of a random
+/// function with `bits` bit outputs.
+fn expected_collisions(keys: usize, bits: u32) -> f64 {
+    let keys = keys as f64;
+    let outputs = 2f64.powi(bits as i32);
+    let distinct = outputs * -(keys * (-1.0 / outputs).ln_1p()).exp_m1();
+
+    keys - distinct
+}
+
+/// Normalizes a chi-squared statistic to a standard normal distribution with
+/// the Wilson-Hilferty transformation.
+fn wilson_hilferty(statistic: f64, degrees_of_freedom: f64) -> f64 {
+    let variance = 2.0 / (9.0 * degrees_of_freedom);
+
+    ((statistic / degrees_of_freedom).cbrt() - (1.0 - variance)) / variance.sqrt()
+}
+
+/// Gets a mask of the lowest `bits` bits, `bits` must be at most 64.
+fn low_bits_mask(bits: u32) -> u64 {
+    if bits == 0 {
+        0
+    } else {
+        u64::MAX >> (64 - bits)
+    }
+}
+
+fn mean(values: &[f64]) -> f64 {
+    values.iter().sum::<f64>() / values.len() as f64
+}
+
+fn hash_input<H: FnvHasher + Default>(input: &[u8]) -> H::Hash {
+    let mut hasher = H::default();
+    hasher.write(input);
+    hasher.finish()
+}
+
+/// Calls `f` with the input bit, the hash and the hash with the input bit
+/// flipped, for every bit of every pseudo-random input.
+fn for_each_flip<H, F>(config: &Config, mut f: F)
+where
+    H: FnvHasher + Default,
+    H::Hash: HashBits,
+    F: FnMut(usize, H::Hash, H::Hash),
+{
+    let mut state = SEED;
+    let mut input = vec![0; config.input_len];
+
+    for _ in 0..config.samples {
+        for byte in &mut input {
+            *byte = split_mix(&mut state) as u8;
+        }
+
+        let hash = hash_input::<H>(&input);
+
+        for input_bit in 0..input.len() * 8 {
+            input[input_bit / 8] ^= 1 << (input_bit % 8);
+            let flipped = hash_input::<H>(&input);
+            input[input_bit / 8] ^= 1 << (input_bit % 8);
+
+            f(input_bit, hash, flipped);
+        }
+    }
+}
+
+/// Hashes the keys `0..config.keys` as little-endian bytes.
+fn key_hashes<H: FnvHasher + Default>(config: &Config) -> impl Iterator<Item = H::Hash> {
+    let input_len = config.input_len;
+
+    assert!(
+        input_len >= 8 || (config.keys as u64) <= 1 << (8 * input_len),
+        "{} keys do not fit in {} bytes",
+        config.keys,
+        input_len
+    );
+
+    (0..config.keys as u64).map(move |key| {
+        let mut input = vec![0; input_len.max(8)];
+        input[..8].copy_from_slice(&key.to_le_bytes());
+
+        hash_input::<H>(&input[..input_len])
+    })
+}
+
+/// The SplitMix64 pseudo-random number generator.
+fn split_mix(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        analyze, avalanche, birthday, bit_independence, chi_squared, expected_collisions,
+        wilson_hilferty, Config, HashBits,
+    };
+    use {Fnv1, Fnv1a, Fnv1a16, FnvHasher, U1024, U256, U512};
+
+    const CONFIG: Config = Config {
+        input_len: 8,
+        samples: 64,
+        keys: 20_000,
+        bucket_bits: 8,
+        birthday_bits: 32,
+    };
+
+    macro_rules! fnv1a_beats_fnv1_tests {
+        ($($name: ident: $size: ty,)*) => {
+            $(
+                #[test]
+                fn $name() {
+                    let fnv1 = avalanche::<Fnv1<$size>>(&CONFIG);
+                    let fnv1a = avalanche::<Fnv1a<$size>>(&CONFIG);
+
+                    assert!(fnv1a.mean_bias < fnv1.mean_bias, "{:?} {:?}", fnv1a, fnv1);
+
+                    let fnv1 = bit_independence::<Fnv1<$size>>(&CONFIG);
+                    let fnv1a = bit_independence::<Fnv1a<$size>>(&CONFIG);
+
+                    assert!(
+                        fnv1a.mean_correlation < fnv1.mean_correlation,
+                        "{:?} {:?}",
+                        fnv1a,
+                        fnv1
+                    );
+                }
+            )*
+        };
+    }
+
+    fnv1a_beats_fnv1_tests! {
+        fnv1a_32_avalanches_better_than_fnv1: u32,
+        fnv1a_64_avalanches_better_than_fnv1: u64,
+        fnv1a_128_avalanches_better_than_fnv1: u128,
+        fnv1a_256_avalanches_better_than_fnv1: U256,
+        fnv1a_512_avalanches_better_than_fnv1: U512,
+        fnv1a_1024_avalanches_better_than_fnv1: U1024,
+    }
+
+    macro_rules! regression_tests {
+        ($($name: ident: $hasher: ty, $max_bias: expr, $max_correlation: expr,)*) => {
+            $(
+                #[test]
+                fn $name() {
+                    let report = analyze::<$hasher>(&CONFIG);
+
+                    assert!(report.avalanche.mean_bias < $max_bias, "{}", report);
+                    assert!(
+                        report.bit_independence.mean_correlation < $max_correlation,
+                        "{}",
+                        report
+                    );
+                    assert!(report.chi_squared.z_score < 3.0, "{}", report);
+                    assert!(report.birthday.collisions <= 4, "{}", report);
+                }
+            )*
+        };
+    }
+
+    // The thresholds are just above the measurements at the time of writing.
+    regression_tests! {
+        fnv1a_32_quality_has_not_regressed: Fnv1a<u32>, 0.17, 0.41,
+        fnv1a_64_quality_has_not_regressed: Fnv1a<u64>, 0.18, 0.40,
+    }
+
+    #[test]
+    fn measures_perfect_avalanche_of_a_random_function() {
+        let hasher_avalanche = avalanche::<Random>(&Config {
+            samples: 4096,
+            ..CONFIG
+        });
+
+        assert!(hasher_avalanche.mean_bias < 0.01, "{:?}", hasher_avalanche);
+    }
+
+    #[test]
+    fn gets_bits_of_wide_hashes() {
+        let value = U256::from_words([1 << 63, 0, 0, 0b101]);
+
+        assert!(value.bit(0));
+        assert!(!value.bit(1));
+        assert!(value.bit(2));
+        assert!(value.bit(255));
+        assert!(!value.bit(254));
+        assert_eq!(value.low_u64(), 0b101);
+    }
+
+    #[test]
+    fn clamps_bucket_bits() {
+        let one_bit = chi_squared::<Fnv1a<u64>>(&Config {
+            bucket_bits: 0,
+            ..CONFIG
+        });
+        let too_many_bits = chi_squared::<Fnv1a<u64>>(&Config {
+            bucket_bits: 64,
+            ..CONFIG
+        });
+        let narrow_hash = chi_squared::<Fnv1a16>(&Config {
+            bucket_bits: u32::MAX,
+            ..CONFIG
+        });
+
+        assert_eq!(one_bit.degrees_of_freedom, 1);
+        assert_eq!(too_many_bits.degrees_of_freedom, (1 << 20) - 1);
+        assert_eq!(narrow_hash.degrees_of_freedom, (1 << 16) - 1);
+    }
+
+    #[test]
+    fn clamps_birthday_bits() {
+        let one_bit = birthday::<Fnv1a<u64>>(&Config {
+            birthday_bits: 0,
+            ..CONFIG
+        });
+        let too_many_bits = birthday::<Fnv1a<u128>>(&Config {
+            birthday_bits: 65,
+            ..CONFIG
+        });
+        let narrow_hash = birthday::<Fnv1a<u32>>(&Config {
+            birthday_bits: u32::MAX,
+            ..CONFIG
+        });
+
+        assert_eq!(one_bit.bits, 1);
+        assert_eq!(one_bit.collisions, CONFIG.keys - 2);
+        assert_eq!(too_many_bits.bits, 64);
+        assert_eq!(narrow_hash.bits, 32);
+    }
+
+    #[test]
+    fn expects_birthday_collisions() {
+        // n^2 / 2m is a close approximation when n is much smaller than m.
+        assert!((expected_collisions(100_000, 32) - 1.164).abs() < 0.001);
+        assert!(expected_collisions(1, 8).abs() < 1e-9);
+    }
+
+    #[test]
+    fn normalizes_chi_squared_statistic() {
+        assert!(wilson_hilferty(1023.0, 1023.0).abs() < 0.05);
+        assert!((wilson_hilferty(1168.0, 1023.0) - 3.09).abs() < 0.05);
+    }
+
+    /// A hasher with the ideal avalanche, it hashes with SplitMix64.
+    #[derive(Default)]
+    struct Random {
+        hash: u64,
+    }
+
+    impl FnvHasher for Random {
+        type Hash = u64;
+
+        fn finish(&self) -> u64 {
+            super::split_mix(&mut { self.hash })
+        }
+
+        fn write(&mut self, bytes: &[u8]) {
+            for byte in bytes {
+                self.hash = super::split_mix(&mut (self.hash ^ u64::from(*byte)));
+            }
+        }
+    }
+}
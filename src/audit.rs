@@ -0,0 +1,235 @@
+//! Audits sets of keys for collisions under each FNV hash.
+//!
+//! Each key set is hashed with FNV-0, FNV-1 and FNV-1a at 32, 64 and 128 bits,
+//! the hashes can then be searched for collisions, xor-folded to fewer bits or
+//! distributed into the buckets of a table. Hashes are held as `u128`
+//! regardless of their width.
+//!
+//! ```
+//! use lz_fnv::audit::{self, Variant};
+//!
+//! let keys: &[&[u8]] = &[b"costarring", b"liquid", b"declinate", b"macallums"];
+//! let hashes = audit::hashes(Variant::Fnv1a, 32, keys);
+//!
+//! assert_eq!(audit::collisions(&hashes), vec![vec![0, 1], vec![2, 3]]);
+//! ```
+pub use state::Variant;
+use std::collections::HashMap;
+use {Fnv0, Fnv1, Fnv1a, FnvHasher};
+
+/// The widths of the hashes which can be audited.
+pub const WIDTHS: [u32; 3] = [32, 64, 128];
+
+/// Every FNV variant.
+pub const VARIANTS: [Variant; 3] = [Variant::Fnv0, Variant::Fnv1, Variant::Fnv1a];
+
+fn hash_with<H>(keys: &[&[u8]]) -> Vec<u128>
+where
+    H: FnvHasher + Default,
+    H::Hash: Into<u128>,
+{
+    keys.iter()
+        .map(|key| {
+            let mut hasher = H::default();
+            hasher.write(key);
+            hasher.finish().into()
+        })
+        .collect()
+}
+
+/// Hashes each key with a variant at a width.
+///
+/// # Panics
+///
+/// Panics if `bits` is not one of `WIDTHS`.
+pub fn hashes(variant: Variant, bits: u32, keys: &[&[u8]]) -> Vec<u128> {
+    match (variant, bits) {
+        (Variant::Fnv0, 32) => hash_with::<Fnv0<u32>>(keys),
+        (Variant::Fnv1, 32) => hash_with::<Fnv1<u32>>(keys),
+        (Variant::Fnv1a, 32) => hash_with::<Fnv1a<u32>>(keys),
+        (Variant::Fnv0, 64) => hash_with::<Fnv0<u64>>(keys),
+        (Variant::Fnv1, 64) => hash_with::<Fnv1<u64>>(keys),
+        (Variant::Fnv1a, 64) => hash_with::<Fnv1a<u64>>(keys),
+        (Variant::Fnv0, 128) => hash_with::<Fnv0<u128>>(keys),
+        (Variant::Fnv1, 128) => hash_with::<Fnv1<u128>>(keys),
+        (Variant::Fnv1a, 128) => hash_with::<Fnv1a<u128>>(keys),
+        _ => panic!("cannot audit {}-bit hashes", bits),
+    }
+}
+
+/// Xor-folds hashes of `bits` bits down to the lowest `folded_bits` bits, as
+/// `finish_folded` does.
+///
+/// # Panics
+///
+/// Panics if `folded_bits` is zero or not less than `bits`.
+pub fn fold(hashes: &[u128], bits: u32, folded_bits: u32) -> Vec<u128> {
+    assert!(
+        folded_bits > 0 && folded_bits < bits,
+        "cannot fold a {}-bit hash to {} bits",
+        bits,
+        folded_bits
+    );
+
+    let mask = (1 << folded_bits) - 1;
+
+    hashes
+        .iter()
+        .map(|hash| ((hash >> folded_bits) ^ hash) & mask)
+        .collect()
+}
+
+/// Finds the groups of indices of hashes which are equal.
+///
+/// The indices in each group are ascending, and the groups are ordered by
+/// their first index.
+pub fn collisions(hashes: &[u128]) -> Vec<Vec<usize>> {
+    let mut groups: HashMap<u128, Vec<usize>> = HashMap::new();
+
+    for (index, hash) in hashes.iter().enumerate() {
+        groups.entry(*hash).or_default().push(index);
+    }
+
+    let mut collisions: Vec<Vec<usize>> = groups
+        .into_values()
+        .filter(|group| group.len() > 1)
+        .collect();
+
+    collisions.sort();
+    collisions
+}
+
+/// The load of the buckets of a table.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BucketLoad {
+    /// The number of buckets in the table.
+    pub buckets: u128,
+    /// The number of buckets with no hashes.
+    pub empty: u128,
+    /// The number of empty buckets expected of a random function.
+    pub expected_empty: f64,
+    /// The largest number of hashes in a bucket.
+    pub max_load: usize,
+}
+
+/// Distributes hashes into `buckets` buckets by the remainder of dividing by
+/// `buckets`, as a hash table would.
+///
+/// # Panics
+///
+/// Panics if `buckets` is zero.
+pub fn bucket_load(hashes: &[u128], buckets: u128) -> BucketLoad {
+    assert!(buckets > 0, "cannot distribute hashes into no buckets");
+
+    let mut loads: HashMap<u128, usize> = HashMap::new();
+
+    for hash in hashes {
+        *loads.entry(hash % buckets).or_insert(0) += 1;
+    }
+
+    let buckets_f64 = buckets as f64;
+
+    BucketLoad {
+        buckets,
+        empty: buckets - loads.len() as u128,
+        expected_empty: buckets_f64 * (hashes.len() as f64 * (-1.0 / buckets_f64).ln_1p()).exp(),
+        max_load: loads.values().cloned().max().unwrap_or(0),
+    }
+}
+
+/// Splits `input` into keys separated by `separator`.
+///
+/// A final separator does not start another key, and when splitting by
+/// newlines a carriage return before each newline is removed.
+pub fn split_keys(input: &[u8], separator: u8) -> Vec<&[u8]> {
+    if input.is_empty() {
+        return Vec::new();
+    }
+
+    input
+        .strip_suffix(&[separator])
+        .unwrap_or(input)
+        .split(|byte| *byte == separator)
+        .map(|key| {
+            if separator == b'\n' {
+                key.strip_suffix(b"\r").unwrap_or(key)
+            } else {
+                key
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{bucket_load, collisions, fold, hashes, split_keys, Variant, VARIANTS, WIDTHS};
+    use {Fnv1a, FnvHasher};
+
+    #[test]
+    fn hashes_match_hashers() {
+        let keys: &[&[u8]] = &[b"", b"a", b"foobar"];
+
+        for variant in &VARIANTS {
+            for bits in &WIDTHS {
+                assert_eq!(hashes(*variant, *bits, keys).len(), keys.len());
+            }
+        }
+
+        let mut fnv1a = Fnv1a::<u64>::new();
+        fnv1a.write(b"foobar");
+
+        assert_eq!(
+            hashes(Variant::Fnv1a, 64, keys)[2],
+            u128::from(fnv1a.finish())
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn hashes_panics_for_unsupported_width() {
+        hashes(Variant::Fnv1a, 256, &[b"foobar"]);
+    }
+
+    #[test]
+    fn finds_known_collisions() {
+        // Known FNV-1a 32-bit collisions.
+        let keys: &[&[u8]] = &[b"costarring", b"declinate", b"liquid", b"macallums"];
+
+        assert_eq!(
+            collisions(&hashes(Variant::Fnv1a, 32, keys)),
+            vec![vec![0, 2], vec![1, 3]]
+        );
+        assert!(collisions(&hashes(Variant::Fnv1a, 64, keys)).is_empty());
+    }
+
+    #[test]
+    fn folds_as_finish_folded() {
+        let mut fnv1a = Fnv1a::<u32>::new();
+        fnv1a.write(b"foobar");
+
+        let folded = fold(&hashes(Variant::Fnv1a, 32, &[b"foobar"]), 32, 16);
+
+        assert_eq!(folded, vec![u128::from(fnv1a.finish_folded(16))]);
+    }
+
+    #[test]
+    fn measures_bucket_load() {
+        let load = bucket_load(&[0, 1, 4, 5, 8], 4);
+
+        assert_eq!(load.buckets, 4);
+        assert_eq!(load.empty, 2);
+        assert_eq!(load.max_load, 3);
+        assert!((load.expected_empty - 4.0 * 0.75f64.powi(5)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn splits_keys() {
+        assert_eq!(
+            split_keys(b"a\nb\r\n\nc\n", b'\n'),
+            vec![&b"a"[..], b"b", b"", b"c"]
+        );
+        assert_eq!(split_keys(b"a\r\0b", b'\0'), vec![&b"a\r"[..], b"b"]);
+        assert!(split_keys(b"", b'\n').is_empty());
+        assert_eq!(split_keys(b"\n", b'\n'), vec![&b""[..]]);
+    }
+}
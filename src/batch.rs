@@ -0,0 +1,397 @@
+//! Hashing of many inputs at once.
+//!
+//! Hashing a single input is a chain of dependent multiplies, so little of it
+//! can run in parallel. Interleaving several independent inputs lets their
+//! multiplies overlap.
+//!
+//! ```
+//! use lz_fnv::{hash_many, Fnv1a, FnvHasher};
+//!
+//! let hashes = hash_many::<Fnv1a<u32>>(&[b"foo", b"bar"]);
+//!
+//! let mut fnv_hasher = Fnv1a::<u32>::new();
+//! fnv_hasher.write(b"bar");
+//!
+//! assert_eq!(hashes[1], fnv_hasher.finish());
+//! ```
+use {Fnv0, Fnv1, Fnv1a, FnvHasher, FnvParameters, U1024, U256, U512};
+
+/// An FNV hasher which can hash many inputs at once.
+///
+/// The default implementation hashes each input in turn, the implementations
+/// for the FNV hashers in this crate interleave the inputs and the 32-bit
+/// hashers use AVX2 when it is available at runtime.
+pub trait HashMany: FnvHasher + Default {
+    /// Hashes each of the inputs, producing the same hashes as hashing each
+    /// input with a new hasher.
+    fn hash_many(inputs: &[&[u8]]) -> Vec<Self::Hash> {
+        inputs
+            .iter()
+            .map(|input| {
+                let mut hasher = Self::default();
+                hasher.write(input);
+                hasher.finish()
+            })
+            .collect()
+    }
+}
+
+/// Hashes each of the inputs with a new `H`.
+///
+/// The hashes are in the same order as the inputs.
+pub fn hash_many<H: HashMany>(inputs: &[&[u8]]) -> Vec<H::Hash> {
+    H::hash_many(inputs)
+}
+
+/// Mixes a byte into a hash, xoring before multiplying when `xor_first` is
+/// set.
+#[inline(always)]
+fn mix<T: FnvParameters>(hash: T, byte: u8, xor_first: bool) -> T {
+    if xor_first {
+        hash.xor_byte(byte).wrapping_mul_prime()
+    } else {
+        hash.wrapping_mul_prime().xor_byte(byte)
+    }
+}
+
+fn hash_one<T: FnvParameters>(mut hash: T, input: &[u8], xor_first: bool) -> T {
+    for byte in input {
+        hash = mix(hash, *byte, xor_first);
+    }
+
+    hash
+}
+
+/// Hashes each of the inputs starting at `basis`, four at a time.
+fn hash_interleaved<T: FnvParameters>(inputs: &[&[u8]], basis: T, xor_first: bool) -> Vec<T> {
+    let mut hashes = Vec::with_capacity(inputs.len());
+    let mut groups = inputs.chunks_exact(4);
+
+    for group in &mut groups {
+        let common_len = group.iter().map(|input| input.len()).min().unwrap_or(0);
+        let mut lanes = [basis; 4];
+
+        let bytes = group[0][..common_len]
+            .iter()
+            .zip(&group[1][..common_len])
+            .zip(&group[2][..common_len])
+            .zip(&group[3][..common_len]);
+
+        for (((byte0, byte1), byte2), byte3) in bytes {
+            lanes[0] = mix(lanes[0], *byte0, xor_first);
+            lanes[1] = mix(lanes[1], *byte1, xor_first);
+            lanes[2] = mix(lanes[2], *byte2, xor_first);
+            lanes[3] = mix(lanes[3], *byte3, xor_first);
+        }
+
+        hashes.extend(
+            lanes
+                .iter()
+                .zip(group)
+                .map(|(hash, input)| hash_one(*hash, &input[common_len..], xor_first)),
+        );
+    }
+
+    hashes.extend(
+        groups
+            .remainder()
+            .iter()
+            .map(|input| hash_one(basis, input, xor_first)),
+    );
+
+    hashes
+}
+
+/// Hashes each of the inputs with a 32-bit hash starting at `basis`.
+fn hash_u32(inputs: &[&[u8]], basis: u32, xor_first: bool) -> Vec<u32> {
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    {
+        if is_x86_feature_detected!("avx2") {
+            // Safety: AVX2 is supported by the running CPU.
+            return unsafe { avx2::hash_u32(inputs, basis, xor_first) };
+        }
+    }
+
+    hash_interleaved(inputs, basis, xor_first)
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod avx2 {
+    #[cfg(target_arch = "x86")]
+    use std::arch::x86::*;
+    #[cfg(target_arch = "x86_64")]
+    use std::arch::x86_64::*;
+
+    use super::hash_one;
+    use FnvParameters;
+
+    /// The number of 32-bit hashes in an AVX2 register.
+    const REGISTER_LANES: usize = 8;
+
+    /// The number of registers hashed together, multiplies have a long
+    /// latency so more than one register is needed to keep the CPU busy.
+    const REGISTERS: usize = 2;
+
+    /// The number of inputs hashed together.
+    const LANES: usize = REGISTER_LANES * REGISTERS;
+
+    /// Reads 4 bytes from each lane as little-endian words.
+    #[target_feature(enable = "avx2")]
+    fn read_words(lanes: &[&[u8]], offset: usize) -> __m256i {
+        let word = |lane: usize| {
+            let mut bytes = [0; 4];
+            bytes.copy_from_slice(&lanes[lane][offset..offset + 4]);
+            i32::from_le_bytes(bytes)
+        };
+
+        _mm256_setr_epi32(
+            word(0),
+            word(1),
+            word(2),
+            word(3),
+            word(4),
+            word(5),
+            word(6),
+            word(7),
+        )
+    }
+
+    /// Hashes each of the inputs with a 32-bit hash, sixteen at a time.
+    ///
+    /// # Safety
+    ///
+    /// The running CPU must support AVX2.
+    #[target_feature(enable = "avx2")]
+    pub(super) unsafe fn hash_u32(inputs: &[&[u8]], basis: u32, xor_first: bool) -> Vec<u32> {
+        let mut hashes = Vec::with_capacity(inputs.len());
+        let prime = _mm256_set1_epi32(u32::PRIME as i32);
+        let low_byte = _mm256_set1_epi32(0xff);
+
+        macro_rules! mix {
+            ($hash: expr, $bytes: expr) => {
+                if xor_first {
+                    _mm256_mullo_epi32(_mm256_xor_si256($hash, $bytes), prime)
+                } else {
+                    _mm256_xor_si256(_mm256_mullo_epi32($hash, prime), $bytes)
+                }
+            };
+        }
+
+        for lanes in inputs.chunks(LANES) {
+            if lanes.len() < LANES {
+                hashes.extend(lanes.iter().map(|input| hash_one(basis, input, xor_first)));
+                continue;
+            }
+
+            let common_len = lanes.iter().map(|input| input.len()).min().unwrap_or(0);
+            let word_len = common_len - common_len % 4;
+            let mut registers = [_mm256_set1_epi32(basis as i32); REGISTERS];
+
+            for offset in (0..word_len).step_by(4) {
+                for (register, hash) in registers.iter_mut().enumerate() {
+                    let words = read_words(&lanes[register * REGISTER_LANES..], offset);
+
+                    *hash = mix!(*hash, _mm256_and_si256(words, low_byte));
+                    *hash = mix!(
+                        *hash,
+                        _mm256_and_si256(_mm256_srli_epi32::<8>(words), low_byte)
+                    );
+                    *hash = mix!(
+                        *hash,
+                        _mm256_and_si256(_mm256_srli_epi32::<16>(words), low_byte)
+                    );
+                    *hash = mix!(*hash, _mm256_srli_epi32::<24>(words));
+                }
+            }
+
+            let mut lane_hashes = [0u32; LANES];
+            for (register, hash) in registers.iter().enumerate() {
+                _mm256_storeu_si256(
+                    lane_hashes[register * REGISTER_LANES..].as_mut_ptr() as *mut __m256i,
+                    *hash,
+                );
+            }
+
+            hashes.extend(
+                lane_hashes
+                    .iter()
+                    .zip(lanes)
+                    .map(|(hash, input)| hash_one(*hash, &input[word_len..], xor_first)),
+            );
+        }
+
+        hashes
+    }
+}
+
+macro_rules! hash_many_impl {
+    ($type: ty, $hash_many: ident) => {
+        impl HashMany for Fnv0<$type> {
+            fn hash_many(inputs: &[&[u8]]) -> Vec<$type> {
+                $hash_many(inputs, <$type>::default(), false)
+            }
+        }
+
+        impl HashMany for Fnv1<$type> {
+            fn hash_many(inputs: &[&[u8]]) -> Vec<$type> {
+                $hash_many(inputs, <$type>::OFFSET_BASIS, false)
+            }
+        }
+
+        impl HashMany for Fnv1a<$type> {
+            fn hash_many(inputs: &[&[u8]]) -> Vec<$type> {
+                $hash_many(inputs, <$type>::OFFSET_BASIS, true)
+            }
+        }
+    };
+}
+
+hash_many_impl!(u32, hash_u32);
+hash_many_impl!(u64, hash_interleaved);
+hash_many_impl!(u128, hash_interleaved);
+hash_many_impl!(U256, hash_interleaved);
+hash_many_impl!(U512, hash_interleaved);
+hash_many_impl!(U1024, hash_interleaved);
+
+#[cfg(test)]
+mod tests {
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    use super::avx2;
+    use super::{hash_interleaved, hash_many, hash_one, HashMany};
+    use FnvParameters;
+    use {Fnv0, Fnv1, Fnv1a, FnvHasher, U1024, U256, U512};
+
+    fn inputs() -> Vec<Vec<u8>> {
+        (0..37)
+            .map(|i| (0..(i * 13) % 50).map(|j| (i * 7 + j * 31) as u8).collect())
+            .collect()
+    }
+
+    /// Inputs of at least 4 bytes and of varied lengths, in two full groups
+    /// of 16 and a remainder, so the word loop of the AVX2 hash is run.
+    fn long_inputs() -> Vec<Vec<u8>> {
+        (0..37)
+            .map(|i| {
+                (0..4 + (i * 7) % 45)
+                    .map(|j| (i * 11 + j * 29) as u8)
+                    .collect()
+            })
+            .collect()
+    }
+
+    macro_rules! hash_many_tests {
+        ($($name: ident: $hasher: ty,)*) => {
+            $(
+                #[test]
+                fn $name() {
+                    for inputs in &[inputs(), long_inputs()] {
+                        let inputs: Vec<&[u8]> = inputs.iter().map(Vec::as_slice).collect();
+
+                        let expected: Vec<_> = inputs
+                            .iter()
+                            .map(|input| {
+                                let mut hasher = <$hasher>::default();
+                                hasher.write(input);
+                                hasher.finish()
+                            })
+                            .collect();
+
+                        assert_eq!(hash_many::<$hasher>(&inputs), expected);
+                    }
+                }
+            )*
+        };
+    }
+
+    hash_many_tests! {
+        fnv0_32_hash_many_matches_hasher: Fnv0<u32>,
+        fnv1_32_hash_many_matches_hasher: Fnv1<u32>,
+        fnv1a_32_hash_many_matches_hasher: Fnv1a<u32>,
+        fnv0_64_hash_many_matches_hasher: Fnv0<u64>,
+        fnv1_64_hash_many_matches_hasher: Fnv1<u64>,
+        fnv1a_64_hash_many_matches_hasher: Fnv1a<u64>,
+        fnv0_128_hash_many_matches_hasher: Fnv0<u128>,
+        fnv1_128_hash_many_matches_hasher: Fnv1<u128>,
+        fnv1a_128_hash_many_matches_hasher: Fnv1a<u128>,
+        fnv0_256_hash_many_matches_hasher: Fnv0<U256>,
+        fnv1_256_hash_many_matches_hasher: Fnv1<U256>,
+        fnv1a_256_hash_many_matches_hasher: Fnv1a<U256>,
+        fnv0_512_hash_many_matches_hasher: Fnv0<U512>,
+        fnv1_512_hash_many_matches_hasher: Fnv1<U512>,
+        fnv1a_512_hash_many_matches_hasher: Fnv1a<U512>,
+        fnv0_1024_hash_many_matches_hasher: Fnv0<U1024>,
+        fnv1_1024_hash_many_matches_hasher: Fnv1<U1024>,
+        fnv1a_1024_hash_many_matches_hasher: Fnv1a<U1024>,
+    }
+
+    #[test]
+    fn portable_hash_u32_matches_hasher() {
+        for inputs in &[inputs(), long_inputs()] {
+            let inputs: Vec<&[u8]> = inputs.iter().map(Vec::as_slice).collect();
+
+            assert_eq!(
+                hash_interleaved(&inputs, u32::OFFSET_BASIS, true),
+                hash_many::<Fnv1a<u32>>(&inputs)
+            );
+            assert_eq!(
+                hash_interleaved(&inputs, u32::OFFSET_BASIS, false),
+                hash_many::<Fnv1<u32>>(&inputs)
+            );
+        }
+    }
+
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    #[test]
+    fn avx2_hash_u32_matches_hasher() {
+        if !is_x86_feature_detected!("avx2") {
+            return;
+        }
+
+        let inputs = long_inputs();
+        let inputs: Vec<&[u8]> = inputs.iter().map(Vec::as_slice).collect();
+
+        for (basis, xor_first) in &[
+            (0, false),
+            (u32::OFFSET_BASIS, false),
+            (u32::OFFSET_BASIS, true),
+        ] {
+            let expected: Vec<u32> = inputs
+                .iter()
+                .map(|input| hash_one(*basis, input, *xor_first))
+                .collect();
+
+            // Safety: AVX2 is supported by the running CPU.
+            assert_eq!(
+                unsafe { avx2::hash_u32(&inputs, *basis, *xor_first) },
+                expected
+            );
+        }
+    }
+
+    #[derive(Default)]
+    struct Sum(u32);
+
+    impl FnvHasher for Sum {
+        type Hash = u32;
+
+        fn finish(&self) -> u32 {
+            self.0
+        }
+
+        fn write(&mut self, bytes: &[u8]) {
+            self.0 += bytes.iter().map(|byte| u32::from(*byte)).sum::<u32>();
+        }
+    }
+
+    impl HashMany for Sum {}
+
+    #[test]
+    fn default_hash_many_hashes_each_input() {
+        assert_eq!(hash_many::<Sum>(&[b"\x01\x02", b"", b"\x03"]), [3, 0, 3]);
+    }
+
+    #[test]
+    fn hash_many_of_no_inputs_is_empty() {
+        assert!(hash_many::<Fnv1a<u32>>(&[]).is_empty());
+    }
+}
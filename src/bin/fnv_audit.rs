@@ -0,0 +1,169 @@
+//! Audits a set of keys for collisions under each FNV hash.
+//!
+//! ```text
+//! fnv_audit [-0] [--fold <bits>,...] [--buckets <count>] [--groups <count>] [file]
+//! ```
+//!
+//! Keys are read from the file, or standard input if none is given, one per
+//! line or separated by nul bytes with `-0`. Duplicate keys are counted once.
+//!
+//! The number of colliding keys is reported for FNV-0, FNV-1 and FNV-1a at 32,
+//! 64 and 128 bits, followed by up to `--groups` groups of colliding keys for
+//! each, 10 by default. `--fold` also reports the hashes xor-folded to each of
+//! the given numbers of bits, and `--buckets` reports the load of a table with
+//! the given number of buckets.
+extern crate lz_fnv;
+
+use lz_fnv::audit::{self, VARIANTS, WIDTHS};
+use std::collections::HashSet;
+use std::env;
+use std::fs;
+use std::io::{self, Read};
+use std::process;
+
+const USAGE: &str =
+    "usage: fnv_audit [-0] [--fold <bits>,...] [--buckets <count>] [--groups <count>] [file]";
+
+const DEFAULT_GROUPS: usize = 10;
+
+struct Options {
+    separator: u8,
+    folds: Vec<u32>,
+    buckets: Option<u128>,
+    groups: usize,
+    path: Option<String>,
+}
+
+fn parse<T: std::str::FromStr>(value: Option<String>, name: &str) -> Result<T, String> {
+    let value = value.ok_or_else(|| USAGE.to_owned())?;
+
+    value
+        .parse()
+        .map_err(|_| format!("invalid {}: {}", name, value))
+}
+
+fn parse_options(mut args: impl Iterator<Item = String>) -> Result<Options, String> {
+    let mut options = Options {
+        separator: b'\n',
+        folds: Vec::new(),
+        buckets: None,
+        groups: DEFAULT_GROUPS,
+        path: None,
+    };
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-0" => options.separator = b'\0',
+            "--fold" => {
+                let folds: String = parse(args.next(), "fold")?;
+
+                for bits in folds.split(',') {
+                    options.folds.push(parse(Some(bits.to_owned()), "fold")?);
+                }
+            }
+            "--buckets" => options.buckets = Some(parse(args.next(), "bucket count")?),
+            "--groups" => options.groups = parse(args.next(), "group count")?,
+            _ if arg.starts_with('-') || options.path.is_some() => return Err(USAGE.to_owned()),
+            _ => options.path = Some(arg),
+        }
+    }
+
+    if options.folds.contains(&0) {
+        return Err("cannot fold hashes to 0 bits".to_owned());
+    }
+
+    if options.buckets == Some(0) {
+        return Err("cannot distribute hashes into 0 buckets".to_owned());
+    }
+
+    Ok(options)
+}
+
+fn read_input(path: Option<&str>) -> Result<Vec<u8>, String> {
+    match path {
+        Some(path) => fs::read(path).map_err(|err| format!("cannot read {}: {}", path, err)),
+        None => {
+            let mut input = Vec::new();
+            io::stdin()
+                .read_to_end(&mut input)
+                .map_err(|err| format!("cannot read standard input: {}", err))?;
+            Ok(input)
+        }
+    }
+}
+
+fn report(name: &str, keys: &[&[u8]], hashes: &[u128], options: &Options) {
+    let groups = audit::collisions(hashes);
+    let colliding_keys: usize = groups.iter().map(Vec::len).sum();
+
+    print!(
+        "{:<20} {} colliding keys in {} groups",
+        name,
+        colliding_keys,
+        groups.len()
+    );
+
+    if let Some(buckets) = options.buckets {
+        let load = audit::bucket_load(hashes, buckets);
+
+        print!(
+            ", {} empty buckets (expected {:.1}), max load {}",
+            load.empty, load.expected_empty, load.max_load
+        );
+    }
+
+    println!();
+
+    for group in groups.iter().take(options.groups) {
+        let keys: Vec<String> = group
+            .iter()
+            .map(|index| format!("\"{}\"", keys[*index].escape_ascii()))
+            .collect();
+
+        println!("    {:#x}: {}", hashes[group[0]], keys.join(", "));
+    }
+}
+
+fn run() -> Result<(), String> {
+    let options = parse_options(env::args().skip(1))?;
+    let input = read_input(options.path.as_deref())?;
+    let all_keys = audit::split_keys(&input, options.separator);
+    let mut unique = HashSet::new();
+    let keys: Vec<&[u8]> = all_keys
+        .iter()
+        .cloned()
+        .filter(|key| unique.insert(*key))
+        .collect();
+
+    println!(
+        "{} keys ({} duplicates ignored)",
+        keys.len(),
+        all_keys.len() - keys.len()
+    );
+
+    for bits in &WIDTHS {
+        for variant in &VARIANTS {
+            let hashes = audit::hashes(*variant, *bits, &keys);
+
+            report(&format!("{} {}", variant, bits), &keys, &hashes, &options);
+
+            for folded_bits in options.folds.iter().filter(|folded| *folded < bits) {
+                report(
+                    &format!("{} {} to {}", variant, bits, folded_bits),
+                    &keys,
+                    &audit::fold(&hashes, *bits, *folded_bits),
+                    &options,
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn main() {
+    if let Err(err) = run() {
+        eprintln!("{}", err);
+        process::exit(1);
+    }
+}
@@ -0,0 +1,115 @@
+//! Finds colliding inputs or preimages of the 32-bit FNV hashes.
+//!
+//! ```text
+//! fnv_collide collisions <fnv1|fnv1a> <alphabet> <length> [count]
+//! fnv_collide preimages <fnv1|fnv1a> <alphabet> <length> <hash> [count]
+//! ```
+//!
+//! The alphabet is given as the bytes it is made up of, such as
+//! `abcdefghijklmnopqrstuvwxyz`, and the hash in hexadecimal, with or without a
+//! `0x` prefix. Up to `count` results are found, 10 by default.
+//!
+//! The results are printed as Rust byte string literals, one per line, so that
+//! they can be pasted into tests. The search is deterministic, the same
+//! arguments always produce the same results.
+extern crate lz_fnv;
+
+use lz_fnv::search::{Algorithm, Search};
+use std::env;
+use std::process;
+
+const USAGE: &str = "usage: fnv_collide collisions <fnv1|fnv1a> <alphabet> <length> [count]
+       fnv_collide preimages <fnv1|fnv1a> <alphabet> <length> <hash> [count]";
+
+const DEFAULT_COUNT: usize = 10;
+
+fn parse_algorithm(algorithm: &str) -> Result<Algorithm, String> {
+    match algorithm {
+        "fnv1" => Ok(Algorithm::Fnv1),
+        "fnv1a" => Ok(Algorithm::Fnv1a),
+        _ => Err(format!("unknown algorithm: {}", algorithm)),
+    }
+}
+
+fn parse_search(algorithm: &str, alphabet: &str, len: &str) -> Result<Search, String> {
+    let algorithm = parse_algorithm(algorithm)?;
+    let len = len
+        .parse()
+        .map_err(|_| format!("invalid length: {}", len))?;
+
+    Search::new(algorithm, alphabet.as_bytes(), len).map_err(|err| err.to_string())
+}
+
+fn parse_count(count: Option<&&str>) -> Result<usize, String> {
+    count.map_or(Ok(DEFAULT_COUNT), |count| {
+        count
+            .parse()
+            .map_err(|_| format!("invalid count: {}", count))
+    })
+}
+
+fn parse_hash(hash: &str) -> Result<u32, String> {
+    u32::from_str_radix(hash.trim_start_matches("0x"), 16)
+        .map_err(|_| format!("invalid hexadecimal hash: {}", hash))
+}
+
+fn byte_string(bytes: &[u8]) -> String {
+    format!("b\"{}\"", bytes.escape_ascii())
+}
+
+fn collisions(args: &[&str]) -> Result<(), String> {
+    let (search, count) = match args {
+        [algorithm, alphabet, len, count @ ..] if count.len() <= 1 => (
+            parse_search(algorithm, alphabet, len)?,
+            parse_count(count.first())?,
+        ),
+        _ => return Err(USAGE.to_owned()),
+    };
+
+    for (first, second) in search.collisions(count) {
+        println!(
+            "({}, {}), // {:#010x}",
+            byte_string(&first),
+            byte_string(&second),
+            search.algorithm().hash(&first)
+        );
+    }
+
+    Ok(())
+}
+
+fn preimages(args: &[&str]) -> Result<(), String> {
+    let (search, hash, count) = match args {
+        [algorithm, alphabet, len, hash, count @ ..] if count.len() <= 1 => (
+            parse_search(algorithm, alphabet, len)?,
+            parse_hash(hash)?,
+            parse_count(count.first())?,
+        ),
+        _ => return Err(USAGE.to_owned()),
+    };
+
+    for preimage in search
+        .preimages(hash, count)
+        .map_err(|err| err.to_string())?
+    {
+        println!("{}, // {:#010x}", byte_string(&preimage), hash);
+    }
+
+    Ok(())
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+
+    let result = match args.split_first() {
+        Some((&"collisions", args)) => collisions(args),
+        Some((&"preimages", args)) => preimages(args),
+        _ => Err(USAGE.to_owned()),
+    };
+
+    if let Err(err) = result {
+        eprintln!("{}", err);
+        process::exit(1);
+    }
+}
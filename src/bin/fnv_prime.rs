@@ -0,0 +1,87 @@
+//! Searches for or validates FNV primes.
+//!
+//! ```text
+//! fnv_prime search <bits>
+//! fnv_prime validate <bits> <prime>
+//! ```
+//!
+//! The prime to validate is given in hexadecimal, with or without a `0x`
+//! prefix.
+extern crate lz_fnv;
+
+use lz_fnv::prime;
+use std::env;
+use std::process;
+
+const USAGE: &str = "usage: fnv_prime search <bits>\n       fnv_prime validate <bits> <prime>";
+
+fn parse_bits(bits: &str) -> Result<u32, String> {
+    bits.parse()
+        .map_err(|_| format!("invalid number of bits: {}", bits))
+}
+
+fn parse_hex(hex: &str) -> Result<Vec<u8>, String> {
+    let digits = hex.trim_start_matches("0x");
+
+    if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(format!("invalid hexadecimal prime: {}", hex));
+    }
+
+    let padded = if digits.len().is_multiple_of(2) {
+        digits.to_owned()
+    } else {
+        format!("0{}", digits)
+    };
+
+    Ok((0..padded.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&padded[i..i + 2], 16).unwrap())
+        .collect())
+}
+
+fn search(bits: &str) -> Result<(), String> {
+    let bits = parse_bits(bits)?;
+    let fnv_prime = prime::find(bits).map_err(|err| err.to_string())?;
+
+    println!(
+        "{} (256^{} + 2^8 + 0x{:02x})",
+        fnv_prime,
+        fnv_prime.t(),
+        fnv_prime.b()
+    );
+
+    Ok(())
+}
+
+fn validate(bits: &str, candidate: &str) -> Result<(), String> {
+    let bits = parse_bits(bits)?;
+    let candidate = parse_hex(candidate)?;
+    let fnv_prime = prime::validate(bits, &candidate).map_err(|err| err.to_string())?;
+
+    if prime::find(bits) == Ok(fnv_prime) {
+        println!("{} is the FNV prime for {} bits", fnv_prime, bits);
+    } else {
+        println!(
+            "{} meets the FNV prime criteria for {} bits but is not the smallest",
+            fnv_prime, bits
+        );
+    }
+
+    Ok(())
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+
+    let result = match args.as_slice() {
+        ["search", bits] => search(bits),
+        ["validate", bits, candidate] => validate(bits, candidate),
+        _ => Err(USAGE.to_owned()),
+    };
+
+    if let Err(err) = result {
+        eprintln!("{}", err);
+        process::exit(1);
+    }
+}
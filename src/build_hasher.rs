@@ -0,0 +1,110 @@
+//! `BuildHasher` implementations for the FNV hashers.
+use core::hash::{BuildHasher, BuildHasherDefault, Hasher};
+use {Fnv0, Fnv1, Fnv1a, FnvParameters};
+
+/// A `BuildHasher` which creates `Fnv0<T>` hashers.
+pub type Fnv0BuildHasher<T = u64> = BuildHasherDefault<Fnv0<T>>;
+
+/// A `BuildHasher` which creates `Fnv1<T>` hashers.
+pub type Fnv1BuildHasher<T = u64> = BuildHasherDefault<Fnv1<T>>;
+
+/// A `BuildHasher` which creates `Fnv1a<T>` hashers.
+///
+/// ```
+/// use std::hash::BuildHasher;
+/// use lz_fnv::Fnv1aBuildHasher;
+///
+/// let build_hasher = Fnv1aBuildHasher::<u64>::default();
+/// let hash = build_hasher.hash_one("foobar");
+/// ```
+pub type Fnv1aBuildHasher<T = u64> = BuildHasherDefault<Fnv1a<T>>;
+
+macro_rules! keyed_build_hasher_impl {
+    ($(#[$attr: meta])* $name: ident, $hasher: ident) => {
+        $(#[$attr])*
+        #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+        pub struct $name<T = u64> {
+            key: T,
+        }
+
+        impl<T> $name<T> {
+            /// Creates a new build hasher which uses the specified key for
+            /// every hasher created.
+            pub fn new(key: T) -> Self {
+                Self { key }
+            }
+
+            /// Gets the key used for every hasher created.
+            pub fn key(&self) -> &T {
+                &self.key
+            }
+        }
+
+        impl<T: FnvParameters> BuildHasher for $name<T>
+        where
+            $hasher<T>: Hasher,
+        {
+            type Hasher = $hasher<T>;
+
+            fn build_hasher(&self) -> Self::Hasher {
+                $hasher::with_key(self.key)
+            }
+        }
+    };
+}
+
+keyed_build_hasher_impl!(
+    /// A `BuildHasher` which creates `Fnv0<T>` hashers with a specified key.
+    ///
+    /// ```
+    /// use lz_fnv::Fnv0KeyedBuildHasher;
+    ///
+    /// let build_hasher = Fnv0KeyedBuildHasher::new(872u64);
+    /// ```
+    Fnv0KeyedBuildHasher,
+    Fnv0
+);
+keyed_build_hasher_impl!(
+    /// A `BuildHasher` which creates `Fnv1<T>` hashers with a specified key.
+    ///
+    /// ```
+    /// use lz_fnv::Fnv1KeyedBuildHasher;
+    ///
+    /// let build_hasher = Fnv1KeyedBuildHasher::new(872u64);
+    /// ```
+    Fnv1KeyedBuildHasher,
+    Fnv1
+);
+keyed_build_hasher_impl!(
+    /// A `BuildHasher` which creates `Fnv1a<T>` hashers with a specified key.
+    ///
+    /// ```
+    /// use lz_fnv::Fnv1aKeyedBuildHasher;
+    ///
+    /// let build_hasher = Fnv1aKeyedBuildHasher::new(872u64);
+    /// ```
+    Fnv1aKeyedBuildHasher,
+    Fnv1a
+);
+
+#[cfg(test)]
+mod tests {
+    use std::hash::BuildHasher;
+    use {Fnv1aBuildHasher, Fnv1aKeyedBuildHasher, FnvParameters};
+
+    #[test]
+    fn keyed_build_hasher_with_offset_basis_matches_default() {
+        let keyed = Fnv1aKeyedBuildHasher::new(u32::OFFSET_BASIS);
+        let default = Fnv1aBuildHasher::<u32>::default();
+
+        assert_eq!(keyed.hash_one("foobar"), default.hash_one("foobar"));
+    }
+
+    #[test]
+    fn keyed_build_hasher_uses_key() {
+        let keyed = Fnv1aKeyedBuildHasher::new(872u32);
+        let default = Fnv1aBuildHasher::<u32>::default();
+
+        assert_ne!(keyed.hash_one("foobar"), default.hash_one("foobar"));
+    }
+}
@@ -0,0 +1,265 @@
+//! Generation of `StaticMap` source code, intended for use from build scripts.
+//!
+//! ```
+//! use lz_fnv::codegen::MapGenerator;
+//!
+//! let mut generator = MapGenerator::new();
+//! generator.entry("start", "1").entry("stop", "2");
+//!
+//! let source = format!(
+//!     "static COMMANDS: ::lz_fnv::StaticMap<u32> = {};",
+//!     generator.generate().unwrap()
+//! );
+//! ```
+use static_map::{displace, key_hashes, KeyHashes};
+use std::collections::HashSet;
+use std::error::Error;
+use std::fmt;
+use {Fnv1a, FnvHasher};
+
+/// The average number of keys in each bucket of displacements.
+const KEYS_PER_BUCKET: usize = 5;
+
+/// The displacements of each bucket and the index of each entry.
+type Placement = (Vec<(u32, u32)>, Vec<usize>);
+
+/// The number of seeds to try before giving up.
+const MAX_SEED_ATTEMPTS: u32 = 1000;
+
+/// An error which occurred when generating a `StaticMap`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum GenerateError {
+    /// The same key was added more than once.
+    DuplicateKey(String),
+    /// No seed was found which produces a perfect hash function for the keys.
+    NoSeedFound,
+}
+
+impl fmt::Display for GenerateError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GenerateError::DuplicateKey(key) => write!(f, "duplicate key {:?}", key),
+            GenerateError::NoSeedFound => f.write_str("no perfect hash function was found"),
+        }
+    }
+}
+
+impl Error for GenerateError {}
+
+/// Generates the source code of a `StaticMap`.
+#[derive(Clone, Debug, Default)]
+pub struct MapGenerator {
+    entries: Vec<(String, String)>,
+}
+
+/// A generated `StaticMap`, the `Display` implementation produces the Rust
+/// expression which creates the map.
+#[derive(Clone, Debug)]
+pub struct GeneratedMap {
+    seed: u128,
+    displacements: Vec<(u32, u32)>,
+    entries: Vec<(String, String)>,
+}
+
+impl MapGenerator {
+    /// Creates a new empty `MapGenerator`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an entry to the map, the value is the Rust source of an
+    /// expression which can be evaluated in a `static`.
+    pub fn entry(&mut self, key: &str, value: &str) -> &mut Self {
+        self.entries.push((key.to_owned(), value.to_owned()));
+        self
+    }
+
+    /// Searches for a seed which produces a minimal perfect hash function for
+    /// the keys.
+    pub fn generate(&self) -> Result<GeneratedMap, GenerateError> {
+        let mut keys = HashSet::new();
+
+        for (key, _) in &self.entries {
+            if !keys.insert(key.as_str()) {
+                return Err(GenerateError::DuplicateKey(key.clone()));
+            }
+        }
+
+        for attempt in 0..MAX_SEED_ATTEMPTS {
+            let mut seed_hasher = Fnv1a::<u128>::new();
+            seed_hasher.write(&attempt.to_le_bytes());
+            let seed = seed_hasher.finish();
+
+            if let Some((displacements, indices)) = self.try_generate(seed) {
+                let mut entries = vec![(String::new(), String::new()); self.entries.len()];
+
+                for (entry, index) in self.entries.iter().zip(indices) {
+                    entries[index] = entry.clone();
+                }
+
+                return Ok(GeneratedMap {
+                    seed,
+                    displacements,
+                    entries,
+                });
+            }
+        }
+
+        Err(GenerateError::NoSeedFound)
+    }
+
+    /// Attempts to find displacements for each bucket which place every key
+    /// at a distinct index, returning the displacements and the index of each
+    /// entry.
+    fn try_generate(&self, seed: u128) -> Option<Placement> {
+        let len = self.entries.len();
+        let hashes: Vec<KeyHashes> = self
+            .entries
+            .iter()
+            .map(|(key, _)| key_hashes(seed, key.as_bytes()))
+            .collect();
+
+        let bucket_count = len.div_ceil(KEYS_PER_BUCKET);
+        let mut buckets = vec![Vec::new(); bucket_count];
+
+        for (entry, hashes) in hashes.iter().enumerate() {
+            buckets[hashes.bucket as usize % bucket_count].push(entry);
+        }
+
+        let mut bucket_order: Vec<usize> = (0..bucket_count).collect();
+        bucket_order.sort_by_key(|bucket| usize::MAX - buckets[*bucket].len());
+
+        let mut displacements = vec![(0, 0); bucket_count];
+        let mut indices = vec![0; len];
+        let mut occupied = vec![false; len];
+        let mut placed = Vec::new();
+
+        for bucket in bucket_order {
+            let entries = &buckets[bucket];
+
+            let found = (0..len as u32)
+                .flat_map(|d1| (0..len as u32).map(move |d2| (d1, d2)))
+                .find(|&(d1, d2)| {
+                    placed.clear();
+
+                    for &entry in entries {
+                        let index = displace(&hashes[entry], d1, d2, len);
+
+                        if occupied[index] || placed.contains(&index) {
+                            return false;
+                        }
+
+                        placed.push(index);
+                    }
+
+                    true
+                });
+
+            let (d1, d2) = found?;
+
+            displacements[bucket] = (d1, d2);
+
+            for (&entry, &index) in entries.iter().zip(placed.iter()) {
+                occupied[index] = true;
+                indices[entry] = index;
+            }
+        }
+
+        Some((displacements, indices))
+    }
+}
+
+impl fmt::Display for GeneratedMap {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "::lz_fnv::StaticMap::new(")?;
+        writeln!(f, "    {:#x},", self.seed)?;
+        write!(f, "    &[")?;
+
+        for (i, (d1, d2)) in self.displacements.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+
+            write!(f, "({}, {})", d1, d2)?;
+        }
+
+        writeln!(f, "],")?;
+        writeln!(f, "    &[")?;
+
+        for (key, value) in &self.entries {
+            writeln!(f, "        ({:?}, {}),", key, value)?;
+        }
+
+        writeln!(f, "    ],")?;
+        write!(f, ")")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{GenerateError, GeneratedMap, MapGenerator};
+    use StaticMap;
+
+    fn to_static_map(generated: GeneratedMap) -> StaticMap<String> {
+        let entries: Vec<(&'static str, String)> = generated
+            .entries
+            .into_iter()
+            .map(|(key, value)| (&*Box::leak(key.into_boxed_str()), value))
+            .collect();
+
+        StaticMap::new(
+            generated.seed,
+            Box::leak(generated.displacements.into_boxed_slice()),
+            Box::leak(entries.into_boxed_slice()),
+        )
+    }
+
+    #[test]
+    fn generated_map_contains_every_key() {
+        let mut generator = MapGenerator::new();
+
+        for i in 0..500 {
+            generator.entry(&format!("key{}", i), &i.to_string());
+        }
+
+        let map = to_static_map(generator.generate().unwrap());
+
+        assert_eq!(map.len(), 500);
+
+        for i in 0..500 {
+            assert_eq!(map.get(&format!("key{}", i)), Some(&i.to_string()));
+        }
+
+        assert_eq!(map.get("key500"), None);
+    }
+
+    #[test]
+    fn empty_map_contains_nothing() {
+        let map = to_static_map(MapGenerator::new().generate().unwrap());
+
+        assert!(map.is_empty());
+        assert_eq!(map.get(""), None);
+    }
+
+    #[test]
+    fn duplicate_keys_are_rejected() {
+        let mut generator = MapGenerator::new();
+        generator.entry("start", "1").entry("start", "2");
+
+        assert_eq!(
+            generator.generate().unwrap_err(),
+            GenerateError::DuplicateKey("start".to_owned())
+        );
+    }
+
+    #[test]
+    fn generated_source_creates_static_map() {
+        let mut generator = MapGenerator::new();
+        generator.entry("start", "1");
+
+        let source = generator.generate().unwrap().to_string();
+
+        assert!(source.starts_with("::lz_fnv::StaticMap::new(\n"));
+        assert!(source.contains("(\"start\", 1),"));
+    }
+}
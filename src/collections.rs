@@ -0,0 +1,78 @@
+//! Collection type aliases which use the FNV hashers.
+use std::collections::{HashMap, HashSet};
+use std::hash::{BuildHasher, Hash};
+use {Fnv0BuildHasher, Fnv1BuildHasher, Fnv1aBuildHasher};
+
+/// A `HashMap` using the FNV-1a hash.
+///
+/// ```
+/// use lz_fnv::FnvHashMap;
+///
+/// let mut map = FnvHashMap::default();
+/// map.insert("foo", 1);
+/// ```
+pub type FnvHashMap<K, V> = Fnv1aHashMap<K, V>;
+
+/// A `HashSet` using the FNV-1a hash.
+///
+/// ```
+/// use lz_fnv::FnvHashSet;
+///
+/// let mut set = FnvHashSet::default();
+/// set.insert("foo");
+/// ```
+pub type FnvHashSet<T> = Fnv1aHashSet<T>;
+
+/// A `HashMap` using the FNV-0 hash.
+pub type Fnv0HashMap<K, V, T = u64> = HashMap<K, V, Fnv0BuildHasher<T>>;
+
+/// A `HashSet` using the FNV-0 hash.
+pub type Fnv0HashSet<K, T = u64> = HashSet<K, Fnv0BuildHasher<T>>;
+
+/// A `HashMap` using the FNV-1 hash.
+pub type Fnv1HashMap<K, V, T = u64> = HashMap<K, V, Fnv1BuildHasher<T>>;
+
+/// A `HashSet` using the FNV-1 hash.
+pub type Fnv1HashSet<K, T = u64> = HashSet<K, Fnv1BuildHasher<T>>;
+
+/// A `HashMap` using the FNV-1a hash.
+pub type Fnv1aHashMap<K, V, T = u64> = HashMap<K, V, Fnv1aBuildHasher<T>>;
+
+/// A `HashSet` using the FNV-1a hash.
+pub type Fnv1aHashSet<K, T = u64> = HashSet<K, Fnv1aBuildHasher<T>>;
+
+/// Creates a `HashMap` with the specified capacity, using the default value
+/// of the `BuildHasher`.
+///
+/// ```
+/// use lz_fnv::{hash_map_with_capacity, FnvHashMap};
+///
+/// let map: FnvHashMap<&str, u32> = hash_map_with_capacity(16);
+///
+/// assert!(map.capacity() >= 16);
+/// ```
+pub fn hash_map_with_capacity<K, V, S>(capacity: usize) -> HashMap<K, V, S>
+where
+    K: Eq + Hash,
+    S: BuildHasher + Default,
+{
+    HashMap::with_capacity_and_hasher(capacity, S::default())
+}
+
+/// Creates a `HashSet` with the specified capacity, using the default value
+/// of the `BuildHasher`.
+///
+/// ```
+/// use lz_fnv::{hash_set_with_capacity, FnvHashSet};
+///
+/// let set: FnvHashSet<&str> = hash_set_with_capacity(16);
+///
+/// assert!(set.capacity() >= 16);
+/// ```
+pub fn hash_set_with_capacity<T, S>(capacity: usize) -> HashSet<T, S>
+where
+    T: Eq + Hash,
+    S: BuildHasher + Default,
+{
+    HashSet::with_capacity_and_hasher(capacity, S::default())
+}
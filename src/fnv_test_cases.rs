@@ -406,6 +406,818 @@ fnv0_tests!{
   fnv0_64_test_200: u64, &repeat(b"\x07", 500), 0xcd73806290557064,
   fnv0_64_test_201: u64, &repeat(b"~", 500), 0x2613a37bbe0317c8,
   fnv0_64_test_202: u64, &repeat(b"\x7f", 500), 0x1480e21fcf2ae5e4,
+  fnv0_128_test_0: u128, b"", 0x00000000000000000000000000000000,
+  fnv0_128_test_1: u128, b"a", 0x00000000000000000000000000000061,
+  fnv0_128_test_2: u128, b"b", 0x00000000000000000000000000000062,
+  fnv0_128_test_3: u128, b"c", 0x00000000000000000000000000000063,
+  fnv0_128_test_4: u128, b"d", 0x00000000000000000000000000000064,
+  fnv0_128_test_5: u128, b"e", 0x00000000000000000000000000000065,
+  fnv0_128_test_6: u128, b"f", 0x00000000000000000000000000000066,
+  fnv0_128_test_7: u128, b"fo", 0x00000000660000000000000000007ded,
+  fnv0_128_test_8: u128, b"foo", 0x000000fb6f00000000000000009af2f0,
+  fnv0_128_test_9: u128, b"foob", 0x0001d0548500000000000000bea8ed32,
+  fnv0_128_test_10: u128, b"fooba", 0x02fa00ecd9000000000000ea99dbdce7,
+  fnv0_128_test_11: u128, b"foobar", 0x9438ff4bea000000000120ab5188d04f,
+  fnv0_128_test_12: u128, ::std::ffi::CString::new("").unwrap().as_bytes_with_nul(), 0x00000000000000000000000000000000,
+  fnv0_128_test_13: u128, ::std::ffi::CString::new("a").unwrap().as_bytes_with_nul(), 0x0000000061000000000000000000775b,
+  fnv0_128_test_14: u128, ::std::ffi::CString::new("b").unwrap().as_bytes_with_nul(), 0x00000000620000000000000000007896,
+  fnv0_128_test_15: u128, ::std::ffi::CString::new("c").unwrap().as_bytes_with_nul(), 0x000000006300000000000000000079d1,
+  fnv0_128_test_16: u128, ::std::ffi::CString::new("d").unwrap().as_bytes_with_nul(), 0x00000000640000000000000000007b0c,
+  fnv0_128_test_17: u128, ::std::ffi::CString::new("e").unwrap().as_bytes_with_nul(), 0x00000000650000000000000000007c47,
+  fnv0_128_test_18: u128, ::std::ffi::CString::new("f").unwrap().as_bytes_with_nul(), 0x00000000660000000000000000007d82,
+  fnv0_128_test_19: u128, ::std::ffi::CString::new("fo").unwrap().as_bytes_with_nul(), 0x000000fb6f00000000000000009af29f,
+  fnv0_128_test_20: u128, ::std::ffi::CString::new("foo").unwrap().as_bytes_with_nul(), 0x0001d0548500000000000000bea8ed50,
+  fnv0_128_test_21: u128, ::std::ffi::CString::new("foob").unwrap().as_bytes_with_nul(), 0x02fa00ecd9000000000000ea99dbdc86,
+  fnv0_128_test_22: u128, ::std::ffi::CString::new("fooba").unwrap().as_bytes_with_nul(), 0x9438ff4bea000000000120ab5188d03d,
+  fnv0_128_test_23: u128, ::std::ffi::CString::new("foobar").unwrap().as_bytes_with_nul(), 0x0d73ab393d000000016332cd53585135,
+  fnv0_128_test_24: u128, b"ch", 0x000000006300000000000000000079b9,
+  fnv0_128_test_25: u128, b"cho", 0x000000f38a000000000000000095c6cc,
+  fnv0_128_test_26: u128, b"chon", 0x0001c1719a00000000000000b84b9d6a,
+  fnv0_128_test_27: u128, b"chong", 0x02e15265e8000000000000e2c50ab109,
+  fnv0_128_test_28: u128, b"chongo", 0x6e056e1581000000000117087427d67c,
+  fnv0_128_test_29: u128, b"chongo ", 0x69229c4c3700000001575766ed04eab4,
+  fnv0_128_test_30: u128, b"chongo w", 0xc48356b261000001a6788ba5a50ccb0b,
+  fnv0_128_test_31: u128, b"chongo wa", 0x7340ba4866000207d653d4d216bdd6e8,
+  fnv0_128_test_32: u128, b"chongo was", 0xa2bbf4ec6a027fa4b926de81fb976f0b,
+  fnv0_128_test_33: u128, b"chongo was ", 0xbf41f6557c130fafd2d3c9f09355a2a9,
+  fnv0_128_test_34: u128, b"chongo was h", 0x46bd70d254744d586a997b054a5f259b,
+  fnv0_128_test_35: u128, b"chongo was he", 0x106431f3861b2bcb2ada5f82831345dc,
+  fnv0_128_test_36: u128, b"chongo was her", 0xadcc89ebdf6ee2fdbab3859748b6f5c6,
+  fnv0_128_test_37: u128, b"chongo was here", 0x71f66c31b3714e34bae5612679206ac7,
+  fnv0_128_test_38: u128, b"chongo was here!", 0x60b04192936b3ae1f83e8a570ae362fc,
+  fnv0_128_test_39: u128, b"chongo was here!\n", 0x4feb92be60f1740c74f4391a65cacc1e,
+  fnv0_128_test_40: u128, ::std::ffi::CString::new("ch").unwrap().as_bytes_with_nul(), 0x000000f38a000000000000000095c6a3,
+  fnv0_128_test_41: u128, ::std::ffi::CString::new("cho").unwrap().as_bytes_with_nul(), 0x0001c1719a00000000000000b84b9d04,
+  fnv0_128_test_42: u128, ::std::ffi::CString::new("chon").unwrap().as_bytes_with_nul(), 0x02e15265e8000000000000e2c50ab16e,
+  fnv0_128_test_43: u128, ::std::ffi::CString::new("chong").unwrap().as_bytes_with_nul(), 0x6e056e1581000000000117087427d613,
+  fnv0_128_test_44: u128, ::std::ffi::CString::new("chongo").unwrap().as_bytes_with_nul(), 0x69229c4c3700000001575766ed04ea94,
+  fnv0_128_test_45: u128, ::std::ffi::CString::new("chongo ").unwrap().as_bytes_with_nul(), 0xc48356b261000001a6788ba5a50ccb7c,
+  fnv0_128_test_46: u128, ::std::ffi::CString::new("chongo w").unwrap().as_bytes_with_nul(), 0x7340ba4866000207d653d4d216bdd689,
+  fnv0_128_test_47: u128, ::std::ffi::CString::new("chongo wa").unwrap().as_bytes_with_nul(), 0xa2bbf4ec6a027fa4b926de81fb976f78,
+  fnv0_128_test_48: u128, ::std::ffi::CString::new("chongo was").unwrap().as_bytes_with_nul(), 0xbf41f6557c130fafd2d3c9f09355a289,
+  fnv0_128_test_49: u128, ::std::ffi::CString::new("chongo was ").unwrap().as_bytes_with_nul(), 0x46bd70d254744d586a997b054a5f25f3,
+  fnv0_128_test_50: u128, ::std::ffi::CString::new("chongo was h").unwrap().as_bytes_with_nul(), 0x106431f3861b2bcb2ada5f82831345b9,
+  fnv0_128_test_51: u128, ::std::ffi::CString::new("chongo was he").unwrap().as_bytes_with_nul(), 0xadcc89ebdf6ee2fdbab3859748b6f5b4,
+  fnv0_128_test_52: u128, ::std::ffi::CString::new("chongo was her").unwrap().as_bytes_with_nul(), 0x71f66c31b3714e34bae5612679206aa2,
+  fnv0_128_test_53: u128, ::std::ffi::CString::new("chongo was here").unwrap().as_bytes_with_nul(), 0x60b04192936b3ae1f83e8a570ae362dd,
+  fnv0_128_test_54: u128, ::std::ffi::CString::new("chongo was here!").unwrap().as_bytes_with_nul(), 0x4feb92be60f1740c74f4391a65cacc14,
+  fnv0_128_test_55: u128, ::std::ffi::CString::new("chongo was here!\n").unwrap().as_bytes_with_nul(), 0x71435b0d6719cb53e882437b408928ea,
+  fnv0_128_test_56: u128, b"cu", 0x000000006300000000000000000079a4,
+  fnv0_128_test_57: u128, b"cur", 0x000000f375000000000000000095acbe,
+  fnv0_128_test_58: u128, b"curd", 0x0001c13db500000000000000b82b8dae,
+  fnv0_128_test_59: u128, b"curds", 0x02e0f27b65000000000000e29d975569,
+  fnv0_128_test_60: u128, b"curds ", 0x6d67f52ab0000000000116d7e9361813,
+  fnv0_128_test_61: u128, b"curds a", 0x76d3e19ea300000001571babf58f9f00,
+  fnv0_128_test_62: u128, b"curds an", 0xe2ac2dd191000001a62f0c9727b8a56e,
+  fnv0_128_test_63: u128, b"curds and", 0x81041982d90002077be47dfde0338e3e,
+  fnv0_128_test_64: u128, b"curds and ", 0xbdeb978f41027f3572270762df70066a,
+  fnv0_128_test_65: u128, b"curds and w", 0x13c2ed4b681286c3760616a8eed7e419,
+  fnv0_128_test_66: u128, b"curds and wh", 0xf9c8d3ad27cbd282397de1dde3a5aaab,
+  fnv0_128_test_67: u128, b"curds and whe", 0x38001bbaa2cc063cbde4ec071cd9000c,
+  fnv0_128_test_68: u128, b"curds and whey", 0xef3ef7a65d0bacbda8ae6cc07f030ebd,
+  fnv0_128_test_69: u128, b"curds and whey\n", 0x22f9bcc33a5d8d5e8e9fd0dc48c32285,
+  fnv0_128_test_70: u128, ::std::ffi::CString::new("cu").unwrap().as_bytes_with_nul(), 0x000000f375000000000000000095accc,
+  fnv0_128_test_71: u128, ::std::ffi::CString::new("cur").unwrap().as_bytes_with_nul(), 0x0001c13db500000000000000b82b8dca,
+  fnv0_128_test_72: u128, ::std::ffi::CString::new("curd").unwrap().as_bytes_with_nul(), 0x02e0f27b65000000000000e29d97551a,
+  fnv0_128_test_73: u128, ::std::ffi::CString::new("curds").unwrap().as_bytes_with_nul(), 0x6d67f52ab0000000000116d7e9361833,
+  fnv0_128_test_74: u128, ::std::ffi::CString::new("curds ").unwrap().as_bytes_with_nul(), 0x76d3e19ea300000001571babf58f9f61,
+  fnv0_128_test_75: u128, ::std::ffi::CString::new("curds a").unwrap().as_bytes_with_nul(), 0xe2ac2dd191000001a62f0c9727b8a500,
+  fnv0_128_test_76: u128, ::std::ffi::CString::new("curds an").unwrap().as_bytes_with_nul(), 0x81041982d90002077be47dfde0338e5a,
+  fnv0_128_test_77: u128, ::std::ffi::CString::new("curds and").unwrap().as_bytes_with_nul(), 0xbdeb978f41027f3572270762df70064a,
+  fnv0_128_test_78: u128, ::std::ffi::CString::new("curds and ").unwrap().as_bytes_with_nul(), 0x13c2ed4b681286c3760616a8eed7e46e,
+  fnv0_128_test_79: u128, ::std::ffi::CString::new("curds and w").unwrap().as_bytes_with_nul(), 0xf9c8d3ad27cbd282397de1dde3a5aac3,
+  fnv0_128_test_80: u128, ::std::ffi::CString::new("curds and wh").unwrap().as_bytes_with_nul(), 0x38001bbaa2cc063cbde4ec071cd90069,
+  fnv0_128_test_81: u128, ::std::ffi::CString::new("curds and whe").unwrap().as_bytes_with_nul(), 0xef3ef7a65d0bacbda8ae6cc07f030ec4,
+  fnv0_128_test_82: u128, ::std::ffi::CString::new("curds and whey").unwrap().as_bytes_with_nul(), 0x22f9bcc33a5d8d5e8e9fd0dc48c3228f,
+  fnv0_128_test_83: u128, ::std::ffi::CString::new("curds and whey\n").unwrap().as_bytes_with_nul(), 0xe594075b561cf3597ea5ff0d881b79a7,
+  fnv0_128_test_84: u128, b"hi", 0x00000000680000000000000000007f91,
+  fnv0_128_test_85: u128, ::std::ffi::CString::new("hi").unwrap().as_bytes_with_nul(), 0x000000ff8900000000000000009cf76b,
+  fnv0_128_test_86: u128, b"hello", 0x030564679b000000000000edbe4f9fc0,
+  fnv0_128_test_87: u128, ::std::ffi::CString::new("hello").unwrap().as_bytes_with_nul(), 0xa560db1b79000000000124892bf99140,
+  fnv0_128_test_88: u128, b"\xff\x00\x00\x01", 0x000486403500000000000001db1055bc,
+  fnv0_128_test_89: u128, b"\x01\x00\x00\xff", 0x0000048acb0000000000000001dcedbc,
+  fnv0_128_test_90: u128, b"\xff\x00\x00\x02", 0x000486403500000000000001db1055bf,
+  fnv0_128_test_91: u128, b"\x02\x00\x00\xff", 0x00000915960000000000000003b9da79,
+  fnv0_128_test_92: u128, b"\xff\x00\x00\x03", 0x000486403500000000000001db1055be,
+  fnv0_128_test_93: u128, b"\x03\x00\x00\xff", 0x00000da061000000000000000596c736,
+  fnv0_128_test_94: u128, b"\xff\x00\x00\x04", 0x000486403500000000000001db1055b9,
+  fnv0_128_test_95: u128, b"\x04\x00\x00\xff", 0x0000122b2c000000000000000773b5f3,
+  fnv0_128_test_96: u128, b"\x40\x51\x4e\x44", 0x0001223ed00000000000000076f3d1c3,
+  fnv0_128_test_97: u128, b"\x44\x4e\x51\x40", 0x0001356301000000000000007f01041d,
+  fnv0_128_test_98: u128, b"\x40\x51\x4e\x4a", 0x0001223ed00000000000000076f3d1cd,
+  fnv0_128_test_99: u128, b"\x4a\x4e\x51\x40", 0x000150998b000000000000008a280f2b,
+  fnv0_128_test_100: u128, b"\x40\x51\x4e\x54", 0x0001223ed00000000000000076f3d1d3,
+  fnv0_128_test_101: u128, b"\x54\x4e\x51\x40", 0x00017cd4d1000000000000009c0e332d,
+  fnv0_128_test_102: u128, b"127.0.0.1", 0x0a1478009e00010241c05b35aa25ab47,
+  fnv0_128_test_103: u128, ::std::ffi::CString::new("127.0.0.1").unwrap().as_bytes_with_nul(), 0x9cd9ce6db1013dc6e7b03b085c59c05d,
+  fnv0_128_test_104: u128, b"127.0.0.2", 0x0a1478009e00010241c05b35aa25ab44,
+  fnv0_128_test_105: u128, ::std::ffi::CString::new("127.0.0.2").unwrap().as_bytes_with_nul(), 0x9cd9ce6dae013dc6e7b03b085c59bcac,
+  fnv0_128_test_106: u128, b"127.0.0.3", 0x0a1478009e00010241c05b35aa25ab45,
+  fnv0_128_test_107: u128, ::std::ffi::CString::new("127.0.0.3").unwrap().as_bytes_with_nul(), 0x9cd9ce6daf013dc6e7b03b085c59bde7,
+  fnv0_128_test_108: u128, b"64.81.78.68", 0x92c19fe21fac859b23dba0bf07d86928,
+  fnv0_128_test_109: u128, ::std::ffi::CString::new("64.81.78.68").unwrap().as_bytes_with_nul(), 0x534793a6214865e51f3ecb0ea7496438,
+  fnv0_128_test_110: u128, b"64.81.78.74", 0x92c19fe220ac859b23dba0bf07d86a7f,
+  fnv0_128_test_111: u128, ::std::ffi::CString::new("64.81.78.74").unwrap().as_bytes_with_nul(), 0x534793a8b34865e51f3ecb0ea74b0a45,
+  fnv0_128_test_112: u128, b"64.81.78.84", 0x92c19fe22dac859b23dba0bf07d87a7e,
+  fnv0_128_test_113: u128, ::std::ffi::CString::new("64.81.78.84").unwrap().as_bytes_with_nul(), 0x534793c8b14865e51f3ecb0ea75eb90a,
+  fnv0_128_test_114: u128, b"feedface", 0x646f633ccc000001b4fa174597e9803f,
+  fnv0_128_test_115: u128, ::std::ffi::CString::new("feedface").unwrap().as_bytes_with_nul(), 0xdaa7054f43000219afbaa2a1ec50cd85,
+  fnv0_128_test_116: u128, b"feedfacedaffdeed", 0x5239b869e1204c20200015ab576eb094,
+  fnv0_128_test_117: u128, ::std::ffi::CString::new("feedfacedaffdeed").unwrap().as_bytes_with_nul(), 0xd85d58f896bdab87601aa9d49533461c,
+  fnv0_128_test_118: u128, b"feedfacedeadbeef", 0x549d0854a8204c20200e0b71223ae2cf,
+  fnv0_128_test_119: u128, ::std::ffi::CString::new("feedfacedeadbeef").unwrap().as_bytes_with_nul(), 0x8e5b7b0daebdab87714814351e7514b5,
+  fnv0_128_test_120: u128, b"line 1\nline 2\nline 3", 0xc1ec0f6c52abbe03d0bedccfde9ce9ae,
+  fnv0_128_test_121: u128, b"chongo <Landon Curt Noll> /\\../\\", 0x6c62272e07bb014262b821756295c58d,
+  fnv0_128_test_122: u128, ::std::ffi::CString::new("chongo <Landon Curt Noll> /\\../\\").unwrap().as_bytes_with_nul(), 0xd228cb69101a8caf78912b704e4a147f,
+  fnv0_128_test_123: u128, b"chongo (Landon Curt Noll) /\\../\\", 0xb5900d0bdb30cd8c98787baaba913608,
+  fnv0_128_test_124: u128, ::std::ffi::CString::new("chongo (Landon Curt Noll) /\\../\\").unwrap().as_bytes_with_nul(), 0x12fa9eccbd0cebff9c402b1390ad7bd8,
+  fnv0_128_test_125: u128, b"http://antwrp.gsfc.nasa.gov/apod/astropix.html", 0x97eeae58ad222b25abdd75b75cbf920d,
+  fnv0_128_test_126: u128, b"http://en.wikipedia.org/wiki/Fowler_Noll_Vo_hash", 0xa1ba7d1cf10f5c92a93a5962f6771e88,
+  fnv0_128_test_127: u128, b"http://epod.usra.edu/", 0x78008ee2d701383c84e771d6af3ad210,
+  fnv0_128_test_128: u128, b"http://exoplanet.eu/", 0xab3c6232d92046c58dec28975252f51f,
+  fnv0_128_test_129: u128, b"http://hvo.wr.usgs.gov/cam3/", 0x4e6d9727dbf6c751df35206ea6fd484a,
+  fnv0_128_test_130: u128, b"http://hvo.wr.usgs.gov/cams/HMcam/", 0xefa33ef8f147ad1af05e4a381f0efaeb,
+  fnv0_128_test_131: u128, b"http://hvo.wr.usgs.gov/kilauea/update/deformation.html", 0x0e5ebbadd6824d491ebf8941b64206e5,
+  fnv0_128_test_132: u128, b"http://hvo.wr.usgs.gov/kilauea/update/images.html", 0xa3ab815b7c61031e83fc59dc35c7a48d,
+  fnv0_128_test_133: u128, b"http://hvo.wr.usgs.gov/kilauea/update/maps.html", 0xa468e5490ec955f1224ed04920713840,
+  fnv0_128_test_134: u128, b"http://hvo.wr.usgs.gov/volcanowatch/current_issue.html", 0x3fa6d90572c3c4db3de9792b46f7158f,
+  fnv0_128_test_135: u128, b"http://neo.jpl.nasa.gov/risk/", 0xd6a807b79234a24f228ffda9d37b6290,
+  fnv0_128_test_136: u128, b"http://norvig.com/21-days.html", 0xd3c70db041b1cfb04cb3fedd0e43ea41,
+  fnv0_128_test_137: u128, b"http://primes.utm.edu/curios/home.php", 0x618171d33b9c13fe3aff04d2fc52befa,
+  fnv0_128_test_138: u128, b"http://slashdot.org/", 0x05af1e7b9fdc7adfa4015b5066f78597,
+  fnv0_128_test_139: u128, b"http://tux.wr.usgs.gov/Maps/155.25-19.5.html", 0x10e89d87839b83aba3c877a7c5f0772a,
+  fnv0_128_test_140: u128, b"http://volcano.wr.usgs.gov/kilaueastatus.php", 0x0858913ccb1ddf79d34e2be6f31cf7fa,
+  fnv0_128_test_141: u128, b"http://www.avo.alaska.edu/activity/Redoubt.php", 0x9b975918a0a2bf1b7672e820f8807176,
+  fnv0_128_test_142: u128, b"http://www.dilbert.com/fast/", 0xbd76123eccc44be551d5bfa4e39bc058,
+  fnv0_128_test_143: u128, b"http://www.fourmilab.ch/gravitation/orbits/", 0xb7e69cd1cd944774ade8d2614bf29e4d,
+  fnv0_128_test_144: u128, b"http://www.fpoa.net/", 0x22589c6e58699b7cec03d8fdaea6683b,
+  fnv0_128_test_145: u128, b"http://www.ioccc.org/index.html", 0x34ea25a800d6b83759ac84afffb186b8,
+  fnv0_128_test_146: u128, b"http://www.isthe.com/cgi-bin/number.cgi", 0xfee9097baf79113fcbbb69322b7e8312,
+  fnv0_128_test_147: u128, b"http://www.isthe.com/chongo/bio.html", 0xce2abcc91c0a75f18686bac8e32a0902,
+  fnv0_128_test_148: u128, b"http://www.isthe.com/chongo/index.html", 0xb23306d59e8c7028fb2c7cf5df5d1d14,
+  fnv0_128_test_149: u128, b"http://www.isthe.com/chongo/src/calc/lucas-calc", 0x8b9f539233ffb4800e9ff979e06f8718,
+  fnv0_128_test_150: u128, b"http://www.isthe.com/chongo/tech/astro/venus2004.html", 0xdb482a23e87818a5ffea88d925a2ba86,
+  fnv0_128_test_151: u128, b"http://www.isthe.com/chongo/tech/astro/vita.html", 0xdbb37e4a2b145bcc442e16530931ffa3,
+  fnv0_128_test_152: u128, b"http://www.isthe.com/chongo/tech/comp/c/expert.html", 0x27794da1a863e0d78ae1c9b46702b75f,
+  fnv0_128_test_153: u128, b"http://www.isthe.com/chongo/tech/comp/calc/index.html", 0x91f497056bf0325bf9b5155d5727020b,
+  fnv0_128_test_154: u128, b"http://www.isthe.com/chongo/tech/comp/fnv/index.html", 0x6c919788133a0e98895a59d36ae54020,
+  fnv0_128_test_155: u128, b"http://www.isthe.com/chongo/tech/math/number/howhigh.html", 0x27c566a27579f7cc8c73519d704503d2,
+  fnv0_128_test_156: u128, b"http://www.isthe.com/chongo/tech/math/number/number.html", 0xa51c291876b1af1919aacaedd8bacce7,
+  fnv0_128_test_157: u128, b"http://www.isthe.com/chongo/tech/math/prime/mersenne.html", 0x47bf3aecc880f13f9d423ca73f5c90b5,
+  fnv0_128_test_158: u128, b"http://www.isthe.com/chongo/tech/math/prime/mersenne.html#largest", 0xc0eab534d1cf5039557532652ae3d594,
+  fnv0_128_test_159: u128, b"http://www.lavarnd.org/cgi-bin/corpspeak.cgi", 0x89bf707d2296495646ec64f91aa0e961,
+  fnv0_128_test_160: u128, b"http://www.lavarnd.org/cgi-bin/haiku.cgi", 0xa0a57046b61226b27a882db667184dc9,
+  fnv0_128_test_161: u128, b"http://www.lavarnd.org/cgi-bin/rand-none.cgi", 0xee8e20bfa0b21beabddeaf6e46947317,
+  fnv0_128_test_162: u128, b"http://www.lavarnd.org/cgi-bin/randdist.cgi", 0xec3df020a78368ec50d6a89578c15a96,
+  fnv0_128_test_163: u128, b"http://www.lavarnd.org/index.html", 0x97e49c4e23db0196feeb7ce25e998aa9,
+  fnv0_128_test_164: u128, b"http://www.lavarnd.org/what/nist-test.html", 0xbc9d93bcdfa6bb8e61ea9e7f8bace30d,
+  fnv0_128_test_165: u128, b"http://www.macosxhints.com/", 0xbdbdba50c67bb55ced05ce9a27741a52,
+  fnv0_128_test_166: u128, b"http://www.mellis.com/", 0x791f25ab47f49b0cf801dc1e5c3b57ad,
+  fnv0_128_test_167: u128, b"http://www.nature.nps.gov/air/webcams/parks/havoso2alert/havoalert.cfm", 0xe8b6b0f258ab84dc8ab48c28f7df8a67,
+  fnv0_128_test_168: u128, b"http://www.nature.nps.gov/air/webcams/parks/havoso2alert/timelines_24.cfm", 0x637fe499a84c2b6b10e31f4adc0b2502,
+  fnv0_128_test_169: u128, b"http://www.paulnoll.com/", 0x9d2b656d90aae63b279df0e334a082e2,
+  fnv0_128_test_170: u128, b"http://www.pepysdiary.com/", 0xf425e3fe9c0a2d68fda72507e2196c8f,
+  fnv0_128_test_171: u128, b"http://www.sciencenews.org/index/home/activity/view", 0xbc0e796ed71af54ddb537fd5417bf6ad,
+  fnv0_128_test_172: u128, b"http://www.skyandtelescope.com/", 0x97708ef9f8ea0057825f2436bcd5886f,
+  fnv0_128_test_173: u128, b"http://www.sput.nl/~rob/sirius.html", 0xc81eaa0cb3b6ad223fdc6b5cf1bd89ce,
+  fnv0_128_test_174: u128, b"http://www.systemexperts.com/", 0x0f47cca21c1027e096d4b9ed262017af,
+  fnv0_128_test_175: u128, b"http://www.tq-international.com/phpBB3/index.php", 0x0775688feee2ef8b80401be3cda72cbd,
+  fnv0_128_test_176: u128, b"http://www.travelquesttours.com/index.htm", 0xc839825b72c424c4f2c55f3d6b5a24d1,
+  fnv0_128_test_177: u128, b"http://www.wunderground.com/global/stations/89606.html", 0x5c978f5155e660d665a100aa02d9df1b,
+  fnv0_128_test_178: u128, &repeat(b"21701", 10), 0x3498046998fce94ba2023d7bb0d9906e,
+  fnv0_128_test_179: u128, &repeat(b"M21701", 10), 0x9013b7c6ff4fc6ec372152dd161316d8,
+  fnv0_128_test_180: u128, &repeat(b"2^21701-1", 10), 0xf98a6bfd26bc16ee25ca5486e3988176,
+  fnv0_128_test_181: u128, &repeat(b"\x54\xc5", 10), 0xc6c89fdee7894e1c907ae617a8b5b5a8,
+  fnv0_128_test_182: u128, &repeat(b"\xc5\x54", 10), 0x4bf1303fdeafefe3c9c9fa1d2a640318,
+  fnv0_128_test_183: u128, &repeat(b"23209", 10), 0xa07fd79afc5201c5eab3ee0ae7968b10,
+  fnv0_128_test_184: u128, &repeat(b"M23209", 10), 0x53fa033835b761bebac4e2391c6fb29c,
+  fnv0_128_test_185: u128, &repeat(b"2^23209-1", 10), 0x0249831afdc1480194d5577de5650ed4,
+  fnv0_128_test_186: u128, &repeat(b"\x5a\xa9", 10), 0x511a0bcde1b94e984564b76bd3b3ca54,
+  fnv0_128_test_187: u128, &repeat(b"\xa9\x5a", 10), 0x401abf3465f462cd058ee239c5a430d4,
+  fnv0_128_test_188: u128, &repeat(b"391581216093", 10), 0xa70a3564d136220323280fe54581a8e4,
+  fnv0_128_test_189: u128, &repeat(b"391581*2^216093-1", 10), 0x8318d90062683b2591e4917a52e094c0,
+  fnv0_128_test_190: u128, &repeat(b"\x05\xf9\x9d\x03\x4c\x81", 10), 0xd95876eae6b6d07702e3ae0fb1ab9860,
+  fnv0_128_test_191: u128, &repeat(b"FEDCBA9876543210", 10), 0x2e638f089236f8b2aec9a1cae95fb430,
+  fnv0_128_test_192: u128, &repeat(b"\xfe\xdc\xba\x98\x76\x54\x32\x10", 10), 0x87b782babe6a4d8d2e373d4265ebe840,
+  fnv0_128_test_193: u128, &repeat(b"EFCDAB8967452301", 10), 0x1f672647d8a80fc86c856da007311c80,
+  fnv0_128_test_194: u128, &repeat(b"\xef\xcd\xab\x89\x67\x45\x23\x01", 10), 0xbe0923383173404dfad897150c55e940,
+  fnv0_128_test_195: u128, &repeat(b"0123456789ABCDEF", 10), 0x32bff9571bf2657a8b2b024533f56400,
+  fnv0_128_test_196: u128, &repeat(b"\x01\x23\x45\x67\x89\xab\xcd\xef", 10), 0x93e17161b53d5dd78da85d845cc43140,
+  fnv0_128_test_197: u128, &repeat(b"1032547698BADCFE", 10), 0x5412135c9b8645d027406c3972307110,
+  fnv0_128_test_198: u128, &repeat(b"\x10\x32\x54\x76\x98\xba\xdc\xfe", 10), 0x474a9d49cd9bdc30a77ff5669c5f23c0,
+  fnv0_128_test_199: u128, &repeat(b"\x00", 500), 0x00000000000000000000000000000000,
+  fnv0_128_test_200: u128, &repeat(b"\x07", 500), 0x330081ff44ab77ddb38fbe1886258bd4,
+  fnv0_128_test_201: u128, &repeat(b"~", 500), 0x905e6b29048e8eb25ab0d3be84fdcda8,
+  fnv0_128_test_202: u128, &repeat(b"\x7f", 500), 0xf00cda7ef87c359683e637ef9b7b33d4,
+  fnv0_256_test_0: U256, b"", U256::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]),
+  fnv0_256_test_1: U256, b"a", U256::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000061]),
+  fnv0_256_test_2: U256, b"b", U256::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000062]),
+  fnv0_256_test_3: U256, b"c", U256::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000063]),
+  fnv0_256_test_4: U256, b"d", U256::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000064]),
+  fnv0_256_test_5: U256, b"e", U256::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000065]),
+  fnv0_256_test_6: U256, b"f", U256::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000066]),
+  fnv0_256_test_7: U256, b"fo", U256::from_words([0x0000000000000000, 0x0000660000000000, 0x0000000000000000, 0x0000000000008d1d]),
+  fnv0_256_test_8: U256, b"foo", U256::from_words([0x0000000000000000, 0x011a8f0000000000, 0x0000000000000000, 0x0000000000c3af58]),
+  fnv0_256_test_9: U256, b"foob", U256::from_words([0x0000000000000002, 0x4b83a50000000000, 0x0000000000000000, 0x000000010f5c276a]),
+  fnv0_256_test_10: U256, b"fooba", U256::from_words([0x000000000000043e, 0x13b5390000000000, 0x0000000000000000, 0x000001784ccaa79f]),
+  fnv0_256_test_11: U256, b"foobar", U256::from_words([0x0000000000075a62, 0x1ef5aa0000000000, 0x0000000000000000, 0x000209d27d06710f]),
+  fnv0_256_test_12: U256, ::std::ffi::CString::new("").unwrap().as_bytes_with_nul(), U256::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]),
+  fnv0_256_test_13: U256, ::std::ffi::CString::new("a").unwrap().as_bytes_with_nul(), U256::from_words([0x0000000000000000, 0x0000610000000000, 0x0000000000000000, 0x0000000000008683]),
+  fnv0_256_test_14: U256, ::std::ffi::CString::new("b").unwrap().as_bytes_with_nul(), U256::from_words([0x0000000000000000, 0x0000620000000000, 0x0000000000000000, 0x00000000000087e6]),
+  fnv0_256_test_15: U256, ::std::ffi::CString::new("c").unwrap().as_bytes_with_nul(), U256::from_words([0x0000000000000000, 0x0000630000000000, 0x0000000000000000, 0x0000000000008949]),
+  fnv0_256_test_16: U256, ::std::ffi::CString::new("d").unwrap().as_bytes_with_nul(), U256::from_words([0x0000000000000000, 0x0000640000000000, 0x0000000000000000, 0x0000000000008aac]),
+  fnv0_256_test_17: U256, ::std::ffi::CString::new("e").unwrap().as_bytes_with_nul(), U256::from_words([0x0000000000000000, 0x0000650000000000, 0x0000000000000000, 0x0000000000008c0f]),
+  fnv0_256_test_18: U256, ::std::ffi::CString::new("f").unwrap().as_bytes_with_nul(), U256::from_words([0x0000000000000000, 0x0000660000000000, 0x0000000000000000, 0x0000000000008d72]),
+  fnv0_256_test_19: U256, ::std::ffi::CString::new("fo").unwrap().as_bytes_with_nul(), U256::from_words([0x0000000000000000, 0x011a8f0000000000, 0x0000000000000000, 0x0000000000c3af37]),
+  fnv0_256_test_20: U256, ::std::ffi::CString::new("foo").unwrap().as_bytes_with_nul(), U256::from_words([0x0000000000000002, 0x4b83a50000000000, 0x0000000000000000, 0x000000010f5c2708]),
+  fnv0_256_test_21: U256, ::std::ffi::CString::new("foob").unwrap().as_bytes_with_nul(), U256::from_words([0x000000000000043e, 0x13b5390000000000, 0x0000000000000000, 0x000001784ccaa7fe]),
+  fnv0_256_test_22: U256, ::std::ffi::CString::new("fooba").unwrap().as_bytes_with_nul(), U256::from_words([0x0000000000075a62, 0x1ef5aa0000000000, 0x0000000000000000, 0x000209d27d06717d]),
+  fnv0_256_test_23: U256, ::std::ffi::CString::new("foobar").unwrap().as_bytes_with_nul(), U256::from_words([0x000000000c3c288d, 0xf51bcd0000000000, 0x0000000000000000, 0x02d39ee35feec7cd]),
+  fnv0_256_test_24: U256, b"ch", U256::from_words([0x0000000000000000, 0x0000630000000000, 0x0000000000000000, 0x0000000000008921]),
+  fnv0_256_test_25: U256, b"cho", U256::from_words([0x0000000000000000, 0x01126a0000000000, 0x0000000000000000, 0x0000000000be28ac]),
+  fnv0_256_test_26: U256, b"chon", U256::from_words([0x0000000000000002, 0x3ab1aa0000000000, 0x0000000000000000, 0x0000000107b266ea]),
+  fnv0_256_test_27: U256, b"chong", U256::from_words([0x000000000000041f, 0x16c5a80000000000, 0x0000000000000000, 0x0000016dac64b619]),
+  fnv0_256_test_28: U256, b"chongo", U256::from_words([0x00000000000724c8, 0xf8ce110000000000, 0x0000000000000000, 0x0001fb160fa884c4]),
+  fnv0_256_test_29: U256, b"chongo ", U256::from_words([0x000000000be318c0, 0xae46570000000000, 0x0000000000000000, 0x02bf2f97b6b01bec]),
+  fnv0_256_test_30: U256, b"chongo w", U256::from_words([0x000000133b1aeae8, 0x5ba6910000000000, 0x0000000000000003, 0xcf1eff625636b833]),
+  fnv0_256_test_31: U256, b"chongo wa", U256::from_words([0x00001e7a1553228d, 0x4eb3460000000000, 0x0000000000000548, 0x37fc255d8de16ed8]),
+  fnv0_256_test_32: U256, b"chongo was", U256::from_words([0x002f8b838e6e4782, 0x0408ea0000000000, 0x0000000000075325, 0xa2a7d0bbbf9cb5fb]),
+  fnv0_256_test_33: U256, b"chongo was ", U256::from_words([0x49419d112abde50b, 0x3512790000000000, 0x000000000a284d30, 0x8eb6745ab6505b31]),
+  fnv0_256_test_34: U256, b"chongo was h", U256::from_words([0xbe49ff5cfbc8f940, 0xe8f8fc0000000000, 0x0000000e15e30a55, 0xe70359cad16e749b]),
+  fnv0_256_test_35: U256, b"chongo was he", U256::from_words([0xc3a773d82b0b6fd4, 0x7fba0f0000000000, 0x0000138859d7551f, 0x59a584406c2bb294]),
+  fnv0_256_test_36: U256, b"chongo was her", U256::from_words([0x288ac41d56605419, 0x4ab5610000000000, 0x001b1614959b0a79, 0x508665560098a34e]),
+  fnv0_256_test_37: U256, b"chongo was here", U256::from_words([0xd3786dff4df9f513, 0x3228d10000000000, 0x258f9e8b75ff863a, 0xaa5e8642d3aa754f]),
+  fnv0_256_test_38: U256, b"chongo was here!", U256::from_words([0x3f86c3b380251c72, 0x390f220000000034, 0x1628db64a157235a, 0x41142eab8560acac]),
+  fnv0_256_test_39: U256, b"chongo was here!\n", U256::from_words([0x6f04bc2bc7a51dea, 0x80a8d2000000483a, 0xbaa83c8bbbd60628, 0x3efcb7d9f50f728e]),
+  fnv0_256_test_40: U256, ::std::ffi::CString::new("ch").unwrap().as_bytes_with_nul(), U256::from_words([0x0000000000000000, 0x01126a0000000000, 0x0000000000000000, 0x0000000000be28c3]),
+  fnv0_256_test_41: U256, ::std::ffi::CString::new("cho").unwrap().as_bytes_with_nul(), U256::from_words([0x0000000000000002, 0x3ab1aa0000000000, 0x0000000000000000, 0x0000000107b26684]),
+  fnv0_256_test_42: U256, ::std::ffi::CString::new("chon").unwrap().as_bytes_with_nul(), U256::from_words([0x000000000000041f, 0x16c5a80000000000, 0x0000000000000000, 0x0000016dac64b67e]),
+  fnv0_256_test_43: U256, ::std::ffi::CString::new("chong").unwrap().as_bytes_with_nul(), U256::from_words([0x00000000000724c8, 0xf8ce110000000000, 0x0000000000000000, 0x0001fb160fa884ab]),
+  fnv0_256_test_44: U256, ::std::ffi::CString::new("chongo").unwrap().as_bytes_with_nul(), U256::from_words([0x000000000be318c0, 0xae46570000000000, 0x0000000000000000, 0x02bf2f97b6b01bcc]),
+  fnv0_256_test_45: U256, ::std::ffi::CString::new("chongo ").unwrap().as_bytes_with_nul(), U256::from_words([0x000000133b1aeae8, 0x5ba6910000000000, 0x0000000000000003, 0xcf1eff625636b844]),
+  fnv0_256_test_46: U256, ::std::ffi::CString::new("chongo w").unwrap().as_bytes_with_nul(), U256::from_words([0x00001e7a1553228d, 0x4eb3460000000000, 0x0000000000000548, 0x37fc255d8de16eb9]),
+  fnv0_256_test_47: U256, ::std::ffi::CString::new("chongo wa").unwrap().as_bytes_with_nul(), U256::from_words([0x002f8b838e6e4782, 0x0408ea0000000000, 0x0000000000075325, 0xa2a7d0bbbf9cb588]),
+  fnv0_256_test_48: U256, ::std::ffi::CString::new("chongo was").unwrap().as_bytes_with_nul(), U256::from_words([0x49419d112abde50b, 0x3512790000000000, 0x000000000a284d30, 0x8eb6745ab6505b11]),
+  fnv0_256_test_49: U256, ::std::ffi::CString::new("chongo was ").unwrap().as_bytes_with_nul(), U256::from_words([0xbe49ff5cfbc8f940, 0xe8f8fc0000000000, 0x0000000e15e30a55, 0xe70359cad16e74f3]),
+  fnv0_256_test_50: U256, ::std::ffi::CString::new("chongo was h").unwrap().as_bytes_with_nul(), U256::from_words([0xc3a773d82b0b6fd4, 0x7fba0f0000000000, 0x0000138859d7551f, 0x59a584406c2bb2f1]),
+  fnv0_256_test_51: U256, ::std::ffi::CString::new("chongo was he").unwrap().as_bytes_with_nul(), U256::from_words([0x288ac41d56605419, 0x4ab5610000000000, 0x001b1614959b0a79, 0x508665560098a33c]),
+  fnv0_256_test_52: U256, ::std::ffi::CString::new("chongo was her").unwrap().as_bytes_with_nul(), U256::from_words([0xd3786dff4df9f513, 0x3228d10000000000, 0x258f9e8b75ff863a, 0xaa5e8642d3aa752a]),
+  fnv0_256_test_53: U256, ::std::ffi::CString::new("chongo was here").unwrap().as_bytes_with_nul(), U256::from_words([0x3f86c3b380251c72, 0x390f220000000034, 0x1628db64a157235a, 0x41142eab8560ac8d]),
+  fnv0_256_test_54: U256, ::std::ffi::CString::new("chongo was here!").unwrap().as_bytes_with_nul(), U256::from_words([0x6f04bc2bc7a51dea, 0x80a8d2000000483a, 0xbaa83c8bbbd60628, 0x3efcb7d9f50f7284]),
+  fnv0_256_test_55: U256, ::std::ffi::CString::new("chongo was here!\n").unwrap().as_bytes_with_nul(), U256::from_words([0xc99718f4d6b05625, 0x798dc40000642970, 0xd74bf5c579ca89cf, 0x5872f33ed46bdaea]),
+  fnv0_256_test_56: U256, b"cu", U256::from_words([0x0000000000000000, 0x0000630000000000, 0x0000000000000000, 0x000000000000893c]),
+  fnv0_256_test_57: U256, b"cur", U256::from_words([0x0000000000000000, 0x0112850000000000, 0x0000000000000000, 0x0000000000be4e46]),
+  fnv0_256_test_58: U256, b"curd", U256::from_words([0x0000000000000002, 0x3afcb50000000000, 0x0000000000000000, 0x0000000107e68b76]),
+  fnv0_256_test_59: U256, b"curds", U256::from_words([0x000000000000041f, 0xb2fa750000000000, 0x0000000000000000, 0x0000016df4b364d1]),
+  fnv0_256_test_60: U256, b"curds ", U256::from_words([0x00000000000725e9, 0xe4b5100000000000, 0x0000000000000000, 0x0001fb7a54c4cdf3]),
+  fnv0_256_test_61: U256, b"curds a", U256::from_words([0x000000000be50dac, 0xebe3230000000000, 0x0000000000000000, 0x02bfbaa38ce99798]),
+  fnv0_256_test_62: U256, b"curds an", U256::from_words([0x000000133e5c9a58, 0x0591210000000000, 0x0000000000000003, 0xcfdfd0cc67ed37a6]),
+  fnv0_256_test_63: U256, b"curds and", U256::from_words([0x00001e7f5a3ad477, 0xa578690000000000, 0x0000000000000549, 0x435e8b741df42b56]),
+  fnv0_256_test_64: U256, b"curds and ", U256::from_words([0x002f93dd7e201608, 0x6a24f10000000000, 0x0000000000075498, 0x6c1b620589981862]),
+  fnv0_256_test_65: U256, b"curds and w", U256::from_words([0x494ea49201e09334, 0xc952950000000000, 0x000000000a2a4f5d, 0xe9f8edadcde9cf91]),
+  fnv0_256_test_66: U256, b"curds and wh", U256::from_words([0xd25d94629359d001, 0x1754300000000000, 0x0000000e18ac0f3b, 0x743198048b3ad67b]),
+  fnv0_256_test_67: U256, b"curds and whe", U256::from_words([0x63d400268723760e, 0x94950b0000000000, 0x0000138c36991f72, 0x20c5ce4d12976cf4]),
+  fnv0_256_test_68: U256, b"curds and whey", U256::from_words([0x081ba78e2bfb034a, 0xa21b350000000000, 0x001b1b6fb6569b43, 0x724d14e0c7fc1625]),
+  fnv0_256_test_69: U256, b"curds and whey\n", U256::from_words([0x94f49d994a2a7146, 0xc7d0a40000000000, 0x25970be9da194e87, 0x80e3f3b55292b545]),
+  fnv0_256_test_70: U256, ::std::ffi::CString::new("cu").unwrap().as_bytes_with_nul(), U256::from_words([0x0000000000000000, 0x0112850000000000, 0x0000000000000000, 0x0000000000be4e34]),
+  fnv0_256_test_71: U256, ::std::ffi::CString::new("cur").unwrap().as_bytes_with_nul(), U256::from_words([0x0000000000000002, 0x3afcb50000000000, 0x0000000000000000, 0x0000000107e68b12]),
+  fnv0_256_test_72: U256, ::std::ffi::CString::new("curd").unwrap().as_bytes_with_nul(), U256::from_words([0x000000000000041f, 0xb2fa750000000000, 0x0000000000000000, 0x0000016df4b364a2]),
+  fnv0_256_test_73: U256, ::std::ffi::CString::new("curds").unwrap().as_bytes_with_nul(), U256::from_words([0x00000000000725e9, 0xe4b5100000000000, 0x0000000000000000, 0x0001fb7a54c4cdd3]),
+  fnv0_256_test_74: U256, ::std::ffi::CString::new("curds ").unwrap().as_bytes_with_nul(), U256::from_words([0x000000000be50dac, 0xebe3230000000000, 0x0000000000000000, 0x02bfbaa38ce997f9]),
+  fnv0_256_test_75: U256, ::std::ffi::CString::new("curds a").unwrap().as_bytes_with_nul(), U256::from_words([0x000000133e5c9a58, 0x0591210000000000, 0x0000000000000003, 0xcfdfd0cc67ed37c8]),
+  fnv0_256_test_76: U256, ::std::ffi::CString::new("curds an").unwrap().as_bytes_with_nul(), U256::from_words([0x00001e7f5a3ad477, 0xa578690000000000, 0x0000000000000549, 0x435e8b741df42b32]),
+  fnv0_256_test_77: U256, ::std::ffi::CString::new("curds and").unwrap().as_bytes_with_nul(), U256::from_words([0x002f93dd7e201608, 0x6a24f10000000000, 0x0000000000075498, 0x6c1b620589981842]),
+  fnv0_256_test_78: U256, ::std::ffi::CString::new("curds and ").unwrap().as_bytes_with_nul(), U256::from_words([0x494ea49201e09334, 0xc952950000000000, 0x000000000a2a4f5d, 0xe9f8edadcde9cfe6]),
+  fnv0_256_test_79: U256, ::std::ffi::CString::new("curds and w").unwrap().as_bytes_with_nul(), U256::from_words([0xd25d94629359d001, 0x1754300000000000, 0x0000000e18ac0f3b, 0x743198048b3ad613]),
+  fnv0_256_test_80: U256, ::std::ffi::CString::new("curds and wh").unwrap().as_bytes_with_nul(), U256::from_words([0x63d400268723760e, 0x94950b0000000000, 0x0000138c36991f72, 0x20c5ce4d12976c91]),
+  fnv0_256_test_81: U256, ::std::ffi::CString::new("curds and whe").unwrap().as_bytes_with_nul(), U256::from_words([0x081ba78e2bfb034a, 0xa21b350000000000, 0x001b1b6fb6569b43, 0x724d14e0c7fc165c]),
+  fnv0_256_test_82: U256, ::std::ffi::CString::new("curds and whey").unwrap().as_bytes_with_nul(), U256::from_words([0x94f49d994a2a7146, 0xc7d0a40000000000, 0x25970be9da194e87, 0x80e3f3b55292b54f]),
+  fnv0_256_test_83: U256, ::std::ffi::CString::new("curds and whey\n").unwrap().as_bytes_with_nul(), U256::from_words([0xa8851312bcceca79, 0xa908b10000000034, 0x207585497117e5e7, 0xbc1af47181715eaf]),
+  fnv0_256_test_84: U256, b"hi", U256::from_words([0x0000000000000000, 0x0000680000000000, 0x0000000000000000, 0x0000000000009051]),
+  fnv0_256_test_85: U256, ::std::ffi::CString::new("hi").unwrap().as_bytes_with_nul(), U256::from_words([0x0000000000000000, 0x0120890000000000, 0x0000000000000000, 0x0000000000c82053]),
+  fnv0_256_test_86: U256, b"hello", U256::from_words([0x0000000000000456, 0x27740b0000000000, 0x0000000000000000, 0x00000180f67f4bd8]),
+  fnv0_256_test_87: U256, ::std::ffi::CString::new("hello").unwrap().as_bytes_with_nul(), U256::from_words([0x000000000007846f, 0x3537190000000000, 0x0000000000000000, 0x000215d5d2862c88]),
+  fnv0_256_test_88: U256, b"\xff\x00\x00\x01", U256::from_words([0x0000000000000005, 0xbf16250000000000, 0x0000000000000000, 0x00000002a7fe91c4]),
+  fnv0_256_test_89: U256, b"\x01\x00\x00\xff", U256::from_words([0x0000000000000000, 0x05c4db0000000000, 0x0000000000000000, 0x0000000002aaa9c4]),
+  fnv0_256_test_90: U256, b"\xff\x00\x00\x02", U256::from_words([0x0000000000000005, 0xbf16250000000000, 0x0000000000000000, 0x00000002a7fe91c7]),
+  fnv0_256_test_91: U256, b"\x02\x00\x00\xff", U256::from_words([0x0000000000000000, 0x0b89b60000000000, 0x0000000000000000, 0x0000000005555289]),
+  fnv0_256_test_92: U256, b"\xff\x00\x00\x03", U256::from_words([0x0000000000000005, 0xbf16250000000000, 0x0000000000000000, 0x00000002a7fe91c6]),
+  fnv0_256_test_93: U256, b"\x03\x00\x00\xff", U256::from_words([0x0000000000000000, 0x114e910000000000, 0x0000000000000000, 0x0000000007fffb4e]),
+  fnv0_256_test_94: U256, b"\xff\x00\x00\x04", U256::from_words([0x0000000000000005, 0xbf16250000000000, 0x0000000000000000, 0x00000002a7fe91c1]),
+  fnv0_256_test_95: U256, b"\x04\x00\x00\xff", U256::from_words([0x0000000000000000, 0x17136c0000000000, 0x0000000000000000, 0x000000000aaaa413]),
+  fnv0_256_test_96: U256, b"\x40\x51\x4e\x44", U256::from_words([0x0000000000000001, 0x70b4b00000000000, 0x0000000000000000, 0x00000000aa5053b3]),
+  fnv0_256_test_97: U256, b"\x44\x4e\x51\x40", U256::from_words([0x0000000000000001, 0x877cc10000000000, 0x0000000000000000, 0x00000000b4c66525]),
+  fnv0_256_test_98: U256, b"\x40\x51\x4e\x4a", U256::from_words([0x0000000000000001, 0x70b4b00000000000, 0x0000000000000000, 0x00000000aa5053bd]),
+  fnv0_256_test_99: U256, b"\x4a\x4e\x51\x40", U256::from_words([0x0000000000000001, 0xab71ab0000000000, 0x0000000000000000, 0x00000000c5b4a383]),
+  fnv0_256_test_100: U256, b"\x40\x51\x4e\x54", U256::from_words([0x0000000000000001, 0x70b4b00000000000, 0x0000000000000000, 0x00000000aa5053a3]),
+  fnv0_256_test_101: U256, b"\x54\x4e\x51\x40", U256::from_words([0x0000000000000001, 0xe3ca510000000000, 0x0000000000000000, 0x00000000df70ccf5]),
+  fnv0_256_test_102: U256, b"127.0.0.1", U256::from_words([0x00000f0fc34b7da4, 0x3de9ae0000000000, 0x000000000000029c, 0x1dbdb5839cd9a117]),
+  fnv0_256_test_103: U256, ::std::ffi::CString::new("127.0.0.1").unwrap().as_bytes_with_nul(), U256::from_words([0x00177ef98f64be5e, 0xb4ad610000000000, 0x0000000000039e7d, 0x3e12b58281ca62e5]),
+  fnv0_256_test_104: U256, b"127.0.0.2", U256::from_words([0x00000f0fc34b7da4, 0x3de9ae0000000000, 0x000000000000029c, 0x1dbdb5839cd9a114]),
+  fnv0_256_test_105: U256, ::std::ffi::CString::new("127.0.0.2").unwrap().as_bytes_with_nul(), U256::from_words([0x00177ef98f64be5e, 0xb4ad5e0000000000, 0x0000000000039e7d, 0x3e12b58281ca5ebc]),
+  fnv0_256_test_106: U256, b"127.0.0.3", U256::from_words([0x00000f0fc34b7da4, 0x3de9ae0000000000, 0x000000000000029c, 0x1dbdb5839cd9a115]),
+  fnv0_256_test_107: U256, ::std::ffi::CString::new("127.0.0.3").unwrap().as_bytes_with_nul(), U256::from_words([0x00177ef98f64be5e, 0xb4ad5f0000000000, 0x0000000000039e7d, 0x3e12b58281ca601f]),
+  fnv0_256_test_108: U256, b"64.81.78.68", U256::from_words([0x27fa07867ed565ab, 0x135d1e0000000000, 0x00000000058b15bd, 0x61c17402a4495948]),
+  fnv0_256_test_109: U256, ::std::ffi::CString::new("64.81.78.68").unwrap().as_bytes_with_nul(), U256::from_words([0xface2ce3a35ffee0, 0x2379e20000000000, 0x00000007afdf259e, 0x8f43dfa9d1b6ced8]),
+  fnv0_256_test_110: U256, b"64.81.78.74", U256::from_words([0x27fa07867ed565ab, 0x135d1f0000000000, 0x00000000058b15bd, 0x61c17402a4495ae7]),
+  fnv0_256_test_111: U256, ::std::ffi::CString::new("64.81.78.74").unwrap().as_bytes_with_nul(), U256::from_words([0xface2ce3a35ffee0, 0x237ce40000000000, 0x00000007afdf259e, 0x8f43dfa9d1b90e55]),
+  fnv0_256_test_112: U256, b"64.81.78.84", U256::from_words([0x27fa07867ed565ab, 0x135d2c0000000000, 0x00000000058b15bd, 0x61c17402a4496cee]),
+  fnv0_256_test_113: U256, ::std::ffi::CString::new("64.81.78.84").unwrap().as_bytes_with_nul(), U256::from_words([0xface2ce3a35ffee0, 0x23a0f20000000000, 0x00000007afdf259e, 0x8f43dfa9d1d20e0a]),
+  fnv0_256_test_114: U256, b"feedface", U256::from_words([0x00000013ca48d7c0, 0xf55fac0000000000, 0x0000000000000003, 0xeb47fbb593b1205f]),
+  fnv0_256_test_115: U256, ::std::ffi::CString::new("feedface").unwrap().as_bytes_with_nul(), U256::from_words([0x00001f5ccafee627, 0xf4cbe30000000000, 0x000000000000056f, 0x44d20ccbce9fe3bd]),
+  fnv0_256_test_116: U256, b"feedfacedaffdeed", U256::from_words([0x1948a05de480dc21, 0x126bf50000000035, 0x973b249b5f1b51dc, 0xa214c8aee4cf385c]),
+  fnv0_256_test_117: U256, ::std::ffi::CString::new("feedfacedaffdeed").unwrap().as_bytes_with_nul(), U256::from_words([0x2b083ed5f379f0c1, 0x5aed1b0000004a50, 0xb703c374e2e284f4, 0xc2d24a874b5b2794]),
+  fnv0_256_test_118: U256, b"feedfacedeadbeef", U256::from_words([0x1948a05de4f7cd77, 0x6dbb1c0000000035, 0x973b249b5f1b51dc, 0xa2301517d7f638ef]),
+  fnv0_256_test_119: U256, ::std::ffi::CString::new("feedfacedeadbeef").unwrap().as_bytes_with_nul(), U256::from_words([0x2b083ed6b3b70475, 0x20b0c30000004a50, 0xb703c374e2e284f4, 0xe8ad40107a70f36d]),
+  fnv0_256_test_120: U256, b"line 1\nline 2\nline 3", U256::from_words([0x6161cc4c595dc44b, 0xfa25f4d23b7b1375, 0x1cb00b693c8e1bab, 0xd9e7394d2b67659e]),
+  fnv0_256_test_121: U256, b"chongo <Landon Curt Noll> /\\../\\", U256::from_words([0xdd268dbcaac55036, 0x2d98c384c4e576cc, 0xc8b1536847b6bbb3, 0x1023b4c8caee0535]),
+  fnv0_256_test_122: U256, ::std::ffi::CString::new("chongo <Landon Curt Noll> /\\../\\").unwrap().as_bytes_with_nul(), U256::from_words([0x63323fb0f35303ec, 0x28dc561d0a33bdfa, 0x4de6a99b7266494f, 0x6183b2716811387f]),
+  fnv0_256_test_123: U256, b"chongo (Landon Curt Noll) /\\../\\", U256::from_words([0x28ef4b97d34c767c, 0x35ae794dce98f5f3, 0x2f0205ddd0286b79, 0xcd568a0424ba0078]),
+  fnv0_256_test_124: U256, ::std::ffi::CString::new("chongo (Landon Curt Noll) /\\../\\").unwrap().as_bytes_with_nul(), U256::from_words([0xec414d5759925263, 0x2af2aee57e1d103a, 0x2fce2297a80d09e7, 0xbf0163beedeea668]),
+  fnv0_256_test_125: U256, b"http://antwrp.gsfc.nasa.gov/apod/astropix.html", U256::from_words([0x763c1803c0dc1150, 0xc042ecaa3f0d69bf, 0xc5cf8b4446005dc2, 0x0c50b2a76c378de5]),
+  fnv0_256_test_126: U256, b"http://en.wikipedia.org/wiki/Fowler_Noll_Vo_hash", U256::from_words([0xc32871b433f24ceb, 0x142d492356fd228a, 0x89c733f0c7a669f4, 0xcdd9648251783c70]),
+  fnv0_256_test_127: U256, b"http://epod.usra.edu/", U256::from_words([0x8749de589eba6bc0, 0xe64d180e731df1b8, 0x1f0e80876a4ebd87, 0xdf9cbbf7bf94ced8]),
+  fnv0_256_test_128: U256, b"http://exoplanet.eu/", U256::from_words([0x30a491728c2e340a, 0xfe4f44caad650ac6, 0xd41947c20af1c86f, 0xfcc7210ad1270e9f]),
+  fnv0_256_test_129: U256, b"http://hvo.wr.usgs.gov/cam3/", U256::from_words([0xb16454b83ff3ddf6, 0x7912ce0a5607487d, 0x3516173763e1d307, 0xe5df595943ce0812]),
+  fnv0_256_test_130: U256, b"http://hvo.wr.usgs.gov/cams/HMcam/", U256::from_words([0xe99ee1b59a014cc2, 0xeeb6393c74f330fb, 0x464c1353d7b37cfe, 0x760e9ae7a537ac33]),
+  fnv0_256_test_131: U256, b"http://hvo.wr.usgs.gov/kilauea/update/deformation.html", U256::from_words([0xa53bfe370c9d701d, 0x8513ac166936b7f3, 0x30ce5643ea25b585, 0x6e73691142757f35]),
+  fnv0_256_test_132: U256, b"http://hvo.wr.usgs.gov/kilauea/update/images.html", U256::from_words([0x0c1440d45ab39ece, 0x06b3306f657b8621, 0x6e156554cae35a8d, 0x4be011841df19675]),
+  fnv0_256_test_133: U256, b"http://hvo.wr.usgs.gov/kilauea/update/maps.html", U256::from_words([0xf9cce2ad87cdf32f, 0xe80a1bcf87f032fc, 0x5995cb1e6d16bacb, 0xdca41417fb4eb780]),
+  fnv0_256_test_134: U256, b"http://hvo.wr.usgs.gov/volcanowatch/current_issue.html", U256::from_words([0xc457162e6547e84c, 0x5f4d9ce8ee83f09f, 0xe7fad02a5db4c4bb, 0xed17d4c38503edbf]),
+  fnv0_256_test_135: U256, b"http://neo.jpl.nasa.gov/risk/", U256::from_words([0xbd15bef8aeeaabb8, 0x68df016a61dc4e30, 0xfcdba5a187dea6f5, 0xef0952f29ff64e58]),
+  fnv0_256_test_136: U256, b"http://norvig.com/21-days.html", U256::from_words([0xf22563f2fbe89555, 0x114e93011b9dfbb9, 0x48de3a60884be10b, 0xaea22663df7b18f9]),
+  fnv0_256_test_137: U256, b"http://primes.utm.edu/curios/home.php", U256::from_words([0xaf7d96173433c8e8, 0xa460dd5aff6b0820, 0x47d05ba3e7a87881, 0xffffa504cee245fa]),
+  fnv0_256_test_138: U256, b"http://slashdot.org/", U256::from_words([0x396b31fb2d316dca, 0x409ff6caad650ac6, 0xd4194c17a547b387, 0xeb7c439d3af55537]),
+  fnv0_256_test_139: U256, b"http://tux.wr.usgs.gov/Maps/155.25-19.5.html", U256::from_words([0x7d4ab3bf1ae641fd, 0x2e2456d517d70fe5, 0x4991e009b1679264, 0xa6a86b7ee4f56d3a]),
+  fnv0_256_test_140: U256, b"http://volcano.wr.usgs.gov/kilaueastatus.php", U256::from_words([0xeaee790ae46a8747, 0x163c9b30e7e75a42, 0x046763d536efc7fc, 0x22ada881ed8ef342]),
+  fnv0_256_test_141: U256, b"http://www.avo.alaska.edu/activity/Redoubt.php", U256::from_words([0x180581baacb30d32, 0xacf9ac53f9a4e215, 0xcd9852517c78ef5b, 0x53d58537b87b24d6]),
+  fnv0_256_test_142: U256, b"http://www.dilbert.com/fast/", U256::from_words([0xc2d9610f9fc9c02e, 0x7f760b44f305b739, 0x90998d96d331e076, 0x0e231f95586e4b68]),
+  fnv0_256_test_143: U256, b"http://www.fourmilab.ch/gravitation/orbits/", U256::from_words([0x543ab72c29a3c4c1, 0xdf10745340688db1, 0x3c1574d18f326b34, 0xce1dbac9c84b8335]),
+  fnv0_256_test_144: U256, b"http://www.fpoa.net/", U256::from_words([0x870202ddd6196d3e, 0x0457f3caad650ac6, 0xd4194b5464d38041, 0x19b8a693645c7c33]),
+  fnv0_256_test_145: U256, b"http://www.ioccc.org/index.html", U256::from_words([0xff27951d0ba7edf3, 0xc7be5a73a58e8235, 0xee0fcdce6f5f5efb, 0xb4d4df922f699ec8]),
+  fnv0_256_test_146: U256, b"http://www.isthe.com/cgi-bin/number.cgi", U256::from_words([0x4d1035639d3f7fec, 0x72c15384f3cc6b09, 0xcd418b7822904c7b, 0x49f419a119c7bc62]),
+  fnv0_256_test_147: U256, b"http://www.isthe.com/chongo/bio.html", U256::from_words([0x0eea3f91948da453, 0x55cc0c742eef64a2, 0x9af8bd66f8b3c81c, 0xb2f5161dc42351a2]),
+  fnv0_256_test_148: U256, b"http://www.isthe.com/chongo/index.html", U256::from_words([0x0ffbd6f9894c37b5, 0x511e435577ac3434, 0x3cefe8566fdbe2f5, 0x05990e3bd3a65cf4]),
+  fnv0_256_test_149: U256, b"http://www.isthe.com/chongo/src/calc/lucas-calc", U256::from_words([0x7f8dafec9a0e2f48, 0xc4c04efc30de5e17, 0xf266f7780ea2fd49, 0x0fdf7ac081e82230]),
+  fnv0_256_test_150: U256, b"http://www.isthe.com/chongo/tech/astro/venus2004.html", U256::from_words([0x8271946b77273142, 0xac10c05bb9e648c2, 0x53f43f76b4a8f3e3, 0x96dbb5798b0ae4e6]),
+  fnv0_256_test_151: U256, b"http://www.isthe.com/chongo/tech/astro/vita.html", U256::from_words([0xc510bea4e073cd2b, 0xa20e33b58fbabf3b, 0xe14ccc9489b2eea8, 0xdd2ea04a4780d32b]),
+  fnv0_256_test_152: U256, b"http://www.isthe.com/chongo/tech/comp/c/expert.html", U256::from_words([0x193a9e3e75478073, 0xa345bb3bddd07a30, 0x9b3ca41dd7a7976d, 0x8013d13e8a55038f]),
+  fnv0_256_test_153: U256, b"http://www.isthe.com/chongo/tech/comp/calc/index.html", U256::from_words([0x64e380c546bb1701, 0x93d5d75eeb484234, 0xcfa32994c6bb3eb2, 0x667a993a92340a53]),
+  fnv0_256_test_154: U256, b"http://www.isthe.com/chongo/tech/comp/fnv/index.html", U256::from_words([0xd702c8fbc6f9cde8, 0xae12d50498197167, 0x451876ecd0e25aca, 0xc2bd62b57e9fbf38]),
+  fnv0_256_test_155: U256, b"http://www.isthe.com/chongo/tech/math/number/howhigh.html", U256::from_words([0x3e6812984b119ec0, 0xb896806851861db7, 0xeae113442a2f8bf7, 0xe56e498a9d0121c2]),
+  fnv0_256_test_156: U256, b"http://www.isthe.com/chongo/tech/math/number/number.html", U256::from_words([0x2aa4e2913403d989, 0x7526ebcc5f6b1ac3, 0xf18499a77dbdabf2, 0xa97e3f7839034087]),
+  fnv0_256_test_157: U256, b"http://www.isthe.com/chongo/tech/math/prime/mersenne.html", U256::from_words([0xf6e7e22b87a75b94, 0x7423006844232586, 0x143ec04b9ac8c965, 0x12b8f1a7d175c545]),
+  fnv0_256_test_158: U256, b"http://www.isthe.com/chongo/tech/math/prime/mersenne.html#largest", U256::from_words([0x7b0871895c43d1e8, 0xc7a01142fcba51d4, 0x444630fb299a8658, 0xbba03763b5e26ba4]),
+  fnv0_256_test_159: U256, b"http://www.lavarnd.org/cgi-bin/corpspeak.cgi", U256::from_words([0x4045d738cc682325, 0xf847a3c7b5037df4, 0x2e0ba0b4ef8b7305, 0xaef96c7c4cdb89d1]),
+  fnv0_256_test_160: U256, b"http://www.lavarnd.org/cgi-bin/haiku.cgi", U256::from_words([0xe2fb7b05be4cb4a0, 0xc8cdfe9c3976bb2f, 0xad3323bf9541b30f, 0xa1a7248c6a9b2ce9]),
+  fnv0_256_test_161: U256, b"http://www.lavarnd.org/cgi-bin/rand-none.cgi", U256::from_words([0xc4b6dcd63e262380, 0x66e43ac7b5037df4, 0x2e0b9d5b9eebd5ad, 0x20bc312b495cc4bf]),
+  fnv0_256_test_162: U256, b"http://www.lavarnd.org/cgi-bin/randdist.cgi", U256::from_words([0x4b936a91dd2e9dfc, 0x4520533f4cf30790, 0xe9c657b318f74a2a, 0xf1f7e8e7cbd8960e]),
+  fnv0_256_test_163: U256, b"http://www.lavarnd.org/index.html", U256::from_words([0x71fe602b7cc04943, 0x363083df9c40f4f3, 0xe37b5394821d19ef, 0x6c634fab8b1c1621]),
+  fnv0_256_test_164: U256, b"http://www.lavarnd.org/what/nist-test.html", U256::from_words([0xb2f097045af9c760, 0x3d875bfc81f4160b, 0xf920ba276801f23c, 0x98f2efd9f4c29df5]),
+  fnv0_256_test_165: U256, b"http://www.macosxhints.com/", U256::from_words([0x17713f8c4b18b606, 0xf8e7f22fc9e101f0, 0x9e7adbfad907b2b1, 0x12c4a6c10e20d60a]),
+  fnv0_256_test_166: U256, b"http://www.mellis.com/", U256::from_words([0xed575b9e42da32ab, 0x791ef509a2863253, 0x17fcacd73a0a4b7d, 0x1205bfa1fc31a7ed]),
+  fnv0_256_test_167: U256, b"http://www.nature.nps.gov/air/webcams/parks/havoso2alert/havoalert.cfm", U256::from_words([0x726b5c078f8cb5b6, 0xa18fbb6e1e2d0455, 0xdd2cdb99d1cb6a61, 0x96e425f75df14997]),
+  fnv0_256_test_168: U256, b"http://www.nature.nps.gov/air/webcams/parks/havoso2alert/timelines_24.cfm", U256::from_words([0x1b9f666754fb38dd, 0x4d504d3a38fbe847, 0x6762eca81f121107, 0x19e4139aaf4e70da]),
+  fnv0_256_test_169: U256, b"http://www.paulnoll.com/", U256::from_words([0x8551a33f9ec762de, 0x012b001940fc0bcb, 0x733af12bbd9802fa, 0x5d7e2f9979231fa2]),
+  fnv0_256_test_170: U256, b"http://www.pepysdiary.com/", U256::from_words([0x6a092304cab3e196, 0xe62b9a17ee8a5e79, 0x234fed6fe23cac6b, 0xf1355b0a9490acff]),
+  fnv0_256_test_171: U256, b"http://www.sciencenews.org/index/home/activity/view", U256::from_words([0x0d4050abb56cec6a, 0x447e3acf0a9ab00f, 0x90faa2a057920eee, 0x4b2ada17f5ab687d]),
+  fnv0_256_test_172: U256, b"http://www.skyandtelescope.com/", U256::from_words([0x8026ae552648728f, 0x0a56fc9e74bfe05c, 0xf592f7b50706acb1, 0xc9a5251a69e7587f]),
+  fnv0_256_test_173: U256, b"http://www.sput.nl/~rob/sirius.html", U256::from_words([0x7a37f04cf72e6eb5, 0xdbbca0caf09e18b5, 0x598fac37c8c87390, 0x3885280be4df6ce6]),
+  fnv0_256_test_174: U256, b"http://www.systemexperts.com/", U256::from_words([0xbe4534f42da1ba9a, 0x970c309d00f63fa8, 0xf2de11f919efd4e7, 0x357f9e1af533d12f]),
+  fnv0_256_test_175: U256, b"http://www.tq-international.com/phpBB3/index.php", U256::from_words([0x148ab39a4217389b, 0x6cc554704db5631b, 0xdb48d45757496121, 0x17f24c5537b40dfd]),
+  fnv0_256_test_176: U256, b"http://www.travelquesttours.com/index.htm", U256::from_words([0x6a8bb42d9b7cf754, 0x4eb498fcdaef6253, 0x254f8472e4a10de8, 0x259b7035272f4989]),
+  fnv0_256_test_177: U256, b"http://www.wunderground.com/global/stations/89606.html", U256::from_words([0x08482a64890647c8, 0xef68d41a35f2fd71, 0x6813c5323fd517de, 0xfdda5faaa5cc6143]),
+  fnv0_256_test_178: U256, &repeat(b"21701", 10), U256::from_words([0xc879b0261928f6b3, 0x38c5d36287dcef42, 0x81a9ef883e31501c, 0xa18064833c7151d6]),
+  fnv0_256_test_179: U256, &repeat(b"M21701", 10), U256::from_words([0x6d5a7f3c476ce6d1, 0x75f9d31ec133c9e7, 0x9b090c5dbc37f167, 0x52de38c88ed23cd8]),
+  fnv0_256_test_180: U256, &repeat(b"2^21701-1", 10), U256::from_words([0x74407a0ba92f5eaa, 0xa4d8ab924bc024bb, 0x88edf244a7d31f1b, 0x1354a6596aaeeabe]),
+  fnv0_256_test_181: U256, &repeat(b"\x54\xc5", 10), U256::from_words([0x0281da12cd1911e1, 0xd72dd6a3f3070f5c, 0xba0032c4ac296828, 0x6d8655ff74e2ae58]),
+  fnv0_256_test_182: U256, &repeat(b"\xc5\x54", 10), U256::from_words([0xc0014c5e4f6baa71, 0x7f9edb8020d5aef0, 0x52c182c7d32dd935, 0x11890e95bbd8bfc8]),
+  fnv0_256_test_183: U256, &repeat(b"23209", 10), U256::from_words([0xe07954c48349a208, 0xc9720e5bd4363cc7, 0xc86888aac51d6bb2, 0xe617aac02a6cf600]),
+  fnv0_256_test_184: U256, &repeat(b"M23209", 10), U256::from_words([0xc9f2d2880efc9c5a, 0xc3baaf7f91ab798c, 0xd810b4a7e0693408, 0xf958774a5f06d3dc]),
+  fnv0_256_test_185: U256, &repeat(b"2^23209-1", 10), U256::from_words([0xd00d7c93d942d8b7, 0xe518167e6cc243e4, 0x66e685dcafe43fbc, 0x26f530b6e90e4e24]),
+  fnv0_256_test_186: U256, &repeat(b"\x5a\xa9", 10), U256::from_words([0xd683f259a4548f29, 0x2b5a46aebbd9d94c, 0xaa0a27831623f58c, 0xab9f196b856e9a54]),
+  fnv0_256_test_187: U256, &repeat(b"\xa9\x5a", 10), U256::from_words([0xd93de6a5bf0b65d1, 0xe8b03748aea6ba10, 0xd2fe934aaaff4120, 0xc5823359e7316bf4]),
+  fnv0_256_test_188: U256, &repeat(b"391581216093", 10), U256::from_words([0x654dd2bf6361f85d, 0xbac2588598f2025a, 0x8f4a00bf731cc018, 0x9d4b5a1657feeb04]),
+  fnv0_256_test_189: U256, &repeat(b"391581*2^216093-1", 10), U256::from_words([0xa4c73345438faf25, 0x83981f759ef917f1, 0x3432d51a0c145ecf, 0x724f5449df4a2680]),
+  fnv0_256_test_190: U256, &repeat(b"\x05\xf9\x9d\x03\x4c\x81", 10), U256::from_words([0xbe081c49c9d4da74, 0x0844211421f2deee, 0x0a1657ac892e4f6e, 0x78d48d2b02a52b30]),
+  fnv0_256_test_191: U256, &repeat(b"FEDCBA9876543210", 10), U256::from_words([0x1d46eed9b3bca62d, 0xed23dfff5751497e, 0x55508292df6e1b96, 0x46b0ec69835ab6a0]),
+  fnv0_256_test_192: U256, &repeat(b"\xfe\xdc\xba\x98\x76\x54\x32\x10", 10), U256::from_words([0xa455e6c5ddb85469, 0x8343ce42ddeba7f5, 0x3508d9f55a9ab1f3, 0x7303de76a7759bc0]),
+  fnv0_256_test_193: U256, &repeat(b"EFCDAB8967452301", 10), U256::from_words([0xdd55bc7230d661db, 0xd1d96b295f9ab34f, 0x5e8ebc693322b01e, 0x3e61906ff229e7b0]),
+  fnv0_256_test_194: U256, &repeat(b"\xef\xcd\xab\x89\x67\x45\x23\x01", 10), U256::from_words([0xae972b2800dbdd31, 0x8ad97802269e6dfe, 0xa24ae0bd1b802e3c, 0xbd60a24085ef1740]),
+  fnv0_256_test_195: U256, &repeat(b"0123456789ABCDEF", 10), U256::from_words([0x1d2a3310ce36e1c0, 0xb66e3a6f08f8561c, 0x691af5b4184c4c1d, 0xb18de283950f67d0]),
+  fnv0_256_test_196: U256, &repeat(b"\x01\x23\x45\x67\x89\xab\xcd\xef", 10), U256::from_words([0x34bf341ce23bb5e2, 0x6a6f663ae73c0a10, 0x7c123f2894ddf9cc, 0x7ebaa773f77b50c0]),
+  fnv0_256_test_197: U256, &repeat(b"1032547698BADCFE", 10), U256::from_words([0xa6beac7fdda77c69, 0x6159717eb49b8853, 0x9185445e9a695b79, 0x2ed9e73808378ba0]),
+  fnv0_256_test_198: U256, &repeat(b"\x10\x32\x54\x76\x98\xba\xdc\xfe", 10), U256::from_words([0x174466684ee65855, 0x20ba167ec5e333c9, 0xd0113fe4585efe1b, 0x00816b293aca8140]),
+  fnv0_256_test_199: U256, &repeat(b"\x00", 500), U256::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]),
+  fnv0_256_test_200: U256, &repeat(b"\x07", 500), U256::from_words([0x9651ca7b3767646e, 0xce23fba62c5e3d8f, 0x68a8e29ca1c177d2, 0xc0566b7bc5d15604]),
+  fnv0_256_test_201: U256, &repeat(b"~", 500), U256::from_words([0x8dd556fe307162eb, 0xdfb1befee57ca1d8, 0x5486798d28271655, 0x80b9e3fec826d108]),
+  fnv0_256_test_202: U256, &repeat(b"\x7f", 500), U256::from_words([0x8a45b1b97474529a, 0xe5e2292f2c867194, 0x76d9b5614d1c3694, 0x0bc9eaab4fa10084]),
+  fnv0_512_test_0: U512, b"", U512::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]),
+  fnv0_512_test_1: U512, b"a", U512::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000061]),
+  fnv0_512_test_2: U512, b"b", U512::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000062]),
+  fnv0_512_test_3: U512, b"c", U512::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000063]),
+  fnv0_512_test_4: U512, b"d", U512::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000064]),
+  fnv0_512_test_5: U512, b"e", U512::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000065]),
+  fnv0_512_test_6: U512, b"f", U512::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000066]),
+  fnv0_512_test_7: U512, b"fo", U512::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000066000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000000088c5]),
+  fnv0_512_test_8: U512, b"foo", U512::from_words([0x0000000000000000, 0x0000000000000000, 0x000001116f000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000b73f9c]),
+  fnv0_512_test_9: U512, b"foob", U512::from_words([0x0000000000000000, 0x0000000000000000, 0x0002259b55000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000f5863a66]),
+  fnv0_512_test_10: U512, b"fooba", U512::from_words([0x0000000000000000, 0x0000000000000000, 0x03d5e95949000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000148f6d83ecb]),
+  fnv0_512_test_11: U512, b"foobar", U512::from_words([0x0000000000000000, 0x0000000000000006, 0x6c927edf9a000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0001b8c2bbbc218f]),
+  fnv0_512_test_12: U512, ::std::ffi::CString::new("").unwrap().as_bytes_with_nul(), U512::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]),
+  fnv0_512_test_13: U512, ::std::ffi::CString::new("a").unwrap().as_bytes_with_nul(), U512::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000061000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000000081f7]),
+  fnv0_512_test_14: U512, ::std::ffi::CString::new("b").unwrap().as_bytes_with_nul(), U512::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000062000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000000000000834e]),
+  fnv0_512_test_15: U512, ::std::ffi::CString::new("c").unwrap().as_bytes_with_nul(), U512::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000063000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000000084a5]),
+  fnv0_512_test_16: U512, ::std::ffi::CString::new("d").unwrap().as_bytes_with_nul(), U512::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000064000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000000085fc]),
+  fnv0_512_test_17: U512, ::std::ffi::CString::new("e").unwrap().as_bytes_with_nul(), U512::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000065000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000008753]),
+  fnv0_512_test_18: U512, ::std::ffi::CString::new("f").unwrap().as_bytes_with_nul(), U512::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000066000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000000088aa]),
+  fnv0_512_test_19: U512, ::std::ffi::CString::new("fo").unwrap().as_bytes_with_nul(), U512::from_words([0x0000000000000000, 0x0000000000000000, 0x000001116f000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000b73ff3]),
+  fnv0_512_test_20: U512, ::std::ffi::CString::new("foo").unwrap().as_bytes_with_nul(), U512::from_words([0x0000000000000000, 0x0000000000000000, 0x0002259b55000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000f5863a04]),
+  fnv0_512_test_21: U512, ::std::ffi::CString::new("foob").unwrap().as_bytes_with_nul(), U512::from_words([0x0000000000000000, 0x0000000000000000, 0x03d5e95949000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000148f6d83eaa]),
+  fnv0_512_test_22: U512, ::std::ffi::CString::new("fooba").unwrap().as_bytes_with_nul(), U512::from_words([0x0000000000000000, 0x0000000000000006, 0x6c927edf9a000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0001b8c2bbbc21fd]),
+  fnv0_512_test_23: U512, ::std::ffi::CString::new("foobar").unwrap().as_bytes_with_nul(), U512::from_words([0x0000000000000000, 0x0000000000000a54, 0x3b03b9b8e5000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x024e8ce98910f699]),
+  fnv0_512_test_24: U512, b"ch", U512::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000063000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000000084cd]),
+  fnv0_512_test_25: U512, b"cho", U512::from_words([0x0000000000000000, 0x0000000000000000, 0x0000010972000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000b1eec4]),
+  fnv0_512_test_26: U512, b"chon", U512::from_words([0x0000000000000000, 0x0000000000000000, 0x0002159682000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000ee66e8f2]),
+  fnv0_512_test_27: U512, b"chong", U512::from_words([0x0000000000000000, 0x0000000000000000, 0x03b9539120000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000013f6be21c59]),
+  fnv0_512_test_28: U512, b"chongo", U512::from_words([0x0000000000000000, 0x0000000000000006, 0x3cbad98e39000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0001abf98bf3fb50]),
+  fnv0_512_test_29: U512, b"chongo ", U512::from_words([0x0000000000000000, 0x0000000000000a07, 0x57e57189af000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x023d6b5a83e5b810]),
+  fnv0_512_test_30: U512, b"chongo w", U512::from_words([0x0000000000000000, 0x00000000000fad42, 0x1eef053189000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000003, 0x004ad646b8c99d07]),
+  fnv0_512_test_31: U512, b"chongo wa", U512::from_words([0x0000000000000000, 0x0000000018016e6d, 0xb8f8befb96000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000405, 0x644518c196216400]),
+  fnv0_512_test_32: U512, b"chongo was", U512::from_words([0x0000000000000000, 0x000000242f4f3a1b, 0x96de0479fa000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000056339, 0x58942b6026bcfc73]),
+  fnv0_512_test_33: U512, b"chongo was ", U512::from_words([0x0000000000000000, 0x000035de9c7f6f22, 0x839ebc6a69000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000000000737f1d5, 0xae861dd3e7363e35]),
+  fnv0_512_test_34: U512, b"chongo was h", U512::from_words([0x0000000000000000, 0x004f6535846c6d5c, 0x2d95a8d0e4000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000009abf5054c, 0xd5b1f6eac9ad596b]),
+  fnv0_512_test_35: U512, b"chongo was he", U512::from_words([0x0000000000000000, 0x740c8fb9ba1c3877, 0xfe4edd3ae7000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000cf5654a19f2, 0x5171d4943742ce38]),
+  fnv0_512_test_36: U512, b"chongo was her", U512::from_words([0x00000000000000a8, 0x7239dbf24e211a9a, 0x4fe1acb9b9000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00115ccab648c3ab, 0x1f83d2960a824d7a]),
+  fnv0_512_test_37: U512, b"chongo was here", U512::from_words([0x000000000000f30d, 0xd63bfa6a597a2893, 0x9d68ef2459000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x1743539a3b7e2a47, 0x399f27081495ce13]),
+  fnv0_512_test_38: U512, b"chongo was here!", U512::from_words([0x00000000015ceadd, 0xa49802a829e5fcee, 0xefacff8152000000, 0x0000000000000000, 0x0000000000000000, 0x000000000000001f, 0x2b3503a5b60aa56e, 0x343d4bd394b71b54]),
+  fnv0_512_test_39: U512, b"chongo was here!\n", U512::from_words([0x00000001f2a9e3fb, 0x2d6199f5915a216e, 0xf45f0d6032000000, 0x0000000000000000, 0x0000000000000000, 0x00000000000029c2, 0xe407e306e843a6a7, 0xfe20987c41559d86]),
+  fnv0_512_test_40: U512, ::std::ffi::CString::new("ch").unwrap().as_bytes_with_nul(), U512::from_words([0x0000000000000000, 0x0000000000000000, 0x0000010972000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000b1eeab]),
+  fnv0_512_test_41: U512, ::std::ffi::CString::new("cho").unwrap().as_bytes_with_nul(), U512::from_words([0x0000000000000000, 0x0000000000000000, 0x0002159682000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000ee66e89c]),
+  fnv0_512_test_42: U512, ::std::ffi::CString::new("chon").unwrap().as_bytes_with_nul(), U512::from_words([0x0000000000000000, 0x0000000000000000, 0x03b9539120000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000013f6be21c3e]),
+  fnv0_512_test_43: U512, ::std::ffi::CString::new("chong").unwrap().as_bytes_with_nul(), U512::from_words([0x0000000000000000, 0x0000000000000006, 0x3cbad98e39000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0001abf98bf3fb3f]),
+  fnv0_512_test_44: U512, ::std::ffi::CString::new("chongo").unwrap().as_bytes_with_nul(), U512::from_words([0x0000000000000000, 0x0000000000000a07, 0x57e57189af000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x023d6b5a83e5b830]),
+  fnv0_512_test_45: U512, ::std::ffi::CString::new("chongo ").unwrap().as_bytes_with_nul(), U512::from_words([0x0000000000000000, 0x00000000000fad42, 0x1eef053189000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000003, 0x004ad646b8c99d70]),
+  fnv0_512_test_46: U512, ::std::ffi::CString::new("chongo w").unwrap().as_bytes_with_nul(), U512::from_words([0x0000000000000000, 0x0000000018016e6d, 0xb8f8befb96000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000405, 0x644518c196216461]),
+  fnv0_512_test_47: U512, ::std::ffi::CString::new("chongo wa").unwrap().as_bytes_with_nul(), U512::from_words([0x0000000000000000, 0x000000242f4f3a1b, 0x96de0479fa000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000056339, 0x58942b6026bcfc00]),
+  fnv0_512_test_48: U512, ::std::ffi::CString::new("chongo was").unwrap().as_bytes_with_nul(), U512::from_words([0x0000000000000000, 0x000035de9c7f6f22, 0x839ebc6a69000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000000000737f1d5, 0xae861dd3e7363e15]),
+  fnv0_512_test_49: U512, ::std::ffi::CString::new("chongo was ").unwrap().as_bytes_with_nul(), U512::from_words([0x0000000000000000, 0x004f6535846c6d5c, 0x2d95a8d0e4000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000009abf5054c, 0xd5b1f6eac9ad5903]),
+  fnv0_512_test_50: U512, ::std::ffi::CString::new("chongo was h").unwrap().as_bytes_with_nul(), U512::from_words([0x0000000000000000, 0x740c8fb9ba1c3877, 0xfe4edd3ae7000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000cf5654a19f2, 0x5171d4943742ce5d]),
+  fnv0_512_test_51: U512, ::std::ffi::CString::new("chongo was he").unwrap().as_bytes_with_nul(), U512::from_words([0x00000000000000a8, 0x7239dbf24e211a9a, 0x4fe1acb9b9000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00115ccab648c3ab, 0x1f83d2960a824d08]),
+  fnv0_512_test_52: U512, ::std::ffi::CString::new("chongo was her").unwrap().as_bytes_with_nul(), U512::from_words([0x000000000000f30d, 0xd63bfa6a597a2893, 0x9d68ef2459000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x1743539a3b7e2a47, 0x399f27081495ce76]),
+  fnv0_512_test_53: U512, ::std::ffi::CString::new("chongo was here").unwrap().as_bytes_with_nul(), U512::from_words([0x00000000015ceadd, 0xa49802a829e5fcee, 0xefacff8152000000, 0x0000000000000000, 0x0000000000000000, 0x000000000000001f, 0x2b3503a5b60aa56e, 0x343d4bd394b71b75]),
+  fnv0_512_test_54: U512, ::std::ffi::CString::new("chongo was here!").unwrap().as_bytes_with_nul(), U512::from_words([0x00000001f2a9e3fb, 0x2d6199f5915a216e, 0xf45f0d6032000000, 0x0000000000000000, 0x0000000000000000, 0x00000000000029c2, 0xe407e306e843a6a7, 0xfe20987c41559d8c]),
+  fnv0_512_test_55: U512, ::std::ffi::CString::new("chongo was here!\n").unwrap().as_bytes_with_nul(), U512::from_words([0x000002c5e4847d6c, 0xd4ad8bac67c0ec41, 0xe79c418084000000, 0x0000000000000000, 0x0000000000000000, 0x000000000037f41f, 0x86912e4132a44b15, 0x7dac4e7b89b60e8a]),
+  fnv0_512_test_56: U512, b"cu", U512::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000063000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000000084d0]),
+  fnv0_512_test_57: U512, b"cur", U512::from_words([0x0000000000000000, 0x0000000000000000, 0x0000010975000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000b1f2c2]),
+  fnv0_512_test_58: U512, b"curd", U512::from_words([0x0000000000000000, 0x0000000000000000, 0x0002159e85000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000ee6c418a]),
+  fnv0_512_test_59: U512, b"curds", U512::from_words([0x0000000000000000, 0x0000000000000000, 0x03b963a5bd000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000013f730bcf95]),
+  fnv0_512_test_60: U512, b"curds ", U512::from_words([0x0000000000000000, 0x0000000000000006, 0x3cd78edfd0000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0001ac0324d32083]),
+  fnv0_512_test_61: U512, b"curds a", U512::from_words([0x0000000000000000, 0x0000000000000a07, 0x87f5410033000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x023d783656e08fe4]),
+  fnv0_512_test_62: U512, b"curds an", U512::from_words([0x0000000000000000, 0x00000000000fad8f, 0x5ff0f7d439000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000003, 0x005c10ce66e0ca12]),
+  fnv0_512_test_63: U512, b"curds and", U512::from_words([0x0000000000000000, 0x000000001801e72a, 0x5a42ee2271000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000405, 0x7b5a848bd72ebe7a]),
+  fnv0_512_test_64: U512, b"curds and ", U512::from_words([0x0000000000000000, 0x0000002430081443, 0x7b843ee3e1000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000056358, 0x4647975d4fa13556]),
+  fnv0_512_test_65: U512, b"curds and w", U512::from_words([0x0000000000000000, 0x000035dfb3196e01, 0xdb7fe487cd000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000007381b46, 0x29ebce05b0fe764d]),
+  fnv0_512_test_66: U512, b"curds and wh", U512::from_words([0x0000000000000000, 0x004f66d43d3c504b, 0x1e0c3069f8000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000009ac2c8b02, 0x2af109a024f08143]),
+  fnv0_512_test_67: U512, b"curds and whe", U512::from_words([0x0000000000000000, 0x740ef2e90dfa85ae, 0xe279ce7c8b000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000cf5afae3fe7, 0x88f3e5917e3d30a0]),
+  fnv0_512_test_68: U512, b"curds and whey", U512::from_words([0x00000000000000a8, 0x75b72481a2321137, 0x02b1e60edd000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00115d2e62779f38, 0x7ec895f023fc2619]),
+  fnv0_512_test_69: U512, b"curds and whey\n", U512::from_words([0x000000000000f312, 0xe6c4614f8993d94a, 0x8c7f3a1034000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x1743d925ee4654b1, 0xdec0e4c036d70b75]),
+  fnv0_512_test_70: U512, ::std::ffi::CString::new("cu").unwrap().as_bytes_with_nul(), U512::from_words([0x0000000000000000, 0x0000000000000000, 0x0000010975000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000b1f2b0]),
+  fnv0_512_test_71: U512, ::std::ffi::CString::new("cur").unwrap().as_bytes_with_nul(), U512::from_words([0x0000000000000000, 0x0000000000000000, 0x0002159e85000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000ee6c41ee]),
+  fnv0_512_test_72: U512, ::std::ffi::CString::new("curd").unwrap().as_bytes_with_nul(), U512::from_words([0x0000000000000000, 0x0000000000000000, 0x03b963a5bd000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000013f730bcfe6]),
+  fnv0_512_test_73: U512, ::std::ffi::CString::new("curds").unwrap().as_bytes_with_nul(), U512::from_words([0x0000000000000000, 0x0000000000000006, 0x3cd78edfd0000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0001ac0324d320a3]),
+  fnv0_512_test_74: U512, ::std::ffi::CString::new("curds ").unwrap().as_bytes_with_nul(), U512::from_words([0x0000000000000000, 0x0000000000000a07, 0x87f5410033000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x023d783656e08f85]),
+  fnv0_512_test_75: U512, ::std::ffi::CString::new("curds a").unwrap().as_bytes_with_nul(), U512::from_words([0x0000000000000000, 0x00000000000fad8f, 0x5ff0f7d439000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000003, 0x005c10ce66e0ca7c]),
+  fnv0_512_test_76: U512, ::std::ffi::CString::new("curds an").unwrap().as_bytes_with_nul(), U512::from_words([0x0000000000000000, 0x000000001801e72a, 0x5a42ee2271000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000405, 0x7b5a848bd72ebe1e]),
+  fnv0_512_test_77: U512, ::std::ffi::CString::new("curds and").unwrap().as_bytes_with_nul(), U512::from_words([0x0000000000000000, 0x0000002430081443, 0x7b843ee3e1000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000056358, 0x4647975d4fa13576]),
+  fnv0_512_test_78: U512, ::std::ffi::CString::new("curds and ").unwrap().as_bytes_with_nul(), U512::from_words([0x0000000000000000, 0x000035dfb3196e01, 0xdb7fe487cd000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000007381b46, 0x29ebce05b0fe763a]),
+  fnv0_512_test_79: U512, ::std::ffi::CString::new("curds and w").unwrap().as_bytes_with_nul(), U512::from_words([0x0000000000000000, 0x004f66d43d3c504b, 0x1e0c3069f8000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000009ac2c8b02, 0x2af109a024f0812b]),
+  fnv0_512_test_80: U512, ::std::ffi::CString::new("curds and wh").unwrap().as_bytes_with_nul(), U512::from_words([0x0000000000000000, 0x740ef2e90dfa85ae, 0xe279ce7c8b000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000cf5afae3fe7, 0x88f3e5917e3d30c5]),
+  fnv0_512_test_81: U512, ::std::ffi::CString::new("curds and whe").unwrap().as_bytes_with_nul(), U512::from_words([0x00000000000000a8, 0x75b72481a2321137, 0x02b1e60edd000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00115d2e62779f38, 0x7ec895f023fc2660]),
+  fnv0_512_test_82: U512, ::std::ffi::CString::new("curds and whey").unwrap().as_bytes_with_nul(), U512::from_words([0x000000000000f312, 0xe6c4614f8993d94a, 0x8c7f3a1034000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x1743d925ee4654b1, 0xdec0e4c036d70b7f]),
+  fnv0_512_test_83: U512, ::std::ffi::CString::new("curds and whey\n").unwrap().as_bytes_with_nul(), U512::from_words([0x00000000015cf22c, 0x570ca7e606f6e3c6, 0xfeada2c121000000, 0x0000000000000000, 0x0000000000000000, 0x000000000000001f, 0x2be7f1d2403b7a51, 0x74727d897a2059c3]),
+  fnv0_512_test_84: U512, b"hi", U512::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000068000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000008b31]),
+  fnv0_512_test_85: U512, ::std::ffi::CString::new("hi").unwrap().as_bytes_with_nul(), U512::from_words([0x0000000000000000, 0x0000000000000000, 0x0000011689000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000ba7ea7]),
+  fnv0_512_test_86: U512, b"hello", U512::from_words([0x0000000000000000, 0x0000000000000000, 0x03e805b5f3000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000014ee7c6f514]),
+  fnv0_512_test_87: U512, ::std::ffi::CString::new("hello").unwrap().as_bytes_with_nul(), U512::from_words([0x0000000000000000, 0x0000000000000006, 0x8ac76dbda9000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0001c0b88b925dcc]),
+  fnv0_512_test_88: U512, b"\xff\x00\x00\x01", U512::from_words([0x0000000000000000, 0x0000000000000000, 0x00055d504d000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000002655787b8]),
+  fnv0_512_test_89: U512, b"\x01\x00\x00\xff", U512::from_words([0x0000000000000000, 0x0000000000000000, 0x00000562b3000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000000000267bfb8]),
+  fnv0_512_test_90: U512, b"\xff\x00\x00\x02", U512::from_words([0x0000000000000000, 0x0000000000000000, 0x00055d504d000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000002655787bb]),
+  fnv0_512_test_91: U512, b"\x02\x00\x00\xff", U512::from_words([0x0000000000000000, 0x0000000000000000, 0x00000ac566000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000004cf7e71]),
+  fnv0_512_test_92: U512, b"\xff\x00\x00\x03", U512::from_words([0x0000000000000000, 0x0000000000000000, 0x00055d504d000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000002655787ba]),
+  fnv0_512_test_93: U512, b"\x03\x00\x00\xff", U512::from_words([0x0000000000000000, 0x0000000000000000, 0x0000102819000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000007373d2a]),
+  fnv0_512_test_94: U512, b"\xff\x00\x00\x04", U512::from_words([0x0000000000000000, 0x0000000000000000, 0x00055d504d000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000002655787bd]),
+  fnv0_512_test_95: U512, b"\x04\x00\x00\xff", U512::from_words([0x0000000000000000, 0x0000000000000000, 0x0000158acc000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000099efde3]),
+  fnv0_512_test_96: U512, b"\x40\x51\x4e\x44", U512::from_words([0x0000000000000000, 0x0000000000000000, 0x0001582e90000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000999b1f4b]),
+  fnv0_512_test_97: U512, b"\x44\x4e\x51\x40", U512::from_words([0x0000000000000000, 0x0000000000000000, 0x00016ec7f1000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000a3ef55d9]),
+  fnv0_512_test_98: U512, b"\x40\x51\x4e\x4a", U512::from_words([0x0000000000000000, 0x0000000000000000, 0x0001582e90000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000999b1f45]),
+  fnv0_512_test_99: U512, b"\x4a\x4e\x51\x40", U512::from_words([0x0000000000000000, 0x0000000000000000, 0x00018f384b000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000b2735c4f]),
+  fnv0_512_test_100: U512, b"\x40\x51\x4e\x54", U512::from_words([0x0000000000000000, 0x0000000000000000, 0x0001582e90000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000999b1f5b]),
+  fnv0_512_test_101: U512, b"\x54\x4e\x51\x40", U512::from_words([0x0000000000000000, 0x0000000000000000, 0x0001c4f341000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000ca6b74a9]),
+  fnv0_512_test_102: U512, b"127.0.0.1", U512::from_words([0x0000000000000000, 0x000000000bdbb020, 0x27f9cb2d46000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000000001fc, 0x5999091c5e8c317f]),
+  fnv0_512_test_103: U512, ::std::ffi::CString::new("127.0.0.1").unwrap().as_bytes_with_nul(), U512::from_words([0x0000000000000000, 0x00000011dfb2941e, 0xac0dc5da49000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000000000002a91c, 0x0c0b3502add65129]),
+  fnv0_512_test_104: U512, b"127.0.0.2", U512::from_words([0x0000000000000000, 0x000000000bdbb020, 0x27f9cb2d46000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000000001fc, 0x5999091c5e8c317c]),
+  fnv0_512_test_105: U512, ::std::ffi::CString::new("127.0.0.2").unwrap().as_bytes_with_nul(), U512::from_words([0x0000000000000000, 0x00000011dfb2941e, 0xac0dc5da46000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000000000002a91c, 0x0c0b3502add64d24]),
+  fnv0_512_test_106: U512, b"127.0.0.3", U512::from_words([0x0000000000000000, 0x000000000bdbb020, 0x27f9cb2d46000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000000001fc, 0x5999091c5e8c317d]),
+  fnv0_512_test_107: U512, ::std::ffi::CString::new("127.0.0.3").unwrap().as_bytes_with_nul(), U512::from_words([0x0000000000000000, 0x00000011dfb2941e, 0xac0dc5da47000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000000000002a91c, 0x0c0b3502add64e7b]),
+  fnv0_512_test_108: U512, b"64.81.78.68", U512::from_words([0x0000000000000000, 0x00001d6174394301, 0x235765c94e000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000003efdd86, 0x571ec523e2d28038]),
+  fnv0_512_test_109: U512, ::std::ffi::CString::new("64.81.78.68").unwrap().as_bytes_with_nul(), U512::from_words([0x0000000000000000, 0x002b4d703f0fe54b, 0x7dfc3337ba000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000000054661cefe, 0xba3a2314e809cb08]),
+  fnv0_512_test_110: U512, b"64.81.78.74", U512::from_words([0x0000000000000000, 0x00001d6174394301, 0x235765c94f000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000003efdd86, 0x571ec523e2d28163]),
+  fnv0_512_test_111: U512, ::std::ffi::CString::new("64.81.78.74").unwrap().as_bytes_with_nul(), U512::from_words([0x0000000000000000, 0x002b4d703f0fe54b, 0x7dfc333a3c000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000000054661cefe, 0xba3a2314e80b5ba5]),
+  fnv0_512_test_112: U512, b"64.81.78.84", U512::from_words([0x0000000000000000, 0x00001d6174394301, 0x235765c95c000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000003efdd86, 0x571ec523e2d292f6]),
+  fnv0_512_test_113: U512, ::std::ffi::CString::new("64.81.78.84").unwrap().as_bytes_with_nul(), U512::from_words([0x0000000000000000, 0x002b4d703f0fe54b, 0x7dfc335d3a000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000000054661cefe, 0xba3a2314e822e79a]),
+  fnv0_512_test_114: U512, b"feedface", U512::from_words([0x0000000000000000, 0x000000000010266e, 0xad55e3391c000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000003, 0x17799f2efbd0cfd7]),
+  fnv0_512_test_115: U512, ::std::ffi::CString::new("feedface").unwrap().as_bytes_with_nul(), U512::from_words([0x0000000000000000, 0x0000000018baf7e9, 0x6d0f42545b000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000424, 0x73f447f364c67911]),
+  fnv0_512_test_116: U512, b"feedfacedaffdeed", U512::from_words([0x00000000016772ec, 0x2d4faa05e5c71f8b, 0xf69d4f3d75000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000020, 0x1bf98c7f597f6126, 0xaf9f6ccd533c83b0]),
+  fnv0_512_test_117: U512, ::std::ffi::CString::new("feedfacedaffdeed").unwrap().as_bytes_with_nul(), U512::from_words([0x0000000201b6f3fd, 0x35164d48047ae3f4, 0x3a1867db73000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000002b05, 0x7b5b3ea0e9ab2ad5, 0x4e9ac71a861470d0]),
+  fnv0_512_test_118: U512, b"feedfacedeadbeef", U512::from_words([0x00000000016772ec, 0x2d4faa05e5c71fe7, 0xf4baf564bc000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000020, 0x1bf98c7f597f6126, 0xafb372ce249dee57]),
+  fnv0_512_test_119: U512, ::std::ffi::CString::new("feedfacedeadbeef").unwrap().as_bytes_with_nul(), U512::from_words([0x0000000201b6f3fd, 0x35164d48047b733b, 0xb4a367e63b000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000002b05, 0x7b5b3ea0e9ab2ad5, 0x696ed2330f9a5691]),
+  fnv0_512_test_120: U512, b"line 1\nline 2\nline 3", U512::from_words([0x0611b5d9bb4454f3, 0x931d8463a96b80bc, 0x9135fb4e0c000000, 0x0000000000000000, 0x0000000000000000, 0x0000006d9236b00a, 0x1882d795527b4ee9, 0x628f81a2b3ff0a5e]),
+  fnv0_512_test_121: U512, b"chongo <Landon Curt Noll> /\\../\\", U512::from_words([0xb86db0b1171f4416, 0xdca1e50f309990ac, 0xac87d059c9000000, 0x0000000000000d21, 0xe948f68a34c192f6, 0x2ea79bc942dbe7ce, 0x182036415f56e34b, 0xac982aac4afe9fd9]),
+  fnv0_512_test_122: U512, ::std::ffi::CString::new("chongo <Landon Curt Noll> /\\../\\").unwrap().as_bytes_with_nul(), U512::from_words([0xe43a992dc8fc5ad7, 0xde493e3d696d6f85, 0xd64326ec28000000, 0x000000000011986f, 0x90c2532caf5be7d8, 0x8291baa894a39522, 0x5328b196bd6a8a64, 0x3fe12cd87b282bbf]),
+  fnv0_512_test_123: U512, b"chongo (Landon Curt Noll) /\\../\\", U512::from_words([0x1111c00befe664a4, 0x589a4858ac3bd43a, 0x24a770112c000000, 0x0000000000000d21, 0xe948f68a34c1c75e, 0x9642cfe6e4ee183a, 0xc52bd549d131472b, 0xac0d227bcc51d328]),
+  fnv0_512_test_124: U512, ::std::ffi::CString::new("chongo (Landon Curt Noll) /\\../\\").unwrap().as_bytes_with_nul(), U512::from_words([0xc5ad3e16a8760408, 0x00882015efd56709, 0x982378d51c000000, 0x000000000011986f, 0x90c2532cafa21fbb, 0x53848e5cbb0276be, 0x2dbac5e749065b83, 0x859933dec1a1ea98]),
+  fnv0_512_test_125: U512, b"http://antwrp.gsfc.nasa.gov/apod/astropix.html", U512::from_words([0x2f9c7ad4b87ed91f, 0x19b0e48da29114ed, 0xfa1def7b0bace6fc, 0xb997769c6578a437, 0x5d7b1acfdc7b920b, 0xd65b5977f501bcb5, 0x2175fc7acb3a4d00, 0xd5c5e3ebb7beb8b9]),
+  fnv0_512_test_126: U512, b"http://en.wikipedia.org/wiki/Fowler_Noll_Vo_hash", U512::from_words([0x2bd938e88b089f83, 0x2afd12e17a7f8a36, 0xbf1d27c66d1db939, 0x9469d2c9831b099e, 0xd8b94756f2c25dd4, 0x8b4d1781a122ac05, 0x227a8ae6de032e5b, 0xbae79c179610ff0c]),
+  fnv0_512_test_127: U512, b"http://epod.usra.edu/", U512::from_words([0x396c58bfad4f6740, 0x2f5528bbc182c66a, 0x60a50a2aff000000, 0x0000000000000000, 0x0000000000000000, 0x00008d09bea6b24c, 0xa4babe8ababe65d5, 0x8ff969ec710c9bc4]),
+  fnv0_512_test_128: U512, b"http://exoplanet.eu/", U512::from_words([0x05d4d46d4812742b, 0xf48361386f234ee2, 0x57986e62bc000000, 0x0000000000000000, 0x0000000000000000, 0x0000006943ba541c, 0x95c05b9d393d7ad2, 0xb6c95203820842bf]),
+  fnv0_512_test_129: U512, b"http://hvo.wr.usgs.gov/cam3/", U512::from_words([0x5b621a363fbafb9c, 0xaf5f2e452748cd92, 0x5fc4a43c55000000, 0x0000000000000000, 0x000004453d621124, 0x106f196f913af7d9, 0xb006127d9d458324, 0x767cd8a9a74cc16e]),
+  fnv0_512_test_130: U512, b"http://hvo.wr.usgs.gov/cams/HMcam/", U512::from_words([0x5458f7e07bd60021, 0x5dde36df8ca205ec, 0xfa31badc69000000, 0x0000000018b4b4c7, 0x09ce0e4b9938b777, 0xdfc4cb5254f763db, 0x6af0fee7143acbfa, 0xfab4d740fbd0fe47]),
+  fnv0_512_test_131: U512, b"http://hvo.wr.usgs.gov/kilauea/update/deformation.html", U512::from_words([0xb39f6cb693c4f013, 0x359f466a5cd0e75b, 0x8e7c6c1161eef1f3, 0x8e48297517d4a045, 0x4bb1ca469150352a, 0x048ea5e72ffe4acc, 0xffe7fad4d5fac33d, 0x270cd5a9a1ea39ed]),
+  fnv0_512_test_132: U512, b"http://hvo.wr.usgs.gov/kilauea/update/images.html", U512::from_words([0xdf3348065c39d4b9, 0x0d3dc326a2cf2300, 0x321c787d0a17e1f5, 0x90ea7ded95733931, 0x150e0186fc504c1b, 0x7fb3075f2671bccf, 0x0d8aa8fc17f18ca6, 0x7fcfcb8c7f8b5101]),
+  fnv0_512_test_133: U512, b"http://hvo.wr.usgs.gov/kilauea/update/maps.html", U512::from_words([0xc48e3666f936c262, 0x4e1f3f99ca25445f, 0x15495ceb05a98674, 0x69fb47b7fad31c60, 0x03cc17adc964e6fe, 0x06d18169d164b2b3, 0xe148331d65b1a2a7, 0x7462950cfe3232a8]),
+  fnv0_512_test_134: U512, b"http://hvo.wr.usgs.gov/volcanowatch/current_issue.html", U512::from_words([0xb8605a40b49febf2, 0x0218de30c4ab3d17, 0x8009ae38efeef1f3, 0x8e48297517d4a048, 0x2edb3056c0708e5d, 0x076784a168cd32b5, 0xabe2f849c14a892b, 0xafc77c7e5c696267]),
+  fnv0_512_test_135: U512, b"http://neo.jpl.nasa.gov/risk/", U512::from_words([0x0dbbbf2b5d07abbd, 0xe4bc754d8a76301d, 0x5a9acdc5f1000000, 0x0000000000000000, 0x0005b8c53e64f752, 0x04e5bbd9e197cb8c, 0x579e69d8bdc5b821, 0x086cf2378a7dd014]),
+  fnv0_512_test_136: U512, b"http://norvig.com/21-days.html", U512::from_words([0x61a95bcec16892a3, 0x4346e18203d43a8b, 0x98e94c630d000000, 0x0000000000000000, 0x07aa904699475ee4, 0x8fd99c0d210c8a67, 0x30814fbd5b1d4002, 0x57650cf7f8ad0cf5]),
+  fnv0_512_test_137: U512, b"http://primes.utm.edu/curios/home.php", U512::from_words([0x9b3b6a4f6dddd0d8, 0x41edcaea76b2398f, 0xcd1b63425e000000, 0x003b6c93f7273188, 0xcd0b6e4cb2d5e934, 0x41a8480fe4cc88ac, 0xc8aec4e3b48bc6d2, 0x4d2265fb4eae47aa]),
+  fnv0_512_test_138: U512, b"http://slashdot.org/", U512::from_words([0x05d4d46d4812742c, 0x05583a68d7c13cda, 0x0287e512e6000000, 0x0000000000000000, 0x0000000000000000, 0x0000006943ba541c, 0x95c05d7f760b2645, 0xaf404f81d7c4494f]),
+  fnv0_512_test_139: U512, b"http://tux.wr.usgs.gov/Maps/155.25-19.5.html", U512::from_words([0x57e221014cb14061, 0x77783d18b5fea71b, 0x74ba8d7f5edbd379, 0x18cded7a420437f3, 0xd914598552729365, 0xa8ba65aee9504a5e, 0xa0c34aa2fb63c9ea, 0xc23b7463d9dbf1e2]),
+  fnv0_512_test_140: U512, b"http://volcano.wr.usgs.gov/kilaueastatus.php", U512::from_words([0xb484d0a88d867e50, 0xd16839499b3b81f6, 0xd1a54fd2e1dbd379, 0x18cf5ccfb1622b74, 0xc6b2cf71ffa29cbc, 0x16c1beec49fb00c2, 0x1923948508057e1b, 0x2f2ba66f091d6ece]),
+  fnv0_512_test_141: U512, b"http://www.avo.alaska.edu/activity/Redoubt.php", U512::from_words([0xb6ad2477a41e3e77, 0x858431baf0b15af7, 0x39d30cab5eace713, 0x484e86a21ced6db1, 0xaf077a9b8606ae7f, 0x5160a78f64b2be9a, 0x22ef12436808414f, 0x805af9881e97813e]),
+  fnv0_512_test_142: U512, b"http://www.dilbert.com/fast/", U512::from_words([0x87c568c2bc44b0a4, 0xf44d51816e394d39, 0x6e4f875268000000, 0x0000000000000000, 0x000004453d621124, 0x106f2d8bf29fae34, 0x9993eb5fbd4ce03b, 0x6e99f436abc9e3d0]),
+  fnv0_512_test_143: U512, b"http://www.fourmilab.ch/gravitation/orbits/", U512::from_words([0x8349278989946c11, 0x90bb53f3d939f1e2, 0xd62373cdf6223a15, 0xff537363ee809d54, 0xaf120f231a32d101, 0x5478fd590ad3d348, 0x19968cda08e37765, 0x1a5f75f819d1a5f1]),
+  fnv0_512_test_144: U512, b"http://www.fpoa.net/", U512::from_words([0x05d4d46d4812742c, 0x09617d37cb0983bd, 0xbaf81ac873000000, 0x0000000000000000, 0x0000000000000000, 0x0000006943ba541c, 0x95c05df1256bde72, 0x9f67cdb517f24587]),
+  fnv0_512_test_145: U512, b"http://www.ioccc.org/index.html", U512::from_words([0x7713f5913efeb786, 0xdecf91c8aca3a9bc, 0x9b9451ea38000000, 0x000000000000000a, 0x45874e975ea02459, 0xed04e973f50bd32d, 0xfd122a477d25ed1b, 0x8975d816a472da38]),
+  fnv0_512_test_146: U512, b"http://www.isthe.com/cgi-bin/number.cgi", U512::from_words([0x7b17c01b2a02b06d, 0x1bbb739cb31fd2de, 0xf09d72344e00006a, 0xad55cbf2430356fa, 0x6b4018481448f7be, 0xd8de2115e9bc6389, 0xcb9c791da149fcb6, 0x95f8ab7568a81d0a]),
+  fnv0_512_test_147: U512, b"http://www.isthe.com/chongo/bio.html", U512::from_words([0x120725dd4dc6d44e, 0x4e1de713ba99bd2d, 0x260a70871a000000, 0x00002c59fe7b4351, 0x01587567b30f5ee2, 0xf4e4fa9fffafa392, 0x8440e9279cdfff14, 0xc1a699525ea8762a]),
+  fnv0_512_test_148: U512, b"http://www.isthe.com/chongo/index.html", U512::from_words([0xc618e51f2aac9732, 0x02a07f45572572e1, 0x4693527ce6000000, 0x4f9e7a4025835e4b, 0x5d9b6dc8fad5aba3, 0x430ed40fbcc3591d, 0x4ace15b579394dc1, 0x30b6560b049c0394]),
+  fnv0_512_test_149: U512, b"http://www.isthe.com/chongo/src/calc/lucas-calc", U512::from_words([0x159b81e017fb78c2, 0xd1c23e63ed1973ff, 0x4606311b1ca99ad5, 0xe13666782b7c0fd0, 0x23d6e8dc36b6a312, 0xe77f6b275c9b6a6c, 0x4173212b990dc8ea, 0x5d5938837fca455c]),
+  fnv0_512_test_150: U512, b"http://www.isthe.com/chongo/tech/astro/venus2004.html", U512::from_words([0x38afe4befa4feb25, 0x66f85f97d1e73535, 0x6a61bdca3f9cf266, 0x778db657a780fd5f, 0x207e8dfe1ff2b539, 0x07811e667a66cfe7, 0xbfd665114234aff9, 0x8e77874627fab436]),
+  fnv0_512_test_151: U512, b"http://www.isthe.com/chongo/tech/astro/vita.html", U512::from_words([0x1b154574d6385f96, 0xf36d5f0805f613a7, 0x65b3c1e03f3e7490, 0xbfe34b0243392fe0, 0x04f1ff0d4eb47e54, 0x2bb891c3a7ec7479, 0x6a99a66287e21410, 0x7c878eaf2332d48f]),
+  fnv0_512_test_152: U512, b"http://www.isthe.com/chongo/tech/comp/c/expert.html", U512::from_words([0xf29c5bbb227b66b1, 0xae5fd44639d8540b, 0x14cc413ddc074e58, 0xff6ffc2a52c04c2d, 0xa560be04fa84de5d, 0x01f9d098cd2b5c83, 0xaa8b5faf5f18d9ab, 0x6923348f650a90af]),
+  fnv0_512_test_153: U512, b"http://www.isthe.com/chongo/tech/comp/calc/index.html", U512::from_words([0x38d2adba9c073920, 0xc81b13bdcaa2b810, 0x5c965034e69cf266, 0x778db657a780fd5f, 0x207e8dfe1ff2b539, 0x07811e68f46f795b, 0x7d8c173f0a7b0a4e, 0x8e86502403d11f07]),
+  fnv0_512_test_154: U512, b"http://www.isthe.com/chongo/tech/comp/fnv/index.html", U512::from_words([0xa84c1317b7012367, 0x8d0388a017eecaea, 0xe9c6ee20d6c9f93e, 0x3f0adcb4dfa61128, 0x949e98aba805ee9d, 0xa5b67cbae518f469, 0x80bc4c04d760f6af, 0x4759da24ca626d54]),
+  fnv0_512_test_155: U512, b"http://www.isthe.com/chongo/tech/math/number/howhigh.html", U512::from_words([0x2ad9006a178a4bbd, 0x4173d3a030f9b13a, 0xbe77954677163903, 0x75b380716bfa627d, 0x4b3310b5dfa98918, 0xd8faad4756054740, 0x70fd0f0d0853ae57, 0x839a44db3526c97a]),
+  fnv0_512_test_156: U512, b"http://www.isthe.com/chongo/tech/math/number/number.html", U512::from_words([0x3bf8486275bcd974, 0x305b990d1e0a6e44, 0x8111214ec78ae30e, 0xefec5f2961574ea7, 0x8c88bb624d86d69a, 0x916cf3d130047e57, 0x82f11076b4f38bde, 0x127c5f88c88a6b27]),
+  fnv0_512_test_157: U512, b"http://www.isthe.com/chongo/tech/math/prime/mersenne.html", U512::from_words([0x2ad93c7801f4bb7a, 0x5ec589ac84fc9a04, 0x870458d6ff163903, 0x75b380716bfa627d, 0x4b3310b5dfa98918, 0xd8faad475a7ca27a, 0x7d245ce015a59f22, 0xd88234e41fd87d9d]),
+  fnv0_512_test_158: U512, b"http://www.isthe.com/chongo/tech/math/prime/mersenne.html#largest", U512::from_words([0x1f33442c814496ff, 0x01972461ece4ef2e, 0x3c79172cbe9c3120, 0xa85a0e6c5d12dc75, 0x1761c2170c865a5e, 0xc92ef7f6a9bd1b8b, 0xb6b99cffd60d96e5, 0xa38b53a0df1032ec]),
+  fnv0_512_test_159: U512, b"http://www.lavarnd.org/cgi-bin/corpspeak.cgi", U512::from_words([0xf72b34aa645fe8fe, 0xb4093d50e7eaa321, 0xe2dba68606dbd379, 0x18cf9ae48e549a14, 0x1f72ed258ea644e7, 0xdb860b136c8581f7, 0xcca620eba198c364, 0x8b326145083c5299]),
+  fnv0_512_test_160: U512, b"http://www.lavarnd.org/cgi-bin/haiku.cgi", U512::from_words([0x1963a060ab99462f, 0x6b679d305a6281f4, 0x82deed0490008eee, 0x3df44197c9798985, 0xb2e0d6500e3c6cc9, 0xca7710494bcf4aef, 0xe1d5a4ad22ca569a, 0x804b924a4a780af1]),
+  fnv0_512_test_161: U512, b"http://www.lavarnd.org/cgi-bin/rand-none.cgi", U512::from_words([0xf72b34aa645fe8fe, 0xc57304376e2de145, 0xa863e1118ddbd379, 0x18cf9ae48e549a14, 0x1f72ed258ea644e7, 0xdb860b136c8581f7, 0xcca622dcf6a3adeb, 0xfc2f85d249f7bc43]),
+  fnv0_512_test_162: U512, b"http://www.lavarnd.org/cgi-bin/randdist.cgi", U512::from_words([0xf95928b1e9b3ac4e, 0x0bb8149136533276, 0x30603b6b26223a15, 0xff537363ee809ead, 0x36935dfc60b21e0e, 0x1c4152e0b5ae4a44, 0xa40c6d11cf75a7b2, 0x8f6ded024211aa1a]),
+  fnv0_512_test_163: U512, b"http://www.lavarnd.org/index.html", U512::from_words([0x653574c052b86aae, 0x5c302cb001e6ea80, 0xea8c262711000000, 0x000000000012707a, 0xfb8cea65b9e1d2e7, 0x0e653a91aa5fd4f5, 0xf27ea86db1b5b51b, 0x43b85ec5c037a6fd]),
+  fnv0_512_test_164: U512, b"http://www.lavarnd.org/what/nist-test.html", U512::from_words([0x6b1fd7d9c763c098, 0x1af6dc074233e2fc, 0x68eca35e4b961e06, 0x08f44d3607699c73, 0x719aecde67476c4f, 0x27a3a4708c797158, 0x3e2daa76100b0f5c, 0xbb750e30a01f5ad1]),
+  fnv0_512_test_165: U512, b"http://www.macosxhints.com/", U512::from_words([0xe2a7503142190202, 0xfa9434a675b5e9f1, 0x18975505af000000, 0x0000000000000000, 0x000000032ff21aeb, 0x350a06d998f6c350, 0xc65956696fd0d6d9, 0x4f3ba8e79c3ce63e]),
+  fnv0_512_test_166: U512, b"http://www.mellis.com/", U512::from_words([0xf9e98f8382083d7f, 0x4e02088fe1e4111a, 0x5262bedd06000000, 0x0000000000000000, 0x0000000000000000, 0x00bcf80e7158e4b0, 0xba6493ae9a95f8ae, 0xebc2068ed08f0d8d]),
+  fnv0_512_test_167: U512, b"http://www.nature.nps.gov/air/webcams/parks/havoso2alert/havoalert.cfm", U512::from_words([0x6b7fe0734d55d329, 0xd2c9716e364eaa3b, 0x83f726cfb5b5f352, 0x47a06987d2865ed6, 0xcb60e875a4b28b29, 0x39a70febfc106996, 0x136fcc8aaa2cba2b, 0x86d0e6a873026aef]),
+  fnv0_512_test_168: U512, b"http://www.nature.nps.gov/air/webcams/parks/havoso2alert/timelines_24.cfm", U512::from_words([0x3f83e38453d2d621, 0x410ae6ea5d1ecc36, 0xc5973502d5aaf1a0, 0x11845b8b7a09251e, 0x27eee76e3361dac9, 0xe22106f0cedcf2ab, 0x6294a91f099e15bf, 0x9dfd7500c5ee0eee]),
+  fnv0_512_test_169: U512, b"http://www.paulnoll.com/", U512::from_words([0x05574873006799b6, 0xbc617029b8a78d96, 0xe5580794da000000, 0x0000000000000000, 0x0000000000000153, 0x3bca65689562664b, 0xe7c1b5b0f84e7381, 0x9de78325d12a2732]),
+  fnv0_512_test_170: U512, b"http://www.pepysdiary.com/", U512::from_words([0xc5a3e8fc6251c91c, 0x67e17071e4efa811, 0x51bfe5f9fc000000, 0x0000000000000000, 0x000000000260fc58, 0xc175f4140a260986, 0x957b76f88b33341a, 0xb32e4bb12b292ae7]),
+  fnv0_512_test_171: U512, b"http://www.sciencenews.org/index/home/activity/view", U512::from_words([0xa1672e343f2635e3, 0x45b9870bbf4f8f10, 0xd7b1de308207568d, 0xda94aac146be1181, 0x82d6c8e1d7854659, 0x8eefaf21655d4661, 0xf7ec46e07a7caf0b, 0x3458de8df7629bed]),
+  fnv0_512_test_172: U512, b"http://www.skyandtelescope.com/", U512::from_words([0x776a7f47f84ad1b0, 0x589086c5f8b5b3fe, 0x6bac36e4ca000000, 0x000000000000000a, 0x45874e975ea02459, 0xed04e97a0f96fe62, 0x8a807c1114937f38, 0x0dfda3c2dbcf11c7]),
+  fnv0_512_test_173: U512, b"http://www.sput.nl/~rob/sirius.html", U512::from_words([0x938444ddaf9fcda6, 0x2dfd1a207e6c2b8d, 0x26f287808b000000, 0x000000211a1e36ae, 0x231527e62e0cbbaf, 0xf3c12597d4887299, 0x13f04ff9919666de, 0xbf15dc8102dac012]),
+  fnv0_512_test_174: U512, b"http://www.systemexperts.com/", U512::from_words([0x7571fcd8a61da5f6, 0x603c346cd5536605, 0x41b923e8c0000000, 0x0000000000000000, 0x0005b8c53e64f752, 0x04f6068213f77755, 0x98fc7372dc81c7e5, 0x744d1864cf95468f]),
+  fnv0_512_test_175: U512, b"http://www.tq-international.com/phpBB3/index.php", U512::from_words([0x3891ac35323ac874, 0xe08aa9273d3f2b67, 0xb089a124203e7490, 0xbfeac885bf2f7df8, 0x7e46ea85aa3c2793, 0x8bdb365da7244e75, 0xbbc5bbdeb992361d, 0xf8a53e2063815eb5]),
+  fnv0_512_test_176: U512, b"http://www.travelquesttours.com/index.htm", U512::from_words([0x25e44fa6ac114867, 0xe71c312c584a11cc, 0xe4bddadf9fbf8135, 0x0243e25ef1d74222, 0xabce84ce70075de7, 0xa468a49ed2d9d3fe, 0xb0c91eab58e7f437, 0x30bb5d39ec7a1fed]),
+  fnv0_512_test_177: U512, b"http://www.wunderground.com/global/stations/89606.html", U512::from_words([0xa405f09e21c0bc0a, 0x2247747ebf7adafe, 0x594556b2b1ae1998, 0x4adf75a7e5198f2f, 0x09a640c42c89266b, 0x63f0cabf909376e2, 0x76b2209b097f1e21, 0x30ba52b37b273a77]),
+  fnv0_512_test_178: U512, &repeat(b"21701", 10), U512::from_words([0xe7ded13ddd68d7e3, 0xc61acf79fc2eaf52, 0xd95a591ae270aedc, 0x34efcce14d47cc8c, 0x16606bf1089702b9, 0xa5975fb40549b690, 0x1b4cf76c1d711bb4, 0x195f4e0bf675d31a]),
+  fnv0_512_test_179: U512, &repeat(b"M21701", 10), U512::from_words([0x6486ebad067cfd9e, 0x9e239547cafd3509, 0xfabf301ddb00eec3, 0xf71eec8f96d094d0, 0x67e91936d37be125, 0x553a298e211fabea, 0xfc121d0c4a14e0b6, 0x86f6e508b2294bd8]),
+  fnv0_512_test_180: U512, &repeat(b"2^21701-1", 10), U512::from_words([0x57cc7a27dca7c754, 0xfdf4288fe18adeba, 0xc89111626ca87af8, 0xdff08c353d79c432, 0xc86fe5bece6058ed, 0x569b4058854dca9c, 0x29f075124cf9c0d4, 0x16c2d2b2fbe903fa]),
+  fnv0_512_test_181: U512, &repeat(b"\x54\xc5", 10), U512::from_words([0x04b480baa7b38cd0, 0x789bf538df493741, 0xefd110ebb6000000, 0x0000000000000000, 0x0000000000000000, 0x00000054edc44da3, 0xd076dd54e1f000d2, 0xcc7edaf17203a988]),
+  fnv0_512_test_182: U512, &repeat(b"\xc5\x54", 10), U512::from_words([0x0b0c0f19bafa3347, 0x11b81735cc3d7193, 0x999edc2382000000, 0x0000000000000000, 0x0000000000000000, 0x000000c76ad667fa, 0x402dad247837ca0f, 0x1d73a14636b9da28]),
+  fnv0_512_test_183: U512, &repeat(b"23209", 10), U512::from_words([0xa915990abbc15785, 0xe7a8c479b92e2816, 0x2887efe44d0523ec, 0x13148768fb58a0f0, 0xec2f35ccb23f7da5, 0x02eb1e09dc557565, 0x279053e4c325a241, 0x6a93443133b9d940]),
+  fnv0_512_test_184: U512, &repeat(b"M23209", 10), U512::from_words([0x5a02b2d6d1606747, 0xb67eca443668bef5, 0xc3e0019768ccd7d8, 0xbe012549cc7d94d9, 0x9fb35fcfb5a5cb46, 0x941fadbf2c48ed53, 0xb22bcfc1d36ef556, 0xc24adf6d20f0dc0c]),
+  fnv0_512_test_185: U512, &repeat(b"2^23209-1", 10), U512::from_words([0xc60c5aaa8c1aacfe, 0x445be2e4ed3ee351, 0x7fe93871a066f25b, 0x122b366bf74533c7, 0x79622076b4bf6f43, 0x6bc7ea198f194239, 0x3833a4c11da17b19, 0x182936bd2f98dccc]),
+  fnv0_512_test_186: U512, &repeat(b"\x5a\xa9", 10), U512::from_words([0x0509ef646a53b09f, 0xfb8db8f270fd40e0, 0x7d0096874e000000, 0x0000000000000000, 0x0000000000000000, 0x0000005af33f729a, 0xd37faf413e55884f, 0xa81a87edc194646c]),
+  fnv0_512_test_187: U512, &repeat(b"\xa9\x5a", 10), U512::from_words([0x097a6c4d4965e3a8, 0xc1e0724abfc78b6f, 0x4d4b2c52de000000, 0x0000000000000000, 0x0000000000000000, 0x000000ab190d1f07, 0x5618d2e6d78e9095, 0x23cfa3cae150da0c]),
+  fnv0_512_test_188: U512, &repeat(b"391581216093", 10), U512::from_words([0x8baee65283e125a6, 0x8d6c070347c3040e, 0x8f89212225168527, 0x22ee29550cc3ce17, 0x18bd0448cf2d132b, 0x70fb0413ec5a5d13, 0xf6463d1c5857e9ae, 0x3079ff9c7f4b275c]),
+  fnv0_512_test_189: U512, &repeat(b"391581*2^216093-1", 10), U512::from_words([0x0f8f04434209eb5e, 0xd9bb6cafdeb8b4b4, 0xfe0c59d8ca18f9f5, 0xf46d451cdb17e036, 0xc2b0f307678f07c6, 0x5632b8b52e8642c2, 0x07d2a26158f74540, 0x81f09da83aa03cc0]),
+  fnv0_512_test_190: U512, &repeat(b"\x05\xf9\x9d\x03\x4c\x81", 10), U512::from_words([0xd6ae41a6c6518ee6, 0x76bc0371ad714e8d, 0xb3a971a8aa1aab9e, 0xf2b53edd6fb66154, 0xe4ca9d1315f482f1, 0x41571e0882311625, 0x4df85e2f4264f3b8, 0x73c4842fb4156220]),
+  fnv0_512_test_191: U512, &repeat(b"FEDCBA9876543210", 10), U512::from_words([0xa638d0cb938107bd, 0xff10fd1cdb957f8d, 0xa770cd4bd0440ae6, 0x0002762b1844c42e, 0x5a45b1b9a4d07ce0, 0x272f20f20e5ee6ad, 0xe7b7bc736a2decc6, 0x77af0e612d3032f0]),
+  fnv0_512_test_192: U512, &repeat(b"\xfe\xdc\xba\x98\x76\x54\x32\x10", 10), U512::from_words([0xfc9ba75e01b912d4, 0x01683e0138c3b465, 0x94c2c2fbce504627, 0xc9dcba66b371b377, 0x2f966a6e8af560e3, 0x24d3be3a4183efb6, 0xb441d27ef7cd1d90, 0xb866f479f08e63e0]),
+  fnv0_512_test_193: U512, &repeat(b"EFCDAB8967452301", 10), U512::from_words([0x6d040613a0edfae1, 0x2889cf813f459ffb, 0x6d18c11c1af3cf41, 0x7083cd0aac581c15, 0x9cca022c88a1ed84, 0x0c409d1419edb8bf, 0x3d423a7f12a1a31d, 0xeafb3d8bbd6b6e20]),
+  fnv0_512_test_194: U512, &repeat(b"\xef\xcd\xab\x89\x67\x45\x23\x01", 10), U512::from_words([0xd32f93ec71575bed, 0xdea45bf8d34d9d01, 0xbaaedf1bd2001c66, 0x22526b36d153f5c9, 0xae0b3d5547f7de6f, 0x7265746ba5385373, 0xaa0c49ac62a5e5a2, 0xfe7d0cf772218ea0]),
+  fnv0_512_test_195: U512, &repeat(b"0123456789ABCDEF", 10), U512::from_words([0x4924d7cfbbf5372b, 0x1245955dbfd34641, 0x22e582623a5ce0b2, 0x3fabcac984ce5bcc, 0x163ee782ca0ca7d5, 0xaa9acb5386f6a6ae, 0xb7780b24c123e3df, 0x25203b71326b0fd0]),
+  fnv0_512_test_196: U512, &repeat(b"\x01\x23\x45\x67\x89\xab\xcd\xef", 10), U512::from_words([0x88062287638aad56, 0xefe11f2f977ca88d, 0xfc12b47de5b164cb, 0xe522473605aa288f, 0x5d8819302159aba4, 0x0a33caa4045e5e86, 0x4eca76c6f8ca06b4, 0xca149df012d41ca0]),
+  fnv0_512_test_197: U512, &repeat(b"1032547698BADCFE", 10), U512::from_words([0x7d755f301d7313ff, 0xcbb92df452d475fc, 0xe699960c8e83d4f1, 0xeae7c9431f98ff21, 0x032eac03d2220b25, 0x0cdc617be895d78d, 0xb5cd402093b2b4a8, 0x1a3759ad4527c2c0]),
+  fnv0_512_test_198: U512, &repeat(b"\x10\x32\x54\x76\x98\xba\xdc\xfe", 10), U512::from_words([0x573e550612ae4001, 0x0d1a525f8ad35d07, 0x8964154dc820c89e, 0xf831a825abb88768, 0x0f061d6595414d8a, 0x03d0a964508ae988, 0xd46387e6eaae3922, 0x0ca021da24069260]),
+  fnv0_512_test_199: U512, &repeat(b"\x00", 500), U512::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]),
+  fnv0_512_test_200: U512, &repeat(b"\x07", 500), U512::from_words([0x5b4cbb9a04a1a8e0, 0x28431d36047168c1, 0x2b35f462d87e0906, 0x0989d1fb711423d0, 0x0fb2fbcf6a28a85a, 0x8e3488767eb77d33, 0xcf6b0c72fca64225, 0xb08a0cbe9f04b79c]),
+  fnv0_512_test_201: U512, &repeat(b"~", 500), U512::from_words([0x3aff3dae64382c6c, 0x2d44fce789d24dab, 0xfd4f977fda9496c2, 0x0634a18533b13d3c, 0x83337452a43c6ca8, 0xe5ddac9e78ddece4, 0xa73442c49b3ed5af, 0xf849f44aa432c4b8]),
+  fnv0_512_test_202: U512, &repeat(b"\x7f", 500), U512::from_words([0xdf6715723b6818a8, 0xbef3b18c65470e6e, 0xa8fc8001434bd045, 0x9996fe53c3370ecf, 0xd79550d0906c6a9a, 0xdbe039eb5cb7ad89, 0xe4781ba0c2b24a4e, 0x0226b4d82875f5dc]),
+  fnv0_1024_test_0: U1024, b"", U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]),
+  fnv0_1024_test_1: U1024, b"a", U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000061]),
+  fnv0_1024_test_2: U1024, b"b", U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000062]),
+  fnv0_1024_test_3: U1024, b"c", U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000063]),
+  fnv0_1024_test_4: U1024, b"d", U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000064]),
+  fnv0_1024_test_5: U1024, b"e", U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000065]),
+  fnv0_1024_test_6: U1024, b"f", U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000066]),
+  fnv0_1024_test_7: U1024, b"fo", U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000660000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000009e41]),
+  fnv0_1024_test_8: U1024, b"foo", U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x013c6f0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000f56aa2]),
+  fnv0_1024_test_9: U1024, b"foob", U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000002, 0xe022c50000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000000017c965d58]),
+  fnv0_1024_test_10: U1024, b"fooba", U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000000005f2, 0x2c48d90000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000024e352ec119]),
+  fnv0_1024_test_11: U1024, b"foobar", U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000000b86c3, 0xdbb99e0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00039348798173b7]),
+  fnv0_1024_test_12: U1024, ::std::ffi::CString::new("").unwrap().as_bytes_with_nul(), U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]),
+  fnv0_1024_test_13: U1024, ::std::ffi::CString::new("a").unwrap().as_bytes_with_nul(), U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000610000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000000000000966d]),
+  fnv0_1024_test_14: U1024, ::std::ffi::CString::new("b").unwrap().as_bytes_with_nul(), U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000620000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000000097fa]),
+  fnv0_1024_test_15: U1024, ::std::ffi::CString::new("c").unwrap().as_bytes_with_nul(), U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000630000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000009987]),
+  fnv0_1024_test_16: U1024, ::std::ffi::CString::new("d").unwrap().as_bytes_with_nul(), U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000640000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000009b14]),
+  fnv0_1024_test_17: U1024, ::std::ffi::CString::new("e").unwrap().as_bytes_with_nul(), U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000650000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000009ca1]),
+  fnv0_1024_test_18: U1024, ::std::ffi::CString::new("f").unwrap().as_bytes_with_nul(), U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000660000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000009e2e]),
+  fnv0_1024_test_19: U1024, ::std::ffi::CString::new("fo").unwrap().as_bytes_with_nul(), U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x013c6f0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000f56acd]),
+  fnv0_1024_test_20: U1024, ::std::ffi::CString::new("foo").unwrap().as_bytes_with_nul(), U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000002, 0xe022c50000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000000017c965d3a]),
+  fnv0_1024_test_21: U1024, ::std::ffi::CString::new("foob").unwrap().as_bytes_with_nul(), U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000000005f2, 0x2c48d90000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000024e352ec178]),
+  fnv0_1024_test_22: U1024, ::std::ffi::CString::new("fooba").unwrap().as_bytes_with_nul(), U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000000b86c3, 0xdbb99e0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00039348798173c5]),
+  fnv0_1024_test_23: U1024, ::std::ffi::CString::new("foobar").unwrap().as_bytes_with_nul(), U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000015734635, 0x404dbd0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x058b67646dc072cb]),
+  fnv0_1024_test_24: U1024, b"ch", U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000630000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000000099ef]),
+  fnv0_1024_test_25: U1024, b"cho", U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0133760000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000eeb7cc]),
+  fnv0_1024_test_26: U1024, b"chon", U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000002, 0xcb85ca0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000172330732]),
+  fnv0_1024_test_27: U1024, b"chong", U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000000005c7, 0xd181740000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000023e192228ed]),
+  fnv0_1024_test_28: U1024, b"chongo", U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000000b34f9, 0x07e9d10000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00037a4cf9f977e6]),
+  fnv0_1024_test_29: U1024, b"chongo ", U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000014db732b, 0x3f11030000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0564a95fa7deef8e]),
+  fnv0_1024_test_30: U1024, b"chongo w", U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000025bcfaf9b8, 0xac51350000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000008, 0x5d1aa95754b97f41]),
+  fnv0_1024_test_31: U1024, b"chongo wa", U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000042e32bde9ab7, 0xf36e720000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000cf8, 0x62589c6e63aa57ac]),
+  fnv0_1024_test_32: U1024, b"chongo was", U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0074b2ad60d25da8, 0x2c9e760000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000141d30, 0x836a97308f29f5cf]),
+  fnv0_1024_test_33: U1024, b"chongo was ", U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0xc916476290d26e5c, 0x5bb2cd0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000000001f31443b, 0xcc4c764e04123223]),
+  fnv0_1024_test_34: U1024, b"chongo was h", U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000157, 0x08d0efa6e2cb733e, 0x467a0c0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000000305f66d0bb, 0xd29376fc5037c02f]),
+  fnv0_1024_test_35: U1024, b"chongo was he", U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000024458, 0x12d461a048f8b3e3, 0x8304cb0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00004b03f271b345, 0x8eaf854866750886]),
+  fnv0_1024_test_36: U1024, b"chongo was her", U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000003cf0087, 0xa50eab1fd9344038, 0xa377550000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0074551efa5302de, 0x4631b146e37e37bc]),
+  fnv0_1024_test_37: U1024, b"chongo was here", U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000000065c58f155, 0x4ac23eaa07b8deb8, 0xfe468d0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0xb468010a32bb72b2, 0xdb0feaeecabc6ee9]),
+  fnv0_1024_test_38: U1024, b"chongo was here!", U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000a919def4b77, 0xaaade089099c53ad, 0x0fd7920000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000117, 0xc5499cd0acb0df5d, 0xb7af54506637ff74]),
+  fnv0_1024_test_39: U1024, b"chongo was here!\n", U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00117b9735b4d940, 0x6084923b96c213c7, 0xc94cde0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000000000001b1dc, 0xf3282f9bce4a6455, 0xdae5c0ae84d726ee]),
+  fnv0_1024_test_40: U1024, ::std::ffi::CString::new("ch").unwrap().as_bytes_with_nul(), U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0133760000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000eeb7a3]),
+  fnv0_1024_test_41: U1024, ::std::ffi::CString::new("cho").unwrap().as_bytes_with_nul(), U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000002, 0xcb85ca0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000000017233075c]),
+  fnv0_1024_test_42: U1024, ::std::ffi::CString::new("chon").unwrap().as_bytes_with_nul(), U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000000005c7, 0xd181740000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000023e1922288a]),
+  fnv0_1024_test_43: U1024, ::std::ffi::CString::new("chong").unwrap().as_bytes_with_nul(), U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000000b34f9, 0x07e9d10000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00037a4cf9f97789]),
+  fnv0_1024_test_44: U1024, ::std::ffi::CString::new("chongo").unwrap().as_bytes_with_nul(), U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000014db732b, 0x3f11030000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0564a95fa7deefae]),
+  fnv0_1024_test_45: U1024, ::std::ffi::CString::new("chongo ").unwrap().as_bytes_with_nul(), U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000025bcfaf9b8, 0xac51350000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000008, 0x5d1aa95754b97f36]),
+  fnv0_1024_test_46: U1024, ::std::ffi::CString::new("chongo w").unwrap().as_bytes_with_nul(), U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000042e32bde9ab7, 0xf36e720000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000cf8, 0x62589c6e63aa57cd]),
+  fnv0_1024_test_47: U1024, ::std::ffi::CString::new("chongo wa").unwrap().as_bytes_with_nul(), U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0074b2ad60d25da8, 0x2c9e760000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000141d30, 0x836a97308f29f5bc]),
+  fnv0_1024_test_48: U1024, ::std::ffi::CString::new("chongo was").unwrap().as_bytes_with_nul(), U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0xc916476290d26e5c, 0x5bb2cd0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000000001f31443b, 0xcc4c764e04123203]),
+  fnv0_1024_test_49: U1024, ::std::ffi::CString::new("chongo was ").unwrap().as_bytes_with_nul(), U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000157, 0x08d0efa6e2cb733e, 0x467a0c0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000000305f66d0bb, 0xd29376fc5037c047]),
+  fnv0_1024_test_50: U1024, ::std::ffi::CString::new("chongo was h").unwrap().as_bytes_with_nul(), U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000024458, 0x12d461a048f8b3e3, 0x8304cb0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00004b03f271b345, 0x8eaf8548667508e3]),
+  fnv0_1024_test_51: U1024, ::std::ffi::CString::new("chongo was he").unwrap().as_bytes_with_nul(), U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000003cf0087, 0xa50eab1fd9344038, 0xa377550000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0074551efa5302de, 0x4631b146e37e37ce]),
+  fnv0_1024_test_52: U1024, ::std::ffi::CString::new("chongo was her").unwrap().as_bytes_with_nul(), U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000000065c58f155, 0x4ac23eaa07b8deb8, 0xfe468d0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0xb468010a32bb72b2, 0xdb0feaeecabc6e8c]),
+  fnv0_1024_test_53: U1024, ::std::ffi::CString::new("chongo was here").unwrap().as_bytes_with_nul(), U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000a919def4b77, 0xaaade089099c53ad, 0x0fd7920000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000117, 0xc5499cd0acb0df5d, 0xb7af54506637ff55]),
+  fnv0_1024_test_54: U1024, ::std::ffi::CString::new("chongo was here!").unwrap().as_bytes_with_nul(), U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00117b9735b4d940, 0x6084923b96c213c7, 0xc94cde0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000000000001b1dc, 0xf3282f9bce4a6455, 0xdae5c0ae84d726e4]),
+  fnv0_1024_test_55: U1024, ::std::ffi::CString::new("chongo was here!\n").unwrap().as_bytes_with_nul(), U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x1cce867171a484a3, 0xf7fb1c43b0b95b58, 0x035b340000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000002a0d3a5, 0x1551d49ee95d9924, 0x764bcea401a75f16]),
+  fnv0_1024_test_56: U1024, b"cu", U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000630000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000000099f2]),
+  fnv0_1024_test_57: U1024, b"cur", U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0133790000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000eebc38]),
+  fnv0_1024_test_58: U1024, b"curd", U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000002, 0xcb8edd0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000000017239e2bc]),
+  fnv0_1024_test_59: U1024, b"curds", U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000000005c7, 0xe66f750000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000023e23c49dff]),
+  fnv0_1024_test_60: U1024, b"curds ", U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000000b3524, 0x1f76700000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00037a5d77e90453]),
+  fnv0_1024_test_61: U1024, b"curds a", U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000014dbc67c, 0xb3b0030000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0564c2f2f45bb4d6]),
+  fnv0_1024_test_62: U1024, b"curds an", U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000025bd95c257, 0x03a97d0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000008, 0x5d4252c4f2376fb0]),
+  fnv0_1024_test_63: U1024, b"curds and", U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000042e4439125e2, 0xe546890000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000cf8, 0x9fda5b6b9ff83394]),
+  fnv0_1024_test_64: U1024, b"curds and ", U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0074b49ca2732c7d, 0x8696090000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000141d8f, 0xe59fc5e713e7fca4]),
+  fnv0_1024_test_65: U1024, b"curds and w", U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0xc919a6cd8c61e5bd, 0x9ea8990000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000000001f31d827, 0x18c5e759dec2ca23]),
+  fnv0_1024_test_66: U1024, b"curds and wh", U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000157, 0x0e9fd3db79b8a0ed, 0xce3f680000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000030604c34a1, 0x6ae7c65e7413782f]),
+  fnv0_1024_test_67: U1024, b"curds and whe", U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000024461, 0xfa102cc6ab17ef3c, 0xebcc770000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00004b05562d9e52, 0xc96ea07a02316086]),
+  fnv0_1024_test_68: U1024, b"curds and whey", U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000003cf1146, 0xf8b3c2e0c2be7b7b, 0xdd75110000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00745746a4be8662, 0x608edd356692afb7]),
+  fnv0_1024_test_69: U1024, b"curds and whey\n", U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000000065c7511b4, 0x6d4b98ee9042b47d, 0x013b140000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0xb46b588d7b76668f, 0xbd8d0bd0117a7ec1]),
+  fnv0_1024_test_70: U1024, ::std::ffi::CString::new("cu").unwrap().as_bytes_with_nul(), U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0133790000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000eebc4a]),
+  fnv0_1024_test_71: U1024, ::std::ffi::CString::new("cur").unwrap().as_bytes_with_nul(), U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000002, 0xcb8edd0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000000017239e2d8]),
+  fnv0_1024_test_72: U1024, ::std::ffi::CString::new("curd").unwrap().as_bytes_with_nul(), U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000000005c7, 0xe66f750000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000023e23c49d8c]),
+  fnv0_1024_test_73: U1024, ::std::ffi::CString::new("curds").unwrap().as_bytes_with_nul(), U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000000b3524, 0x1f76700000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00037a5d77e90473]),
+  fnv0_1024_test_74: U1024, ::std::ffi::CString::new("curds ").unwrap().as_bytes_with_nul(), U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000014dbc67c, 0xb3b0030000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0564c2f2f45bb4b7]),
+  fnv0_1024_test_75: U1024, ::std::ffi::CString::new("curds a").unwrap().as_bytes_with_nul(), U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000025bd95c257, 0x03a97d0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000008, 0x5d4252c4f2376fde]),
+  fnv0_1024_test_76: U1024, ::std::ffi::CString::new("curds an").unwrap().as_bytes_with_nul(), U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000042e4439125e2, 0xe546890000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000cf8, 0x9fda5b6b9ff833f0]),
+  fnv0_1024_test_77: U1024, ::std::ffi::CString::new("curds and").unwrap().as_bytes_with_nul(), U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0074b49ca2732c7d, 0x8696090000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000141d8f, 0xe59fc5e713e7fc84]),
+  fnv0_1024_test_78: U1024, ::std::ffi::CString::new("curds and ").unwrap().as_bytes_with_nul(), U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0xc919a6cd8c61e5bd, 0x9ea8990000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000000001f31d827, 0x18c5e759dec2ca54]),
+  fnv0_1024_test_79: U1024, ::std::ffi::CString::new("curds and w").unwrap().as_bytes_with_nul(), U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000157, 0x0e9fd3db79b8a0ed, 0xce3f680000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000030604c34a1, 0x6ae7c65e74137847]),
+  fnv0_1024_test_80: U1024, ::std::ffi::CString::new("curds and wh").unwrap().as_bytes_with_nul(), U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000024461, 0xfa102cc6ab17ef3c, 0xebcc770000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00004b05562d9e52, 0xc96ea07a023160e3]),
+  fnv0_1024_test_81: U1024, ::std::ffi::CString::new("curds and whe").unwrap().as_bytes_with_nul(), U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000003cf1146, 0xf8b3c2e0c2be7b7b, 0xdd75110000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00745746a4be8662, 0x608edd356692afce]),
+  fnv0_1024_test_82: U1024, ::std::ffi::CString::new("curds and whey").unwrap().as_bytes_with_nul(), U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000000065c7511b4, 0x6d4b98ee9042b47d, 0x013b140000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0xb46b588d7b76668f, 0xbd8d0bd0117a7ecb]),
+  fnv0_1024_test_83: U1024, ::std::ffi::CString::new("curds and whey\n").unwrap().as_bytes_with_nul(), U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000a91cce50248, 0xf4a2b9b3447db5ec, 0x631cc50000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000117, 0xca785368769d0ce8, 0xf3bb51ab1af6914d]),
+  fnv0_1024_test_84: U1024, b"hi", U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000680000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000000000000a121]),
+  fnv0_1024_test_85: U1024, ::std::ffi::CString::new("hi").unwrap().as_bytes_with_nul(), U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0142690000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000f9e02d]),
+  fnv0_1024_test_86: U1024, b"hello", U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000000000000060e, 0xb685ab0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000002591aeb0fc6]),
+  fnv0_1024_test_87: U1024, ::std::ffi::CString::new("hello").unwrap().as_bytes_with_nul(), U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000000bbdeb, 0xf859f50000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0003a42ebe87760e]),
+  fnv0_1024_test_88: U1024, b"\xff\x00\x00\x01", U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000007, 0x2fc4050000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000003b70653ea]),
+  fnv0_1024_test_89: U1024, b"\x01\x00\x00\xff", U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0736fb0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000003bac1ea]),
+  fnv0_1024_test_90: U1024, b"\xff\x00\x00\x02", U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000007, 0x2fc4050000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000003b70653e9]),
+  fnv0_1024_test_91: U1024, b"\x02\x00\x00\xff", U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0e6df60000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000077582d5]),
+  fnv0_1024_test_92: U1024, b"\xff\x00\x00\x03", U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000007, 0x2fc4050000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000003b70653e8]),
+  fnv0_1024_test_93: U1024, b"\x03\x00\x00\xff", U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x15a4f10000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000000000b3043c0]),
+  fnv0_1024_test_94: U1024, b"\xff\x00\x00\x04", U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000007, 0x2fc4050000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000003b70653ef]),
+  fnv0_1024_test_95: U1024, b"\x04\x00\x00\xff", U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x1cdbec0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000000000eeb04ab]),
+  fnv0_1024_test_96: U1024, b"\x40\x51\x4e\x44", U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000001, 0xcd2cb00000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000ee3eca33]),
+  fnv0_1024_test_97: U1024, b"\x44\x4e\x51\x40", U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000001, 0xe9e6790000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000fd0f5287]),
+  fnv0_1024_test_98: U1024, b"\x40\x51\x4e\x4a", U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000001, 0xcd2cb00000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000ee3eca3d]),
+  fnv0_1024_test_99: U1024, b"\x4a\x4e\x51\x40", U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000002, 0x153d430000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000001137a3e29]),
+  fnv0_1024_test_100: U1024, b"\x40\x51\x4e\x54", U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000001, 0xcd2cb00000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000ee3eca23]),
+  fnv0_1024_test_101: U1024, b"\x54\x4e\x51\x40", U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000002, 0x5d56c90000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000138bc5c77]),
+  fnv0_1024_test_102: U1024, b"127.0.0.1", U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000020f60e52b12f, 0x3acf260000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000663, 0x372985972eaf0dc3]),
+  fnv0_1024_test_103: U1024, ::std::ffi::CString::new("127.0.0.1").unwrap().as_bytes_with_nul(), U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x003980cb5fc25d6c, 0xe24bb10000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000000000009e7dc, 0x8b642b7365785767]),
+  fnv0_1024_test_104: U1024, b"127.0.0.2", U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000020f60e52b12f, 0x3acf260000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000663, 0x372985972eaf0dc0]),
+  fnv0_1024_test_105: U1024, ::std::ffi::CString::new("127.0.0.2").unwrap().as_bytes_with_nul(), U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x003980cb5fc25d6c, 0xe24bae0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000000000009e7dc, 0x8b642b73657852c0]),
+  fnv0_1024_test_106: U1024, b"127.0.0.3", U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000020f60e52b12f, 0x3acf260000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000663, 0x372985972eaf0dc1]),
+  fnv0_1024_test_107: U1024, ::std::ffi::CString::new("127.0.0.3").unwrap().as_bytes_with_nul(), U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x003980cb5fc25d6c, 0xe24baf0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000000000009e7dc, 0x8b642b736578544d]),
+  fnv0_1024_test_108: U1024, b"64.81.78.68", U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x6d2f3fe31a0d11d1, 0x81fcbe0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000010ed93f3, 0x995535a9249929a4]),
+  fnv0_1024_test_109: U1024, ::std::ffi::CString::new("64.81.78.68").unwrap().as_bytes_with_nul(), U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000000000ba, 0x3fda06c8bb7a4b0b, 0x2e1c4a0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000001a406e70c4, 0xc924374dc1859354]),
+  fnv0_1024_test_110: U1024, b"64.81.78.74", U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x6d2f3fe31a0d11d1, 0x81fcbf0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000010ed93f3, 0x995535a924992b1d]),
+  fnv0_1024_test_111: U1024, ::std::ffi::CString::new("64.81.78.74").unwrap().as_bytes_with_nul(), U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000000000ba, 0x3fda06c8bb7a4b0b, 0x2e1f500000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000001a406e70c4, 0xc924374dc187dbf9]),
+  fnv0_1024_test_112: U1024, b"64.81.78.84", U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x6d2f3fe31a0d11d1, 0x81fcb40000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000010ed93f3, 0x995535a924991a2e]),
+  fnv0_1024_test_113: U1024, ::std::ffi::CString::new("64.81.78.84").unwrap().as_bytes_with_nul(), U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000000000ba, 0x3fda06c8bb7a4b0b, 0x2dfd520000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000001a406e70c4, 0xc924374dc16d9956]),
+  fnv0_1024_test_114: U1024, b"feedface", U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000026d146197b, 0xd348b00000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000008, 0x99baa4589730029b]),
+  fnv0_1024_test_115: U1024, ::std::ffi::CString::new("feedface").unwrap().as_bytes_with_nul(), U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000044cc4459dd9d, 0xd7bb8b0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000d56, 0x6670dd6275740a5f]),
+  fnv0_1024_test_116: U1024, b"feedfacedaffdeed", U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000ade95a66e17, 0xb6de2122b748fe31, 0x8f50910000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000000000000011f, 0xb13ac5fca3cfce23, 0xf2aff83c8429f502]),
+  fnv0_1024_test_117: U1024, ::std::ffi::CString::new("feedfacedaffdeed").unwrap().as_bytes_with_nul(), U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0011fadf4ddeb76a, 0x664786c8ec2a6f5f, 0x69e5df0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000000000001be25, 0xd82508ca0942adbf, 0x5ae3f5d8f510f41a]),
+  fnv0_1024_test_118: U1024, b"feedfacedeadbeef", U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000ade95a66e17, 0xb6de2122b6641b0d, 0x60ef200000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000000000000011f, 0xb13ac5fca3cfce23, 0xf2741c055672854b]),
+  fnv0_1024_test_119: U1024, ::std::ffi::CString::new("feedfacedeadbeef").unwrap().as_bytes_with_nul(), U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0011fadf4ddeb76a, 0x664786c74d59f915, 0xc559eb0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000000000001be25, 0xd82508ca0942adbe, 0xfe0f74470f98b54f]),
+  fnv0_1024_test_120: U1024, b"line 1\nline 2\nline 3", U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000005410, 0xe2b25dfdfea986ee, 0x50b975372bb81665, 0x6f42e40000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000006dc508fb725, 0xf56904c5300bb587, 0x0acc1de2c1ce6bae]),
+  fnv0_1024_test_121: U1024, b"chongo <Landon Curt Noll> /\\../\\", U1024::from_words([0x0000000000000000, 0x005f7a76758ecc4d, 0x32e56d5a591028b7, 0x4b29fc4223fdada1, 0x6c3bf34eda3674da, 0x9a21d90000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000000000004c6d7, 0xeb6e73802734510a, 0x555f256cc005ae55, 0x6bde8cc9c6a93b21, 0xaff4b16c71ee90b3]),
+  fnv0_1024_test_122: U1024, ::std::ffi::CString::new("chongo <Landon Curt Noll> /\\../\\").unwrap().as_bytes_with_nul(), U1024::from_words([0x0000000000000000, 0x98d7c19fbce653df, 0x221b9f717d3490ff, 0x95ca87fdaef30d1b, 0x823372f85b24a372, 0xf50e380000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000007685cd8, 0x1a491dbccc21ad06, 0x648d09a5c8cf5a78, 0x482054e91470b33d, 0xde77252caef66597]),
+  fnv0_1024_test_123: U1024, b"chongo (Landon Curt Noll) /\\../\\", U1024::from_words([0x0000000000000000, 0x005f7a76758ecc4d, 0x3396093343fcb46a, 0xaf6afb9b5f00519f, 0xadc94a1799791384, 0x1d612c0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000000000004c6d7, 0xeb6e7380273fba53, 0x7a1baa73d9e6d480, 0xc8663104287cfaaf, 0x3fe5486163a86224]),
+  fnv0_1024_test_124: U1024, ::std::ffi::CString::new("chongo (Landon Curt Noll) /\\../\\").unwrap().as_bytes_with_nul(), U1024::from_words([0x0000000000000000, 0x98d7c19fbce653e0, 0x3f6697fa8a8e3d4b, 0xefbcb0bab9af98c8, 0xfe2295d8e60ba545, 0x3813600000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000007685cd8, 0x1a491dbcddd3f374, 0x5ce755a8eaf78bb6, 0xc67a0372c9d0c1c6, 0x16913f078c2031d4]),
+  fnv0_1024_test_125: U1024, b"http://antwrp.gsfc.nasa.gov/apod/astropix.html", U1024::from_words([0xde8263485e2a6bd2, 0x7c6d64d9a7aef42f, 0xeba46b79d2a2b45a, 0xdcd882ba113f44d7, 0xbf97c51a8868f015, 0xd74e130000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000917, 0xe68e5b4587bb25c9, 0x4ff736b527b8c1eb, 0x77e16f94ddf2a6ca, 0xd873a9dcc05ea546, 0x045f40cfe87371d4, 0xb4071ba4d8ccd67f]),
+  fnv0_1024_test_126: U1024, b"http://en.wikipedia.org/wiki/Fowler_Noll_Vo_hash", U1024::from_words([0xa7c114458aa0410a, 0xa77a045b6167368d, 0xedb0dfc03580d473, 0x05d485228dc7b2dd, 0xbc45e844f999618e, 0xf2cd050000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000015de6ba7, 0x313e49f92931737f, 0xfcef96600685b64b, 0xfc955dc99f4ea65f, 0x64047bf6f3ac56a0, 0x6871829da8db42b7, 0x6c50a0bb2e4a9e2e]),
+  fnv0_1024_test_127: U1024, b"http://epod.usra.edu/", U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000846964, 0xced8096697fa3af7, 0x1f864f5434b229be, 0x27269b0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000a4455ee36b3c6, 0xf497b1081e3a5432, 0xfec69d5e546a53d6]),
+  fnv0_1024_test_128: U1024, b"http://exoplanet.eu/", U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x000000000000511d, 0x5ee97ae03b92d77d, 0xb1c46cc307ea67e7, 0xcd3f480000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000069ed89ec47b, 0xaa35423c0a15070d, 0x614413333423d143]),
+  fnv0_1024_test_129: U1024, b"http://hvo.wr.usgs.gov/cam3/", U1024::from_words([0x0000000000000000, 0x00000000000f0fc7, 0xe45cb36c0f01612f, 0x6c6959b4c570febb, 0x29ce3b9777e91c6f, 0x60d4cd0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000dd75ad1816cf, 0xc47dc03d0551789e, 0x6985a94306c33901, 0xafec3fd9120b54ac]),
+  fnv0_1024_test_130: U1024, b"http://hvo.wr.usgs.gov/cams/HMcam/", U1024::from_words([0x0000000000000100, 0x0cd4cc2267c07fe1, 0x01c1936e7c60bf93, 0x44e4d7cc1c4b1d48, 0xb48bd757723d24a9, 0x00e2e90000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000c0855abfe, 0x0735df33424e7a2a, 0xa6a4c884931b6af7, 0xb8cf7731b88fd59c, 0x33eada4659aa3789]),
+  fnv0_1024_test_131: U1024, b"http://hvo.wr.usgs.gov/kilauea/update/deformation.html", U1024::from_words([0x8d4e151d2c27981e, 0xa5fe8ab8bb72edab, 0x978e2c01c3008b79, 0xe3dfa4f3ba179da1, 0x483734ea45ffbf40, 0xf236ee0000000000, 0x0000000000000000, 0x0000000000000000, 0x000000000001302d, 0xbf52e737077587ef, 0xfcef81776677feac, 0x48e67b562ecf2218, 0x9d929919dfd1ca0f, 0x266aa98adf473cfc, 0x389648b41730da9d, 0x03e367ffb16dc785]),
+  fnv0_1024_test_132: U1024, b"http://hvo.wr.usgs.gov/kilauea/update/images.html", U1024::from_words([0x0766078e4a3656dd, 0x8bf0d463c8530a4a, 0x9138d567f9363f83, 0xfe21a69ba7ae0998, 0xe2b8ba8c222e04f2, 0x8c8e630000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000021e9ecf247, 0x5d98b78eea9ccf59, 0x34468ebbe2d5e389, 0xdaea202c4a4e112e, 0xb7723b61aef7d230, 0xd7dca5db502cf4af, 0xd927679e8e5c6457]),
+  fnv0_1024_test_133: U1024, b"http://hvo.wr.usgs.gov/kilauea/update/maps.html", U1024::from_words([0xcc7d0f58c30b4026, 0x11c90fa568bc82fb, 0xca57b5a294f5d918, 0x1ac4bd9fc8b6d0c6, 0xe5ba3a960f3c709a, 0xa0da920000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000000e1a10, 0x8ac38ad37d4530ad, 0x078b1fd0c6ef2a6f, 0xebe001ae00f5736a, 0x82a6b2bde986ab1c, 0x3f7f5829655f128d, 0x0280ca5eb433cfc0]),
+  fnv0_1024_test_134: U1024, b"http://hvo.wr.usgs.gov/volcanowatch/current_issue.html", U1024::from_words([0x8d4e151d2c27981e, 0xa5fe7a69f4102a1a, 0xf4a49259ed1b3ae4, 0x761d7747acbe8feb, 0x7111d488e6606699, 0xb7ddac0000000000, 0x0000000000000000, 0x0000000000000000, 0x000000000001302d, 0xbf52e737077587ef, 0xfcef81776677feac, 0x48e67b562ecf2140, 0xc3a72c76c7f2df5d, 0x4f9a8f94cfd5fb37, 0xe4bec0251e958777, 0x447bc1842f54276f]),
+  fnv0_1024_test_135: U1024, b"http://neo.jpl.nasa.gov/risk/", U1024::from_words([0x0000000000000000, 0x000000001838eeaa, 0x3bd90e57b61ac9c0, 0xdc3dd659bd2f68dd, 0x071999ed690adea9, 0xac843d0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x01576f7d6e5b6033, 0xb6111515cdb2aecc, 0x848fac8aae679bb7, 0x106f8e8b8be01312]),
+  fnv0_1024_test_136: U1024, b"http://norvig.com/21-days.html", U1024::from_words([0x0000000000000000, 0x00000026e7b99b6d, 0x2afb71bb6ce7ab4f, 0x42a7f5b5cd1eecc3, 0x53bbaf67265a7b1b, 0xc4e1910000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000002, 0x1497e58423b43031, 0x57a0d220ede5b41b, 0x6622da32dd23a1af, 0x02154fcedeb6592f]),
+  fnv0_1024_test_137: U1024, b"http://primes.utm.edu/curios/home.php", U1024::from_words([0x0000000411c0d5a0, 0x6cfb61e7a0ea3829, 0x0f4de5549240e63a, 0xeee7014eeeb07207, 0x8d43040b8d66e315, 0xd84bba0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x2ce0228836587e07, 0x807980d4e9bee5f8, 0x390905bc28e0ad1f, 0x29705b5d50bfd274, 0x05ec4f25d9ec09c2]),
+  fnv0_1024_test_138: U1024, b"http://slashdot.org/", U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x000000000000511d, 0x5ee97ae03b9286c1, 0x3bf342b01e65b6e4, 0x8291520000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000069ed89ec47b, 0xaa3537d06d4cbf77, 0xaffbef0d689cf4cb]),
+  fnv0_1024_test_139: U1024, b"http://tux.wr.usgs.gov/Maps/155.25-19.5.html", U1024::from_words([0x5f913ba438fb2d18, 0x498b650ae3b4d633, 0x6828887829dbd485, 0x48e271f00e16fd2a, 0xcfd8c613ce8dc654, 0xf62ebe0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x03c7f9263edd7670, 0x569307f5dce6c2b4, 0x1bbdff3d7da7c99e, 0x76bbbcaaadc0387c, 0x49bb1f76ede0839f, 0x601e2635bfa532be]),
+  fnv0_1024_test_140: U1024, b"http://volcano.wr.usgs.gov/kilaueastatus.php", U1024::from_words([0x5f913ba4259442c8, 0xf3a639d9a27bb874, 0xbd41f3a635990c07, 0xab75bf1bf0d689b2, 0xddd9524cab9125d5, 0xc8db2d0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x03c7f9263edd7670, 0x55bd45427c7eaa89, 0xe41acc8f54e79335, 0xed45f1419b4af1a2, 0x4c201413dd6183aa, 0x3818038166eea0d4]),
+  fnv0_1024_test_141: U1024, b"http://www.avo.alaska.edu/activity/Redoubt.php", U1024::from_words([0xde8038df7edc5f41, 0x4627844347ef2a36, 0xff3f737a50a7dcbd, 0xf9d9d5d62d83a02a, 0x9d7a572f6e3bd840, 0xb34b920000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000917, 0xe68e5b4587bb0f2a, 0xa4d0c97fcad78c08, 0xf3e2b5cd43534a05, 0x27b11edaade60627, 0x28763a0c9763e631, 0x9c8b9aad472dce3e]),
+  fnv0_1024_test_142: U1024, b"http://www.dilbert.com/fast/", U1024::from_words([0x0000000000000000, 0x00000000000f0fc7, 0xe45cb36c0edc74c2, 0xe8decddeec920bea, 0xfbf664275e9f5f00, 0xf74a800000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000dd75ad1816cf, 0xc47ae350bcab237c, 0xa05718dc03c6ffbc, 0x139a7feead6e6d5c]),
+  fnv0_1024_test_143: U1024, b"http://www.fourmilab.ch/gravitation/orbits/", U1024::from_words([0x8e840fa4b9757130, 0x3a7e87872dc2a386, 0x6b1d10c97bba6b4b, 0x51524a6f98533e14, 0x634d61134e42bd66, 0x5c16e50000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0002702f2b5cc4f9, 0x30946bcf86d70edc, 0xda04f57a34846060, 0x1e971b735334d947, 0x061a99d2ea574492, 0x05a17b5c821ad36b]),
+  fnv0_1024_test_144: U1024, b"http://www.fpoa.net/", U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x000000000000511d, 0x5ee97ae03b926da4, 0x692c1ad49af2c53e, 0x1fef230000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000069ed89ec47b, 0xaa35348c6b675dd1, 0x1ee6eb6137b857f9]),
+  fnv0_1024_test_145: U1024, b"http://www.ioccc.org/index.html", U1024::from_words([0x0000000000000000, 0x00003e69f2bb8c6f, 0x5c1f14642f84f909, 0xa6ddf984386880c0, 0x63ce11b6e1e3138b, 0x482e100000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000339, 0xef8eedeb5e6eb41e, 0xde5399cb3672f4db, 0x33f5a4a3fc31b66c, 0x2deb77c9bdf48608]),
+  fnv0_1024_test_146: U1024, b"http://www.isthe.com/cgi-bin/number.cgi", U1024::from_words([0x000a5485249be546, 0x51053d1aa1f76179, 0xdd035fc4f822dced, 0x57c7377b0573f10d, 0xcabe16cd5d2362d3, 0x00d17e0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000006bec, 0x12ec067a772ef6fc, 0xcf0df8528d92ffac, 0xcc38bc3b53efd392, 0xdb5158b6cb333e00, 0x22f998999055ef7a]),
+  fnv0_1024_test_147: U1024, b"http://www.isthe.com/chongo/bio.html", U1024::from_words([0x00000000028d19b5, 0x66534c5300cdca9a, 0xeda4f840da2cb51b, 0x103ac94df2e02311, 0x0847b81aa151d931, 0x52e04a0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x001ceff750bdce05, 0x2d64b86491b79ae1, 0xe09ac8e754388e6e, 0x891a508e9d17445a, 0xc395d7ad3c7efeca]),
+  fnv0_1024_test_148: U1024, b"http://www.isthe.com/chongo/index.html", U1024::from_words([0x0000067c682dd1ff, 0x5a54d8844d7cdd21, 0xa8ac419181b50e12, 0x5a2bfc29680f645c, 0xeca78adb4150325a, 0x2ad39e0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000045, 0x97958d3c473b7177, 0xee7754744749e1b1, 0x1ed25c0a77819256, 0xb9e5c98899931d4b, 0x0dbaa2bad2df2548]),
+  fnv0_1024_test_149: U1024, b"http://www.isthe.com/chongo/src/calc/lucas-calc", U1024::from_words([0xc7e75d3c8cbbe965, 0x8022f599524f7228, 0xbe1ec606599a7a9c, 0xe96bf7a27d0d6c1d, 0x519a7b2baf4def3f, 0xda176d0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000000e1a10, 0x8ac38ad37d168521, 0x97caba5342b7197f, 0xa377573d8cfcf4f7, 0xe6b3ea5b5c7b86a0, 0xb23cbed4da365ae0, 0x7c103f2404045306]),
+  fnv0_1024_test_150: U1024, b"http://www.isthe.com/chongo/tech/astro/venus2004.html", U1024::from_words([0x1f8203a5c94c97c1, 0xa94fa35686a55334, 0xedda4c43493d173f, 0x2509ccaaa7171b4c, 0x67d15c5baf65d5d9, 0xf6cf800000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000000000c4, 0x253cd2cc8d3ce697, 0xb372fe3f397d0d44, 0x0bf2e90af8d595eb, 0x23aa0849db5cd908, 0x061c1e890131f532, 0xc4232e571b251b6a, 0x173b3f0f5fe23caa]),
+  fnv0_1024_test_151: U1024, b"http://www.isthe.com/chongo/tech/astro/vita.html", U1024::from_words([0x1850b87e0a2345aa, 0x6d505e681c8b46bb, 0xd2ae0fbfa87a8350, 0xa4fe26395881d34a, 0x34c3e01311221a14, 0xcc4d5b0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000015de6ba7, 0x313e49f8fbec7518, 0x6562f31e75f28af0, 0x80124a73a447e470, 0xc5026fe571254688, 0x578a2964742d48c4, 0xeddf7f999f542dd9]),
+  fnv0_1024_test_152: U1024, b"http://www.isthe.com/chongo/tech/comp/c/expert.html", U1024::from_words([0x9421f25a2b8af17f, 0x0f33ec8bbc91845f, 0xf487d206cdc745d5, 0xbd3688e8e8d524fe, 0xce8630f4d0d1d88e, 0x81d3760000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00518f5ba77562f6, 0xa9bb96864b96d22d, 0xe9eb72454be1fa71, 0xbe937e3ff95cecdb, 0xfc96cd083a5e44eb, 0x6b1aa23248d065e5, 0xb42a4c642d25dbdf]),
+  fnv0_1024_test_153: U1024, b"http://www.isthe.com/chongo/tech/comp/calc/index.html", U1024::from_words([0x1f8203a5c94c97c1, 0xa94fa35686a55334, 0xedda4c43493d18b0, 0x4daa134a599587be, 0x611ca2ff4d6fbf5c, 0xc34b3f0000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000000000c4, 0x253cd2cc8d3ce697, 0xb372fe3f397d0d44, 0x0bf2e90af8d595eb, 0x23aa0849db5cd908, 0x061c1ea6ffcd0db6, 0xaffceab1250e62fd, 0x9da448724425589d]),
+  fnv0_1024_test_154: U1024, b"http://www.isthe.com/chongo/tech/comp/fnv/index.html", U1024::from_words([0x4f7703bf71eac756, 0x75804672012888c7, 0x938d9089b4d454b0, 0xcbd73c9c2d4d5b2f, 0xb316a5eb5743ebd6, 0xb49b6f0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x7e7b5122b10a7885, 0x37e86e4338e3f133, 0xc2203576ad716264, 0x8abac935b51b5126, 0xb5dbf5c28430e115, 0x185179b8765b27b9, 0xdfee80ddce0dd256]),
+  fnv0_1024_test_155: U1024, b"http://www.isthe.com/chongo/tech/math/number/howhigh.html", U1024::from_words([0x404c272b54566862, 0x6f0af7c665ef551e, 0xfe47124d84779e11, 0xc9f75410d09c3e2c, 0x874a499bce1e2e4e, 0x29b88e0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000046e6fe66bef, 0xd52f31755e270eb8, 0x0b8423ac564a23e1, 0x8c1d10de3932e790, 0xaa5272e3453da685, 0xa99b80f5e127cac4, 0x5a9e4ebb98bdcf63, 0x7eb8a3f6063ef436]),
+  fnv0_1024_test_156: U1024, b"http://www.isthe.com/chongo/tech/math/number/number.html", U1024::from_words([0x76ea521d68fe7c6f, 0x15af1dc33d3d53c2, 0x51ccd6199bf30aa2, 0xd1e311023c75cad0, 0x7b3d94a690556c7c, 0x02bc260000000000, 0x0000000000000000, 0x0000000000000000, 0x00000002db86b4d3, 0x772fd635d12a0342, 0xe1b8c18a6dd6eacf, 0x8f81af701dce79d0, 0x006dd470c6239f0c, 0x96ac901f924cd5f3, 0x990df0b8e61bd19b, 0xc97737a368ba15a3]),
+  fnv0_1024_test_157: U1024, b"http://www.isthe.com/chongo/tech/math/prime/mersenne.html", U1024::from_words([0x404c272b54566862, 0x6f0af7c665ef551e, 0xfe47124d84779e09, 0x2d56c1b8e1a5a83b, 0xad10c68aa9aefda6, 0x4e04da0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000046e6fe66bef, 0xd52f31755e270eb8, 0x0b8423ac564a23e1, 0x8c1d10de3932e790, 0xaa5272e3453da685, 0xa99b80f5232c7575, 0x5ebc42a851a21c92, 0x333bd89f781ce081]),
+  fnv0_1024_test_158: U1024, b"http://www.isthe.com/chongo/tech/math/prime/mersenne.html#largest", U1024::from_words([0x0531e012c97b6815, 0xe4098c59a9eee697, 0x174a38693b953092, 0xd709700c9c49d148, 0x31c1000ba3fe9a3a, 0x2c9d9c0000000000, 0x0000000000000000, 0x0000943ba37def42, 0xdff0a4f0ebbcc291, 0xf61223d0dca34b47, 0x9b3b1f30b28b4f3b, 0x427d38f1415a6ecc, 0xa2c5e98e5c96fde5, 0x2314d5b35602c934, 0xac885815d31a5159, 0xbda20cd62653a0b8]),
+  fnv0_1024_test_159: U1024, b"http://www.lavarnd.org/cgi-bin/corpspeak.cgi", U1024::from_words([0x5f913ba42f8c5751, 0x9c9f50816735a570, 0x83f3aa1ada53b0e7, 0x5ad473df56bc2db5, 0x385c5ad0acfa22b5, 0x1a83be0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x03c7f9263edd7670, 0x562b30d41c4065ee, 0x92d1eac826887f98, 0x95b38cd516029ee7, 0xa6db5dc22a2b8f4a, 0x7530b9d7aca7ac71]),
+  fnv0_1024_test_160: U1024, b"http://www.lavarnd.org/cgi-bin/haiku.cgi", U1024::from_words([0x1070fe8cb1c90882, 0xd416bf1c3bd4eaba, 0x0bcc4615446ec42a, 0x16fe5cee8521bc96, 0x23742b7c920845ee, 0x9ddc540000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000a75d19, 0x58060bead3d5060d, 0x18bb8ab7fb1ad01f, 0xa19d3cf643665ef0, 0x730d2153cc40c9f7, 0xf5f40a939a6d0eed]),
+  fnv0_1024_test_161: U1024, b"http://www.lavarnd.org/cgi-bin/rand-none.cgi", U1024::from_words([0x5f913ba42f8c5751, 0x9c9f50816735a570, 0x83f3aa1ada53b0e7, 0x5ad473df56bc8742, 0x0bec2e2403c9dcdc, 0xfa14310000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x03c7f9263edd7670, 0x562b30d41c4065ee, 0x92d1eac826887f98, 0x95b38cd516029ee7, 0xa6db695a46988f15, 0xefeb1fdb9ee0c021]),
+  fnv0_1024_test_162: U1024, b"http://www.lavarnd.org/cgi-bin/randdist.cgi", U1024::from_words([0x8e840fa4b9757130, 0x3a881d79526c91f1, 0x31ff3467e662faf5, 0xf0bcf3245461cc8c, 0x0475c7b3e53487ac, 0x0d79850000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0002702f2b5cc4f9, 0x30946bcf86d7899b, 0x5c94d75265fb2f64, 0xfa92d4818105cf64, 0x887b12521f214556, 0xf891dcc19e9c287c]),
+  fnv0_1024_test_163: U1024, b"http://www.lavarnd.org/index.html", U1024::from_words([0x0000000000000000, 0xa01b6b131a1b3eb5, 0xd528e1db2700b826, 0x6a15d872d0ac055b, 0x213eb9d6fa60b2a1, 0xa698a90000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000007c24fbf, 0x82eb803d49cb107b, 0x9c772a75682be6a8, 0xb85c02e449724fc5, 0x06e90aac12fd8093]),
+  fnv0_1024_test_164: U1024, b"http://www.lavarnd.org/what/nist-test.html", U1024::from_words([0x64dca4fe121f3ee3, 0x5ce59c15abb287fa, 0xafb976b1f5746a08, 0x3a5caaba6133be57, 0xea6af99eacfe82cd, 0x0368af0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000001927f245831, 0xa29f00ea992a750f, 0xde0e8fb22eaf6222, 0x6e40da7c9a8210af, 0x9d796e82d82f3bce, 0x2b92e05461f15313]),
+  fnv0_1024_test_165: U1024, b"http://www.macosxhints.com/", U1024::from_words([0x0000000000000000, 0x000000000000095a, 0x42e763de13a5eb60, 0xb9ee6719a6da3d6f, 0x5aa8c480f779d3ec, 0xb8813f0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000008ece2386d4, 0x07968e6fc2922a13, 0x65b6da43130d8074, 0xba556066db8fe1a4]),
+  fnv0_1024_test_166: U1024, b"http://www.mellis.com/", U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x00000000d79bc742, 0xfbba5b0d1e0bdf51, 0x0cd1587e9112af54, 0x599c860000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0febf9426ad4cb89, 0x2e5d06b83c4783f3, 0x03f29ca8cc8c5529]),
+  fnv0_1024_test_167: U1024, b"http://www.nature.nps.gov/air/webcams/parks/havoso2alert/havoalert.cfm", U1024::from_words([0x118ae06af38a3a80, 0x977f3b95b3652099, 0x1a178408e748fcc5, 0xedad546846f651dc, 0xf7d32d02ff244aed, 0x5a918e0000000000, 0x000000000531872d, 0x0eb19bf75022cb4f, 0xdfe01cb31d617c17, 0x6ba36d1240594843, 0xdb01562c43065c1b, 0xf510384b044fe58d, 0x8186d0e42d0b78ef, 0x2877e36919006824, 0x0d63677aca4aa6de, 0x77044bc6b9b0af73]),
+  fnv0_1024_test_168: U1024, b"http://www.nature.nps.gov/air/webcams/parks/havoso2alert/timelines_24.cfm", U1024::from_words([0xe00d27bc07c8f443, 0x05b00687eb96ddc1, 0x33c6be465cf800cb, 0x1286e2e72ecc8404, 0x63776e1b6154c72d, 0xcf78330000000000, 0x00135e7c81e1d0a5, 0x52c7e05d75ca2e80, 0x4680e12056d3f516, 0x7fa681b9e3ac38a8, 0x1a0535dc8b25e07e, 0x1c533e2507f233a8, 0xb5db056e035a07e6, 0x29ef0efc71e59dcc, 0x175822e87c70be34, 0x4573049688eff550]),
+  fnv0_1024_test_169: U1024, b"http://www.paulnoll.com/", U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x000237e774a0754c, 0xde0a18a3e229ac41, 0x2667d9ee1a9f87ba, 0x022a320000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000000000000264a, 0x66961b7dbb70ebf1, 0x168e0c7655bd7f48, 0x59ab0ca3e64ee282]),
+  fnv0_1024_test_170: U1024, b"http://www.pepysdiary.com/", U1024::from_words([0x0000000000000000, 0x0000000000000005, 0xcc86b32708fd1085, 0x11a5b876fcd537cc, 0x1927902982ba0c25, 0x8bacd40000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000000005c160390, 0x5523334d045bef57, 0x23b21cf2615b4f33, 0x78dcfb7e14f3809b]),
+  fnv0_1024_test_171: U1024, b"http://www.sciencenews.org/index/home/activity/view", U1024::from_words([0x952063593dd71c5b, 0x28ba3fd898f488e1, 0x3e3617810b3ca87f, 0xd028eb3929649e76, 0xf0a11d57c236421d, 0xad38700000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00518f5ba77562f6, 0xa9bb96864ba0f35c, 0xa606742fcf22dc29, 0x54491c2a0256b2da, 0xaae469753628c9e2, 0x8882199042136d6d, 0x8d5bab0cc115feb5]),
+  fnv0_1024_test_172: U1024, b"http://www.skyandtelescope.com/", U1024::from_words([0x0000000000000000, 0x00003e69f2bb8c6f, 0x5c1f14642f84fdf5, 0x064c6b3259cc57d3, 0xa66fe0e2b45b14b0, 0xd4b4360000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000339, 0xef8eedeb5e6eb41e, 0xde539a3247a233f2, 0x75aedcccf44d6d1b, 0x3a3781d691cb7aab]),
+  fnv0_1024_test_173: U1024, b"http://www.sput.nl/~rob/sirius.html", U1024::from_words([0x000000000001991c, 0x3bac8f621b614ffe, 0xbfb1fced1f580ddb, 0x5c0434075784af33, 0x5397bdca0885044e, 0xbdddbb0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000012a8ecdbb8f1, 0x2e8ae4bc8ba6d6d7, 0x4eee7628e983699c, 0xc57a760cdba304a4, 0xa0606854261e9ee4]),
+  fnv0_1024_test_174: U1024, b"http://www.systemexperts.com/", U1024::from_words([0x0000000000000000, 0x000000001838eeaa, 0x3bd90e5786c462ff, 0xcf45d6e9609fc8b0, 0xc4a639ab2977eb84, 0x63a33c0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x01576f7d6e5b6033, 0xb292843495a31209, 0x6a0ed9befbe261c0, 0x894e6f91e6a06b43]),
+  fnv0_1024_test_175: U1024, b"http://www.tq-international.com/phpBB3/index.php", U1024::from_words([0x1850b87e8ad50bea, 0x144604f75df76891, 0x074aec53281f540e, 0x0183cb1327cf3ca2, 0x73e3a1541374b6de, 0x8d1bc80000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000015de6ba7, 0x313e49f8fbec7518, 0x6aedacbc3a1c1818, 0xa9284d01bdf693cc, 0x9b8dc20a71afa943, 0xe3beb733186b20d8, 0xd26a351495c363ed]),
+  fnv0_1024_test_176: U1024, b"http://www.travelquesttours.com/index.htm", U1024::from_words([0x2697d987bace1db6, 0xbc4c6be459e13eaf, 0xf04fa19b833f3947, 0x3903000b9d66d178, 0xf145db4fba26a902, 0xbcd4eb0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000001038b604d, 0x81607b2a815a624f, 0x8189109f4ca5e8e2, 0x5efee8d58e32ab15, 0x88893d938a637ab5, 0x1b9a55bb2cb45c77]),
+  fnv0_1024_test_177: U1024, b"http://www.wunderground.com/global/stations/89606.html", U1024::from_words([0xffcb27ff3755fe45, 0x04cd294f59aa54e8, 0x1c3b4e6fdb65c250, 0x4f389c63bfe08192, 0xf1504faf5d1f4e10, 0x66aae10000000000, 0x0000000000000000, 0x0000000000000000, 0x000000000001302d, 0xbf52e73707719941, 0x4954840d685cac7c, 0x2f4434fd8f12e25f, 0xd98beccd3eea8c30, 0x3a9ada8238e44429, 0x2157ec2a1bc84e4c, 0x8e3889c4a858c0fd]),
+  fnv0_1024_test_178: U1024, &repeat(b"21701", 10), U1024::from_words([0xdd422833eed4f542, 0x3836c54d081aa19a, 0x52880c0b29b10918, 0x27379a0c0f21a628, 0x9c02b4568620b527, 0x9f8b050000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000195abdfb6598, 0x7381768c68218267, 0x78a69c887d95a4a2, 0x3639320c237afa43, 0x079914595d6fb9c0, 0x893c2dbf6df60aa0, 0xa427d17983120f0c]),
+  fnv0_1024_test_179: U1024, &repeat(b"M21701", 10), U1024::from_words([0xde1f2db3c0f18865, 0xd0765e4ba9891126, 0x0d3f58e7a13c235b, 0x6e8458c7bb504807, 0x648c75750f900eec, 0x2fd0980000000000, 0x0000000000000000, 0x000000000000000c, 0x3bf26e601c93a926, 0xa86e30a155818e7c, 0x4e1010f54d5854b8, 0xb241a0c36d4a10bb, 0x19ca891f60a607d2, 0x164040c15c1796df, 0xf3ca1dda258f5f43, 0xfad53d7b3e67b720]),
+  fnv0_1024_test_180: U1024, &repeat(b"2^21701-1", 10), U1024::from_words([0xa3662f6e94d44f7d, 0x118508ff4c6f675a, 0x6d8068f39bcd16d1, 0xc3e200fa803e6a7d, 0x7c6a396687eff51a, 0x4e3821dccd792e9c, 0x1fa23df5081ecc2c, 0xe2fe78bec35dbd59, 0x8c0248e6eaa3eda6, 0x86a02c43abcf475c, 0x7b47431beba0ea43, 0x1396490532eac4fe, 0x6bf55161c67b27fb, 0x1f271a15fb615e1a, 0x6f2ed402755fe9b6, 0xd9d0164a4af0e804]),
+  fnv0_1024_test_181: U1024, &repeat(b"\x54\xc5", 10), U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x00000000000041a5, 0xb5a801f3a73db013, 0x9302d8cf1481c7ea, 0xb639820000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000055bd0d74fbd, 0x4fde859c5227d6b0, 0xd065cce2b3bbc1c0]),
+  fnv0_1024_test_182: U1024, &repeat(b"\xc5\x54", 10), U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x00000000000099d9, 0x1d9a9ab04b9950c1, 0x34ac1e2a798d6cf6, 0x2323fa0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000c8ecc496a11, 0x4ab6fa8f5b2d8075, 0x9f941764bc051810]),
+  fnv0_1024_test_183: U1024, &repeat(b"23209", 10), U1024::from_words([0x1468d1c3e27e168c, 0x9ecaf825e64dcb3b, 0x2421d1cd6f7d1333, 0x1da4cbf2a20a11c0, 0x1f95d9281231c0c1, 0x5112f00000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000195a1f992e46, 0xc43bde64014f8f74, 0x59b68f5c1ec82b4d, 0x27e55dd04882d880, 0x415fcdb9267356c1, 0x834d2e399852428a, 0xd16f7c7ed7c1f2c0]),
+  fnv0_1024_test_184: U1024, &repeat(b"M23209", 10), U1024::from_words([0x698c91f7cc7a7ae2, 0x43e6dd53acbeacbd, 0xef745aa36112cbc8, 0x5f879c49473a768c, 0xe63ae919a6205b14, 0x1772920000000000, 0x0000000000000000, 0x000000000000000c, 0x3bf24a19d9e5847f, 0xa54c621d91c96ebb, 0xdb888267747a48bf, 0xd5356e3aa55583fa, 0x9f0c48b76b4e2f9c, 0x2cde74f9284d6a20, 0x07fff239fcb822cc, 0x5aef5673349c4da0]),
+  fnv0_1024_test_185: U1024, &repeat(b"2^23209-1", 10), U1024::from_words([0x3b8e914d8c0483e1, 0x798aed6a5543f4ad, 0xe29f303c124b869d, 0x903d9879f8c359b6, 0x68688ac228285fa7, 0x5850a9a123f2f10e, 0x9b42051aa7436011, 0x6bc5d01599eec44c, 0x1162d9c30ed6e32c, 0x315817e789bb65d5, 0x81e247c2f34e2866, 0xc4bb7ce2f67e0eab, 0xa4f6bf8612102df3, 0xb37148228962ad22, 0xb22bac17bde8d5d3, 0xfd719e472c9af408]),
+  fnv0_1024_test_186: U1024, &repeat(b"\x5a\xa9", 10), U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x000000000000460d, 0x4f4c61d3fbd06e1f, 0x9f4c8d4d712bc166, 0x986b460000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000005b78767fd0c, 0xabb33ea2c98f933e, 0xf5b5947867f564a8]),
+  fnv0_1024_test_187: U1024, &repeat(b"\xa9\x5a", 10), U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x00000000000083f4, 0x5d1fe8c7e69547f5, 0x7ada2a4135f1d268, 0x8afc760000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000ac547a6ff7e, 0x4b35939cab30c3ee, 0x4798ea5acb2fbb58]),
+  fnv0_1024_test_188: U1024, &repeat(b"391581216093", 10), U1024::from_words([0x2da05874444f8736, 0xd779edf93a7b72e5, 0xbd902d5d9196ffca, 0x4a6d9e26539a1f5e, 0x725c833feec966c7, 0x63acf0e360cf7ca8, 0xe5ef524cf5e1144e, 0x3b42de3394e957bc, 0x044ddc2aee6e892e, 0xe2f522fcc82f89e9, 0x4f612e8f7c79b4d4, 0x8261965917c62e09, 0x397f7b4898d2684a, 0xcbfac8acbd06e716, 0xd93867d59100594d, 0x50b745a75b645298]),
+  fnv0_1024_test_189: U1024, &repeat(b"391581*2^216093-1", 10), U1024::from_words([0x060656a784ddbdad, 0xdc52b211bd2fe8e3, 0x0934030b47d05d36, 0xc34050a666e2cdf6, 0x29dd3e07ab52afaa, 0x8c0fd3e7b5698bd0, 0x6cc781906716d44b, 0x71359b5a4f3e4ac7, 0x824e68b134abba5d, 0x0b1d53ef0bac41a2, 0x00dad80eaaac5a31, 0x921a64f5af50114d, 0x22282304c8eb8ece, 0x3875c3016bd13df6, 0xa87de988532184b1, 0xd80dcba6ecf3a750]),
+  fnv0_1024_test_190: U1024, &repeat(b"\x05\xf9\x9d\x03\x4c\x81", 10), U1024::from_words([0x35b67d876a5a78a5, 0x7c72a81baf6d1668, 0x490e3a4a0a7c86c0, 0x89aac6c915598f12, 0x27d3db2d56f05ccc, 0xed57120000000000, 0x0000000000000000, 0x0000000000000000, 0xbd62aa4219d5a13b, 0xdb243096c64d9cfa, 0x186ac551da040e41, 0x57d1d4a8bd81a0bc, 0x26e21c66f69f6f69, 0x6bf037ea06fcd36b, 0x47097178c5a4c1a7, 0x97f2963d23f5e240]),
+  fnv0_1024_test_191: U1024, &repeat(b"FEDCBA9876543210", 10), U1024::from_words([0x715256faceb8a5a3, 0xcec70fdad6cee32b, 0x77aa9c6f648017d7, 0x7e5d567a886e6b12, 0x430b021032e71a43, 0x4e17b3a13d6ec469, 0xf08c81654fa59158, 0xe797763a101ebd9f, 0xfb9023161b7137f2, 0x41f024ab7983727f, 0xbb9e4bb783169e22, 0x6931ccdeed33c5a6, 0xbd6dd1f7e8b21429, 0xac6125b1afcb1edd, 0xfcf64969b8793f63, 0x4f493960f16d1600]),
+  fnv0_1024_test_192: U1024, &repeat(b"\xfe\xdc\xba\x98\x76\x54\x32\x10", 10), U1024::from_words([0x28395e43bb0afa63, 0xe92f709adbf838d7, 0xa15c14f68a5fcc26, 0x4e8b553781224aeb, 0xc2608ae30dbfd8da, 0x235ffaed88493504, 0xfbb8b89b88408205, 0xb83a538938ecb0af, 0x547caf8092498d6f, 0x23e8fe6b09287067, 0xf408a1b860e2d4b3, 0xd032e501f8a19993, 0x4506008f1d73a22e, 0x5215a67932891004, 0x76ebf0301dced6c5, 0x364b45ddf0e42160]),
+  fnv0_1024_test_193: U1024, &repeat(b"EFCDAB8967452301", 10), U1024::from_words([0x40ce11082d785b2d, 0xe902d488e8013e42, 0x91d65619785d8ee4, 0x750e29212f72488f, 0x7fcc95fcb87ccd49, 0xed43b2857b54d1ea, 0xefa2488c032eca7f, 0xadc624642266e34d, 0x71719a10bdd079e1, 0x478e9a653031a3a2, 0x157882435ddf7d31, 0x353a3877b4a2b6ee, 0x0d9565360b4b3631, 0x01e27769a2b16c8d, 0x0189721868b0d4a0, 0xa2d6d3828d9d24f0]),
+  fnv0_1024_test_194: U1024, &repeat(b"\xef\xcd\xab\x89\x67\x45\x23\x01", 10), U1024::from_words([0xdb3a39a065efc11f, 0x097963b4868e65fc, 0x8420455cd8a9e131, 0xb6dadd60df35bb8d, 0xf0d788d7a0e9b396, 0xf536e7dbc3df3dd7, 0x447d31b9082c72c2, 0x447bd34036840aa3, 0xd80e3cb128e5b0cc, 0x806318115bf9165b, 0xe7ee6f558aa68f76, 0x7a9eb5a362a7b5c1, 0x6df20c3044397ebe, 0x733c51f1839b62d1, 0x2f84611ff476a54a, 0x5ef4066218c749e0]),
+  fnv0_1024_test_195: U1024, &repeat(b"0123456789ABCDEF", 10), U1024::from_words([0x98aa42015cb08484, 0x7e6d9d744180197c, 0xf2ccbab02ec00352, 0x091362108a038709, 0x134cecc68dc276e9, 0xb7376f40646a1b6f, 0xb42200224188a44d, 0xb6f268556f521f2c, 0x16b678f00a0a74c8, 0xa0e5c518da14e703, 0x7d236bb750e43c43, 0x65e9bdc7e1814755, 0x156afa45ca056987, 0x262f7c60b74a8ec7, 0x590faf8cc1ae1ad7, 0x6ed76c38877281b0]),
+  fnv0_1024_test_196: U1024, &repeat(b"\x01\x23\x45\x67\x89\xab\xcd\xef", 10), U1024::from_words([0x8bee748e57454d27, 0x3aecabfb945d64ae, 0xca622161a35346de, 0xaeef30461a8c0cae, 0x599ae627351ce311, 0x8ca48c59c7e71141, 0x1c2b01df1ce51c24, 0x645f70fc6df24951, 0x5b4fe5308e663793, 0x2eda7c25dcbef82a, 0x903440afbe76e214, 0x86ec01d2f240f658, 0x02234ce1abe59068, 0x51ed99b4be1ac898, 0x5bb54255501ae83f, 0xc040428a45091c60]),
+  fnv0_1024_test_197: U1024, &repeat(b"1032547698BADCFE", 10), U1024::from_words([0xe59462ddf84996af, 0xf41fbdbf9fac782e, 0x67ca410dfdbea4b5, 0x9b97290da7848622, 0x93f26dcefffdc895, 0x9214757237bdf7dd, 0x38ecdaed67aeb6b0, 0x3fbd9705488d4b57, 0x6949a91a626b3017, 0xcf5a74ef9caeb049, 0x1aa01fafe3cc4ea9, 0xbf4d7cde8af5a9e8, 0xd678fb0709730288, 0x8dd5ad7ad8be58ee, 0xd5e0f2ad97578998, 0x77a87bff3ca8ad40]),
+  fnv0_1024_test_198: U1024, &repeat(b"\x10\x32\x54\x76\x98\xba\xdc\xfe", 10), U1024::from_words([0x7cb2fd7e19c94375, 0x376a2653303112c8, 0x58fdaa9710ac4fda, 0xee57c3be413173ef, 0x1f486f2b5cfbf3c7, 0x4abaa079811a512a, 0x6ffed38edcb09769, 0x6da94aff3439fef1, 0x1c45409df21254f9, 0x28240d87c9b1906c, 0x4af59dfe1c6267cd, 0x5bc7ea5d80b14a7f, 0x6e2c3a252312daf1, 0x48cf484690157e98, 0x2f98c9a4f198d8a9, 0x06000eb17a813320]),
+  fnv0_1024_test_199: U1024, &repeat(b"\x00", 500), U1024::from_words([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]),
+  fnv0_1024_test_200: U1024, &repeat(b"\x07", 500), U1024::from_words([0xcd53031c90aae865, 0x8278070f9b77fa6d, 0x92a5e298445017a8, 0x628b0bcc7dd2161d, 0x8e51387f4a1d3887, 0xcfa8f85c0f0ee83e, 0x32b89ee21a467bb8, 0xe72eb03ed8b783a4, 0x9d2f1ce7ec04bffb, 0xb892c31d99dcdf87, 0xe8ef1e9f0912444f, 0x71ba9eca674a0056, 0x938e5c77db1243d1, 0x7f034b9c3884801e, 0xa59bc587eaac7708, 0x1e4790fdea9fa208]),
+  fnv0_1024_test_201: U1024, &repeat(b"~", 500), U1024::from_words([0x2fbc90d86d8da35c, 0x37dd8e3dda12f9ec, 0x73034f13751051e1, 0x8d04e1d97447d16b, 0x46c21e341e698ef8, 0x91f410b0261ed706, 0xd3f0d9e29b5d7d0f, 0x293539fe6f74ece1, 0x34f64b20aa09b1d6, 0x1c4de2faedc5d528, 0x674728e10bef3e3d, 0xf8634d8a0fe1e239, 0x0b4f2b0141f7aabc, 0x757968a280eba9e0, 0x7e41bec25242009a, 0x53a2fe8d375abc30]),
+  fnv0_1024_test_202: U1024, &repeat(b"\x7f", 500), U1024::from_words([0x094689aeab30b6a0, 0xe42fbf0faab67332, 0x444b5b61d9f20b0e, 0x1f469162044637b4, 0xbe3a18f7bf6a24ea, 0xc4229bb5d15097cd, 0x23cf72958034c808, 0xb7defda4d5da431d, 0xdd158bd2b2a00a9f, 0x11645e16af6c13d8, 0x46f04b6c67d599f7, 0x2a608102c767096a, 0xe3cc77aeabc57c7a, 0x0193c2518ca765bc, 0x3382dfcb8e7e4925, 0xc136eed477dc2d18]),
 }
 fnv1_tests!{
   fnv1_32_test_0: u32, b"", 0x811c9dc5,
@@ -814,6 +1626,818 @@ fnv1_tests!{
   fnv1_64_test_200: u64, &repeat(b"\x07", 500), 0x0289a488a8df69d9,
   fnv1_64_test_201: u64, &repeat(b"~", 500), 0x15e96e1613df98b5,
   fnv1_64_test_202: u64, &repeat(b"\x7f", 500), 0xe6be57375ad89b99,
+  fnv1_128_test_0: u128, b"", 0x6c62272e07bb014262b821756295c58d,
+  fnv1_128_test_1: u128, b"a", 0xd228cb69101a8caf78912b704e4a141e,
+  fnv1_128_test_2: u128, b"b", 0xd228cb69101a8caf78912b704e4a141d,
+  fnv1_128_test_3: u128, b"c", 0xd228cb69101a8caf78912b704e4a141c,
+  fnv1_128_test_4: u128, b"d", 0xd228cb69101a8caf78912b704e4a141b,
+  fnv1_128_test_5: u128, b"e", 0xd228cb69101a8caf78912b704e4a141a,
+  fnv1_128_test_6: u128, b"f", 0xd228cb69101a8caf78912b704e4a1419,
+  fnv1_128_test_7: u128, b"fo", 0x0880945ae9ab1be95aa073305526baac,
+  fnv1_128_test_8: u128, b"foo", 0xa68bb298318b5822836dbc78c6a7b1cb,
+  fnv1_128_test_9: u128, b"foob", 0x66ab68f6c1757277b806e89c7057c4ab,
+  fnv1_128_test_10: u128, b"fooba", 0xf15a7f64b683d94f7080387e3bfefe08,
+  fnv1_128_test_11: u128, b"foobar", 0x7896bfea9c3c64bf6dc58353d2c293aa,
+  fnv1_128_test_12: u128, ::std::ffi::CString::new("").unwrap().as_bytes_with_nul(), 0xd228cb69101a8caf78912b704e4a147f,
+  fnv1_128_test_13: u128, ::std::ffi::CString::new("a").unwrap().as_bytes_with_nul(), 0x0880945aeeab1be95aa073305526c0ea,
+  fnv1_128_test_14: u128, ::std::ffi::CString::new("b").unwrap().as_bytes_with_nul(), 0x0880945aedab1be95aa073305526bfaf,
+  fnv1_128_test_15: u128, ::std::ffi::CString::new("c").unwrap().as_bytes_with_nul(), 0x0880945aecab1be95aa073305526be74,
+  fnv1_128_test_16: u128, ::std::ffi::CString::new("d").unwrap().as_bytes_with_nul(), 0x0880945aebab1be95aa073305526bd39,
+  fnv1_128_test_17: u128, ::std::ffi::CString::new("e").unwrap().as_bytes_with_nul(), 0x0880945aeaab1be95aa073305526bbfe,
+  fnv1_128_test_18: u128, ::std::ffi::CString::new("f").unwrap().as_bytes_with_nul(), 0x0880945ae9ab1be95aa073305526bac3,
+  fnv1_128_test_19: u128, ::std::ffi::CString::new("fo").unwrap().as_bytes_with_nul(), 0xa68bb298318b5822836dbc78c6a7b1a4,
+  fnv1_128_test_20: u128, ::std::ffi::CString::new("foo").unwrap().as_bytes_with_nul(), 0x66ab68f6c1757277b806e89c7057c4c9,
+  fnv1_128_test_21: u128, ::std::ffi::CString::new("foob").unwrap().as_bytes_with_nul(), 0xf15a7f64b683d94f7080387e3bfefe69,
+  fnv1_128_test_22: u128, ::std::ffi::CString::new("fooba").unwrap().as_bytes_with_nul(), 0x7896bfea9c3c64bf6dc58353d2c293d8,
+  fnv1_128_test_23: u128, ::std::ffi::CString::new("foobar").unwrap().as_bytes_with_nul(), 0xb550e841e84ff78c12089824556bb22e,
+  fnv1_128_test_24: u128, b"ch", 0x0880945aecab1be95aa073305526be1c,
+  fnv1_128_test_25: u128, b"cho", 0xa68bb29f528b5822836dbc78c6abec1b,
+  fnv1_128_test_26: u128, b"chon", 0x66ab75f6ac757277b806e89c758b8557,
+  fnv1_128_test_27: u128, b"chong", 0xf16fb20b8b83d94f70803884a2ad126a,
+  fnv1_128_test_28: u128, b"chongo", 0x9912c147153c64bf6dc58b342af5a801,
+  fnv1_128_test_29: u128, b"chongo ", 0x8e3ec81f224ff78c12124930dc45b91b,
+  fnv1_128_test_30: u128, b"chongo w", 0x381c84085365995a3c800f1f09cac44e,
+  fnv1_128_test_31: u128, b"chongo wa", 0x2a204102ec03b20871929b310c7f8b9b,
+  fnv1_128_test_32: u128, b"chongo was", 0x06bc7e24038c1063bf64f55a60f0c7ca,
+  fnv1_128_test_33: u128, b"chongo was ", 0xa450271827582abc8139e6354845d5ae,
+  fnv1_128_test_34: u128, b"chongo was h", 0x63e8608e177c95f3023e438fededed72,
+  fnv1_128_test_35: u128, b"chongo was he", 0x7edcbcc4584c8203c29d2219c3c32b23,
+  fnv1_128_test_36: u128, b"chongo was her", 0x336008c3c923faa07758f5b3e1261463,
+  fnv1_128_test_37: u128, b"chongo was here", 0xeb0beefce2456372da76545609db15b4,
+  fnv1_128_test_38: u128, b"chongo was here!", 0x8db8ec401f615e52cf99c5de2093b45d,
+  fnv1_128_test_39: u128, b"chongo was here!\n", 0x40ab469af9cf0fe57236785215beee65,
+  fnv1_128_test_40: u128, ::std::ffi::CString::new("ch").unwrap().as_bytes_with_nul(), 0xa68bb29f528b5822836dbc78c6abec74,
+  fnv1_128_test_41: u128, ::std::ffi::CString::new("cho").unwrap().as_bytes_with_nul(), 0x66ab75f6ac757277b806e89c758b8539,
+  fnv1_128_test_42: u128, ::std::ffi::CString::new("chon").unwrap().as_bytes_with_nul(), 0xf16fb20b8b83d94f70803884a2ad120d,
+  fnv1_128_test_43: u128, ::std::ffi::CString::new("chong").unwrap().as_bytes_with_nul(), 0x9912c147153c64bf6dc58b342af5a86e,
+  fnv1_128_test_44: u128, ::std::ffi::CString::new("chongo").unwrap().as_bytes_with_nul(), 0x8e3ec81f224ff78c12124930dc45b93b,
+  fnv1_128_test_45: u128, ::std::ffi::CString::new("chongo ").unwrap().as_bytes_with_nul(), 0x381c84085365995a3c800f1f09cac439,
+  fnv1_128_test_46: u128, ::std::ffi::CString::new("chongo w").unwrap().as_bytes_with_nul(), 0x2a204102ec03b20871929b310c7f8bfa,
+  fnv1_128_test_47: u128, ::std::ffi::CString::new("chongo wa").unwrap().as_bytes_with_nul(), 0x06bc7e24038c1063bf64f55a60f0c7b9,
+  fnv1_128_test_48: u128, ::std::ffi::CString::new("chongo was").unwrap().as_bytes_with_nul(), 0xa450271827582abc8139e6354845d58e,
+  fnv1_128_test_49: u128, ::std::ffi::CString::new("chongo was ").unwrap().as_bytes_with_nul(), 0x63e8608e177c95f3023e438fededed1a,
+  fnv1_128_test_50: u128, ::std::ffi::CString::new("chongo was h").unwrap().as_bytes_with_nul(), 0x7edcbcc4584c8203c29d2219c3c32b46,
+  fnv1_128_test_51: u128, ::std::ffi::CString::new("chongo was he").unwrap().as_bytes_with_nul(), 0x336008c3c923faa07758f5b3e1261411,
+  fnv1_128_test_52: u128, ::std::ffi::CString::new("chongo was her").unwrap().as_bytes_with_nul(), 0xeb0beefce2456372da76545609db15d1,
+  fnv1_128_test_53: u128, ::std::ffi::CString::new("chongo was here").unwrap().as_bytes_with_nul(), 0x8db8ec401f615e52cf99c5de2093b47c,
+  fnv1_128_test_54: u128, ::std::ffi::CString::new("chongo was here!").unwrap().as_bytes_with_nul(), 0x40ab469af9cf0fe57236785215beee6f,
+  fnv1_128_test_55: u128, ::std::ffi::CString::new("chongo was here!\n").unwrap().as_bytes_with_nul(), 0xe4d59f9fc6c88f5389060d00c1ef5647,
+  fnv1_128_test_56: u128, b"cu", 0x0880945aecab1be95aa073305526be01,
+  fnv1_128_test_57: u128, b"cur", 0xa68bb29f378b5822836dbc78c6abcb49,
+  fnv1_128_test_58: u128, b"curd", 0x66ab75b4a1757277b806e89c756322b7,
+  fnv1_128_test_59: u128, b"curds", 0xf16f38656283d94f7080388470fbb75e,
+  fnv1_128_test_60: u128, b"curds ", 0x984b6077963c64bf6dc58af705baa08a,
+  fnv1_128_test_61: u128, b"curds a", 0x5bc56dc6664ff78c1211fdf40ca389af,
+  fnv1_128_test_62: u128, b"curds an", 0xdffab6a99365995a3c237b4b8d3a6a3b,
+  fnv1_128_test_63: u128, b"curds and", 0xe50bfd129903b207ffa8b5f6c6e0b6fd,
+  fnv1_128_test_64: u128, b"curds and ", 0xcc874699448c0fd79497e6a6b681296f,
+  fnv1_128_test_65: u128, b"curds and w", 0x512a5fc0c7577e43d6e8cf2290edfbe2,
+  fnv1_128_test_66: u128, b"curds and wh", 0x01b4c0312aa85d797076df8854d4ef7e,
+  fnv1_128_test_67: u128, b"curds and whe", 0xa1bd516efb2b046d62450cc06202b06f,
+  fnv1_128_test_68: u128, b"curds and whey", 0xc455363f7cee7297eaf6b0b8994f18ec,
+  fnv1_128_test_69: u128, b"curds and whey\n", 0x4d730f37a56700ee1d8b7324a453aa6e,
+  fnv1_128_test_70: u128, ::std::ffi::CString::new("cu").unwrap().as_bytes_with_nul(), 0xa68bb29f378b5822836dbc78c6abcb3b,
+  fnv1_128_test_71: u128, ::std::ffi::CString::new("cur").unwrap().as_bytes_with_nul(), 0x66ab75b4a1757277b806e89c756322d3,
+  fnv1_128_test_72: u128, ::std::ffi::CString::new("curd").unwrap().as_bytes_with_nul(), 0xf16f38656283d94f7080388470fbb72d,
+  fnv1_128_test_73: u128, ::std::ffi::CString::new("curds").unwrap().as_bytes_with_nul(), 0x984b6077963c64bf6dc58af705baa0aa,
+  fnv1_128_test_74: u128, ::std::ffi::CString::new("curds ").unwrap().as_bytes_with_nul(), 0x5bc56dc6664ff78c1211fdf40ca389ce,
+  fnv1_128_test_75: u128, ::std::ffi::CString::new("curds a").unwrap().as_bytes_with_nul(), 0xdffab6a99365995a3c237b4b8d3a6a55,
+  fnv1_128_test_76: u128, ::std::ffi::CString::new("curds an").unwrap().as_bytes_with_nul(), 0xe50bfd129903b207ffa8b5f6c6e0b699,
+  fnv1_128_test_77: u128, ::std::ffi::CString::new("curds and").unwrap().as_bytes_with_nul(), 0xcc874699448c0fd79497e6a6b681294f,
+  fnv1_128_test_78: u128, ::std::ffi::CString::new("curds and ").unwrap().as_bytes_with_nul(), 0x512a5fc0c7577e43d6e8cf2290edfb95,
+  fnv1_128_test_79: u128, ::std::ffi::CString::new("curds and w").unwrap().as_bytes_with_nul(), 0x01b4c0312aa85d797076df8854d4ef16,
+  fnv1_128_test_80: u128, ::std::ffi::CString::new("curds and wh").unwrap().as_bytes_with_nul(), 0xa1bd516efb2b046d62450cc06202b00a,
+  fnv1_128_test_81: u128, ::std::ffi::CString::new("curds and whe").unwrap().as_bytes_with_nul(), 0xc455363f7cee7297eaf6b0b8994f1895,
+  fnv1_128_test_82: u128, ::std::ffi::CString::new("curds and whey").unwrap().as_bytes_with_nul(), 0x4d730f37a56700ee1d8b7324a453aa64,
+  fnv1_128_test_83: u128, ::std::ffi::CString::new("curds and whey\n").unwrap().as_bytes_with_nul(), 0x71380d22f3be24fe5a96ae1632f2b55a,
+  fnv1_128_test_84: u128, b"hi", 0x0880945ae7ab1be95aa073305526b824,
+  fnv1_128_test_85: u128, ::std::ffi::CString::new("hi").unwrap().as_bytes_with_nul(), 0xa68bb293338b5822836dbc78c6a4944c,
+  fnv1_128_test_86: u128, b"hello", 0xf14b58486483d94f708038798c29697f,
+  fnv1_128_test_87: u128, ::std::ffi::CString::new("hello").unwrap().as_bytes_with_nul(), 0x6141ca7d2d3c64bf6dc57d8f76f4cf45,
+  fnv1_128_test_88: u128, b"\xff\x00\x00\x01", 0x66ad3d06e1757277b806e89d305ddd81,
+  fnv1_128_test_89: u128, b"\x01\x00\x00\xff", 0x66ad33f14b757277b806e89d2ca40205,
+  fnv1_128_test_90: u128, b"\xff\x00\x00\x02", 0x66ad3d06e1757277b806e89d305ddd82,
+  fnv1_128_test_91: u128, b"\x02\x00\x00\xff", 0x66ad2f6680757277b806e89d2ac71548,
+  fnv1_128_test_92: u128, b"\xff\x00\x00\x03", 0x66ad3d06e1757277b806e89d305ddd83,
+  fnv1_128_test_93: u128, b"\x03\x00\x00\xff", 0x66ad2adbb5757277b806e89d28ea288b,
+  fnv1_128_test_94: u128, b"\xff\x00\x00\x04", 0x66ad3d06e1757277b806e89d305ddd84,
+  fnv1_128_test_95: u128, b"\x04\x00\x00\xff", 0x66ad2650ea757277b806e89d270d3bce,
+  fnv1_128_test_96: u128, b"\x40\x51\x4e\x44", 0x66ac168b76757277b806e89cb7bce0e2,
+  fnv1_128_test_97: u128, b"\x44\x4e\x51\x40", 0x66ac04370d757277b806e89cb0302af4,
+  fnv1_128_test_98: u128, b"\x40\x51\x4e\x4a", 0x66ac168b76757277b806e89cb7bce0ec,
+  fnv1_128_test_99: u128, b"\x4a\x4e\x51\x40", 0x66abe8ffa3757277b806e89ca5080c46,
+  fnv1_128_test_100: u128, b"\x40\x51\x4e\x54", 0x66ac168b76757277b806e89cb7bce0f2,
+  fnv1_128_test_101: u128, b"\x54\x4e\x51\x40", 0x66abba4ebd757277b806e89c919ec564,
+  fnv1_128_test_102: u128, b"127.0.0.1", 0x7c4e6a711003b30fd4229f1c4e8930b8,
+  fnv1_128_test_103: u128, ::std::ffi::CString::new("127.0.0.1").unwrap().as_bytes_with_nul(), 0x10cb824f6c8d547a0699c7d4a2cef268,
+  fnv1_128_test_104: u128, b"127.0.0.2", 0x7c4e6a711003b30fd4229f1c4e8930bb,
+  fnv1_128_test_105: u128, ::std::ffi::CString::new("127.0.0.2").unwrap().as_bytes_with_nul(), 0x10cb824f6f8d547a0699c7d4a2cef619,
+  fnv1_128_test_106: u128, b"127.0.0.3", 0x7c4e6a711003b30fd4229f1c4e8930ba,
+  fnv1_128_test_107: u128, ::std::ffi::CString::new("127.0.0.3").unwrap().as_bytes_with_nul(), 0x10cb824f6e8d547a0699c7d4a2cef4de,
+  fnv1_128_test_108: u128, b"64.81.78.68", 0x6957564463c0ed7d9ff53efa9bce2357,
+  fnv1_128_test_109: u128, ::std::ffi::CString::new("64.81.78.68").unwrap().as_bytes_with_nul(), 0x9912f44a15643993d2c47e5db6a57c0d,
+  fnv1_128_test_110: u128, b"64.81.78.74", 0x6957564462c0ed7d9ff53efa9bce2200,
+  fnv1_128_test_111: u128, ::std::ffi::CString::new("64.81.78.74").unwrap().as_bytes_with_nul(), 0x9912f44783643993d2c47e5db6a3d600,
+  fnv1_128_test_112: u128, b"64.81.78.84", 0x6957564459c0ed7d9ff53efa9bce1715,
+  fnv1_128_test_113: u128, ::std::ffi::CString::new("64.81.78.84").unwrap().as_bytes_with_nul(), 0x9912f43185643993d2c47e5db69666d7,
+  fnv1_128_test_114: u128, b"feedface", 0xbd0acaa30865995a307da6d7f6dfd63e,
+  fnv1_128_test_115: u128, ::std::ffi::CString::new("feedface").unwrap().as_bytes_with_nul(), 0x743e36719303b1f9aa9c4bbcc56c9e4a,
+  fnv1_128_test_116: u128, b"feedfacedaffdeed", 0x54cb9aa4cdc20f25753a0aab17737bd5,
+  fnv1_128_test_117: u128, ::std::ffi::CString::new("feedfacedaffdeed").unwrap().as_bytes_with_nul(), 0x019ebc4502c8a3173e6b2085db195f17,
+  fnv1_128_test_118: u128, b"feedfacedeadbeef", 0xb3017e6566c20f257547d7cf97894892,
+  fnv1_128_test_119: u128, ::std::ffi::CString::new("feedfacedeadbeef").unwrap().as_bytes_with_nul(), 0x126e100e02c8a3174f668c6f75ec4ba6,
+  fnv1_128_test_120: u128, b"line 1\nline 2\nline 3", 0xbb3cb6762492b145364b3f4c2c47a3cb,
+  fnv1_128_test_121: u128, b"chongo <Landon Curt Noll> /\\../\\", 0xf07e80295b7ec5ed254a4c20bfbeab04,
+  fnv1_128_test_122: u128, ::std::ffi::CString::new("chongo <Landon Curt Noll> /\\../\\").unwrap().as_bytes_with_nul(), 0x0c67718e98fd8acce26bac4bef9c6dec,
+  fnv1_128_test_123: u128, b"chongo (Landon Curt Noll) /\\../\\", 0x5e90ef69ee9418f7065d4be3c8c82d09,
+  fnv1_128_test_124: u128, ::std::ffi::CString::new("chongo (Landon Curt Noll) /\\../\\").unwrap().as_bytes_with_nul(), 0x401f5f85993ab7f4d4cc61480e4f6a13,
+  fnv1_128_test_125: u128, b"http://antwrp.gsfc.nasa.gov/apod/astropix.html", 0xef5aef761d160fc0b1b45fa52afe28e0,
+  fnv1_128_test_126: u128, b"http://en.wikipedia.org/wiki/Fowler_Noll_Vo_hash", 0xebfeba5a84e115b860a1a833608685f9,
+  fnv1_128_test_127: u128, b"http://epod.usra.edu/", 0x8df723c2c8b87c188b2f7f9d041a7c37,
+  fnv1_128_test_128: u128, b"http://exoplanet.eu/", 0x15c8c811fc2b3f31e3d0179db6569ed6,
+  fnv1_128_test_129: u128, b"http://hvo.wr.usgs.gov/cam3/", 0xeff20f634ad7c2aacb655fd6c822191b,
+  fnv1_128_test_130: u128, b"http://hvo.wr.usgs.gov/cams/HMcam/", 0x52fb68e555c813245e26cf51eb442c0e,
+  fnv1_128_test_131: u128, b"http://hvo.wr.usgs.gov/kilauea/update/deformation.html", 0x3833ad561a7a2b346980bc69ae491194,
+  fnv1_128_test_132: u128, b"http://hvo.wr.usgs.gov/kilauea/update/images.html", 0x6d5ac2db5807f82bf6c1968f32b229a2,
+  fnv1_128_test_133: u128, b"http://hvo.wr.usgs.gov/kilauea/update/maps.html", 0x045271bc35cdc1487f2a0a6a818e38e3,
+  fnv1_128_test_134: u128, b"http://hvo.wr.usgs.gov/volcanowatch/current_issue.html", 0x8659e464b8616d6480af480112b1e832,
+  fnv1_128_test_135: u128, b"http://neo.jpl.nasa.gov/risk/", 0x62cd9e274023a31ae6517e893d08216f,
+  fnv1_128_test_136: u128, b"http://norvig.com/21-days.html", 0x4297b24656de2ec34bcea6c78e411868,
+  fnv1_128_test_137: u128, b"http://primes.utm.edu/curios/home.php", 0xc86ffb186aa20b0381ed84e29dd0954d,
+  fnv1_128_test_138: u128, b"http://slashdot.org/", 0x3bce668e13a8a43a344f2ae8c1d6251e,
+  fnv1_128_test_139: u128, b"http://tux.wr.usgs.gov/Maps/155.25-19.5.html", 0xe07138ecd54da35a771cfddf97776cc7,
+  fnv1_128_test_140: u128, b"http://volcano.wr.usgs.gov/kilaueastatus.php", 0xf1afb30001a9a4d46d114709756a5457,
+  fnv1_128_test_141: u128, b"http://www.avo.alaska.edu/activity/Redoubt.php", 0xd74eacb4c611324b353cf172e3355073,
+  fnv1_128_test_142: u128, b"http://www.dilbert.com/fast/", 0x630e42357baf7ee2bb813b15ddb0c019,
+  fnv1_128_test_143: u128, b"http://www.fourmilab.ch/gravitation/orbits/", 0xd4a16667df724188f705d75b923c85d6,
+  fnv1_128_test_144: u128, b"http://www.fpoa.net/", 0x7d845a55a47520d4a77dc18e17190286,
+  fnv1_128_test_145: u128, b"http://www.ioccc.org/index.html", 0xb1dfea3e9d07f6ca2b8023e36b2bae1b,
+  fnv1_128_test_146: u128, b"http://www.isthe.com/cgi-bin/number.cgi", 0x47b814cc9a63e23a792c23377bfc0cc5,
+  fnv1_128_test_147: u128, b"http://www.isthe.com/chongo/bio.html", 0xf308060e952997f3064e362352fad937,
+  fnv1_128_test_148: u128, b"http://www.isthe.com/chongo/index.html", 0x1db98c43a88ff9ee3f59999e8a65cc61,
+  fnv1_128_test_149: u128, b"http://www.isthe.com/chongo/src/calc/lucas-calc", 0xbbe35b60b39c33f26bea0d2a42ee466f,
+  fnv1_128_test_150: u128, b"http://www.isthe.com/chongo/tech/astro/venus2004.html", 0x9e4b15fe8e8ba499ee86863146eadcf1,
+  fnv1_128_test_151: u128, b"http://www.isthe.com/chongo/tech/astro/vita.html", 0x2c49711c633cac489ac80bae3eb1f4de,
+  fnv1_128_test_152: u128, b"http://www.isthe.com/chongo/tech/comp/c/expert.html", 0xceb14e61fcba71bd8ba056dad3714174,
+  fnv1_128_test_153: u128, b"http://www.isthe.com/chongo/tech/comp/calc/index.html", 0x8d4bf478354a3794f3e6290a7e180670,
+  fnv1_128_test_154: u128, b"http://www.isthe.com/chongo/tech/comp/fnv/index.html", 0x7178c0c913682444710de8102c95251d,
+  fnv1_128_test_155: u128, b"http://www.isthe.com/chongo/tech/math/number/howhigh.html", 0x021c1c2b34d6ce12fc4e94128ade64ed,
+  fnv1_128_test_156: u128, b"http://www.isthe.com/chongo/tech/math/number/number.html", 0x7ae08d80465fad9b9bd576bb7cc98302,
+  fnv1_128_test_157: u128, b"http://www.isthe.com/chongo/tech/math/prime/mersenne.html", 0xa89746e910db474f4e7b93c0bbb39af6,
+  fnv1_128_test_158: u128, b"http://www.isthe.com/chongo/tech/math/prime/mersenne.html#largest", 0xe5fbdf17b692e5f507af6a81b3c9e6f3,
+  fnv1_128_test_159: u128, b"http://www.lavarnd.org/cgi-bin/corpspeak.cgi", 0xf642103ec5eab9498fcea4aaaaca2700,
+  fnv1_128_test_160: u128, b"http://www.lavarnd.org/cgi-bin/haiku.cgi", 0x3529f36a3e109187c427f2d7df93ecf0,
+  fnv1_128_test_161: u128, b"http://www.lavarnd.org/cgi-bin/rand-none.cgi", 0xbf690def4d414e65bb6743b8566a28ce,
+  fnv1_128_test_162: u128, b"http://www.lavarnd.org/cgi-bin/randdist.cgi", 0x55c591c3383df8f02bba970492f7d45d,
+  fnv1_128_test_163: u128, b"http://www.lavarnd.org/index.html", 0xb1ff0d51969e23ae5fec0cf75b3116da,
+  fnv1_128_test_164: u128, b"http://www.lavarnd.org/what/nist-test.html", 0x38347b1554351d0124464b9e18bcfc0c,
+  fnv1_128_test_165: u128, b"http://www.macosxhints.com/", 0xda60c311301dd6f40f5aac21f44857cd,
+  fnv1_128_test_166: u128, b"http://www.mellis.com/", 0x37b593261df94c9ca555d50f0e02c4c8,
+  fnv1_128_test_167: u128, b"http://www.nature.nps.gov/air/webcams/parks/havoso2alert/havoalert.cfm", 0xa5db327d16d7eb90c034d145da589436,
+  fnv1_128_test_168: u128, b"http://www.nature.nps.gov/air/webcams/parks/havoso2alert/timelines_24.cfm", 0x6154b953ffce7ef956d3fd91b799eaf1,
+  fnv1_128_test_169: u128, b"http://www.paulnoll.com/", 0xfeed988b1f9400523262f9fa9183f457,
+  fnv1_128_test_170: u128, b"http://www.pepysdiary.com/", 0x347353aa1e87a07b6c5a4fa43e62d6de,
+  fnv1_128_test_171: u128, b"http://www.sciencenews.org/index/home/activity/view", 0x2bc8640a5139a1868a06c6bce3ab67de,
+  fnv1_128_test_172: u128, b"http://www.skyandtelescope.com/", 0xf049525468b451361eaee706cf7d54a0,
+  fnv1_128_test_173: u128, b"http://www.sput.nl/~rob/sirius.html", 0x011670285ee9d440bc68a7d8632173ad,
+  fnv1_128_test_174: u128, b"http://www.systemexperts.com/", 0x9a7c07126fa622f7b3ee3d9241842560,
+  fnv1_128_test_175: u128, b"http://www.tq-international.com/phpBB3/index.php", 0xb73aa5620c7c15f88b19e6559ff10fdc,
+  fnv1_128_test_176: u128, b"http://www.travelquesttours.com/index.htm", 0xe0f514b34fab1e5922b956a6e125898e,
+  fnv1_128_test_177: u128, b"http://www.wunderground.com/global/stations/89606.html", 0x67f4812513b6cd15265f6431c79f6bf2,
+  fnv1_128_test_178: u128, &repeat(b"21701", 10), 0x667e708c5accf5acc687815fa7a88bc7,
+  fnv1_128_test_179: u128, &repeat(b"M21701", 10), 0x63159df12c781692bfafa75a265c3275,
+  fnv1_128_test_180: u128, &repeat(b"2^21701-1", 10), 0xb475d5e1a5346d2a6cc2843eaffc46a7,
+  fnv1_128_test_181: u128, &repeat(b"\x54\xc5", 10), 0x56e4ba1c24d45ba8f25b94c460561435,
+  fnv1_128_test_182: u128, &repeat(b"\xc5\x54", 10), 0xf5b67e5559d06107f7672fa4edb85e1d,
+  fnv1_128_test_183: u128, &repeat(b"23209", 10), 0x31442cadbbf3af2dda4b3161310bcad1,
+  fnv1_128_test_184: u128, &repeat(b"M23209", 10), 0xb7989ef929855c61083a9b854736ee71,
+  fnv1_128_test_185: u128, &repeat(b"2^23209-1", 10), 0xb81c85df036e524dd3b7edc322865b0d,
+  fnv1_128_test_186: u128, &repeat(b"\x5a\xa9", 10), 0x9b602f8762603cfcc7a54336b5fbcaf9,
+  fnv1_128_test_187: u128, &repeat(b"\xa9\x5a", 10), 0x5d0215578c5fb780fee46e7a742a47a1,
+  fnv1_128_test_188: u128, &repeat(b"391581216093", 10), 0x9627ad84bfe386da361bf355901f0a31,
+  fnv1_128_test_189: u128, &repeat(b"391581*2^216093-1", 10), 0x28ebc548ed2068087d9c8767490176f1,
+  fnv1_128_test_190: u128, &repeat(b"\x05\xf9\x9d\x03\x4c\x81", 10), 0x738fade2c091204b66aeaeb444276055,
+  fnv1_128_test_191: u128, &repeat(b"FEDCBA9876543210", 10), 0xfd24b3f7a0c6d078e4a2301420eb98dd,
+  fnv1_128_test_192: u128, &repeat(b"\xfe\xdc\xba\x98\x76\x54\x32\x10", 10), 0x94c6d67576487751b4efc0202f428e6d,
+  fnv1_128_test_193: u128, &repeat(b"EFCDAB8967452301", 10), 0xd104410eb31db8637f0bdd8f0969d14d,
+  fnv1_128_test_194: u128, &repeat(b"\xef\xcd\xab\x89\x67\x45\x23\x01", 10), 0xdc4b02de121405b96af8a2e5654d0c4d,
+  fnv1_128_test_195: u128, &repeat(b"0123456789ABCDEF", 10), 0x42ce0c437eacbd58fd36312aecda130d,
+  fnv1_128_test_196: u128, &repeat(b"\x01\x23\x45\x67\x89\xab\xcd\xef", 10), 0x908853e3fcddf8385893bb4de91ae92d,
+  fnv1_128_test_197: u128, &repeat(b"1032547698BADCFE", 10), 0x7afd30a67a304b349752ffe09c1231ad,
+  fnv1_128_test_198: u128, &repeat(b"\x10\x32\x54\x76\x98\xba\xdc\xfe", 10), 0xf5a1fc8cff2357684107736dc477f96d,
+  fnv1_128_test_199: u128, &repeat(b"\x00", 500), 0xbc5a731e7ff34af0587bc0739c8b2b7d,
+  fnv1_128_test_200: u128, &repeat(b"\x07", 500), 0x98b9e6863c9b793385ece69b9bab23b1,
+  fnv1_128_test_201: u128, &repeat(b"~", 500), 0x4d450eec7df9aee1344367dad0445c7d,
+  fnv1_128_test_202: u128, &repeat(b"\x7f", 500), 0x214cf245ab07d3498081869ec209ac51,
+  fnv1_256_test_0: U256, b"", U256::from_words([0xdd268dbcaac55036, 0x2d98c384c4e576cc, 0xc8b1536847b6bbb3, 0x1023b4c8caee0535]),
+  fnv1_256_test_1: U256, b"a", U256::from_words([0x63323fb0f35303ec, 0x28dc561d0a33bdfa, 0x4de6a99b7266494f, 0x6183b2716811381e]),
+  fnv1_256_test_2: U256, b"b", U256::from_words([0x63323fb0f35303ec, 0x28dc561d0a33bdfa, 0x4de6a99b7266494f, 0x6183b2716811381d]),
+  fnv1_256_test_3: U256, b"c", U256::from_words([0x63323fb0f35303ec, 0x28dc561d0a33bdfa, 0x4de6a99b7266494f, 0x6183b2716811381c]),
+  fnv1_256_test_4: U256, b"d", U256::from_words([0x63323fb0f35303ec, 0x28dc561d0a33bdfa, 0x4de6a99b7266494f, 0x6183b2716811381b]),
+  fnv1_256_test_5: U256, b"e", U256::from_words([0x63323fb0f35303ec, 0x28dc561d0a33bdfa, 0x4de6a99b7266494f, 0x6183b2716811381a]),
+  fnv1_256_test_6: U256, b"f", U256::from_words([0x63323fb0f35303ec, 0x28dc561d0a33bdfa, 0x4de6a99b7266494f, 0x6183b27168113819]),
+  fnv1_256_test_7: U256, b"fo", U256::from_words([0xf4f7a1c2efd0e1e4, 0xbac3834525c0721a, 0x06dd328fa3d7a914, 0x39a073434fe0cac4]),
+  fnv1_256_test_8: U256, b"foo", U256::from_words([0x8b0e658c2f1c837e, 0xdde9cce359de3a17, 0x84bd1d30340f770b, 0xe97fd657c4b92da3]),
+  fnv1_256_test_9: U256, b"foob", U256::from_words([0xe46ddd4ed460b0b2, 0x7464c2459f2a8e9d, 0x123f79d831721584, 0xcc463bb5ccca496b]),
+  fnv1_256_test_10: U256, b"fooba", U256::from_words([0x366f691cc850bd44, 0x3202d18bb803c3d0, 0x4e05f6cc9133d727, 0x4564cd1afc83cf00]),
+  fnv1_256_test_11: U256, b"foobar", U256::from_words([0xb055ea2f2cc3908d, 0xddb794c02d3889dc, 0x32453dad5ae35b75, 0x3ac86c6c2ac80d72]),
+  fnv1_256_test_12: U256, ::std::ffi::CString::new("").unwrap().as_bytes_with_nul(), U256::from_words([0x63323fb0f35303ec, 0x28dc561d0a33bdfa, 0x4de6a99b7266494f, 0x6183b2716811387f]),
+  fnv1_256_test_13: U256, ::std::ffi::CString::new("a").unwrap().as_bytes_with_nul(), U256::from_words([0xf4f7a1c2efd0e1e4, 0xbac3884525c0721a, 0x06dd328fa3d7a914, 0x39a073434fe0d19a]),
+  fnv1_256_test_14: U256, ::std::ffi::CString::new("b").unwrap().as_bytes_with_nul(), U256::from_words([0xf4f7a1c2efd0e1e4, 0xbac3874525c0721a, 0x06dd328fa3d7a914, 0x39a073434fe0d037]),
+  fnv1_256_test_15: U256, ::std::ffi::CString::new("c").unwrap().as_bytes_with_nul(), U256::from_words([0xf4f7a1c2efd0e1e4, 0xbac3864525c0721a, 0x06dd328fa3d7a914, 0x39a073434fe0ced4]),
+  fnv1_256_test_16: U256, ::std::ffi::CString::new("d").unwrap().as_bytes_with_nul(), U256::from_words([0xf4f7a1c2efd0e1e4, 0xbac3854525c0721a, 0x06dd328fa3d7a914, 0x39a073434fe0cd71]),
+  fnv1_256_test_17: U256, ::std::ffi::CString::new("e").unwrap().as_bytes_with_nul(), U256::from_words([0xf4f7a1c2efd0e1e4, 0xbac3844525c0721a, 0x06dd328fa3d7a914, 0x39a073434fe0cc0e]),
+  fnv1_256_test_18: U256, ::std::ffi::CString::new("f").unwrap().as_bytes_with_nul(), U256::from_words([0xf4f7a1c2efd0e1e4, 0xbac3834525c0721a, 0x06dd328fa3d7a914, 0x39a073434fe0caab]),
+  fnv1_256_test_19: U256, ::std::ffi::CString::new("fo").unwrap().as_bytes_with_nul(), U256::from_words([0x8b0e658c2f1c837e, 0xdde9cce359de3a17, 0x84bd1d30340f770b, 0xe97fd657c4b92dcc]),
+  fnv1_256_test_20: U256, ::std::ffi::CString::new("foo").unwrap().as_bytes_with_nul(), U256::from_words([0xe46ddd4ed460b0b2, 0x7464c2459f2a8e9d, 0x123f79d831721584, 0xcc463bb5ccca4909]),
+  fnv1_256_test_21: U256, ::std::ffi::CString::new("foob").unwrap().as_bytes_with_nul(), U256::from_words([0x366f691cc850bd44, 0x3202d18bb803c3d0, 0x4e05f6cc9133d727, 0x4564cd1afc83cf61]),
+  fnv1_256_test_22: U256, ::std::ffi::CString::new("fooba").unwrap().as_bytes_with_nul(), U256::from_words([0xb055ea2f2cc3908d, 0xddb794c02d3889dc, 0x32453dad5ae35b75, 0x3ac86c6c2ac80d00]),
+  fnv1_256_test_23: U256, ::std::ffi::CString::new("foobar").unwrap().as_bytes_with_nul(), U256::from_words([0x6a7f34a5db9de0e5, 0x3da0b87eb5672c59, 0xb60487650947d390, 0x83ee59ff536aa516]),
+  fnv1_256_test_24: U256, b"ch", U256::from_words([0xf4f7a1c2efd0e1e4, 0xbac3864525c0721a, 0x06dd328fa3d7a914, 0x39a073434fe0cebc]),
+  fnv1_256_test_25: U256, b"cho", U256::from_words([0x8b0e658c2f1c837e, 0xddf1ede359de3a17, 0x84bd1d30340f770b, 0xe97fd657c4beaedb]),
+  fnv1_256_test_26: U256, b"chon", U256::from_words([0xe46ddd4ed460b0b2, 0x852bbd459f2a8e9d, 0x123f79d831721584, 0xcc463bb5d46c79df]),
+  fnv1_256_test_27: U256, b"chong", U256::from_words([0x366f691cc850bd63, 0x1821568bb803c3d0, 0x4e05f6cc9133d727, 0x4564cd25926d005a]),
+  fnv1_256_test_28: U256, b"chongo", U256::from_words([0xb055ea2f2cc3c5fc, 0xe33b5dc02d3889dc, 0x32453dad5ae35b75, 0x3ac87b1a0d277ca1]),
+  fnv1_256_test_29: U256, b"chongo ", U256::from_words([0x6a7f34a5dbf6a7bc, 0x42cfa27eb5672c59, 0xb60487650947d390, 0x8402b5203dc1d363]),
+  fnv1_256_test_30: U256, b"chongo w", U256::from_words([0xf639928409bfba4e, 0x67c1b8b58e128067, 0x6847c11bde9a6167, 0x0fc12bb5a3c8223e]),
+  fnv1_256_test_31: U256, b"chongo wa", U256::from_words([0x0c379429460b105d, 0xa9c561c403a80f65, 0x9b80c9a5b01511ea, 0xd8df9ce21e877b9b]),
+  fnv1_256_test_32: U256, b"chongo was", U256::from_words([0x0624601500f49400, 0xf4322dd1120d59e6, 0xa397a0c32d37d8aa, 0xbe168d9055e06782]),
+  fnv1_256_test_33: U256, b"chongo was ", U256::from_words([0xbc49e7de69b6cda8, 0x81fd0aec0883aad4, 0xdb43eea7b47174c5, 0x99464f27162f8966]),
+  fnv1_256_test_34: U256, b"chongo was h", U256::from_words([0x8bf14f05dece57c2, 0x716f8b4fce95e52c, 0x0f33f28f3954ee03, 0x8c7fc333c3eb881a]),
+  fnv1_256_test_35: U256, b"chongo was he", U256::from_words([0x648e98b077e6e667, 0x393649ab79dccc19, 0x15095c9c80c60eeb, 0xd52bb0c8af9dbc6b]),
+  fnv1_256_test_36: U256, b"chongo was her", U256::from_words([0x37cca88b70e249d3, 0xf40493c9fd2f06c8, 0x2bfb6d0692a6b108, 0x9b96264b87bc4813]),
+  fnv1_256_test_37: U256, b"chongo was here", U256::from_words([0x077ec1f91ff2ac73, 0x1ea1041a18366794, 0xfda8301d5d277eef, 0xc1371abd3a17f23c]),
+  fnv1_256_test_38: U256, b"chongo was here!", U256::from_words([0x8c45ec38849fe0dd, 0x913aec2f9371a39b, 0xc03ab8b82dc50678, 0xef6a14678f34e915]),
+  fnv1_256_test_39: U256, b"chongo was here!\n", U256::from_words([0x49fd0b4f53c93acf, 0x999e9af97695e0fb, 0x916e27677837f9b4, 0x001a4b9b965f3815]),
+  fnv1_256_test_40: U256, ::std::ffi::CString::new("ch").unwrap().as_bytes_with_nul(), U256::from_words([0x8b0e658c2f1c837e, 0xddf1ede359de3a17, 0x84bd1d30340f770b, 0xe97fd657c4beaeb4]),
+  fnv1_256_test_41: U256, ::std::ffi::CString::new("cho").unwrap().as_bytes_with_nul(), U256::from_words([0xe46ddd4ed460b0b2, 0x852bbd459f2a8e9d, 0x123f79d831721584, 0xcc463bb5d46c79b1]),
+  fnv1_256_test_42: U256, ::std::ffi::CString::new("chon").unwrap().as_bytes_with_nul(), U256::from_words([0x366f691cc850bd63, 0x1821568bb803c3d0, 0x4e05f6cc9133d727, 0x4564cd25926d003d]),
+  fnv1_256_test_43: U256, ::std::ffi::CString::new("chong").unwrap().as_bytes_with_nul(), U256::from_words([0xb055ea2f2cc3c5fc, 0xe33b5dc02d3889dc, 0x32453dad5ae35b75, 0x3ac87b1a0d277cce]),
+  fnv1_256_test_44: U256, ::std::ffi::CString::new("chongo").unwrap().as_bytes_with_nul(), U256::from_words([0x6a7f34a5dbf6a7bc, 0x42cfa27eb5672c59, 0xb60487650947d390, 0x8402b5203dc1d343]),
+  fnv1_256_test_45: U256, ::std::ffi::CString::new("chongo ").unwrap().as_bytes_with_nul(), U256::from_words([0xf639928409bfba4e, 0x67c1b8b58e128067, 0x6847c11bde9a6167, 0x0fc12bb5a3c82249]),
+  fnv1_256_test_46: U256, ::std::ffi::CString::new("chongo w").unwrap().as_bytes_with_nul(), U256::from_words([0x0c379429460b105d, 0xa9c561c403a80f65, 0x9b80c9a5b01511ea, 0xd8df9ce21e877bfa]),
+  fnv1_256_test_47: U256, ::std::ffi::CString::new("chongo wa").unwrap().as_bytes_with_nul(), U256::from_words([0x0624601500f49400, 0xf4322dd1120d59e6, 0xa397a0c32d37d8aa, 0xbe168d9055e067f1]),
+  fnv1_256_test_48: U256, ::std::ffi::CString::new("chongo was").unwrap().as_bytes_with_nul(), U256::from_words([0xbc49e7de69b6cda8, 0x81fd0aec0883aad4, 0xdb43eea7b47174c5, 0x99464f27162f8946]),
+  fnv1_256_test_49: U256, ::std::ffi::CString::new("chongo was ").unwrap().as_bytes_with_nul(), U256::from_words([0x8bf14f05dece57c2, 0x716f8b4fce95e52c, 0x0f33f28f3954ee03, 0x8c7fc333c3eb8872]),
+  fnv1_256_test_50: U256, ::std::ffi::CString::new("chongo was h").unwrap().as_bytes_with_nul(), U256::from_words([0x648e98b077e6e667, 0x393649ab79dccc19, 0x15095c9c80c60eeb, 0xd52bb0c8af9dbc0e]),
+  fnv1_256_test_51: U256, ::std::ffi::CString::new("chongo was he").unwrap().as_bytes_with_nul(), U256::from_words([0x37cca88b70e249d3, 0xf40493c9fd2f06c8, 0x2bfb6d0692a6b108, 0x9b96264b87bc4861]),
+  fnv1_256_test_52: U256, ::std::ffi::CString::new("chongo was her").unwrap().as_bytes_with_nul(), U256::from_words([0x077ec1f91ff2ac73, 0x1ea1041a18366794, 0xfda8301d5d277eef, 0xc1371abd3a17f259]),
+  fnv1_256_test_53: U256, ::std::ffi::CString::new("chongo was here").unwrap().as_bytes_with_nul(), U256::from_words([0x8c45ec38849fe0dd, 0x913aec2f9371a39b, 0xc03ab8b82dc50678, 0xef6a14678f34e934]),
+  fnv1_256_test_54: U256, ::std::ffi::CString::new("chongo was here!").unwrap().as_bytes_with_nul(), U256::from_words([0x49fd0b4f53c93acf, 0x999e9af97695e0fb, 0x916e27677837f9b4, 0x001a4b9b965f381f]),
+  fnv1_256_test_55: U256, ::std::ffi::CString::new("chongo was here!\n").unwrap().as_bytes_with_nul(), U256::from_words([0xd1e063014a582978, 0x6628fcef71d6fcda, 0xabc0a47bb59f449c, 0x2476d8c1860ac51f]),
+  fnv1_256_test_56: U256, b"cu", U256::from_words([0xf4f7a1c2efd0e1e4, 0xbac3864525c0721a, 0x06dd328fa3d7a914, 0x39a073434fe0cea1]),
+  fnv1_256_test_57: U256, b"cur", U256::from_words([0x8b0e658c2f1c837e, 0xddf1d2e359de3a17, 0x84bd1d30340f770b, 0xe97fd657c4be8931]),
+  fnv1_256_test_58: U256, b"curd", U256::from_words([0xe46ddd4ed460b0b2, 0x84e0a2459f2a8e9d, 0x123f79d831721584, 0xcc463bb5d4383e97]),
+  fnv1_256_test_59: U256, b"curds", U256::from_words([0x366f691cc850bd62, 0x7bbf9d8bb803c3d0, 0x4e05f6cc9133d727, 0x4564cd2549fecb16]),
+  fnv1_256_test_60: U256, b"curds ", U256::from_words([0xb055ea2f2cc3c4db, 0x99828ec02d3889dc, 0x32453dad5ae35b75, 0x3ac87ab59c539fa2]),
+  fnv1_256_test_61: U256, b"curds a", U256::from_words([0x6a7f34a5dbf4b222, 0x33ab967eb5672c59, 0xb60487650947d390, 0x840229d7c7f65dc7]),
+  fnv1_256_test_62: U256, b"curds an", U256::from_words([0xf6399284067cdd35, 0x9d4f78b58e128067, 0x6847c11bde9a6167, 0x0f00063a4aa40a9b]),
+  fnv1_256_test_63: U256, b"curds and", U256::from_words([0x0c379423ff2cfba3, 0xc93efec403a80f65, 0x9b80c9a5b01511e9, 0xcd08a2d5817ab495]),
+  fnv1_256_test_64: U256, b"curds and ", U256::from_words([0x062457b7e403c9a1, 0x8d0fded1120d59e6, 0xa397a0c32d37d737, 0x52f9ce128d286abf]),
+  fnv1_256_test_65: U256, b"curds and w", U256::from_words([0xbc3cdb542b0ead93, 0xc56cbaec0883aad4, 0xdb43eea7b46f71b8, 0x1068c3b9bf0c06aa]),
+  fnv1_256_test_66: U256, b"curds and wh", U256::from_words([0x77d5ddc81e1e6da9, 0xd1cddf4fce95e52c, 0x0f33f28f368ab23e, 0xc1476a93edad3dd6]),
+  fnv1_256_test_67: U256, b"curds and whe", U256::from_words([0xb844cb430b9ca66b, 0x9dba81ab79dccc19, 0x15095c98a2552d06, 0x0608cb22973cbfa7]),
+  fnv1_256_test_68: U256, b"curds and whey", U256::from_words([0xdc92e3ff2305e9d2, 0xf66177c9fd2f06c8, 0x2bfb67a91c1d6f5a, 0x5e31b0f7b93dc4ec]),
+  fnv1_256_test_69: U256, b"curds and whey\n", U256::from_words([0xfd21852bc2e43744, 0xe6ee091a18366794, 0xfda0bf81fcd16a50, 0x9ee86785e0a8134e]),
+  fnv1_256_test_70: U256, ::std::ffi::CString::new("cu").unwrap().as_bytes_with_nul(), U256::from_words([0x8b0e658c2f1c837e, 0xddf1d2e359de3a17, 0x84bd1d30340f770b, 0xe97fd657c4be8943]),
+  fnv1_256_test_71: U256, ::std::ffi::CString::new("cur").unwrap().as_bytes_with_nul(), U256::from_words([0xe46ddd4ed460b0b2, 0x84e0a2459f2a8e9d, 0x123f79d831721584, 0xcc463bb5d4383ef3]),
+  fnv1_256_test_72: U256, ::std::ffi::CString::new("curd").unwrap().as_bytes_with_nul(), U256::from_words([0x366f691cc850bd62, 0x7bbf9d8bb803c3d0, 0x4e05f6cc9133d727, 0x4564cd2549fecb65]),
+  fnv1_256_test_73: U256, ::std::ffi::CString::new("curds").unwrap().as_bytes_with_nul(), U256::from_words([0xb055ea2f2cc3c4db, 0x99828ec02d3889dc, 0x32453dad5ae35b75, 0x3ac87ab59c539f82]),
+  fnv1_256_test_74: U256, ::std::ffi::CString::new("curds ").unwrap().as_bytes_with_nul(), U256::from_words([0x6a7f34a5dbf4b222, 0x33ab967eb5672c59, 0xb60487650947d390, 0x840229d7c7f65da6]),
+  fnv1_256_test_75: U256, ::std::ffi::CString::new("curds a").unwrap().as_bytes_with_nul(), U256::from_words([0xf6399284067cdd35, 0x9d4f78b58e128067, 0x6847c11bde9a6167, 0x0f00063a4aa40af5]),
+  fnv1_256_test_76: U256, ::std::ffi::CString::new("curds an").unwrap().as_bytes_with_nul(), U256::from_words([0x0c379423ff2cfba3, 0xc93efec403a80f65, 0x9b80c9a5b01511e9, 0xcd08a2d5817ab4f1]),
+  fnv1_256_test_77: U256, ::std::ffi::CString::new("curds and").unwrap().as_bytes_with_nul(), U256::from_words([0x062457b7e403c9a1, 0x8d0fded1120d59e6, 0xa397a0c32d37d737, 0x52f9ce128d286a9f]),
+  fnv1_256_test_78: U256, ::std::ffi::CString::new("curds and ").unwrap().as_bytes_with_nul(), U256::from_words([0xbc3cdb542b0ead93, 0xc56cbaec0883aad4, 0xdb43eea7b46f71b8, 0x1068c3b9bf0c06dd]),
+  fnv1_256_test_79: U256, ::std::ffi::CString::new("curds and w").unwrap().as_bytes_with_nul(), U256::from_words([0x77d5ddc81e1e6da9, 0xd1cddf4fce95e52c, 0x0f33f28f368ab23e, 0xc1476a93edad3dbe]),
+  fnv1_256_test_80: U256, ::std::ffi::CString::new("curds and wh").unwrap().as_bytes_with_nul(), U256::from_words([0xb844cb430b9ca66b, 0x9dba81ab79dccc19, 0x15095c98a2552d06, 0x0608cb22973cbfc2]),
+  fnv1_256_test_81: U256, ::std::ffi::CString::new("curds and whe").unwrap().as_bytes_with_nul(), U256::from_words([0xdc92e3ff2305e9d2, 0xf66177c9fd2f06c8, 0x2bfb67a91c1d6f5a, 0x5e31b0f7b93dc495]),
+  fnv1_256_test_82: U256, ::std::ffi::CString::new("curds and whey").unwrap().as_bytes_with_nul(), U256::from_words([0xfd21852bc2e43744, 0xe6ee091a18366794, 0xfda0bf81fcd16a50, 0x9ee86785e0a81344]),
+  fnv1_256_test_83: U256, ::std::ffi::CString::new("curds and whey\n").unwrap().as_bytes_with_nul(), U256::from_words([0xd6e5fc4e2ae02a6c, 0xe429ed2f9371a39b, 0xb5e9914196666dcc, 0x5c478ea68912c52a]),
+  fnv1_256_test_84: U256, b"hi", U256::from_words([0xf4f7a1c2efd0e1e4, 0xbac3814525c0721a, 0x06dd328fa3d7a914, 0x39a073434fe0c78c]),
+  fnv1_256_test_85: U256, ::std::ffi::CString::new("hi").unwrap().as_bytes_with_nul(), U256::from_words([0x8b0e658c2f1c837e, 0xdde3cee359de3a17, 0x84bd1d30340f770b, 0xe97fd657c4b4b724]),
+  fnv1_256_test_86: U256, b"hello", U256::from_words([0x366f691cc850bd2c, 0x0771ef8bb803c3d0, 0x4e05f6cc9133d727, 0x4564cd1248510217]),
+  fnv1_256_test_87: U256, ::std::ffi::CString::new("hello").unwrap().as_bytes_with_nul(), U256::from_words([0xb055ea2f2cc36656, 0xa40145c02d3889dc, 0x32453dad5ae35b75, 0x3ac8605a4855e5e5]),
+  fnv1_256_test_88: U256, b"\xff\x00\x00\x01", U256::from_words([0xe46ddd4ed460b0b4, 0xc653b2459f2a8e9d, 0x123f79d831721584, 0xcc463bb6df448581]),
+  fnv1_256_test_89: U256, b"\x01\x00\x00\xff", U256::from_words([0xe46ddd4ed460b0b4, 0xbac9fc459f2a8e9d, 0x123f79d831721584, 0xcc463bb6d9ef33f5]),
+  fnv1_256_test_90: U256, b"\xff\x00\x00\x02", U256::from_words([0xe46ddd4ed460b0b4, 0xc653b2459f2a8e9d, 0x123f79d831721584, 0xcc463bb6df448582]),
+  fnv1_256_test_91: U256, b"\x02\x00\x00\xff", U256::from_words([0xe46ddd4ed460b0b4, 0xb50521459f2a8e9d, 0x123f79d831721584, 0xcc463bb6d7448930]),
+  fnv1_256_test_92: U256, b"\xff\x00\x00\x03", U256::from_words([0xe46ddd4ed460b0b4, 0xc653b2459f2a8e9d, 0x123f79d831721584, 0xcc463bb6df448583]),
+  fnv1_256_test_93: U256, b"\x03\x00\x00\xff", U256::from_words([0xe46ddd4ed460b0b4, 0xaf4046459f2a8e9d, 0x123f79d831721584, 0xcc463bb6d499e06b]),
+  fnv1_256_test_94: U256, b"\xff\x00\x00\x04", U256::from_words([0xe46ddd4ed460b0b4, 0xc653b2459f2a8e9d, 0x123f79d831721584, 0xcc463bb6df448584]),
+  fnv1_256_test_95: U256, b"\x04\x00\x00\xff", U256::from_words([0xe46ddd4ed460b0b4, 0xa97b6b459f2a8e9d, 0x123f79d831721584, 0xcc463bb6d1ef37a6]),
+  fnv1_256_test_96: U256, b"\x40\x51\x4e\x44", U256::from_words([0xe46ddd4ed460b0b3, 0x4e77b7459f2a8e9d, 0x123f79d831721584, 0xcc463bb631542b3a]),
+  fnv1_256_test_97: U256, b"\x44\x4e\x51\x40", U256::from_words([0xe46ddd4ed460b0b3, 0x37b9ae459f2a8e9d, 0x123f79d831721584, 0xcc463bb626e45244]),
+  fnv1_256_test_98: U256, b"\x40\x51\x4e\x4a", U256::from_words([0xe46ddd4ed460b0b3, 0x4e77b7459f2a8e9d, 0x123f79d831721584, 0xcc463bb631542b34]),
+  fnv1_256_test_99: U256, b"\x4a\x4e\x51\x40", U256::from_words([0xe46ddd4ed460b0b3, 0x14cee4459f2a8e9d, 0x123f79d831721584, 0xcc463bb616ae82e6]),
+  fnv1_256_test_100: U256, b"\x40\x51\x4e\x54", U256::from_words([0xe46ddd4ed460b0b3, 0x4e77b7459f2a8e9d, 0x123f79d831721584, 0xcc463bb631542b2a]),
+  fnv1_256_test_101: U256, b"\x54\x4e\x51\x40", U256::from_words([0xe46ddd4ed460b0b2, 0xdccf1e459f2a8e9d, 0x123f79d831721584, 0xcc463bb5fd300f74]),
+  fnv1_256_test_102: U256, b"127.0.0.1", U256::from_words([0x0c37a392d3cd62b1, 0xa81755c403a80f65, 0x9b80c9a5b0151496, 0xcc53bc38c5893258]),
+  fnv1_256_test_103: U256, ::std::ffi::CString::new("127.0.0.1").unwrap().as_bytes_with_nul(), U256::from_words([0x063c6b68098c1521, 0xa18e46d1120d59e6, 0xa397a0c32d3b8d1d, 0x581e02b9ed40d008]),
+  fnv1_256_test_104: U256, b"127.0.0.2", U256::from_words([0x0c37a392d3cd62b1, 0xa81755c403a80f65, 0x9b80c9a5b0151496, 0xcc53bc38c589325b]),
+  fnv1_256_test_105: U256, ::std::ffi::CString::new("127.0.0.2").unwrap().as_bytes_with_nul(), U256::from_words([0x063c6b68098c1521, 0xa18e49d1120d59e6, 0xa397a0c32d3b8d1d, 0x581e02b9ed40d431]),
+  fnv1_256_test_106: U256, b"127.0.0.3", U256::from_words([0x0c37a392d3cd62b1, 0xa81755c403a80f65, 0x9b80c9a5b0151496, 0xcc53bc38c589325a]),
+  fnv1_256_test_107: U256, ::std::ffi::CString::new("127.0.0.3").unwrap().as_bytes_with_nul(), U256::from_words([0x063c6b68098c1521, 0xa18e48d1120d59e6, 0xa397a0c32d3b8d1d, 0x581e02b9ed40d2ce]),
+  fnv1_256_test_108: U256, b"64.81.78.68", U256::from_words([0xdd9564b76c8c48bb, 0x07e725ec0883aad4, 0xdb43eea7b90f467a, 0x542f45ad9efcabf7]),
+  fnv1_256_test_109: U256, ::std::ffi::CString::new("64.81.78.68").unwrap().as_bytes_with_nul(), U256::from_words([0x557124afb5ce88fa, 0xf2358d4fce95e52c, 0x0f33f295a02ebba2, 0xbd8d9fc378627785]),
+  fnv1_256_test_110: U256, b"64.81.78.74", U256::from_words([0xdd9564b76c8c48bb, 0x07e724ec0883aad4, 0xdb43eea7b90f467a, 0x542f45ad9efcaa58]),
+  fnv1_256_test_111: U256, ::std::ffi::CString::new("64.81.78.74").unwrap().as_bytes_with_nul(), U256::from_words([0x557124afb5ce88fa, 0xf2328b4fce95e52c, 0x0f33f295a02ebba2, 0xbd8d9fc378603808]),
+  fnv1_256_test_112: U256, b"64.81.78.84", U256::from_words([0xdd9564b76c8c48bb, 0x07e72bec0883aad4, 0xdb43eea7b90f467a, 0x542f45ad9efcb415]),
+  fnv1_256_test_113: U256, ::std::ffi::CString::new("64.81.78.84").unwrap().as_bytes_with_nul(), U256::from_words([0x557124afb5ce88fa, 0xf245fd4fce95e52c, 0x0f33f295a02ebba2, 0xbd8d9fc3786db91f]),
+  fnv1_256_test_114: U256, b"feedface", U256::from_words([0xf63992837b822c23, 0x8625fdb58e128067, 0x6847c11bde9a6166, 0xf3cf791c2ab49e06]),
+  fnv1_256_test_115: U256, ::std::ffi::CString::new("feedface").unwrap().as_bytes_with_nul(), U256::from_words([0x0c37934814fc516d, 0xbb4cd8c403a80f65, 0x9b80c9a5b01511c4, 0x18b4f20f38772252]),
+  fnv1_256_test_116: U256, b"feedfacedaffdeed", U256::from_words([0x515f311c4e13fe9f, 0xfe5db12f9371a39a, 0x421c76afd5c6438c, 0x711a7f1fe30c6eb5]),
+  fnv1_256_test_117: U256, ::std::ffi::CString::new("feedfacedaffdeed").unwrap().as_bytes_with_nul(), U256::from_words([0x9d44a6b1603937c0, 0xc85b69f97695dee9, 0xad7895d571efabc0, 0xd7be4937da3d84ff]),
+  fnv1_256_test_118: U256, b"feedfacedeadbeef", U256::from_words([0x515f311c4e8e871c, 0xbc63ea2f9371a39a, 0x421c76afd5c6438c, 0x7136ca165f1f181a]),
+  fnv1_256_test_119: U256, ::std::ffi::CString::new("feedfacedeadbeef").unwrap().as_bytes_with_nul(), U256::from_words([0x9d44a6b2266f7338, 0x5da5d9f97695dee9, 0xad7895d571efabc0, 0xfefa3d05e81e6c0e]),
+  fnv1_256_test_120: U256, b"line 1\nline 2\nline 3", U256::from_words([0x5eacd1924a459656, 0x5e2824ff04fb6313, 0xbe6633cb43e3c951, 0x86aac773468aee13]),
+  fnv1_256_test_121: U256, b"chongo <Landon Curt Noll> /\\../\\", U256::from_words([0xbe08cda508edcbe7, 0x7284caaa0e6f6761, 0x03af865cf2c035bd, 0x55991613a70ef604]),
+  fnv1_256_test_122: U256, ::std::ffi::CString::new("chongo <Landon Curt Noll> /\\../\\").unwrap().as_bytes_with_nul(), U256::from_words([0x466ae930fad7d59a, 0xdd1b0dd2047c5b88, 0x1c6752e4a08a858d, 0xb3499d40a9bf278c]),
+  fnv1_256_test_123: U256, b"chongo (Landon Curt Noll) /\\../\\", U256::from_words([0xe54769d25ed32d7d, 0x560fa37584168d3f, 0xf2630dd22229c0d3, 0xaf8aa255de2d9721]),
+  fnv1_256_test_124: U256, ::std::ffi::CString::new("chongo (Landon Curt Noll) /\\../\\").unwrap().as_bytes_with_nul(), U256::from_words([0x1bc89269097a6aac, 0x8546ccf62b45dfad, 0x1f5c2a655fe6658c, 0x6d3f1d13193892c3]),
+  fnv1_256_test_125: U256, b"http://antwrp.gsfc.nasa.gov/apod/astropix.html", U256::from_words([0xe024ea50ab2b7c93, 0x120f086395901f9e, 0xc70431a5d2d5b03f, 0x62e441d352e3e1a0]),
+  fnv1_256_test_126: U256, b"http://en.wikipedia.org/wiki/Fowler_Noll_Vo_hash", U256::from_words([0xc86250af05bfaee1, 0x8a99fdad0817c01c, 0x4544d8a12c2bee0f, 0x295ac3fee55626d9]),
+  fnv1_256_test_127: U256, b"http://epod.usra.edu/", U256::from_words([0xcf0c6ae4ba4f3ed7, 0xbd12e21ddac7cb12, 0x3a2b40575e045c03, 0xcbc115b140525fbf]),
+  fnv1_256_test_128: U256, b"http://exoplanet.eu/", U256::from_words([0xa3da27ec77249ee2, 0xb7f1d506930148af, 0x48ff6693b0e55542, 0x99d075c2e64bb46e]),
+  fnv1_256_test_129: U256, b"http://hvo.wr.usgs.gov/cam3/", U256::from_words([0xf0c9f07c3fd24830, 0xb61e36e1d8a7dae2, 0x4c5d13fb309f9c7f, 0x021e8f38acbb8b5b]),
+  fnv1_256_test_130: U256, b"http://hvo.wr.usgs.gov/cams/HMcam/", U256::from_words([0x634f991ca5e4848c, 0x5a7edde945e6e8da, 0xd2e1d8700ad5601a, 0xbe93410bbdcc8a9e]),
+  fnv1_256_test_131: U256, b"http://hvo.wr.usgs.gov/kilauea/update/deformation.html", U256::from_words([0x0e5bbdae6c151bdc, 0xeb24af9374fe8b8a, 0x7ef2b1dec2030a20, 0xecfca58ee3ba853c]),
+  fnv1_256_test_132: U256, b"http://hvo.wr.usgs.gov/kilauea/update/images.html", U256::from_words([0x3bd0c758ba61df7b, 0xfc24985080f7415c, 0xba133bd5b22e0f19, 0xebb98b301dfb853a]),
+  fnv1_256_test_133: U256, b"http://hvo.wr.usgs.gov/kilauea/update/maps.html", U256::from_words([0x3ffee17fafbaca45, 0x9e9557997b15063a, 0x86981d806d610b65, 0x3adf19eb974240e3]),
+  fnv1_256_test_134: U256, b"http://hvo.wr.usgs.gov/volcanowatch/current_issue.html", U256::from_words([0xf7d747749202539e, 0xaf9e12d6f9dd3f1a, 0x981c92dcbc41b857, 0x34d8d3a85a20b02a]),
+  fnv1_256_test_135: U256, b"http://neo.jpl.nasa.gov/risk/", U256::from_words([0xf53dcbfac741a3e2, 0x793589dff3cc5a0c, 0x2219f0cb3ad602d5, 0x854b3af77a25fd77]),
+  fnv1_256_test_136: U256, b"http://norvig.com/21-days.html", U256::from_words([0x488eb93509cb1da8, 0x9b707edf2f7d392d, 0xf15831af8e71047c, 0xa89b548f89dc3558]),
+  fnv1_256_test_137: U256, b"http://primes.utm.edu/curios/home.php", U256::from_words([0xcbbc1ddec6c0df6d, 0x536e891886437a81, 0x8790f2e558b25718, 0xada81e9af24c8bed]),
+  fnv1_256_test_138: U256, b"http://slashdot.org/", U256::from_words([0x98c9078960b0df96, 0x53a2bb06930148af, 0x48ff648f2567ba87, 0xac732ce392ec72d6]),
+  fnv1_256_test_139: U256, b"http://tux.wr.usgs.gov/Maps/155.25-19.5.html", U256::from_words([0x7176cb685e63af3a, 0xd03448a70f06a811, 0xeb8fbcad9cf20234, 0x5b5a48ea3f09528f]),
+  fnv1_256_test_140: U256, b"http://volcano.wr.usgs.gov/kilaueastatus.php", U256::from_words([0x8a857fe0ffd4547f, 0xeab273934f896a9e, 0x44f9dda6d59536fa, 0x68d5ea00b883e4d7]),
+  fnv1_256_test_141: U256, b"http://www.avo.alaska.edu/activity/Redoubt.php", U256::from_words([0xd4784075d98c2866, 0x128f60cfa4aa47b5, 0xa3272e4d244a5a81, 0x6eeb1d4bd9f4290b]),
+  fnv1_256_test_142: U256, b"http://www.dilbert.com/fast/", U256::from_words([0x3e186c18364a0f6e, 0xc0ab4533a91375a8, 0x2c818fbea6179fd3, 0x0b9868cce0b86dd1]),
+  fnv1_256_test_143: U256, b"http://www.fourmilab.ch/gravitation/orbits/", U256::from_words([0x186283bdb4a6cfc7, 0xedbfb8133156f35c, 0xe81008f8e928d2b0, 0xaa92eb91a706c90e]),
+  fnv1_256_test_144: U256, b"http://www.fpoa.net/", U256::from_words([0x59fb26c150166352, 0xace77606930148af, 0x48ff63cb91c7462e, 0x247dd59e86e36136]),
+  fnv1_256_test_145: U256, b"http://www.ioccc.org/index.html", U256::from_words([0xf841fb92525964f9, 0x39064e57d504be23, 0xb6a2710b121181f0, 0x7a891653cf8eaf6b]),
+  fnv1_256_test_146: U256, b"http://www.isthe.com/cgi-bin/number.cgi", U256::from_words([0xe950b0079c68ba20, 0xe134018f7c8be2b8, 0x799d6536eccb14b7, 0x0da1d2a1a656a8c5]),
+  fnv1_256_test_147: U256, b"http://www.isthe.com/chongo/bio.html", U256::from_words([0xa03d6b229d91e9f6, 0x2bf67d704f4ad283, 0x209166982bddb197, 0x45204d24f1c73c1f]),
+  fnv1_256_test_148: U256, b"http://www.isthe.com/chongo/index.html", U256::from_words([0x95f71dd38f3421d5, 0x870f7f6a68ea09fb, 0xbaa2b5b29386e7c7, 0xf8dfe3b0a7745899]),
+  fnv1_256_test_149: U256, b"http://www.isthe.com/chongo/src/calc/lucas-calc", U256::from_words([0x310b768b3f0795ea, 0xf65805e0b00d9e20, 0x0b1519a0a679940c, 0x3bf6e47acaf6b5a7]),
+  fnv1_256_test_150: U256, b"http://www.isthe.com/chongo/tech/astro/venus2004.html", U256::from_words([0x6adca7428240bb12, 0x251c9c1c78ab539c, 0x777eacb5db71d6f5, 0x4b1701e794006071]),
+  fnv1_256_test_151: U256, b"http://www.isthe.com/chongo/tech/astro/vita.html", U256::from_words([0xf02b9158d320eeed, 0x08005194e98f5bdf, 0x34e77ee2889d2f53, 0x90ee46d05bfab6ee]),
+  fnv1_256_test_152: U256, b"http://www.isthe.com/chongo/tech/comp/c/expert.html", U256::from_words([0x23605b62800bc7a2, 0xdf10096c3855c9dc, 0xbebe9a4db2418e84, 0xc8f83911c8ba0c34]),
+  fnv1_256_test_153: U256, b"http://www.isthe.com/chongo/tech/comp/calc/index.html", U256::from_words([0x1e896b0516bee244, 0xadc13120f06ace88, 0xb4fb8b522fa0f27e, 0xb12c27aae5868428]),
+  fnv1_256_test_154: U256, b"http://www.isthe.com/chongo/tech/comp/fnv/index.html", U256::from_words([0x101272d1a4d0043d, 0xa9d53b121ef6ed1c, 0x824f11da6ae0f6fe, 0x48ace181f49cac7d]),
+  fnv1_256_test_155: U256, b"http://www.isthe.com/chongo/tech/math/number/howhigh.html", U256::from_words([0x1e575afe4c1211b7, 0xb2ba975dd9e93d97, 0xa6ac9f32810aa308, 0x0e90b03c4f71980d]),
+  fnv1_256_test_156: U256, b"http://www.isthe.com/chongo/tech/math/number/number.html", U256::from_words([0x1bb20b38ea542262, 0x6c2484809ffb9bef, 0xd75d82f8ee7e287d, 0xdf0c84d5c55c22ea]),
+  fnv1_256_test_157: U256, b"http://www.isthe.com/chongo/tech/math/prime/mersenne.html", U256::from_words([0xd012f12fbc4023c3, 0xcd44535dc61be093, 0x8300703278dd6a19, 0xdbc631a621489bd6]),
+  fnv1_256_test_158: U256, b"http://www.isthe.com/chongo/tech/math/prime/mersenne.html#largest", U256::from_words([0xc8674169dba4435b, 0xb8ac7cc517cc5276, 0x4eb0aef19ec61c71, 0x73ec4b6f2a9af8b3]),
+  fnv1_256_test_159: U256, b"http://www.lavarnd.org/cgi-bin/corpspeak.cgi", U256::from_words([0x1f5b9245cb4b9aca, 0xd3e243f4e59d8dc8, 0x6fb24d20799cfa1c, 0x6aa432cddcc57608]),
+  fnv1_256_test_160: U256, b"http://www.lavarnd.org/cgi-bin/haiku.cgi", U256::from_words([0xef2256255c30ec7d, 0xcd82b73df9aa958f, 0x3cd9eb6b646f3c22, 0xdc9a3044053c7ad8]),
+  fnv1_256_test_161: U256, b"http://www.lavarnd.org/cgi-bin/rand-none.cgi", U256::from_words([0x11bbb89c4c1c94d3, 0x672f84f4e59d8dc8, 0x6fb2507d41b23a2b, 0x945f5dab6ff132de]),
+  fnv1_256_test_162: U256, b"http://www.lavarnd.org/cgi-bin/randdist.cgi", U256::from_words([0x91ca0dd402bff780, 0xebfcfb8c96a423bc, 0x0ebcb76496e68aa1, 0x82112f9957fc3285]),
+  fnv1_256_test_163: U256, b"http://www.lavarnd.org/index.html", U256::from_words([0xab2225bb07488b49, 0x179a6ff4d3324cc9, 0x74096477b0403ead, 0xd486a1a98f3e0f12]),
+  fnv1_256_test_164: U256, b"http://www.lavarnd.org/what/nist-test.html", U256::from_words([0x02e797516d512563, 0xa9aa1f7ff783e00b, 0x2ab46d00b5dd7e33, 0xc14adc1c4d78c38c]),
+  fnv1_256_test_165: U256, b"http://www.macosxhints.com/", U256::from_words([0x33074ad89c30315d, 0xfd557e8fa655de6c, 0xc261812227fa982c, 0x0c6c19aefef44835]),
+  fnv1_256_test_166: U256, b"http://www.mellis.com/", U256::from_words([0x00032de59dda3399, 0xc0048d66630e9a46, 0xa49eb1378c2f409a, 0xe8ab0393b4c73cb0]),
+  fnv1_256_test_167: U256, b"http://www.nature.nps.gov/air/webcams/parks/havoso2alert/havoalert.cfm", U256::from_words([0xc2550a0df3197d56, 0x0463627d4f4161ce, 0x916a6fb277927a89, 0x9ae766c8515e0d1e]),
+  fnv1_256_test_168: U256, b"http://www.nature.nps.gov/air/webcams/parks/havoso2alert/timelines_24.cfm", U256::from_words([0xa0d48c145e1bafaf, 0xad1f2661fdf24e21, 0xd2ee6d2914ce1809, 0xd99ad445b7ae6c79]),
+  fnv1_256_test_169: U256, b"http://www.paulnoll.com/", U256::from_words([0x4faa74cdb700f0f4, 0xc363b2924fa9c285, 0xedd7493ba371908f, 0xbf6de524dabe059f]),
+  fnv1_256_test_170: U256, b"http://www.pepysdiary.com/", U256::from_words([0x5874e9aa39d5550c, 0xf9b160d2f941dd4a, 0x9323980499e895a8, 0x934182d61b7ab1b6]),
+  fnv1_256_test_171: U256, b"http://www.sciencenews.org/index/home/activity/view", U256::from_words([0x605953197c45dc73, 0xf5a1623383a3bb64, 0x39d965474ca20f9f, 0x02022cbb7555141e]),
+  fnv1_256_test_172: U256, b"http://www.skyandtelescope.com/", U256::from_words([0x15242210fe21fb48, 0x43b7504400ea92ac, 0x43b9abb373efea76, 0xb1be751709de03e0]),
+  fnv1_256_test_173: U256, b"http://www.sput.nl/~rob/sirius.html", U256::from_words([0xf4379a10ea1c97f4, 0x29aae56c6636cf78, 0xa1120b669d992849, 0x8339f05043a3b5e5]),
+  fnv1_256_test_174: U256, b"http://www.systemexperts.com/", U256::from_words([0x30f06dfc3b8bd1bb, 0x7ce223a375ef12c8, 0x831076a4748b7393, 0x0e2cf20f7e710280]),
+  fnv1_256_test_175: U256, b"http://www.tq-international.com/phpBB3/index.php", U256::from_words([0x37a68b847b4f2a41, 0xe403710789fb8560, 0xa8524323bd4ecf54, 0x71fddc206c83fe14]),
+  fnv1_256_test_176: U256, b"http://www.travelquesttours.com/index.htm", U256::from_words([0xf424dc3a5f181669, 0xc831c7940c3eeb87, 0x2543d00ede93d0a8, 0xa41824d5ae005246]),
+  fnv1_256_test_177: U256, b"http://www.wunderground.com/global/stations/89606.html", U256::from_words([0xc42a8747d5e2a22f, 0xc41dd29f3cdc115f, 0xcdec2ad566dd11c7, 0xb7e5ddd67aaf6552]),
+  fnv1_256_test_178: U256, &repeat(b"21701", 10), U256::from_words([0xf5dffd691e3bd4cd, 0x0422816c96385d3c, 0xf6a46f13ec320151, 0xa2d18f77739debf7]),
+  fnv1_256_test_179: U256, &repeat(b"M21701", 10), U256::from_words([0xa384570be6c4d74c, 0x7a5157f315b8db64, 0x25286ed6e5f375d5, 0x0b743307cb1c265d]),
+  fnv1_256_test_180: U256, &repeat(b"2^21701-1", 10), U256::from_words([0x259b820a80ebc8e1, 0xaeb8a3ba614a3b83, 0xc122dc967e8667c0, 0x58a027aa645b5ff7]),
+  fnv1_256_test_181: U256, &repeat(b"\x54\xc5", 10), U256::from_words([0x579b59c477cb94e8, 0xc379ab2da7e64c19, 0xa4353e679cb52ee5, 0x09a733b5a6828b0d]),
+  fnv1_256_test_182: U256, &repeat(b"\xc5\x54", 10), U256::from_words([0xceb3f6748eb6bb2a, 0xa05998443bb84665, 0x18bc85c121e135b6, 0x4771a96fc06648f5]),
+  fnv1_256_test_183: U256, &repeat(b"23209", 10), U256::from_words([0x47953bf06e70ed6c, 0x4b5bf60dbfb88a65, 0x30b8cbf723f9d548, 0x624d4a8d718dae49]),
+  fnv1_256_test_184: U256, &repeat(b"M23209", 10), U256::from_words([0xfbe772c20325c310, 0xf02b9bbbc3640c07, 0xd008d79da83da1fc, 0xa1b0d01d8972a9d9]),
+  fnv1_256_test_185: U256, &repeat(b"2^23209-1", 10), U256::from_words([0x4674ac87c68f48d4, 0x33c6d4ee8c939df0, 0x7b876e4dc9a9b720, 0x979aaa4a9dfbad25]),
+  fnv1_256_test_186: U256, &repeat(b"\x5a\xa9", 10), U256::from_words([0xa9363850cf875340, 0x2b6a2b23214b3438, 0xdd4929a900405e9a, 0x47ad94733bbdde21]),
+  fnv1_256_test_187: U256, &repeat(b"\xa9\x5a", 10), U256::from_words([0x28bb8b50f89c74f0, 0x96d3f47ad3ccfe24, 0xc5f08fba0ac26d58, 0xb1b3c8db09e3a429]),
+  fnv1_256_test_188: U256, &repeat(b"391581216093", 10), U256::from_words([0xb6f041e571b3704e, 0x29f373626241d1c4, 0x10357e86c900ffe9, 0xd2fb29b5d41afb39]),
+  fnv1_256_test_189: U256, &repeat(b"391581*2^216093-1", 10), U256::from_words([0xb2caadbf40e2a70d, 0xa904a236538b240a, 0xf4e1f0b29ac125fe, 0xa9def9edcc55a3c9]),
+  fnv1_256_test_190: U256, &repeat(b"\x05\xf9\x9d\x03\x4c\x81", 10), U256::from_words([0x91fad069184caeee, 0x538287d472754d89, 0xd2a72e4bd4512e02, 0x39083de39575a46d]),
+  fnv1_256_test_191: U256, &repeat(b"FEDCBA9876543210", 10), U256::from_words([0x6bc61747479bb7a7, 0x21f94e52dfcb50d3, 0xd14ef8765c183130, 0x42ef212222d25175]),
+  fnv1_256_test_192: U256, &repeat(b"\xfe\xdc\xba\x98\x76\x54\x32\x10", 10), U256::from_words([0x48accd3892c7a320, 0x91b24c2e97a105ed, 0xb79baced614037a2, 0xadc04eff13e54415]),
+  fnv1_256_test_193: U256, &repeat(b"EFCDAB8967452301", 10), U256::from_words([0xbefadf0bf4ec61ac, 0xfe616aadcaaf8552, 0x42e874b68c5a9d49, 0x53e03092f95ed145]),
+  fnv1_256_test_194: U256, &repeat(b"\xef\xcd\xab\x89\x67\x45\x23\x01", 10), U256::from_words([0x29bca819c4070c54, 0x4ba289793e0c5ee6, 0x047792ca680f466c, 0x426c8d271d2829b5]),
+  fnv1_256_test_195: U256, &repeat(b"0123456789ABCDEF", 10), U256::from_words([0x62932418b796b458, 0xd43bbea72c4d2329, 0xc7981c078a2e6324, 0xdff5ecf3e642fe25]),
+  fnv1_256_test_196: U256, &repeat(b"\x01\x23\x45\x67\x89\xab\xcd\xef", 10), U256::from_words([0x63c5051cbd61b36d, 0xec003a35e4a668d5, 0xb37a6183357eef36, 0x75e0693e9f4b9795]),
+  fnv1_256_test_197: U256, &repeat(b"1032547698BADCFE", 10), U256::from_words([0xaf3da834c32d85ec, 0x22d7d2d31cac692a, 0xf3e19945a6161276, 0x326e00620efe7c65]),
+  fnv1_256_test_198: U256, &repeat(b"\x10\x32\x54\x76\x98\xba\xdc\xfe", 10), U256::from_words([0x03a39fe8e8baaaad, 0x403b8da833016bd8, 0x78d4d5c09601f606, 0xe717c2e29459df95]),
+  fnv1_256_test_199: U256, &repeat(b"\x00", 500), U256::from_words([0xf863872ce894f872, 0x17b7f65615aaba6b, 0x36013372dcfe427f, 0x3027fd63f3eb9605]),
+  fnv1_256_test_200: U256, &repeat(b"\x07", 500), U256::from_words([0x387d09bc8b7321dc, 0x976d00e441e78b9d, 0x37b47aaf43121cb3, 0xcad3489acc2df489]),
+  fnv1_256_test_201: U256, &repeat(b"~", 500), U256::from_words([0xd6f0035efdb2516e, 0xb413c16ca64cfe34, 0x0fd0cd7be530c0df, 0xa405ed01afd6a305]),
+  fnv1_256_test_202: U256, &repeat(b"\x7f", 500), U256::from_words([0x77b3f134924c1a91, 0x26694a4e65824eba, 0x67f2004329da359c, 0xd5ee9594cd198989]),
+  fnv1_512_test_0: U512, b"", U512::from_words([0xb86db0b1171f4416, 0xdca1e50f309990ac, 0xac87d059c9000000, 0x0000000000000d21, 0xe948f68a34c192f6, 0x2ea79bc942dbe7ce, 0x182036415f56e34b, 0xac982aac4afe9fd9]),
+  fnv1_512_test_1: U512, b"a", U512::from_words([0xe43a992dc8fc5ad7, 0xde493e3d696d6f85, 0xd64326ec28000000, 0x000000000011986f, 0x90c2532caf5be7d8, 0x8291baa894a39522, 0x5328b196bd6a8a64, 0x3fe12cd87b282bde]),
+  fnv1_512_test_2: U512, b"b", U512::from_words([0xe43a992dc8fc5ad7, 0xde493e3d696d6f85, 0xd64326ec28000000, 0x000000000011986f, 0x90c2532caf5be7d8, 0x8291baa894a39522, 0x5328b196bd6a8a64, 0x3fe12cd87b282bdd]),
+  fnv1_512_test_3: U512, b"c", U512::from_words([0xe43a992dc8fc5ad7, 0xde493e3d696d6f85, 0xd64326ec28000000, 0x000000000011986f, 0x90c2532caf5be7d8, 0x8291baa894a39522, 0x5328b196bd6a8a64, 0x3fe12cd87b282bdc]),
+  fnv1_512_test_4: U512, b"d", U512::from_words([0xe43a992dc8fc5ad7, 0xde493e3d696d6f85, 0xd64326ec28000000, 0x000000000011986f, 0x90c2532caf5be7d8, 0x8291baa894a39522, 0x5328b196bd6a8a64, 0x3fe12cd87b282bdb]),
+  fnv1_512_test_5: U512, b"e", U512::from_words([0xe43a992dc8fc5ad7, 0xde493e3d696d6f85, 0xd64326ec28000000, 0x000000000011986f, 0x90c2532caf5be7d8, 0x8291baa894a39522, 0x5328b196bd6a8a64, 0x3fe12cd87b282bda]),
+  fnv1_512_test_6: U512, b"f", U512::from_words([0xe43a992dc8fc5ad7, 0xde493e3d696d6f85, 0xd64326ec28000000, 0x000000000011986f, 0x90c2532caf5be7d8, 0x8291baa894a39522, 0x5328b196bd6a8a64, 0x3fe12cd87b282bd9]),
+  fnv1_512_test_7: U512, b"fo", U512::from_words([0x7317dfed6c70dfec, 0x6adfced2a5e04d7e, 0xec744e9571000000, 0x0000000017933d7a, 0xf45d70def423a316, 0xf14117df272cd0fd, 0x6b85f0f7c9bf6c51, 0x96b3160d02d2bfd0]),
+  fnv1_512_test_8: U512, b"foo", U512::from_words([0x142433ed48a78bb4, 0x29a7dba8911e8824, 0xdcd81cfa37000000, 0x0000001f96475fbd, 0x69323ab91bbf83bd, 0x3e36fbfd7d0c038b, 0x1075dbff4f7a2150, 0xe9f28b6ec85effdf]),
+  fnv1_512_test_9: U512, b"foob", U512::from_words([0xf9fe9eefe38ca43f, 0xcf36c8fbc0d25bef, 0x5457323f90000000, 0x00002a5259a146c7, 0xf24cae042d99828e, 0x5baba0a28b18bf53, 0x0de9c3137ca2a369, 0x73f8d16e7748d3ab]),
+  fnv1_512_test_10: U512, b"fooba", U512::from_words([0x96b20c29347dfb41, 0xb5e3ebf2c34d267b, 0x6f4b9bfd9b000000, 0x0038b4561715d5e5, 0xa4bd279918adecbc, 0xd2f439c85e285847, 0xa4345f1bfde8f24a, 0x62609b01d2939a7c]),
+  fnv1_512_test_11: U512, b"foobar", U512::from_words([0xb0ec738d9c6fd969, 0xd05f0b35f6c0effd, 0x2020946529000000, 0x4bf99f58ee4196af, 0xb9700e20110830fe, 0xa5396b76280e47fd, 0x022b6e81331ca1a9, 0xcf6faf7123c3fc56]),
+  fnv1_512_test_12: U512, ::std::ffi::CString::new("").unwrap().as_bytes_with_nul(), U512::from_words([0xe43a992dc8fc5ad7, 0xde493e3d696d6f85, 0xd64326ec28000000, 0x000000000011986f, 0x90c2532caf5be7d8, 0x8291baa894a39522, 0x5328b196bd6a8a64, 0x3fe12cd87b282bbf]),
+  fnv1_512_test_13: U512, ::std::ffi::CString::new("a").unwrap().as_bytes_with_nul(), U512::from_words([0x7317dfed6c70dfec, 0x6adfced2a5e04d7e, 0xec744e9576000000, 0x0000000017933d7a, 0xf45d70def423a316, 0xf14117df272cd0fd, 0x6b85f0f7c9bf6c51, 0x96b3160d02d2c672]),
+  fnv1_512_test_14: U512, ::std::ffi::CString::new("b").unwrap().as_bytes_with_nul(), U512::from_words([0x7317dfed6c70dfec, 0x6adfced2a5e04d7e, 0xec744e9575000000, 0x0000000017933d7a, 0xf45d70def423a316, 0xf14117df272cd0fd, 0x6b85f0f7c9bf6c51, 0x96b3160d02d2c51b]),
+  fnv1_512_test_15: U512, ::std::ffi::CString::new("c").unwrap().as_bytes_with_nul(), U512::from_words([0x7317dfed6c70dfec, 0x6adfced2a5e04d7e, 0xec744e9574000000, 0x0000000017933d7a, 0xf45d70def423a316, 0xf14117df272cd0fd, 0x6b85f0f7c9bf6c51, 0x96b3160d02d2c3c4]),
+  fnv1_512_test_16: U512, ::std::ffi::CString::new("d").unwrap().as_bytes_with_nul(), U512::from_words([0x7317dfed6c70dfec, 0x6adfced2a5e04d7e, 0xec744e9573000000, 0x0000000017933d7a, 0xf45d70def423a316, 0xf14117df272cd0fd, 0x6b85f0f7c9bf6c51, 0x96b3160d02d2c26d]),
+  fnv1_512_test_17: U512, ::std::ffi::CString::new("e").unwrap().as_bytes_with_nul(), U512::from_words([0x7317dfed6c70dfec, 0x6adfced2a5e04d7e, 0xec744e9572000000, 0x0000000017933d7a, 0xf45d70def423a316, 0xf14117df272cd0fd, 0x6b85f0f7c9bf6c51, 0x96b3160d02d2c116]),
+  fnv1_512_test_18: U512, ::std::ffi::CString::new("f").unwrap().as_bytes_with_nul(), U512::from_words([0x7317dfed6c70dfec, 0x6adfced2a5e04d7e, 0xec744e9571000000, 0x0000000017933d7a, 0xf45d70def423a316, 0xf14117df272cd0fd, 0x6b85f0f7c9bf6c51, 0x96b3160d02d2bfbf]),
+  fnv1_512_test_19: U512, ::std::ffi::CString::new("fo").unwrap().as_bytes_with_nul(), U512::from_words([0x142433ed48a78bb4, 0x29a7dba8911e8824, 0xdcd81cfa37000000, 0x0000001f96475fbd, 0x69323ab91bbf83bd, 0x3e36fbfd7d0c038b, 0x1075dbff4f7a2150, 0xe9f28b6ec85effb0]),
+  fnv1_512_test_20: U512, ::std::ffi::CString::new("foo").unwrap().as_bytes_with_nul(), U512::from_words([0xf9fe9eefe38ca43f, 0xcf36c8fbc0d25bef, 0x5457323f90000000, 0x00002a5259a146c7, 0xf24cae042d99828e, 0x5baba0a28b18bf53, 0x0de9c3137ca2a369, 0x73f8d16e7748d3c9]),
+  fnv1_512_test_21: U512, ::std::ffi::CString::new("foob").unwrap().as_bytes_with_nul(), U512::from_words([0x96b20c29347dfb41, 0xb5e3ebf2c34d267b, 0x6f4b9bfd9b000000, 0x0038b4561715d5e5, 0xa4bd279918adecbc, 0xd2f439c85e285847, 0xa4345f1bfde8f24a, 0x62609b01d2939a1d]),
+  fnv1_512_test_22: U512, ::std::ffi::CString::new("fooba").unwrap().as_bytes_with_nul(), U512::from_words([0xb0ec738d9c6fd969, 0xd05f0b35f6c0effd, 0x2020946529000000, 0x4bf99f58ee4196af, 0xb9700e20110830fe, 0xa5396b76280e47fd, 0x022b6e81331ca1a9, 0xcf6faf7123c3fc24]),
+  fnv1_512_test_23: U512, ::std::ffi::CString::new("foobar").unwrap().as_bytes_with_nul(), U512::from_words([0x82f6e10496de7834, 0xb08b21ef4650fbd5, 0x7cca978645000065, 0xcb74802739e0e571, 0x7522ecf6d1f9a52f, 0x5feefb4fab2273fd, 0xe8310f1b7b5c9a84, 0xeea41096eb97173a]),
+  fnv1_512_test_24: U512, b"ch", U512::from_words([0x7317dfed6c70dfec, 0x6adfced2a5e04d7e, 0xec744e9574000000, 0x0000000017933d7a, 0xf45d70def423a316, 0xf14117df272cd0fd, 0x6b85f0f7c9bf6c51, 0x96b3160d02d2c3ac]),
+  fnv1_512_test_25: U512, b"cho", U512::from_words([0x142433ed48a78bb4, 0x29a7dba8911e8824, 0xdcd81d0218000000, 0x0000001f96475fbd, 0x69323ab91bbf83bd, 0x3e36fbfd7d0c038b, 0x1075dbff4f7a2150, 0xe9f28b6ec8642b1b]),
+  fnv1_512_test_26: U512, b"chon", U512::from_words([0xf9fe9eefe38ca43f, 0xcf36c8fbc0d25bef, 0x545741f943000000, 0x00002a5259a146c7, 0xf24cae042d99828e, 0x5baba0a28b18bf53, 0x0de9c3137ca2a369, 0x73f8d16e7e35c143]),
+  fnv1_512_test_27: U512, b"chong", U512::from_words([0x96b20c29347dfb41, 0xb5e3ebf2c34d267b, 0x6f679aba08000000, 0x0038b4561715d5e5, 0xa4bd279918adecbc, 0xd2f439c85e285847, 0xa4345f1bfde8f24a, 0x62609b0b1a05f0a2]),
+  fnv1_512_test_28: U512, b"chongo", U512::from_words([0xb0ec738d9c6fd969, 0xd05f0b35f6c0effd, 0x4eea55315a000000, 0x4bf99f58ee4196af, 0xb9700e20110830fe, 0xa5396b76280e47fd, 0x022b6e81331ca1a9, 0xcf6fbbdfddf56961]),
+  fnv1_512_test_29: U512, b"chongo ", U512::from_words([0x82f6e10496de7834, 0xb08b21ef4650fc20, 0x9bd61a88f7000065, 0xcb74802739e0e571, 0x7522ecf6d1f9a52f, 0x5feefb4fab2273fd, 0xe8310f1b7b5c9a84, 0xeeb4b8f263d030d7]),
+  fnv1_512_test_30: U512, b"chongo w", U512::from_words([0xc8729f9a21fb3fa7, 0xa5e5d431bb708469, 0xbe415db3c8008863, 0x9917b48e8c536b03, 0xf1cb7eb3557c5279, 0x8932b7be4b296932, 0x19bb3dd24913081b, 0xd423ccc3bbf17066]),
+  fnv1_512_test_31: U512, b"chongo wa", U512::from_words([0x4fdf00ecb9bc04dd, 0x193861aa3f958d7a, 0xad507d515eb6bd72, 0x1ec2eafe03c46248, 0xf7a6c247899280d6, 0xd2f42ff6b47bf220, 0x79dfd4bfe87fdd49, 0x3bf75a40d07d98cb]),
+  fnv1_512_test_32: U512, b"chongo was", U512::from_words([0xfa7eb91efb646411, 0x8a735af87a9c86b8, 0x77a8659eb1d7d3e7, 0x3728da570c1fafc3, 0xd06e4dd9534a9fd4, 0xa52c438bd2116983, 0x4ae60d2083517d1f, 0x5869ecd75847b78e]),
+  fnv1_512_test_33: U512, b"chongo was ", U512::from_words([0x2b9c19ec56ccf98d, 0xa11234696710eb15, 0x29f86f57d62ceaca, 0xe7bc8aa13e747f5c, 0x43ca4e2e98fc25e9, 0x4e4e805675545ee9, 0x5a3b968ff22ea4ff, 0x75ec54874816ef62]),
+  fnv1_512_test_34: U512, b"chongo was h", U512::from_words([0xc49c100735fc9859, 0x5f5667de1720e5af, 0xc325459f582e95dc, 0x7d9dbe0aae16a49e, 0xd40ec06ef9d6cb97, 0xeb2df3d7340b2aa7, 0xe5d6badd7c7f1246, 0xffa5414196babc26]),
+  fnv1_512_test_35: U512, b"chongo was he", U512::from_words([0x444d84d3fd55f679, 0x9a41a9a3441363bf, 0xb887033b4c6aca6c, 0x4e59a04f405690ce, 0x1fc3d4b0becac88c, 0x1a91b556baf626f4, 0xf2b45ec1ce417d20, 0x866a6ee0f432168f]),
+  fnv1_512_test_36: U512, b"chongo was her", U512::from_words([0xda97ee2f631ff14c, 0x6fc88b3d5681104f, 0x1dd98689f215371c, 0xfa15ca2f33fc042c, 0x8f61f8cfa1b2b3b7, 0x9939f7347fce3231, 0x2faaf5ab59bea694, 0x189a8f672f1c39eb]),
+  fnv1_512_test_37: U512, b"chongo was here", U512::from_words([0x160fefb000fbf85f, 0x710c4dd57b057490, 0x65a25f0d456cd7d3, 0x1331e53ea6a997b4, 0x1c445e31a66ecafe, 0x4caa37573d453fe6, 0xde0f28953e71306c, 0xf71623401ed199b8]),
+  fnv1_512_test_38: U512, b"chongo was here!", U512::from_words([0xe6976a113877d709, 0x0cbaba384146439b, 0x6cac2c61bcd52bce, 0xb7da26f14d3a4251, 0xdf9a3485fe71fab8, 0xb81025e317c89e53, 0x864f5ff6a9a7e1ff, 0x0ea93ae94ad4f5a9]),
+  fnv1_512_test_39: U512, b"chongo was here!\n", U512::from_words([0xd7f6e5b0fc176c80, 0x04d9294174333e79, 0x83fa4be9aa9db1f8, 0x554a2d4e790edbb2, 0x979c5f87eab6ed7e, 0x9da2c344ddcc21e8, 0xf459937d4fefccbc, 0xa4bdee9343552565]),
+  fnv1_512_test_40: U512, ::std::ffi::CString::new("ch").unwrap().as_bytes_with_nul(), U512::from_words([0x142433ed48a78bb4, 0x29a7dba8911e8824, 0xdcd81d0218000000, 0x0000001f96475fbd, 0x69323ab91bbf83bd, 0x3e36fbfd7d0c038b, 0x1075dbff4f7a2150, 0xe9f28b6ec8642b74]),
+  fnv1_512_test_41: U512, ::std::ffi::CString::new("cho").unwrap().as_bytes_with_nul(), U512::from_words([0xf9fe9eefe38ca43f, 0xcf36c8fbc0d25bef, 0x545741f943000000, 0x00002a5259a146c7, 0xf24cae042d99828e, 0x5baba0a28b18bf53, 0x0de9c3137ca2a369, 0x73f8d16e7e35c12d]),
+  fnv1_512_test_42: U512, ::std::ffi::CString::new("chon").unwrap().as_bytes_with_nul(), U512::from_words([0x96b20c29347dfb41, 0xb5e3ebf2c34d267b, 0x6f679aba08000000, 0x0038b4561715d5e5, 0xa4bd279918adecbc, 0xd2f439c85e285847, 0xa4345f1bfde8f24a, 0x62609b0b1a05f0c5]),
+  fnv1_512_test_43: U512, ::std::ffi::CString::new("chong").unwrap().as_bytes_with_nul(), U512::from_words([0xb0ec738d9c6fd969, 0xd05f0b35f6c0effd, 0x4eea55315a000000, 0x4bf99f58ee4196af, 0xb9700e20110830fe, 0xa5396b76280e47fd, 0x022b6e81331ca1a9, 0xcf6fbbdfddf5690e]),
+  fnv1_512_test_44: U512, ::std::ffi::CString::new("chongo").unwrap().as_bytes_with_nul(), U512::from_words([0x82f6e10496de7834, 0xb08b21ef4650fc20, 0x9bd61a88f7000065, 0xcb74802739e0e571, 0x7522ecf6d1f9a52f, 0x5feefb4fab2273fd, 0xe8310f1b7b5c9a84, 0xeeb4b8f263d030f7]),
+  fnv1_512_test_45: U512, ::std::ffi::CString::new("chongo ").unwrap().as_bytes_with_nul(), U512::from_words([0xc8729f9a21fb3fa7, 0xa5e5d431bb708469, 0xbe415db3c8008863, 0x9917b48e8c536b03, 0xf1cb7eb3557c5279, 0x8932b7be4b296932, 0x19bb3dd24913081b, 0xd423ccc3bbf17011]),
+  fnv1_512_test_46: U512, ::std::ffi::CString::new("chongo w").unwrap().as_bytes_with_nul(), U512::from_words([0x4fdf00ecb9bc04dd, 0x193861aa3f958d7a, 0xad507d515eb6bd72, 0x1ec2eafe03c46248, 0xf7a6c247899280d6, 0xd2f42ff6b47bf220, 0x79dfd4bfe87fdd49, 0x3bf75a40d07d98aa]),
+  fnv1_512_test_47: U512, ::std::ffi::CString::new("chongo wa").unwrap().as_bytes_with_nul(), U512::from_words([0xfa7eb91efb646411, 0x8a735af87a9c86b8, 0x77a8659eb1d7d3e7, 0x3728da570c1fafc3, 0xd06e4dd9534a9fd4, 0xa52c438bd2116983, 0x4ae60d2083517d1f, 0x5869ecd75847b7fd]),
+  fnv1_512_test_48: U512, ::std::ffi::CString::new("chongo was").unwrap().as_bytes_with_nul(), U512::from_words([0x2b9c19ec56ccf98d, 0xa11234696710eb15, 0x29f86f57d62ceaca, 0xe7bc8aa13e747f5c, 0x43ca4e2e98fc25e9, 0x4e4e805675545ee9, 0x5a3b968ff22ea4ff, 0x75ec54874816ef42]),
+  fnv1_512_test_49: U512, ::std::ffi::CString::new("chongo was ").unwrap().as_bytes_with_nul(), U512::from_words([0xc49c100735fc9859, 0x5f5667de1720e5af, 0xc325459f582e95dc, 0x7d9dbe0aae16a49e, 0xd40ec06ef9d6cb97, 0xeb2df3d7340b2aa7, 0xe5d6badd7c7f1246, 0xffa5414196babc4e]),
+  fnv1_512_test_50: U512, ::std::ffi::CString::new("chongo was h").unwrap().as_bytes_with_nul(), U512::from_words([0x444d84d3fd55f679, 0x9a41a9a3441363bf, 0xb887033b4c6aca6c, 0x4e59a04f405690ce, 0x1fc3d4b0becac88c, 0x1a91b556baf626f4, 0xf2b45ec1ce417d20, 0x866a6ee0f43216ea]),
+  fnv1_512_test_51: U512, ::std::ffi::CString::new("chongo was he").unwrap().as_bytes_with_nul(), U512::from_words([0xda97ee2f631ff14c, 0x6fc88b3d5681104f, 0x1dd98689f215371c, 0xfa15ca2f33fc042c, 0x8f61f8cfa1b2b3b7, 0x9939f7347fce3231, 0x2faaf5ab59bea694, 0x189a8f672f1c3999]),
+  fnv1_512_test_52: U512, ::std::ffi::CString::new("chongo was her").unwrap().as_bytes_with_nul(), U512::from_words([0x160fefb000fbf85f, 0x710c4dd57b057490, 0x65a25f0d456cd7d3, 0x1331e53ea6a997b4, 0x1c445e31a66ecafe, 0x4caa37573d453fe6, 0xde0f28953e71306c, 0xf71623401ed199dd]),
+  fnv1_512_test_53: U512, ::std::ffi::CString::new("chongo was here").unwrap().as_bytes_with_nul(), U512::from_words([0xe6976a113877d709, 0x0cbaba384146439b, 0x6cac2c61bcd52bce, 0xb7da26f14d3a4251, 0xdf9a3485fe71fab8, 0xb81025e317c89e53, 0x864f5ff6a9a7e1ff, 0x0ea93ae94ad4f588]),
+  fnv1_512_test_54: U512, ::std::ffi::CString::new("chongo was here!").unwrap().as_bytes_with_nul(), U512::from_words([0xd7f6e5b0fc176c80, 0x04d9294174333e79, 0x83fa4be9aa9db1f8, 0x554a2d4e790edbb2, 0x979c5f87eab6ed7e, 0x9da2c344ddcc21e8, 0xf459937d4fefccbc, 0xa4bdee934355256f]),
+  fnv1_512_test_55: U512, ::std::ffi::CString::new("chongo was here!\n").unwrap().as_bytes_with_nul(), U512::from_words([0xa0ab8c43ac56b919, 0xfc46367f6d4d72be, 0x679f0b38fe4973ba, 0x4662b42432e85c49, 0x2283ff1b7b1834a5, 0x3513a1452c816f1f, 0x64049ce61a4b50c0, 0xba7aa74f37151a53]),
+  fnv1_512_test_56: U512, b"cu", U512::from_words([0x7317dfed6c70dfec, 0x6adfced2a5e04d7e, 0xec744e9574000000, 0x0000000017933d7a, 0xf45d70def423a316, 0xf14117df272cd0fd, 0x6b85f0f7c9bf6c51, 0x96b3160d02d2c3b1]),
+  fnv1_512_test_57: U512, b"cur", U512::from_words([0x142433ed48a78bb4, 0x29a7dba8911e8824, 0xdcd81d021d000000, 0x0000001f96475fbd, 0x69323ab91bbf83bd, 0x3e36fbfd7d0c038b, 0x1075dbff4f7a2150, 0xe9f28b6ec8643255]),
+  fnv1_512_test_58: U512, b"curd", U512::from_words([0xf9fe9eefe38ca43f, 0xcf36c8fbc0d25bef, 0x5457420730000000, 0x00002a5259a146c7, 0xf24cae042d99828e, 0x5baba0a28b18bf53, 0x0de9c3137ca2a369, 0x73f8d16e7e3f6f87]),
+  fnv1_512_test_59: U512, b"curds", U512::from_words([0x96b20c29347dfb41, 0xb5e3ebf2c34d267b, 0x6f67b710d7000000, 0x0038b4561715d5e5, 0xa4bd279918adecbc, 0xd2f439c85e285847, 0xa4345f1bfde8f24a, 0x62609b0b26fe6d92]),
+  fnv1_512_test_60: U512, b"curds ", U512::from_words([0xb0ec738d9c6fd969, 0xd05f0b35f6c0effd, 0x4f1d45fda3000000, 0x4bf99f58ee4196af, 0xb9700e20110830fe, 0xa5396b76280e47fd, 0x022b6e81331ca1a9, 0xcf6fbbf13ee4cebe]),
+  fnv1_512_test_61: U512, b"curds a", U512::from_words([0x82f6e10496de7834, 0xb08b21ef4650fc20, 0xf177aba423000065, 0xcb74802739e0e571, 0x7522ecf6d1f9a52f, 0x5feefb4fab2273fd, 0xe8310f1b7b5c9a84, 0xeeb4d03b449100f3]),
+  fnv1_512_test_62: U512, b"curds an", U512::from_words([0xc8729f9a21fb3fa7, 0xa5e5d431bb7084f3, 0xc29b89ebd8008863, 0x9917b48e8c536b03, 0xf1cb7eb3557c5279, 0x8932b7be4b296932, 0x19bb3dd24913081b, 0xd442ff68de4845fb]),
+  fnv1_512_test_63: U512, b"curds and", U512::from_words([0x4fdf00ecb9bc04dd, 0x193861aa3f966599, 0x2744134463b6bd72, 0x1ec2eafe03c46248, 0xf7a6c247899280d6, 0xd2f42ff6b47bf220, 0x79dfd4bfe87fdd49, 0x65c43581d2d5c329]),
+  fnv1_512_test_64: U512, b"curds and ", U512::from_words([0xfa7eb91efb646411, 0x8a735af87be7e469, 0x1e08a664c2d7d3e7, 0x3728da570c1fafc3, 0xd06e4dd9534a9fd4, 0xa52c438bd2116983, 0x4ae60d2083517d57, 0x59e3b0f17c687bcf]),
+  fnv1_512_test_65: U512, b"curds and w", U512::from_words([0x2b9c19ec56ccf98d, 0xa112346b5b0cec88, 0x2f13597cde2ceaca, 0xe7bc8aa13e747f5c, 0x43ca4e2e98fc25e9, 0x4e4e805675545ee9, 0x5a3b968ff22ef009, 0x7012138daffde22e]),
+  fnv1_512_test_66: U512, b"curds and wh", U512::from_words([0xc49c100735fc9859, 0x5f566ac707c0fc8a, 0xa09ce42fdc2e95dc, 0x7d9dbe0aae16a49e, 0xd40ec06ef9d6cb97, 0xeb2df3d7340b2aa7, 0xe5d6badd7ce39ca5, 0x283832d6cd2a0bca]),
+  fnv1_512_test_67: U512, b"curds and whe", U512::from_words([0x444d84d3fd55f679, 0x9a45f44808ba95f0, 0x0902e62bcc6aca6c, 0x4e59a04f405690ce, 0x1fc3d4b0becac88c, 0x1a91b556baf626f4, 0xf2b45ec254f6e148, 0xe34c1dcce355cbc3]),
+  fnv1_512_test_68: U512, b"curds and whey", U512::from_words([0xda97ee2f631ff14c, 0x760f4364fae230b9, 0xdfc5ba7aa615371c, 0xfa15ca2f33fc042c, 0x8f61f8cfa1b2b3b7, 0x9939f7347fce3231, 0x2faaf65fd6c7d8a8, 0x8afbed8497f4023c]),
+  fnv1_512_test_69: U512, b"curds and whey\n", U512::from_words([0x160fefb000fbf868, 0x8e4a1424cd9a44f8, 0x5684ce56c26cd7d3, 0x1331e53ea6a997b4, 0x1c445e31a66ecafe, 0x4caa37573d453fe6, 0xde101a68c5c349d2, 0x378b3ca797eefe6e]),
+  fnv1_512_test_70: U512, ::std::ffi::CString::new("cu").unwrap().as_bytes_with_nul(), U512::from_words([0x142433ed48a78bb4, 0x29a7dba8911e8824, 0xdcd81d021d000000, 0x0000001f96475fbd, 0x69323ab91bbf83bd, 0x3e36fbfd7d0c038b, 0x1075dbff4f7a2150, 0xe9f28b6ec8643227]),
+  fnv1_512_test_71: U512, ::std::ffi::CString::new("cur").unwrap().as_bytes_with_nul(), U512::from_words([0xf9fe9eefe38ca43f, 0xcf36c8fbc0d25bef, 0x5457420730000000, 0x00002a5259a146c7, 0xf24cae042d99828e, 0x5baba0a28b18bf53, 0x0de9c3137ca2a369, 0x73f8d16e7e3f6fe3]),
+  fnv1_512_test_72: U512, ::std::ffi::CString::new("curd").unwrap().as_bytes_with_nul(), U512::from_words([0x96b20c29347dfb41, 0xb5e3ebf2c34d267b, 0x6f67b710d7000000, 0x0038b4561715d5e5, 0xa4bd279918adecbc, 0xd2f439c85e285847, 0xa4345f1bfde8f24a, 0x62609b0b26fe6de1]),
+  fnv1_512_test_73: U512, ::std::ffi::CString::new("curds").unwrap().as_bytes_with_nul(), U512::from_words([0xb0ec738d9c6fd969, 0xd05f0b35f6c0effd, 0x4f1d45fda3000000, 0x4bf99f58ee4196af, 0xb9700e20110830fe, 0xa5396b76280e47fd, 0x022b6e81331ca1a9, 0xcf6fbbf13ee4ce9e]),
+  fnv1_512_test_74: U512, ::std::ffi::CString::new("curds ").unwrap().as_bytes_with_nul(), U512::from_words([0x82f6e10496de7834, 0xb08b21ef4650fc20, 0xf177aba423000065, 0xcb74802739e0e571, 0x7522ecf6d1f9a52f, 0x5feefb4fab2273fd, 0xe8310f1b7b5c9a84, 0xeeb4d03b44910092]),
+  fnv1_512_test_75: U512, ::std::ffi::CString::new("curds a").unwrap().as_bytes_with_nul(), U512::from_words([0xc8729f9a21fb3fa7, 0xa5e5d431bb7084f3, 0xc29b89ebd8008863, 0x9917b48e8c536b03, 0xf1cb7eb3557c5279, 0x8932b7be4b296932, 0x19bb3dd24913081b, 0xd442ff68de484595]),
+  fnv1_512_test_76: U512, ::std::ffi::CString::new("curds an").unwrap().as_bytes_with_nul(), U512::from_words([0x4fdf00ecb9bc04dd, 0x193861aa3f966599, 0x2744134463b6bd72, 0x1ec2eafe03c46248, 0xf7a6c247899280d6, 0xd2f42ff6b47bf220, 0x79dfd4bfe87fdd49, 0x65c43581d2d5c34d]),
+  fnv1_512_test_77: U512, ::std::ffi::CString::new("curds and").unwrap().as_bytes_with_nul(), U512::from_words([0xfa7eb91efb646411, 0x8a735af87be7e469, 0x1e08a664c2d7d3e7, 0x3728da570c1fafc3, 0xd06e4dd9534a9fd4, 0xa52c438bd2116983, 0x4ae60d2083517d57, 0x59e3b0f17c687bef]),
+  fnv1_512_test_78: U512, ::std::ffi::CString::new("curds and ").unwrap().as_bytes_with_nul(), U512::from_words([0x2b9c19ec56ccf98d, 0xa112346b5b0cec88, 0x2f13597cde2ceaca, 0xe7bc8aa13e747f5c, 0x43ca4e2e98fc25e9, 0x4e4e805675545ee9, 0x5a3b968ff22ef009, 0x7012138daffde259]),
+  fnv1_512_test_79: U512, ::std::ffi::CString::new("curds and w").unwrap().as_bytes_with_nul(), U512::from_words([0xc49c100735fc9859, 0x5f566ac707c0fc8a, 0xa09ce42fdc2e95dc, 0x7d9dbe0aae16a49e, 0xd40ec06ef9d6cb97, 0xeb2df3d7340b2aa7, 0xe5d6badd7ce39ca5, 0x283832d6cd2a0ba2]),
+  fnv1_512_test_80: U512, ::std::ffi::CString::new("curds and wh").unwrap().as_bytes_with_nul(), U512::from_words([0x444d84d3fd55f679, 0x9a45f44808ba95f0, 0x0902e62bcc6aca6c, 0x4e59a04f405690ce, 0x1fc3d4b0becac88c, 0x1a91b556baf626f4, 0xf2b45ec254f6e148, 0xe34c1dcce355cba6]),
+  fnv1_512_test_81: U512, ::std::ffi::CString::new("curds and whe").unwrap().as_bytes_with_nul(), U512::from_words([0xda97ee2f631ff14c, 0x760f4364fae230b9, 0xdfc5ba7aa615371c, 0xfa15ca2f33fc042c, 0x8f61f8cfa1b2b3b7, 0x9939f7347fce3231, 0x2faaf65fd6c7d8a8, 0x8afbed8497f40245]),
+  fnv1_512_test_82: U512, ::std::ffi::CString::new("curds and whey").unwrap().as_bytes_with_nul(), U512::from_words([0x160fefb000fbf868, 0x8e4a1424cd9a44f8, 0x5684ce56c26cd7d3, 0x1331e53ea6a997b4, 0x1c445e31a66ecafe, 0x4caa37573d453fe6, 0xde101a68c5c349d2, 0x378b3ca797eefe64]),
+  fnv1_512_test_83: U512, ::std::ffi::CString::new("curds and whey\n").unwrap().as_bytes_with_nul(), U512::from_words([0xe6976a113877e431, 0x0e06c0994be9f3f8, 0x9388653cedd52bce, 0xb7da26f14d3a4251, 0xdf9a3485fe71fab8, 0xb81025e317c89e53, 0x87936260f8a7e8a8, 0x6b8e448c9136e562]),
+  fnv1_512_test_84: U512, b"hi", U512::from_words([0x7317dfed6c70dfec, 0x6adfced2a5e04d7e, 0xec744e956f000000, 0x0000000017933d7a, 0xf45d70def423a316, 0xf14117df272cd0fd, 0x6b85f0f7c9bf6c51, 0x96b3160d02d2bd78]),
+  fnv1_512_test_85: U512, ::std::ffi::CString::new("hi").unwrap().as_bytes_with_nul(), U512::from_words([0x142433ed48a78bb4, 0x29a7dba8911e8824, 0xdcd81cf531000000, 0x0000001f96475fbd, 0x69323ab91bbf83bd, 0x3e36fbfd7d0c038b, 0x1075dbff4f7a2150, 0xe9f28b6ec85bdbc8]),
+  fnv1_512_test_86: U512, b"hello", U512::from_words([0x96b20c29347dfb41, 0xb5e3ebf2c34d267b, 0x6f3a1601e9000000, 0x0038b4561715d5e5, 0xa4bd279918adecbc, 0xd2f439c85e285847, 0xa4345f1bfde8f24a, 0x62609afc24b6c6db]),
+  fnv1_512_test_87: U512, ::std::ffi::CString::new("hello").unwrap().as_bytes_with_nul(), U512::from_words([0xb0ec738d9c6fd969, 0xd05f0b35f6c0effd, 0x02f833560a000000, 0x4bf99f58ee4196af, 0xb9700e20110830fe, 0xa5396b76280e47fd, 0x022b6e81331ca1a9, 0xcf6fa7d530e46f6d]),
+  fnv1_512_test_88: U512, b"\xff\x00\x00\x01", U512::from_words([0xf9fe9eefe38ca43f, 0xcf36c8fbc0d25bef, 0x5453fa14d8000000, 0x00002a5259a146c7, 0xf24cae042d99828e, 0x5baba0a28b18bf53, 0x0de9c3137ca2a369, 0x73f8d16d0728bec1]),
+  fnv1_512_test_89: U512, b"\x01\x00\x00\xff", U512::from_words([0xf9fe9eefe38ca43f, 0xcf36c8fbc0d25bef, 0x5456a0a8f2000000, 0x00002a5259a146c7, 0xf24cae042d99828e, 0x5baba0a28b18bf53, 0x0de9c3137ca2a369, 0x73f8d16e3638e34d]),
+  fnv1_512_test_90: U512, b"\xff\x00\x00\x02", U512::from_words([0xf9fe9eefe38ca43f, 0xcf36c8fbc0d25bef, 0x5453fa14d8000000, 0x00002a5259a146c7, 0xf24cae042d99828e, 0x5baba0a28b18bf53, 0x0de9c3137ca2a369, 0x73f8d16d0728bec2]),
+  fnv1_512_test_91: U512, b"\x02\x00\x00\xff", U512::from_words([0xf9fe9eefe38ca43f, 0xcf36c8fbc0d25bef, 0x54569b463f000000, 0x00002a5259a146c7, 0xf24cae042d99828e, 0x5baba0a28b18bf53, 0x0de9c3137ca2a369, 0x73f8d16e33d12494]),
+  fnv1_512_test_92: U512, b"\xff\x00\x00\x03", U512::from_words([0xf9fe9eefe38ca43f, 0xcf36c8fbc0d25bef, 0x5453fa14d8000000, 0x00002a5259a146c7, 0xf24cae042d99828e, 0x5baba0a28b18bf53, 0x0de9c3137ca2a369, 0x73f8d16d0728bec3]),
+  fnv1_512_test_93: U512, b"\x03\x00\x00\xff", U512::from_words([0xf9fe9eefe38ca43f, 0xcf36c8fbc0d25bef, 0x545695e38c000000, 0x00002a5259a146c7, 0xf24cae042d99828e, 0x5baba0a28b18bf53, 0x0de9c3137ca2a369, 0x73f8d16e316965db]),
+  fnv1_512_test_94: U512, b"\xff\x00\x00\x04", U512::from_words([0xf9fe9eefe38ca43f, 0xcf36c8fbc0d25bef, 0x5453fa14d8000000, 0x00002a5259a146c7, 0xf24cae042d99828e, 0x5baba0a28b18bf53, 0x0de9c3137ca2a369, 0x73f8d16d0728bec4]),
+  fnv1_512_test_95: U512, b"\x04\x00\x00\xff", U512::from_words([0xf9fe9eefe38ca43f, 0xcf36c8fbc0d25bef, 0x54569080d9000000, 0x00002a5259a146c7, 0xf24cae042d99828e, 0x5baba0a28b18bf53, 0x0de9c3137ca2a369, 0x73f8d16e2f01a522]),
+  fnv1_512_test_96: U512, b"\x40\x51\x4e\x44", U512::from_words([0xf9fe9eefe38ca43f, 0xcf36c8fbc0d25bef, 0x5457ff8bd5000000, 0x00002a5259a146c7, 0xf24cae042d99828e, 0x5baba0a28b18bf53, 0x0de9c3137ca2a369, 0x73f8d16ed31dee4e]),
+  fnv1_512_test_97: U512, b"\x44\x4e\x51\x40", U512::from_words([0xf9fe9eefe38ca43f, 0xcf36c8fbc0d25bef, 0x5457e8679c000000, 0x00002a5259a146c7, 0xf24cae042d99828e, 0x5baba0a28b18bf53, 0x0de9c3137ca2a369, 0x73f8d16ec86d09cc]),
+  fnv1_512_test_98: U512, b"\x40\x51\x4e\x4a", U512::from_words([0xf9fe9eefe38ca43f, 0xcf36c8fbc0d25bef, 0x5457ff8bd5000000, 0x00002a5259a146c7, 0xf24cae042d99828e, 0x5baba0a28b18bf53, 0x0de9c3137ca2a369, 0x73f8d16ed31dee40]),
+  fnv1_512_test_99: U512, b"\x4a\x4e\x51\x40", U512::from_words([0xf9fe9eefe38ca43f, 0xcf36c8fbc0d25bef, 0x5457c84c82000000, 0x00002a5259a146c7, 0xf24cae042d99828e, 0x5baba0a28b18bf53, 0x0de9c3137ca2a369, 0x73f8d16eba21c9f6]),
+  fnv1_512_test_100: U512, b"\x40\x51\x4e\x54", U512::from_words([0xf9fe9eefe38ca43f, 0xcf36c8fbc0d25bef, 0x5457ff8bd5000000, 0x00002a5259a146c7, 0xf24cae042d99828e, 0x5baba0a28b18bf53, 0x0de9c3137ca2a369, 0x73f8d16ed31dee5e]),
+  fnv1_512_test_101: U512, b"\x54\x4e\x51\x40", U512::from_words([0xf9fe9eefe38ca43f, 0xcf36c8fbc0d25bef, 0x5457923bcc000000, 0x00002a5259a146c7, 0xf24cae042d99828e, 0x5baba0a28b18bf53, 0x0de9c3137ca2a369, 0x73f8d16ea1f03efc]),
+  fnv1_512_test_102: U512, b"127.0.0.1", U512::from_words([0x4fdf00ecb9bc04dd, 0x193861aa2cbbcf01, 0xb0ba3ba1cab6bd72, 0x1ec2eafe03c46248, 0xf7a6c247899280d6, 0xd2f42ff6b47bf220, 0x79dfd4bfe87fda21, 0x48a61293c5f49c68]),
+  fnv1_512_test_103: U512, ::std::ffi::CString::new("127.0.0.1").unwrap().as_bytes_with_nul(), U512::from_words([0xfa7eb91efb646411, 0x8a735adc10eb0156, 0x5d4bda6302d7d3e7, 0x3728da570c1fafc3, 0xd06e4dd9534a9fd4, 0xa52c438bd2116983, 0x4ae60d20834d4298, 0x5682e3fe3abd8f58]),
+  fnv1_512_test_104: U512, b"127.0.0.2", U512::from_words([0x4fdf00ecb9bc04dd, 0x193861aa2cbbcf01, 0xb0ba3ba1cab6bd72, 0x1ec2eafe03c46248, 0xf7a6c247899280d6, 0xd2f42ff6b47bf220, 0x79dfd4bfe87fda21, 0x48a61293c5f49c6b]),
+  fnv1_512_test_105: U512, ::std::ffi::CString::new("127.0.0.2").unwrap().as_bytes_with_nul(), U512::from_words([0xfa7eb91efb646411, 0x8a735adc10eb0156, 0x5d4bda6305d7d3e7, 0x3728da570c1fafc3, 0xd06e4dd9534a9fd4, 0xa52c438bd2116983, 0x4ae60d20834d4298, 0x5682e3fe3abd935d]),
+  fnv1_512_test_106: U512, b"127.0.0.3", U512::from_words([0x4fdf00ecb9bc04dd, 0x193861aa2cbbcf01, 0xb0ba3ba1cab6bd72, 0x1ec2eafe03c46248, 0xf7a6c247899280d6, 0xd2f42ff6b47bf220, 0x79dfd4bfe87fda21, 0x48a61293c5f49c6a]),
+  fnv1_512_test_107: U512, ::std::ffi::CString::new("127.0.0.3").unwrap().as_bytes_with_nul(), U512::from_words([0xfa7eb91efb646411, 0x8a735adc10eb0156, 0x5d4bda6304d7d3e7, 0x3728da570c1fafc3, 0xd06e4dd9534a9fd4, 0xa52c438bd2116983, 0x4ae60d20834d4298, 0x5682e3fe3abd9206]),
+  fnv1_512_test_108: U512, b"64.81.78.68", U512::from_words([0x2b9c19ec56ccf98d, 0xa1120764c646afda, 0x67ef4d4b312ceaca, 0xe7bc8aa13e747f5c, 0x43ca4e2e98fc25e9, 0x4e4e805675545ee9, 0x5a3b968fec26e5c6, 0xeba530dc2dfe48d7]),
+  fnv1_512_test_109: U512, ::std::ffi::CString::new("64.81.78.68").unwrap().as_bytes_with_nul(), U512::from_words([0xc49c100735fc9859, 0x5f140eeb6fa142d2, 0x1dce8e07ba2e95dc, 0x7d9dbe0aae16a49e, 0xd40ec06ef9d6cb97, 0xeb2df3d7340b2aa7, 0xe5d6bad5681ddd85, 0xba5477019fb39811]),
+  fnv1_512_test_110: U512, b"64.81.78.74", U512::from_words([0x2b9c19ec56ccf98d, 0xa1120764c646afda, 0x67ef4d4b302ceaca, 0xe7bc8aa13e747f5c, 0x43ca4e2e98fc25e9, 0x4e4e805675545ee9, 0x5a3b968fec26e5c6, 0xeba530dc2dfe47ac]),
+  fnv1_512_test_111: U512, ::std::ffi::CString::new("64.81.78.74").unwrap().as_bytes_with_nul(), U512::from_words([0xc49c100735fc9859, 0x5f140eeb6fa142d2, 0x1dce8e05382e95dc, 0x7d9dbe0aae16a49e, 0xd40ec06ef9d6cb97, 0xeb2df3d7340b2aa7, 0xe5d6bad5681ddd85, 0xba5477019fb20774]),
+  fnv1_512_test_112: U512, b"64.81.78.84", U512::from_words([0x2b9c19ec56ccf98d, 0xa1120764c646afda, 0x67ef4d4b2f2ceaca, 0xe7bc8aa13e747f5c, 0x43ca4e2e98fc25e9, 0x4e4e805675545ee9, 0x5a3b968fec26e5c6, 0xeba530dc2dfe4675]),
+  fnv1_512_test_113: U512, ::std::ffi::CString::new("64.81.78.84").unwrap().as_bytes_with_nul(), U512::from_words([0xc49c100735fc9859, 0x5f140eeb6fa142d2, 0x1dce8e02aa2e95dc, 0x7d9dbe0aae16a49e, 0xd40ec06ef9d6cb97, 0xeb2df3d7340b2aa7, 0xe5d6bad5681ddd85, 0xba5477019fb066c3]),
+  fnv1_512_test_114: U512, b"feedface", U512::from_words([0xc8729f9a21fb3fa7, 0xa5e5d431bb701020, 0xc6d06c1545008863, 0x9917b48e8c536b03, 0xf1cb7eb3557c5279, 0x8932b7be4b296932, 0x19bb3dd24913081b, 0xbe0cdcb23522db3a]),
+  fnv1_512_test_115: U512, ::std::ffi::CString::new("feedface").unwrap().as_bytes_with_nul(), U512::from_words([0x4fdf00ecb9bc04dd, 0x193861aa3ee3a8c7, 0x1375f35aadb6bd72, 0x1ec2eafe03c46248, 0xf7a6c247899280d6, 0xd2f42ff6b47bf220, 0x79dfd4bfe87fdd2b, 0xa33bb2c531b3bab6]),
+  fnv1_512_test_116: U512, b"feedfacedaffdeed", U512::from_words([0xe6976a11386dc58a, 0xaea3961fb32ac761, 0x6c3f6bb911d52bce, 0xb7da26f14d3a4251, 0xdf9a3485fe71fab8, 0xb81025e317c89e52, 0xa0e53b076957d2ae, 0x58d0729fffde49e5]),
+  fnv1_512_test_117: U512, ::std::ffi::CString::new("feedfacedaffdeed").unwrap().as_bytes_with_nul(), U512::from_words([0xd7f6e5b0edb4920b, 0x04977c4bbca9f3fa, 0xa8f93340c99db1f8, 0x554a2d4e790edbb2, 0x979c5f87eab6ed7e, 0x9da2c344ddcc20b5, 0x932216ee24ab4798, 0xff49945fd2d501d3]),
+  fnv1_512_test_118: U512, b"feedfacedeadbeef", U512::from_words([0xe6976a11386dc58a, 0xaea3961fb32ac705, 0x6d5fd5480ad52bce, 0xb7da26f14d3a4251, 0xdf9a3485fe71fab8, 0xb81025e317c89e52, 0xa0e53b076957d2ae, 0x58bc6c5e0cb3cf3e]),
+  fnv1_512_test_119: U512, ::std::ffi::CString::new("feedfacedeadbeef").unwrap().as_bytes_with_nul(), U512::from_words([0xd7f6e5b0edb4920b, 0x04977c4bbca964b1, 0xe9737755c19db1f8, 0x554a2d4e790edbb2, 0x979c5f87eab6ed7e, 0x9da2c344ddcc20b5, 0x932216ee24ab4798, 0xe475320304eaac12]),
+  fnv1_512_test_120: U512, b"line 1\nline 2\nline 3", U512::from_words([0xf26bcb22a05cbb07, 0x9dc705a9b5167524, 0x24df4049e81988b4, 0xd6e4efc77313664f, 0x3729c4451f1a07bd, 0x42455ef1586cb075, 0xc11ca244f150147e, 0x2cc297792603f397]),
+  fnv1_512_test_121: U512, b"chongo <Landon Curt Noll> /\\../\\", U512::from_words([0x7842c70b5b5570a0, 0xb3a16d5cdbbaf96b, 0xd2c2edece798aec6, 0x1d0f993918df6a0e, 0x6096f9cfc06b31ec, 0x684ff4ef93b20af9, 0xcc83e2cb43efd4f8, 0x0fae951dd1eee464]),
+  fnv1_512_test_122: U512, ::std::ffi::CString::new("chongo <Landon Curt Noll> /\\../\\").unwrap().as_bytes_with_nul(), U512::from_words([0x110c624259466b33, 0x788d773f5f93de0c, 0x80feb74eb1922b70, 0xefe64b8053571943, 0x6a48b55acf9fe3bf, 0xc3212cfee38cb4b1, 0x04b4de580656585d, 0x02e9caf4471401fc]),
+  fnv1_512_test_123: U512, b"chongo (Landon Curt Noll) /\\../\\", U512::from_words([0xb803fb4f580e5a5c, 0xea29594cd8bbb968, 0xeafc8e978498aec6, 0x1d0f993918df6a0e, 0x6096f9cfc06b6654, 0xcde07c018e401b00, 0xa931c0561fa35096, 0xa5545b32cc603f8d]),
+  fnv1_512_test_124: U512, ::std::ffi::CString::new("chongo (Landon Curt Noll) /\\../\\").unwrap().as_bytes_with_nul(), U512::from_words([0x8ee3f769fbe4443e, 0x13864946fa2abfee, 0x0b2f6d4235922b70, 0xefe64b8053571943, 0x6a48b55acfe61b9f, 0xd7c6261597e42de2, 0xb1a8b36463d0f9d7, 0x8406310fd4f525eb]),
+  fnv1_512_test_125: U512, b"http://antwrp.gsfc.nasa.gov/apod/astropix.html", U512::from_words([0x7bae22ed248861e9, 0xc0278bfa5d532d64, 0xbbcd0bf846d418fe, 0x90182c3acd024d79, 0xf5f5cbb7d7e46822, 0xc1fb8d2e6b081aa5, 0x24901adbc17e577e, 0xd69bb1bea7578aa8]),
+  fnv1_512_test_126: U512, b"http://en.wikipedia.org/wiki/Fowler_Noll_Vo_hash", U512::from_words([0x410db7de66e4912a, 0xa874f893dbf853ec, 0xde7cf55adeeaa5e1, 0x61d2da6272fb0b9f, 0x7ee9a0b55217ebd0, 0x83d5bcb4bdf49e17, 0x0cdf56a64f5b5a64, 0x8274ac09b22921e9]),
+  fnv1_512_test_127: U512, b"http://epod.usra.edu/", U512::from_words([0x135e658df9c99bd3, 0xb4849dabc3343fa4, 0x4623283148362a4b, 0xecbd443b2efe1422, 0xe8f5f89cabe05e93, 0xcaf23af96fc407f0, 0x298db71621c6c05e, 0x93a00f100933f6eb]),
+  fnv1_512_test_128: U512, b"http://exoplanet.eu/", U512::from_words([0xf2a70fd923cf2c67, 0xc85694e8908a5dc8, 0x6c1148dd681988b4, 0xd6e4efc77313664f, 0x3729c4451f1a07bd, 0x42455ef588299eff, 0x351cc6523ad08c4b, 0xc19b37d21d1f1f7a]),
+  fnv1_512_test_129: U512, b"http://hvo.wr.usgs.gov/cam3/", U512::from_words([0xc5b3d958d9ddb0f5, 0xd165da728a40d8cd, 0x1c7a7f6ec0b3d532, 0x5884182a0866985f, 0x20b66c605bc127fc, 0x0c789e531d3f99b7, 0x4da28801ff84b417, 0x2112a20533be6bcb]),
+  fnv1_512_test_130: U512, b"http://hvo.wr.usgs.gov/cams/HMcam/", U512::from_words([0x1bf39c8056058431, 0x8e382147bc371541, 0x9d5f6753dcd83451, 0x6d8f28efa8959800, 0x73410aad1d23a735, 0x921b15315eadd879, 0x9fd93dafc8b7b908, 0xad45a62cd27aaabe]),
+  fnv1_512_test_131: U512, b"http://hvo.wr.usgs.gov/kilauea/update/deformation.html", U512::from_words([0xc4452a01aee25494, 0x2f1c362c94810da9, 0x172f676375ae7cff, 0xb3e58fb6ea0741b0, 0x6982b307b0dee69c, 0x2bca82ff73f18784, 0xca02cd148b69c758, 0xd8f6629b4e614ce8]),
+  fnv1_512_test_132: U512, b"http://hvo.wr.usgs.gov/kilauea/update/images.html", U512::from_words([0x5daf0bc4c08f739d, 0x26866861ab429651, 0x22d07b2bcdfe8400, 0xc5a55c84209423f4, 0xaa69087a057d6390, 0xa3f8e106c38ef10a, 0xd15174376fb9ba78, 0x88323b42956f64be]),
+  fnv1_512_test_133: U512, b"http://hvo.wr.usgs.gov/kilauea/update/maps.html", U512::from_words([0x7fa8dde59dad55b1, 0x24c557ba137ee15a, 0xa0cfb62a8a2d8950, 0xb9e198bea8ba9833, 0x4639f1455e5c58c8, 0x4892fb61e11821f5, 0xcc07f311b9445264, 0x32903b7c50eed853]),
+  fnv1_512_test_134: U512, b"http://hvo.wr.usgs.gov/volcanowatch/current_issue.html", U512::from_words([0x8eddaecd87d4df51, 0xf4fd57650de74088, 0x305da639f7ae7cff, 0xb3e58fb6ea0741b3, 0x109c1fd86040b20e, 0x097f16a23b10aac6, 0x708add0419396d19, 0x44e9526c2ae6689e]),
+  fnv1_512_test_135: U512, b"http://neo.jpl.nasa.gov/risk/", U512::from_words([0x7cb305a974ca164b, 0xd0404e11e3ac983e, 0x1d8a294e43f2a674, 0x98fc605141762774, 0xd46b351aefcc92b4, 0xb5990c3c554cc1c5, 0x5413b4a71198e54b, 0x08a2bdd9340b90d3]),
+  fnv1_512_test_136: U512, b"http://norvig.com/21-days.html", U512::from_words([0xed7e6560da1253e0, 0x8a77e4e2ee8e7e95, 0x9b7a91838b1d0638, 0xfa250cdeb54edd88, 0x9ba427174b18901f, 0x5004b9e03b7a27b3, 0x232d040f6bd7432c, 0x476e77accba98828]),
+  fnv1_512_test_137: U512, b"http://primes.utm.edu/curios/home.php", U512::from_words([0xfa116fb00c9b4908, 0x030706db7dfeac38, 0xee0a7669d29d763e, 0x89fd289f86fa5f5e, 0x248016d7ec3b08e9, 0xa62e4015fcaedd1f, 0x78878d6f41023583, 0x2c61670ca7bef185]),
+  fnv1_512_test_138: U512, b"http://slashdot.org/", U512::from_words([0xf2a70fd923cf2c67, 0xe2360f63ba1ed5ce, 0x21ca23c2e61988b4, 0xd6e4efc77313664f, 0x3729c4451f1a07bd, 0x42455ef588299eff, 0x351cc9360e192364, 0x7234b3abf2cb8f8a]),
+  fnv1_512_test_139: U512, b"http://tux.wr.usgs.gov/Maps/155.25-19.5.html", U512::from_words([0x8f0af84586a6fc2a, 0x7db0d60e1db82085, 0x00e11da375db0f7a, 0x541563d30a1387e6, 0x9537a0ebae4c448c, 0x0463427d8f2a678b, 0xe7dc119ffeaf7119, 0x5678f646bc5845db]),
+  fnv1_512_test_140: U512, b"http://volcano.wr.usgs.gov/kilaueastatus.php", U512::from_words([0xa292b22e55582093, 0x6f05d1d2ad2ec6b5, 0xee320765c2db0f7a, 0x54166af6c0ab1cdc, 0x6f77acd22b176065, 0xb448cb24fe94071c, 0x88cfbe16a7066cd3, 0x3174b78a61208bc7]),
+  fnv1_512_test_141: U512, b"http://www.avo.alaska.edu/activity/Redoubt.php", U512::from_words([0xeada6a09dde91427, 0x8c762d5b9e53aa38, 0x6e639727cbd4190c, 0x86bb1a5d493eef8f, 0x641c9d9af9aeb490, 0x04472c1db0118fa0, 0xaccb0debdaee38e5, 0xf24dae02a58dd697]),
+  fnv1_512_test_142: U512, b"http://www.dilbert.com/fast/", U512::from_words([0x2546546be9b2e1b2, 0xc1bb1019b6bb19d2, 0x188dcb0875b3d532, 0x5884182a0866985f, 0x20b66c605bc127fc, 0x0c78a4b4fb5b07ce, 0xba815cccaa0bdb66, 0xc8baa342453e1285]),
+  fnv1_512_test_143: U512, b"http://www.fourmilab.ch/gravitation/orbits/", U512::from_words([0x825da5219037d658, 0x1fe2a5c24fbc5420, 0x7fdd766163217a72, 0x8c8f5d1149d767da, 0xe1dfafb331977b41, 0x0748e3496a392282, 0x18c5ef94dce5b8c4, 0xed044267e6738d6a]),
+  fnv1_512_test_144: U512, b"http://www.fpoa.net/", U512::from_words([0xf2a70fd923cf2c67, 0xdd4668bf77a194b4, 0xc06c2f6bd11988b4, 0xd6e4efc77313664f, 0x3729c4451f1a07bd, 0x42455ef588299eff, 0x351cc8a84fc5acbe, 0xf8735ff59f42574e]),
+  fnv1_512_test_145: U512, b"http://www.ioccc.org/index.html", U512::from_words([0x29d9b567b304bdf6, 0xc02685ad08270755, 0x55638bd7c3e35657, 0x27a43e64ecaad208, 0x88f060359de91a09, 0x27208d5ed919e4a6, 0xb11379f912153db3, 0xe3150231409456e3]),
+  fnv1_512_test_146: U512, b"http://www.isthe.com/cgi-bin/number.cgi", U512::from_words([0x42bcf968a1af1aaa, 0x09ea903d8f71057d, 0xeeb2f50e70321ad5, 0x103a095e5ccdcf48, 0x2fb40912d90a07d3, 0xc7d49e13f011ca60, 0x6ec463102590a242, 0xf4bbee5e5bac78bd]),
+  fnv1_512_test_147: U512, b"http://www.isthe.com/chongo/bio.html", U512::from_words([0x8cf125f25c5ecfaa, 0xd99443bebd6b59d0, 0x1bea8c80b143a18e, 0xbc7be302b443e6e6, 0xe5fbb195307bd8df, 0x11416392b1244f63, 0x63be801a6cc3926b, 0xb453c5c4f7cd64b3]),
+  fnv1_512_test_148: U512, b"http://www.isthe.com/chongo/index.html", U512::from_words([0x4a4f56a0a5536f28, 0xe1e56f021826825c, 0x575e39127bf96dca, 0xe2316dbdd975c722, 0x8af6136e83fdca49, 0x039169b26fe82b22, 0x0e95effa424c8e25, 0x969cf8b7e523cb1d]),
+  fnv1_512_test_149: U512, b"http://www.isthe.com/chongo/src/calc/lucas-calc", U512::from_words([0xfe696ee3c949986f, 0x11b02d02892331c8, 0x48a4ba2bb72d8fc8, 0x84b04fb228096c71, 0xe3a4a76c2c6bc0dd, 0xb79c4f90533a179f, 0x6276538736f6c1db, 0x45e6d26f48e755db]),
+  fnv1_512_test_150: U512, b"http://www.isthe.com/chongo/tech/astro/venus2004.html", U512::from_words([0xc54cca7fc40981d5, 0xb791b054ebdf6052, 0x4c18d6540ecd8711, 0x63a1647dc4834603, 0x40c2f5488b48e97c, 0xa9287443c44537f5, 0xd1ea72d2e12c7d81, 0x7f93c641b7f56109]),
+  fnv1_512_test_151: U512, b"http://www.isthe.com/chongo/tech/astro/vita.html", U512::from_words([0x6f3a90bcb5826674, 0xbe72a5d5846a81ae, 0x68fb654dbd0ba5a9, 0xc83ac7b3a4a04c98, 0x019c51ef845f6911, 0x026e9a590a176b17, 0xcf2496db7df7b8fd, 0x3c873c40c907ccde]),
+  fnv1_512_test_152: U512, b"http://www.isthe.com/chongo/tech/comp/c/expert.html", U512::from_words([0xd4afd223363819f5, 0xd42aa1a55724bf56, 0x1f7f5708b7b4a3b0, 0x23dce7be42c685e4, 0xe9e36e664db6ac3c, 0xfdbc7b7b8b99834a, 0x0b2056f6ca6747ac, 0xd764986458994044]),
+  fnv1_512_test_153: U512, b"http://www.isthe.com/chongo/tech/comp/calc/index.html", U512::from_words([0xc57085f8b623211f, 0x54942ca249c2f9ed, 0xe891211e43cd8711, 0x63a1647dc4834603, 0x40c2f5488b48e97c, 0xa928744650591ee3, 0x494954cad1d9ace2, 0xf853297c79c23bbc]),
+  fnv1_512_test_154: U512, b"http://www.isthe.com/chongo/tech/comp/fnv/index.html", U512::from_words([0x731e26b0ef35e8be, 0x6ac832b545ad62db, 0x9fcce2d795075100, 0x0cfa7feb77fd65b5, 0x5fb8eb121fc0c5b7, 0xf78972880aaee834, 0xe8555be0a2732d8c, 0x235ba9cbdbc9b465]),
+  fnv1_512_test_155: U512, b"http://www.isthe.com/chongo/tech/math/number/howhigh.html", U512::from_words([0xc292fefd551da78a, 0x39aaff065054256c, 0xe340d69fed9fcdbd, 0xeeda249f025479db, 0xec4489421cb13070, 0x9f08312458a7eccc, 0xae7885dd02332d33, 0x55ccb288989a7c8d]),
+  fnv1_512_test_156: U512, b"http://www.isthe.com/chongo/tech/math/number/number.html", U512::from_words([0x0463511154998ed2, 0x53f6f9c1dc715ebc, 0x9851e5f04c8cc7e0, 0x75dfcbdca3756c93, 0xac59c332f1e723f7, 0x5f4035e18160c4fb, 0x5f4bbbb9bd1924ae, 0x1eb2ae0293176016]),
+  fnv1_512_test_157: U512, b"http://www.isthe.com/chongo/tech/math/prime/mersenne.html", U512::from_words([0xc293b406a71d5214, 0xc2cb43dda5237b4f, 0x1836e015e99fcdbd, 0xeeda249f025479db, 0xec4489421cb13070, 0x9f083124661f9656, 0x0615b20c2b305a58, 0x1f06349306c2b07e]),
+  fnv1_512_test_158: U512, b"http://www.isthe.com/chongo/tech/math/prime/mersenne.html#largest", U512::from_words([0xe29ec164bb0b00b4, 0xd45ef641cfc51ed8, 0x88d8d2133d181779, 0xb3a278a6520e84f2, 0xf6e1b86bfd30668c, 0x7552133713520fc0, 0x9c8530e2c4725a94, 0x39edf2816b2b8423]),
+  fnv1_512_test_159: U512, b"http://www.lavarnd.org/cgi-bin/corpspeak.cgi", U512::from_words([0x1c3c2809c55862bd, 0xa33f4a27cb08b003, 0x7ee5439895db0f7a, 0x5415b229ef9b14c9, 0x9fe93f02ee5ab37b, 0xe8fe3d48a020a1c3, 0x69a7cd71438141a0, 0x1edc5e288f7ca7bc]),
+  fnv1_512_test_160: U512, b"http://www.lavarnd.org/cgi-bin/haiku.cgi", U512::from_words([0xc566808b0ebb2577, 0xa42d492007154f6b, 0x46b08d53a421f378, 0xbdc28d6e57c0b9b7, 0xea3851ea5cff3228, 0xf3a5a10eac9e277a, 0x7d19f1519fd80205, 0x62be7eb728fe217c]),
+  fnv1_512_test_161: U512, b"http://www.lavarnd.org/cgi-bin/rand-none.cgi", U512::from_words([0x1c3c2809c55862bd, 0xb760caa2b902b3d9, 0x31133fcba6db0f7a, 0x5415b229ef9b14c9, 0x9fe93f02ee5ab37b, 0xe8fe3d48a020a1c3, 0x69a7cfb140dd1b3b, 0x87efbd1c407e77ce]),
+  fnv1_512_test_162: U512, b"http://www.lavarnd.org/cgi-bin/randdist.cgi", U512::from_words([0x7568e115c4652d91, 0x79935289b265d06e, 0x78b121fd3b217a72, 0x8c8f5d1149d7670e, 0xc4c1fc73b194ca0a, 0x105a4dccb693dfa1, 0xc854d3d2fd3424a4, 0xe6adb7941ce23961]),
+  fnv1_512_test_163: U512, b"http://www.lavarnd.org/index.html", U512::from_words([0x766f28142f1c060c, 0x1fb58b31d55c6726, 0x788a3bdb10922b70, 0xefe64b805356445c, 0x8ca6a07f62b62856, 0x86ee922f3cf33650, 0xb9ffbfe1d9defbe5, 0xe0d8f84f354e0b4e]),
+  fnv1_512_test_164: U512, b"http://www.lavarnd.org/what/nist-test.html", U512::from_words([0xea17766310a47879, 0xc4c602b422b71af0, 0x1c5410c211c4639f, 0x4ec6f2d64125f21e, 0xa4c59a7c3b7ad60b, 0xf89588cf4d50a18e, 0x4fc28278d6ebd3f1, 0xcf38a419754da51c]),
+  fnv1_512_test_165: U512, b"http://www.macosxhints.com/", U512::from_words([0x58c2a0a22c5a2c81, 0xfa9a07195954644f, 0xedecd589da7d2a6a, 0xe02a2e583148b213, 0xaebe1b2f4d246361, 0xc2d5cf38811b2258, 0xa487042719ebd3cc, 0x639538af531624a1]),
+  fnv1_512_test_166: U512, b"http://www.mellis.com/", U512::from_words([0xece9d5419d498244, 0x9874523fd118ddaf, 0x61751bb4a292abba, 0x31966b4bf66cfac6, 0x219019ea499eb804, 0xea8d0434bfa6a2c7, 0xb1136e5c9b588c9d, 0xc8c7841370003ca4]),
+  fnv1_512_test_167: U512, b"http://www.nature.nps.gov/air/webcams/parks/havoso2alert/havoalert.cfm", U512::from_words([0xc83a124ba62a6a42, 0x8b66e91eea7c2507, 0x4727cece011d41ff, 0xfd8548b57bdfe64d, 0xc98bdfb29415b64f, 0xaedf54f5b56aa86e, 0x38f50607d00c342f, 0x1b54075deaf007ea]),
+  fnv1_512_test_168: U512, b"http://www.nature.nps.gov/air/webcams/parks/havoso2alert/timelines_24.cfm", U512::from_words([0x2575e8edaa08725f, 0xe9d5940fefdef548, 0x307ff9998b555757, 0x6d3328a3accc17f8, 0xa7b3072c3e781e17, 0x3a9ab5ba8509c7ba, 0xe8240dd0e26cb306, 0x351a9886276680bd]),
+  fnv1_512_test_169: U512, b"http://www.paulnoll.com/", U512::from_words([0xcb5ef75fffb5d7ef, 0x281308a04062e52e, 0x1cdfd91860fa1b5e, 0xc893f8dbf95b4866, 0x6115bfb346d70b53, 0xb069f580533e3d99, 0x22e18f7dabd81218, 0x9e0b9b25cfbe0563]),
+  fnv1_512_test_170: U512, b"http://www.pepysdiary.com/", U512::from_words([0x556405eb3ba189cc, 0xa6a30d823bc531f3, 0x0bda5bad88bc7250, 0xeb025caee783920e, 0xfc08048eb11c77b4, 0xe73122efbeb27547, 0x2e07fea08ef2daef, 0x7244e1f8eb517862]),
+  fnv1_512_test_171: U512, b"http://www.sciencenews.org/index/home/activity/view", U512::from_words([0x8748a9e0b5373b20, 0xb7b3ec0ba9790594, 0xd34ba0c221b4c223, 0x445f65b2b3c3e174, 0x5d96144162e59882, 0xe10628ec36a702f0, 0xdad6513461506188, 0xefe4407f9f907946]),
+  fnv1_512_test_172: U512, b"http://www.skyandtelescope.com/", U512::from_words([0x2b17cabdf86d21c3, 0xe197665f402002af, 0xf79b564735e35657, 0x27a43e64ecaad208, 0x88f060359de91a09, 0x27208d7549490de5, 0x73189002cb9e1006, 0x3693b7ba0b73ad50]),
+  fnv1_512_test_173: U512, b"http://www.sput.nl/~rob/sirius.html", U512::from_words([0xb321a88bb4e45fc3, 0x97b86c463cdda377, 0x0ccef19faeae1919, 0xcacfd91ae06ea89a, 0x6c254e23837ffd2e, 0xac5b8e6fa77a2024, 0x252b2628482a84d1, 0xf0559d12989b8b29]),
+  fnv1_512_test_174: U512, b"http://www.systemexperts.com/", U512::from_words([0xa63677eacaca5e36, 0x713761092c387e47, 0xe619ab0de4f2a674, 0x98fc605141762774, 0xd46b351aefcc92b4, 0xb5a4ae7cc6fd826b, 0x55ae8e0bae3b8bd2, 0x5ca25430a44f6828]),
+  fnv1_512_test_175: U512, b"http://www.tq-international.com/phpBB3/index.php", U512::from_words([0x7d01821fc972acbf, 0x3b26189c06ed5c40, 0xeaf0a3957c0ba5a9, 0xc84a1124c351c777, 0x4e378c4cf1f6e8ea, 0x0247199dac19e920, 0x554078458280ebd4, 0x4a900ab9df31b2e8]),
+  fnv1_512_test_176: U512, b"http://www.travelquesttours.com/index.htm", U512::from_words([0xc6f31032d6a30980, 0x324b9c3d86c92d0c, 0xe78824a6e37d36c6, 0x3fab7ed79338d56a, 0xd336a5d474ef1245, 0x29d7bfe44e2e5b12, 0xf65ad1539a663f84, 0xfd996d9e99966b42]),
+  fnv1_512_test_177: U512, b"http://www.wunderground.com/global/stations/89606.html", U512::from_words([0x3003deed5cc30c3d, 0x183b006527390732, 0x5ea8f660bed2775e, 0x72fc066279b934ef, 0xd9504265d13d973a, 0x3de79fa47d9ddd19, 0x2eed86eb50ddce1c, 0x0a96ffe9d0b6a75a]),
+  fnv1_512_test_178: U512, &repeat(b"21701", 10), U512::from_words([0x3c1b8cf2d18fec4b, 0x3c3d6b40030c79f9, 0x9ed9de0a50403be1, 0x9b1717700c171032, 0xa6b8fa5d2e5db540, 0x08cd1b9c19281fe8, 0x734a71f27f15f275, 0x8586e9dcc9d77eb7]),
+  fnv1_512_test_179: U512, &repeat(b"M21701", 10), U512::from_words([0x66afa9fa7d4e59ff, 0xc6687b575e4ca2dd, 0x4235477e498a9826, 0x6e0050b90f1a796f, 0x53a7c429fb732ffd, 0x0c70a3d98448edad, 0xb6c8adc8dfc033a9, 0xdb8b61ceecbbef51]),
+  fnv1_512_test_180: U512, &repeat(b"2^21701-1", 10), U512::from_words([0xe9dc06e28fd932f9, 0x7393a31241e44639, 0x942a04b6e6491aad, 0xddbeb8b75c940469, 0xfe776c80555e4ef5, 0x703b1b7b7b8d54bc, 0x214b500620e8f1ea, 0x061606dcdf4fe7bf]),
+  fnv1_512_test_181: U512, &repeat(b"\x54\xc5", 10), U512::from_words([0xf3bb57db5b323a41, 0xd58c9ae4d0def76f, 0xeaacaaefa61988b4, 0xd6e4efc77313664f, 0x3729c4451f1a07bd, 0x42455f08f89c435f, 0x956d39ec74d7f590, 0x14718b9ddfd2ed81]),
+  fnv1_512_test_182: U512, &repeat(b"\xc5\x54", 10), U512::from_words([0xed67e230a4850689, 0xd142a8e460788bf1, 0x37b8a9e56a1988b4, 0xd6e4efc77313664f, 0x3729c4451f1a07bd, 0x42455e96c990b908, 0xac2d2a8748a4334c, 0xcde6be096452aa69]),
+  fnv1_512_test_183: U512, &repeat(b"23209", 10), U512::from_words([0x13d2824c0f14c216, 0x21360a8c5b818122, 0x9d93502c67e22bfa, 0xbc39b3f11b2c6f81, 0xe564a3ec4d99ac59, 0x51e0b613e1eb62fe, 0x424338d2f0f68e9a, 0xd27093a23883f175]),
+  fnv1_512_test_184: U512, &repeat(b"M23209", 10), U512::from_words([0xa09e456c71a72111, 0x512489b5b30b0c6e, 0x9ac7009d238128af, 0x1a517ebe6c307fd2, 0x950cb1dadfe5aad1, 0x4b73028276e11753, 0xd601fd4fafb87fe6, 0x10d7533e8f9e37ad]),
+  fnv1_512_test_185: U512, &repeat(b"2^23209-1", 10), U512::from_words([0x4d63c92ba9d4dfe3, 0x2f628d4116602ddf, 0xe8ce8a405b6b3aee, 0xc863baca2d9f42e2, 0xc8ab30bbd12944e4, 0x12920e8506d30ebe, 0x802ce73421b1cab1, 0x6e240190c5fccd79]),
+  fnv1_512_test_186: U512, &repeat(b"\x5a\xa9", 10), U512::from_words([0xf36962e95bf8404d, 0x5efb3effc4122006, 0x704a6d74fe1988b4, 0xd6e4efc77313664f, 0x3729c4451f1a07bd, 0x42455f0335562643, 0xde00a57c3d56db1f, 0x96cc42c14632452d]),
+  fnv1_512_test_187: U512, &repeat(b"\xa9\x5a", 10), U512::from_words([0xe7ca880f93470415, 0x5584c0a118a40107, 0x6b66edba661988b4, 0xd6e4efc77313664f, 0x3729c4451f1a07bd, 0x42455e316d51d6a8, 0xc64b49ca0c9f44db, 0x9bcec41e765de465]),
+  fnv1_512_test_188: U512, &repeat(b"391581216093", 10), U512::from_words([0xd367da4c982ef312, 0xa3552e960bc0fe78, 0x206ac78699ab1ae6, 0x361e38986e198aed, 0xe30270ad6904861e, 0xf357c1c7034e9b8c, 0xfa3711b9cd785321, 0x47481897248a41f5]),
+  fnv1_512_test_189: U512, &repeat(b"391581*2^216093-1", 10), U512::from_words([0x53eeccefa1e306ce, 0xe36e6451e3d820d2, 0x3f8514584fba0e09, 0x7914dd7c3aad87e6, 0x096987b2f30c2aba, 0x525814890b4f4018, 0xa37f38a6cc57700c, 0xbba0eaa9b552f8c5]),
+  fnv1_512_test_190: U512, &repeat(b"\x05\xf9\x9d\x03\x4c\x81", 10), U512::from_words([0x15bb3b598ee1595c, 0x67432314fada9cfe, 0x0ed7ca7fa5223217, 0xe2b8b61f8a55ae45, 0x1cecc924012159c5, 0x9fb084702ccb29d4, 0x2650ccd174b47fca, 0x62e0e6596f0761b1]),
+  fnv1_512_test_191: U512, &repeat(b"FEDCBA9876543210", 10), U512::from_words([0x37991a71a98501c6, 0xcf40604260e68c08, 0x201d9a8c76a5afc0, 0xb4272f03a7663a65, 0x1f3d58388c2bba44, 0x4856f6d1e5413b24, 0x1f94bf1121c7a2b0, 0xa1701a4fccb87bb9]),
+  fnv1_512_test_192: U512, &repeat(b"\xfe\xdc\xba\x98\x76\x54\x32\x10", 10), U512::from_words([0xcd3008cb534aef34, 0x19a19e2981d3b193, 0xeba5a10e34b54ced, 0x51932efa062eba3e, 0xb5f617d4209ac0d6, 0xee00e2263c5acdfa, 0x519b6e7d6b8ee8c9, 0x352561204ced6e19]),
+  fnv1_512_test_193: U512, &repeat(b"EFCDAB8967452301", 10), U512::from_words([0x1545aee5530576ac, 0x008ae0b702b5a95d, 0xd82555a70cef28ff, 0xa56bb2ef10fdd206, 0x6703352199fdf83c, 0x585ff7c131934c36, 0x08f632dba7c9920c, 0xede942a98b4dbd29]),
+  fnv1_512_test_194: U512, &repeat(b"\xef\xcd\xab\x89\x67\x45\x23\x01", 10), U512::from_words([0xc32d4201815b7023, 0x45ddc910a2636aa7, 0x974dec303e77dab6, 0x97951a65d77c8a4d, 0x2cf18b2d3465315a, 0x35eb38f6b6128bd6, 0x2146829a991ffcac, 0x097402bc400db319]),
+  fnv1_512_test_195: U512, &repeat(b"0123456789ABCDEF", 10), U512::from_words([0x482f5787f13f9165, 0xf5e093d20f45b471, 0x61a35ed4166377c1, 0xf11c05b9e9661930, 0xbd0a460f6d0fff66, 0x387ff7d57ac76979, 0x135b4c4d5dbe7515, 0x37dcc6bf6ec0a1b9]),
+  fnv1_512_test_196: U512, &repeat(b"\x01\x23\x45\x67\x89\xab\xcd\xef", 10), U512::from_words([0x355d3ef878f2634b, 0xf20b4088dd2bd33c, 0xe9a5cc632104f59a, 0xd76bee1705f54838, 0xed3d166dd31c0c40, 0xbedae472f6dbb9ea, 0x33de21305f62a4c4, 0x0d5313cdf0f31799]),
+  fnv1_512_test_197: U512, &repeat(b"1032547698BADCFE", 10), U512::from_words([0x1974840bee251a3d, 0x92847d1df9e7c56f, 0xf4bf731a500f07e7, 0x1143e600c336df5a, 0xcf12fc1037407bad, 0x6eff637ad83ad932, 0x1a73e718d9f01eac, 0x9e37a48eb001fe39]),
+  fnv1_512_test_198: U512, &repeat(b"\x10\x32\x54\x76\x98\xba\xdc\xfe", 10), U512::from_words([0xe4cc227d9d73e359, 0x073267c886ef3225, 0x04cfd1e41f37fdaf, 0x877d600e26299f39, 0xbcd755b3eaa94457, 0x9ee7a6f169879110, 0x59c2fcd975ce77a1, 0xa90d9e75e15f7819]),
+  fnv1_512_test_199: U512, &repeat(b"\x00", 500), U512::from_words([0xc19b47b4943e2edb, 0xd6658fba79d71e19, 0x052c9e8de22f7f77, 0xcaf0e8bd9894c081, 0x46507992c0023f2d, 0x39689f79d0dfe680, 0xfab06b976086472e, 0x4a21dd5f96c67e79]),
+  fnv1_512_test_200: U512, &repeat(b"\x07", 500), U512::from_words([0x7cb8921ba4b399f2, 0xeb816e6bb6c7d59d, 0x96aa6c42cae7e6ae, 0xfc35ceddb1aeb3ae, 0xb90c47380200ded4, 0xa390252353cf10a8, 0x509a91da1a83505c, 0xf9c9d71b4ca8e165]),
+  fnv1_512_test_201: U512, &repeat(b"~", 500), U512::from_words([0x8fef88d9a06dce86, 0x3c85f4f85437983a, 0xa13f7e6b30b8efcc, 0xcf30dba9575753bb, 0x652606c2567d4930, 0x524b715edf5167a6, 0x3e9473efe724aee2, 0x9b7d17a213684579]),
+  fnv1_512_test_202: U512, &repeat(b"\x7f", 500), U512::from_words([0x8157d1cab1120dfc, 0xee4cfaa4b0f6c3cc, 0x008671686a0c24a4, 0xd68ff0a018ba90cd, 0x1249290304846649, 0x347613a64d9ad7de, 0x7c218782cd3fa310, 0x76ebbf6e8e474755]),
+  fnv1_1024_test_0: U1024, b"", U1024::from_words([0x0000000000000000, 0x005f7a76758ecc4d, 0x32e56d5a591028b7, 0x4b29fc4223fdada1, 0x6c3bf34eda3674da, 0x9a21d90000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000000000004c6d7, 0xeb6e73802734510a, 0x555f256cc005ae55, 0x6bde8cc9c6a93b21, 0xaff4b16c71ee90b3]),
+  fnv1_1024_test_1: U1024, b"a", U1024::from_words([0x0000000000000000, 0x98d7c19fbce653df, 0x221b9f717d3490ff, 0x95ca87fdaef30d1b, 0x823372f85b24a372, 0xf50e380000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000007685cd8, 0x1a491dbccc21ad06, 0x648d09a5c8cf5a78, 0x482054e91470b33d, 0xde77252caef665f6]),
+  fnv1_1024_test_2: U1024, b"b", U1024::from_words([0x0000000000000000, 0x98d7c19fbce653df, 0x221b9f717d3490ff, 0x95ca87fdaef30d1b, 0x823372f85b24a372, 0xf50e380000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000007685cd8, 0x1a491dbccc21ad06, 0x648d09a5c8cf5a78, 0x482054e91470b33d, 0xde77252caef665f5]),
+  fnv1_1024_test_3: U1024, b"c", U1024::from_words([0x0000000000000000, 0x98d7c19fbce653df, 0x221b9f717d3490ff, 0x95ca87fdaef30d1b, 0x823372f85b24a372, 0xf50e380000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000007685cd8, 0x1a491dbccc21ad06, 0x648d09a5c8cf5a78, 0x482054e91470b33d, 0xde77252caef665f4]),
+  fnv1_1024_test_4: U1024, b"d", U1024::from_words([0x0000000000000000, 0x98d7c19fbce653df, 0x221b9f717d3490ff, 0x95ca87fdaef30d1b, 0x823372f85b24a372, 0xf50e380000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000007685cd8, 0x1a491dbccc21ad06, 0x648d09a5c8cf5a78, 0x482054e91470b33d, 0xde77252caef665f3]),
+  fnv1_1024_test_5: U1024, b"e", U1024::from_words([0x0000000000000000, 0x98d7c19fbce653df, 0x221b9f717d3490ff, 0x95ca87fdaef30d1b, 0x823372f85b24a372, 0xf50e380000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000007685cd8, 0x1a491dbccc21ad06, 0x648d09a5c8cf5a78, 0x482054e91470b33d, 0xde77252caef665f2]),
+  fnv1_1024_test_6: U1024, b"f", U1024::from_words([0x0000000000000000, 0x98d7c19fbce653df, 0x221b9f717d3490ff, 0x95ca87fdaef30d1b, 0x823372f85b24a372, 0xf50e380000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000007685cd8, 0x1a491dbccc21ad06, 0x648d09a5c8cf5a78, 0x482054e91470b33d, 0xde77252caef665f1]),
+  fnv1_1024_test_7: U1024, b"fo", U1024::from_words([0x00000000000000f4, 0x6ef41cd23a4dcdd4, 0x06834963b78e8224, 0x1a6f5cb06f403cbd, 0x5a7c8903cef6a5f4, 0xfd72c90000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000b7cd7fb20, 0xc3631dc8903952e9, 0xeeb7f618698f4c87, 0xda23ad74b2c5f6f1, 0xfec4a64b541c16d2]),
+  fnv1_1024_test_8: U1024, b"foo", U1024::from_words([0x000000000001868c, 0xe88bd2c7cdc5fa5e, 0x52ebb9925ff5ea66, 0x8dff4576aa4ba658, 0x19176ce6b925a841, 0x2718870000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000011d09af071cf, 0x00b53007a8e594c7, 0x3348a3dbb339aead, 0x4953fdf93cfff548, 0x16f5e2d16f8f63c5]),
+  fnv1_1024_test_9: U1024, b"foob", U1024::from_words([0x00000000026f791f, 0x9147aedad1354bef, 0x7d238f3219005cbd, 0x6e8d664f6b4eefdb, 0xe94929e41548be79, 0x306d200000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x001ba08046e07e04, 0x18fb7be0ec07b8ea, 0x87a61bb4f073e2ba, 0xb740db8398ef60cb, 0x9b50beca015db8e3]),
+  fnv1_1024_test_10: U1024, b"fooba", U1024::from_words([0x00000003e27f563b, 0x2ca82d6f6b22a351, 0x17ddfb386bab86b4, 0xe52a63e0aa457ba1, 0xb5d6c250528e2bf1, 0x76f3830000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x2ad7e6edea236c5a, 0xbdff1bce07f9c3b4, 0x5c98f798e3b69b8e, 0x2f946b142b391bbf, 0xdc37df441e57b866]),
+  fnv1_1024_test_11: U1024, b"foobar", U1024::from_words([0x00000631175fa7ae, 0x643ad08723d312c9, 0xfd024adb91f77f6b, 0x19587197a22bcdf2, 0x3727166c3e596993, 0xcf5a8d0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000042, 0x70d11ef418ef08b8, 0xa49e1e825e547eb3, 0x9937f819222f3b7f, 0xc92a0e4707900888, 0x82a53ca30e08f65c]),
+  fnv1_1024_test_12: U1024, ::std::ffi::CString::new("").unwrap().as_bytes_with_nul(), U1024::from_words([0x0000000000000000, 0x98d7c19fbce653df, 0x221b9f717d3490ff, 0x95ca87fdaef30d1b, 0x823372f85b24a372, 0xf50e380000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000007685cd8, 0x1a491dbccc21ad06, 0x648d09a5c8cf5a78, 0x482054e91470b33d, 0xde77252caef66597]),
+  fnv1_1024_test_13: U1024, ::std::ffi::CString::new("a").unwrap().as_bytes_with_nul(), U1024::from_words([0x00000000000000f4, 0x6ef41cd23a4dcdd4, 0x06834963b78e8224, 0x1a6f5cb06f403cbd, 0x5a7c8903cef6a5f4, 0xfd72ce0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000b7cd7fb20, 0xc3631dc8903952e9, 0xeeb7f618698f4c87, 0xda23ad74b2c5f6f1, 0xfec4a64b541c1e7e]),
+  fnv1_1024_test_14: U1024, ::std::ffi::CString::new("b").unwrap().as_bytes_with_nul(), U1024::from_words([0x00000000000000f4, 0x6ef41cd23a4dcdd4, 0x06834963b78e8224, 0x1a6f5cb06f403cbd, 0x5a7c8903cef6a5f4, 0xfd72cd0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000b7cd7fb20, 0xc3631dc8903952e9, 0xeeb7f618698f4c87, 0xda23ad74b2c5f6f1, 0xfec4a64b541c1cf1]),
+  fnv1_1024_test_15: U1024, ::std::ffi::CString::new("c").unwrap().as_bytes_with_nul(), U1024::from_words([0x00000000000000f4, 0x6ef41cd23a4dcdd4, 0x06834963b78e8224, 0x1a6f5cb06f403cbd, 0x5a7c8903cef6a5f4, 0xfd72cc0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000b7cd7fb20, 0xc3631dc8903952e9, 0xeeb7f618698f4c87, 0xda23ad74b2c5f6f1, 0xfec4a64b541c1b64]),
+  fnv1_1024_test_16: U1024, ::std::ffi::CString::new("d").unwrap().as_bytes_with_nul(), U1024::from_words([0x00000000000000f4, 0x6ef41cd23a4dcdd4, 0x06834963b78e8224, 0x1a6f5cb06f403cbd, 0x5a7c8903cef6a5f4, 0xfd72cb0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000b7cd7fb20, 0xc3631dc8903952e9, 0xeeb7f618698f4c87, 0xda23ad74b2c5f6f1, 0xfec4a64b541c19d7]),
+  fnv1_1024_test_17: U1024, ::std::ffi::CString::new("e").unwrap().as_bytes_with_nul(), U1024::from_words([0x00000000000000f4, 0x6ef41cd23a4dcdd4, 0x06834963b78e8224, 0x1a6f5cb06f403cbd, 0x5a7c8903cef6a5f4, 0xfd72ca0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000b7cd7fb20, 0xc3631dc8903952e9, 0xeeb7f618698f4c87, 0xda23ad74b2c5f6f1, 0xfec4a64b541c184a]),
+  fnv1_1024_test_18: U1024, ::std::ffi::CString::new("f").unwrap().as_bytes_with_nul(), U1024::from_words([0x00000000000000f4, 0x6ef41cd23a4dcdd4, 0x06834963b78e8224, 0x1a6f5cb06f403cbd, 0x5a7c8903cef6a5f4, 0xfd72c90000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000b7cd7fb20, 0xc3631dc8903952e9, 0xeeb7f618698f4c87, 0xda23ad74b2c5f6f1, 0xfec4a64b541c16bd]),
+  fnv1_1024_test_19: U1024, ::std::ffi::CString::new("fo").unwrap().as_bytes_with_nul(), U1024::from_words([0x000000000001868c, 0xe88bd2c7cdc5fa5e, 0x52ebb9925ff5ea66, 0x8dff4576aa4ba658, 0x19176ce6b925a841, 0x2718870000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000011d09af071cf, 0x00b53007a8e594c7, 0x3348a3dbb339aead, 0x4953fdf93cfff548, 0x16f5e2d16f8f63aa]),
+  fnv1_1024_test_20: U1024, ::std::ffi::CString::new("foo").unwrap().as_bytes_with_nul(), U1024::from_words([0x00000000026f791f, 0x9147aedad1354bef, 0x7d238f3219005cbd, 0x6e8d664f6b4eefdb, 0xe94929e41548be79, 0x306d200000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x001ba08046e07e04, 0x18fb7be0ec07b8ea, 0x87a61bb4f073e2ba, 0xb740db8398ef60cb, 0x9b50beca015db881]),
+  fnv1_1024_test_21: U1024, ::std::ffi::CString::new("foob").unwrap().as_bytes_with_nul(), U1024::from_words([0x00000003e27f563b, 0x2ca82d6f6b22a351, 0x17ddfb386bab86b4, 0xe52a63e0aa457ba1, 0xb5d6c250528e2bf1, 0x76f3830000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x2ad7e6edea236c5a, 0xbdff1bce07f9c3b4, 0x5c98f798e3b69b8e, 0x2f946b142b391bbf, 0xdc37df441e57b807]),
+  fnv1_1024_test_22: U1024, ::std::ffi::CString::new("fooba").unwrap().as_bytes_with_nul(), U1024::from_words([0x00000631175fa7ae, 0x643ad08723d312c9, 0xfd024adb91f77f6b, 0x19587197a22bcdf2, 0x3727166c3e596993, 0xcf5a8d0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000042, 0x70d11ef418ef08b8, 0xa49e1e825e547eb3, 0x9937f819222f3b7f, 0xc92a0e4707900888, 0x82a53ca30e08f62e]),
+  fnv1_1024_test_23: U1024, ::std::ffi::CString::new("foobar").unwrap().as_bytes_with_nul(), U1024::from_words([0x0009dc921075fd8a, 0x5e3e1a372c72a59b, 0xb10cca1a94c8b238, 0x7d63a7efa7fca7a7, 0x17a64e5f55e55d46, 0x9863050000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000006708, 0xf44d008aaab08657, 0x4935502c49087c84, 0x9bcbbefa033f452a, 0xf6382426ba5d3bb2, 0x9a3f08dcc3e60cac]),
+  fnv1_1024_test_24: U1024, b"ch", U1024::from_words([0x00000000000000f4, 0x6ef41cd23a4dcdd4, 0x06834963b78e8224, 0x1a6f5cb06f403cbd, 0x5a7c8903cef6a5f4, 0xfd72cc0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000b7cd7fb20, 0xc3631dc8903952e9, 0xeeb7f618698f4c87, 0xda23ad74b2c5f6f1, 0xfec4a64b541c1b0c]),
+  fnv1_1024_test_25: U1024, b"cho", U1024::from_words([0x000000000001868c, 0xe88bd2c7cdc5fa5e, 0x52ebb9925ff5ea66, 0x8dff4576aa4ba658, 0x19176ce6b925a841, 0x2721680000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000011d09af071cf, 0x00b53007a8e594c7, 0x3348a3dbb339aead, 0x4953fdf93cfff548, 0x16f5e2d16f95f1f3]),
+  fnv1_1024_test_26: U1024, b"chon", U1024::from_words([0x00000000026f791f, 0x9147aedad1354bef, 0x7d238f3219005cbd, 0x6e8d664f6b4eefdb, 0xe94929e41548be79, 0x44c03b0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x001ba08046e07e04, 0x18fb7be0ec07b8ea, 0x87a61bb4f073e2ba, 0xb740db8398ef60cb, 0x9b50beca0b8835b9]),
+  fnv1_1024_test_27: U1024, b"chong", U1024::from_words([0x00000003e27f563b, 0x2ca82d6f6b22a351, 0x17ddfb386bab86b4, 0xe52a63e0aa457ba1, 0xb5d6c250528e2c1b, 0x2651380000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x2ad7e6edea236c5a, 0xbdff1bce07f9c3b4, 0x5c98f798e3b69b8e, 0x2f946b142b391bbf, 0xdc37df53e23b4f82]),
+  fnv1_1024_test_28: U1024, b"chongo", U1024::from_words([0x00000631175fa7ae, 0x643ad08723d312c9, 0xfd024adb91f77f6b, 0x19587197a22bcdf2, 0x3727166c3e59b9fc, 0xa7435a0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000042, 0x70d11ef418ef08b8, 0xa49e1e825e547eb3, 0x9937f819222f3b7f, 0xc92a0e4707900888, 0x82a55515d5fa4cf5]),
+  fnv1_1024_test_29: U1024, b"chongo ", U1024::from_words([0x0009dc921075fd8a, 0x5e3e1a372c72a59b, 0xb10cca1a94c8b238, 0x7d63a7efa7fca7a7, 0x17a64e5f567a82a5, 0x5dbf870000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000006708, 0xf44d008aaab08657, 0x4935502c49087c84, 0x9bcbbefa033f452a, 0xf6382426ba5d3bb2, 0x9a64f2dcd52957d1]),
+  fnv1_1024_test_30: U1024, b"chongo w", U1024::from_words([0x0fb21777d3faba3e, 0xd6d4fed9231afeba, 0x9951efd486fb5b9d, 0xb2d0999dbaf424da, 0x0a233c7380ef7747, 0x8b5c2c0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000009fc8e2, 0xdb69d70ab3c0555c, 0x87ad54ad422919a5, 0x9af729b7091e439f, 0xd510100f029593f9, 0x6e8ca076911d2f6a]),
+  fnv1_1024_test_31: U1024, b"chongo wa", U1024::from_words([0xf6f747af25a9de26, 0xe8a493431e31b4a1, 0xed2a92304af6ca97, 0x6bc1d96ffcad3524, 0x4e38b88d7ffc7084, 0x3b1fa60000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000f7ca87ce, 0x43227b98c144607e, 0x67cc50af99bcc5d1, 0x514bb0d923eededd, 0x69e8e74701f679d0, 0x7014d7df0a428703]),
+  fnv1_1024_test_32: U1024, b"chongo was", U1024::from_words([0xc801f8e08ae91b18, 0x0b98dd7d9f65ceb6, 0x87ca86358c6905f6, 0x0a7d1014c182b04f, 0x447003df8f525c19, 0xf29b710000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000018045149ade, 0x1c79abe3b709a406, 0xf7d9205169bec59b, 0x126140bcb96f9d5d, 0x3e2ea91e0b3ae83d, 0xd052c4e2e92b5fd4]),
+  fnv1_1024_test_33: U1024, b"chongo was ", U1024::from_words([0x3fa9d253e52ae801, 0x05b382c80a01e27a, 0x53d7bc1d201efb47, 0xb38f4d6e465488f8, 0x5c9e3f81957db726, 0x666e110000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000253eb20f42a72, 0x28af9022d9f35ece, 0x5bb71e40fcd8717b, 0x80d164ab92170999, 0x6e5c43976a5a27dc, 0x105b53e398439be4]),
+  fnv1_1024_test_34: U1024, b"chongo was h", U1024::from_words([0xae859e431319ec6f, 0xcac09e933c0a78b0, 0xde0034aba17059c3, 0x8c44ad716d5c0091, 0xfb9058052f48ea25, 0x1c4c410000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x039c23a21aa5d309, 0x1842860bfe6a0604, 0x3af9eac81ba7fc86, 0xc4b91e118db9e2f0, 0x2514d1cfedcfd045, 0x5da117f320d8c0fc]),
+  fnv1_1024_test_35: U1024, b"chongo was he", U1024::from_words([0x4b09771ce1b9b55b, 0xd6bbee8f1627f263, 0xee4e34ee145945ce, 0x4063e90bac7eb250, 0xeea8c567f52b0ead, 0xbb01c90000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000005, 0x992342635328451a, 0x9f29e0998a6b548f, 0x75911852e3829cff, 0x1313a138c944ee69, 0x81495f73cb45fb92, 0x32d22409f02346a9]),
+  fnv1_1024_test_36: U1024, b"chongo was her", U1024::from_words([0x85f2d26936ded8f6, 0x6cc5835aed0b37dc, 0x11e51448a21279a3, 0x1fded09bc9d9fb4d, 0x61b5b46a05e9cd5b, 0x250b5e0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000000008ae, 0x7badf407f5732a48, 0xd3f14e1ba8722279, 0x5202b88ad18d7790, 0x957105101fe5b99d, 0x7ec906923b8721b8, 0xcfe1e36966b49467]),
+  fnv1_1024_test_37: U1024, b"chongo was here", U1024::from_words([0x2cba95fe08e491cf, 0x206f2d539d1e2b19, 0x4db80538c7abb218, 0x52431f16d011493c, 0x0dea813b0d76dfbf, 0x27352d0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000000d7695, 0xccc37057a39892f0, 0xad3620e438ff7624, 0x2e382f46f8626b37, 0xc044da017740d93d, 0x9dbf30c6508f4b9a, 0x614daa74460a23de]),
+  fnv1_1024_test_38: U1024, b"chongo was here!", U1024::from_words([0xf5ed8ba10097026e, 0x4bdf6ed8dff81d35, 0xe8cb50cdea1d312e, 0xd2f073fe69ff5872, 0x23f602f52f036fb5, 0xd79aa70000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000014e0e64e, 0x8b1537e8b39bdf3c, 0x9cf101ec642a361b, 0xad21510f30a04575, 0x2ac61445ef90e48d, 0xa17ea38aee383e68, 0xe57156509db99f67]),
+  fnv1_1024_test_39: U1024, b"chongo was here!\n", U1024::from_words([0xfd40c54adb30b16f, 0xd3b5020075165bca, 0xa391c47ed5598b8c, 0xafc86f2bdf9fb3ef, 0xfcc0ff1f59aa8d9d, 0x1474620000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000002060c527cd, 0xafe7b3de88b930ff, 0x61bffb975575e8eb, 0x7caab48e688bb6b3, 0x552d707483b26fa3, 0x71639e736d38c8ab, 0xd0c2db0498dc32b1]),
+  fnv1_1024_test_40: U1024, ::std::ffi::CString::new("ch").unwrap().as_bytes_with_nul(), U1024::from_words([0x000000000001868c, 0xe88bd2c7cdc5fa5e, 0x52ebb9925ff5ea66, 0x8dff4576aa4ba658, 0x19176ce6b925a841, 0x2721680000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000011d09af071cf, 0x00b53007a8e594c7, 0x3348a3dbb339aead, 0x4953fdf93cfff548, 0x16f5e2d16f95f19c]),
+  fnv1_1024_test_41: U1024, ::std::ffi::CString::new("cho").unwrap().as_bytes_with_nul(), U1024::from_words([0x00000000026f791f, 0x9147aedad1354bef, 0x7d238f3219005cbd, 0x6e8d664f6b4eefdb, 0xe94929e41548be79, 0x44c03b0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x001ba08046e07e04, 0x18fb7be0ec07b8ea, 0x87a61bb4f073e2ba, 0xb740db8398ef60cb, 0x9b50beca0b8835d7]),
+  fnv1_1024_test_42: U1024, ::std::ffi::CString::new("chon").unwrap().as_bytes_with_nul(), U1024::from_words([0x00000003e27f563b, 0x2ca82d6f6b22a351, 0x17ddfb386bab86b4, 0xe52a63e0aa457ba1, 0xb5d6c250528e2c1b, 0x2651380000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x2ad7e6edea236c5a, 0xbdff1bce07f9c3b4, 0x5c98f798e3b69b8e, 0x2f946b142b391bbf, 0xdc37df53e23b4fe5]),
+  fnv1_1024_test_43: U1024, ::std::ffi::CString::new("chong").unwrap().as_bytes_with_nul(), U1024::from_words([0x00000631175fa7ae, 0x643ad08723d312c9, 0xfd024adb91f77f6b, 0x19587197a22bcdf2, 0x3727166c3e59b9fc, 0xa7435a0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000042, 0x70d11ef418ef08b8, 0xa49e1e825e547eb3, 0x9937f819222f3b7f, 0xc92a0e4707900888, 0x82a55515d5fa4c9a]),
+  fnv1_1024_test_44: U1024, ::std::ffi::CString::new("chongo").unwrap().as_bytes_with_nul(), U1024::from_words([0x0009dc921075fd8a, 0x5e3e1a372c72a59b, 0xb10cca1a94c8b238, 0x7d63a7efa7fca7a7, 0x17a64e5f567a82a5, 0x5dbf870000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000006708, 0xf44d008aaab08657, 0x4935502c49087c84, 0x9bcbbefa033f452a, 0xf6382426ba5d3bb2, 0x9a64f2dcd52957f1]),
+  fnv1_1024_test_45: U1024, ::std::ffi::CString::new("chongo ").unwrap().as_bytes_with_nul(), U1024::from_words([0x0fb21777d3faba3e, 0xd6d4fed9231afeba, 0x9951efd486fb5b9d, 0xb2d0999dbaf424da, 0x0a233c7380ef7747, 0x8b5c2c0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000009fc8e2, 0xdb69d70ab3c0555c, 0x87ad54ad422919a5, 0x9af729b7091e439f, 0xd510100f029593f9, 0x6e8ca076911d2f1d]),
+  fnv1_1024_test_46: U1024, ::std::ffi::CString::new("chongo w").unwrap().as_bytes_with_nul(), U1024::from_words([0xf6f747af25a9de26, 0xe8a493431e31b4a1, 0xed2a92304af6ca97, 0x6bc1d96ffcad3524, 0x4e38b88d7ffc7084, 0x3b1fa60000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000f7ca87ce, 0x43227b98c144607e, 0x67cc50af99bcc5d1, 0x514bb0d923eededd, 0x69e8e74701f679d0, 0x7014d7df0a428762]),
+  fnv1_1024_test_47: U1024, ::std::ffi::CString::new("chongo wa").unwrap().as_bytes_with_nul(), U1024::from_words([0xc801f8e08ae91b18, 0x0b98dd7d9f65ceb6, 0x87ca86358c6905f6, 0x0a7d1014c182b04f, 0x447003df8f525c19, 0xf29b710000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000018045149ade, 0x1c79abe3b709a406, 0xf7d9205169bec59b, 0x126140bcb96f9d5d, 0x3e2ea91e0b3ae83d, 0xd052c4e2e92b5fa7]),
+  fnv1_1024_test_48: U1024, ::std::ffi::CString::new("chongo was").unwrap().as_bytes_with_nul(), U1024::from_words([0x3fa9d253e52ae801, 0x05b382c80a01e27a, 0x53d7bc1d201efb47, 0xb38f4d6e465488f8, 0x5c9e3f81957db726, 0x666e110000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000253eb20f42a72, 0x28af9022d9f35ece, 0x5bb71e40fcd8717b, 0x80d164ab92170999, 0x6e5c43976a5a27dc, 0x105b53e398439bc4]),
+  fnv1_1024_test_49: U1024, ::std::ffi::CString::new("chongo was ").unwrap().as_bytes_with_nul(), U1024::from_words([0xae859e431319ec6f, 0xcac09e933c0a78b0, 0xde0034aba17059c3, 0x8c44ad716d5c0091, 0xfb9058052f48ea25, 0x1c4c410000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x039c23a21aa5d309, 0x1842860bfe6a0604, 0x3af9eac81ba7fc86, 0xc4b91e118db9e2f0, 0x2514d1cfedcfd045, 0x5da117f320d8c094]),
+  fnv1_1024_test_50: U1024, ::std::ffi::CString::new("chongo was h").unwrap().as_bytes_with_nul(), U1024::from_words([0x4b09771ce1b9b55b, 0xd6bbee8f1627f263, 0xee4e34ee145945ce, 0x4063e90bac7eb250, 0xeea8c567f52b0ead, 0xbb01c90000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000005, 0x992342635328451a, 0x9f29e0998a6b548f, 0x75911852e3829cff, 0x1313a138c944ee69, 0x81495f73cb45fb92, 0x32d22409f02346cc]),
+  fnv1_1024_test_51: U1024, ::std::ffi::CString::new("chongo was he").unwrap().as_bytes_with_nul(), U1024::from_words([0x85f2d26936ded8f6, 0x6cc5835aed0b37dc, 0x11e51448a21279a3, 0x1fded09bc9d9fb4d, 0x61b5b46a05e9cd5b, 0x250b5e0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000000008ae, 0x7badf407f5732a48, 0xd3f14e1ba8722279, 0x5202b88ad18d7790, 0x957105101fe5b99d, 0x7ec906923b8721b8, 0xcfe1e36966b49415]),
+  fnv1_1024_test_52: U1024, ::std::ffi::CString::new("chongo was her").unwrap().as_bytes_with_nul(), U1024::from_words([0x2cba95fe08e491cf, 0x206f2d539d1e2b19, 0x4db80538c7abb218, 0x52431f16d011493c, 0x0dea813b0d76dfbf, 0x27352d0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000000d7695, 0xccc37057a39892f0, 0xad3620e438ff7624, 0x2e382f46f8626b37, 0xc044da017740d93d, 0x9dbf30c6508f4b9a, 0x614daa74460a23bb]),
+  fnv1_1024_test_53: U1024, ::std::ffi::CString::new("chongo was here").unwrap().as_bytes_with_nul(), U1024::from_words([0xf5ed8ba10097026e, 0x4bdf6ed8dff81d35, 0xe8cb50cdea1d312e, 0xd2f073fe69ff5872, 0x23f602f52f036fb5, 0xd79aa70000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000014e0e64e, 0x8b1537e8b39bdf3c, 0x9cf101ec642a361b, 0xad21510f30a04575, 0x2ac61445ef90e48d, 0xa17ea38aee383e68, 0xe57156509db99f46]),
+  fnv1_1024_test_54: U1024, ::std::ffi::CString::new("chongo was here!").unwrap().as_bytes_with_nul(), U1024::from_words([0xfd40c54adb30b16f, 0xd3b5020075165bca, 0xa391c47ed5598b8c, 0xafc86f2bdf9fb3ef, 0xfcc0ff1f59aa8d9d, 0x1474620000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000002060c527cd, 0xafe7b3de88b930ff, 0x61bffb975575e8eb, 0x7caab48e688bb6b3, 0x552d707483b26fa3, 0x71639e736d38c8ab, 0xd0c2db0498dc32bb]),
+  fnv1_1024_test_55: U1024, ::std::ffi::CString::new("chongo was here!\n").unwrap().as_bytes_with_nul(), U1024::from_words([0x76a2f477aa7ec1c0, 0xc59d06323e60e7a8, 0x34c46c06094dddb0, 0x4c440a7b2e487e98, 0x30144f6ed058a131, 0x94aeab0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000323611beb9f9, 0xca51f01a0730fc0a, 0x96b929af87da3530, 0x54ba00d820aa541b, 0x177764b03bb72076, 0xd77cb900610f3272, 0xbe2da6210d7a9c7d]),
+  fnv1_1024_test_56: U1024, b"cu", U1024::from_words([0x00000000000000f4, 0x6ef41cd23a4dcdd4, 0x06834963b78e8224, 0x1a6f5cb06f403cbd, 0x5a7c8903cef6a5f4, 0xfd72cc0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000b7cd7fb20, 0xc3631dc8903952e9, 0xeeb7f618698f4c87, 0xda23ad74b2c5f6f1, 0xfec4a64b541c1b11]),
+  fnv1_1024_test_57: U1024, b"cur", U1024::from_words([0x000000000001868c, 0xe88bd2c7cdc5fa5e, 0x52ebb9925ff5ea66, 0x8dff4576aa4ba658, 0x19176ce6b925a841, 0x27216d0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000011d09af071cf, 0x00b53007a8e594c7, 0x3348a3dbb339aead, 0x4953fdf93cfff548, 0x16f5e2d16f95f92f]),
+  fnv1_1024_test_58: U1024, b"curd", U1024::from_words([0x00000000026f791f, 0x9147aedad1354bef, 0x7d238f3219005cbd, 0x6e8d664f6b4eefdb, 0xe94929e41548be79, 0x44cf380000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x001ba08046e07e04, 0x18fb7be0ec07b8ea, 0x87a61bb4f073e2ba, 0xb740db8398ef60cb, 0x9b50beca0b936d87]),
+  fnv1_1024_test_59: U1024, b"curds", U1024::from_words([0x00000003e27f563b, 0x2ca82d6f6b22a351, 0x17ddfb386bab86b4, 0xe52a63e0aa457ba1, 0xb5d6c250528e2c1b, 0x48c75f0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x2ad7e6edea236c5a, 0xbdff1bce07f9c3b4, 0x5c98f798e3b69b8e, 0x2f946b142b391bbf, 0xdc37df53f3a0da28]),
+  fnv1_1024_test_60: U1024, b"curds ", U1024::from_words([0x00000631175fa7ae, 0x643ad08723d312c9, 0xfd024adb91f77f6b, 0x19587197a22bcdf2, 0x3727166c3e59ba43, 0x7e087b0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000042, 0x70d11ef418ef08b8, 0xa49e1e825e547eb3, 0x9937f819222f3b7f, 0xc92a0e4707900888, 0x82a55530d0725028]),
+  fnv1_1024_test_61: U1024, b"curds a", U1024::from_words([0x0009dc921075fd8a, 0x5e3e1a372c72a59b, 0xb10cca1a94c8b238, 0x7d63a7efa7fca7a7, 0x17a64e5f567b0b7a, 0xe576e70000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000006708, 0xf44d008aaab08657, 0x4935502c49087c84, 0x9bcbbefa033f452a, 0xf6382426ba5d3bb2, 0x9a651cb341464e69]),
+  fnv1_1024_test_62: U1024, b"curds an", U1024::from_words([0x0fb21777d3faba3e, 0xd6d4fed9231afeba, 0x9951efd486fb5b9d, 0xb2d0999dbaf424da, 0x0a233c7381ed80d7, 0x1fb2a40000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000009fc8e2, 0xdb69d70ab3c0555c, 0x87ad54ad422919a5, 0x9af729b7091e439f, 0xd510100f029593f9, 0x6ecd81fc3a0798bb]),
+  fnv1_1024_test_63: U1024, b"curds and", U1024::from_words([0xf6f747af25a9de26, 0xe8a493431e31b4a1, 0xed2a92304af6ca97, 0x6bc1d96ffcad3524, 0x4e38b88f4ad2c9d6, 0x2fa10f0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000f7ca87ce, 0x43227b98c144607e, 0x67cc50af99bcc5d1, 0x514bb0d923eededd, 0x69e8e74701f679d0, 0xd4b29425fdc7d99b]),
+  fnv1_1024_test_64: U1024, b"curds and ", U1024::from_words([0xc801f8e08ae91b18, 0x0b98dd7d9f65ceb6, 0x87ca86358c6905f6, 0x0a7d1014c182b04f, 0x4470070bbb772725, 0xa49dde0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000018045149ade, 0x1c79abe3b709a406, 0xf7d9205169bec59b, 0x126140bcb96f9d5d, 0x3e2ea91e0b3ae8d9, 0xd8efbeea8eec757f]),
+  fnv1_1024_test_65: U1024, b"curds and w", U1024::from_words([0x3fa9d253e52ae801, 0x05b382c80a01e27a, 0x53d7bc1d201efb47, 0xb38f4d6e465488f8, 0x5ca3c70aa7869fef, 0x3546c50000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000253eb20f42a72, 0x28af9022d9f35ece, 0x5bb71e40fcd8717b, 0x80d164ab92170999, 0x6e5c43976a5b19d5, 0x6bcb11bfa4b23584]),
+  fnv1_1024_test_66: U1024, b"curds and wh", U1024::from_words([0xae859e431319ec6f, 0xcac09e933c0a78b0, 0xde0034aba17059c3, 0x8c44ad716d5c0092, 0x051580f196d7c59a, 0x50f5050000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x039c23a21aa5d309, 0x1842860bfe6a0604, 0x3af9eac81ba7fc86, 0xc4b91e118db9e2f0, 0x2514d1cfef470ff8, 0x29ea8632685cfddc]),
+  fnv1_1024_test_67: U1024, b"curds and whe", U1024::from_words([0x4b09771ce1b9b55b, 0xd6bbee8f1627f263, 0xee4e34ee145945ce, 0x4063e90bac7eb261, 0x2968eed0d723a2b7, 0xe8f69d0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000005, 0x992342635328451a, 0x9f29e0998a6b548f, 0x75911852e3829cff, 0x1313a138c944ee69, 0x81495f761133c3d9, 0x00b21c2bd835ae49]),
+  fnv1_1024_test_68: U1024, b"curds and whey", U1024::from_words([0x85f2d26936ded8f6, 0x6cc5835aed0b37dc, 0x11e51448a21279a3, 0x1fded09bc9da16be, 0x6b7e32de545f830c, 0x7c1fc20000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000000008ae, 0x7badf407f5732a48, 0xd3f14e1ba8722279, 0x5202b88ad18d7790, 0x957105101fe5b99d, 0x7ec90a18ad46b786, 0x1435affe4b3f474c]),
+  fnv1_1024_test_69: U1024, b"curds and whey\n", U1024::from_words([0x2cba95fe08e491cf, 0x206f2d539d1e2b19, 0x4db80538c7abb218, 0x52431f16d03f5df9, 0xf96c68dd0dce38a7, 0xbc87260000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000000d7695, 0xccc37057a39892f0, 0xad3620e438ff7624, 0x2e382f46f8626b37, 0xc044da017740d93d, 0x9dc4a844b6aa9aed, 0x5741ed5ab12190d6]),
+  fnv1_1024_test_70: U1024, ::std::ffi::CString::new("cu").unwrap().as_bytes_with_nul(), U1024::from_words([0x000000000001868c, 0xe88bd2c7cdc5fa5e, 0x52ebb9925ff5ea66, 0x8dff4576aa4ba658, 0x19176ce6b925a841, 0x27216d0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000011d09af071cf, 0x00b53007a8e594c7, 0x3348a3dbb339aead, 0x4953fdf93cfff548, 0x16f5e2d16f95f95d]),
+  fnv1_1024_test_71: U1024, ::std::ffi::CString::new("cur").unwrap().as_bytes_with_nul(), U1024::from_words([0x00000000026f791f, 0x9147aedad1354bef, 0x7d238f3219005cbd, 0x6e8d664f6b4eefdb, 0xe94929e41548be79, 0x44cf380000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x001ba08046e07e04, 0x18fb7be0ec07b8ea, 0x87a61bb4f073e2ba, 0xb740db8398ef60cb, 0x9b50beca0b936de3]),
+  fnv1_1024_test_72: U1024, ::std::ffi::CString::new("curd").unwrap().as_bytes_with_nul(), U1024::from_words([0x00000003e27f563b, 0x2ca82d6f6b22a351, 0x17ddfb386bab86b4, 0xe52a63e0aa457ba1, 0xb5d6c250528e2c1b, 0x48c75f0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x2ad7e6edea236c5a, 0xbdff1bce07f9c3b4, 0x5c98f798e3b69b8e, 0x2f946b142b391bbf, 0xdc37df53f3a0da5b]),
+  fnv1_1024_test_73: U1024, ::std::ffi::CString::new("curds").unwrap().as_bytes_with_nul(), U1024::from_words([0x00000631175fa7ae, 0x643ad08723d312c9, 0xfd024adb91f77f6b, 0x19587197a22bcdf2, 0x3727166c3e59ba43, 0x7e087b0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000042, 0x70d11ef418ef08b8, 0xa49e1e825e547eb3, 0x9937f819222f3b7f, 0xc92a0e4707900888, 0x82a55530d0725008]),
+  fnv1_1024_test_74: U1024, ::std::ffi::CString::new("curds ").unwrap().as_bytes_with_nul(), U1024::from_words([0x0009dc921075fd8a, 0x5e3e1a372c72a59b, 0xb10cca1a94c8b238, 0x7d63a7efa7fca7a7, 0x17a64e5f567b0b7a, 0xe576e70000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000006708, 0xf44d008aaab08657, 0x4935502c49087c84, 0x9bcbbefa033f452a, 0xf6382426ba5d3bb2, 0x9a651cb341464e08]),
+  fnv1_1024_test_75: U1024, ::std::ffi::CString::new("curds a").unwrap().as_bytes_with_nul(), U1024::from_words([0x0fb21777d3faba3e, 0xd6d4fed9231afeba, 0x9951efd486fb5b9d, 0xb2d0999dbaf424da, 0x0a233c7381ed80d7, 0x1fb2a40000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000009fc8e2, 0xdb69d70ab3c0555c, 0x87ad54ad422919a5, 0x9af729b7091e439f, 0xd510100f029593f9, 0x6ecd81fc3a0798d5]),
+  fnv1_1024_test_76: U1024, ::std::ffi::CString::new("curds an").unwrap().as_bytes_with_nul(), U1024::from_words([0xf6f747af25a9de26, 0xe8a493431e31b4a1, 0xed2a92304af6ca97, 0x6bc1d96ffcad3524, 0x4e38b88f4ad2c9d6, 0x2fa10f0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000f7ca87ce, 0x43227b98c144607e, 0x67cc50af99bcc5d1, 0x514bb0d923eededd, 0x69e8e74701f679d0, 0xd4b29425fdc7d9ff]),
+  fnv1_1024_test_77: U1024, ::std::ffi::CString::new("curds and").unwrap().as_bytes_with_nul(), U1024::from_words([0xc801f8e08ae91b18, 0x0b98dd7d9f65ceb6, 0x87ca86358c6905f6, 0x0a7d1014c182b04f, 0x4470070bbb772725, 0xa49dde0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000018045149ade, 0x1c79abe3b709a406, 0xf7d9205169bec59b, 0x126140bcb96f9d5d, 0x3e2ea91e0b3ae8d9, 0xd8efbeea8eec755f]),
+  fnv1_1024_test_78: U1024, ::std::ffi::CString::new("curds and ").unwrap().as_bytes_with_nul(), U1024::from_words([0x3fa9d253e52ae801, 0x05b382c80a01e27a, 0x53d7bc1d201efb47, 0xb38f4d6e465488f8, 0x5ca3c70aa7869fef, 0x3546c50000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000253eb20f42a72, 0x28af9022d9f35ece, 0x5bb71e40fcd8717b, 0x80d164ab92170999, 0x6e5c43976a5b19d5, 0x6bcb11bfa4b235f3]),
+  fnv1_1024_test_79: U1024, ::std::ffi::CString::new("curds and w").unwrap().as_bytes_with_nul(), U1024::from_words([0xae859e431319ec6f, 0xcac09e933c0a78b0, 0xde0034aba17059c3, 0x8c44ad716d5c0092, 0x051580f196d7c59a, 0x50f5050000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x039c23a21aa5d309, 0x1842860bfe6a0604, 0x3af9eac81ba7fc86, 0xc4b91e118db9e2f0, 0x2514d1cfef470ff8, 0x29ea8632685cfdb4]),
+  fnv1_1024_test_80: U1024, ::std::ffi::CString::new("curds and wh").unwrap().as_bytes_with_nul(), U1024::from_words([0x4b09771ce1b9b55b, 0xd6bbee8f1627f263, 0xee4e34ee145945ce, 0x4063e90bac7eb261, 0x2968eed0d723a2b7, 0xe8f69d0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000005, 0x992342635328451a, 0x9f29e0998a6b548f, 0x75911852e3829cff, 0x1313a138c944ee69, 0x81495f761133c3d9, 0x00b21c2bd835ae2c]),
+  fnv1_1024_test_81: U1024, ::std::ffi::CString::new("curds and whe").unwrap().as_bytes_with_nul(), U1024::from_words([0x85f2d26936ded8f6, 0x6cc5835aed0b37dc, 0x11e51448a21279a3, 0x1fded09bc9da16be, 0x6b7e32de545f830c, 0x7c1fc20000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000000008ae, 0x7badf407f5732a48, 0xd3f14e1ba8722279, 0x5202b88ad18d7790, 0x957105101fe5b99d, 0x7ec90a18ad46b786, 0x1435affe4b3f4735]),
+  fnv1_1024_test_82: U1024, ::std::ffi::CString::new("curds and whey").unwrap().as_bytes_with_nul(), U1024::from_words([0x2cba95fe08e491cf, 0x206f2d539d1e2b19, 0x4db80538c7abb218, 0x52431f16d03f5df9, 0xf96c68dd0dce38a7, 0xbc87260000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000000d7695, 0xccc37057a39892f0, 0xad3620e438ff7624, 0x2e382f46f8626b37, 0xc044da017740d93d, 0x9dc4a844b6aa9aed, 0x5741ed5ab12190dc]),
+  fnv1_1024_test_83: U1024, ::std::ffi::CString::new("curds and whey\n").unwrap().as_bytes_with_nul(), U1024::from_words([0xf5ed8ba10097026e, 0x4bdf6ed8dff81d35, 0xe8cb50cdea1d312e, 0xd2f073feb6ed015e, 0x77b98c25aabb36d0, 0x7f26c40000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000014e0e64e, 0x8b1537e8b39bdf3c, 0x9cf101ec642a361b, 0xad21510f30a04575, 0x2ac61445ef90e48d, 0xa9f8f28f46924210, 0x513d15a4b10d9bde]),
+  fnv1_1024_test_84: U1024, b"hi", U1024::from_words([0x00000000000000f4, 0x6ef41cd23a4dcdd4, 0x06834963b78e8224, 0x1a6f5cb06f403cbd, 0x5a7c8903cef6a5f4, 0xfd72d70000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000b7cd7fb20, 0xc3631dc8903952e9, 0xeeb7f618698f4c87, 0xda23ad74b2c5f6f1, 0xfec4a64b541c2c1a]),
+  fnv1_1024_test_85: U1024, ::std::ffi::CString::new("hi").unwrap().as_bytes_with_nul(), U1024::from_words([0x000000000001868c, 0xe88bd2c7cdc5fa5e, 0x52ebb9925ff5ea66, 0x8dff4576aa4ba658, 0x19176ce6b925a841, 0x2743850000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000011d09af071cf, 0x00b53007a8e594c7, 0x3348a3dbb339aead, 0x4953fdf93cfff548, 0x16f5e2d16fb06452]),
+  fnv1_1024_test_86: U1024, b"hello", U1024::from_words([0x00000003e27f563b, 0x2ca82d6f6b22a351, 0x17ddfb386bab86b4, 0xe52a63e0aa457ba1, 0xb5d6c250528e2cbf, 0x1b64990000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x2ad7e6edea236c5a, 0xbdff1bce07f9c3b4, 0x5c98f798e3b69b8e, 0x2f946b142b391bbf, 0xdc37df936e51ff01]),
+  fnv1_1024_test_87: U1024, ::std::ffi::CString::new("hello").unwrap().as_bytes_with_nul(), U1024::from_words([0x00000631175fa7ae, 0x643ad08723d312c9, 0xfd024adb91f77f6b, 0x19587197a22bcdf2, 0x3727166c3e5af7cb, 0xcd00460000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000042, 0x70d11ef418ef08b8, 0xa49e1e825e547eb3, 0x9937f819222f3b7f, 0xc92a0e4707900888, 0x82a5b7a21528748d]),
+  fnv1_1024_test_88: U1024, b"\xff\x00\x00\x01", U1024::from_words([0x00000000026f791f, 0x9147aedad1354bef, 0x7d238f3219005cbd, 0x6e8d664f6b4eefdb, 0xe94929e41548be75, 0x53bf900000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x001ba08046e07e04, 0x18fb7be0ec07b8ea, 0x87a61bb4f073e2ba, 0xb740db8398ef60cb, 0x9b50bec80239b989]),
+  fnv1_1024_test_89: U1024, b"\x01\x00\x00\xff", U1024::from_words([0x00000000026f791f, 0x9147aedad1354bef, 0x7d238f3219005cbd, 0x6e8d664f6b4eefdb, 0xe94929e41548be76, 0x9fa0aa0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x001ba08046e07e04, 0x18fb7be0ec07b8ea, 0x87a61bb4f073e2ba, 0xb740db8398ef60cb, 0x9b50bec8adc86bb1]),
+  fnv1_1024_test_90: U1024, b"\xff\x00\x00\x02", U1024::from_words([0x00000000026f791f, 0x9147aedad1354bef, 0x7d238f3219005cbd, 0x6e8d664f6b4eefdb, 0xe94929e41548be75, 0x53bf900000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x001ba08046e07e04, 0x18fb7be0ec07b8ea, 0x87a61bb4f073e2ba, 0xb740db8398ef60cb, 0x9b50bec80239b98a]),
+  fnv1_1024_test_91: U1024, b"\x02\x00\x00\xff", U1024::from_words([0x00000000026f791f, 0x9147aedad1354bef, 0x7d238f3219005cbd, 0x6e8d664f6b4eefdb, 0xe94929e41548be76, 0x9869af0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x001ba08046e07e04, 0x18fb7be0ec07b8ea, 0x87a61bb4f073e2ba, 0xb740db8398ef60cb, 0x9b50bec8aa0daac6]),
+  fnv1_1024_test_92: U1024, b"\xff\x00\x00\x03", U1024::from_words([0x00000000026f791f, 0x9147aedad1354bef, 0x7d238f3219005cbd, 0x6e8d664f6b4eefdb, 0xe94929e41548be75, 0x53bf900000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x001ba08046e07e04, 0x18fb7be0ec07b8ea, 0x87a61bb4f073e2ba, 0xb740db8398ef60cb, 0x9b50bec80239b98b]),
+  fnv1_1024_test_93: U1024, b"\x03\x00\x00\xff", U1024::from_words([0x00000000026f791f, 0x9147aedad1354bef, 0x7d238f3219005cbd, 0x6e8d664f6b4eefdb, 0xe94929e41548be76, 0x9132b40000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x001ba08046e07e04, 0x18fb7be0ec07b8ea, 0x87a61bb4f073e2ba, 0xb740db8398ef60cb, 0x9b50bec8a652e9db]),
+  fnv1_1024_test_94: U1024, b"\xff\x00\x00\x04", U1024::from_words([0x00000000026f791f, 0x9147aedad1354bef, 0x7d238f3219005cbd, 0x6e8d664f6b4eefdb, 0xe94929e41548be75, 0x53bf900000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x001ba08046e07e04, 0x18fb7be0ec07b8ea, 0x87a61bb4f073e2ba, 0xb740db8398ef60cb, 0x9b50bec80239b98c]),
+  fnv1_1024_test_95: U1024, b"\x04\x00\x00\xff", U1024::from_words([0x00000000026f791f, 0x9147aedad1354bef, 0x7d238f3219005cbd, 0x6e8d664f6b4eefdb, 0xe94929e41548be76, 0x89fbb90000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x001ba08046e07e04, 0x18fb7be0ec07b8ea, 0x87a61bb4f073e2ba, 0xb740db8398ef60cb, 0x9b50bec8a29828f0]),
+  fnv1_1024_test_96: U1024, b"\x40\x51\x4e\x44", U1024::from_words([0x00000000026f791f, 0x9147aedad1354bef, 0x7d238f3219005cbd, 0x6e8d664f6b4eefdb, 0xe94929e41548be78, 0x73fe350000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x001ba08046e07e04, 0x18fb7be0ec07b8ea, 0x87a61bb4f073e2ba, 0xb740db8398ef60cb, 0x9b50bec99fbd46c8]),
+  fnv1_1024_test_97: U1024, b"\x44\x4e\x51\x40", U1024::from_words([0x00000000026f791f, 0x9147aedad1354bef, 0x7d238f3219005cbd, 0x6e8d664f6b4eefdb, 0xe94929e41548be78, 0x58877c0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x001ba08046e07e04, 0x18fb7be0ec07b8ea, 0x87a61bb4f073e2ba, 0xb740db8398ef60cb, 0x9b50bec991e7a124]),
+  fnv1_1024_test_98: U1024, b"\x40\x51\x4e\x4a", U1024::from_words([0x00000000026f791f, 0x9147aedad1354bef, 0x7d238f3219005cbd, 0x6e8d664f6b4eefdb, 0xe94929e41548be78, 0x73fe350000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x001ba08046e07e04, 0x18fb7be0ec07b8ea, 0x87a61bb4f073e2ba, 0xb740db8398ef60cb, 0x9b50bec99fbd46c6]),
+  fnv1_1024_test_99: U1024, b"\x4a\x4e\x51\x40", U1024::from_words([0x00000000026f791f, 0x9147aedad1354bef, 0x7d238f3219005cbd, 0x6e8d664f6b4eefdb, 0xe94929e41548be78, 0xa0a0e20000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x001ba08046e07e04, 0x18fb7be0ec07b8ea, 0x87a61bb4f073e2ba, 0xb740db8398ef60cb, 0x9b50bec9b7298dd2]),
+  fnv1_1024_test_100: U1024, b"\x40\x51\x4e\x54", U1024::from_words([0x00000000026f791f, 0x9147aedad1354bef, 0x7d238f3219005cbd, 0x6e8d664f6b4eefdb, 0xe94929e41548be78, 0x73fe350000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x001ba08046e07e04, 0x18fb7be0ec07b8ea, 0x87a61bb4f073e2ba, 0xb740db8398ef60cb, 0x9b50bec99fbd46d8]),
+  fnv1_1024_test_101: U1024, b"\x54\x4e\x51\x40", U1024::from_words([0x00000000026f791f, 0x9147aedad1354bef, 0x7d238f3219005cbd, 0x6e8d664f6b4eefdb, 0xe94929e41548be77, 0xe38aac0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x001ba08046e07e04, 0x18fb7be0ec07b8ea, 0x87a61bb4f073e2ba, 0xb740db8398ef60cb, 0x9b50bec9550789b4]),
+  fnv1_1024_test_102: U1024, b"127.0.0.1", U1024::from_words([0xf6f747af25a9de26, 0xe8a493431e31b4a1, 0xed2a92304af6ca97, 0x6bc1d96ffcad3524, 0x4e3884143eab9754, 0x5737620000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000f7ca87ce, 0x43227b98c144607e, 0x67cc50af99bcc5d1, 0x514bb0d923eededd, 0x69e8e74701f66fa5, 0x2a1fbe886a2ef5fc]),
+  fnv1_1024_test_103: U1024, ::std::ffi::CString::new("127.0.0.1").unwrap().as_bytes_with_nul(), U1024::from_words([0xc801f8e08ae91b18, 0x0b98dd7d9f65ceb6, 0x87ca86358c6905f6, 0x0a7d1014c182b04f, 0x4414788f4fd83635, 0x6fd8f60000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000018045149ade, 0x1c79abe3b709a406, 0xf7d9205169bec59b, 0x126140bcb96f9d5d, 0x3e2ea91e0b2b2322, 0x533a798caad377cc]),
+  fnv1_1024_test_104: U1024, b"127.0.0.2", U1024::from_words([0xf6f747af25a9de26, 0xe8a493431e31b4a1, 0xed2a92304af6ca97, 0x6bc1d96ffcad3524, 0x4e3884143eab9754, 0x5737620000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000f7ca87ce, 0x43227b98c144607e, 0x67cc50af99bcc5d1, 0x514bb0d923eededd, 0x69e8e74701f66fa5, 0x2a1fbe886a2ef5ff]),
+  fnv1_1024_test_105: U1024, ::std::ffi::CString::new("127.0.0.2").unwrap().as_bytes_with_nul(), U1024::from_words([0xc801f8e08ae91b18, 0x0b98dd7d9f65ceb6, 0x87ca86358c6905f6, 0x0a7d1014c182b04f, 0x4414788f4fd83635, 0x6fd8f90000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000018045149ade, 0x1c79abe3b709a406, 0xf7d9205169bec59b, 0x126140bcb96f9d5d, 0x3e2ea91e0b2b2322, 0x533a798caad37c73]),
+  fnv1_1024_test_106: U1024, b"127.0.0.3", U1024::from_words([0xf6f747af25a9de26, 0xe8a493431e31b4a1, 0xed2a92304af6ca97, 0x6bc1d96ffcad3524, 0x4e3884143eab9754, 0x5737620000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000f7ca87ce, 0x43227b98c144607e, 0x67cc50af99bcc5d1, 0x514bb0d923eededd, 0x69e8e74701f66fa5, 0x2a1fbe886a2ef5fe]),
+  fnv1_1024_test_107: U1024, ::std::ffi::CString::new("127.0.0.3").unwrap().as_bytes_with_nul(), U1024::from_words([0xc801f8e08ae91b18, 0x0b98dd7d9f65ceb6, 0x87ca86358c6905f6, 0x0a7d1014c182b04f, 0x4414788f4fd83635, 0x6fd8f80000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000018045149ade, 0x1c79abe3b709a406, 0xf7d9205169bec59b, 0x126140bcb96f9d5d, 0x3e2ea91e0b2b2322, 0x533a798caad37ae6]),
+  fnv1_1024_test_108: U1024, b"64.81.78.68", U1024::from_words([0x3fa9d253e52ae801, 0x05b382c80a01e27a, 0x53d7bc1d201efb47, 0xb38f4d6e465488f7, 0xb4be286b4cafaa3a, 0xcef4300000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000253eb20f42a72, 0x28af9022d9f35ece, 0x5bb71e40fcd8717b, 0x80d164ab92170999, 0x6e5c43975052e08b, 0xfa3c64996958c163]),
+  fnv1_1024_test_109: U1024, ::std::ffi::CString::new("64.81.78.68").unwrap().as_bytes_with_nul(), U1024::from_words([0xae859e431319ec6f, 0xcac09e933c0a78b0, 0xde0034aba17059c3, 0x8c44ad716d5bff73, 0x9dc53a6028cf969c, 0x496fd30000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x039c23a21aa5d309, 0x1842860bfe6a0604, 0x3af9eac81ba7fc86, 0xc4b91e118db9e2f0, 0x2514d1a790863913, 0x0fa801e85ea3e687]),
+  fnv1_1024_test_110: U1024, b"64.81.78.74", U1024::from_words([0x3fa9d253e52ae801, 0x05b382c80a01e27a, 0x53d7bc1d201efb47, 0xb38f4d6e465488f7, 0xb4be286b4cafaa3a, 0xcef42f0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000253eb20f42a72, 0x28af9022d9f35ece, 0x5bb71e40fcd8717b, 0x80d164ab92170999, 0x6e5c43975052e08b, 0xfa3c64996958bffa]),
+  fnv1_1024_test_111: U1024, ::std::ffi::CString::new("64.81.78.74").unwrap().as_bytes_with_nul(), U1024::from_words([0xae859e431319ec6f, 0xcac09e933c0a78b0, 0xde0034aba17059c3, 0x8c44ad716d5bff73, 0x9dc53a6028cf969c, 0x496cdd0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x039c23a21aa5d309, 0x1842860bfe6a0604, 0x3af9eac81ba7fc86, 0xc4b91e118db9e2f0, 0x2514d1a790863913, 0x0fa801e85ea1b6b2]),
+  fnv1_1024_test_112: U1024, b"64.81.78.84", U1024::from_words([0x3fa9d253e52ae801, 0x05b382c80a01e27a, 0x53d7bc1d201efb47, 0xb38f4d6e465488f7, 0xb4be286b4cafaa3a, 0xcef4320000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000253eb20f42a72, 0x28af9022d9f35ece, 0x5bb71e40fcd8717b, 0x80d164ab92170999, 0x6e5c43975052e08b, 0xfa3c64996958c441]),
+  fnv1_1024_test_113: U1024, ::std::ffi::CString::new("64.81.78.84").unwrap().as_bytes_with_nul(), U1024::from_words([0xae859e431319ec6f, 0xcac09e933c0a78b0, 0xde0034aba17059c3, 0x8c44ad716d5bff73, 0x9dc53a6028cf969c, 0x4975cb0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x039c23a21aa5d309, 0x1842860bfe6a0604, 0x3af9eac81ba7fc86, 0xc4b91e118db9e2f0, 0x2514d1a790863913, 0x0fa801e85ea858cd]),
+  fnv1_1024_test_114: U1024, b"feedface", U1024::from_words([0x0fb21777d3faba3e, 0xd6d4fed9231afeba, 0x9951efd486fb5b9d, 0xb2d0999dbaf424da, 0x0a233c7274f007c0, 0xaaa0710000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000009fc8e2, 0xdb69d70ab3c0555c, 0x87ad54ad422919a5, 0x9af729b7091e439f, 0xd510100f029593f9, 0x34100c470a2eabf0]),
+  fnv1_1024_test_115: U1024, ::std::ffi::CString::new("feedface").unwrap().as_bytes_with_nul(), U1024::from_words([0xf6f747af25a9de26, 0xe8a493431e31b4a1, 0xed2a92304af6ca97, 0x6bc1d96ffcad3524, 0x4e38b6b368484cd2, 0xc97b2d0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000f7ca87ce, 0x43227b98c144607e, 0x67cc50af99bcc5d1, 0x514bb0d923eededd, 0x69e8e74701f67975, 0xbce30a2aca60a330]),
+  fnv1_1024_test_116: U1024, b"feedfacedaffdeed", U1024::from_words([0xf5ed8ba10097026e, 0x4bdf6ed8dff81d35, 0xe8cb50cdea1d312e, 0xd2f073b3f8c359a2, 0x799bb024d5dbb9a9, 0xb5cd580000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000014e0e64e, 0x8b1537e8b39bdf3c, 0x9cf101ec642a361b, 0xad21510f30a04575, 0x2ac61445ef90e485, 0xfd1468af48c9b353, 0x3bd7ba408feec231]),
+  fnv1_1024_test_117: U1024, ::std::ffi::CString::new("feedfacedaffdeed").unwrap().as_bytes_with_nul(), U1024::from_words([0xfd40c54adb30b16f, 0xd3b5020075165bca, 0xa391c47ed5598b8c, 0xafc7f415db5ab03f, 0x60237c5b7d792cbe, 0xde33a90000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000002060c527cd, 0xafe7b3de88b930ff, 0x61bffb975575e8eb, 0x7caab48e688bb6b3, 0x552d707483b263c9, 0x78a657d3e0cb1813, 0xcd8bd61f354325fd]),
+  fnv1_1024_test_118: U1024, b"feedfacedeadbeef", U1024::from_words([0xf5ed8ba10097026e, 0x4bdf6ed8dff81d35, 0xe8cb50cdea1d312e, 0xd2f073b3f8c359a2, 0x799bb024d51221c5, 0x4f0a590000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000014e0e64e, 0x8b1537e8b39bdf3c, 0x9cf101ec642a361b, 0xad21510f30a04575, 0x2ac61445ef90e485, 0xfd1468af48c9b353, 0x3ba45452bac08990]),
+  fnv1_1024_test_119: U1024, ::std::ffi::CString::new("feedfacedeadbeef").unwrap().as_bytes_with_nul(), U1024::from_words([0xfd40c54adb30b16f, 0xd3b5020075165bca, 0xa391c47ed5598b8c, 0xafc7f415db5ab03f, 0x60237c5a1172b1b6, 0x5395950000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000002060c527cd, 0xafe7b3de88b930ff, 0x61bffb975575e8eb, 0x7caab48e688bb6b3, 0x552d707483b263c9, 0x78a657d3e0cb1813, 0x7dd6c44b9c955450]),
+  fnv1_1024_test_120: U1024, b"line 1\nline 2\nline 3", U1024::from_words([0xb897b5105c6e6e59, 0x7783b1cee11c5a1e, 0xfe09d71e82447f7a, 0x94e146d80a5443bb, 0xc398a791a54dc035, 0xdd0af10000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x78c10a52e7afdaf1, 0x67e608756cd3d314, 0x5361343de4d43872, 0xc6d1c526c07e9b42, 0x50b4d84301f29f06, 0x6a6f43dc95c1b8da, 0x28b61944192fb625]),
+  fnv1_1024_test_121: U1024, b"chongo <Landon Curt Noll> /\\../\\", U1024::from_words([0x93ab73cfe16325c0, 0x2e323aae47c67155, 0x39db8de7ef5115ce, 0xc100c5a01529eb24, 0xe70f63a53bfd4c45, 0xa59bf00000000000, 0x0000000000000000, 0x0000005b41e7aa83, 0xaeb3a44e230384f5, 0xaca946916dadb061, 0x21c1b8482c0d1285, 0x542305ba8871386f, 0x0d1b9bd7c4b66802, 0xbb50de34d43ab560, 0xedbffa1d41cbdb48, 0xfd8d44056a31a040]),
+  fnv1_1024_test_122: U1024, ::std::ffi::CString::new("chongo <Landon Curt Noll> /\\../\\").unwrap().as_bytes_with_nul(), U1024::from_words([0x0df51eb4a9c74590, 0x151d6f526a5998ef, 0x6fe31369719d0675, 0x88e7da2e91fbc57c, 0x1eb8d03b95134d6c, 0x0473700000000000, 0x0000000000000000, 0x00008d8534436e35, 0xec95cd2c4c7530fc, 0xc2826f87165a86a1, 0x596ac7ec5045b8c3, 0x7a4fe24597948437, 0x53d0ad9c0edf4c3c, 0x7c6897ed1f0b4750, 0xb2b6df5f09231031, 0x34127c65aef58340]),
+  fnv1_1024_test_123: U1024, b"chongo (Landon Curt Noll) /\\../\\", U1024::from_words([0x93ab73cfe16325c0, 0x2e323aae47c67155, 0x3a8cd889e046bece, 0xabc41d86f15331dc, 0xb43f1990e9c87581, 0x22754d0000000000, 0x0000000000000000, 0x0000005b41e7aa83, 0xaeb3a44e230384f5, 0xaca946916dadb061, 0x21c1b8482c0d1285, 0x542305ba8871386f, 0x0d1b9bd7c4c1dd14, 0xd1380ceabcb426e8, 0x0b33c15df3053b31, 0xd4fbc5a8f9bfa357]),
+  fnv1_1024_test_124: U1024, ::std::ffi::CString::new("chongo (Landon Curt Noll) /\\../\\").unwrap().as_bytes_with_nul(), U1024::from_words([0x0df51eb4a9c74590, 0x151d6f526a5998f0, 0x8e48e2a205c2d13d, 0x1348b24f71c5b136, 0x8b15d78f87a3e23c, 0x2f8bc00000000000, 0x0000000000000000, 0x00008d8534436e35, 0xec95cd2c4c7530fc, 0xc2826f87165a86a1, 0x596ac7ec5045b8c3, 0x7a4fe24597948437, 0x53d0ad9c20a3d948, 0x73ec0806a36055d9, 0x5f42deb1df1ccc47, 0x4a71870b4e304deb]),
+  fnv1_1024_test_125: U1024, b"http://antwrp.gsfc.nasa.gov/apod/astropix.html", U1024::from_words([0xb1a2a23c476dcf97, 0x8904747603c17e24, 0x21772f29328f58f2, 0xda683de5a8274eff, 0x4d77c1bbbf1ad333, 0x09b62000a5db98d3, 0x73db2c6a37cd7f4d, 0x9608df27d07254e1, 0x61b614df2165f94b, 0xcfc260c582d57d0f, 0x064427101c698122, 0xf1d32dcdbadd8faa, 0x97bb4b3d6983ddb1, 0x29d54a8d7a81f1fc, 0x0ce02a6df2f3011b, 0x43db7ec1d0812fcc]),
+  fnv1_1024_test_126: U1024, b"http://en.wikipedia.org/wiki/Fowler_Noll_Vo_hash", U1024::from_words([0x3c6d0da1a00e0a1e, 0x9e8c68f51adc9650, 0x7d9d3442984afa78, 0xe4da7d4d1a8fd2b2, 0x4d9ec1d2b4a40533, 0xc1dc62e00a1bdeeb, 0x03637e2d837c6da7, 0x5bf3f70b45637a85, 0xed57d0890636b328, 0x1dcc5fb23e5ea6f8, 0xdcd57de42d58767f, 0x1545a19b9baf8e2a, 0xf21716bb64fbe06a, 0x52bc62b82d771c88, 0xd264bef49c6def91, 0x1726f0d32fbcbced]),
+  fnv1_1024_test_127: U1024, b"http://epod.usra.edu/", U1024::from_words([0x1716deb2b8755ea3, 0x2b733099eabce8d0, 0x73dddca0b916a601, 0x836d78b2ccdb524a, 0x599e0490c695c2a6, 0xe3e2d10000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000000000bb, 0x435d02914bb6885e, 0x1fbb1e19c47e5685, 0x4dbe03fbdd1b89fe, 0x534ebd188456c5d7, 0x2873bcb0fe33df4d, 0x29ae27355e85f8db, 0xa8940d5bc06e1559]),
+  fnv1_1024_test_128: U1024, b"http://exoplanet.eu/", U1024::from_words([0xb897b5105c6e6e59, 0x7783b1cee11c5a1e, 0xfe09d71e8244825b, 0x086ebe8ecf3b3163, 0x2cb22653ef11146d, 0xdf44ad0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x78c10a52e7afdaf1, 0x67e608756cd3d314, 0x5361343de4d43872, 0xc6d1c526c07e9b42, 0x50b4d87ed56faff5, 0x3b6e0f8f836d8b68, 0xa614f3d773c668f8]),
+  fnv1_1024_test_129: U1024, b"http://hvo.wr.usgs.gov/cam3/", U1024::from_words([0x4b99d3efcef51ea9, 0x5709d3e4206b0b80, 0x2ab1ceb79286b58f, 0x283a8f4149ab3104, 0x91e06041b1ed43de, 0x6464700000000000, 0x0000000000000000, 0x000000000000000f, 0xc74cbee6ed33d7d2, 0x390d47dca6702e84, 0x4d01b13b2ca28dd9, 0x8b9e2dfbf81e5c7f, 0x31caaa8368308cbe, 0x9d671d778883f662, 0x1355ca16b617bfb3, 0x4e51b2978d6ef7af]),
+  fnv1_1024_test_130: U1024, b"http://hvo.wr.usgs.gov/cams/HMcam/", U1024::from_words([0xead961a599e92623, 0x4ad55ba964b1d7b2, 0xea44b76a123ac150, 0xc93d7002413d8865, 0xe9701cb055a491ef, 0xf315360000000000, 0x0000000000000000, 0x00db77920c91e99f, 0xe44f2db291bcf7f9, 0xa446f47daa62c837, 0xaa9809787c1f8724, 0xade1e5ed55b2518f, 0xfffa5186fad42081, 0x802e61a03f9c5544, 0x07555766a4c394aa, 0x7486064aa5cca30a]),
+  fnv1_1024_test_131: U1024, b"http://hvo.wr.usgs.gov/kilauea/update/deformation.html", U1024::from_words([0xb712f31923ac23d3, 0x6e8c1bb4fd232e5f, 0x2a92c9a7854c9853, 0xd08969deaea2b5e8, 0xdb9a524cc8f59b0d, 0xdb6476c724ae8c14, 0xf070adcf242d4386, 0x836d84958897496a, 0x9d050d2d6126614b, 0x1133d0fa57f8a7fa, 0xd3dc820e16c5c0aa, 0x85def42096323271, 0xa23e815e2f5ddccf, 0x2a9d7c5501dd0f3f, 0x8c1efa221987ba72, 0x3a9abf9fd581b12e]),
+  fnv1_1024_test_132: U1024, b"http://hvo.wr.usgs.gov/kilauea/update/images.html", U1024::from_words([0x2ea1754615d7bb4c, 0x2430c11c057d87e9, 0x9e0e89e4c54c9213, 0x40eecb45af7c91bd, 0xee7e1eff1f909051, 0x094fa66fad38b274, 0x414aac94e7f60c89, 0x99561c7a9b4501b1, 0x112e647ea2d3d536, 0x35f0676ab8c8efee, 0x77143acf912ea74b, 0xbbbd65366eae7c3c, 0xb9e09170f5c10464, 0x3e6ae1e110e11e14, 0x7554b19334bafdd5, 0x3be7ea43f14b8520]),
+  fnv1_1024_test_133: U1024, b"http://hvo.wr.usgs.gov/kilauea/update/maps.html", U1024::from_words([0xe1ab3e13eb61b651, 0x633622d550db6224, 0x5fa44d40e22972ac, 0x0ae94b72999f60cc, 0x23f6ab635665c3f4, 0xabbeb101358bffea, 0xaae3e0b889ae6b51, 0xabc210be414da184, 0x875e5e06cb239a91, 0x3070124be512f04c, 0xb7b093fc0f92a494, 0x30e58c5d219af3ac, 0x973a3b4a45afa4c1, 0xaa79d7331fb08e14, 0x87eb7da80fa9c6ad, 0x53c03353b93089df]),
+  fnv1_1024_test_134: U1024, b"http://hvo.wr.usgs.gov/volcanowatch/current_issue.html", U1024::from_words([0xb712f31923ac23d3, 0x6e8c1dc550454ffc, 0x3158728269cedc43, 0x3635549b779a2e53, 0x33e02eb6a93e49b9, 0x90a148c724ae8c14, 0xf070adcf242d4386, 0x836d84958897496a, 0x9d050d2d6126614b, 0x1133d0fa57f8a7fa, 0xd3dc820e16c5c0aa, 0x85def4209632328d, 0x106bcfd2b550458e, 0x83db35bbfe7bc7e9, 0x6c38089555b7e90f, 0x09a8757126ad65cc]),
+  fnv1_1024_test_135: U1024, b"http://neo.jpl.nasa.gov/risk/", U1024::from_words([0xe01b846f904e8894, 0x189a17f810ab592a, 0x664a514b9cc861f0, 0xbfff339f10c110ab, 0x053f167e9e69b8cc, 0x937ed70000000000, 0x0000000000000000, 0x0000000000001878, 0x12040c1dd965b102, 0x7998712e1bf8232b, 0x6b9fd8c43815fa5d, 0x844d4fbfc7157140, 0x374a6dc8934a439a, 0x1884aa36db46a290, 0xd812d0e23e800080, 0x97e2bd4f832400ad]),
+  fnv1_1024_test_136: U1024, b"http://norvig.com/21-days.html", U1024::from_words([0xa0a6be8717198d71, 0x3c686be924270f53, 0xeb83ac5ab304053f, 0x083d0affff62d442, 0x39c1097830d1181d, 0x2241a20000000000, 0x0000000000000000, 0x000000000025f233, 0xf046ca4a22b380d6, 0x916784815fce8a55, 0xe6e3284afa154306, 0x2be4ac67bc40a095, 0xbe6c400c6a2ad5f8, 0x06967d20f1d4f14d, 0xe0f87cc4d89395cc, 0xa4eb28e2443df04c]),
+  fnv1_1024_test_137: U1024, b"http://primes.utm.edu/curios/home.php", U1024::from_words([0x75913be2ff3d4245, 0x8d347f985bde2585, 0x3ad246bc6f02808e, 0x4db52505ddc9c816, 0xbcb344bfab5e1488, 0xd6ca720000000000, 0x0000000000033281, 0x1d68c235a6f2844d, 0xb76579b983c1bcbf, 0x1471bfe1927e1ec0, 0xe89b29f39228b8ec, 0x6851249769be2ba0, 0x01a7b06d845447e1, 0xf58ecca981458542, 0x6fd728c2639b9602, 0x65370deb7189245d]),
+  fnv1_1024_test_138: U1024, b"http://slashdot.org/", U1024::from_words([0xb897b5105c6e6e59, 0x7783b1cee11c5a1e, 0xfe09d71e8244825b, 0x086ebe8ecf3bb11e, 0xac09515f785add0a, 0x5b28430000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x78c10a52e7afdaf1, 0x67e608756cd3d314, 0x5361343de4d43872, 0xc6d1c526c07e9b42, 0x50b4d87ed56faff5, 0x3b6e200e8cc7a7b6, 0x4dd28bbd067b3a10]),
+  fnv1_1024_test_139: U1024, b"http://tux.wr.usgs.gov/Maps/155.25-19.5.html", U1024::from_words([0xfeff971726e02d26, 0x8f8b03e04e383370, 0xc5833ffa6fa8edb9, 0x732bc4251b1abc76, 0x62ff416bbafa7bc3, 0xa349ff00000044f7, 0x4980985600aebb81, 0x568c7797e6f9103e, 0xb11dbeadaa581cd1, 0x5b9d11830bc38942, 0xf7324c9e0050b6ca, 0xac0245d1b5d88e91, 0x1d3344c7718d424c, 0x325fd4c0e6332905, 0xefa0b74aa163817d, 0xe63385f10b943c25]),
+  fnv1_1024_test_140: U1024, b"http://volcano.wr.usgs.gov/kilaueastatus.php", U1024::from_words([0xfeff97173a473718, 0xaa66c76d5c773720, 0x592c68894b6fc072, 0x3160219ecfbf45cf, 0x7011e75244a41f86, 0xc938c000000044f7, 0x4980985600aebb81, 0x568c7797e6f9103e, 0xb11dbeadaa581cd1, 0x5b9d11830bc38942, 0xf7324c9e0050b6ca, 0xacd809f689d55d5e, 0xf855bd992e0a59a3, 0xf2437020866c9849, 0x844718e17a13ce1e, 0xa71861e5b0a0a837]),
+  fnv1_1024_test_141: U1024, b"http://www.avo.alaska.edu/activity/Redoubt.php", U1024::from_words([0xb1a40e1d83cb9d49, 0xec5ba05b4ea6b013, 0x1d9fafac5f922b73, 0x49906a05fde4d8c7, 0x813cc88befa840cf, 0x3cb9d100a5db98d3, 0x73db2c6a37cd7f4d, 0x9608df27d07254e1, 0x61b614df2165f94b, 0xcfc260c582d57d0f, 0x064427101c698ffe, 0xba3c5da8b8c841b4, 0x19be8c49baae5c7f, 0xf02cf119d8e11b61, 0x24bbb9b7b11ebe7c, 0x0cea45e1213a1ea5]),
+  fnv1_1024_test_142: U1024, b"http://www.dilbert.com/fast/", U1024::from_words([0x4b99d3efcef51ea9, 0x5709d3e4206b0b80, 0x2ab1ceb792a158b3, 0xc8cf3d519edd5353, 0xc503b32a6a355b66, 0x7609fd0000000000, 0x0000000000000000, 0x000000000000000f, 0xc74cbee6ed33d7d2, 0x390d47dca6702e84, 0x4d01b13b2ca28dd9, 0x8b9e2dfbf81e5c7f, 0x31caaa8368308cbe, 0x9d692e1ad918e683, 0x083e2313ca1e2cd3, 0x77a0a89ea4f0080f]),
+  fnv1_1024_test_143: U1024, b"http://www.fourmilab.ch/gravitation/orbits/", U1024::from_words([0x79f504f407fcc3cc, 0x037aabdd060b9a6f, 0x333c73cf68166b48, 0xc1387444ce70eb50, 0x982480d2dffde3b6, 0x13a481000000002c, 0x78c4ffbd2756340f, 0x2832a6ab43a50f84, 0x596efc9c7ce1ea25, 0xed87cfae6f961834, 0x6681bd2148de06e8, 0xa6cd5340d6d9f331, 0x4441bdeacbb3d9cc, 0x0e15f618989a65f4, 0x18cabbb8194e4cc4, 0xb6dce0ca51424db4]),
+  fnv1_1024_test_144: U1024, b"http://www.fpoa.net/", U1024::from_words([0xb897b5105c6e6e59, 0x7783b1cee11c5a1e, 0xfe09d71e8244825b, 0x086ebe8ecf3b9b3a, 0x4f72153d555c0ff7, 0xfa25320000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x78c10a52e7afdaf1, 0x67e608756cd3d314, 0x5361343de4d43872, 0xc6d1c526c07e9b42, 0x50b4d87ed56faff5, 0x3b6e1d3eccdc2e07, 0x76e5e17f4088d182]),
+  fnv1_1024_test_145: U1024, b"http://www.ioccc.org/index.html", U1024::from_words([0x37d87daab74cc056, 0xee8fee4b7cd2d18e, 0x6608c0a7cee3f5f9, 0x797aaeebe7ae480c, 0xed2095d13977034a, 0x9818a20000000000, 0x0000000000000000, 0x000000003ad89a8b, 0x9dc7b4f7d05eccbf, 0x7d887ca1934c8737, 0x0e457c45d2f8f092, 0x119f5cdef0390838, 0x4ddf5340a46dda45, 0x05700d0130898fdb, 0x61837f38518be1a6, 0x55ad9415966b8ba7]),
+  fnv1_1024_test_146: U1024, b"http://www.isthe.com/cgi-bin/number.cgi", U1024::from_words([0xd14c17925523741b, 0x297ac9c8305cb04b, 0x48c3fdefd1744ffc, 0x665b29094464a4ff, 0x86a828d8ff37ad75, 0xfe46340000000000, 0x00000007b07084e3, 0x14b7197bd1f00ae2, 0xd96a06fe6754a441, 0xc30e92d6b123bc26, 0x5036d5eb512202ab, 0xcc78e6fca9580dab, 0xf16b6f0bc73409e1, 0xe94a6e095cea3320, 0x818d8dca5dac4e29, 0xe0d02165a09a04dd]),
+  fnv1_1024_test_147: U1024, b"http://www.isthe.com/chongo/bio.html", U1024::from_words([0x0c3e0e6058ab11de, 0x070613a91c523b0b, 0x27125a53df92b5b5, 0x2c5e8eac4c92ae31, 0x21d4b7bb6a46dc50, 0x70b3bb0000000000, 0x000000000000020f, 0xcd21cb9cd4925807, 0xef0d26ff2d786d73, 0xe8314b48ca33e792, 0xdd1eb2b9ae7954f0, 0x797df28cf4a8c802, 0x566029f53d35e0e8, 0xb6c7ea0cbaefea4e, 0x3f18bca5c844d342, 0xae0cad28a7448551]),
+  fnv1_1024_test_148: U1024, b"http://www.isthe.com/chongo/index.html", U1024::from_words([0x7af2cb6e23245d45, 0xb8957d9380436512, 0xfdaee660164badfd, 0x8fe2cb65a66735da, 0xd21a1bec71b0b49e, 0xa0b10d0000000000, 0x0000000004f5523a, 0x9b752d33e6172c85, 0x685dc4b15371b452, 0xb46690d02d95b128, 0xb8a00eb9a926c69d, 0xc5d5becefbe9a70c, 0x0a47d8f1d7e088ba, 0xed3e177dc443a73c, 0x1486cd8e8cf6d984, 0x0fcbc9b4dcfb5aeb]),
+  fnv1_1024_test_149: U1024, b"http://www.isthe.com/chongo/src/calc/lucas-calc", U1024::from_words([0xe4f9e27f9f535ea3, 0xcc18502a69f1c77a, 0x3723584ab219ca96, 0x4dbfc619fa43af76, 0x03655ac8b404e316, 0x70106e01358bffea, 0xaae3e0b889ae6b51, 0xabc210be414da184, 0x875e5e06cb239a91, 0x3070124be512f04c, 0xb7b093fc0fb44e06, 0xcf9f7eef773c930a, 0xcb5f65e7589e3fce, 0xc643b6b21c0eef60, 0xb6b92ba3c6acb4cb, 0xe42c34f6368a6e11]),
+  fnv1_1024_test_150: U1024, b"http://www.isthe.com/chongo/tech/astro/venus2004.html", U1024::from_words([0x4a69bb5e69a4ea18, 0x343764aae3182b75, 0x51e970b6a6b9f524, 0x4d0301ef27461bd4, 0x3a972e1cfea733dd, 0x0b9edc77cbdc542e, 0x7b1fe17c54908ead, 0xccbe80a808ba180b, 0x3b135b9995d8c2fc, 0x51f00275fda46c01, 0x563cc4d23572d2e7, 0x271fe6a3adf9381d, 0x937cd6a13bb4ff64, 0xcae960db9c645421, 0x3f8e1efc5444478c, 0x18dc497345818ad5]),
+  fnv1_1024_test_151: U1024, b"http://www.isthe.com/chongo/tech/astro/vita.html", U1024::from_words([0xcbd246b9b3c8b37a, 0xbe475c91ab583fe0, 0x200fba9c75b5da88, 0xdf06fdf147afa646, 0x3d8d653cbccda47a, 0x1647cce00a1bdeeb, 0x03637e2d837c6da7, 0x5bf3f70b45637a85, 0xed57d0890636b328, 0x1dcc5fb23e5ea6f8, 0xdcd57de45a9d008f, 0xfa57d95be8f007bd, 0x62f107c46d68f3a9, 0x770251fd7deb0566, 0xfb353b9faa51c8ac, 0xa0f9bf345f0dc3ba]),
+  fnv1_1024_test_152: U1024, b"http://www.isthe.com/chongo/tech/comp/c/expert.html", U1024::from_words([0xcd5e27a0650e28af, 0x86bb170ef4a73a3e, 0xd6f03daea408c49b, 0x4887b6a687d7d6e9, 0xaa444c3055ffb450, 0x02ea6bdc8f231799, 0x7ee5a78d398cd634, 0x184357f3479e7e2a, 0xcb0c90c4630cfc5e, 0x2425a75eb50c447e, 0x3666291fe4d882eb, 0xc4952da3e9492057, 0xc6a072b51ed43e45, 0x0da8138ba51206cd, 0xbd345aaff8aac2bf, 0x07665283bc1f6fc8]),
+  fnv1_1024_test_153: U1024, b"http://www.isthe.com/chongo/tech/comp/calc/index.html", U1024::from_words([0x4a69bb5e69a4ea18, 0x343764aae3182b75, 0x51e970b6a6b9f3ba, 0xd27bc11b92232939, 0xc2d64c57e747f75d, 0x002bdd77cbdc542e, 0x7b1fe17c54908ead, 0xccbe80a808ba180b, 0x3b135b9995d8c2fc, 0x51f00275fda46c01, 0x563cc4d23572d2e7, 0x271fe6a3adf9381d, 0x937cd6a13bb4ff64, 0xcae960be473ebf5a, 0xad320fd6bb184640, 0x61fb5d78f7a90ff2]),
+  fnv1_1024_test_154: U1024, b"http://www.isthe.com/chongo/tech/comp/fnv/index.html", U1024::from_words([0x53865f814c22bc1d, 0x394319f807c80892, 0x26cdece209ac7074, 0x8c7d0dfdddf5d481, 0xf0cbb82bd29e6a2d, 0x42537b09f96b9909, 0xca24d6023f682ec9, 0xa06f644610c9a85c, 0xe27c808d9b235dfe, 0x0e648ddec40637ba, 0x5c6dc675e3c3079f, 0xdb57c730c667281f, 0x06d1e2e0cf249116, 0x2da64f8efcf48d0e, 0x6a2d29d33664283e, 0x4af8a6105cded4dd]),
+  fnv1_1024_test_155: U1024, b"http://www.isthe.com/chongo/tech/math/number/howhigh.html", U1024::from_words([0x33cbe1f6635e6f01, 0xb50520bc6a59557d, 0xde859fee81d306aa, 0x7d3ed1b8dcf76e51, 0x6a457986172116dc, 0x5474fc739ff54ae4, 0x62343cf1bae4b93e, 0x6e2fa005da0f8af2, 0xcb7553fd793dae39, 0x3eb8c011a37ef39d, 0xb2239685dbc94c88, 0x766fd20b0dda8cf0, 0x73283a3e38283a3b, 0xc8b35a867b0ee2b6, 0xcb2f7dbae818b673, 0x2298f18f8004a339]),
+  fnv1_1024_test_156: U1024, b"http://www.isthe.com/chongo/tech/math/number/number.html", U1024::from_words([0x72610059c4a10875, 0x2f8ce51ae206e402, 0xd60045705e0962f2, 0x77d1689a35bbbd19, 0xa7b347a47c0e09b3, 0x8a78a89689c0c771, 0x6bef5da79f19b988, 0xdccfc1da9d3e9698, 0xcafdb758561190e1, 0xd63e5ea140e53c95, 0x5ab9cd6fe4d35e56, 0xc075a45146f785d6, 0x0be5a9bd180019f0, 0xacad4494a6ad7f9a, 0x9388d32d9df68e89, 0xedc766d884b0cc08]),
+  fnv1_1024_test_157: U1024, b"http://www.isthe.com/chongo/tech/math/prime/mersenne.html", U1024::from_words([0x33cbe1f6635e6f01, 0xb50520bc6a59557d, 0xde859fee81d306a3, 0xa4307cf741fd8e25, 0x525e6fbc37564e1c, 0x91d368739ff54ae4, 0x62343cf1bae4b93e, 0x6e2fa005da0f8af2, 0xcb7553fd793dae39, 0x3eb8c011a37ef39d, 0xb2239685dbc94c88, 0x766fd20b0dda8cf0, 0x73283a3e38283a3b, 0xc8b35a85e3cc0e23, 0x75245939a53fe528, 0x9f2186048b464256]),
+  fnv1_1024_test_158: U1024, b"http://www.isthe.com/chongo/tech/math/prime/mersenne.html#largest", U1024::from_words([0xb4fa2cfea57383fb, 0x13773ec107cd1d83, 0x1e75607588fe3779, 0xe9251bdcc07ebc06, 0x1dc2e1ed13bf0bb1, 0x997acda7d40f1f6e, 0xa539674ebc053b87, 0x55a6cbc0f2c388f8, 0xde37c98d7d2ca154, 0x3c5e842ce5bba5e0, 0xbab61c06ad2ed8f4, 0xb4bdf2e7961f183c, 0x641aaab28a825907, 0xd908891848dd20d5, 0xc4c2ec6c1c3d9199, 0xaf0f3d9c037183e7]),
+  fnv1_1024_test_159: U1024, b"http://www.lavarnd.org/cgi-bin/corpspeak.cgi", U1024::from_words([0xfeff9717304ee438, 0x48b828ef7cec0410, 0x83200c4fa96613d4, 0xafcc1631c6c9b620, 0x7c5e9d8dbf2a468e, 0x735cff00000044f7, 0x4980985600aebb81, 0x568c7797e6f9103e, 0xb11dbeadaa581cd1, 0x5b9d11830bc38942, 0xf7324c9e0050b6ca, 0xac6a1b8d30748648, 0xf954760fb0042d9a, 0xacdfc87f65c0118e, 0xdc75674e6df55002, 0xa413c99c6ab144fa]),
+  fnv1_1024_test_160: U1024, b"http://www.lavarnd.org/cgi-bin/haiku.cgi", U1024::from_words([0xb50339ba80e20ac8, 0xab7897646bd3c98f, 0xffc81d56bda72a5c, 0x7d0e32b718a38b70, 0x88a3e96c304ed092, 0x91e05d0000000000, 0x00000bec9e7e1427, 0x1ff285049140e1cb, 0x296cd8863e42b9fb, 0x7d99b6f0b46ac76a, 0x6509bdecd1be246c, 0x177e35d29d8d33a5, 0x63a473f2c055e058, 0xd68cebd9b01bef94, 0xe62871b7457d4a0b, 0x50c0022878ad9c9e]),
+  fnv1_1024_test_161: U1024, b"http://www.lavarnd.org/cgi-bin/rand-none.cgi", U1024::from_words([0xfeff9717304ee438, 0x48b828ef7cec0410, 0x83200c4fa96613d4, 0xafcc1631c6c95cc9, 0xc3c51d346fabc529, 0x7b165c00000044f7, 0x4980985600aebb81, 0x568c7797e6f9103e, 0xb11dbeadaa581cd1, 0x5b9d11830bc38942, 0xf7324c9e0050b6ca, 0xac6a1b8d30748648, 0xf954760fb0042d9a, 0xacdfc87f65c0118e, 0xdc755bbdedea0a53, 0x64b67b04fffdcc52]),
+  fnv1_1024_test_162: U1024, b"http://www.lavarnd.org/cgi-bin/randdist.cgi", U1024::from_words([0x79f504f407fcc3cc, 0x0380a052fc1a3637, 0x2259c495514a93e3, 0xf452a95b99f54085, 0xd9f442a7b69ae0d7, 0x038551000000002c, 0x78c4ffbd2756340f, 0x2832a6ab43a50f84, 0x596efc9c7ce1ea25, 0xed87cfae6f961834, 0x6681bd2148de06e8, 0xa6cd5340d6da3f88, 0x3e8845f0903dea30, 0xc098bebb52cb9b73, 0x2405744d36c3f98d, 0xb2296e4554165f53]),
+  fnv1_1024_test_163: U1024, b"http://www.lavarnd.org/index.html", U1024::from_words([0x0df51eb4a9c74590, 0x25fa4a3a84cfec1d, 0x199463aac69f7b5a, 0x19b95b650bb51782, 0x62832e6598251d16, 0xea27170000000000, 0x0000000000000000, 0x00008d8534436e35, 0xec95cd2c4c7530fc, 0xc2826f87165a86a1, 0x596ac7ec5045b8c3, 0x7a4fe2459865b03e, 0xe96e40b073e0e7a0, 0xc6a557e322ce3be5, 0x6279277f9f41a893, 0xee181bbeca1ee42c]),
+  fnv1_1024_test_164: U1024, b"http://www.lavarnd.org/what/nist-test.html", U1024::from_words([0xfbc7689b90106f0f, 0xd87d276107a7e813, 0xf36a4950dda053a8, 0xcae30319e580b366, 0x3d2cbbc3ab599d9f, 0x8d62c80000000000, 0x1cad4ff583a54b93, 0xb4a7c8f6c2441fa2, 0xd505703166d7f1c2, 0x30f606539f8e3df7, 0x1ab24d364005b7cb, 0xb59e808839496a8e, 0xda1cb74f46a68612, 0x2d9fa8c78d1ca62c, 0x1d686594299a3f2d, 0xd4fc49934cbdf848]),
+  fnv1_1024_test_165: U1024, b"http://www.macosxhints.com/", U1024::from_words([0x62bd286df22a3bf8, 0x14c20abb52cd242d, 0x8e0f8030c9695236, 0x9acfc3eb06db6d88, 0x8b861123f8d4c7a6, 0x4530df0000000000, 0x0000000000000000, 0x0000000000000000, 0x0a2cafe057016b96, 0x21acd97da76e8cb1, 0x04b53408882c4221, 0x6f43761ef1261f2f, 0x64b849f0dadca803, 0x0f3a4cda05b49e05, 0x71b4525193b595ea, 0xf4825ecfc531f613]),
+  fnv1_1024_test_166: U1024, b"http://www.mellis.com/", U1024::from_words([0x4ccde075cc07bee7, 0x7d2c5d0455b223c8, 0x05d4fc677bdc2157, 0x04a37a7286f755bc, 0x78aa7a29191fbd49, 0x012d350000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000012267, 0x773cfb526a1179f7, 0x352dadf5b7ec2cb9, 0x8fac2d95e3b4ff67, 0x311b3f053a90cea9, 0xbb7b9e7a36714aa9, 0xc3f32c9a32324922, 0x116ae78fe90256ba]),
+  fnv1_1024_test_167: U1024, b"http://www.nature.nps.gov/air/webcams/parks/havoso2alert/havoalert.cfm", U1024::from_words([0x49da04679c08701a, 0x238a977105ebf9b8, 0xabde7803a98de114, 0xddab7897c57746da, 0x4202336f98c9c6ed, 0xba555caff64499f8, 0x85884915f8fd99bb, 0x34529b81f82b0b87, 0x83d6e2aa3362d688, 0x9d34521691bba882, 0x953c097f9395e507, 0xf0c2d32a5376e7a0, 0xe47bff82b518efd9, 0xf786d4afaa56156f, 0xae49ac3125209757, 0xc7b5e274d914b7f0]),
+  fnv1_1024_test_168: U1024, b"http://www.nature.nps.gov/air/webcams/parks/havoso2alert/timelines_24.cfm", U1024::from_words([0x5520d681a2ddb4a8, 0x9905094709db3a25, 0x104a11cc8454c794, 0xd4e878f0fe797155, 0x6835e527c07a87b3, 0x410de2beb3d69bef, 0x0f2970938154baa5, 0x145a8e2ac63a6b4b, 0x7fc6670733f26cb3, 0x3f48d50a650f66f3, 0xe681f2529c8dfc30, 0x8ce94480aaf4c28a, 0x66e45f2e7a236cf2, 0x8b08a69fc1687369, 0xd712f6c647dc1b16, 0x0d2a507cba2f581f]),
+  fnv1_1024_test_169: U1024, b"http://www.paulnoll.com/", U1024::from_words([0xccdbb286521e08c6, 0x95e4e00a08055cb3, 0xa4f1aa5c01f11b72, 0x2ca781978094f482, 0xab039145b276cdc7, 0x513a9f0000000000, 0x0000000000000000, 0x0000000000000000, 0x00000002ba6645c1, 0x3704e638018a64f2, 0xd811240eca5b4ea6, 0x7a2d2dfb240d8217, 0xdf622a258a122f72, 0xbe5337781b281fcf, 0x8baeca574492fc1a, 0xd67deeb71db1a2e1]),
+  fnv1_1024_test_170: U1024, b"http://www.pepysdiary.com/", U1024::from_words([0x38d9e0e27d85cb03, 0xafce0abaa3f63214, 0xdaf661b59b24ebe0, 0x55913a25d9727b58, 0x9fd67e00ea6aa91f, 0x136fd30000000000, 0x0000000000000000, 0x0000000000000000, 0x00068f98bf1737ba, 0x174c86ac7ce00961, 0xd0f055e4f038db69, 0x3149ae6740723b67, 0x4b0e0179d5fdf9f9, 0x8f31278751e89894, 0x1e7f8c930bc90fb1, 0x2c2f0807048bc4b8]),
+  fnv1_1024_test_171: U1024, b"http://www.sciencenews.org/index/home/activity/view", U1024::from_words([0xc9ce84cb9efd55ae, 0x82057837c1fee70a, 0x347f3af110ef47ef, 0x5c55eee4756abed1, 0x3df8047f52ffe568, 0x6a16b9dc8f231799, 0x7ee5a78d398cd634, 0x184357f3479e7e2a, 0xcb0c90c4630cfc5e, 0x2425a75eb50c447e, 0x3666291fe4b43d4f, 0xd8e51f4740bceb54, 0x65c0bb4bd14afd8d, 0xc30fa2ab3d3bcc65, 0xd9a57fc4c8b9530f, 0x2cb2a3e642da02ba]),
+  fnv1_1024_test_172: U1024, b"http://www.skyandtelescope.com/", U1024::from_words([0x37d87daab74cc056, 0xee8fee4b7cd2d18e, 0x6608c0a7cee3e474, 0x4d4a4ccb66b963cc, 0xbb367dd6a4d5ec3f, 0xa95b9c0000000000, 0x0000000000000000, 0x000000003ad89a8b, 0x9dc7b4f7d05eccbf, 0x7d887ca1934c8737, 0x0e457c45d2f8f092, 0x119f5cdef0390838, 0x4ddf5340a46dda45, 0x05700b92b2494ae9, 0x737aca19da486841, 0xdf0d30d4adf0d3d4]),
+  fnv1_1024_test_173: U1024, b"http://www.sput.nl/~rob/sirius.html", U1024::from_words([0x52a3927d90761210, 0xbf30b5b122200f10, 0xeccd2cf9ee912ad4, 0x4ed48c2e4c0c55d5, 0x07905214c6776801, 0xf7c34b0000000000, 0x0000000000000001, 0x54586d7d7e474cf5, 0x0ec9ddec020c8e23, 0xc20926e13b307e53, 0x8dc6afd87ce493e1, 0xa751910de5887c4f, 0xf7309addcbec1d0a, 0x926fe9a6e6417469, 0x1fb5a794bd7f37b0, 0xa0392099ba105c83]),
+  fnv1_1024_test_174: U1024, b"http://www.systemexperts.com/", U1024::from_words([0xe01b846f904e8894, 0x189a17f810ab592a, 0x664a514bcd64a9a7, 0x7d50ac6905bec98d, 0xb60c0e6007f687cd, 0xfef9280000000000, 0x0000000000000000, 0x0000000000001878, 0x12040c1dd965b102, 0x7998712e1bf8232b, 0x6b9fd8c43815fa5d, 0x844d4fbfc7157140, 0x374a6dc8934a439a, 0x1c1c7fa2ab296538, 0x4aa4809d09a12b94, 0x42fde9863d32bb2c]),
+  fnv1_1024_test_175: U1024, b"http://www.tq-international.com/phpBB3/index.php", U1024::from_words([0xcbd246b8dbe28e54, 0xcb2df4e5329e0552, 0x60691d2e67bda1fc, 0x5ace58cc373f8a77, 0x0b2acd31cea1c6f2, 0xf98c1fe00a1bdeeb, 0x03637e2d837c6da7, 0x5bf3f70b45637a85, 0xed57d0890636b328, 0x1dcc5fb23e5ea6f8, 0xdcd57de45a9d008f, 0xf1093cb8936c6435, 0x827f8762915fc46f, 0x88b5d58331355609, 0x21d6853aa5e32025, 0x646e63b4c701671e]),
+  fnv1_1024_test_176: U1024, b"http://www.travelquesttours.com/index.htm", U1024::from_words([0x7424f2515cc08bcf, 0x77366c15bf919ff8, 0xbcb244a6a3fc9701, 0x97986a4f3dd6baa1, 0x9b9038083a235ec8, 0x03d6c50000000000, 0x00127df1c98540ac, 0x8b184815419e280f, 0x3dcbc82e8d7a6b01, 0xc760b347c9973ffe, 0xb01b884143de7ba0, 0x6eb9779e53f9177b, 0x093ee40dc089a223, 0xd32a10bf25985029, 0xea71e2c99845abbc, 0x21297b60156d0400]),
+  fnv1_1024_test_177: U1024, b"http://www.wunderground.com/global/stations/89606.html", U1024::from_words([0x12bc21cedcdef6f5, 0x7111080f4fe95755, 0x8304715398d23bb1, 0xfc7dc4a6d96c5dcf, 0x8679b2fcedf10677, 0xb7a2f3c724ae8c14, 0xf070adcf242d4386, 0x836d84958897496a, 0x9d050d2d6126614b, 0x1133d0fa57fb7e12, 0xbc3ccd7ad0723a63, 0x34b3258e8d2bf8dc, 0x4c597b6d9806d7e7, 0x2b8722aaec8fc648, 0xd9bcf9f2819bcd16, 0xa6c223810bbdb8ce]),
+  fnv1_1024_test_178: U1024, &repeat(b"21701", 10), U1024::from_words([0x2d90386d8df0f572, 0x40067c95ddcff5ee, 0x07d32becf52af7e6, 0x65d077169fcdc1ad, 0xfca5dfc064df00c4, 0xe366942fa0ecbe49, 0x40cda2ebb8917162, 0xca8a2a22ca039f97, 0xa4f1d8628281a511, 0xa5d03317a6c9f6a4, 0xf72a1a481ae3deda, 0x697bd19567f505d2, 0x0fc53897e8d392a8, 0xc3ade1498c40e3b0, 0xbcb674e0977164ce, 0xffc579e4c76215e7]),
+  fnv1_1024_test_179: U1024, &repeat(b"M21701", 10), U1024::from_words([0x1446a07184784c5f, 0x1e4ff6008de7f632, 0xecc4c360af34a90d, 0xe50a46487e9c3014, 0xad371efe909a9116, 0x4ab6bc2d23ab4970, 0x8d0db6151711e0ab, 0x475b27cc67d1a483, 0x4e64dc8cf73199e5, 0xfaa6eda9de1db412, 0x3653bb65c5cf8589, 0xc3b930492b15af78, 0xc81c9116280c32a6, 0x94ad37a57c263e1b, 0x5adabc3f9f298ae7, 0x8797b2b2d8687d13]),
+  fnv1_1024_test_180: U1024, &repeat(b"2^21701-1", 10), U1024::from_words([0xf96f7b5e9855b5a4, 0x0b819db6af6e25ad, 0x2430cb7e07a13d07, 0x792dedb7993197e3, 0xd49f270c79140b80, 0xc77f4627ff82da9e, 0x285fb1933ed0f859, 0x8072ef95a8ed42be, 0xa651e96dc34dfdcc, 0x6f5b36991bb447e2, 0xfff2b72b73bde883, 0x1102d60ff7110c21, 0x5d4a2e41ac0b4ac4, 0x2eedf4a8f7561ce4, 0xf8f9009ff1796532, 0xfed17f0dbaae82ff]),
+  fnv1_1024_test_181: U1024, &repeat(b"\x54\xc5", 10), U1024::from_words([0xb897b5105c6e6e59, 0x7783b1cee11c5a1e, 0xfe09d71e824453db, 0x7bb769dc4c5735ef, 0x42b8d0e14e118012, 0x449cc30000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x78c10a52e7afdaf1, 0x67e608756cd3d314, 0x5361343de4d43872, 0xc6d1c526c07e9b42, 0x50b4d4b3a08ec70f, 0x9611edd04fece834, 0x9978dea0123e39d3]),
+  fnv1_1024_test_182: U1024, &repeat(b"\xc5\x54", 10), U1024::from_words([0xb897b5105c6e6e59, 0x7783b1cee11c5a1e, 0xfe09d71e8243fbbe, 0xf421f7564610c494, 0xf4ba28d09eabbacc, 0xd542ab0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x78c10a52e7afdaf1, 0x67e608756cd3d314, 0x5361343de4d43872, 0xc6d1c526c07e9b42, 0x50b4cd829da9d96e, 0x948fa54d5f6d9a9c, 0x55217c7cdee04813]),
+  fnv1_1024_test_183: U1024, &repeat(b"23209", 10), U1024::from_words([0x4f80fff23a55ba10, 0xebea7c332f819877, 0xfae116c1456ab061, 0x30b8fc1cf04807d7, 0xc243d2eea959b23e, 0x25e4292fa0ecbe49, 0x40cda2ebb8917162, 0xca8a2a22ca039f97, 0xa4f1d8628281a511, 0xa5d03318452c2c94, 0x0175875e4b95a79f, 0xb99279be3dda7755, 0x4b261114c36c0f15, 0xf7c1c33232089566, 0x114d5cfb1b046287, 0xe92dbc5bee0738db]),
+  fnv1_1024_test_184: U1024, &repeat(b"M23209", 10), U1024::from_words([0xbd947b7945ab3382, 0x7c37279474625bd5, 0xd654d9c585acb3ab, 0x506acc90842667fa, 0xe3c5d4297e81a274, 0x623d422d23ab4970, 0x8d0db6151711e0ab, 0x475b27cc67d1a483, 0x4e64fbb6c56735d1, 0x441d7447c67e593e, 0xa937e816eb6a3354, 0x0f63a37f0bdf6288, 0xaa64666011ea5d16, 0x7bf3cd7004f3d2bb, 0x81cccaf95c852f57, 0xcdea94e285d071f3]),
+  fnv1_1024_test_185: U1024, &repeat(b"2^23209-1", 10), U1024::from_words([0x9cb02c907ddc99eb, 0x4e50f157e7b3aaac, 0x5500b28945aa7372, 0x218e7ef6135720e6, 0xbb8bb5aaa024fa70, 0x52f0d72257e329ab, 0x3340811d07fa03a8, 0x8a6dd55684da042f, 0x18f885c083dbf2f1, 0x7eff9c95988fa1f4, 0x154fa6d902a0cf85, 0x7d1147404f306f81, 0xa12f56428fc90c75, 0xa17968ed319d6f98, 0xae65d9df1302def6, 0x456ff414c00d2043]),
+  fnv1_1024_test_186: U1024, &repeat(b"\x5a\xa9", 10), U1024::from_words([0xb897b5105c6e6e59, 0x7783b1cee11c5a1e, 0xfe09d71e82445b3b, 0xed0f48aa79e8b168, 0x8735870afad3d287, 0xbf1d3f0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x78c10a52e7afdaf1, 0x67e608756cd3d314, 0x5361343de4d43872, 0xc6d1c526c07e9b42, 0x50b4d54d44af2352, 0xebe915a04c880ead, 0x96fdc3a15151d31b]),
+  fnv1_1024_test_187: U1024, &repeat(b"\xa9\x5a", 10), U1024::from_words([0xb897b5105c6e6e59, 0x7783b1cee11c5a1e, 0xfe09d71e8243ec25, 0x9841900b66a948ac, 0xe289c3816a4f9df1, 0xbfa56f0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x78c10a52e7afdaf1, 0x67e608756cd3d314, 0x5361343de4d43872, 0xc6d1c526c07e9b42, 0x50b4cc3caed83d8f, 0x0052b2e5b68dda7c, 0x83e6b8d28ce0be9b]),
+  fnv1_1024_test_188: U1024, &repeat(b"391581216093", 10), U1024::from_words([0x8a107acbabc86b8d, 0xad4a0352273322be, 0x67399d3eb589f972, 0x66d281b432065635, 0x69a80d04c40b64b2, 0xf9eb37ded084e28d, 0x2ba5fd7585a09911, 0x44bea8a1078b547a, 0x3a36475f85ed438a, 0x8167a09aac54868d, 0x49a83b254b0b400d, 0x76accac2c078addf, 0x66901a9bf1dc131b, 0x08a200185d70b4a5, 0xae43fcc9a5e1038e, 0x842f88c6d073057b]),
+  fnv1_1024_test_189: U1024, &repeat(b"391581*2^216093-1", 10), U1024::from_words([0xf7c2945ee4a1147d, 0x5c4bf0fee65112cd, 0x64ea7f9f501747ba, 0xfec244819df3a49d, 0xee77988214a36f29, 0xc01cfe181406d298, 0x63fd7e5b767ba709, 0x34f0a692fb229d2f, 0x361cca7b89fa3ae7, 0xee5b5021fada147b, 0xba5181d4c72bcc65, 0xce01a86d864a1af2, 0xcdcf4a9cb5c26e5f, 0x62654b6eb3e40db8, 0x2c805962bbf3eea5, 0xb3dec114e6813e2b]),
+  fnv1_1024_test_190: U1024, &repeat(b"\x05\xf9\x9d\x03\x4c\x81", 10), U1024::from_words([0x3cb906cd01aa3219, 0x40c8217f96ca5396, 0xe020a5b2f31bda93, 0x2d1bac602b1e982d, 0x2a52ac5ec192a25a, 0x2755322d23ab4970, 0x8d0db6151711e0ab, 0x475b27cc67d1a477, 0xdf378b4a6d03552e, 0x56c4ccfb33f85515, 0x82052520e819034c, 0xadce912c514cd0d5, 0x1f70518dae03e745, 0x60ca57dca769a748, 0xbe97d4fd154d4b26, 0xdc447086ce4e89a3]),
+  fnv1_1024_test_191: U1024, &repeat(b"FEDCBA9876543210", 10), U1024::from_words([0x5f7eecf45cb09108, 0x8374a790a4ead4f3, 0x8802963d9e87b82e, 0xdce601e51c059ca0, 0xcd8350d559e84216, 0x44729f5077d5c8d1, 0x5eaad239e7ec4d66, 0x005add07fd05bc0e, 0xa4aa0acb92c3288e, 0xd5b2d20849669f0f, 0x72b6037d7023662a, 0xd304f0afc79abbd6, 0xa7a9a66c088a2c6b, 0xbdeef124dc4e8614, 0x74b2600a81ae7d99, 0x86fe8bd2c5d09a43]),
+  fnv1_1024_test_192: U1024, &repeat(b"\xfe\xdc\xba\x98\x76\x54\x32\x10", 10), U1024::from_words([0x5203959baafef8d7, 0xea1e8ed0eda4438d, 0x3d443145f6bdfc0f, 0xaa6ca62de9274661, 0xd8fae4c69f861ab7, 0x1e2c6057b44d1762, 0xb6757c75239a7b6f, 0x823205b843001754, 0xfdae3cfe41824da3, 0x84f8aa8e8f3e8d18, 0x5ed0aa624d14ca7b, 0x40706f474f6e9771, 0x591450e1df97b0f6, 0x34dce548481ff251, 0xef87905147fd794a, 0x0432d4947d21b4d3]),
+  fnv1_1024_test_193: U1024, &repeat(b"EFCDAB8967452301", 10), U1024::from_words([0x0fab8c67c76c3eef, 0x88f1bbb01ad1032f, 0xdb1416e8ac08e343, 0x361b94e8fee23821, 0x296e05ed123c3a18, 0xf7b8c5ed7cba4754, 0xceb3b451288741fb, 0x0dcf3281dafc726d, 0xdd79141472107e70, 0xacf4f889a5532e6d, 0xc29d9b70464c0b57, 0x5e8114098304d999, 0xf31e2e31ba946716, 0x35991c6ce13ba6f1, 0x7104fe83bf1c47d2, 0x40de2f6479722d03]),
+  fnv1_1024_test_194: U1024, &repeat(b"\xef\xcd\xab\x89\x67\x45\x23\x01", 10), U1024::from_words([0xe3fb576afb6847c8, 0x0326b9cf57868d5f, 0x1bf6e26be8cc9d9b, 0x1ffbdea2a1d7c438, 0xc0be9321ccebaeda, 0x6880789ae29f3059, 0x0d3b6ef93424f591, 0x9235d3e7599384e0, 0x4ccd20d0c9efa02d, 0xfb764122e9219db2, 0x58890d373fc3f0ed, 0x45a85c38a60619f3, 0x20a4de1c99661703, 0x831f93d07663730d, 0x0f2188eaacd430b8, 0x09befcc52ce5e8b3]),
+  fnv1_1024_test_195: U1024, &repeat(b"0123456789ABCDEF", 10), U1024::from_words([0xf6a6923c44aeca05, 0xa02964f2affb9737, 0xd00b1f475cb83e0c, 0x5a3df42aeb2aad57, 0xce92f67737baed3a, 0xf6c77acaed0f1a61, 0xa95e53d77f7644a8, 0xc1d6794762156a68, 0xed73313aeb8daa4b, 0x35bde830747fca49, 0xd7afade861e9fc0c, 0x32917e34c8a046a9, 0x7b2b6c0ce6146724, 0x69fd41a1938a532b, 0x0cb2d3eea2bdd2b8, 0xba25f9fad1b0d363]),
+  fnv1_1024_test_196: U1024, &repeat(b"\x01\x23\x45\x67\x89\xab\xcd\xef", 10), U1024::from_words([0xe6d8f1e997a998e3, 0x4d233254be5cf320, 0xcaec7aaabccdc41c, 0x0ac56ea672d6635c, 0x8bf2cbe8e8845d61, 0xa3b92f8e1c275630, 0x93cf9871603f4017, 0xc393b77eecdeb8ec, 0x657694f694afb1cc, 0x0747f4e386d41bbf, 0x808f5fabaf6fcd58, 0xc5e2ef4113e2714d, 0x114579bdf1a0882e, 0x23403a9ec16d27e3, 0xd7e6689713094b1d, 0x4c618371fe6d6c73]),
+  fnv1_1024_test_197: U1024, &repeat(b"1032547698BADCFE", 10), U1024::from_words([0x195eda3b6d407962, 0x20a1d3f8220957be, 0xaace030a999e6995, 0x2ab28767b3218c5e, 0x5dc3cbedb93f2c1b, 0x5b6ce0e62130cfac, 0x10f83ed3c2c1c331, 0xdd5a4c942874d3b4, 0x60bac510d6081b01, 0x92d36026d254c14c, 0x971a92fc8b314e4d, 0x64c75326f45fa19e, 0xb5f57723add30228, 0xd66f4d0b57a2e6bb, 0xb2dd51847eeb6f9f, 0xb8ab073039d0a963]),
+  fnv1_1024_test_198: U1024, &repeat(b"\x10\x32\x54\x76\x98\xba\xdc\xfe", 10), U1024::from_words([0xaf201842f096d1b5, 0xed72cf74229e0e91, 0xb4f51c52f1598e44, 0x049d5ec94edcd712, 0x84ab5bb3d60458a9, 0x905a1b1a6cf5e81b, 0x0f96356b13c2a784, 0x93a06b9e4c18ab48, 0x40abcf294334d533, 0x43213b7c4f8a511c, 0xe26f45e2510e9553, 0x92b591e69cddba87, 0xe33afe6910b4ad0a, 0x965f3648b865984e, 0x2e99e69951d3205d, 0x40af6168cff16953]),
+  fnv1_1024_test_199: U1024, &repeat(b"\x00", 500), U1024::from_words([0x09b7ee4023569c0e, 0x8aee21336dd6e667, 0x05a02e53303ff68a, 0x850e159a2318046b, 0xd506a0b2eed1a0bf, 0x72c496c66b27088d, 0x8024aa13ff025604, 0x93b3a40af1be87f5, 0x41131ff1e8e001d0, 0xc72e803b8f0c3fd8, 0x81a49227e0babae4, 0x3bf4c4a421e3205d, 0x8d2a6a3d8812a5df, 0x2e1e852b4a6a3830, 0x7340980ab656984a, 0x31a272a7f84487a3]),
+  fnv1_1024_test_200: U1024, &repeat(b"\x07", 500), U1024::from_words([0xa87278550472dd09, 0x129089158c204d2e, 0x03f3ef5bae0556b6, 0xf00ee6a700ad0f25, 0x351a2a77a6c80af4, 0x4f767c9f499e7b4b, 0x211b16f609a9102d, 0x384551a163110c7b, 0xcfe6c530a9023c52, 0x0ab21e807c478f36, 0xbf6f24a1aba0b7b6, 0xac63619b0b15689f, 0x18acd909bb09db29, 0x74b584d0d590521c, 0x4a8a39087e239d31, 0xb567c754733685db]),
+  fnv1_1024_test_201: U1024, &repeat(b"~", 500), U1024::from_words([0x0595bdde211e9f2e, 0x433bf5d5030d6518, 0xc1fee39ce581793a, 0xf38633e7952461a3, 0x161e2210cd2c1429, 0xa43bb91aed5142bd, 0x50686a630cfe10de, 0x7797beeb96f38eb5, 0x0fa39055f12ba083, 0x6f230b0433f3f428, 0xdba9cd2ded0a58a4, 0xcb3cc77ddd294a13, 0xde2a41c4daa45869, 0xd369f18a85405d2a, 0xdacf1b61e2c18e52, 0x643d5279d2c1bfa3]),
+  fnv1_1024_test_202: U1024, &repeat(b"\x7f", 500), U1024::from_words([0x49e780f59473425d, 0x46a4116e92ac83d7, 0xe6c76cdb37c1c969, 0xf379d5be62b66de2, 0x19d39d7ec0d3a478, 0x3c2e5f2b8834453c, 0x1a899f1f82e754a2, 0xe996a06e48c19ac1, 0x85bd3a8416295be2, 0xb89e849fc62a164e, 0xa8837aed411b9e03, 0xead400952e0a4160, 0xb044223f797171cc, 0x91d3863c26f7cad1, 0x8f53423a6757cc2d, 0x7b0097a8944a7dbb]),
 }
 fnv1a_tests!{
   fnv1a_32_test_0: u32, b"", 0x811c9dc5,
@@ -1222,6 +2846,816 @@ fnv1a_tests!{
   fnv1a_64_test_200: u64, &repeat(b"\x07", 500), 0xc23e9fccd6f70591,
   fnv1a_64_test_201: u64, &repeat(b"~", 500), 0xc1af12bdfe16b5b5,
   fnv1a_64_test_202: u64, &repeat(b"\x7f", 500), 0x39e9f18f2f85e221,
+  fnv1a_128_test_0: u128, b"", 0x6c62272e07bb014262b821756295c58d,
+  fnv1a_128_test_1: u128, b"a", 0xd228cb696f1a8caf78912b704e4a8964,
+  fnv1a_128_test_2: u128, b"b", 0xd228cb69721a8caf78912b704e4a8d15,
+  fnv1a_128_test_3: u128, b"c", 0xd228cb69711a8caf78912b704e4a8bda,
+  fnv1a_128_test_4: u128, b"d", 0xd228cb696c1a8caf78912b704e4a85b3,
+  fnv1a_128_test_5: u128, b"e", 0xd228cb696b1a8caf78912b704e4a8478,
+  fnv1a_128_test_6: u128, b"f", 0xd228cb696e1a8caf78912b704e4a8829,
+  fnv1a_128_test_7: u128, b"fo", 0x08809542c0ab1be95aa0733055b5ae22,
+  fnv1a_128_test_8: u128, b"foo", 0xa68d5ed15f8b5822836dbc79768d78bf,
+  fnv1a_128_test_9: u128, b"foob", 0x696a39196d757277b806e974e013b7ef,
+  fnv1a_128_test_10: u128, b"fooba", 0x2a9456013d83d94f708142cfb842dbba,
+  fnv1a_128_test_11: u128, b"foobar", 0x343e1662793c64bf6f0d3597ba446f18,
+  fnv1a_128_test_12: u128, ::std::ffi::CString::new("").unwrap().as_bytes_with_nul(), 0xd228cb69101a8caf78912b704e4a147f,
+  fnv1a_128_test_13: u128, ::std::ffi::CString::new("a").unwrap().as_bytes_with_nul(), 0x0880954519ab1be95aa0733055b70e0c,
+  fnv1a_128_test_14: u128, ::std::ffi::CString::new("b").unwrap().as_bytes_with_nul(), 0x0880954c7bab1be95aa0733055bb98d7,
+  fnv1a_128_test_15: u128, ::std::ffi::CString::new("c").unwrap().as_bytes_with_nul(), 0x0880954a05ab1be95aa0733055ba153e,
+  fnv1a_128_test_16: u128, ::std::ffi::CString::new("d").unwrap().as_bytes_with_nul(), 0x0880953db7ab1be95aa0733055b28341,
+  fnv1a_128_test_17: u128, ::std::ffi::CString::new("e").unwrap().as_bytes_with_nul(), 0x0880953b41ab1be95aa0733055b0ffa8,
+  fnv1a_128_test_18: u128, ::std::ffi::CString::new("f").unwrap().as_bytes_with_nul(), 0x08809542a3ab1be95aa0733055b58a73,
+  fnv1a_128_test_19: u128, ::std::ffi::CString::new("fo").unwrap().as_bytes_with_nul(), 0xa68d5ed1348b5822836dbc79768d43d6,
+  fnv1a_128_test_20: u128, ::std::ffi::CString::new("foo").unwrap().as_bytes_with_nul(), 0x696a39194f757277b806e974e0139305,
+  fnv1a_128_test_21: u128, ::std::ffi::CString::new("foob").unwrap().as_bytes_with_nul(), 0x2a9456019e83d94f708142cfb8435315,
+  fnv1a_128_test_22: u128, ::std::ffi::CString::new("fooba").unwrap().as_bytes_with_nul(), 0x343e16626b3c64bf6f0d3597ba445dde,
+  fnv1a_128_test_23: u128, ::std::ffi::CString::new("foobar").unwrap().as_bytes_with_nul(), 0xe01fcf9a454ff78da540f1b23234b288,
+  fnv1a_128_test_24: u128, b"ch", 0x08809549ddab1be95aa0733055b9e406,
+  fnv1a_128_test_25: u128, b"cho", 0xa68d6bc82a8b5822836dbc797bbc0d33,
+  fnv1a_128_test_26: u128, b"chon", 0x697f5b59b6757277b806e97b4064716f,
+  fnv1a_128_test_27: u128, b"chong", 0x4af5cbd48a83d94f70814aa83b9714d8,
+  fnv1a_128_test_28: u128, b"chongo", 0xe4ad659b273c64bf6f16dd0152e67d2d,
+  fnv1a_128_test_29: u128, b"chongo ", 0x62aeec66544ff78db121f0a1019bdeff,
+  fnv1a_128_test_30: u128, b"chongo w", 0x0e3e7dc846659b58f4c3161cfacad158,
+  fnv1a_128_test_31: u128, b"chongo wa", 0xa3df903fd80626752c0c35a8978f7123,
+  fnv1a_128_test_32: u128, b"chongo was", 0x4cae0e001f91522d330606727d806d70,
+  fnv1a_128_test_33: u128, b"chongo was ", 0xcca8ba9427d01d9dc869eee06d068170,
+  fnv1a_128_test_34: u128, b"chongo was h", 0xb40a9ace151471259a58ee262700d888,
+  fnv1a_128_test_35: u128, b"chongo was he", 0xaf337c6cdd273944eb6d08f1fe0aeb9f,
+  fnv1a_128_test_36: u128, b"chongo was her", 0x865824e00c4377cdaf2a01c397704c9f,
+  fnv1a_128_test_37: u128, b"chongo was here", 0x120ccffc11046a1688b02bab572eb79e,
+  fnv1a_128_test_38: u128, b"chongo was here!", 0xe11b19e0af6e89ba30c5bbd4467c1805,
+  fnv1a_128_test_39: u128, b"chongo was here!\n", 0xd09f538fec03781a034e1e32bab19a75,
+  fnv1a_128_test_40: u128, ::std::ffi::CString::new("ch").unwrap().as_bytes_with_nul(), 0xa68d6bc7c78b5822836dbc797bbb9362,
+  fnv1a_128_test_41: u128, ::std::ffi::CString::new("cho").unwrap().as_bytes_with_nul(), 0x697f5b598c757277b806e97b40643dc1,
+  fnv1a_128_test_42: u128, ::std::ffi::CString::new("chon").unwrap().as_bytes_with_nul(), 0x4af5cbd4f183d94f70814aa83b979395,
+  fnv1a_128_test_43: u128, ::std::ffi::CString::new("chong").unwrap().as_bytes_with_nul(), 0xe4ad659b483c64bf6f16dd0152e6a5c8,
+  fnv1a_128_test_44: u128, ::std::ffi::CString::new("chongo").unwrap().as_bytes_with_nul(), 0x62aeec66744ff78db121f0a1019c065f,
+  fnv1a_128_test_45: u128, ::std::ffi::CString::new("chongo ").unwrap().as_bytes_with_nul(), 0x0e3e7dc8bd659b58f4c3161cfacb63c5,
+  fnv1a_128_test_46: u128, ::std::ffi::CString::new("chongo w").unwrap().as_bytes_with_nul(), 0xa3df903ff70626752c0c35a8978f9748,
+  fnv1a_128_test_47: u128, ::std::ffi::CString::new("chongo wa").unwrap().as_bytes_with_nul(), 0x4cae0dfff291522d330606727d803611,
+  fnv1a_128_test_48: u128, ::std::ffi::CString::new("chongo was").unwrap().as_bytes_with_nul(), 0xcca8ba9447d01d9dc869eee06d06a8d0,
+  fnv1a_128_test_49: u128, ::std::ffi::CString::new("chongo was ").unwrap().as_bytes_with_nul(), 0xb40a9ace6d1471259a58ee26270144d0,
+  fnv1a_128_test_50: u128, ::std::ffi::CString::new("chongo was h").unwrap().as_bytes_with_nul(), 0xaf337c6c78273944eb6d08f1fe0a6f58,
+  fnv1a_128_test_51: u128, ::std::ffi::CString::new("chongo was he").unwrap().as_bytes_with_nul(), 0x865824dfbe4377cdaf2a01c3976feca5,
+  fnv1a_128_test_52: u128, ::std::ffi::CString::new("chongo was her").unwrap().as_bytes_with_nul(), 0x120ccffbb6046a1688b02bab572e47a5,
+  fnv1a_128_test_53: u128, ::std::ffi::CString::new("chongo was here").unwrap().as_bytes_with_nul(), 0xe11b19e08e6e89ba30c5bbd4467bef6a,
+  fnv1a_128_test_54: u128, ::std::ffi::CString::new("chongo was here!").unwrap().as_bytes_with_nul(), 0xd09f538fe203781a034e1e32bab18e27,
+  fnv1a_128_test_55: u128, ::std::ffi::CString::new("chongo was here!\n").unwrap().as_bytes_with_nul(), 0xe6c683b1dd44c802111f286bb8890df7,
+  fnv1a_128_test_56: u128, b"cu", 0x08809549daab1be95aa0733055b9e055,
+  fnv1a_128_test_57: u128, b"cur", 0xa68d6bc0378b5822836dbc797bb6cffd,
+  fnv1a_128_test_58: u128, b"curd", 0x697f4c53f1757277b806e97b39f17143,
+  fnv1a_128_test_59: u128, b"curds", 0x4adcdcbb4b83d94f70814aa04c164610,
+  fnv1a_128_test_60: u128, b"curds ", 0xbe0fb0bc1b3c64bf6f16d33d9f685d10,
+  fnv1a_128_test_61: u128, b"curds a", 0x1aeddfd2f44ff78db115ecd3256afa0b,
+  fnv1a_128_test_62: u128, b"curds an", 0xf5d7d38d03659b58e5fa67cf0aa21a47,
+  fnv1a_128_test_63: u128, b"curds and", 0x4f9bf09d51062662fb1dbbc215762911,
+  fnv1a_128_test_64: u128, b"curds and ", 0xb6f687bbe3913bcafd9607d06864af4b,
+  fnv1a_128_test_65: u128, b"curds and w", 0xf1c168e03fb492c6079b9d7073e39ed4,
+  fnv1a_128_test_66: u128, b"curds and wh", 0xe96fef8d1f3099ab5c7ab95e99145154,
+  fnv1a_128_test_67: u128, b"curds and whe", 0x9b54d6f691cd15dacb0217665bffe74b,
+  fnv1a_128_test_68: u128, b"curds and whey", 0x87c0814c9959e437cb92caf333e17a86,
+  fnv1a_128_test_69: u128, b"curds and whey\n", 0xfd12fabb3d9bd0a77d9fb940d671ca44,
+  fnv1a_128_test_70: u128, ::std::ffi::CString::new("cu").unwrap().as_bytes_with_nul(), 0xa68d6bc0658b5822836dbc797bb70897,
+  fnv1a_128_test_71: u128, ::std::ffi::CString::new("cur").unwrap().as_bytes_with_nul(), 0x697f4c5455757277b806e97b39f1ec4f,
+  fnv1a_128_test_72: u128, ::std::ffi::CString::new("curd").unwrap().as_bytes_with_nul(), 0x4adcdcbb5e83d94f70814aa04c165d71,
+  fnv1a_128_test_73: u128, ::std::ffi::CString::new("curds").unwrap().as_bytes_with_nul(), 0xbe0fb0bbfb3c64bf6f16d33d9f6835b0,
+  fnv1a_128_test_74: u128, ::std::ffi::CString::new("curds ").unwrap().as_bytes_with_nul(), 0x1aeddfd2934ff78db115ecd3256a82b0,
+  fnv1a_128_test_75: u128, ::std::ffi::CString::new("curds a").unwrap().as_bytes_with_nul(), 0xf5d7d38ca9659b58e5fa67cf0aa1ab89,
+  fnv1a_128_test_76: u128, ::std::ffi::CString::new("curds an").unwrap().as_bytes_with_nul(), 0x4f9bf09d75062662fb1dbbc21576555d,
+  fnv1a_128_test_77: u128, ::std::ffi::CString::new("curds and").unwrap().as_bytes_with_nul(), 0xb6f687bbc3913bcafd9607d0686487eb,
+  fnv1a_128_test_78: u128, ::std::ffi::CString::new("curds and ").unwrap().as_bytes_with_nul(), 0xf1c168e04eb492c6079b9d7073e3b149,
+  fnv1a_128_test_79: u128, ::std::ffi::CString::new("curds and w").unwrap().as_bytes_with_nul(), 0xe96fef8d373099ab5c7ab95e99146edc,
+  fnv1a_128_test_80: u128, ::std::ffi::CString::new("curds and wh").unwrap().as_bytes_with_nul(), 0x9b54d6f6b4cd15dacb0217665c00125c,
+  fnv1a_128_test_81: u128, ::std::ffi::CString::new("curds and whe").unwrap().as_bytes_with_nul(), 0x87c0814cb259e437cb92caf333e19949,
+  fnv1a_128_test_82: u128, ::std::ffi::CString::new("curds and whey").unwrap().as_bytes_with_nul(), 0xfd12fabb379bd0a77d9fb940d671c2e2,
+  fnv1a_128_test_83: u128, ::std::ffi::CString::new("curds and whey\n").unwrap().as_bytes_with_nul(), 0xa730f62f12b9be179388f2c7de03e1ac,
+  fnv1a_128_test_84: u128, b"hi", 0x08809533c6ab1be95aa0733055ac561a,
+  fnv1a_128_test_85: u128, ::std::ffi::CString::new("hi").unwrap().as_bytes_with_nul(), 0xa68d430b8e8b5822836dbc796b0df1fe,
+  fnv1a_128_test_86: u128, b"hello", 0xe3e1efd54283d94f7081314b599d31b3,
+  fnv1a_128_test_87: u128, ::std::ffi::CString::new("hello").unwrap().as_bytes_with_nul(), 0xb25bb89a8b3c64bf6ef7a7b7446c2741,
+  fnv1a_128_test_88: u128, b"\xff\x00\x00\x01", 0x65e40463d3757277b806e85f49ba7a8d,
+  fnv1a_128_test_89: u128, b"\x01\x00\x00\xff", 0x66a5c4c6c1757277b806e89ae3a8a4f9,
+  fnv1a_128_test_90: u128, b"\xff\x00\x00\x02", 0x65e40463d0757277b806e85f49ba76dc,
+  fnv1a_128_test_91: u128, b"\x02\x00\x00\xff", 0x66bc1fe653757277b806e8a1c43100a6,
+  fnv1a_128_test_92: u128, b"\xff\x00\x00\x03", 0x65e40463d1757277b806e85f49ba7817,
+  fnv1a_128_test_93: u128, b"\x03\x00\x00\xff", 0x66b4ac31cd757277b806e89f7959b417,
+  fnv1a_128_test_94: u128, b"\xff\x00\x00\x04", 0x65e40463ce757277b806e85f49ba7466,
+  fnv1a_128_test_95: u128, b"\x04\x00\x00\xff", 0x668f69a72f757277b806e8940320494c,
+  fnv1a_128_test_96: u128, b"\x40\x51\x4e\x44", 0x688afa8e53757277b806e9303ba329c8,
+  fnv1a_128_test_97: u128, b"\x44\x4e\x51\x40", 0x686b6266be757277b806e92654fb658e,
+  fnv1a_128_test_98: u128, b"\x40\x51\x4e\x4a", 0x688afa8e51757277b806e9303ba32752,
+  fnv1a_128_test_99: u128, b"\x4a\x4e\x51\x40", 0x685c1ee9ca757277b806e92199472cf0,
+  fnv1a_128_test_100: u128, b"\x40\x51\x4e\x54", 0x688afa8e43757277b806e9303ba31618,
+  fnv1a_128_test_101: u128, b"\x54\x4e\x51\x40", 0x68e4e2cdbe757277b806e94bf0c00d7e,
+  fnv1a_128_test_102: u128, b"127.0.0.1", 0xa78a10ffe104e4586dcbbf0007dd9276,
+  fnv1a_128_test_103: u128, ::std::ffi::CString::new("127.0.0.1").unwrap().as_bytes_with_nul(), 0x26eac86c5704f8cf19b40509ada33732,
+  fnv1a_128_test_104: u128, b"127.0.0.2", 0xa78a10ffe004e4586dcbbf0007dd913b,
+  fnv1a_128_test_105: u128, ::std::ffi::CString::new("127.0.0.2").unwrap().as_bytes_with_nul(), 0x26eac869e104f8cf19b40509ada1b399,
+  fnv1a_128_test_106: u128, b"127.0.0.3", 0xa78a10ffdf04e4586dcbbf0007dd9000,
+  fnv1a_128_test_107: u128, ::std::ffi::CString::new("127.0.0.3").unwrap().as_bytes_with_nul(), 0x26eac8676b04f8cf19b40509ada03000,
+  fnv1a_128_test_108: u128, b"64.81.78.68", 0x08e84cfdac6308366d3af9f697eb3f07,
+  fnv1a_128_test_109: u128, ::std::ffi::CString::new("64.81.78.68").unwrap().as_bytes_with_nul(), 0xec6ea76224db1af86791926cee768d9d,
+  fnv1a_128_test_110: u128, b"64.81.78.74", 0x08e84cfb226308366d3af9f697e9a2d2,
+  fnv1a_128_test_111: u128, ::std::ffi::CString::new("64.81.78.74").unwrap().as_bytes_with_nul(), 0xec6ea2a621db1af86791926cec7b5866,
+  fnv1a_128_test_112: u128, b"64.81.78.84", 0x08e84d02a46308366d3af9f697ee54fd,
+  fnv1a_128_test_113: u128, ::std::ffi::CString::new("64.81.78.84").unwrap().as_bytes_with_nul(), 0xec6eb09542db1af86791926cf242934f,
+  fnv1a_128_test_114: u128, b"feedface", 0x9f4a1091e1659b4a713606714f2d9b0c,
+  fnv1a_128_test_115: u128, ::std::ffi::CString::new("feedface").unwrap().as_bytes_with_nul(), 0x7171911b640614994d79ed6c6d1dc7c4,
+  fnv1a_128_test_116: u128, b"feedfacedaffdeed", 0x3066e5f812e6e8d50b5f85f1e9658b31,
+  fnv1a_128_test_117: u128, ::std::ffi::CString::new("feedfacedaffdeed").unwrap().as_bytes_with_nul(), 0x80865dca73207e24fe89d0aa2ff2454b,
+  fnv1a_128_test_118: u128, b"feedfacedeadbeef", 0xbf2351b922e6e8d51d4660d73419f758,
+  fnv1a_128_test_119: u128, ::std::ffi::CString::new("feedfacedeadbeef").unwrap().as_bytes_with_nul(), 0x07a9a8c54a207e3b059928cd1bf35948,
+  fnv1a_128_test_120: u128, b"line 1\nline 2\nline 3", 0xcdb92c5b9d37edc377f61c7a33cc7feb,
+  fnv1a_128_test_121: u128, b"chongo <Landon Curt Noll> /\\../\\", 0x51392a3b0d394195533727930c41b74e,
+  fnv1a_128_test_122: u128, ::std::ffi::CString::new("chongo <Landon Curt Noll> /\\../\\").unwrap().as_bytes_with_nul(), 0x846338609373b2bd64ddb1f014dc8cfa,
+  fnv1a_128_test_123: u128, b"chongo (Landon Curt Noll) /\\../\\", 0x20e57627f7df6fe0f82c8c66cea98d49,
+  fnv1a_128_test_124: u128, ::std::ffi::CString::new("chongo (Landon Curt Noll) /\\../\\").unwrap().as_bytes_with_nul(), 0xe1270cbb48eea9d15ed0c2804aa0d8d3,
+  fnv1a_128_test_125: u128, b"http://antwrp.gsfc.nasa.gov/apod/astropix.html", 0xcb996ba058e21efa841045a26e2be5a2,
+  fnv1a_128_test_126: u128, b"http://en.wikipedia.org/wiki/Fowler_Noll_Vo_hash", 0x6fb64901feb7e15ca8df708d34c01565,
+  fnv1a_128_test_127: u128, b"http://epod.usra.edu/", 0xab5532893a83543916960b03ce9d93ab,
+  fnv1a_128_test_128: u128, b"http://exoplanet.eu/", 0xa6d52a583917c0e240742a840e9652fc,
+  fnv1a_128_test_129: u128, b"http://hvo.wr.usgs.gov/cam3/", 0x32fc857a5a19b1070fd32dca8c8a7567,
+  fnv1a_128_test_130: u128, b"http://hvo.wr.usgs.gov/cams/HMcam/", 0x4ee7a48da43a8bee7bf35f989d7f8488,
+  fnv1a_128_test_131: u128, b"http://hvo.wr.usgs.gov/kilauea/update/deformation.html", 0x9fcef5521e8e36e11cdb31a8ea925e62,
+  fnv1a_128_test_132: u128, b"http://hvo.wr.usgs.gov/kilauea/update/images.html", 0x69009d9f84cfdcb9c3d888f3653231e4,
+  fnv1a_128_test_133: u128, b"http://hvo.wr.usgs.gov/kilauea/update/maps.html", 0xf7268aa9ec891ff94f05f270b4b1b2d3,
+  fnv1a_128_test_134: u128, b"http://hvo.wr.usgs.gov/volcanowatch/current_issue.html", 0x683bda7ea755128c291735dcc9f83d30,
+  fnv1a_128_test_135: u128, b"http://neo.jpl.nasa.gov/risk/", 0x9d993e04db251f374afafb1a5fbcd243,
+  fnv1a_128_test_136: u128, b"http://norvig.com/21-days.html", 0x325e7309a368d47634a75f0ad64caba2,
+  fnv1a_128_test_137: u128, b"http://primes.utm.edu/curios/home.php", 0xf4c8fdfaf15b6b6ccdac63b4930a174d,
+  fnv1a_128_test_138: u128, b"http://slashdot.org/", 0x51d7f824b2ac5b528a78fbdc500490fc,
+  fnv1a_128_test_139: u128, b"http://tux.wr.usgs.gov/Maps/155.25-19.5.html", 0xdabe84f531ce62be1d9f07b04bda1ccf,
+  fnv1a_128_test_140: u128, b"http://volcano.wr.usgs.gov/kilaueastatus.php", 0xab40cd571ad1f6907bc230e279302e43,
+  fnv1a_128_test_141: u128, b"http://www.avo.alaska.edu/activity/Redoubt.php", 0x5e0be1cc92d3d49c47099b197eae1783,
+  fnv1a_128_test_142: u128, b"http://www.dilbert.com/fast/", 0x6850ad22a57a16afded2ed6631978e61,
+  fnv1a_128_test_143: u128, b"http://www.fourmilab.ch/gravitation/orbits/", 0xa0b3252b83215c6b55a19465c8698d58,
+  fnv1a_128_test_144: u128, b"http://www.fpoa.net/", 0xd0c3ea6b50d8a57f9dcac72b7c33a630,
+  fnv1a_128_test_145: u128, b"http://www.ioccc.org/index.html", 0xa22621e1453dc4a76f22ddfef32da153,
+  fnv1a_128_test_146: u128, b"http://www.isthe.com/cgi-bin/number.cgi", 0x16b277f872b13555b7c3722100686d15,
+  fnv1a_128_test_147: u128, b"http://www.isthe.com/chongo/bio.html", 0xa2ad7c6ac166be698e75acdea8dd1adf,
+  fnv1a_128_test_148: u128, b"http://www.isthe.com/chongo/index.html", 0x2aaa4534f8979677066c0087a9cd9b31,
+  fnv1a_128_test_149: u128, b"http://www.isthe.com/chongo/src/calc/lucas-calc", 0xa3f71de088bf1595648be13f23b3afcb,
+  fnv1a_128_test_150: u128, b"http://www.isthe.com/chongo/tech/astro/venus2004.html", 0x9e6e982a7925864d0567bb20a1433141,
+  fnv1a_128_test_151: u128, b"http://www.isthe.com/chongo/tech/astro/vita.html", 0x10139e40f720eabf1e78a38d75c7a2e8,
+  fnv1a_128_test_152: u128, b"http://www.isthe.com/chongo/tech/comp/c/expert.html", 0xa6d3d60c458d5d37d107d3cb350273e2,
+  fnv1a_128_test_153: u128, b"http://www.isthe.com/chongo/tech/comp/calc/index.html", 0xf77a286c45e85dbe1350a98e98357602,
+  fnv1a_128_test_154: u128, b"http://www.isthe.com/chongo/tech/comp/fnv/index.html", 0x289bf8066f7c3c1ff7b5a7eba1b780e1,
+  fnv1a_128_test_155: u128, b"http://www.isthe.com/chongo/tech/math/number/howhigh.html", 0x0f58352224118cfd1f2b3c997e10ed1d,
+  fnv1a_128_test_156: u128, b"http://www.isthe.com/chongo/tech/math/number/number.html", 0x0e8c6e22a979f45005a117bd4045b3f8,
+  fnv1a_128_test_157: u128, b"http://www.isthe.com/chongo/tech/math/prime/mersenne.html", 0xbfa0374319a275cd96b390c3b219faec,
+  fnv1a_128_test_158: u128, b"http://www.isthe.com/chongo/tech/math/prime/mersenne.html#largest", 0x9212af7468bd20d05cf0581fd1840043,
+  fnv1a_128_test_159: u128, b"http://www.lavarnd.org/cgi-bin/corpspeak.cgi", 0xb7f54c182e61ee74828799c8bef0d4ce,
+  fnv1a_128_test_160: u128, b"http://www.lavarnd.org/cgi-bin/haiku.cgi", 0x75cb79c6bacb5f308796892cf49b5a16,
+  fnv1a_128_test_161: u128, b"http://www.lavarnd.org/cgi-bin/rand-none.cgi", 0x7368394b1dd90ba68c2878d3d9133c50,
+  fnv1a_128_test_162: u128, b"http://www.lavarnd.org/cgi-bin/randdist.cgi", 0x5e84f8380e5123eb2f8033547573b829,
+  fnv1a_128_test_163: u128, b"http://www.lavarnd.org/index.html", 0x1a0455cc577c0b81b87a2c0861481404,
+  fnv1a_128_test_164: u128, b"http://www.lavarnd.org/what/nist-test.html", 0xaad260a3bdeda42108f200b0fabb9d76,
+  fnv1a_128_test_165: u128, b"http://www.macosxhints.com/", 0x7e3b7e5a11a78822532092cd40fe2821,
+  fnv1a_128_test_166: u128, b"http://www.mellis.com/", 0x23d902e407262d9b85df5d95255e785e,
+  fnv1a_128_test_167: u128, b"http://www.nature.nps.gov/air/webcams/parks/havoso2alert/havoalert.cfm", 0x27a40723ac8272ef5ad6befdb126bf5c,
+  fnv1a_128_test_168: u128, b"http://www.nature.nps.gov/air/webcams/parks/havoso2alert/timelines_24.cfm", 0x32621096f38c839490302de33fba126d,
+  fnv1a_128_test_169: u128, b"http://www.paulnoll.com/", 0x03bc792da8ad86ef80b1ed14dbc2a2ff,
+  fnv1a_128_test_170: u128, b"http://www.pepysdiary.com/", 0x2b113aa92f0e68e6aa97717f4aed1ab4,
+  fnv1a_128_test_171: u128, b"http://www.sciencenews.org/index/home/activity/view", 0xc386456cb81df9453d481a092c430f4c,
+  fnv1a_128_test_172: u128, b"http://www.skyandtelescope.com/", 0x72d24bccc630bae68fd5175e965bd046,
+  fnv1a_128_test_173: u128, b"http://www.sput.nl/~rob/sirius.html", 0x0ce1b392c8715b5d949d34e5c6dcc001,
+  fnv1a_128_test_174: u128, b"http://www.systemexperts.com/", 0x4b678dd50fc7bc4149e889dee700cae6,
+  fnv1a_128_test_175: u128, b"http://www.tq-international.com/phpBB3/index.php", 0x351a7236200affa55729a50504f3a352,
+  fnv1a_128_test_176: u128, b"http://www.travelquesttours.com/index.htm", 0xe7e2c3fb79cbb57d24870c55903e0b90,
+  fnv1a_128_test_177: u128, b"http://www.wunderground.com/global/stations/89606.html", 0x810acd7884b677050240ebf2e132023c,
+  fnv1a_128_test_178: u128, &repeat(b"21701", 10), 0x984687e966e9b19c8994f3b49706c2cb,
+  fnv1a_128_test_179: u128, &repeat(b"M21701", 10), 0x03c366d38cfa8f4177aa33bdd5e5ece5,
+  fnv1a_128_test_180: u128, &repeat(b"2^21701-1", 10), 0xb6d4e76beb01c762c985c99559d777eb,
+  fnv1a_128_test_181: u128, &repeat(b"\x54\xc5", 10), 0x8aed6fc3de40d4e492b33bb66c7f65ed,
+  fnv1a_128_test_182: u128, &repeat(b"\xc5\x54", 10), 0x54b6a2f372d4596dab0546ad543f7375,
+  fnv1a_128_test_183: u128, &repeat(b"23209", 10), 0x1438dc9d78de461628f330f870b26d71,
+  fnv1a_128_test_184: u128, &repeat(b"M23209", 10), 0xa84680ee2f24d2b3fbfd9ad4aebfe089,
+  fnv1a_128_test_185: u128, &repeat(b"2^23209-1", 10), 0xb693542327982b69f14876828275f19d,
+  fnv1a_128_test_186: u128, &repeat(b"\x5a\xa9", 10), 0xb8c7273f07dd7ac7b35bded2fa50dc09,
+  fnv1a_128_test_187: u128, &repeat(b"\xa9\x5a", 10), 0x2b0f99d1005bc2e65385ecf7f5c09021,
+  fnv1a_128_test_188: u128, &repeat(b"391581216093", 10), 0x4a46aa899be6028821c1a305b5b2cc19,
+  fnv1a_128_test_189: u128, &repeat(b"391581*2^216093-1", 10), 0x061b6ab7be9bda5520727fe5881af6b1,
+  fnv1a_128_test_190: u128, &repeat(b"\x05\xf9\x9d\x03\x4c\x81", 10), 0xec3d17a536c37911f0efb4f0b4d9a70d,
+  fnv1a_128_test_191: u128, &repeat(b"FEDCBA9876543210", 10), 0xcfe9e6b423c69cff150dc1f53d2515bd,
+  fnv1a_128_test_192: u128, &repeat(b"\xfe\xdc\xba\x98\x76\x54\x32\x10", 10), 0xa3831051114ced43c203852b34ff12ad,
+  fnv1a_128_test_193: u128, &repeat(b"EFCDAB8967452301", 10), 0xa0b1815c08621da96a76501ce59f1e7d,
+  fnv1a_128_test_194: u128, &repeat(b"\xef\xcd\xab\x89\x67\x45\x23\x01", 10), 0x0b71becdd62f2c96684ad5c1ffc2d4ad,
+  fnv1a_128_test_195: u128, &repeat(b"0123456789ABCDEF", 10), 0x0e88e4faba98fadb6e4f2b219d7f4bed,
+  fnv1a_128_test_196: u128, &repeat(b"\x01\x23\x45\x67\x89\xab\xcd\xef", 10), 0x92d2374990224e7d2bd41b4bb46c654d,
+  fnv1a_128_test_197: u128, &repeat(b"1032547698BADCFE", 10), 0xec4d085593f2639f657dc27a7deb5f1d,
+  fnv1a_128_test_198: u128, &repeat(b"\x10\x32\x54\x76\x98\xba\xdc\xfe", 10), 0x3490e8c0b0bae951620c90652a1f3f2d,
+  fnv1a_128_test_199: u128, &repeat(b"\x00", 500), 0xbc5a731e7ff34af0587bc0739c8b2b7d,
+  fnv1a_128_test_200: u128, &repeat(b"\x07", 500), 0xea81ccf61c18caef3932cc4e497c1e69,
+  fnv1a_128_test_201: u128, &repeat(b"~", 500), 0x4b4162512e8b1c92f271a551732e977d,
+  fnv1a_128_test_202: u128, &repeat(b"\x7f", 500), 0x6077104af9f3c2f026de5ca224f04559,
+  fnv1a_256_test_0: U256, b"", U256::from_words([0xdd268dbcaac55036, 0x2d98c384c4e576cc, 0xc8b1536847b6bbb3, 0x1023b4c8caee0535]),
+  fnv1a_256_test_1: U256, b"a", U256::from_words([0x63323fb0f35303ec, 0x28dc751d0a33bdfa, 0x4de6a99b7266494f, 0x6183b2716811637c]),
+  fnv1a_256_test_2: U256, b"b", U256::from_words([0x63323fb0f35303ec, 0x28dc781d0a33bdfa, 0x4de6a99b7266494f, 0x6183b271681167a5]),
+  fnv1a_256_test_3: U256, b"c", U256::from_words([0x63323fb0f35303ec, 0x28dc771d0a33bdfa, 0x4de6a99b7266494f, 0x6183b27168116642]),
+  fnv1a_256_test_4: U256, b"d", U256::from_words([0x63323fb0f35303ec, 0x28dc721d0a33bdfa, 0x4de6a99b7266494f, 0x6183b27168115f53]),
+  fnv1a_256_test_5: U256, b"e", U256::from_words([0x63323fb0f35303ec, 0x28dc711d0a33bdfa, 0x4de6a99b7266494f, 0x6183b27168115df0]),
+  fnv1a_256_test_6: U256, b"f", U256::from_words([0x63323fb0f35303ec, 0x28dc741d0a33bdfa, 0x4de6a99b7266494f, 0x6183b27168116219]),
+  fnv1a_256_test_7: U256, b"fo", U256::from_words([0xf4f7a1c2efd0e1e4, 0xbb177a4525c0721a, 0x06dd328fa3d7a914, 0x39a07343501b89a2]),
+  fnv1a_256_test_8: U256, b"foo", U256::from_words([0x8b0e658c2f1c837f, 0x8d185ae359de3a17, 0x84bd1d30340f770b, 0xe97fd65816301747]),
+  fnv1a_256_test_9: U256, b"foob", U256::from_words([0xe46ddd4ed460b1f6, 0xd8dd2e459f2a8e9d, 0x123f79d831721584, 0xcc463c26c4b0184f]),
+  fnv1a_256_test_10: U256, b"fooba", U256::from_words([0x366f691cc852f013, 0x6acf588bb803c3d0, 0x4e05f6cc9133d727, 0x456569c2c03187ca]),
+  fnv1a_256_test_11: U256, b"foobar", U256::from_words([0xb055ea2f306cadad, 0x4f0f81c02d3889dc, 0x32453dad5ae35b75, 0x3ba1a91084af3428]),
+  fnv1a_256_test_12: U256, ::std::ffi::CString::new("").unwrap().as_bytes_with_nul(), U256::from_words([0x63323fb0f35303ec, 0x28dc561d0a33bdfa, 0x4de6a99b7266494f, 0x6183b2716811387f]),
+  fnv1a_256_test_13: U256, ::std::ffi::CString::new("a").unwrap().as_bytes_with_nul(), U256::from_words([0xf4f7a1c2efd0e1e4, 0xbb19e34525c0721a, 0x06dd328fa3d7a914, 0x39a07343501cf4f4]),
+  fnv1a_256_test_14: U256, ::std::ffi::CString::new("b").unwrap().as_bytes_with_nul(), U256::from_words([0xf4f7a1c2efd0e1e4, 0xbb22354525c0721a, 0x06dd328fa3d7a914, 0x39a073435022b9cf]),
+  fnv1a_256_test_15: U256, ::std::ffi::CString::new("c").unwrap().as_bytes_with_nul(), U256::from_words([0xf4f7a1c2efd0e1e4, 0xbb1f6f4525c0721a, 0x06dd328fa3d7a914, 0x39a073435020cd86]),
+  fnv1a_256_test_16: U256, ::std::ffi::CString::new("d").unwrap().as_bytes_with_nul(), U256::from_words([0xf4f7a1c2efd0e1e4, 0xbb11914525c0721a, 0x06dd328fa3d7a914, 0x39a0734350173019]),
+  fnv1a_256_test_17: U256, ::std::ffi::CString::new("e").unwrap().as_bytes_with_nul(), U256::from_words([0xf4f7a1c2efd0e1e4, 0xbb0ecb4525c0721a, 0x06dd328fa3d7a914, 0x39a07343501543d0]),
+  fnv1a_256_test_18: U256, ::std::ffi::CString::new("f").unwrap().as_bytes_with_nul(), U256::from_words([0xf4f7a1c2efd0e1e4, 0xbb171d4525c0721a, 0x06dd328fa3d7a914, 0x39a07343501b08ab]),
+  fnv1a_256_test_19: U256, ::std::ffi::CString::new("fo").unwrap().as_bytes_with_nul(), U256::from_words([0x8b0e658c2f1c837f, 0x8d182fe359de3a17, 0x84bd1d30340f770b, 0xe97fd658162fdba6]),
+  fnv1a_256_test_20: U256, ::std::ffi::CString::new("foo").unwrap().as_bytes_with_nul(), U256::from_words([0xe46ddd4ed460b1f6, 0xd8dd50459f2a8e9d, 0x123f79d831721584, 0xcc463c26c4b04775]),
+  fnv1a_256_test_21: U256, ::std::ffi::CString::new("foob").unwrap().as_bytes_with_nul(), U256::from_words([0x366f691cc852f013, 0x6acf798bb803c3d0, 0x4e05f6cc9133d727, 0x456569c2c031b58d]),
+  fnv1a_256_test_22: U256, ::std::ffi::CString::new("fooba").unwrap().as_bytes_with_nul(), U256::from_words([0xb055ea2f306cadad, 0x4f0f93c02d3889dc, 0x32453dad5ae35b75, 0x3ba1a91084af4d1e]),
+  fnv1a_256_test_23: U256, ::std::ffi::CString::new("foobar").unwrap().as_bytes_with_nul(), U256::from_words([0x6a7f34abc85de7d9, 0x51b5157eb5672c59, 0xb60487650947d391, 0xb12d71e7fef55378]),
+  fnv1a_256_test_24: U256, b"ch", U256::from_words([0xf4f7a1c2efd0e1e4, 0xbb1f574525c0721a, 0x06dd328fa3d7a914, 0x39a073435020ac3e]),
+  fnv1a_256_test_25: U256, b"cho", U256::from_words([0x8b0e658c2f1c837f, 0x9d2255e359de3a17, 0x84bd1d30340f770b, 0xe97fd6581d4ef453]),
+  fnv1a_256_test_26: U256, b"chon", U256::from_words([0xe46ddd4ed460b214, 0x359157459f2a8e9d, 0x123f79d831721584, 0xcc463c30a47cb097]),
+  fnv1a_256_test_27: U256, b"chong", U256::from_words([0x366f691cc85322aa, 0xc53cf58bb803c3d0, 0x4e05f6cc9133d727, 0x4565777418e95cd0]),
+  fnv1a_256_test_28: U256, b"chongo", U256::from_words([0xb055ea2f30c086e8, 0x6ce53fc02d3889dc, 0x32453dad5ae35b75, 0x3bb4a5fe8b9b9cdd]),
+  fnv1a_256_test_29: U256, b"chongo ", U256::from_words([0x6a7f34ac4fa112da, 0x9d84647eb5672c59, 0xb60487650947d391, 0xcb822ffb98cab2d7]),
+  fnv1a_256_test_30: U256, b"chongo w", U256::from_words([0xf6399cbdee8d20c1, 0x3949fbb58e128067, 0x6847c11bde9a632d, 0x358889e4e115b3e0]),
+  fnv1a_256_test_31: U256, b"chongo wa", U256::from_words([0x0c478897563e50d3, 0x874b8dc403a80f65, 0x9b80c9a5b01787b1, 0x3c5738642117ebe3]),
+  fnv1a_256_test_32: U256, b"chongo was", U256::from_words([0x1eba1b18efa27975, 0xb5b126d1120d59e6, 0xa397a0c330a12ac6, 0xacf332d9e42ba8b0]),
+  fnv1a_256_test_33: U256, b"chongo was ", U256::from_words([0x3d3e5a414181481f, 0x205163ec0883aad4, 0xdb43eeac6f7e5181, 0xd53f8427688abfb0]),
+  fnv1a_256_test_34: U256, b"chongo was h", U256::from_words([0x6bc8aa5315cb2a92, 0x5b9d684fce95e52c, 0x0f33f91e9c2b070a, 0xb71442a5f8680888]),
+  fnv1a_256_test_35: U256, b"chongo was he", U256::from_words([0xa24b3bee4cfeaeed, 0x735093ab79dccc19, 0x151275728faac3db, 0xe1186c27784460a7]),
+  fnv1a_256_test_36: U256, b"chongo was her", U256::from_words([0xb917f755dd98babf, 0x2d1d9bc9fd2f06c8, 0x3898dddd39cd99e9, 0x24ddfabbc6d2475f]),
+  fnv1a_256_test_37: U256, b"chongo was here", U256::from_words([0x79d5e53728c5b2e2, 0x6256431a183667a6, 0x7bfba9c7281c6e4e, 0x1fd2b264b598c56e]),
+  fnv1a_256_test_38: U256, b"chongo was here!", U256::from_words([0x100b299d5cd974a3, 0xf6645c2f9371bbdd, 0xedfc6f2c9f6cf656, 0x212d61a7d2d99c8d]),
+  fnv1a_256_test_39: U256, b"chongo was here!\n", U256::from_words([0xac710b58eeee6731, 0x86c85cf976b784c1, 0x050e2ae114199970, 0x01ee6bb963c40f35]),
+  fnv1a_256_test_40: U256, ::std::ffi::CString::new("ch").unwrap().as_bytes_with_nul(), U256::from_words([0x8b0e658c2f1c837f, 0x9d2242e359de3a17, 0x84bd1d30340f770b, 0xe97fd6581d4ed9fa]),
+  fnv1a_256_test_41: U256, ::std::ffi::CString::new("cho").unwrap().as_bytes_with_nul(), U256::from_words([0xe46ddd4ed460b214, 0x35916d459f2a8e9d, 0x123f79d831721584, 0xcc463c30a47ccf19]),
+  fnv1a_256_test_42: U256, ::std::ffi::CString::new("chon").unwrap().as_bytes_with_nul(), U256::from_words([0x366f691cc85322aa, 0xc53c9c8bb803c3d0, 0x4e05f6cc9133d727, 0x4565777418e8e165]),
+  fnv1a_256_test_43: U256, ::std::ffi::CString::new("chong").unwrap().as_bytes_with_nul(), U256::from_words([0xb055ea2f30c086e8, 0x6ce550c02d3889dc, 0x32453dad5ae35b75, 0x3bb4a5fe8b9bb470]),
+  fnv1a_256_test_44: U256, ::std::ffi::CString::new("chongo").unwrap().as_bytes_with_nul(), U256::from_words([0x6a7f34ac4fa112da, 0x9d84447eb5672c59, 0xb60487650947d391, 0xcb822ffb98ca8677]),
+  fnv1a_256_test_45: U256, ::std::ffi::CString::new("chongo ").unwrap().as_bytes_with_nul(), U256::from_words([0xf6399cbdee8d20c1, 0x394a32b58e128067, 0x6847c11bde9a632d, 0x358889e4e1160025]),
+  fnv1a_256_test_46: U256, ::std::ffi::CString::new("chongo w").unwrap().as_bytes_with_nul(), U256::from_words([0x0c478897563e50d3, 0x874becc403a80f65, 0x9b80c9a5b01787b1, 0x3c57386421186fa0]),
+  fnv1a_256_test_47: U256, ::std::ffi::CString::new("chongo wa").unwrap().as_bytes_with_nul(), U256::from_words([0x1eba1b18efa27975, 0xb5b179d1120d59e6, 0xa397a0c330a12ac6, 0xacf332d9e42c1bc9]),
+  fnv1a_256_test_48: U256, ::std::ffi::CString::new("chongo was").unwrap().as_bytes_with_nul(), U256::from_words([0x3d3e5a414181481f, 0x205183ec0883aad4, 0xdb43eeac6f7e5181, 0xd53f8427688aec10]),
+  fnv1a_256_test_49: U256, ::std::ffi::CString::new("chongo was ").unwrap().as_bytes_with_nul(), U256::from_words([0x6bc8aa5315cb2a92, 0x5b9d404fce95e52c, 0x0f33f91e9c2b070a, 0xb71442a5f867d110]),
+  fnv1a_256_test_50: U256, ::std::ffi::CString::new("chongo was h").unwrap().as_bytes_with_nul(), U256::from_words([0xa24b3bee4cfeaeed, 0x73502eab79dccc19, 0x151275728faac3db, 0xe1186c277843d498]),
+  fnv1a_256_test_51: U256, ::std::ffi::CString::new("chongo was he").unwrap().as_bytes_with_nul(), U256::from_words([0xb917f755dd98babf, 0x2d1d6dc9fd2f06c8, 0x3898dddd39cd99e9, 0x24ddfabbc6d20795]),
+  fnv1a_256_test_52: U256, ::std::ffi::CString::new("chongo was her").unwrap().as_bytes_with_nul(), U256::from_words([0x79d5e53728c5b2e2, 0x6256681a183667a6, 0x7bfba9c7281c6e4e, 0x1fd2b264b598f8bd]),
+  fnv1a_256_test_53: U256, ::std::ffi::CString::new("chongo was here").unwrap().as_bytes_with_nul(), U256::from_words([0x100b299d5cd974a3, 0xf6647b2f9371bbdd, 0xedfc6f2c9f6cf656, 0x212d61a7d2d9c78a]),
+  fnv1a_256_test_54: U256, ::std::ffi::CString::new("chongo was here!").unwrap().as_bytes_with_nul(), U256::from_words([0xac710b58eeee6731, 0x86c862f976b784c1, 0x050e2ae114199970, 0x01ee6bb963c41787]),
+  fnv1a_256_test_55: U256, ::std::ffi::CString::new("chongo was here!\n").unwrap().as_bytes_with_nul(), U256::from_words([0x3a5c2c554304d311, 0xabe822efa07d17aa, 0x02a5761edf7fc652, 0xad9f621558e1167f]),
+  fnv1a_256_test_56: U256, b"cu", U256::from_words([0xf4f7a1c2efd0e1e4, 0xbb1f644525c0721a, 0x06dd328fa3d7a914, 0x39a073435020be45]),
+  fnv1a_256_test_57: U256, b"cur", U256::from_words([0x8b0e658c2f1c837f, 0x9d4642e359de3a17, 0x84bd1d30340f770b, 0xe97fd6581d67c645]),
+  fnv1a_256_test_58: U256, b"curd", U256::from_words([0xe46ddd4ed460b214, 0x8034e2459f2a8e9d, 0x123f79d831721584, 0xcc463c30c6e7bfc3]),
+  fnv1a_256_test_59: U256, b"curds", U256::from_words([0x366f691cc8532334, 0xb115768bb803c3d0, 0x4e05f6cc9133d727, 0x456577a3d35ed110]),
+  fnv1a_256_test_60: U256, b"curds ", U256::from_words([0xb055ea2f30c175e4, 0xef9493c02d3889dc, 0x32453dad5ae35b75, 0x3bb4e82e1c7c1590]),
+  fnv1a_256_test_61: U256, b"curds a", U256::from_words([0x6a7f34ac512eaa94, 0xb71ed47eb5672c59, 0xb60487650947d391, 0xcbddf7f180126d33]),
+  fnv1a_256_test_62: U256, b"curds an", U256::from_words([0xf6399cc071ae7dba, 0x022e08b58e128067, 0x6847c11bde9a632d, 0xb4ced3e4998da7f7]),
+  fnv1a_256_test_63: U256, b"curds and", U256::from_words([0x0c478c9273cc3d8a, 0x937da6c403a80f65, 0x9b80c9a5b0178861, 0xbacfd600ef6f60d9]),
+  fnv1a_256_test_64: U256, b"curds and ", U256::from_words([0x1ec050d1640f5819, 0xf69f3ad1120d59e6, 0xa397a0c330a21f86, 0x0e35c34c0773794b]),
+  fnv1a_256_test_65: U256, b"curds and w", U256::from_words([0x46cf986bf70a7808, 0x7247cbec0883aad4, 0xdb43eeac70d1b6e5, 0xb48dd26e55211e34]),
+  fnv1a_256_test_66: U256, b"curds and wh", U256::from_words([0x0397436c2156e20b, 0x9aae244fce95e52c, 0x0f33f92072d0a089, 0x60aacf000ced1994]),
+  fnv1a_256_test_67: U256, b"curds and whe", U256::from_words([0xcb630852e64a7624, 0x6c964bab79dccc19, 0x151277ff374e9e81, 0x0cdd0d11eccaf933]),
+  fnv1a_256_test_68: U256, b"curds and whey", U256::from_words([0x58f30c02364ee66f, 0x5f6438c9fd2f06c8, 0x389c66e9b205ccf4, 0xd6891fdb5d77b19e]),
+  fnv1a_256_test_69: U256, b"curds and whey\n", U256::from_words([0x5ed69be7d88967ce, 0xbfac541a183667a6, 0x80e2b611de0b3785, 0x80272d329cfb403c]),
+  fnv1a_256_test_70: U256, ::std::ffi::CString::new("cu").unwrap().as_bytes_with_nul(), U256::from_words([0x8b0e658c2f1c837f, 0x9d4650e359de3a17, 0x84bd1d30340f770b, 0xe97fd6581d67d9af]),
+  fnv1a_256_test_71: U256, ::std::ffi::CString::new("cur").unwrap().as_bytes_with_nul(), U256::from_words([0xe46ddd4ed460b214, 0x803506459f2a8e9d, 0x123f79d831721584, 0xcc463c30c6e7f1af]),
+  fnv1a_256_test_72: U256, ::std::ffi::CString::new("curd").unwrap().as_bytes_with_nul(), U256::from_words([0x366f691cc8532334, 0xb115898bb803c3d0, 0x4e05f6cc9133d727, 0x456577a3d35eeb69]),
+  fnv1a_256_test_73: U256, ::std::ffi::CString::new("curds").unwrap().as_bytes_with_nul(), U256::from_words([0xb055ea2f30c175e4, 0xef9473c02d3889dc, 0x32453dad5ae35b75, 0x3bb4e82e1c7be930]),
+  fnv1a_256_test_74: U256, ::std::ffi::CString::new("curds ").unwrap().as_bytes_with_nul(), U256::from_words([0x6a7f34ac512eaa94, 0xb71e737eb5672c59, 0xb60487650947d391, 0xcbddf7f18011e6b0]),
+  fnv1a_256_test_75: U256, ::std::ffi::CString::new("curds a").unwrap().as_bytes_with_nul(), U256::from_words([0xf6399cc071ae7dba, 0x022ddeb58e128067, 0x6847c11bde9a632d, 0xb4ced3e4998d6db9]),
+  fnv1a_256_test_76: U256, ::std::ffi::CString::new("curds an").unwrap().as_bytes_with_nul(), U256::from_words([0x0c478c9273cc3d8a, 0x937e0ac403a80f65, 0x9b80c9a5b0178861, 0xbacfd600ef6feb85]),
+  fnv1a_256_test_77: U256, ::std::ffi::CString::new("curds and").unwrap().as_bytes_with_nul(), U256::from_words([0x1ec050d1640f5819, 0xf69f1ad1120d59e6, 0xa397a0c330a21f86, 0x0e35c34c07734ceb]),
+  fnv1a_256_test_78: U256, ::std::ffi::CString::new("curds and ").unwrap().as_bytes_with_nul(), U256::from_words([0x46cf986bf70a7808, 0x7247daec0883aad4, 0xdb43eeac70d1b6e5, 0xb48dd26e55213301]),
+  fnv1a_256_test_79: U256, ::std::ffi::CString::new("curds and w").unwrap().as_bytes_with_nul(), U256::from_words([0x0397436c2156e20b, 0x9aadfc4fce95e52c, 0x0f33f92072d0a089, 0x60aacf000cece21c]),
+  fnv1a_256_test_80: U256, ::std::ffi::CString::new("curds and wh").unwrap().as_bytes_with_nul(), U256::from_words([0xcb630852e64a7624, 0x6c95eeab79dccc19, 0x151277ff374e9e81, 0x0cdd0d11ecca783c]),
+  fnv1a_256_test_81: U256, ::std::ffi::CString::new("curds and whe").unwrap().as_bytes_with_nul(), U256::from_words([0x58f30c02364ee66f, 0x5f6421c9fd2f06c8, 0x389c66e9b205ccf4, 0xd6891fdb5d7791b9]),
+  fnv1a_256_test_82: U256, ::std::ffi::CString::new("curds and whey").unwrap().as_bytes_with_nul(), U256::from_words([0x5ed69be7d88967ce, 0xbfac5e1a183667a6, 0x80e2b611de0b3785, 0x80272d329cfb4e1a]),
+  fnv1a_256_test_83: U256, ::std::ffi::CString::new("curds and whey\n").unwrap().as_bytes_with_nul(), U256::from_words([0x8ed1b8016db82650, 0xc738dc2f9371bbe4, 0xba627ac6e98dfe20, 0xb653ad2fb06a1334]),
+  fnv1a_256_test_84: U256, b"hi", U256::from_words([0xf4f7a1c2efd0e1e4, 0xbb32804525c0721a, 0x06dd328fa3d7a914, 0x39a07343502dc81a]),
+  fnv1a_256_test_85: U256, ::std::ffi::CString::new("hi").unwrap().as_bytes_with_nul(), U256::from_words([0x8b0e658c2f1c837f, 0xc4cff9e359de3a17, 0x84bd1d30340f770b, 0xe97fd6582f7c7c0e]),
+  fnv1a_256_test_86: U256, b"hello", U256::from_words([0x366f691cc853a0e0, 0x020cdd8bb803c3d0, 0x4e05f6cc9133d727, 0x45659a3b744e63fb]),
+  fnv1a_256_test_87: U256, ::std::ffi::CString::new("hello").unwrap().as_bytes_with_nul(), U256::from_words([0xb055ea2f31925217, 0x263b33c02d3889dc, 0x32453dad5ae35b75, 0x3be4e07248b4a511]),
+  fnv1a_256_test_88: U256, b"\xff\x00\x00\x01", U256::from_words([0xe46ddd4ed460b6ea, 0x128c34459f2a8e9d, 0x123f79d831721584, 0xcc463dddd8c6734d]),
+  fnv1a_256_test_89: U256, b"\x01\x00\x00\xff", U256::from_words([0xe46ddd4ed460b0aa, 0x15e8f2459f2a8e9d, 0x123f79d831721584, 0xcc463bb329efd629]),
+  fnv1a_256_test_90: U256, b"\xff\x00\x00\x02", U256::from_words([0xe46ddd4ed460b6ea, 0x128c31459f2a8e9d, 0x123f79d831721584, 0xcc463dddd8c66f24]),
+  fnv1a_256_test_91: U256, b"\x02\x00\x00\xff", U256::from_words([0xe46ddd4ed460b0ca, 0x15d854459f2a8e9d, 0x123f79d831721584, 0xcc463bbe41eab7b6]),
+  fnv1a_256_test_92: U256, b"\xff\x00\x00\x03", U256::from_words([0xe46ddd4ed460b6ea, 0x128c32459f2a8e9d, 0x123f79d831721584, 0xcc463dddd8c67087]),
+  fnv1a_256_test_93: U256, b"\x03\x00\x00\xff", U256::from_words([0xe46ddd4ed460b0bf, 0x6b33de459f2a8e9d, 0x123f79d831721584, 0xcc463bba8f42ae87]),
+  fnv1a_256_test_94: U256, b"\xff\x00\x00\x04", U256::from_words([0xe46ddd4ed460b6ea, 0x128c2f459f2a8e9d, 0x123f79d831721584, 0xcc463dddd8c66c5e]),
+  fnv1a_256_test_95: U256, b"\x04\x00\x00\xff", U256::from_words([0xe46ddd4ed460b08a, 0x15fb90459f2a8e9d, 0x123f79d831721584, 0xcc463ba811f7ba9c]),
+  fnv1a_256_test_96: U256, b"\x40\x51\x4e\x44", U256::from_words([0xe46ddd4ed460b360, 0x798ea4459f2a8e9d, 0x123f79d831721584, 0xcc463ca404894c98]),
+  fnv1a_256_test_97: U256, b"\x44\x4e\x51\x40", U256::from_words([0xe46ddd4ed460b336, 0x693c8f459f2a8e9d, 0x123f79d831721584, 0xcc463c9580db2c76]),
+  fnv1a_256_test_98: U256, b"\x40\x51\x4e\x4a", U256::from_words([0xe46ddd4ed460b360, 0x798ea2459f2a8e9d, 0x123f79d831721584, 0xcc463ca4048949d2]),
+  fnv1a_256_test_99: U256, b"\x4a\x4e\x51\x40", U256::from_words([0xe46ddd4ed460b3cb, 0x4c435b459f2a8e9d, 0x123f79d831721584, 0xcc463cc911b58bc8]),
+  fnv1a_256_test_100: U256, b"\x40\x51\x4e\x54", U256::from_words([0xe46ddd4ed460b360, 0x798eb4459f2a8e9d, 0x123f79d831721584, 0xcc463ca4048962c8]),
+  fnv1a_256_test_101: U256, b"\x54\x4e\x51\x40", U256::from_words([0xe46ddd4ed460b28b, 0xbf470f459f2a8e9d, 0x123f79d831721584, 0xcc463c5a568e9a06]),
+  fnv1a_256_test_102: U256, b"127.0.0.1", U256::from_words([0x0c2033aa9025a7cf, 0xdabe36c403a80f65, 0x9b80c9a5b01178b5, 0xf98c65c649bd45ae]),
+  fnv1a_256_test_103: U256, ::std::ffi::CString::new("127.0.0.1").unwrap().as_bytes_with_nul(), U256::from_words([0xe2205b7f709d7b86, 0x130b9fd1120d59e6, 0xa397a0c3283a6459, 0x0db121f84177a04a]),
+  fnv1a_256_test_104: U256, b"127.0.0.2", U256::from_words([0x0c2033aa9025a7cf, 0xdabe35c403a80f65, 0x9b80c9a5b01178b5, 0xf98c65c649bd444b]),
+  fnv1a_256_test_105: U256, ::std::ffi::CString::new("127.0.0.2").unwrap().as_bytes_with_nul(), U256::from_words([0xe2205b7f709d7b86, 0x1308d9d1120d59e6, 0xa397a0c3283a6459, 0x0db121f84175b401]),
+  fnv1a_256_test_106: U256, b"127.0.0.3", U256::from_words([0x0c2033aa9025a7cf, 0xdabe34c403a80f65, 0x9b80c9a5b01178b5, 0xf98c65c649bd42e8]),
+  fnv1a_256_test_107: U256, ::std::ffi::CString::new("127.0.0.3").unwrap().as_bytes_with_nul(), U256::from_words([0xe2205b7f709d7b86, 0x130613d1120d59e6, 0xa397a0c3283a6459, 0x0db121f84173c7b8]),
+  fnv1a_256_test_108: U256, b"64.81.78.68", U256::from_words([0x7e1d14352381bdbd, 0x01d879ec0883aad4, 0xdb43eea09e24f7d1, 0x1401a6e63632c287]),
+  fnv1a_256_test_109: U256, ::std::ffi::CString::new("64.81.78.68").unwrap().as_bytes_with_nul(), U256::from_words([0x074ad6c43e91034f, 0xc1f3994fce95e52c, 0x0f33e8bb4d43a6ee, 0xbe4a713d2863c135]),
+  fnv1a_256_test_110: U256, b"64.81.78.74", U256::from_words([0x7e1d14352381bdbd, 0x01d5efec0883aad4, 0xdb43eea09e24f7d1, 0x1401a6e636312972]),
+  fnv1a_256_test_111: U256, ::std::ffi::CString::new("64.81.78.74").unwrap().as_bytes_with_nul(), U256::from_words([0x074ad6c43e91034f, 0xbcd5264fce95e52c, 0x0f33e8bb4d43a6ee, 0xbe4a713d262c7916]),
+  fnv1a_256_test_112: U256, b"64.81.78.84", U256::from_words([0x7e1d14352381bdbd, 0x01de01ec0883aad4, 0xdb43eea09e24f7d1, 0x1401a6e63636958d]),
+  fnv1a_256_test_113: U256, ::std::ffi::CString::new("64.81.78.84").unwrap().as_bytes_with_nul(), U256::from_words([0x074ad6c43e91034f, 0xcd72374fce95e52c, 0x0f33e8bb4d43a6ee, 0xbe4a713d2db16287]),
+  fnv1a_256_test_114: U256, b"feedface", U256::from_words([0xf6399be9214c8781, 0xc01616b58e128067, 0x6847c11bde9a6308, 0xe9404dc33c39b5bc]),
+  fnv1a_256_test_115: U256, ::std::ffi::CString::new("feedface").unwrap().as_bytes_with_nul(), U256::from_words([0x0c463d326d6dac29, 0x985739c403a80f65, 0x9b80c9a5b017555b, 0x742bd5bc840703b4]),
+  fnv1a_256_test_116: U256, b"feedfacedaffdeed", U256::from_words([0x5c0725ede52357ac, 0x5601532f9371b9ed, 0x93f286768a779bce, 0x35ad570da6664661]),
+  fnv1a_256_test_117: U256, ::std::ffi::CString::new("feedfacedaffdeed").unwrap().as_bytes_with_nul(), U256::from_words([0x1585671a6d59a1a1, 0xaa1cbbf976b4d474, 0x2950766203dd0ef4, 0x6f5fb7edbfd39883]),
+  fnv1a_256_test_118: U256, b"feedfacedeadbeef", U256::from_words([0x5c0725eea43b8242, 0xa40cd32f9371b9ed, 0x93f286768a779bce, 0x5b454952c784a988]),
+  fnv1a_256_test_119: U256, ::std::ffi::CString::new("feedfacedeadbeef").unwrap().as_bytes_with_nul(), U256::from_words([0x1585684903cef531, 0x027262f976b4d474, 0x2950766203dd0f28, 0x9114adcaacf71798]),
+  fnv1a_256_test_120: U256, b"line 1\nline 2\nline 3", U256::from_words([0x5209c96840478a00, 0xddb95041850f0750, 0x6951a0bf96f4d369, 0x3031c6ca05fced73]),
+  fnv1a_256_test_121: U256, b"chongo <Landon Curt Noll> /\\../\\", U256::from_words([0x4e4a25bfb530772a, 0x8232c94adffde114, 0x56e83ac788b47f47, 0xa8cce602fda72b16]),
+  fnv1a_256_test_122: U256, ::std::ffi::CString::new("chongo <Landon Curt Noll> /\\../\\").unwrap().as_bytes_with_nul(), U256::from_words([0x4551a0810f1b42f0, 0x339838d49d0f1f34, 0x840982b2924c805f, 0x1422f625bed0bf82]),
+  fnv1a_256_test_123: U256, b"chongo (Landon Curt Noll) /\\../\\", U256::from_words([0x97c71e01c46345a0, 0x47519d3c589c3cd5, 0xf86e9f16efc55356, 0x9bec18f0ab80ea01]),
+  fnv1a_256_test_124: U256, ::std::ffi::CString::new("chongo (Landon Curt Noll) /\\../\\").unwrap().as_bytes_with_nul(), U256::from_words([0x3e71f30f41c27dee, 0x67170baee0a85cb7, 0x81669cce7ea2911a, 0x386695bdd3c47f63]),
+  fnv1a_256_test_125: U256, b"http://antwrp.gsfc.nasa.gov/apod/astropix.html", U256::from_words([0x3c49842baf2c1270, 0x58ed8a8c3e38224d, 0xfbf5086ec64e4ae6, 0x8fc197d4b273adca]),
+  fnv1a_256_test_126: U256, b"http://en.wikipedia.org/wiki/Fowler_Noll_Vo_hash", U256::from_words([0xc7916da931ade077, 0xcca2bd1773f9b598, 0x746362e8bbda0273, 0xce659a6a0a301ec5]),
+  fnv1a_256_test_127: U256, b"http://epod.usra.edu/", U256::from_words([0xdb15d1b583dd2bdb, 0x0f508b37cfe944c3, 0xb2048a9dcc7e8bf8, 0xbc8d8892203a1d83]),
+  fnv1a_256_test_128: U256, b"http://exoplanet.eu/", U256::from_words([0xfadb8a220a33b5e0, 0xeea931fa636149bf, 0xa65c4f95df932b31, 0x720d437032c41a5c]),
+  fnv1a_256_test_129: U256, b"http://hvo.wr.usgs.gov/cam3/", U256::from_words([0x7f5801bc4a9e2a46, 0x891b30bd6248d8cb, 0x75b8fd80f2140a74, 0x8484cf8e037ab4d7]),
+  fnv1a_256_test_130: U256, b"http://hvo.wr.usgs.gov/cams/HMcam/", U256::from_words([0xfd4d0a349a08b9b2, 0xa99a3591c210bc61, 0x3666cbfccc6804cb, 0xe834832049113660]),
+  fnv1a_256_test_131: U256, b"http://hvo.wr.usgs.gov/kilauea/update/deformation.html", U256::from_words([0xf7542574de6df318, 0x12f3a32def6c80a4, 0x8a8d954709a6de5d, 0x7555f5733492ba12]),
+  fnv1a_256_test_132: U256, b"http://hvo.wr.usgs.gov/kilauea/update/images.html", U256::from_words([0x93c2641c805f3662, 0xff43dbdd282f5f26, 0x4b7bbe09cc9a2610, 0xacf216bfd610e344]),
+  fnv1a_256_test_133: U256, b"http://hvo.wr.usgs.gov/kilauea/update/maps.html", U256::from_words([0xc10e0e9fda0190bb, 0xce813aeb70c4aee1, 0x900a857463d29eb7, 0x0140b5c066315143]),
+  fnv1a_256_test_134: U256, b"http://hvo.wr.usgs.gov/volcanowatch/current_issue.html", U256::from_words([0xadddaa70a34a96bf, 0x1dd7ea506264609a, 0x033852e7153d0a85, 0xa2aed886a36463d0]),
+  fnv1a_256_test_135: U256, b"http://neo.jpl.nasa.gov/risk/", U256::from_words([0xfdc66404ba33675b, 0xbddabdd50e1144bc, 0xf4a1490e954f0394, 0x27b7892622449c0b]),
+  fnv1a_256_test_136: U256, b"http://norvig.com/21-days.html", U256::from_words([0x93173e67ec4d97b2, 0x4c7f4c5ff9cc83e1, 0x98cfa8d06092e333, 0xf41d2efde61362ca]),
+  fnv1a_256_test_137: U256, b"http://primes.utm.edu/curios/home.php", U256::from_words([0xea252ea769870e3f, 0x2fd33f2d0ebea759, 0x8fedd5afce5061c4, 0x0fceb8bbfe6b6e5d]),
+  fnv1a_256_test_138: U256, b"http://slashdot.org/", U256::from_words([0x9ab2173ab831ad77, 0x99f3ebfa636149bf, 0xa6564ce7acffe70b, 0x003c040bd29c18dc]),
+  fnv1a_256_test_139: U256, b"http://tux.wr.usgs.gov/Maps/155.25-19.5.html", U256::from_words([0x6be68f9503a0ce35, 0xc02b38f47e2cac8c, 0x3046db2c51f42f7c, 0xaa3b3cdddfd6df47]),
+  fnv1a_256_test_140: U256, b"http://volcano.wr.usgs.gov/kilaueastatus.php", U256::from_words([0xd89ce8777a56cd37, 0xb54ca94a986d8cd0, 0x2d5d1762162f5d50, 0x79db57366e4ff223]),
+  fnv1a_256_test_141: U256, b"http://www.avo.alaska.edu/activity/Redoubt.php", U256::from_words([0xaa129076aac3f150, 0x260137ba98fe3763, 0x7b18fc7a0400098b, 0x3902a5f2eddcf1ab]),
+  fnv1a_256_test_142: U256, b"http://www.dilbert.com/fast/", U256::from_words([0x05e91a03ad1dccec, 0xd8569f1f26d3a331, 0xd518c23d86b869bd, 0xf7a684ec26c1a5b9]),
+  fnv1a_256_test_143: U256, b"http://www.fourmilab.ch/gravitation/orbits/", U256::from_words([0x7dba058d80c8b553, 0x52cd49c1aa190e4b, 0x3bdd3a7634881b73, 0x3fcddd5799da5cd8]),
+  fnv1a_256_test_144: U256, b"http://www.fpoa.net/", U256::from_words([0x7ef5daee86ac0427, 0x128c90fa636149bf, 0xa6575c6c5804a860, 0x5233660afc2d0fe8]),
+  fnv1a_256_test_145: U256, b"http://www.ioccc.org/index.html", U256::from_words([0xcd2aca975ed8e612, 0x26a8bff36b7d4a3f, 0xf0bfed1d15a11dbd, 0x578fca19df614393]),
+  fnv1a_256_test_146: U256, b"http://www.isthe.com/cgi-bin/number.cgi", U256::from_words([0xb41d428d0a3c1064, 0x0f09a43718bc7d94, 0x501080e3af629cdb, 0x74aa76283741d1a5]),
+  fnv1a_256_test_147: U256, b"http://www.isthe.com/chongo/bio.html", U256::from_words([0xbd72599b70a22414, 0x875a3f86f835c597, 0x3e5f1c98117b35c0, 0xf4a65d330a1364f7]),
+  fnv1a_256_test_148: U256, b"http://www.isthe.com/chongo/index.html", U256::from_words([0x89496dbb7bf8b8e5, 0x964e92841f1403ff, 0x941368f5c89bae16, 0x58fd17c8aa4dc579]),
+  fnv1a_256_test_149: U256, b"http://www.isthe.com/chongo/src/calc/lucas-calc", U256::from_words([0xf5132c6aa6ba6869, 0x7a98ff9dd446a06c, 0xdc6959d0e9cec88e, 0xa19bad9262f7faf3]),
+  fnv1a_256_test_150: U256, b"http://www.isthe.com/chongo/tech/astro/venus2004.html", U256::from_words([0xec8c735b45e1d659, 0xcd40c39621c9b233, 0x2bdcc8c4baeb84d9, 0x187ce15721047581]),
+  fnv1a_256_test_151: U256, b"http://www.isthe.com/chongo/tech/astro/vita.html", U256::from_words([0x3ee7dfc72aa71d11, 0x47eaac5c87659c59, 0xe53f3da0d6feb9fd, 0xb43623f17dbf33a0]),
+  fnv1a_256_test_152: U256, b"http://www.isthe.com/chongo/tech/comp/c/expert.html", U256::from_words([0xe1ca4b5564e25768, 0x56838d67ccf70616, 0x3eb0377643c360b0, 0x8bfc99f344e898ba]),
+  fnv1a_256_test_153: U256, b"http://www.isthe.com/chongo/tech/comp/calc/index.html", U256::from_words([0x50e953024a973054, 0xf6fa238432237cd2, 0x71c59deb1a0ac4f5, 0xe085e43fbbab1572]),
+  fnv1a_256_test_154: U256, b"http://www.isthe.com/chongo/tech/comp/fnv/index.html", U256::from_words([0xd1229be4b76a40c1, 0x6007cff13a8d70d8, 0xf043dd2db5a8ee52, 0x5748f517b3b00db1]),
+  fnv1a_256_test_155: U256, b"http://www.isthe.com/chongo/tech/math/number/howhigh.html", U256::from_words([0x5cb97ce6bfdbcd91, 0x03505191c8758934, 0xca08cf8e648c32a2, 0x35bc7c4ede88a75d]),
+  fnv1a_256_test_156: U256, b"http://www.isthe.com/chongo/tech/math/number/number.html", U256::from_words([0x3049c994c5f37144, 0xbe764ba7b649e2d1, 0xff2079bcae24b1b6, 0x32c387686218e2c8]),
+  fnv1a_256_test_157: U256, b"http://www.isthe.com/chongo/tech/math/prime/mersenne.html", U256::from_words([0x0ceb31e1374cb333, 0xe0c39e5f51d32eea, 0xecf1b292d17cba6a, 0x9969626ddae33ad4]),
+  fnv1a_256_test_158: U256, b"http://www.isthe.com/chongo/tech/math/prime/mersenne.html#largest", U256::from_words([0x58cd768338c03b85, 0xb49fffbc9c933579, 0x10596052e9a51695, 0xf7bb992f4bc76183]),
+  fnv1a_256_test_159: U256, b"http://www.lavarnd.org/cgi-bin/corpspeak.cgi", U256::from_words([0xab69a5571956b178, 0x300f67625b817141, 0x31368a3c9e05fdea, 0x515af13ba32dfe3e]),
+  fnv1a_256_test_160: U256, b"http://www.lavarnd.org/cgi-bin/haiku.cgi", U256::from_words([0x9e61f949ed48ac64, 0xf2d16b1f7c0696bd, 0x177a4c0043f62f17, 0xc0a0b0b6bbe033b6]),
+  fnv1a_256_test_161: U256, b"http://www.lavarnd.org/cgi-bin/rand-none.cgi", U256::from_words([0x0504838528267c9a, 0xe0dfec625b817141, 0x313286de35537812, 0xdd7d0f65394b63b8]),
+  fnv1a_256_test_162: U256, b"http://www.lavarnd.org/cgi-bin/randdist.cgi", U256::from_words([0x22d3aee70bca9377, 0x5c626baf82c81de2, 0x9e10ba6e3cb99383, 0x931fbc94982cf7e1]),
+  fnv1a_256_test_163: U256, b"http://www.lavarnd.org/index.html", U256::from_words([0xb6466132f96386fd, 0x0686827878b2ce40, 0x30f3d4c1f345c483, 0x8d623b3346151c54]),
+  fnv1a_256_test_164: U256, b"http://www.lavarnd.org/what/nist-test.html", U256::from_words([0x571bd4fc25c87607, 0x2bfd5f6e03776345, 0xf5d7108933d31430, 0x146df930a316a9ee]),
+  fnv1a_256_test_165: U256, b"http://www.macosxhints.com/", U256::from_words([0xbf5946f9b9c54e33, 0xe870f55f46c79b94, 0xfadbe75932b9c28a, 0x415839520c08a679]),
+  fnv1a_256_test_166: U256, b"http://www.mellis.com/", U256::from_words([0x53470dbbb0f3d5b7, 0x5a612965507a5b56, 0x5489940cc02079f3, 0x635830826a58164e]),
+  fnv1a_256_test_167: U256, b"http://www.nature.nps.gov/air/webcams/parks/havoso2alert/havoalert.cfm", U256::from_words([0x9f6121c74c7c4d9e, 0x3002a92162f6e82e, 0xf447f1eff76a120a, 0x15d22a12d915adbc]),
+  fnv1a_256_test_168: U256, b"http://www.nature.nps.gov/air/webcams/parks/havoso2alert/timelines_24.cfm", U256::from_words([0xed46489e2e613620, 0x85e369cd5b454093, 0x5d9b88fd4588c029, 0x33d17d40ce3fbc95]),
+  fnv1a_256_test_169: U256, b"http://www.paulnoll.com/", U256::from_words([0x9f25c56b5e9dc9e1, 0x0880d18f1a6df616, 0x83cb2a57ea46a32c, 0x2e2e1933ad02b817]),
+  fnv1a_256_test_170: U256, b"http://www.pepysdiary.com/", U256::from_words([0x707dbc5e9e82029a, 0x40857b99de4e71b6, 0x08cc51ac59f0a51a, 0xb935aeac5932d4b4]),
+  fnv1a_256_test_171: U256, b"http://www.sciencenews.org/index/home/activity/view", U256::from_words([0x49dd6e47876643a5, 0xa2af72613e2a133d, 0x342bb934f34e29b2, 0x28446e06b74bf9e4]),
+  fnv1a_256_test_172: U256, b"http://www.skyandtelescope.com/", U256::from_words([0xe9515ef1117e0e03, 0xbf9f993d1c0810d2, 0x1206f49e8603a01f, 0x370cddcc600970ee]),
+  fnv1a_256_test_173: U256, b"http://www.sput.nl/~rob/sirius.html", U256::from_words([0xe4ef804d35652c17, 0xfc63275029132a23, 0x84be33421840ae0e, 0xa9f125a0f2b19619]),
+  fnv1a_256_test_174: U256, b"http://www.systemexperts.com/", U256::from_words([0xd53891a525be3d1c, 0x0190c232f7f85ab7, 0xa7c73bdc38764154, 0xc6a5d1bd3857a58e]),
+  fnv1a_256_test_175: U256, b"http://www.tq-international.com/phpBB3/index.php", U256::from_words([0xf103e67b673671bd, 0x8fc3feb37bd8526f, 0xf4a709c4a0c86e20, 0x513558f779891062]),
+  fnv1a_256_test_176: U256, b"http://www.travelquesttours.com/index.htm", U256::from_words([0x54f7094903852ad3, 0x4c31cfd7f240567b, 0x4615f05603ffb4a3, 0x2fc9ed8f9edbd0e0]),
+  fnv1a_256_test_177: U256, b"http://www.wunderground.com/global/stations/89606.html", U256::from_words([0x867822e02f099479, 0x3b557ce24dc39f01, 0x84e7d7ca62e33cfe, 0x825a149bd891e114]),
+  fnv1a_256_test_178: U256, &repeat(b"21701", 10), U256::from_words([0x8cb05cca73d89605, 0x640af70f2c3ef39b, 0x73cd24662cf27b31, 0x971c325c9582319b]),
+  fnv1a_256_test_179: U256, &repeat(b"M21701", 10), U256::from_words([0xaf5c9b82b95cf956, 0x26442474372c8889, 0x01502f7a3f5c8833, 0x6905567de293d00d]),
+  fnv1a_256_test_180: U256, &repeat(b"2^21701-1", 10), U256::from_words([0x84fe8048a1f08e47, 0x0c4bef953c6ce4a2, 0x47b4e9c24f085ed7, 0x859dc1c14779891b]),
+  fnv1a_256_test_181: U256, &repeat(b"\x54\xc5", 10), U256::from_words([0xe5d010b0356b9fe3, 0x7e3e6e335d7c0d18, 0x671e90097e1dd55a, 0xc185ad3183951ee5]),
+  fnv1a_256_test_182: U256, &repeat(b"\xc5\x54", 10), U256::from_words([0xa28e7a6729a2e8a7, 0xf3c4a455d5f25ddf, 0x82c1f2a3d9e98846, 0xc26d0e6118cebfcd]),
+  fnv1a_256_test_183: U256, &repeat(b"23209", 10), U256::from_words([0xf06cfd3094b3ee40, 0xabb8727ee42d6d53, 0x4f8270473765180f, 0x1e4741722d2b4e69]),
+  fnv1a_256_test_184: U256, &repeat(b"M23209", 10), U256::from_words([0x837deb0847d66209, 0x26c0825257588719, 0x1633ba508995fbae, 0x881108e1aa760211]),
+  fnv1a_256_test_185: U256, &repeat(b"2^23209-1", 10), U256::from_words([0xde9aae8fc31ac760, 0x046a81d4829bfcfe, 0x28efa51ccef706fd, 0x8ec53a82beb24a35]),
+  fnv1a_256_test_186: U256, &repeat(b"\x5a\xa9", 10), U256::from_words([0xc10fee76dbb7ac9a, 0x33338f30dfe8c1b8, 0x87fb4869f0ae45c5, 0x5d3940889bb22f51]),
+  fnv1a_256_test_187: U256, &repeat(b"\xa9\x5a", 10), U256::from_words([0x36444c288dd57d5d, 0x5669b17e3d48d5ba, 0x1d968650ad834cff, 0x59223d98dc6005a9]),
+  fnv1a_256_test_188: U256, &repeat(b"391581216093", 10), U256::from_words([0x77333d0cdae5a4e3, 0xb5f441bf2602664a, 0x5780dc16513f5519, 0xdad61ad50c28a4a1]),
+  fnv1a_256_test_189: U256, &repeat(b"391581*2^216093-1", 10), U256::from_words([0x090007c21f749773, 0x0cb7bfa9ed95fc74, 0xcf728174daacc1c4, 0x8d1a4fab24651ae9]),
+  fnv1a_256_test_190: U256, &repeat(b"\x05\xf9\x9d\x03\x4c\x81", 10), U256::from_words([0x8e45b4089783dc36, 0xe21becf63a65986b, 0xb2d1d13b79eb22d2, 0x5e6425647e3b45a5]),
+  fnv1a_256_test_191: U256, &repeat(b"FEDCBA9876543210", 10), U256::from_words([0x6c358dde4a48253c, 0xcde3bd673eddf5f0, 0xed71bfad36bff094, 0xda94d6df7e652215]),
+  fnv1a_256_test_192: U256, &repeat(b"\xfe\xdc\xba\x98\x76\x54\x32\x10", 10), U256::from_words([0x157ee0f8fb1ea87d, 0x98e80afe53d8a270, 0x1fa05e688be74975, 0x47caf36f6d4c4255]),
+  fnv1a_256_test_193: U256, &repeat(b"EFCDAB8967452301", 10), U256::from_words([0x1e686ef1d2c32f5d, 0xc0d379037ddfaed4, 0x4bcd0ff864361a74, 0x19d23a17d939a375]),
+  fnv1a_256_test_194: U256, &repeat(b"\xef\xcd\xab\x89\x67\x45\x23\x01", 10), U256::from_words([0xa25e7e91edf3587b, 0x88899f04ab961c4c, 0x0fee8528de9bb604, 0xd6413c0968acb195]),
+  fnv1a_256_test_195: U256, &repeat(b"0123456789ABCDEF", 10), U256::from_words([0x025ea3c66392acb1, 0x73d11aab5e383ba8, 0x99439c3cfa0f93b3, 0x73684fa1b4e79905]),
+  fnv1a_256_test_196: U256, &repeat(b"\x01\x23\x45\x67\x89\xab\xcd\xef", 10), U256::from_words([0xef4da2c93b574ac7, 0xee609bcbaa74d30c, 0x84e22e32cc4882d9, 0x7a95594492f7e5b5]),
+  fnv1a_256_test_197: U256, &repeat(b"1032547698BADCFE", 10), U256::from_words([0xd32f3ccc09c2d2c2, 0xee159d5bad39bdf7, 0x4b70871d1d136b00, 0x422696e2e8d0a395]),
+  fnv1a_256_test_198: U256, &repeat(b"\x10\x32\x54\x76\x98\xba\xdc\xfe", 10), U256::from_words([0x99f68979caf7ff88, 0x68eaf330731fbe23, 0x6b485cb797084ce6, 0xe6fbcd23d08f4555]),
+  fnv1a_256_test_199: U256, &repeat(b"\x00", 500), U256::from_words([0xf863872ce894f872, 0x17b7f65615aaba6b, 0x36013372dcfe427f, 0x3027fd63f3eb9605]),
+  fnv1a_256_test_200: U256, &repeat(b"\x07", 500), U256::from_words([0xc1aa258b68efae03, 0x5bfc8a1dd38e4a1d, 0x8883fe2fc32fa03e, 0xb6ed517d95352941]),
+  fnv1a_256_test_201: U256, &repeat(b"~", 500), U256::from_words([0xe9459404b9dc4096, 0x0b9deba98d22102a, 0xce5ac61a5d81ab71, 0xddaa2444222ccf05]),
+  fnv1a_256_test_202: U256, &repeat(b"\x7f", 500), U256::from_words([0x08fc26ee71ea5e23, 0xe9c9ab88b60857cd, 0x0b06f1254a1269ba, 0x163a8b7d3519f511]),
+  fnv1a_512_test_0: U512, b"", U512::from_words([0xb86db0b1171f4416, 0xdca1e50f309990ac, 0xac87d059c9000000, 0x0000000000000d21, 0xe948f68a34c192f6, 0x2ea79bc942dbe7ce, 0x182036415f56e34b, 0xac982aac4afe9fd9]),
+  fnv1a_512_test_1: U512, b"a", U512::from_words([0xe43a992dc8fc5ad7, 0xde493e3d696d6f85, 0xd64326ec07000000, 0x000000000011986f, 0x90c2532caf5be7d8, 0x8291baa894a39522, 0x5328b196bd6a8a64, 0x3fe12cd87b27ff88]),
+  fnv1a_512_test_2: U512, b"b", U512::from_words([0xe43a992dc8fc5ad7, 0xde493e3d696d6f85, 0xd64326ec0a000000, 0x000000000011986f, 0x90c2532caf5be7d8, 0x8291baa894a39522, 0x5328b196bd6a8a64, 0x3fe12cd87b28038d]),
+  fnv1a_512_test_3: U512, b"c", U512::from_words([0xe43a992dc8fc5ad7, 0xde493e3d696d6f85, 0xd64326ec09000000, 0x000000000011986f, 0x90c2532caf5be7d8, 0x8291baa894a39522, 0x5328b196bd6a8a64, 0x3fe12cd87b280236]),
+  fnv1a_512_test_4: U512, b"d", U512::from_words([0xe43a992dc8fc5ad7, 0xde493e3d696d6f85, 0xd64326ec0c000000, 0x000000000011986f, 0x90c2532caf5be7d8, 0x8291baa894a39522, 0x5328b196bd6a8a64, 0x3fe12cd87b28063b]),
+  fnv1a_512_test_5: U512, b"e", U512::from_words([0xe43a992dc8fc5ad7, 0xde493e3d696d6f85, 0xd64326ec0b000000, 0x000000000011986f, 0x90c2532caf5be7d8, 0x8291baa894a39522, 0x5328b196bd6a8a64, 0x3fe12cd87b2804e4]),
+  fnv1a_512_test_6: U512, b"f", U512::from_words([0xe43a992dc8fc5ad7, 0xde493e3d696d6f85, 0xd64326ec0e000000, 0x000000000011986f, 0x90c2532caf5be7d8, 0x8291baa894a39522, 0x5328b196bd6a8a64, 0x3fe12cd87b2808e9]),
+  fnv1a_512_test_7: U512, b"fo", U512::from_words([0x7317dfed6c70dfec, 0x6adfced2a5e04d7e, 0xec744e4f48000000, 0x0000000017933d7a, 0xf45d70def423a316, 0xf14117df272cd0fd, 0x6b85f0f7c9bf6c51, 0x96b3160d02a36b8a]),
+  fnv1a_512_test_8: U512, b"foo", U512::from_words([0x142433ed48a78bb4, 0x29a7dba8911e8824, 0xdcd78fa55d000000, 0x0000001f96475fbd, 0x69323ab91bbf83bd, 0x3e36fbfd7d0c038b, 0x1075dbff4f7a2150, 0xe9f28b6e88f58fd3]),
+  fnv1a_512_test_9: U512, b"foob", U512::from_words([0xf9fe9eefe38ca43f, 0xcf36c8fbc0d25bef, 0x535a6c1f4c000000, 0x00002a5259a146c7, 0xf24cae042d99828e, 0x5baba0a28b18bf53, 0x0de9c3137ca2a369, 0x73f8d11981038627]),
+  fnv1a_512_test_10: U512, b"fooba", U512::from_words([0x96b20c29347dfb41, 0xb5e3ebf2c34d2679, 0xc7a7e1751a000000, 0x0038b4561715d5e5, 0xa4bd279918adecbc, 0xd2f439c85e285847, 0xa4345f1bfde8f24a, 0x6260292bdbb8e7ca]),
+  fnv1a_512_test_11: U512, b"foobar", U512::from_words([0xb0ec738d9c6fd969, 0xd05f0b35f6c0ed53, 0xadcacccd8e000000, 0x4bf99f58ee4196af, 0xb9700e20110830fe, 0xa5396b76280e47fd, 0x022b6e81331ca1a9, 0xced729c364be7788]),
+  fnv1a_512_test_12: U512, ::std::ffi::CString::new("").unwrap().as_bytes_with_nul(), U512::from_words([0xe43a992dc8fc5ad7, 0xde493e3d696d6f85, 0xd64326ec28000000, 0x000000000011986f, 0x90c2532caf5be7d8, 0x8291baa894a39522, 0x5328b196bd6a8a64, 0x3fe12cd87b282bbf]),
+  fnv1a_512_test_13: U512, ::std::ffi::CString::new("a").unwrap().as_bytes_with_nul(), U512::from_words([0x7317dfed6c70dfec, 0x6adfced2a5e04d7e, 0xec744e3ce9000000, 0x0000000017933d7a, 0xf45d70def423a316, 0xf14117df272cd0fd, 0x6b85f0f7c9bf6c51, 0x96b3160d02975f38]),
+  fnv1a_512_test_14: U512, ::std::ffi::CString::new("b").unwrap().as_bytes_with_nul(), U512::from_words([0x7317dfed6c70dfec, 0x6adfced2a5e04d7e, 0xec744e44f3000000, 0x0000000017933d7a, 0xf45d70def423a316, 0xf14117df272cd0fd, 0x6b85f0f7c9bf6c51, 0x96b3160d029cc1eb]),
+  fnv1a_512_test_15: U512, ::std::ffi::CString::new("c").unwrap().as_bytes_with_nul(), U512::from_words([0x7317dfed6c70dfec, 0x6adfced2a5e04d7e, 0xec744e4245000000, 0x0000000017933d7a, 0xf45d70def423a316, 0xf14117df272cd0fd, 0x6b85f0f7c9bf6c51, 0x96b3160d029af65a]),
+  fnv1a_512_test_16: U512, ::std::ffi::CString::new("d").unwrap().as_bytes_with_nul(), U512::from_words([0x7317dfed6c70dfec, 0x6adfced2a5e04d7e, 0xec744e4a4f000000, 0x0000000017933d7a, 0xf45d70def423a316, 0xf14117df272cd0fd, 0x6b85f0f7c9bf6c51, 0x96b3160d02a0590d]),
+  fnv1a_512_test_17: U512, ::std::ffi::CString::new("e").unwrap().as_bytes_with_nul(), U512::from_words([0x7317dfed6c70dfec, 0x6adfced2a5e04d7e, 0xec744e47a1000000, 0x0000000017933d7a, 0xf45d70def423a316, 0xf14117df272cd0fd, 0x6b85f0f7c9bf6c51, 0x96b3160d029e8d7c]),
+  fnv1a_512_test_18: U512, ::std::ffi::CString::new("f").unwrap().as_bytes_with_nul(), U512::from_words([0x7317dfed6c70dfec, 0x6adfced2a5e04d7e, 0xec744e4fab000000, 0x0000000017933d7a, 0xf45d70def423a316, 0xf14117df272cd0fd, 0x6b85f0f7c9bf6c51, 0x96b3160d02a3f02f]),
+  fnv1a_512_test_19: U512, ::std::ffi::CString::new("fo").unwrap().as_bytes_with_nul(), U512::from_words([0x142433ed48a78bb4, 0x29a7dba8911e8824, 0xdcd78fa502000000, 0x0000001f96475fbd, 0x69323ab91bbf83bd, 0x3e36fbfd7d0c038b, 0x1075dbff4f7a2150, 0xe9f28b6e88f515e6]),
+  fnv1a_512_test_20: U512, ::std::ffi::CString::new("foo").unwrap().as_bytes_with_nul(), U512::from_words([0xf9fe9eefe38ca43f, 0xcf36c8fbc0d25bef, 0x535a6c1f6e000000, 0x00002a5259a146c7, 0xf24cae042d99828e, 0x5baba0a28b18bf53, 0x0de9c3137ca2a369, 0x73f8d1198103b3b5]),
+  fnv1a_512_test_21: U512, ::std::ffi::CString::new("foob").unwrap().as_bytes_with_nul(), U512::from_words([0x96b20c29347dfb41, 0xb5e3ebf2c34d2679, 0xc7a7e174fb000000, 0x0038b4561715d5e5, 0xa4bd279918adecbc, 0xd2f439c85e285847, 0xa4345f1bfde8f24a, 0x6260292bdbb8be41]),
+  fnv1a_512_test_22: U512, ::std::ffi::CString::new("fooba").unwrap().as_bytes_with_nul(), U512::from_words([0xb0ec738d9c6fd969, 0xd05f0b35f6c0ed53, 0xadcacccda0000000, 0x4bf99f58ee4196af, 0xb9700e20110830fe, 0xa5396b76280e47fd, 0x022b6e81331ca1a9, 0xced729c364be8fa6]),
+  fnv1a_512_test_23: U512, ::std::ffi::CString::new("foobar").unwrap().as_bytes_with_nul(), U512::from_words([0x82f6e10496de7834, 0xb08b21ef464cd247, 0x9e1d25e0ca000065, 0xcb74802739e0e571, 0x7522ecf6d1f9a52f, 0x5feefb4fab2273fd, 0xe8310f1b7b5c9a84, 0x2248f4cbfb322738]),
+  fnv1a_512_test_24: U512, b"ch", U512::from_words([0x7317dfed6c70dfec, 0x6adfced2a5e04d7e, 0xec744e426d000000, 0x0000000017933d7a, 0xf45d70def423a316, 0xf14117df272cd0fd, 0x6b85f0f7c9bf6c51, 0x96b3160d029b2bf2]),
+  fnv1a_512_test_25: U512, b"cho", U512::from_words([0x142433ed48a78bb4, 0x29a7dba8911e8824, 0xdcd7762ba8000000, 0x0000001f96475fbd, 0x69323ab91bbf83bd, 0x3e36fbfd7d0c038b, 0x1075dbff4f7a2150, 0xe9f28b6e7de76f5b]),
+  fnv1a_512_test_26: U512, b"chon", U512::from_words([0xf9fe9eefe38ca43f, 0xcf36c8fbc0d25bef, 0x532d3bed4d000000, 0x00002a5259a146c7, 0xf24cae042d99828e, 0x5baba0a28b18bf53, 0x0de9c3137ca2a369, 0x73f8d10ab1160003]),
+  fnv1a_512_test_27: U512, b"chong", U512::from_words([0x96b20c29347dfb41, 0xb5e3ebf2c34d2679, 0x7c4c60f28f000000, 0x0038b4561715d5e5, 0xa4bd279918adecbc, 0xd2f439c85e285847, 0xa4345f1bfde8f24a, 0x62601553447a85fc]),
+  fnv1a_512_test_28: U512, b"chongo", U512::from_words([0xb0ec738d9c6fd969, 0xd05f0b35f6c0ecda, 0xdd9a5f832c000000, 0x4bf99f58ee4196af, 0xb9700e20110830fe, 0xa5396b76280e47fd, 0x022b6e81331ca1a9, 0xcebc9290c028f7f5]),
+  fnv1a_512_test_29: U512, b"chongo ", U512::from_words([0x82f6e10496de7834, 0xb08b21ef464c15d1, 0x7a9621b7c9000065, 0xcb74802739e0e571, 0x7522ecf6d1f9a52f, 0x5feefb4fab2273fd, 0xe8310f1b7b5c9a83, 0xfea85ff176e40e63]),
+  fnv1a_512_test_30: U512, b"chongo w", U512::from_words([0xc8729f9a21fb3fa7, 0xa5e5d431b3efe40b, 0x309e114c63008863, 0x9917b48e8c536b03, 0xf1cb7eb3557c5279, 0x8932b7be4b296932, 0x19bb3dd2491306da, 0x33988c864b8edccc]),
+  fnv1a_512_test_31: U512, b"chongo wa", U512::from_words([0x4fdf00ecb9bc04dd, 0x1938619ef09e238a, 0xaa14bc3552b6bd72, 0x1ec2eafe03c46248, 0xf7a6c247899280d6, 0xd2f42ff6b47bf220, 0x79dfd4bfe87e2e5b, 0x216447ef3c69abcb]),
+  fnv1a_512_test_32: U512, b"chongo was", U512::from_words([0xfa7eb91efb646411, 0x8a734a22bf030311, 0xd104951d8ad7d3e7, 0x3728da570c1fafc3, 0xd06e4dd9534a9fd4, 0xa52c438bd2116983, 0x4ae60d2081101c19, 0xbd5c6189f1951388]),
+  fnv1a_512_test_33: U512, b"chongo was ", U512::from_words([0x2b9c19ec56ccf98d, 0xa0f964aa06c67940, 0x97155fa8af2ceaca, 0xe7bc8aa13e747f5c, 0x43ca4e2e98fc25e9, 0x4e4e805675545ee9, 0x5a3b968cec95a67c, 0xb6c6afd2aebd5618]),
+  fnv1a_512_test_34: U512, b"chongo was h", U512::from_words([0xc49c100735fc9859, 0x3b12757590a33c3a, 0x4051e859252e95dc, 0x7d9dbe0aae16a49e, 0xd40ec06ef9d6cb97, 0xeb2df3d7340b2aa7, 0xe5d6b6d0fc821118, 0xe43593481faed010]),
+  fnv1a_512_test_35: U512, b"chongo was he", U512::from_words([0x444d84d3fd55f644, 0xf6b7e295e399e79f, 0x75ddfe41466aca6c, 0x4e59a04f405690ce, 0x1fc3d4b0becac88c, 0x1a91b556baf626f4, 0xf2aef2025244e859, 0xc3c855a273394cc3]),
+  fnv1a_512_test_36: U512, b"chongo was her", U512::from_words([0xda97ee2f631fa558, 0x92b2dbbc4cf91efc, 0x8ee2e2c20a15371c, 0xfa15ca2f33fc042c, 0x8f61f8cfa1b2b3b7, 0x9939f7347fce3231, 0x2866411c3a535045, 0x516abca861c5c127]),
+  fnv1a_512_test_37: U512, b"chongo was here", U512::from_words([0x160fefb0008eefed, 0xa9debc9b6719ef20, 0x1a5f97bcc46cd7d3, 0x1331e53ea6a997b4, 0x1c445e31a66ecafe, 0x4caa37573d453fdd, 0x21013cd225a08ce0, 0x1602c59afff5ef6e]),
+  fnv1a_512_test_38: U512, b"chongo was here!", U512::from_words([0xe6976a109ca478ab, 0x6b9454c403d566c8, 0xf11443da7cd52bce, 0xb7da26f14d3a4251, 0xdf9a3485fe71fab8, 0xb81025e317c89147, 0x38a87d906a1cc03d, 0x7db6c2acf283a2d9]),
+  fnv1a_512_test_39: U512, b"chongo was here!\n", U512::from_words([0xd7f6e4d32796562a, 0xb427af61606a6dfd, 0xaf196d60149db1f8, 0x554a2d4e790edbb2, 0x979c5f87eab6ed7e, 0x9da2c344ddbaa66c, 0xe9c03c7e2c859263, 0x6fded5b8ee5f28b5]),
+  fnv1a_512_test_40: U512, ::std::ffi::CString::new("ch").unwrap().as_bytes_with_nul(), U512::from_words([0x142433ed48a78bb4, 0x29a7dba8911e8824, 0xdcd7762bfd000000, 0x0000001f96475fbd, 0x69323ab91bbf83bd, 0x3e36fbfd7d0c038b, 0x1075dbff4f7a2150, 0xe9f28b6e7de7e13e]),
+  fnv1a_512_test_41: U512, ::std::ffi::CString::new("cho").unwrap().as_bytes_with_nul(), U512::from_words([0xf9fe9eefe38ca43f, 0xcf36c8fbc0d25bef, 0x532d3bed73000000, 0x00002a5259a146c7, 0xf24cae042d99828e, 0x5baba0a28b18bf53, 0x0de9c3137ca2a369, 0x73f8d10ab11632ed]),
+  fnv1a_512_test_42: U512, ::std::ffi::CString::new("chon").unwrap().as_bytes_with_nul(), U512::from_words([0x96b20c29347dfb41, 0xb5e3ebf2c34d2679, 0x7c4c60f22e000000, 0x0038b4561715d5e5, 0xa4bd279918adecbc, 0xd2f439c85e285847, 0xa4345f1bfde8f24a, 0x62601553447a0405]),
+  fnv1a_512_test_43: U512, ::std::ffi::CString::new("chong").unwrap().as_bytes_with_nul(), U512::from_words([0xb0ec738d9c6fd969, 0xd05f0b35f6c0ecda, 0xdd9a5f8395000000, 0x4bf99f58ee4196af, 0xb9700e20110830fe, 0xa5396b76280e47fd, 0x022b6e81331ca1a9, 0xcebc9290c02984a4]),
+  fnv1a_512_test_44: U512, ::std::ffi::CString::new("chongo").unwrap().as_bytes_with_nul(), U512::from_words([0x82f6e10496de7834, 0xb08b21ef464c15d1, 0x7a9621b7e9000065, 0xcb74802739e0e571, 0x7522ecf6d1f9a52f, 0x5feefb4fab2273fd, 0xe8310f1b7b5c9a83, 0xfea85ff176e43943]),
+  fnv1a_512_test_45: U512, ::std::ffi::CString::new("chongo ").unwrap().as_bytes_with_nul(), U512::from_words([0xc8729f9a21fb3fa7, 0xa5e5d431b3efe40b, 0x309e114cb2008863, 0x9917b48e8c536b03, 0xf1cb7eb3557c5279, 0x8932b7be4b296932, 0x19bb3dd2491306da, 0x33988c864b8f46a5]),
+  fnv1a_512_test_46: U512, ::std::ffi::CString::new("chongo w").unwrap().as_bytes_with_nul(), U512::from_words([0x4fdf00ecb9bc04dd, 0x1938619ef09e238a, 0xaa14bc3571b6bd72, 0x1ec2eafe03c46248, 0xf7a6c247899280d6, 0xd2f42ff6b47bf220, 0x79dfd4bfe87e2e5b, 0x216447ef3c69d554]),
+  fnv1a_512_test_47: U512, ::std::ffi::CString::new("chongo wa").unwrap().as_bytes_with_nul(), U512::from_words([0xfa7eb91efb646411, 0x8a734a22bf030311, 0xd104951d9dd7d3e7, 0x3728da570c1fafc3, 0xd06e4dd9534a9fd4, 0xa52c438bd2116983, 0x4ae60d2081101c19, 0xbd5c6189f1952cfd]),
+  fnv1a_512_test_48: U512, ::std::ffi::CString::new("chongo was").unwrap().as_bytes_with_nul(), U512::from_words([0x2b9c19ec56ccf98d, 0xa0f964aa06c67940, 0x97155fa88f2ceaca, 0xe7bc8aa13e747f5c, 0x43ca4e2e98fc25e9, 0x4e4e805675545ee9, 0x5a3b968cec95a67c, 0xb6c6afd2aebd2b38]),
+  fnv1a_512_test_49: U512, ::std::ffi::CString::new("chongo was ").unwrap().as_bytes_with_nul(), U512::from_words([0xc49c100735fc9859, 0x3b12757590a33c3a, 0x4051e858cd2e95dc, 0x7d9dbe0aae16a49e, 0xd40ec06ef9d6cb97, 0xeb2df3d7340b2aa7, 0xe5d6b6d0fc821118, 0xe43593481fae5a28]),
+  fnv1a_512_test_50: U512, ::std::ffi::CString::new("chongo was h").unwrap().as_bytes_with_nul(), U512::from_words([0x444d84d3fd55f644, 0xf6b7e295e399e79f, 0x75ddfe40e16aca6c, 0x4e59a04f405690ce, 0x1fc3d4b0becac88c, 0x1a91b556baf626f4, 0xf2aef2025244e859, 0xc3c855a27338c570]),
+  fnv1a_512_test_51: U512, ::std::ffi::CString::new("chongo was he").unwrap().as_bytes_with_nul(), U512::from_words([0xda97ee2f631fa558, 0x92b2dbbc4cf91efc, 0x8ee2e2c21c15371c, 0xfa15ca2f33fc042c, 0x8f61f8cfa1b2b3b7, 0x9939f7347fce3231, 0x2866411c3a535045, 0x516abca861c5d945]),
+  fnv1a_512_test_52: U512, ::std::ffi::CString::new("chongo was her").unwrap().as_bytes_with_nul(), U512::from_words([0x160fefb0008eefed, 0xa9debc9b6719ef20, 0x1a5f97bca96cd7d3, 0x1331e53ea6a997b4, 0x1c445e31a66ecafe, 0x4caa37573d453fdd, 0x21013cd225a08ce0, 0x1602c59afff5cb41]),
+  fnv1a_512_test_53: U512, ::std::ffi::CString::new("chongo was here").unwrap().as_bytes_with_nul(), U512::from_words([0xe6976a109ca478ab, 0x6b9454c403d566c8, 0xf11443da9bd52bce, 0xb7da26f14d3a4251, 0xdf9a3485fe71fab8, 0xb81025e317c89147, 0x38a87d906a1cc03d, 0x7db6c2acf283cc62]),
+  fnv1a_512_test_54: U512, ::std::ffi::CString::new("chongo was here!").unwrap().as_bytes_with_nul(), U512::from_words([0xd7f6e4d32796562a, 0xb427af61606a6dfd, 0xaf196d601a9db1f8, 0x554a2d4e790edbb2, 0x979c5f87eab6ed7e, 0x9da2c344ddbaa66c, 0xe9c03c7e2c859263, 0x6fded5b8ee5f30bf]),
+  fnv1a_512_test_55: U512, ::std::ffi::CString::new("chongo was here!\n").unwrap().as_bytes_with_nul(), U512::from_words([0xa0aa519077573373, 0xdf58810a92093dbb, 0x53ffeae4544973ba, 0x4662b42432e85c49, 0x2283ff1b7b1834a5, 0x3513a1451514fbed, 0x30910d0da6f7233a, 0xe3905ac7617f8a83]),
+  fnv1a_512_test_56: U512, b"cu", U512::from_words([0x7317dfed6c70dfec, 0x6adfced2a5e04d7e, 0xec744e4252000000, 0x0000000017933d7a, 0xf45d70def423a316, 0xf14117df272cd0fd, 0x6b85f0f7c9bf6c51, 0x96b3160d029b07c5]),
+  fnv1a_512_test_57: U512, b"cur", U512::from_words([0x142433ed48a78bb4, 0x29a7dba8911e8824, 0xdcd775e395000000, 0x0000001f96475fbd, 0x69323ab91bbf83bd, 0x3e36fbfd7d0c038b, 0x1075dbff4f7a2150, 0xe9f28b6e7db75631]),
+  fnv1a_512_test_58: U512, b"curd", U512::from_words([0xf9fe9eefe38ca43f, 0xcf36c8fbc0d25bef, 0x532cab42f8000000, 0x00002a5259a146c7, 0xf24cae042d99828e, 0x5baba0a28b18bf53, 0x0de9c3137ca2a369, 0x73f8d10a70a4abe3]),
+  fnv1a_512_test_59: U512, b"curds", U512::from_words([0x96b20c29347dfb41, 0xb5e3ebf2c34d2679, 0x7b4a1b65d8000000, 0x0038b4561715d5e5, 0xa4bd279918adecbc, 0xd2f439c85e285847, 0xa4345f1bfde8f24a, 0x626014fceca1ddf0]),
+  fnv1a_512_test_60: U512, b"curds ", U512::from_words([0xb0ec738d9c6fd969, 0xd05f0b35f6c0ecd9, 0x2d37575238000000, 0x4bf99f58ee4196af, 0xb9700e20110830fe, 0xa5396b76280e47fd, 0x022b6e81331ca1a9, 0xcebc1ee10ce031b0]),
+  fnv1a_512_test_61: U512, b"curds a", U512::from_words([0x82f6e10496de7834, 0xb08b21ef464c131a, 0x7632df5ad9000065, 0xcb74802739e0e571, 0x7522ecf6d1f9a52f, 0x5feefb4fab2273fd, 0xe8310f1b7b5c9a83, 0xfe0d5f884062bf07]),
+  fnv1a_512_test_62: U512, b"curds an", U512::from_words([0xc8729f9a21fb3fa7, 0xa5e5d431b3eba5d3, 0xe669a57828008863, 0x9917b48e8c536b03, 0xf1cb7eb3557c5279, 0x8932b7be4b296932, 0x19bb3dd2491306d9, 0x63eaff8e444e75af]),
+  fnv1a_512_test_63: U512, b"curds and", U512::from_words([0x4fdf00ecb9bc04dd, 0x1938619eea1f19e9, 0x45d1027363b6bd72, 0x1ec2eafe03c46248, 0xf7a6c247899280d6, 0xd2f42ff6b47bf220, 0x79dfd4bfe87e2d44, 0xdfdc679d851fd2fd]),
+  fnv1a_512_test_64: U512, b"curds and ", U512::from_words([0xfa7eb91efb646411, 0x8a734a18f48b93f4, 0x288f686d76d7d3e7, 0x3728da570c1fafc3, 0xd06e4dd9534a9fd4, 0xa52c438bd2116983, 0x4ae60d20810ea747, 0xf04ed40d5da3861b]),
+  fnv1a_512_test_65: U512, b"curds and w", U512::from_words([0x2b9c19ec56ccf98d, 0xa0f95616eef38af6, 0x65828e30a72ceaca, 0xe7bc8aa13e747f5c, 0x43ca4e2e98fc25e9, 0x4e4e805675545ee9, 0x5a3b968ceaa22162, 0xf99e1de876191ab4]),
+  fnv1a_512_test_66: U512, b"curds and wh", U512::from_words([0xc49c100735fc9859, 0x3afcfadb8b48ce3f, 0xea629c4ad92e95dc, 0x7d9dbe0aae16a49e, 0xd40ec06ef9d6cb97, 0xeb2df3d7340b2aa7, 0xe5d6b6ce5f3abb9c, 0x72da12763ba2fcc4]),
+  fnv1a_512_test_67: U512, b"curds and whe", U512::from_words([0x444d84d3fd55f644, 0xd75356e33aff31b5, 0x805b0b459e6aca6c, 0x4e59a04f405690ce, 0x1fc3d4b0becac88c, 0x1a91b556baf626f4, 0xf2aeee8197b15e9d, 0xe22ebc69e7607bb7]),
+  fnv1a_512_test_68: U512, b"curds and whey", U512::from_words([0xda97ee2f631fa52b, 0x02411bd2a9cdc8eb, 0x63e37ac30f15371c, 0xfa15ca2f33fc042c, 0x8f61f8cfa1b2b3b7, 0x9939f7347fce3231, 0x28618fa23ea5c58a, 0x0c9e71e50245e102]),
+  fnv1a_512_test_69: U512, b"curds and whey\n", U512::from_words([0x160fefb0008eae2f, 0xa77aed070cc4d1d4, 0xbacbc13a3d6cd7d3, 0x1331e53ea6a997b4, 0x1c445e31a66ecafe, 0x4caa37573d453fdd, 0x1ab77261f01babf6, 0xe84a99d60ba081b8]),
+  fnv1a_512_test_70: U512, ::std::ffi::CString::new("cu").unwrap().as_bytes_with_nul(), U512::from_words([0x142433ed48a78bb4, 0x29a7dba8911e8824, 0xdcd775e3a3000000, 0x0000001f96475fbd, 0x69323ab91bbf83bd, 0x3e36fbfd7d0c038b, 0x1075dbff4f7a2150, 0xe9f28b6e7db768f3]),
+  fnv1a_512_test_71: U512, ::std::ffi::CString::new("cur").unwrap().as_bytes_with_nul(), U512::from_words([0xf9fe9eefe38ca43f, 0xcf36c8fbc0d25bef, 0x532cab42d4000000, 0x00002a5259a146c7, 0xf24cae042d99828e, 0x5baba0a28b18bf53, 0x0de9c3137ca2a369, 0x73f8d10a70a47ba7]),
+  fnv1a_512_test_72: U512, ::std::ffi::CString::new("curd").unwrap().as_bytes_with_nul(), U512::from_words([0x96b20c29347dfb41, 0xb5e3ebf2c34d2679, 0x7b4a1b662b000000, 0x0038b4561715d5e5, 0xa4bd279918adecbc, 0xd2f439c85e285847, 0xa4345f1bfde8f24a, 0x626014fceca24d25]),
+  fnv1a_512_test_73: U512, ::std::ffi::CString::new("curds").unwrap().as_bytes_with_nul(), U512::from_words([0xb0ec738d9c6fd969, 0xd05f0b35f6c0ecd9, 0x2d37575258000000, 0x4bf99f58ee4196af, 0xb9700e20110830fe, 0xa5396b76280e47fd, 0x022b6e81331ca1a9, 0xcebc1ee10ce05c90]),
+  fnv1a_512_test_74: U512, ::std::ffi::CString::new("curds ").unwrap().as_bytes_with_nul(), U512::from_words([0x82f6e10496de7834, 0xb08b21ef464c131a, 0x7632df5ab8000065, 0xcb74802739e0e571, 0x7522ecf6d1f9a52f, 0x5feefb4fab2273fd, 0xe8310f1b7b5c9a83, 0xfe0d5f88406292d0]),
+  fnv1a_512_test_75: U512, ::std::ffi::CString::new("curds a").unwrap().as_bytes_with_nul(), U512::from_words([0xc8729f9a21fb3fa7, 0xa5e5d431b3eba5d3, 0xe669a577c6008863, 0x9917b48e8c536b03, 0xf1cb7eb3557c5279, 0x8932b7be4b296932, 0x19bb3dd2491306d9, 0x63eaff8e444df261]),
+  fnv1a_512_test_76: U512, ::std::ffi::CString::new("curds an").unwrap().as_bytes_with_nul(), U512::from_words([0x4fdf00ecb9bc04dd, 0x1938619eea1f19e9, 0x45d1027347b6bd72, 0x1ec2eafe03c46248, 0xf7a6c247899280d6, 0xd2f42ff6b47bf220, 0x79dfd4bfe87e2d44, 0xdfdc679d851fad79]),
+  fnv1a_512_test_77: U512, ::std::ffi::CString::new("curds and").unwrap().as_bytes_with_nul(), U512::from_words([0xfa7eb91efb646411, 0x8a734a18f48b93f4, 0x288f686d96d7d3e7, 0x3728da570c1fafc3, 0xd06e4dd9534a9fd4, 0xa52c438bd2116983, 0x4ae60d20810ea747, 0xf04ed40d5da3b0fb]),
+  fnv1a_512_test_78: U512, ::std::ffi::CString::new("curds and ").unwrap().as_bytes_with_nul(), U512::from_words([0x2b9c19ec56ccf98d, 0xa0f95616eef38af6, 0x65828e30562ceaca, 0xe7bc8aa13e747f5c, 0x43ca4e2e98fc25e9, 0x4e4e805675545ee9, 0x5a3b968ceaa22162, 0xf99e1de87618ae2d]),
+  fnv1a_512_test_79: U512, ::std::ffi::CString::new("curds and w").unwrap().as_bytes_with_nul(), U512::from_words([0xc49c100735fc9859, 0x3afcfadb8b48ce3f, 0xea629c4ab12e95dc, 0x7d9dbe0aae16a49e, 0xd40ec06ef9d6cb97, 0xeb2df3d7340b2aa7, 0xe5d6b6ce5f3abb9c, 0x72da12763ba2c72c]),
+  fnv1a_512_test_80: U512, ::std::ffi::CString::new("curds and wh").unwrap().as_bytes_with_nul(), U512::from_words([0x444d84d3fd55f644, 0xd75356e33aff31b5, 0x805b0b45c16aca6c, 0x4e59a04f405690ce, 0x1fc3d4b0becac88c, 0x1a91b556baf626f4, 0xf2aeee8197b15e9d, 0xe22ebc69e760aa9c]),
+  fnv1a_512_test_81: U512, ::std::ffi::CString::new("curds and whe").unwrap().as_bytes_with_nul(), U512::from_words([0xda97ee2f631fa52b, 0x02411bd2a9cdc8eb, 0x63e37ac2f815371c, 0xfa15ca2f33fc042c, 0x8f61f8cfa1b2b3b7, 0x9939f7347fce3231, 0x28618fa23ea5c58a, 0x0c9e71e50245c231]),
+  fnv1a_512_test_82: U512, ::std::ffi::CString::new("curds and whey").unwrap().as_bytes_with_nul(), U512::from_words([0x160fefb0008eae2f, 0xa77aed070cc4d1d4, 0xbacbc13a376cd7d3, 0x1331e53ea6a997b4, 0x1c445e31a66ecafe, 0x4caa37573d453fdd, 0x1ab77261f01babf6, 0xe84a99d60ba079ae]),
+  fnv1a_512_test_83: U512, ::std::ffi::CString::new("curds and whey\n").unwrap().as_bytes_with_nul(), U512::from_words([0xe6976a109c46194b, 0xc7a3b01e129d6ea0, 0x1d0b858a04d52bce, 0xb7da26f14d3a4251, 0xdf9a3485fe71fab8, 0xb81025e317c8913e, 0xcbca4138b51367d1, 0x3bf41dc9940dcd88]),
+  fnv1a_512_test_84: U512, b"hi", U512::from_words([0x7317dfed6c70dfec, 0x6adfced2a5e04d7e, 0xec744e2a4e000000, 0x0000000017933d7a, 0xf45d70def423a316, 0xf14117df272cd0fd, 0x6b85f0f7c9bf6c51, 0x96b3160d028b0282]),
+  fnv1a_512_test_85: U512, ::std::ffi::CString::new("hi").unwrap().as_bytes_with_nul(), U512::from_words([0x142433ed48a78bb4, 0x29a7dba8911e8824, 0xdcd745b104000000, 0x0000001f96475fbd, 0x69323ab91bbf83bd, 0x3e36fbfd7d0c038b, 0x1075dbff4f7a2150, 0xe9f28b6e68405c2e]),
+  fnv1a_512_test_86: U512, b"hello", U512::from_words([0x96b20c29347dfb41, 0xb5e3ebf2c34d2678, 0xeac99926df000000, 0x0038b4561715d5e5, 0xa4bd279918adecbc, 0xd2f439c85e285847, 0xa4345f1bfde8f24a, 0x625fee4cc209f1d7]),
+  fnv1a_512_test_87: U512, ::std::ffi::CString::new("hello").unwrap().as_bytes_with_nul(), U512::from_words([0xb0ec738d9c6fd969, 0xd05f0b35f6c0ebf0, 0xe0de3d06a0000000, 0x4bf99f58ee4196af, 0xb9700e20110830fe, 0xa5396b76280e47fd, 0x022b6e81331ca1a9, 0xce8848d7fb530711]),
+  fnv1a_512_test_88: U512, b"\xff\x00\x00\x01", U512::from_words([0xf9fe9eefe38ca43f, 0xcf36c8fbc0d25bef, 0x4d9c7b1112000000, 0x00002a5259a146c7, 0xf24cae042d99828e, 0x5baba0a28b18bf53, 0x0de9c3137ca2a369, 0x73f8cf2d5cbb353d]),
+  fnv1a_512_test_89: U512, b"\x01\x00\x00\xff", U512::from_words([0xf9fe9eefe38ca43f, 0xcf36c8fbc0d25bef, 0x544d070db8000000, 0x00002a5259a146c7, 0xf24cae042d99828e, 0x5baba0a28b18bf53, 0x0de9c3137ca2a369, 0x73f8d16aff9e42d1]),
+  fnv1a_512_test_90: U512, b"\xff\x00\x00\x02", U512::from_words([0xf9fe9eefe38ca43f, 0xcf36c8fbc0d25bef, 0x4d9c7b110f000000, 0x00002a5259a146c7, 0xf24cae042d99828e, 0x5baba0a28b18bf53, 0x0de9c3137ca2a369, 0x73f8cf2d5cbb3138]),
+  fnv1a_512_test_91: U512, b"\x02\x00\x00\xff", U512::from_words([0xf9fe9eefe38ca43f, 0xcf36c8fbc0d25bef, 0x5469e40562000000, 0x00002a5259a146c7, 0xf24cae042d99828e, 0x5baba0a28b18bf53, 0x0de9c3137ca2a369, 0x73f8d174aaa28e6e]),
+  fnv1a_512_test_92: U512, b"\xff\x00\x00\x03", U512::from_words([0xf9fe9eefe38ca43f, 0xcf36c8fbc0d25bef, 0x4d9c7b1110000000, 0x00002a5259a146c7, 0xf24cae042d99828e, 0x5baba0a28b18bf53, 0x0de9c3137ca2a369, 0x73f8cf2d5cbb328f]),
+  fnv1a_512_test_93: U512, b"\x03\x00\x00\xff", U512::from_words([0xf9fe9eefe38ca43f, 0xcf36c8fbc0d25bef, 0x54604508d4000000, 0x00002a5259a146c7, 0xf24cae042d99828e, 0x5baba0a28b18bf53, 0x0de9c3137ca2a369, 0x73f8d17171a2048f]),
+  fnv1a_512_test_94: U512, b"\xff\x00\x00\x04", U512::from_words([0xf9fe9eefe38ca43f, 0xcf36c8fbc0d25bef, 0x4d9c7b1115000000, 0x00002a5259a146c7, 0xf24cae042d99828e, 0x5baba0a28b18bf53, 0x0de9c3137ca2a369, 0x73f8cf2d5cbb3942]),
+  fnv1a_512_test_95: U512, b"\x04\x00\x00\xff", U512::from_words([0xf9fe9eefe38ca43f, 0xcf36c8fbc0d25bef, 0x547d22007e000000, 0x00002a5259a146c7, 0xf24cae042d99828e, 0x5baba0a28b18bf53, 0x0de9c3137ca2a369, 0x73f8d17b1ca6502c]),
+  fnv1a_512_test_96: U512, b"\x40\x51\x4e\x44", U512::from_words([0xf9fe9eefe38ca43f, 0xcf36c8fbc0d25bef, 0x51ed33508a000000, 0x00002a5259a146c7, 0xf24cae042d99828e, 0x5baba0a28b18bf53, 0x0de9c3137ca2a369, 0x73f8d09f36003d90]),
+  fnv1a_512_test_97: U512, b"\x44\x4e\x51\x40", U512::from_words([0xf9fe9eefe38ca43f, 0xcf36c8fbc0d25bef, 0x5213ea9015000000, 0x00002a5259a146c7, 0xf24cae042d99828e, 0x5baba0a28b18bf53, 0x0de9c3137ca2a369, 0x73f8d0ac34839552]),
+  fnv1a_512_test_98: U512, b"\x40\x51\x4e\x4a", U512::from_words([0xf9fe9eefe38ca43f, 0xcf36c8fbc0d25bef, 0x51ed335098000000, 0x00002a5259a146c7, 0xf24cae042d99828e, 0x5baba0a28b18bf53, 0x0de9c3137ca2a369, 0x73f8d09f36005052]),
+  fnv1a_512_test_99: U512, b"\x4a\x4e\x51\x40", U512::from_words([0xf9fe9eefe38ca43f, 0xcf36c8fbc0d25bef, 0x51b3f54ce1000000, 0x00002a5259a146c7, 0xf24cae042d99828e, 0x5baba0a28b18bf53, 0x0de9c3137ca2a369, 0x73f8d08c17530fdc]),
+  fnv1a_512_test_100: U512, b"\x40\x51\x4e\x54", U512::from_words([0xf9fe9eefe38ca43f, 0xcf36c8fbc0d25bef, 0x51ed33507a000000, 0x00002a5259a146c7, 0xf24cae042d99828e, 0x5baba0a28b18bf53, 0x0de9c3137ca2a369, 0x73f8d09f36002820]),
+  fnv1a_512_test_101: U512, b"\x54\x4e\x51\x40", U512::from_words([0xf9fe9eefe38ca43f, 0xcf36c8fbc0d25bef, 0x5179fa6815000000, 0x00002a5259a146c7, 0xf24cae042d99828e, 0x5baba0a28b18bf53, 0x0de9c3137ca2a369, 0x73f8d078a434f5a2]),
+  fnv1a_512_test_102: U512, b"127.0.0.1", U512::from_words([0x4fdf00ecb9bc04dd, 0x193861afb705bfec, 0xaea76e16abb6bd72, 0x1ec2eafe03c46248, 0xf7a6c247899280d6, 0xd2f42ff6b47bf220, 0x79dfd4bfe880ad93, 0x240076a3da31f232]),
+  fnv1a_512_test_103: U512, ::std::ffi::CString::new("127.0.0.1").unwrap().as_bytes_with_nul(), U512::from_words([0xfa7eb91efb646411, 0x8a73631bcbd82694, 0xa62eb25243d7d3e7, 0x3728da570c1fafc3, 0xd06e4dd9534a9fd4, 0xa52c438bd2116983, 0x4ae60d2084689025, 0x3c9ef58958eb80fe]),
+  fnv1a_512_test_104: U512, b"127.0.0.2", U512::from_words([0x4fdf00ecb9bc04dd, 0x193861afb705bfec, 0xaea76e16aab6bd72, 0x1ec2eafe03c46248, 0xf7a6c247899280d6, 0xd2f42ff6b47bf220, 0x79dfd4bfe880ad93, 0x240076a3da31f0db]),
+  fnv1a_512_test_105: U512, ::std::ffi::CString::new("127.0.0.2").unwrap().as_bytes_with_nul(), U512::from_words([0xfa7eb91efb646411, 0x8a73631bcbd82694, 0xa62eb24f95d7d3e7, 0x3728da570c1fafc3, 0xd06e4dd9534a9fd4, 0xa52c438bd2116983, 0x4ae60d2084689025, 0x3c9ef58958e9b56d]),
+  fnv1a_512_test_106: U512, b"127.0.0.3", U512::from_words([0x4fdf00ecb9bc04dd, 0x193861afb705bfec, 0xaea76e16a9b6bd72, 0x1ec2eafe03c46248, 0xf7a6c247899280d6, 0xd2f42ff6b47bf220, 0x79dfd4bfe880ad93, 0x240076a3da31ef84]),
+  fnv1a_512_test_107: U512, ::std::ffi::CString::new("127.0.0.3").unwrap().as_bytes_with_nul(), U512::from_words([0xfa7eb91efb646411, 0x8a73631bcbd82694, 0xa62eb24ce7d7d3e7, 0x3728da570c1fafc3, 0xd06e4dd9534a9fd4, 0xa52c438bd2116983, 0x4ae60d2084689025, 0x3c9ef58958e7e9dc]),
+  fnv1a_512_test_108: U512, b"64.81.78.68", U512::from_words([0x2b9c19ec56ccf98d, 0xa123ac95e6bf1a73, 0x788464d9752ceaca, 0xe7bc8aa13e747f5c, 0x43ca4e2e98fc25e9, 0x4e4e805675545ee9, 0x5a3b9692126d4a4d, 0x2661b421f65a419f]),
+  fnv1a_512_test_109: U512, ::std::ffi::CString::new("64.81.78.68").unwrap().as_bytes_with_nul(), U512::from_words([0xc49c100735fc9859, 0x78deaa227732d26a, 0x9b59799d9e2e95dc, 0x7d9dbe0aae16a49e, 0xd40ec06ef9d6cb97, 0xeb2df3d7340b2aa7, 0xe5d6bdb6b06e8d5e, 0x6ce8598112edec09]),
+  fnv1a_512_test_110: U512, b"64.81.78.74", U512::from_words([0x2b9c19ec56ccf98d, 0xa123ac95e6bf1a73, 0x788464d6832ceaca, 0xe7bc8aa13e747f5c, 0x43ca4e2e98fc25e9, 0x4e4e805675545ee9, 0x5a3b9692126d4a4d, 0x2661b421f6581af2]),
+  fnv1a_512_test_111: U512, ::std::ffi::CString::new("64.81.78.74").unwrap().as_bytes_with_nul(), U512::from_words([0xc49c100735fc9859, 0x78deaa227732d26a, 0x9b597384b32e95dc, 0x7d9dbe0aae16a49e, 0xd40ec06ef9d6cb97, 0xeb2df3d7340b2aa7, 0xe5d6bdb6b06e8d5e, 0x6ce85981100c1a3e]),
+  fnv1a_512_test_112: U512, b"64.81.78.84", U512::from_words([0x2b9c19ec56ccf98d, 0xa123ac95e6bf1a73, 0x788464de852ceaca, 0xe7bc8aa13e747f5c, 0x43ca4e2e98fc25e9, 0x4e4e805675545ee9, 0x5a3b9692126d4a4d, 0x2661b421f65d72ed]),
+  fnv1a_512_test_113: U512, ::std::ffi::CString::new("64.81.78.84").unwrap().as_bytes_with_nul(), U512::from_words([0xc49c100735fc9859, 0x78deaa227732d26a, 0x9b5983975c2e95dc, 0x7d9dbe0aae16a49e, 0xd40ec06ef9d6cb97, 0xeb2df3d7340b2aa7, 0xe5d6bdb6b06e8d5e, 0x6ce859811734fb8b]),
+  fnv1a_512_test_114: U512, b"feedface", U512::from_words([0xc8729f9a21fb3fa7, 0xa5e5d431b510fa14, 0xb913333486008863, 0x9917b48e8c536b03, 0xf1cb7eb3557c5279, 0x8932b7be4b296932, 0x19bb3dd24913070a, 0x1e4c92358f8246d4]),
+  fnv1a_512_test_115: U512, ::std::ffi::CString::new("feedface").unwrap().as_bytes_with_nul(), U512::from_words([0x4fdf00ecb9bc04dd, 0x193861a0a3dd5e56, 0x2e491da65eb6bd72, 0x1ec2eafe03c46248, 0xf7a6c247899280d6, 0xd2f42ff6b47bf220, 0x79dfd4bfe87e6e8e, 0x9897e5c3478ce60c]),
+  fnv1a_512_test_116: U512, b"feedfacedaffdeed", U512::from_words([0xe6976a10b3fa2fcd, 0x951e9d046d5c7004, 0x3ad0227c4bd52bce, 0xb7da26f14d3a4251, 0xdf9a3485fe71fab8, 0xb81025e317c89338, 0xde36c24dc12662aa, 0x85e6ac5a30555f51]),
+  fnv1a_512_test_117: U512, ::std::ffi::CString::new("feedfacedaffdeed").unwrap().as_bytes_with_nul(), U512::from_words([0xd7f6e4f45d144335, 0x19c58751315ffc57, 0x270e89e8eb9db1f8, 0x554a2d4e790edbb2, 0x979c5f87eab6ed7e, 0x9da2c344ddbd4131, 0xbb5e562dca6e3279, 0x6810ecd6c262b587]),
+  fnv1a_512_test_118: U512, b"feedfacedeadbeef", U512::from_words([0xe6976a10b3fa2fcd, 0x951e9d046d5d18f4, 0xd9d3126b2bd52bce, 0xb7da26f14d3a4251, 0xdf9a3485fe71fab8, 0xb81025e317c89338, 0xde36c24dc12662aa, 0xa67526755262db10]),
+  fnv1a_512_test_119: U512, ::std::ffi::CString::new("feedfacedeadbeef").unwrap().as_bytes_with_nul(), U512::from_words([0xd7f6e4f45d144335, 0x19c587513262e536, 0x4f201072ca9db1f8, 0x554a2d4e790edbb2, 0x979c5f87eab6ed7e, 0x9da2c344ddbd4131, 0xbb5e562dca6e32a5, 0x06f6873162738270]),
+  fnv1a_512_test_120: U512, b"line 1\nline 2\nline 3", U512::from_words([0x1d6d923f613d2ae4, 0xa8a41d3fc516ce0b, 0xae702981921988b4, 0xd6e4efc77313664f, 0x3729c4451f1a07bd, 0x42452e63f0082f9b, 0x089c608d3394904e, 0x05ef381c83fdf207]),
+  fnv1a_512_test_121: U512, b"chongo <Landon Curt Noll> /\\../\\", U512::from_words([0x51421b9f1c00632f, 0x71d4a349ebc2b9c5, 0xfa885f5ba098aec6, 0x1d0f993918d9eaac, 0x3bb6cc3496352252, 0xc7fd77a45bf1f229, 0xd520e7f7fac62f2b, 0x9a59287e37437bc2]),
+  fnv1a_512_test_122: U512, ::std::ffi::CString::new("chongo <Landon Curt Noll> /\\../\\").unwrap().as_bytes_with_nul(), U512::from_words([0x83eef420ae5a0579, 0x7be18e3a0d81416b, 0x2aef073fee922b70, 0xefe64b804bf96cc4, 0x01eb9a754130fce9, 0xf49b4d37312b760c, 0x8f16cd40ff89336b, 0xcd75411c0b6ad0ee]),
+  fnv1a_512_test_123: U512, b"chongo (Landon Curt Noll) /\\../\\", U512::from_words([0xa283952c6c834ee2, 0x55d2235095896d42, 0x0a3b5eee5c98aec6, 0x1d0f993918d9eaac, 0x3bb6cc3495bfbd2b, 0xd37d3465c90c4239, 0x2b7d991374dc84d7, 0x8068d731fb4319e5]),
+  fnv1a_512_test_124: U512, ::std::ffi::CString::new("chongo (Landon Curt Noll) /\\../\\").unwrap().as_bytes_with_nul(), U512::from_words([0x2415eac79d1a2eda, 0x10022d7d32a1cc52, 0xe7877477f5922b70, 0xefe64b804bf96cc4, 0x01eb9a74a3e675b8, 0x5cc134605f6cba99, 0x454819119375fcbd, 0x0c7853f7a6e7b1d3]),
+  fnv1a_512_test_125: U512, b"http://antwrp.gsfc.nasa.gov/apod/astropix.html", U512::from_words([0xea36f2c173f8b695, 0x7e1948dd1db2f87c, 0xcff942699ec85006, 0x91dfa6f4e9a734b9, 0xcfee12944e06994f, 0x22b3d76fd42f8b5e, 0xe52477be39810b30, 0xf38ce92f1d066fd6]),
+  fnv1a_512_test_126: U512, b"http://en.wikipedia.org/wiki/Fowler_Noll_Vo_hash", U512::from_words([0xf2851543f9f02730, 0xa68e904fa897f580, 0x1b162a3d7d8a7e91, 0x238919a10ad5e63c, 0xcdc888fddd5229de, 0x9c6a2ad5d27a7e1d, 0x1159b470aea8e64e, 0xc39a8eeaea0e6d2d]),
+  fnv1a_512_test_127: U512, b"http://epod.usra.edu/", U512::from_words([0x127a1abc2c2d5f70, 0xbb5b115a4652b704, 0xcd92c52806362a4b, 0xecbd443b2efe1422, 0xe8f5f89cabe05e93, 0xcaa990845b9c44d7, 0xedc3083f453edfa5, 0x85b0191823103e77]),
+  fnv1a_512_test_128: U512, b"http://exoplanet.eu/", U512::from_words([0xc90775b11ba10446, 0x109091a458043177, 0xbba4bd02c91988b4, 0xd6e4efc77313664f, 0x3729c4451f1a07bd, 0x424528b97b6a7027, 0x70a5824824c13b06, 0x0ba60cfc3eceedbc]),
+  fnv1a_512_test_129: U512, b"http://hvo.wr.usgs.gov/cam3/", U512::from_words([0x59c2eec252a6597a, 0xddc1edbb0357fce3, 0xb8ac27dfbcb3d532, 0x5884182a0866985f, 0x20b4391d9fd6a5e2, 0x88855b6e16f74ed2, 0xb40b1286e80daf37, 0x3ad7101652c090a7]),
+  fnv1a_512_test_130: U512, b"http://hvo.wr.usgs.gov/cams/HMcam/", U512::from_words([0xf0c87c0975c71ef0, 0x0b503e8931faf829, 0xa731dce0ffd83451, 0x6d8f28e2edf5a2a5, 0x2842ab939f3feef0, 0x93c4da78da40474c, 0x1aea3c4afaf3e1b6, 0x0f87d20469b33424]),
+  fnv1a_512_test_131: U512, b"http://hvo.wr.usgs.gov/kilauea/update/deformation.html", U512::from_words([0x44f6cb0efb87bbd4, 0xad71779afafad1b6, 0xba85eff389e31623, 0x738f8826346d8940, 0x12916d30db7419e2, 0x325cc744f22639f1, 0xf456377cdad1fff1, 0xcab080c57fec8212]),
+  fnv1a_512_test_132: U512, b"http://hvo.wr.usgs.gov/kilauea/update/images.html", U512::from_words([0x06517bae889ebae9, 0x1db8b1f47f21d622, 0xdf125a7cbb4777fe, 0x664bdc4423e0f414, 0xa99f3e1039f4f0ce, 0x3b4fe76a7eef5e99, 0xb6578a5f8e1b5ab3, 0x692506906f36f91c]),
+  fnv1a_512_test_133: U512, b"http://hvo.wr.usgs.gov/kilauea/update/maps.html", U512::from_words([0x5e8696afd725ce09, 0x9749fa8cc922b2ca, 0x6ee8342243739e47, 0xd8916b9a71a8dc6f, 0x99dc978616ee490e, 0xd0443176bc0c42ec, 0x2e31f18761fd02fc, 0x7672b0258ad313e3]),
+  fnv1a_512_test_134: U512, b"http://hvo.wr.usgs.gov/volcanowatch/current_issue.html", U512::from_words([0xeea2c0181e1d5079, 0x607d8c9090c296da, 0xd5f47b8ea5e31623, 0x738f8826346d867e, 0x214da44e070183b6, 0x02c02a7966a8afa4, 0x5f77e63321a4fd2e, 0x9a15552589ef9ff0]),
+  fnv1a_512_test_135: U512, b"http://neo.jpl.nasa.gov/risk/", U512::from_words([0x9c12a00188447e19, 0x39f6f56ad6f27eee, 0xb1d6bd0e45f2a674, 0x98fc605141762774, 0xd17886b128984284, 0xdc51ce697047a71b, 0xcc8b4e0e4ad8b422, 0xf080e9cd9aea9fa7]),
+  fnv1a_512_test_136: U512, b"http://norvig.com/21-days.html", U512::from_words([0x50b2e1091f83191b, 0x5f0c8ecea058834c, 0x9ca1afd3181d0638, 0xfa250cdeb54edd84, 0xa87c775d64012003, 0x18a78c8c3d2e3f8a, 0xf6dbcc15431d7977, 0x584b33db9752cd4e]),
+  fnv1a_512_test_137: U512, b"http://primes.utm.edu/curios/home.php", U512::from_words([0xac2ac1c0054330e7, 0xe37fd441695a7ba3, 0x2eefc651889d763e, 0x6b5f4f93fe3d0cff, 0xbd07c5eadb30d587, 0xc224a1d20ff5eb6d, 0xa8a950b1ced5a521, 0x3c172bef561fbbfd]),
+  fnv1a_512_test_138: U512, b"http://slashdot.org/", U512::from_words([0xc90775b11ba1042e, 0xe9a87b0db37bbf33, 0xe0504c975b1988b4, 0xd6e4efc77313664f, 0x3729c4451f1a07bd, 0x424528b97b6a7027, 0x70a320d8665c538b, 0x12cebeaccc7d0044]),
+  fnv1a_512_test_139: U512, b"http://tux.wr.usgs.gov/Maps/155.25-19.5.html", U512::from_words([0x5b5cad5ac49e1a2e, 0x2d79ed1da246e520, 0x02536674c1406994, 0x072a3be6c1af88e8, 0xa1139d2d58b3abe6, 0xd67d7937d0ad5afe, 0x310f06a429584184, 0xa37fc2ed16d4c0b3]),
+  fnv1a_512_test_140: U512, b"http://volcano.wr.usgs.gov/kilaueastatus.php", U512::from_words([0x9e1d49003b52e333, 0x2bcc908ea4280ed2, 0x3a839c60b0406994, 0x05e54a24132f2365, 0xf131a4ca783d0d3e, 0xa87bf8da6d6609af, 0xba1b2d7d77c52168, 0x9dc0db2dcfad9e83]),
+  fnv1a_512_test_141: U512, b"http://www.avo.alaska.edu/activity/Redoubt.php", U512::from_words([0xfa3de2da4252acf6, 0x8f6dd1198aaca781, 0x070684dd8cc8319c, 0x7cf24985cbabbbd4, 0x95f7aec9ecd8b0e2, 0xd2efce4b61463824, 0x018b8cf5322f1c00, 0x43f6d9eac17aefe7]),
+  fnv1a_512_test_142: U512, b"http://www.dilbert.com/fast/", U512::from_words([0x836ec9c5219a76c2, 0x0a99b25c32a1bcc3, 0xb00a0fff15b3d532, 0x5884182a0866985f, 0x20b4391d9fd6a5e2, 0x884cf788304ea970, 0xca8ae6e761aa8c8f, 0xadd75db7cdf08a75]),
+  fnv1a_512_test_143: U512, b"http://www.fourmilab.ch/gravitation/orbits/", U512::from_words([0x905a734677e31ffd, 0xbd3a3dd9e54958b4, 0x0b9ef9f25c9cec27, 0xfd076227d25b4647, 0x4e949251ac78670e, 0x3dd39f74be6eec67, 0x2643c7125d4288db, 0x884dbcf59ecabaa0]),
+  fnv1a_512_test_144: U512, b"http://www.fpoa.net/", U512::from_words([0xc90775b11ba10427, 0xb01b4e00b6eefddd, 0x1e1f8941201988b4, 0xd6e4efc77313664f, 0x3729c4451f1a07bd, 0x424528b97b6a7027, 0x70a26148203cd0af, 0x08c76a1a6c22edc4]),
+  fnv1a_512_test_145: U512, b"http://www.ioccc.org/index.html", U512::from_words([0xda1d8b2784f28bd8, 0x909dc8db12a5ee3c, 0x7649845b33e35657, 0x27a43e64ecaaccbd, 0xbec3ee20fd81766b, 0xe2334bc62ae25a09, 0x68df94a5b5a66c82, 0x2f36b1616267aa9b]),
+  fnv1a_512_test_146: U512, b"http://www.isthe.com/cgi-bin/number.cgi", U512::from_words([0x67a036a78cb198ba, 0x7efca5172ecd94de, 0x860aee055631e3de, 0xb4aa6baa76e19ed5, 0x4f0ddacb458d7899, 0x51f42fa1136214f5, 0xb81a764ed357ce20, 0xdf7ea990b4ad9a95]),
+  fnv1a_512_test_147: U512, b"http://www.isthe.com/chongo/bio.html", U512::from_words([0x6effd4030fc4a654, 0x1bfc672cb75a87e2, 0x9ffab888a343a18e, 0xbc65093032bf3ebb, 0x551b371ce209a1ac, 0x70615ff2d475b461, 0x0d4d80a6dfdbc0a9, 0x45ea04353eba5873]),
+  fnv1a_512_test_148: U512, b"http://www.isthe.com/chongo/index.html", U512::from_words([0x455bfc5d9640b290, 0x92d278ba28333a0c, 0xffaa298493f96da1, 0xdcb39f49a3cc69b3, 0x003b00a34c54c02a, 0x3e2fbb7961152dd2, 0x7e9111e3cd8e25c5, 0xa4cf0d152374bc9d]),
+  fnv1a_512_test_149: U512, b"http://www.isthe.com/chongo/src/calc/lucas-calc", U512::from_words([0x47f1b8786865bf97, 0xb277dd4f6475d9ff, 0x4af306375e3a78ab, 0x689c158a58e41d52, 0xddcb3efd190fd6d2, 0x97cdcbc5df05c166, 0x4423be9580874112, 0x15b87f4c84f4869f]),
+  fnv1a_512_test_150: U512, b"http://www.isthe.com/chongo/tech/astro/venus2004.html", U512::from_words([0x3644d189067c3162, 0x6c9184978816ef25, 0x70c7e142534694ac, 0xfd3814dbb3356cc0, 0x3b9212f33f02b09b, 0x7121f5e417ecff92, 0x067536463971cf61, 0xcc389697c7a9bf91]),
+  fnv1a_512_test_151: U512, b"http://www.isthe.com/chongo/tech/astro/vita.html", U512::from_words([0xeec95739ed3ed2b2, 0x4149b44eed6380e4, 0x665017517857ada9, 0x2920dc5d19a34a07, 0x2b51651c9438d429, 0x64bc021a68efd20b, 0xc44f307dae4b55fe, 0x0d7bda81508992d4]),
+  fnv1a_512_test_152: U512, b"http://www.isthe.com/chongo/tech/comp/c/expert.html", U512::from_words([0x505c959e5842f565, 0xa8fc84e07580b171, 0xf77870e59a442d8b, 0x502f2b39c7bdf1d8, 0xa5ab7209317eb94c, 0x432861bfa0a56534, 0x8ab3cd10ca1935c3, 0x092a4782c2a97106]),
+  fnv1a_512_test_153: U512, b"http://www.isthe.com/chongo/tech/comp/calc/index.html", U512::from_words([0x052edc22d7979977, 0x30eeeec8dc768aaf, 0x23c53691994694ac, 0xfd3814dbb3356cc0, 0x3b9212f33f02b09b, 0x7121f2928a238772, 0xe4c83613817850d0, 0xa853493495e12dc2]),
+  fnv1a_512_test_154: U512, b"http://www.isthe.com/chongo/tech/comp/fnv/index.html", U512::from_words([0x6bad1c8d76418be0, 0xc95bd9411b57c493, 0xdabd55c4e65905a8, 0x6f32ea6a9f7f0945, 0xf8b5ca5150ca452d, 0xfb1af7c03d9a9965, 0xd8242dc310f7b979, 0xe6878df5d8c1acc1]),
+  fnv1a_512_test_155: U512, b"http://www.isthe.com/chongo/tech/math/number/howhigh.html", U512::from_words([0x0d6cbe01986ea2c1, 0x025c3d437ef31c2c, 0x440f4dd734a089f4, 0x837b59660d33102c, 0xbc2bc53b81366d47, 0xc7cecd4072f586dd, 0x0eb8342c5750b3b8, 0xff29f5c3fa10b83d]),
+  fnv1a_512_test_156: U512, b"http://www.isthe.com/chongo/tech/math/number/number.html", U512::from_words([0x2f5adcbdf5699e09, 0x6357259cb20b6e21, 0xae11b92b17149ea1, 0x2de938fbd1c3b1ae, 0x08031cefc0efbd25, 0x86ed329aaefb7dc7, 0xebe42cef8a88b1b1, 0x6a961fc05933cae8]),
+  fnv1a_512_test_157: U512, b"http://www.isthe.com/chongo/tech/math/prime/mersenne.html", U512::from_words([0x0d53868d1db0a632, 0x87e3f3ca3159cd37, 0x3ee7d40929a089f4, 0x837b59660d33102c, 0xbc2bc53b81366d47, 0xc7cecd3eaf42ad10, 0xb46a47ff10b5b07d, 0x06917c9f38dd10e8]),
+  fnv1a_512_test_158: U512, b"http://www.isthe.com/chongo/tech/math/prime/mersenne.html#largest", U512::from_words([0xc1cdafa8de101776, 0x8fb0e60181809526, 0x75db7b2d2fb62f5e, 0xd98dfda8f4287b73, 0x7975ba748f53bad6, 0x2a17bec6871d9263, 0x12f78ad5f4f52753, 0xf7e1c701673d9613]),
+  fnv1a_512_test_159: U512, b"http://www.lavarnd.org/cgi-bin/corpspeak.cgi", U512::from_words([0xabb856a3f8a21b09, 0x47d89f7c9374dbe6, 0x2beae8656a406994, 0x04e4835ad5de8f6e, 0x1c5fb04710543c20, 0xf3de10bd0c03e36f, 0x1a0fe2492e7e84e6, 0xae1a003421e0466e]),
+  fnv1a_512_test_160: U512, b"http://www.lavarnd.org/cgi-bin/haiku.cgi", U512::from_words([0xec71d763b958a146, 0xb1b185c9ce0e890c, 0xc95a5df7fad84f64, 0x10564165484bcfcc, 0xeb29f5ab9bd588af, 0xc0495dbe93cc1e59, 0x833399892d854386, 0x18f2820d61ba122e]),
+  fnv1a_512_test_161: U512, b"http://www.lavarnd.org/cgi-bin/rand-none.cgi", U512::from_words([0xabb856a3f8a21aef, 0xce2b2e3b7256a0c0, 0x1d17dab9a7406994, 0x04e4835ad5de8f6e, 0x1c5fb04710543c20, 0xf3de10bd0c03e36f, 0x1a0d422c7dc95ff9, 0x79ead1ad2ccd04ac]),
+  fnv1a_512_test_162: U512, b"http://www.lavarnd.org/cgi-bin/randdist.cgi", U512::from_words([0xc5eb7ee9cb3ae368, 0xfbdc87c86ee4c9fc, 0xf8d0fcb66f9cec27, 0xfd076227d2597716, 0xb63189d7e76af975, 0x461f3f7d314b64ac, 0xbb694659b140c8f7, 0x6e544446ae5fd46d]),
+  fnv1a_512_test_163: U512, b"http://www.lavarnd.org/index.html", U512::from_words([0x7e3f7b750f2a3a90, 0x66f153ec31637d41, 0x622bcca939922b70, 0xefe64b8049d62c82, 0x58ef0736b5a781ad, 0x0ac2cb6002ff504f, 0x72ecdc0647a92fa3, 0x35fa18b36ff2091c]),
+  fnv1a_512_test_164: U512, b"http://www.lavarnd.org/what/nist-test.html", U512::from_words([0xbad9c8736dddff3d, 0x446be94ff7c4e861, 0x55416f3bebdd60f7, 0xe81ba2fde0851cb5, 0x6e3ed69b2005894f, 0x74a2cc05270fb878, 0x4249b4b1cc263558, 0x1006e10782ecd4ba]),
+  fnv1a_512_test_165: U512, b"http://www.macosxhints.com/", U512::from_words([0x55d23e005e94f89a, 0x30914a366afa1fc6, 0xd29c7d695a7d2a6a, 0xe02a2e583148b213, 0xaebe198ae895268c, 0x3a9cf586bdb7131d, 0xf52e178b2fed0008, 0x9359d7870074c365]),
+  fnv1a_512_test_166: U512, b"http://www.mellis.com/", U512::from_words([0x45f56e640879a3ea, 0xfaae3e1517bcecbe, 0xbcdecf28a992abba, 0x31966b4bf66cfac6, 0x219019ea499eb804, 0x8930a156be603d49, 0xf0656ba66ca0ce8e, 0x8f2594ecd6f7f38e]),
+  fnv1a_512_test_167: U512, b"http://www.nature.nps.gov/air/webcams/parks/havoso2alert/havoalert.cfm", U512::from_words([0x35ed7fa51320ba25, 0x059f5823132b0103, 0x9cef472e24a46c09, 0xa61ff69c20ad2b4c, 0x5ebbf57a8643c531, 0xebc19ba501a9265f, 0x2955e361c970e29f, 0xec7b4d206f3d5a5c]),
+  fnv1a_512_test_168: U512, b"http://www.nature.nps.gov/air/webcams/parks/havoso2alert/timelines_24.cfm", U512::from_words([0x1d5555bdb2fcb3de, 0x01d2ee9d7d6658bc, 0x85861db260634068, 0x5cee61b147cc9267, 0x3ba00bf687d94dda, 0xbf39445bd826ec43, 0x6eb7b65ec6e68377, 0x93d60e9fa6eec9d1]),
+  fnv1a_512_test_169: U512, b"http://www.paulnoll.com/", U512::from_words([0x1f16c61707fbedfe, 0x7a2d0de3cf87a400, 0x992c094062fa1b5e, 0xc893f8dbf95b4866, 0x6115bfb346d65c8b, 0xe5d1e978626648e7, 0x01748f4b07a2b927, 0xd493b0a6ecd7bc23]),
+  fnv1a_512_test_170: U512, b"http://www.pepysdiary.com/", U512::from_words([0x08bf95b73f4f5feb, 0xe156bb732742b583, 0x8c39555ad9bc7250, 0xeb025caee783920e, 0xfc08048d7759404c, 0x8980159d20a57a73, 0xcef49d1d12a5b927, 0x060ee4c2df6241f4]),
+  fnv1a_512_test_171: U512, b"http://www.sciencenews.org/index/home/activity/view", U512::from_words([0xc521497126feb530, 0x8f516a00da5937c4, 0x21b3cf19863964c5, 0xad9b25ddfa76256a, 0x79f106efcfda1f04, 0xe82baa8649d60dca, 0x7ccb292194f00205, 0x368d6ed26b1492b0]),
+  fnv1a_512_test_172: U512, b"http://www.skyandtelescope.com/", U512::from_words([0x6334a298ae0c4a89, 0x8eb0849eea4a7e93, 0x0215531a76e35657, 0x27a43e64ecaaccbd, 0xbec3ee20fd81766b, 0xe23343d14ed29e00, 0x7db69df3c7e23e47, 0x11a6dce0cc438e42]),
+  fnv1a_512_test_173: U512, b"http://www.sput.nl/~rob/sirius.html", U512::from_words([0x38237df9fd4ddd6e, 0x97a8c2c74cfc1df7, 0xea2d4e808aae1919, 0xcacfc80cd41ceb48, 0xf1522db03961b32a, 0x4d08d4ac3ae3bb41, 0x3d1c6d8bd6685e47, 0x222349a6cb85940d]),
+  fnv1a_512_test_174: U512, b"http://www.systemexperts.com/", U512::from_words([0xa1a04b9e241a6dd0, 0xf3ba28baf739b117, 0x2d8b49eaf9f2a674, 0x98fc605141762774, 0xd17886b128984284, 0x9f1fa778b2b03b9e, 0x8f32396ead75a0a2, 0x710c24ffa116d75a]),
+  fnv1a_512_test_175: U512, b"http://www.tq-international.com/phpBB3/index.php", U512::from_words([0x012da68c6d4b2934, 0xa9717337f04b945a, 0x62ab932ecb57ada9, 0x1f19dc052885abd4, 0xf71c690eee799f59, 0xea17c809f8f1c0ab, 0x7e5ba6c438fb9242, 0x0d9e951dae7d349a]),
+  fnv1a_512_test_176: U512, b"http://www.travelquesttours.com/index.htm", U512::from_words([0x12c0a695b18d9a5e, 0x0a8b7fccc8d5e216, 0x2213f0fc9dd25f11, 0xe3919eb3dd936b8e, 0x530c52979d894f08, 0xf9b8d832aee3d97f, 0xd857d0a88bd8338f, 0x123f972dc92504b0]),
+  fnv1a_512_test_177: U512, b"http://www.wunderground.com/global/stations/89606.html", U512::from_words([0xf108feffada0ff8e, 0x1d12c817e461a208, 0x53a7321334a63798, 0x2e78feac9369566e, 0xd821a81d922dbb3d, 0x9bdcb9ceb951c35c, 0x1fcdb10c219807bd, 0x6517c2518f21bd38]),
+  fnv1a_512_test_178: U512, &repeat(b"21701", 10), U512::from_words([0x3e422efbaa846f61, 0x32b134ee1d4d9d5f, 0x05f4c58da7d7bf79, 0x1caae4b4a57bc51e, 0xd246e7761ddf8c9d, 0x8cc9b39f68442843, 0xa95de9b281c89cb8, 0xaa957463eddae3fb]),
+  fnv1a_512_test_179: U512, &repeat(b"M21701", 10), U512::from_words([0xd7861d542f433898, 0x006f6468b617c0fc, 0xc61ed36797a1d18c, 0x166ef146b72712be, 0x7a1375fabb7b3a43, 0xb9682e38677062f8, 0x4ab10f47019e40b2, 0xc03d4d47ac5d9031]),
+  fnv1a_512_test_180: U512, &repeat(b"2^21701-1", 10), U512::from_words([0x989da7c7b10b907e, 0x82a9eb73dcbf739a, 0x2eb6effec897b1cb, 0xaf5b17e4d5d1986a, 0xb6bf2601ae6f089e, 0xcac2afab932e869c, 0x2b2a6ae3fd484d3d, 0x699b6a9a15d04143]),
+  fnv1a_512_test_181: U512, &repeat(b"\x54\xc5", 10), U512::from_words([0xe24c7cc906a93e48, 0x82cbb1619c634fc4, 0x1e4d2e976a1988b4, 0xd6e4efc77313664f, 0x3729c4451f1a07bd, 0x4244f6ebdf0537f6, 0xcbaffbb9fb67d14a, 0x42075af12bff5609]),
+  fnv1a_512_test_182: U512, &repeat(b"\xc5\x54", 10), U512::from_words([0xfed38913a8e21d41, 0x91e10c875c37a05d, 0x03e09765a61988b4, 0xd6e4efc77313664f, 0x3729c4451f1a07bd, 0x42445e88e76647e5, 0x902fe294bb5b8606, 0x20f9c5e0635b39b1]),
+  fnv1a_512_test_183: U512, &repeat(b"23209", 10), U512::from_words([0x9c4b288583f30db6, 0xb6bc317b88be62e2, 0x20efdb42ad8fe7c5, 0x7f659624f5efc606, 0xcf36655330e7d518, 0x7c9cfb9b9e2527a2, 0x1bcda82d05ec1219, 0x5882d5311275fa45]),
+  fnv1a_512_test_184: U512, &repeat(b"M23209", 10), U512::from_words([0xb2794edc0d3fb79d, 0xc3cf90309f6ae6b9, 0x1b09a979f4693ab8, 0x06914a533e49163e, 0x9bcf3d79358e2aba, 0x4029f70cccca7187, 0x1d522c10a4f438d7, 0x34a5788d78b95455]),
+  fnv1a_512_test_185: U512, &repeat(b"2^23209-1", 10), U512::from_words([0x00e3b72747d44040, 0x3276cf1aa90186f2, 0xc1727aedbb3cca7d, 0xf19cc9238aa5d589, 0x0496c9e41ae34ce6, 0xbb25abca335cf63b, 0xeedf848ed88ddb79, 0xf4b4d26b4da88f29]),
+  fnv1a_512_test_186: U512, &repeat(b"\x5a\xa9", 10), U512::from_words([0x1d3473e538f7c290, 0xf4c403314721624e, 0x180e9585c61988b4, 0xd6e4efc77313664f, 0x3729c4451f1a07bd, 0x4244e9bcbb9b7163, 0x3dbd8ad61cc3f325, 0x1d4750f381c0186d]),
+  fnv1a_512_test_187: U512, &repeat(b"\xa9\x5a", 10), U512::from_words([0xa4628d18ffa806ac, 0x93422ae36dcd0f9b, 0x8b244668ce1988b4, 0xd6e4efc77313664f, 0x3729c4451f1a07bd, 0x4244d085c575d377, 0x95d604975e1582e4, 0xbc748f4a76ce2a75]),
+  fnv1a_512_test_188: U512, &repeat(b"391581216093", 10), U512::from_words([0xdc6dd964a4584f7f, 0x3fd2fb160fa53287, 0x6ab1514182878b14, 0xcee354b94e9dee43, 0x8b6f02a4a99e45ab, 0x810b44bfc7397cd6, 0x9092857b35ee0a6b, 0x5b3e42fbfa7dba7d]),
+  fnv1a_512_test_189: U512, &repeat(b"391581*2^216093-1", 10), U512::from_words([0xb2e149f6a0dfbaf4, 0xdf7235b3f9de69c1, 0x81faa95e597c08bb, 0xb42d9891e3dd2a9c, 0x486b8257e62a61e7, 0x378025b97aa1b387, 0x0b2ece18cad18862, 0x96f7a537c49547e5]),
+  fnv1a_512_test_190: U512, &repeat(b"\x05\xf9\x9d\x03\x4c\x81", 10), U512::from_words([0x3908224dc236bc29, 0x26dc2a86853d742a, 0x5b328b3a7cf517ea, 0x96ac317da7cef2ee, 0x68ec592186900ac2, 0x7dbfc9d9d2bf57c8, 0x4d97a78b6cc81ccc, 0x2c5359d5b50d04a9]),
+  fnv1a_512_test_191: U512, &repeat(b"FEDCBA9876543210", 10), U512::from_words([0x14ad0eb83013e994, 0x7db945e4b09ac959, 0x72470fa9e2931fec, 0x7971235e3dca9a24, 0x07b391bd7a875953, 0x0abff0426371c062, 0x3ab7f6e8f51f5654, 0x986f9568e100f459]),
+  fnv1a_512_test_192: U512, &repeat(b"\xfe\xdc\xba\x98\x76\x54\x32\x10", 10), U512::from_words([0x452253579b0b5a75, 0x25b92ed1be012601, 0x22ebfdbc3bf491bc, 0x3e4cd59bbca25ef7, 0xb7e752fd10c73210, 0xb1bf5ca30b9b7c97, 0x0be3ba96ccf6c8f9, 0x562b8c811ee9e899]),
+  fnv1a_512_test_193: U512, &repeat(b"EFCDAB8967452301", 10), U512::from_words([0x48f2292de67852ae, 0x47f9c95530ebf46e, 0x10e1ff69a708267f, 0xafa012973e840f68, 0x0dd57ca8600c9a75, 0xa08e7cf490bb81cd, 0xbc6c6101a726fe11, 0x91f48803d90d9399]),
+  fnv1a_512_test_194: U512, &repeat(b"\xef\xcd\xab\x89\x67\x45\x23\x01", 10), U512::from_words([0xff14eacfac6b2242, 0xa39852865ce7896e, 0x509dd87191326381, 0x39b33a6e9ca5cb09, 0x17489625007ec456, 0x1ba73a69617a3f7d, 0x0ca29cdda89a0cc3, 0x1f9570e701af3f99]),
+  fnv1a_512_test_195: U512, &repeat(b"0123456789ABCDEF", 10), U512::from_words([0x8640dc890a655972, 0x61c0294822fd174f, 0xeb5a7c9b7b0d7ea7, 0x06e42db407869487, 0x195e2c82350f45ea, 0xb63fb3ac9a9be5ce, 0x0233b6476edd585c, 0x4df38c553d728199]),
+  fnv1a_512_test_196: U512, &repeat(b"\x01\x23\x45\x67\x89\xab\xcd\xef", 10), U512::from_words([0xc67f73e164911ed0, 0x7591ec976c7f3cfe, 0xcbd898fe4b351e73, 0x4c2e712a7cea9e28, 0x71b67da6375b0d6f, 0x52eec52d72b7d7e8, 0x5f43d5fb487c3875, 0xc02994e274050f99]),
+  fnv1a_512_test_197: U512, &repeat(b"1032547698BADCFE", 10), U512::from_words([0xee36088ead2d6e59, 0x920c855769952705, 0xbf73f330419329f2, 0x9bd8f89acfe5f81d, 0x45d01a16f186ce8f, 0x2df49c07c21a5389, 0xf5ca7cd19ca7db0d, 0x89bf21b36b840669]),
+  fnv1a_512_test_198: U512, &repeat(b"\x10\x32\x54\x76\x98\xba\xdc\xfe", 10), U512::from_words([0xf6594d6dfb16f625, 0xb8832c49b316db3b, 0xdf0826933fbf9a0c, 0x616a7c9a5b342503, 0xb5c9ca51d2734a0b, 0x657d3667d1569275, 0xff3a9dabca4e7e76, 0x52e4cd41db436a19]),
+  fnv1a_512_test_199: U512, &repeat(b"\x00", 500), U512::from_words([0xc19b47b4943e2edb, 0xd6658fba79d71e19, 0x052c9e8de22f7f77, 0xcaf0e8bd9894c081, 0x46507992c0023f2d, 0x39689f79d0dfe680, 0xfab06b976086472e, 0x4a21dd5f96c67e79]),
+  fnv1a_512_test_200: U512, &repeat(b"\x07", 500), U512::from_words([0x868b20bebcbe676f, 0x715aaa2b726a976d, 0x5f97f95fb064c37f, 0xf6783932fdec532f, 0xc175626b73e3ff43, 0x6a7d2bccc67c5ca5, 0xf97044ea185199f2, 0xef1dcb7cfaec5b7d]),
+  fnv1a_512_test_201: U512, &repeat(b"~", 500), U512::from_words([0x4df7a9cc03ac68e9, 0x9fbd37bebbcb0368, 0x6981a255785b53bf, 0x75a89db4ec7708b6, 0x2e64d8bd80ef976b, 0x84b978444e312ef0, 0x281fa2a17bc19ea7, 0x8f12e0d07f752a79]),
+  fnv1a_512_test_202: U512, &repeat(b"\x7f", 500), U512::from_words([0x090f980a8f15746e, 0x7c951d179284d414, 0xeec7f49e855f8bf7, 0x02be43bdaa9985e0, 0xea8050c8d835bac5, 0xfe97d94e6299cc7f, 0x694621d0a4d98c44, 0xdf04d3ecaf0d303d]),
+  fnv1a_1024_test_0: U1024, b"", U1024::from_words([0x0000000000000000, 0x005f7a76758ecc4d, 0x32e56d5a591028b7, 0x4b29fc4223fdada1, 0x6c3bf34eda3674da, 0x9a21d90000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000000000004c6d7, 0xeb6e73802734510a, 0x555f256cc005ae55, 0x6bde8cc9c6a93b21, 0xaff4b16c71ee90b3]),
+  fnv1a_1024_test_1: U1024, b"a", U1024::from_words([0x0000000000000000, 0x98d7c19fbce653df, 0x221b9f717d3490ff, 0x95ca87fdaef30d1b, 0x823372f85b24a372, 0xf50e570000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000007685cd8, 0x1a491dbccc21ad06, 0x648d09a5c8cf5a78, 0x482054e91470b33d, 0xde77252caef695aa]),
+  fnv1a_1024_test_2: U1024, b"b", U1024::from_words([0x0000000000000000, 0x98d7c19fbce653df, 0x221b9f717d3490ff, 0x95ca87fdaef30d1b, 0x823372f85b24a372, 0xf50e560000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000007685cd8, 0x1a491dbccc21ad06, 0x648d09a5c8cf5a78, 0x482054e91470b33d, 0xde77252caef6941d]),
+  fnv1a_1024_test_3: U1024, b"c", U1024::from_words([0x0000000000000000, 0x98d7c19fbce653df, 0x221b9f717d3490ff, 0x95ca87fdaef30d1b, 0x823372f85b24a372, 0xf50e550000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000007685cd8, 0x1a491dbccc21ad06, 0x648d09a5c8cf5a78, 0x482054e91470b33d, 0xde77252caef69290]),
+  fnv1a_1024_test_4: U1024, b"d", U1024::from_words([0x0000000000000000, 0x98d7c19fbce653df, 0x221b9f717d3490ff, 0x95ca87fdaef30d1b, 0x823372f85b24a372, 0xf50e5c0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000007685cd8, 0x1a491dbccc21ad06, 0x648d09a5c8cf5a78, 0x482054e91470b33d, 0xde77252caef69d6b]),
+  fnv1a_1024_test_5: U1024, b"e", U1024::from_words([0x0000000000000000, 0x98d7c19fbce653df, 0x221b9f717d3490ff, 0x95ca87fdaef30d1b, 0x823372f85b24a372, 0xf50e5b0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000007685cd8, 0x1a491dbccc21ad06, 0x648d09a5c8cf5a78, 0x482054e91470b33d, 0xde77252caef69bde]),
+  fnv1a_1024_test_6: U1024, b"f", U1024::from_words([0x0000000000000000, 0x98d7c19fbce653df, 0x221b9f717d3490ff, 0x95ca87fdaef30d1b, 0x823372f85b24a372, 0xf50e5a0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000007685cd8, 0x1a491dbccc21ad06, 0x648d09a5c8cf5a78, 0x482054e91470b33d, 0xde77252caef69a51]),
+  fnv1a_1024_test_7: U1024, b"fo", U1024::from_words([0x00000000000000f4, 0x6ef41cd23a4dcdd4, 0x06834963b78e8224, 0x1a6f5cb06f403cbd, 0x5a7c8903cef6a5f4, 0xfddbd00000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000b7cd7fb20, 0xc3631dc8903952e9, 0xeeb7f618698f4c87, 0xda23ad74b2c5f6f1, 0xfec4a64b546d3226]),
+  fnv1a_1024_test_8: U1024, b"foo", U1024::from_words([0x000000000001868c, 0xe88bd2c7cdc5fa5e, 0x52ebb9925ff5ea66, 0x8dff4576aa4ba658, 0x19176ce6b925a842, 0x1b13d90000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000011d09af071cf, 0x00b53007a8e594c7, 0x3348a3dbb339aead, 0x4953fdf93cfff548, 0x16f5e2d1ed56fb35]),
+  fnv1a_1024_test_9: U1024, b"foob", U1024::from_words([0x00000000026f791f, 0x9147aedad1354bef, 0x7d238f3219005cbd, 0x6e8d664f6b4eefdb, 0xe94929e41548c071, 0x54c2dc0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x001ba08046e07e04, 0x18fb7be0ec07b8ea, 0x87a61bb4f073e2ba, 0xb740db8398ef60cb, 0x9b50bf8d0fe3c5eb]),
+  fnv1a_1024_test_10: U1024, b"fooba", U1024::from_words([0x00000003e27f563b, 0x2ca82d6f6b22a351, 0x17ddfb386bab86b4, 0xe52a63e0aa457ba1, 0xb5d6c2505291fcd0, 0x55f4b60000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x2ad7e6edea236c5a, 0xbdff1bce07f9c3b4, 0x5c98f798e3b69b8e, 0x2f946b142b391bbf, 0xdc390dc1a4395702]),
+  fnv1a_1024_test_11: U1024, b"foobar", U1024::from_words([0x00000631175fa7ae, 0x643ad08723d312c9, 0xfd024adb91f77f6b, 0x19587197a22bcdf2, 0x3727166c4572d0b9, 0x85d5ae0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000042, 0x70d11ef418ef08b8, 0xa49e1e825e547eb3, 0x9937f819222f3b7f, 0xc92a0e4707900888, 0x847a554bacec98b0]),
+  fnv1a_1024_test_12: U1024, ::std::ffi::CString::new("").unwrap().as_bytes_with_nul(), U1024::from_words([0x0000000000000000, 0x98d7c19fbce653df, 0x221b9f717d3490ff, 0x95ca87fdaef30d1b, 0x823372f85b24a372, 0xf50e380000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000007685cd8, 0x1a491dbccc21ad06, 0x648d09a5c8cf5a78, 0x482054e91470b33d, 0xde77252caef66597]),
+  fnv1a_1024_test_13: U1024, ::std::ffi::CString::new("a").unwrap().as_bytes_with_nul(), U1024::from_words([0x00000000000000f4, 0x6ef41cd23a4dcdd4, 0x06834963b78e8224, 0x1a6f5cb06f403cbd, 0x5a7c8903cef6a5f4, 0xfdd2950000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000b7cd7fb20, 0xc3631dc8903952e9, 0xeeb7f618698f4c87, 0xda23ad74b2c5f6f1, 0xfec4a64b546618a2]),
+  fnv1a_1024_test_14: U1024, ::std::ffi::CString::new("b").unwrap().as_bytes_with_nul(), U1024::from_words([0x00000000000000f4, 0x6ef41cd23a4dcdd4, 0x06834963b78e8224, 0x1a6f5cb06f403cbd, 0x5a7c8903cef6a5f4, 0xfdcf7b0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000b7cd7fb20, 0xc3631dc8903952e9, 0xeeb7f618698f4c87, 0xda23ad74b2c5f6f1, 0xfec4a64b5463b0f9]),
+  fnv1a_1024_test_15: U1024, ::std::ffi::CString::new("c").unwrap().as_bytes_with_nul(), U1024::from_words([0x00000000000000f4, 0x6ef41cd23a4dcdd4, 0x06834963b78e8224, 0x1a6f5cb06f403cbd, 0x5a7c8903cef6a5f4, 0xfdcc610000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000b7cd7fb20, 0xc3631dc8903952e9, 0xeeb7f618698f4c87, 0xda23ad74b2c5f6f1, 0xfec4a64b54614950]),
+  fnv1a_1024_test_16: U1024, ::std::ffi::CString::new("d").unwrap().as_bytes_with_nul(), U1024::from_words([0x00000000000000f4, 0x6ef41cd23a4dcdd4, 0x06834963b78e8224, 0x1a6f5cb06f403cbd, 0x5a7c8903cef6a5f4, 0xfde2170000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000b7cd7fb20, 0xc3631dc8903952e9, 0xeeb7f618698f4c87, 0xda23ad74b2c5f6f1, 0xfec4a64b54721eef]),
+  fnv1a_1024_test_17: U1024, ::std::ffi::CString::new("e").unwrap().as_bytes_with_nul(), U1024::from_words([0x00000000000000f4, 0x6ef41cd23a4dcdd4, 0x06834963b78e8224, 0x1a6f5cb06f403cbd, 0x5a7c8903cef6a5f4, 0xfddefd0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000b7cd7fb20, 0xc3631dc8903952e9, 0xeeb7f618698f4c87, 0xda23ad74b2c5f6f1, 0xfec4a64b546fb746]),
+  fnv1a_1024_test_18: U1024, ::std::ffi::CString::new("f").unwrap().as_bytes_with_nul(), U1024::from_words([0x00000000000000f4, 0x6ef41cd23a4dcdd4, 0x06834963b78e8224, 0x1a6f5cb06f403cbd, 0x5a7c8903cef6a5f4, 0xfddbe30000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000b7cd7fb20, 0xc3631dc8903952e9, 0xeeb7f618698f4c87, 0xda23ad74b2c5f6f1, 0xfec4a64b546d4f9d]),
+  fnv1a_1024_test_19: U1024, ::std::ffi::CString::new("fo").unwrap().as_bytes_with_nul(), U1024::from_words([0x000000000001868c, 0xe88bd2c7cdc5fa5e, 0x52ebb9925ff5ea66, 0x8dff4576aa4ba658, 0x19176ce6b925a842, 0x1b13b60000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000011d09af071cf, 0x00b53007a8e594c7, 0x3348a3dbb339aead, 0x4953fdf93cfff548, 0x16f5e2d1ed56c4ee]),
+  fnv1a_1024_test_20: U1024, ::std::ffi::CString::new("foo").unwrap().as_bytes_with_nul(), U1024::from_words([0x00000000026f791f, 0x9147aedad1354bef, 0x7d238f3219005cbd, 0x6e8d664f6b4eefdb, 0xe94929e41548c071, 0x54c2ba0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x001ba08046e07e04, 0x18fb7be0ec07b8ea, 0x87a61bb4f073e2ba, 0xb740db8398ef60cb, 0x9b50bf8d0fe39131]),
+  fnv1a_1024_test_21: U1024, ::std::ffi::CString::new("foob").unwrap().as_bytes_with_nul(), U1024::from_words([0x00000003e27f563b, 0x2ca82d6f6b22a351, 0x17ddfb386bab86b4, 0xe52a63e0aa457ba1, 0xb5d6c2505291fcd0, 0x55f5170000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x2ad7e6edea236c5a, 0xbdff1bce07f9c3b4, 0x5c98f798e3b69b8e, 0x2f946b142b391bbf, 0xdc390dc1a439ed6f]),
+  fnv1a_1024_test_22: U1024, ::std::ffi::CString::new("fooba").unwrap().as_bytes_with_nul(), U1024::from_words([0x00000631175fa7ae, 0x643ad08723d312c9, 0xfd024adb91f77f6b, 0x19587197a22bcdf2, 0x3727166c4572d0b9, 0x85d5400000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000042, 0x70d11ef418ef08b8, 0xa49e1e825e547eb3, 0x9937f819222f3b7f, 0xc92a0e4707900888, 0x847a554bacebee1a]),
+  fnv1a_1024_test_23: U1024, ::std::ffi::CString::new("foobar").unwrap().as_bytes_with_nul(), U1024::from_words([0x0009dc921075fd8a, 0x5e3e1a372c72a59b, 0xb10cca1a94c8b238, 0x7d63a7efa7fca7a7, 0x17a64e6c2d62fb61, 0x78f7860000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000006708, 0xf44d008aaab08657, 0x4935502c49087c84, 0x9bcbbefa033f452a, 0xf6382426ba5d3bb5, 0x71b6465b2ae8c8f0]),
+  fnv1a_1024_test_24: U1024, b"ch", U1024::from_words([0x00000000000000f4, 0x6ef41cd23a4dcdd4, 0x06834963b78e8224, 0x1a6f5cb06f403cbd, 0x5a7c8903cef6a5f4, 0xfdccc90000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000b7cd7fb20, 0xc3631dc8903952e9, 0xeeb7f618698f4c87, 0xda23ad74b2c5f6f1, 0xfec4a64b5461ea98]),
+  fnv1a_1024_test_25: U1024, b"cho", U1024::from_words([0x000000000001868c, 0xe88bd2c7cdc5fa5e, 0x52ebb9925ff5ea66, 0x8dff4576aa4ba658, 0x19176ce6b925a841, 0xf87eac0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000011d09af071cf, 0x00b53007a8e594c7, 0x3348a3dbb339aead, 0x4953fdf93cfff548, 0x16f5e2d1dbd9610b]),
+  fnv1a_1024_test_26: U1024, b"chon", U1024::from_words([0x00000000026f791f, 0x9147aedad1354bef, 0x7d238f3219005cbd, 0x6e8d664f6b4eefdb, 0xe94929e41548c02a, 0x35d2210000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x001ba08046e07e04, 0x18fb7be0ec07b8ea, 0x87a61bb4f073e2ba, 0xb740db8398ef60cb, 0x9b50bf71f01c09a1]),
+  fnv1a_1024_test_27: U1024, b"chong", U1024::from_words([0x00000003e27f563b, 0x2ca82d6f6b22a351, 0x17ddfb386bab86b4, 0xe52a63e0aa457ba1, 0xb5d6c25052917365, 0x92e6f30000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x2ad7e6edea236c5a, 0xbdff1bce07f9c3b4, 0x5c98f798e3b69b8e, 0x2f946b142b391bbf, 0xdc38e3b15b7b280e]),
+  fnv1a_1024_test_28: U1024, b"chongo", U1024::from_words([0x00000631175fa7ae, 0x643ad08723d312c9, 0xfd024adb91f77f6b, 0x19587197a22bcdf2, 0x3727166c4473a5e0, 0x4b4f380000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000042, 0x70d11ef418ef08b8, 0xa49e1e825e547eb3, 0x9937f819222f3b7f, 0xc92a0e4707900888, 0x84391a0addfd9e6d]),
+  fnv1a_1024_test_29: U1024, b"chongo ", U1024::from_words([0x0009dc921075fd8a, 0x5e3e1a372c72a59b, 0xb10cca1a94c8b238, 0x7d63a7efa7fca7a7, 0x17a64e6a607247b2, 0xc778250000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000006708, 0xf44d008aaab08657, 0x4935502c49087c84, 0x9bcbbefa033f452a, 0xf6382426ba5d3bb5, 0x0c8d62da424e7d69]),
+  fnv1a_1024_test_30: U1024, b"chongo w", U1024::from_words([0x0fb21777d3faba3e, 0xd6d4fed9231afeba, 0x9951efd486fb5b9d, 0xb2d0999dbaf424da, 0x0a2350041e9c0a81, 0xa3ce7f0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000009fc8e2, 0xdb69d70ab3c0555c, 0x87ad54ad422919a5, 0x9af729b7091e439f, 0xd510100f029597c4, 0x77424c78d3b80786]),
+  fnv1a_1024_test_31: U1024, b"chongo wa", U1024::from_words([0xf6f747af25a9de26, 0xe8a493431e31b4a1, 0xed2a92304af6ca97, 0x6bc1d96ffcad3524, 0x4e5adadaba48c3de, 0xbf42da0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000f7ca87ce, 0x43227b98c144607e, 0x67cc50af99bcc5d1, 0x514bb0d923eededd, 0x69e8e74701fc5bac, 0xf1d097605464413b]),
+  fnv1a_1024_test_32: U1024, b"chongo was", U1024::from_words([0xc801f8e08ae91b18, 0x0b98dd7d9f65ceb6, 0x87ca86358c6905f6, 0x0a7d1014c182b04f, 0x7f411224b36f20c2, 0xfeed5a0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000018045149ade, 0x1c79abe3b709a406, 0xf7d9205169bec59b, 0x126140bcb96f9d5d, 0x3e2ea91e145a2b33, 0x007ac062df793ca8]),
+  fnv1a_1024_test_33: U1024, b"chongo was ", U1024::from_words([0x3fa9d253e52ae801, 0x05b382c80a01e27a, 0x53d7bc1d201efb47, 0xb38f4d6e4654895c, 0xb21455eabe163144, 0xcf511a0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000253eb20f42a72, 0x28af9022d9f35ece, 0x5bb71e40fcd8717b, 0x80d164ab92170999, 0x6e5c43a58fd4fe17, 0xbe5c59548f02dee8]),
+  fnv1a_1024_test_34: U1024, b"chongo was h", U1024::from_words([0xae859e431319ec6f, 0xcac09e933c0a78b0, 0xde0034aba17059c3, 0x8c44ad716d5caa4f, 0xfe8754c724c3bc44, 0x83a3d20000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x039c23a21aa5d309, 0x1842860bfe6a0604, 0x3af9eac81ba7fc86, 0xc4b91e118db9e2f0, 0x2514e7c00d4e0ad2, 0x35368821c7730c80]),
+  fnv1a_1024_test_35: U1024, b"chongo was he", U1024::from_words([0x4b09771ce1b9b55b, 0xd6bbee8f1627f263, 0xee4e34ee145945ce, 0x4063e90bad9bde1b, 0x05e94b093a131807, 0x98198f0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000005, 0x992342635328451a, 0x9f29e0998a6b548f, 0x75911852e3829cff, 0x1313a138c944ee69, 0x816b64d4a206c7fc, 0x85911c624d6aff21]),
+  fnv1a_1024_test_36: U1024, b"chongo was her", U1024::from_words([0x85f2d26936ded8f6, 0x6cc5835aed0b37dc, 0x11e51448a21279a3, 0x1fded09da61c448a, 0x319159d4a0b8a614, 0x4aa2160000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000000008ae, 0x7badf407f5732a48, 0xd3f14e1ba8722279, 0x5202b88ad18d7790, 0x957105101fe5b99d, 0xb38b5dbf4484229b, 0x220904720eedf3b7]),
+  fnv1a_1024_test_37: U1024, b"chongo was here", U1024::from_words([0x2cba95fe08e491cf, 0x206f2d539d1e2b19, 0x4db80538c7abb218, 0x5243222e25340993, 0x628ae9df475dff86, 0xab4ff00000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000000d7695, 0xccc37057a39892f0, 0xad3620e438ff7624, 0x2e382f46f8626b37, 0xc044da017740d98f, 0x6f20619d40e9aa93, 0xc7fbe4e127031caa]),
+  fnv1a_1024_test_38: U1024, b"chongo was here!", U1024::from_words([0xf5ed8ba10097026e, 0x4bdf6ed8dff81d35, 0xe8cb50cdea1d312e, 0xd2f590fed21476d0, 0xbb174309a8aa24fe, 0xae13bb0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000014e0e64e, 0x8b1537e8b39bdf3c, 0x9cf101ec642a361b, 0xad21510f30a04575, 0x2ac61445ef91636f, 0x553760ddaa5d872d, 0x21a1f1297fd3438f]),
+  fnv1a_1024_test_39: U1024, b"chongo was here!\n", U1024::from_words([0xfd40c54adb30b16f, 0xd3b5020075165bca, 0xa391c47ed5598b8c, 0xb8354a81011d1f5c, 0x809a231c31cc8873, 0xc7dc840000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000002060c527cd, 0xafe7b3de88b930ff, 0x61bffb975575e8eb, 0x7caab48e688bb6b3, 0x552d7074847733a7, 0x26e137c1330aa0fd, 0x2822fd5b3a9fb541]),
+  fnv1a_1024_test_40: U1024, ::std::ffi::CString::new("ch").unwrap().as_bytes_with_nul(), U1024::from_words([0x000000000001868c, 0xe88bd2c7cdc5fa5e, 0x52ebb9925ff5ea66, 0x8dff4576aa4ba658, 0x19176ce6b925a841, 0xf87e4d0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000011d09af071cf, 0x00b53007a8e594c7, 0x3348a3dbb339aead, 0x4953fdf93cfff548, 0x16f5e2d1dbd8cdb8]),
+  fnv1a_1024_test_41: U1024, ::std::ffi::CString::new("cho").unwrap().as_bytes_with_nul(), U1024::from_words([0x00000000026f791f, 0x9147aedad1354bef, 0x7d238f3219005cbd, 0x6e8d664f6b4eefdb, 0xe94929e41548c02a, 0x35d1c70000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x001ba08046e07e04, 0x18fb7be0ec07b8ea, 0x87a61bb4f073e2ba, 0xb740db8398ef60cb, 0x9b50bf71f01b7e0f]),
+  fnv1a_1024_test_42: U1024, ::std::ffi::CString::new("chon").unwrap().as_bytes_with_nul(), U1024::from_words([0x00000003e27f563b, 0x2ca82d6f6b22a351, 0x17ddfb386bab86b4, 0xe52a63e0aa457ba1, 0xb5d6c25052917365, 0x92e6ce0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x2ad7e6edea236c5a, 0xbdff1bce07f9c3b4, 0x5c98f798e3b69b8e, 0x2f946b142b391bbf, 0xdc38e3b15b7aeead]),
+  fnv1a_1024_test_43: U1024, ::std::ffi::CString::new("chong").unwrap().as_bytes_with_nul(), U1024::from_words([0x00000631175fa7ae, 0x643ad08723d312c9, 0xfd024adb91f77f6b, 0x19587197a22bcdf2, 0x3727166c4473a5e0, 0x4b4ee50000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000042, 0x70d11ef418ef08b8, 0xa49e1e825e547eb3, 0x9937f819222f3b7f, 0xc92a0e4707900888, 0x84391a0addfd1db6]),
+  fnv1a_1024_test_44: U1024, ::std::ffi::CString::new("chongo").unwrap().as_bytes_with_nul(), U1024::from_words([0x0009dc921075fd8a, 0x5e3e1a372c72a59b, 0xb10cca1a94c8b238, 0x7d63a7efa7fca7a7, 0x17a64e6a607247b2, 0xc778450000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000006708, 0xf44d008aaab08657, 0x4935502c49087c84, 0x9bcbbefa033f452a, 0xf6382426ba5d3bb5, 0x0c8d62da424eaf09]),
+  fnv1a_1024_test_45: U1024, ::std::ffi::CString::new("chongo ").unwrap().as_bytes_with_nul(), U1024::from_words([0x0fb21777d3faba3e, 0xd6d4fed9231afeba, 0x9951efd486fb5b9d, 0xb2d0999dbaf424da, 0x0a2350041e9c0a81, 0xa3ceca0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000009fc8e2, 0xdb69d70ab3c0555c, 0x87ad54ad422919a5, 0x9af729b7091e439f, 0xd510100f029597c4, 0x77424c78d3b87bd5]),
+  fnv1a_1024_test_46: U1024, ::std::ffi::CString::new("chongo w").unwrap().as_bytes_with_nul(), U1024::from_words([0xf6f747af25a9de26, 0xe8a493431e31b4a1, 0xed2a92304af6ca97, 0x6bc1d96ffcad3524, 0x4e5adadaba48c3de, 0xbf42790000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000f7ca87ce, 0x43227b98c144607e, 0x67cc50af99bcc5d1, 0x514bb0d923eededd, 0x69e8e74701fc5bac, 0xf1d097605463aace]),
+  fnv1a_1024_test_47: U1024, ::std::ffi::CString::new("chongo wa").unwrap().as_bytes_with_nul(), U1024::from_words([0xc801f8e08ae91b18, 0x0b98dd7d9f65ceb6, 0x87ca86358c6905f6, 0x0a7d1014c182b04f, 0x7f411224b36f20c2, 0xfeed4d0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000018045149ade, 0x1c79abe3b709a406, 0xf7d9205169bec59b, 0x126140bcb96f9d5d, 0x3e2ea91e145a2b33, 0x007ac062df79287f]),
+  fnv1a_1024_test_48: U1024, ::std::ffi::CString::new("chongo was").unwrap().as_bytes_with_nul(), U1024::from_words([0x3fa9d253e52ae801, 0x05b382c80a01e27a, 0x53d7bc1d201efb47, 0xb38f4d6e4654895c, 0xb21455eabe163144, 0xcf513a0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000253eb20f42a72, 0x28af9022d9f35ece, 0x5bb71e40fcd8717b, 0x80d164ab92170999, 0x6e5c43a58fd4fe17, 0xbe5c59548f031088]),
+  fnv1a_1024_test_49: U1024, ::std::ffi::CString::new("chongo was ").unwrap().as_bytes_with_nul(), U1024::from_words([0xae859e431319ec6f, 0xcac09e933c0a78b0, 0xde0034aba17059c3, 0x8c44ad716d5caa4f, 0xfe8754c724c3bc44, 0x83a43a0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x039c23a21aa5d309, 0x1842860bfe6a0604, 0x3af9eac81ba7fc86, 0xc4b91e118db9e2f0, 0x2514e7c00d4e0ad2, 0x35368821c773adc8]),
+  fnv1a_1024_test_50: U1024, ::std::ffi::CString::new("chongo was h").unwrap().as_bytes_with_nul(), U1024::from_words([0x4b09771ce1b9b55b, 0xd6bbee8f1627f263, 0xee4e34ee145945ce, 0x4063e90bad9bde1b, 0x05e94b093a131807, 0x98192a0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000005, 0x992342635328451a, 0x9f29e0998a6b548f, 0x75911852e3829cff, 0x1313a138c944ee69, 0x816b64d4a206c7fc, 0x85911c624d6a6280]),
+  fnv1a_1024_test_51: U1024, ::std::ffi::CString::new("chongo was he").unwrap().as_bytes_with_nul(), U1024::from_words([0x85f2d26936ded8f6, 0x6cc5835aed0b37dc, 0x11e51448a21279a3, 0x1fded09da61c448a, 0x319159d4a0b8a614, 0x4aa1e40000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000000008ae, 0x7badf407f5732a48, 0xd3f14e1ba8722279, 0x5202b88ad18d7790, 0x957105101fe5b99d, 0xb38b5dbf4484229b, 0x220904720eeda62d]),
+  fnv1a_1024_test_52: U1024, ::std::ffi::CString::new("chongo was her").unwrap().as_bytes_with_nul(), U1024::from_words([0x2cba95fe08e491cf, 0x206f2d539d1e2b19, 0x4db80538c7abb218, 0x5243222e25340993, 0x628ae9df475dff86, 0xab4fd50000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000000d7695, 0xccc37057a39892f0, 0xad3620e438ff7624, 0x2e382f46f8626b37, 0xc044da017740d98f, 0x6f20619d40e9aa93, 0xc7fbe4e12702f2cb]),
+  fnv1a_1024_test_53: U1024, ::std::ffi::CString::new("chongo was here").unwrap().as_bytes_with_nul(), U1024::from_words([0xf5ed8ba10097026e, 0x4bdf6ed8dff81d35, 0xe8cb50cdea1d312e, 0xd2f590fed21476d0, 0xbb174309a8aa24fe, 0xae13da0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000014e0e64e, 0x8b1537e8b39bdf3c, 0x9cf101ec642a361b, 0xad21510f30a04575, 0x2ac61445ef91636f, 0x553760ddaa5d872d, 0x21a1f1297fd373a2]),
+  fnv1a_1024_test_54: U1024, ::std::ffi::CString::new("chongo was here!").unwrap().as_bytes_with_nul(), U1024::from_words([0xfd40c54adb30b16f, 0xd3b5020075165bca, 0xa391c47ed5598b8c, 0xb8354a81011d1f5c, 0x809a231c31cc8873, 0xc7dc8e0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000002060c527cd, 0xafe7b3de88b930ff, 0x61bffb975575e8eb, 0x7caab48e688bb6b3, 0x552d7074847733a7, 0x26e137c1330aa0fd, 0x2822fd5b3a9fc4c3]),
+  fnv1a_1024_test_55: U1024, ::std::ffi::CString::new("chongo was here!\n").unwrap().as_bytes_with_nul(), U1024::from_words([0x76a2f477aa7ec1c0, 0xc59d06323e60e7a8, 0x34c46c06094dddbe, 0x21d831359b6163a6, 0x79a96fe15d2cf6c7, 0x90adf50000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000323611beb9f9, 0xca51f01a0730fc0a, 0x96b929af87da3530, 0x54ba00d820aa541b, 0x177764b16cdb1a37, 0x4b43769c277ba897, 0x3e42e679e9ac15cd]),
+  fnv1a_1024_test_56: U1024, b"cu", U1024::from_words([0x00000000000000f4, 0x6ef41cd23a4dcdd4, 0x06834963b78e8224, 0x1a6f5cb06f403cbd, 0x5a7c8903cef6a5f4, 0xfdccb60000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000b7cd7fb20, 0xc3631dc8903952e9, 0xeeb7f618698f4c87, 0xda23ad74b2c5f6f1, 0xfec4a64b5461cd21]),
+  fnv1a_1024_test_57: U1024, b"cur", U1024::from_words([0x000000000001868c, 0xe88bd2c7cdc5fa5e, 0x52ebb9925ff5ea66, 0x8dff4576aa4ba658, 0x19176ce6b925a841, 0xf843910000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000011d09af071cf, 0x00b53007a8e594c7, 0x3348a3dbb339aead, 0x4953fdf93cfff548, 0x16f5e2d1dbab69b7]),
+  fnv1a_1024_test_58: U1024, b"curd", U1024::from_words([0x00000000026f791f, 0x9147aedad1354bef, 0x7d238f3219005cbd, 0x6e8d664f6b4eefdb, 0xe94929e41548c029, 0xac31b00000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x001ba08046e07e04, 0x18fb7be0ec07b8ea, 0x87a61bb4f073e2ba, 0xb740db8398ef60cb, 0x9b50bf71a8d31c37]),
+  fnv1a_1024_test_59: U1024, b"curds", U1024::from_words([0x00000003e27f563b, 0x2ca82d6f6b22a351, 0x17ddfb386bab86b4, 0xe52a63e0aa457ba1, 0xb5d6c25052917248, 0xdc2a340000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x2ad7e6edea236c5a, 0xbdff1bce07f9c3b4, 0x5c98f798e3b69b8e, 0x2f946b142b391bbf, 0xdc38e342cf62d574]),
+  fnv1a_1024_test_60: U1024, b"curds ", U1024::from_words([0x00000631175fa7ae, 0x643ad08723d312c9, 0xfd024adb91f77f6b, 0x19587197a22bcdf2, 0x3727166c44717dcc, 0xd047f80000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000042, 0x70d11ef418ef08b8, 0xa49e1e825e547eb3, 0x9937f819222f3b7f, 0xc92a0e4707900888, 0x84386e9b9c44d344]),
+  fnv1a_1024_test_61: U1024, b"curds a", U1024::from_words([0x0009dc921075fd8a, 0x5e3e1a372c72a59b, 0xb10cca1a94c8b238, 0x7d63a7efa7fca7a7, 0x17a64e6a5c6eb23b, 0x446ebd0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000006708, 0xf44d008aaab08657, 0x4935502c49087c84, 0x9bcbbefa033f452a, 0xf6382426ba5d3bb5, 0x0b83875156bb7061]),
+  fnv1a_1024_test_62: U1024, b"curds an", U1024::from_words([0x0fb21777d3faba3e, 0xd6d4fed9231afeba, 0x9951efd486fb5b9d, 0xb2d0999dbaf424da, 0x0a234ffcdb31b73f, 0xdb2b280000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000009fc8e2, 0xdb69d70ab3c0555c, 0x87ad54ad422919a5, 0x9af729b7091e439f, 0xd510100f029597c2, 0xdaf8d92380acc743]),
+  fnv1a_1024_test_63: U1024, b"curds and", U1024::from_words([0xf6f747af25a9de26, 0xe8a493431e31b4a1, 0xed2a92304af6ca97, 0x6bc1d96ffcad3524, 0x4e5acdfae4f25187, 0x8eb42f0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000f7ca87ce, 0x43227b98c144607e, 0x67cc50af99bcc5d1, 0x514bb0d923eededd, 0x69e8e74701fc592d, 0x93e8bc0e8bf0d77b]),
+  fnv1a_1024_test_64: U1024, b"curds and ", U1024::from_words([0xc801f8e08ae91b18, 0x0b98dd7d9f65ceb6, 0x87ca86358c6905f6, 0x0a7d1014c182b04f, 0x7f2a9ba8f4847dc4, 0x3e443e0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000018045149ade, 0x1c79abe3b709a406, 0xf7d9205169bec59b, 0x126140bcb96f9d5d, 0x3e2ea91e14564bae, 0x5feba28f047df81f]),
+  fnv1a_1024_test_65: U1024, b"curds and w", U1024::from_words([0x3fa9d253e52ae801, 0x05b382c80a01e27a, 0x53d7bc1d201efb47, 0xb38f4d6e4654895c, 0x8b5f13631d199859, 0x0dcc8e0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000253eb20f42a72, 0x28af9022d9f35ece, 0x5bb71e40fcd8717b, 0x80d164ab92170999, 0x6e5c43a589d35d6a, 0xc06b17c9f75a3948]),
+  fnv1a_1024_test_66: U1024, b"curds and wh", U1024::from_words([0xae859e431319ec6f, 0xcac09e933c0a78b0, 0xde0034aba17059c3, 0x8c44ad716d5caa0d, 0xf5ce7b748bc90c11, 0xc071560000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x039c23a21aa5d309, 0x1842860bfe6a0604, 0x3af9eac81ba7fc86, 0xc4b91e118db9e2f0, 0x2514e7b6bcc7de8c, 0x6613e43496ea96a0]),
+  fnv1a_1024_test_67: U1024, b"curds and whe", U1024::from_words([0x4b09771ce1b9b55b, 0xd6bbee8f1627f263, 0xee4e34ee145945ce, 0x4063e90bad9b6e62, 0xf9140022daabec1e, 0x5a59230000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000005, 0x992342635328451a, 0x9f29e0998a6b548f, 0x75911852e3829cff, 0x1313a138c944ee69, 0x816b5662c1f41fba, 0x4cd8e58e09cbcf81]),
+  fnv1a_1024_test_68: U1024, b"curds and whey", U1024::from_words([0x85f2d26936ded8f6, 0x6cc5835aed0b37dc, 0x11e51448a21279a3, 0x1fded09da560923e, 0x3823f059f582b91b, 0xe80b3f0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000000008ae, 0x7badf407f5732a48, 0xd3f14e1ba8722279, 0x5202b88ad18d7790, 0x957105101fe5b99d, 0xb374f726c79533e9, 0x2c5bfd4531118398]),
+  fnv1a_1024_test_69: U1024, b"curds and whey\n", U1024::from_words([0x2cba95fe08e491cf, 0x206f2d539d1e2b19, 0x4db80538c7abb218, 0x5243222cebb9f144, 0xa4efa4ae17b65577, 0xeaf4450000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000000d7695, 0xccc37057a39892f0, 0xad3620e438ff7624, 0x2e382f46f8626b37, 0xc044da017740d98f, 0x4c63472382618099, 0xcaa7c44d1829096a]),
+  fnv1a_1024_test_70: U1024, ::std::ffi::CString::new("cu").unwrap().as_bytes_with_nul(), U1024::from_words([0x000000000001868c, 0xe88bd2c7cdc5fa5e, 0x52ebb9925ff5ea66, 0x8dff4576aa4ba658, 0x19176ce6b925a841, 0xf8435f0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000011d09af071cf, 0x00b53007a8e594c7, 0x3348a3dbb339aead, 0x4953fdf93cfff548, 0x16f5e2d1dbab1c2d]),
+  fnv1a_1024_test_71: U1024, ::std::ffi::CString::new("cur").unwrap().as_bytes_with_nul(), U1024::from_words([0x00000000026f791f, 0x9147aedad1354bef, 0x7d238f3219005cbd, 0x6e8d664f6b4eefdb, 0xe94929e41548c029, 0xac31940000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x001ba08046e07e04, 0x18fb7be0ec07b8ea, 0x87a61bb4f073e2ba, 0xb740db8398ef60cb, 0x9b50bf71a8d2f0cb]),
+  fnv1a_1024_test_72: U1024, ::std::ffi::CString::new("curd").unwrap().as_bytes_with_nul(), U1024::from_words([0x00000003e27f563b, 0x2ca82d6f6b22a351, 0x17ddfb386bab86b4, 0xe52a63e0aa457ba1, 0xb5d6c25052917248, 0xdc2a270000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x2ad7e6edea236c5a, 0xbdff1bce07f9c3b4, 0x5c98f798e3b69b8e, 0x2f946b142b391bbf, 0xdc38e342cf62c14b]),
+  fnv1a_1024_test_73: U1024, ::std::ffi::CString::new("curds").unwrap().as_bytes_with_nul(), U1024::from_words([0x00000631175fa7ae, 0x643ad08723d312c9, 0xfd024adb91f77f6b, 0x19587197a22bcdf2, 0x3727166c44717dcc, 0xd048180000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000042, 0x70d11ef418ef08b8, 0xa49e1e825e547eb3, 0x9937f819222f3b7f, 0xc92a0e4707900888, 0x84386e9b9c4504e4]),
+  fnv1a_1024_test_74: U1024, ::std::ffi::CString::new("curds ").unwrap().as_bytes_with_nul(), U1024::from_words([0x0009dc921075fd8a, 0x5e3e1a372c72a59b, 0xb10cca1a94c8b238, 0x7d63a7efa7fca7a7, 0x17a64e6a5c6eb23b, 0x446edc0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000006708, 0xf44d008aaab08657, 0x4935502c49087c84, 0x9bcbbefa033f452a, 0xf6382426ba5d3bb5, 0x0b83875156bba074]),
+  fnv1a_1024_test_75: U1024, ::std::ffi::CString::new("curds a").unwrap().as_bytes_with_nul(), U1024::from_words([0x0fb21777d3faba3e, 0xd6d4fed9231afeba, 0x9951efd486fb5b9d, 0xb2d0999dbaf424da, 0x0a234ffcdb31b73f, 0xdb2b7a0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000009fc8e2, 0xdb69d70ab3c0555c, 0x87ad54ad422919a5, 0x9af729b7091e439f, 0xd510100f029597c2, 0xdaf8d92380ad466d]),
+  fnv1a_1024_test_76: U1024, ::std::ffi::CString::new("curds an").unwrap().as_bytes_with_nul(), U1024::from_words([0xf6f747af25a9de26, 0xe8a493431e31b4a1, 0xed2a92304af6ca97, 0x6bc1d96ffcad3524, 0x4e5acdfae4f25187, 0x8eb44b0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000f7ca87ce, 0x43227b98c144607e, 0x67cc50af99bcc5d1, 0x514bb0d923eededd, 0x69e8e74701fc592d, 0x93e8bc0e8bf102e7]),
+  fnv1a_1024_test_77: U1024, ::std::ffi::CString::new("curds and").unwrap().as_bytes_with_nul(), U1024::from_words([0xc801f8e08ae91b18, 0x0b98dd7d9f65ceb6, 0x87ca86358c6905f6, 0x0a7d1014c182b04f, 0x7f2a9ba8f4847dc4, 0x3e445e0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000018045149ade, 0x1c79abe3b709a406, 0xf7d9205169bec59b, 0x126140bcb96f9d5d, 0x3e2ea91e14564bae, 0x5feba28f047e29bf]),
+  fnv1a_1024_test_78: U1024, ::std::ffi::CString::new("curds and ").unwrap().as_bytes_with_nul(), U1024::from_words([0x3fa9d253e52ae801, 0x05b382c80a01e27a, 0x53d7bc1d201efb47, 0xb38f4d6e4654895c, 0x8b5f13631d199859, 0x0dcc450000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000253eb20f42a72, 0x28af9022d9f35ece, 0x5bb71e40fcd8717b, 0x80d164ab92170999, 0x6e5c43a589d35d6a, 0xc06b17c9f759c813]),
+  fnv1a_1024_test_79: U1024, ::std::ffi::CString::new("curds and w").unwrap().as_bytes_with_nul(), U1024::from_words([0xae859e431319ec6f, 0xcac09e933c0a78b0, 0xde0034aba17059c3, 0x8c44ad716d5caa0d, 0xf5ce7b748bc90c11, 0xc0717e0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x039c23a21aa5d309, 0x1842860bfe6a0604, 0x3af9eac81ba7fc86, 0xc4b91e118db9e2f0, 0x2514e7b6bcc7de8c, 0x6613e43496ead4a8]),
+  fnv1a_1024_test_80: U1024, ::std::ffi::CString::new("curds and wh").unwrap().as_bytes_with_nul(), U1024::from_words([0x4b09771ce1b9b55b, 0xd6bbee8f1627f263, 0xee4e34ee145945ce, 0x4063e90bad9b6e62, 0xf9140022daabec1e, 0x5a58fe0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000005, 0x992342635328451a, 0x9f29e0998a6b548f, 0x75911852e3829cff, 0x1313a138c944ee69, 0x816b5662c1f41fba, 0x4cd8e58e09cb9620]),
+  fnv1a_1024_test_81: U1024, ::std::ffi::CString::new("curds and whe").unwrap().as_bytes_with_nul(), U1024::from_words([0x85f2d26936ded8f6, 0x6cc5835aed0b37dc, 0x11e51448a21279a3, 0x1fded09da560923e, 0x3823f059f582b91b, 0xe80ac80000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000000008ae, 0x7badf407f5732a48, 0xd3f14e1ba8722279, 0x5202b88ad18d7790, 0x957105101fe5b99d, 0xb374f726c79533e9, 0x2c5bfd453110cb0d]),
+  fnv1a_1024_test_82: U1024, ::std::ffi::CString::new("curds and whey").unwrap().as_bytes_with_nul(), U1024::from_words([0x2cba95fe08e491cf, 0x206f2d539d1e2b19, 0x4db80538c7abb218, 0x5243222cebb9f144, 0xa4efa4ae17b65577, 0xeaf44b0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000000d7695, 0xccc37057a39892f0, 0xad3620e438ff7624, 0x2e382f46f8626b37, 0xc044da017740d98f, 0x4c63472382618099, 0xcaa7c44d182912b8]),
+  fnv1a_1024_test_83: U1024, ::std::ffi::CString::new("curds and whey\n").unwrap().as_bytes_with_nul(), U1024::from_words([0xf5ed8ba10097026e, 0x4bdf6ed8dff81d35, 0xe8cb50cdea1d312e, 0xd2f58ef5f2a24af6, 0x2922fbc56d86d80f, 0x85d86b0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000014e0e64e, 0x8b1537e8b39bdf3c, 0x9cf101ec642a361b, 0xad21510f30a04575, 0x2ac61445ef916339, 0x75f5521131346e7f, 0x462b6b8e77a39962]),
+  fnv1a_1024_test_84: U1024, b"hi", U1024::from_words([0x00000000000000f4, 0x6ef41cd23a4dcdd4, 0x06834963b78e8224, 0x1a6f5cb06f403cbd, 0x5a7c8903cef6a5f4, 0xfdeed60000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000b7cd7fb20, 0xc3631dc8903952e9, 0xeeb7f618698f4c87, 0xda23ad74b2c5f6f1, 0xfec4a64b547c447e]),
+  fnv1a_1024_test_85: U1024, ::std::ffi::CString::new("hi").unwrap().as_bytes_with_nul(), U1024::from_words([0x000000000001868c, 0xe88bd2c7cdc5fa5e, 0x52ebb9925ff5ea66, 0x8dff4576aa4ba658, 0x19176ce6b925a842, 0x47a65c0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000011d09af071cf, 0x00b53007a8e594c7, 0x3348a3dbb339aead, 0x4953fdf93cfff548, 0x16f5e2d204b63766]),
+  fnv1a_1024_test_86: U1024, b"hello", U1024::from_words([0x00000003e27f563b, 0x2ca82d6f6b22a351, 0x17ddfb386bab86b4, 0xe52a63e0aa457ba1, 0xb5d6c2505292b0bc, 0x04d6df0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x2ad7e6edea236c5a, 0xbdff1bce07f9c3b4, 0x5c98f798e3b69b8e, 0x2f946b142b391bbf, 0xdc39460ea1ac6e69]),
+  fnv1a_1024_test_87: U1024, ::std::ffi::CString::new("hello").unwrap().as_bytes_with_nul(), U1024::from_words([0x00000631175fa7ae, 0x643ad08723d312c9, 0xfd024adb91f77f6b, 0x19587197a22bcdf2, 0x3727166c46c22235, 0x2da63c0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000042, 0x70d11ef418ef08b8, 0xa49e1e825e547eb3, 0x9937f819222f3b7f, 0xc92a0e4707900888, 0x84d1a4b0b86738d5]),
+  fnv1a_1024_test_88: U1024, b"\xff\x00\x00\x01", U1024::from_words([0x00000000026f791f, 0x9147aedad1354bef, 0x7d238f3219005cbd, 0x6e8d664f6b4eefdb, 0xe94929e41548b876, 0x1819da0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x001ba08046e07e04, 0x18fb7be0ec07b8ea, 0x87a61bb4f073e2ba, 0xb740db8398ef60cb, 0x9b50bc74fa2c1899]),
+  fnv1a_1024_test_89: U1024, b"\x01\x00\x00\xff", U1024::from_words([0x00000000026f791f, 0x9147aedad1354bef, 0x7d238f3219005cbd, 0x6e8d664f6b4eefdb, 0xe94929e41548be67, 0xbbd31c0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x001ba08046e07e04, 0x18fb7be0ec07b8ea, 0x87a61bb4f073e2ba, 0xb740db8398ef60cb, 0x9b50bec2e8e56ca1]),
+  fnv1a_1024_test_90: U1024, b"\xff\x00\x00\x02", U1024::from_words([0x00000000026f791f, 0x9147aedad1354bef, 0x7d238f3219005cbd, 0x6e8d664f6b4eefdb, 0xe94929e41548b876, 0x1819db0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x001ba08046e07e04, 0x18fb7be0ec07b8ea, 0x87a61bb4f073e2ba, 0xb740db8398ef60cb, 0x9b50bc74fa2c1a26]),
+  fnv1a_1024_test_91: U1024, b"\x02\x00\x00\xff", U1024::from_words([0x00000000026f791f, 0x9147aedad1354bef, 0x7d238f3219005cbd, 0x6e8d664f6b4eefdb, 0xe94929e41548be58, 0xd0cef20000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x001ba08046e07e04, 0x18fb7be0ec07b8ea, 0x87a61bb4f073e2ba, 0xb740db8398ef60cb, 0x9b50bebd20484032]),
+  fnv1a_1024_test_92: U1024, b"\xff\x00\x00\x03", U1024::from_words([0x00000000026f791f, 0x9147aedad1354bef, 0x7d238f3219005cbd, 0x6e8d664f6b4eefdb, 0xe94929e41548b876, 0x1819dc0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x001ba08046e07e04, 0x18fb7be0ec07b8ea, 0x87a61bb4f073e2ba, 0xb740db8398ef60cb, 0x9b50bc74fa2c1bb3]),
+  fnv1a_1024_test_93: U1024, b"\x03\x00\x00\xff", U1024::from_words([0x00000000026f791f, 0x9147aedad1354bef, 0x7d238f3219005cbd, 0x6e8d664f6b4eefdb, 0xe94929e41548be49, 0xe5cac80000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x001ba08046e07e04, 0x18fb7be0ec07b8ea, 0x87a61bb4f073e2ba, 0xb740db8398ef60cb, 0x9b50beb757ab13c3]),
+  fnv1a_1024_test_94: U1024, b"\xff\x00\x00\x04", U1024::from_words([0x00000000026f791f, 0x9147aedad1354bef, 0x7d238f3219005cbd, 0x6e8d664f6b4eefdb, 0xe94929e41548b876, 0x1819d50000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x001ba08046e07e04, 0x18fb7be0ec07b8ea, 0x87a61bb4f073e2ba, 0xb740db8398ef60cb, 0x9b50bc74fa2c10d8]),
+  fnv1a_1024_test_95: U1024, b"\x04\x00\x00\xff", U1024::from_words([0x00000000026f791f, 0x9147aedad1354bef, 0x7d238f3219005cbd, 0x6e8d664f6b4eefdb, 0xe94929e41548beb2, 0x52e9ee0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x001ba08046e07e04, 0x18fb7be0ec07b8ea, 0x87a61bb4f073e2ba, 0xb740db8398ef60cb, 0x9b50bedfd3fa64cc]),
+  fnv1a_1024_test_96: U1024, b"\x40\x51\x4e\x44", U1024::from_words([0x00000000026f791f, 0x9147aedad1354bef, 0x7d238f3219005cbd, 0x6e8d664f6b4eefdb, 0xe94929e41548c22f, 0x1e95920000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x001ba08046e07e04, 0x18fb7be0ec07b8ea, 0x87a61bb4f073e2ba, 0xb740db8398ef60cb, 0x9b50c039aa0c4cf4]),
+  fnv1a_1024_test_97: U1024, b"\x44\x4e\x51\x40", U1024::from_words([0x00000000026f791f, 0x9147aedad1354bef, 0x7d238f3219005cbd, 0x6e8d664f6b4eefdb, 0xe94929e41548c26e, 0xb768190000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x001ba08046e07e04, 0x18fb7be0ec07b8ea, 0x87a61bb4f073e2ba, 0xb740db8398ef60cb, 0x9b50c052d4626610]),
+  fnv1a_1024_test_98: U1024, b"\x40\x51\x4e\x4a", U1024::from_words([0x00000000026f791f, 0x9147aedad1354bef, 0x7d238f3219005cbd, 0x6e8d664f6b4eefdb, 0xe94929e41548c22f, 0x1e95980000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x001ba08046e07e04, 0x18fb7be0ec07b8ea, 0x87a61bb4f073e2ba, 0xb740db8398ef60cb, 0x9b50c039aa0c5642]),
+  fnv1a_1024_test_99: U1024, b"\x4a\x4e\x51\x40", U1024::from_words([0x00000000026f791f, 0x9147aedad1354bef, 0x7d238f3219005cbd, 0x6e8d664f6b4eefdb, 0xe94929e41548c28c, 0xe277850000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x001ba08046e07e04, 0x18fb7be0ec07b8ea, 0x87a61bb4f073e2ba, 0xb740db8398ef60cb, 0x9b50c05e912a79ae]),
+  fnv1a_1024_test_100: U1024, b"\x40\x51\x4e\x54", U1024::from_words([0x00000000026f791f, 0x9147aedad1354bef, 0x7d238f3219005cbd, 0x6e8d664f6b4eefdb, 0xe94929e41548c22f, 0x1e95a20000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x001ba08046e07e04, 0x18fb7be0ec07b8ea, 0x87a61bb4f073e2ba, 0xb740db8398ef60cb, 0x9b50c039aa0c65c4]),
+  fnv1a_1024_test_101: U1024, b"\x54\x4e\x51\x40", U1024::from_words([0x00000000026f791f, 0x9147aedad1354bef, 0x7d238f3219005cbd, 0x6e8d664f6b4eefdb, 0xe94929e41548c180, 0x0532990000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x001ba08046e07e04, 0x18fb7be0ec07b8ea, 0x87a61bb4f073e2ba, 0xb740db8398ef60cb, 0x9b50bff6490ac360]),
+  fnv1a_1024_test_102: U1024, b"127.0.0.1", U1024::from_words([0xf6f747af25a9de26, 0xe8a493431e31b4a1, 0xed2a92304af6ca97, 0x6bc1d96ffcad3524, 0x4dfee3c8db86f5ec, 0x7deb570000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000f7ca87ce, 0x43227b98c144607e, 0x67cc50af99bcc5d1, 0x514bb0d923eededd, 0x69e8e74701ec81ae, 0xb6999b5958653d38]),
+  fnv1a_1024_test_103: U1024, ::std::ffi::CString::new("127.0.0.1").unwrap().as_bytes_with_nul(), U1024::from_words([0xc801f8e08ae91b18, 0x0b98dd7d9f65ceb6, 0x87ca86358c6905f6, 0x0a7d1014c182b04e, 0xe0c8ed3309e6b917, 0xab33230000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000018045149ade, 0x1c79abe3b709a406, 0xf7d9205169bec59b, 0x126140bcb96f9d5d, 0x3e2ea91dfbc51bf1, 0x2c35e98e14ffefd8]),
+  fnv1a_1024_test_104: U1024, b"127.0.0.2", U1024::from_words([0xf6f747af25a9de26, 0xe8a493431e31b4a1, 0xed2a92304af6ca97, 0x6bc1d96ffcad3524, 0x4dfee3c8db86f5ec, 0x7deb5a0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000f7ca87ce, 0x43227b98c144607e, 0x67cc50af99bcc5d1, 0x514bb0d923eededd, 0x69e8e74701ec81ae, 0xb6999b59586541df]),
+  fnv1a_1024_test_105: U1024, ::std::ffi::CString::new("127.0.0.2").unwrap().as_bytes_with_nul(), U1024::from_words([0xc801f8e08ae91b18, 0x0b98dd7d9f65ceb6, 0x87ca86358c6905f6, 0x0a7d1014c182b04e, 0xe0c8ed3309e6b917, 0xab3c710000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000018045149ade, 0x1c79abe3b709a406, 0xf7d9205169bec59b, 0x126140bcb96f9d5d, 0x3e2ea91dfbc51bf1, 0x2c35e98e150726d3]),
+  fnv1a_1024_test_106: U1024, b"127.0.0.3", U1024::from_words([0xf6f747af25a9de26, 0xe8a493431e31b4a1, 0xed2a92304af6ca97, 0x6bc1d96ffcad3524, 0x4dfee3c8db86f5ec, 0x7deb590000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000f7ca87ce, 0x43227b98c144607e, 0x67cc50af99bcc5d1, 0x514bb0d923eededd, 0x69e8e74701ec81ae, 0xb6999b5958654052]),
+  fnv1a_1024_test_107: U1024, ::std::ffi::CString::new("127.0.0.3").unwrap().as_bytes_with_nul(), U1024::from_words([0xc801f8e08ae91b18, 0x0b98dd7d9f65ceb6, 0x87ca86358c6905f6, 0x0a7d1014c182b04e, 0xe0c8ed3309e6b917, 0xab39570000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000018045149ade, 0x1c79abe3b709a406, 0xf7d9205169bec59b, 0x126140bcb96f9d5d, 0x3e2ea91dfbc51bf1, 0x2c35e98e1504bf2a]),
+  fnv1a_1024_test_108: U1024, b"64.81.78.68", U1024::from_words([0x3fa9d253e52ae801, 0x05b382c80a01e27a, 0x53d7bc1d201efb47, 0xb38f4d6e46548859, 0x0817b9143bf10aa7, 0x98e0fc0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000253eb20f42a72, 0x28af9022d9f35ece, 0x5bb71e40fcd8717b, 0x80d164ab92170999, 0x6e5c4380f2d1ea6c, 0xc79da3838257bbfb]),
+  fnv1a_1024_test_109: U1024, ::std::ffi::CString::new("64.81.78.68").unwrap().as_bytes_with_nul(), U1024::from_words([0xae859e431319ec6f, 0xcac09e933c0a78b0, 0xde0034aba17059c3, 0x8c44ad716d5af304, 0x5eb471289271096a, 0x6ca2c70000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x039c23a21aa5d309, 0x1842860bfe6a0604, 0x3af9eac81ba7fc86, 0xc4b91e118db9e2f0, 0x2514aef88f888ab1, 0x8f7692f1220e843f]),
+  fnv1a_1024_test_110: U1024, b"64.81.78.74", U1024::from_words([0x3fa9d253e52ae801, 0x05b382c80a01e27a, 0x53d7bc1d201efb47, 0xb38f4d6e46548859, 0x0817b9143bf10aa7, 0x98ddee0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000253eb20f42a72, 0x28af9022d9f35ece, 0x5bb71e40fcd8717b, 0x80d164ab92170999, 0x6e5c4380f2d1ea6c, 0xc79da383825566ee]),
+  fnv1a_1024_test_111: U1024, ::std::ffi::CString::new("64.81.78.74").unwrap().as_bytes_with_nul(), U1024::from_words([0xae859e431319ec6f, 0xcac09e933c0a78b0, 0xde0034aba17059c3, 0x8c44ad716d5af304, 0x5eb471289271096a, 0x6591040000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x039c23a21aa5d309, 0x1842860bfe6a0604, 0x3af9eac81ba7fc86, 0xc4b91e118db9e2f0, 0x2514aef88f888ab1, 0x8f7692f11e709f16]),
+  fnv1a_1024_test_112: U1024, b"64.81.78.84", U1024::from_words([0x3fa9d253e52ae801, 0x05b382c80a01e27a, 0x53d7bc1d201efb47, 0xb38f4d6e46548859, 0x0817b9143bf10aa7, 0x98ce6c0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000253eb20f42a72, 0x28af9022d9f35ece, 0x5bb71e40fcd8717b, 0x80d164ab92170999, 0x6e5c4380f2d1ea6c, 0xc79da383824960a1]),
+  fnv1a_1024_test_113: U1024, ::std::ffi::CString::new("64.81.78.84").unwrap().as_bytes_with_nul(), U1024::from_words([0xae859e431319ec6f, 0xcac09e933c0a78b0, 0xde0034aba17059c3, 0x8c44ad716d5af304, 0x5eb471289271096a, 0x417e1d0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x039c23a21aa5d309, 0x1842860bfe6a0604, 0x3af9eac81ba7fc86, 0xc4b91e118db9e2f0, 0x2514aef88f888ab1, 0x8f7692f10bcad9ad]),
+  fnv1a_1024_test_114: U1024, b"feedface", U1024::from_words([0x0fb21777d3faba3e, 0xd6d4fed9231afeba, 0x9951efd486fb5b9d, 0xb2d0999dbaf424da, 0x0a23533049d5f1de, 0xa5e4960000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000009fc8e2, 0xdb69d70ab3c0555c, 0x87ad54ad422919a5, 0x9af729b7091e439f, 0xd510100f02959860, 0x7fa18d6a285640ac]),
+  fnv1a_1024_test_115: U1024, ::std::ffi::CString::new("feedface").unwrap().as_bytes_with_nul(), U1024::from_words([0xf6f747af25a9de26, 0xe8a493431e31b4a1, 0xed2a92304af6ca97, 0x6bc1d96ffcad3524, 0x4e6062622255806f, 0x99bd4a0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000f7ca87ce, 0x43227b98c144607e, 0x67cc50af99bcc5d1, 0x514bb0d923eededd, 0x69e8e74701fd4da5, 0xed884da08dc24abc]),
+  fnv1a_1024_test_116: U1024, b"feedfacedaffdeed", U1024::from_words([0xf5ed8ba10097026e, 0x4bdf6ed8dff81d35, 0xe8cb50cdea1d312e, 0xd2f6644b9c8ffccc, 0xe7b95f348fbc37ee, 0x4be41a0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000014e0e64e, 0x8b1537e8b39bdf3c, 0x9cf101ec642a361b, 0xad21510f30a04575, 0x2ac61445ef9177d2, 0xb9b84ea0c640ebba, 0x2d2f2bb7bab1e441]),
+  fnv1a_1024_test_117: U1024, ::std::ffi::CString::new("feedfacedaffdeed").unwrap().as_bytes_with_nul(), U1024::from_words([0xfd40c54adb30b16f, 0xd3b5020075165bca, 0xa391c47ed5598b8c, 0xb9915bfb8399aa89, 0x9b645eb0160e7446, 0x62a0930000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000002060c527cd, 0xafe7b3de88b930ff, 0x61bffb975575e8eb, 0x7caab48e688bb6b3, 0x552d70748496d1ca, 0x02d1ef5372ad8fb8, 0x1226cbec85def8cd]),
+  fnv1a_1024_test_118: U1024, b"feedfacedeadbeef", U1024::from_words([0xf5ed8ba10097026e, 0x4bdf6ed8dff81d35, 0xe8cb50cdea1d312e, 0xd2f6644b9c8ffccc, 0xe7b95f35fa71c5e5, 0x85be7e0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000014e0e64e, 0x8b1537e8b39bdf3c, 0x9cf101ec642a361b, 0xad21510f30a04575, 0x2ac61445ef9177d2, 0xb9b84ea0c640ebba, 0x7c8c6df7fc29e60c]),
+  fnv1a_1024_test_119: U1024, ::std::ffi::CString::new("feedfacedeadbeef").unwrap().as_bytes_with_nul(), U1024::from_words([0xfd40c54adb30b16f, 0xd3b5020075165bca, 0xa391c47ed5598b8c, 0xb9915bfb8399aa89, 0x9b646131eedddcec, 0x924f720000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000002060c527cd, 0xafe7b3de88b930ff, 0x61bffb975575e8eb, 0x7caab48e688bb6b3, 0x552d70748496d1ca, 0x02d1ef5372ad9033, 0x25c689920cf9c09c]),
+  fnv1a_1024_test_120: U1024, b"line 1\nline 2\nline 3", U1024::from_words([0xb897b5105c6e6e59, 0x7783b1cee11c5a1e, 0xfe09d71e827c26d6, 0x1d89e2177e10d83b, 0xb715c83d04f065f7, 0xfc7d930000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x78c10a52e7afdaf1, 0x67e608756cd3d314, 0x5361343de4d43872, 0xc6d1c526c07e9b42, 0x50b92896b957c79c, 0xe2c968f74ae97c46, 0x542a3e35be67f205]),
+  fnv1a_1024_test_121: U1024, b"chongo <Landon Curt Noll> /\\../\\", U1024::from_words([0x93ab73cfe16325c0, 0x5ae5926f480a95d5, 0x5c5e7273418a3d15, 0x1a9d218d2dba627a, 0x9502c06ecb7b7046, 0xb061a10000000000, 0x0000000000000000, 0x0000005b41e7aa83, 0xaeb3a44e230384f5, 0xaca946916dadb061, 0x21c1b8482c0d1285, 0x542305ba8a9bcdfe, 0xb6ab252cd9b5e443, 0xa198f7c0c080b5ad, 0xc85a0ade03e8bcaa, 0x38eb139f3e6fe7c4]),
+  fnv1a_1024_test_122: U1024, ::std::ffi::CString::new("chongo <Landon Curt Noll> /\\../\\").unwrap().as_bytes_with_nul(), U1024::from_words([0x0df51eb4a9c745d7, 0x91d21549638f88b9, 0xf45bc05e3c587a7a, 0xc662b5b84415961c, 0xfe01160a7980bcdd, 0xf74e710000000000, 0x0000000000000000, 0x00008d8534436e35, 0xec95cd2c4c7530fc, 0xc2826f87165a86a1, 0x596ac7ec5045b8c3, 0x7a4fe248f39e7401, 0x4768a68d9f12fce1, 0x9a3835ea8799be7f, 0xb3a2da4c0fec93fa, 0x448d6df3d38a6af4]),
+  fnv1a_1024_test_123: U1024, b"chongo (Landon Curt Noll) /\\../\\", U1024::from_words([0x93ab73cfe16325c0, 0x5ae5926f480a95d4, 0x3dfe162889c578b5, 0x59d2079969c85411, 0xe6a2d75c79e67be0, 0xe05ec90000000000, 0x0000000000000000, 0x0000005b41e7aa83, 0xaeb3a44e230384f5, 0xaca946916dadb061, 0x21c1b8482c0d1285, 0x542305ba8a9bcdfe, 0xb6ab252cc7f1b542, 0xb60ebf005097f31d, 0xa2c6f1e8a9996806, 0x56259cfacbbe4b4f]),
+  fnv1a_1024_test_124: U1024, ::std::ffi::CString::new("chongo (Landon Curt Noll) /\\../\\").unwrap().as_bytes_with_nul(), U1024::from_words([0x0df51eb4a9c745d7, 0x91d21549638f86ec, 0x14bd9f93b5fb318c, 0xe2a8e68bd29c486c, 0x43f000bf300b1687, 0xb149040000000000, 0x0000000000000000, 0x00008d8534436e35, 0xec95cd2c4c7530fc, 0xc2826f87165a86a1, 0x596ac7ec5045b8c3, 0x7a4fe248f39e7401, 0x4768a67211d61874, 0x54de337cfba404f5, 0x6e8525cf02e651d3, 0x985470edf61ac983]),
+  fnv1a_1024_test_125: U1024, b"http://antwrp.gsfc.nasa.gov/apod/astropix.html", U1024::from_words([0x23e5e1428cea7f42, 0x528b3c7a2e5cd60e, 0x58b300039428b031, 0x12570c5d8d122649, 0x1f53778c64108a70, 0x29e57d00a5db98d3, 0x73db2c6a37cd7f4d, 0x9608df27d07254e1, 0x61b614df2165f94b, 0xcfc260c582dae77e, 0xf6f0356609b3f005, 0xbe7ecbd71489842d, 0x647b7e5ca9ba6af3, 0x455dcc84f0698c81, 0x0714b6ac661d2e2d, 0xf2fb1afe353443e8]),
+  fnv1a_1024_test_126: U1024, b"http://en.wikipedia.org/wiki/Fowler_Noll_Vo_hash", U1024::from_words([0x463809a3f635f99e, 0xc1875709c2f57be7, 0xd2362990d211e1c8, 0x850f9ab1180303d2, 0xf9202c1f81b41626, 0xd34808e00a1bdeeb, 0x03637e2d837c6da7, 0x5bf3f70b45637a85, 0xed57d0890636b328, 0x1dcc5fbf44a4d60c, 0x14db61ba755224ac, 0x2c936d008d273ed4, 0x9dde5da8e489638f, 0xd95a8a7bbef23c55, 0x0757426fbf542007, 0xdb4eaf5ca39d4205]),
+  fnv1a_1024_test_127: U1024, b"http://epod.usra.edu/", U1024::from_words([0x1716deb2b8755ea3, 0x2b733099eabce8d0, 0x73dddca10be5f58d, 0xdd1ef93e817b9fac, 0x4a064f573e691189, 0x4086770000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000000000bb, 0x435d02914bb6885e, 0x1fbb1e19c47e5685, 0x4dbe03fbdd1b89fe, 0x534ebd188456c5d7, 0x2e911b0d7a835e1d, 0x2998d640efc33637, 0xea7c14ec9eed4d61]),
+  fnv1a_1024_test_128: U1024, b"http://exoplanet.eu/", U1024::from_words([0xb897b5105c6e6e59, 0x7783b1cee11c5a1e, 0xfe09d71e82775d89, 0xc80a21b52375f23c, 0x84da0cf8101a55a7, 0x56fa1a0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x78c10a52e7afdaf1, 0x67e608756cd3d314, 0x5361343de4d43872, 0xc6d1c526c07e9b42, 0x50b8c9e6e2a568b3, 0x56834344af320b5f, 0xa15083d6213d11b4]),
+  fnv1a_1024_test_129: U1024, b"http://hvo.wr.usgs.gov/cam3/", U1024::from_words([0x4b99d3efcef51ea9, 0x5709d3e429b8a007, 0xca12de7e03bf3815, 0x0c8836afac354aa6, 0xb1548946230cdeec, 0xf2e3b80000000000, 0x0000000000000000, 0x000000000000000f, 0xc74cbee6ed33d7d2, 0x390d47dca6702e84, 0x4d01b13b2ca28dd9, 0x8b9e2dfbf81e5c7f, 0x324e8fcc20cd39de, 0x1a88f3db0e813cb5, 0xb6edccba7484db0e, 0x850afcb0760f739f]),
+  fnv1a_1024_test_130: U1024, b"http://hvo.wr.usgs.gov/cams/HMcam/", U1024::from_words([0xead961a599e9c343, 0x39a3a0495710fd8f, 0xd70768351d71594d, 0x8cea647dc411ae05, 0x83b2fa6a5f22fd11, 0x40f4e10000000000, 0x0000000000000000, 0x00db77920c91e99f, 0xe44f2db291bcf7f9, 0xa446f47daa62c837, 0xaa9809787c1f8724, 0xade1ed17e6f8c934, 0x235575162f6c945e, 0xd28794da94664bdf, 0x1902ba1111e505a3, 0xa1d0b5a796299f96]),
+  fnv1a_1024_test_131: U1024, b"http://hvo.wr.usgs.gov/kilauea/update/deformation.html", U1024::from_words([0x2d7319721e26c9ec, 0x8bc43cb8f801cab1, 0x5a503b1624c706e6, 0x4991cc088db89d4e, 0xc4ed3e3c67475b02, 0x12f089c724ae8c14, 0xf070adcf242d4386, 0x836d84958897496a, 0x9d050d2d61db8a7f, 0x1722f7f31cd5ca2c, 0xd76caeade03fbd90, 0xb6d1ace10846ab2e, 0x1e45c2a5e10894d1, 0x7cd5f55abfd51a0b, 0x5e2a523b42c1f195, 0xde9ead755a1bd90a]),
+  fnv1a_1024_test_132: U1024, b"http://hvo.wr.usgs.gov/kilauea/update/images.html", U1024::from_words([0x942b16568a42e351, 0x06950e6b02bdc939, 0x925a320cb00dedf5, 0x257482392ba3c2b4, 0x25efab5f8ad80188, 0x4880eb6fad38b274, 0x414aac94e7f60c89, 0x99561c7a9b4501b1, 0x112e647ea2d3d536, 0x35f07b9d739ff0bc, 0x58364c79b982baf9, 0xbfd7de18af52b78a, 0xceec201068329561, 0xf61d276c9b3f4312, 0x037c9693f8cde1bb, 0xc9698c24517417bc]),
+  fnv1a_1024_test_133: U1024, b"http://hvo.wr.usgs.gov/kilauea/update/maps.html", U1024::from_words([0x95087c02ba9f56c0, 0xcf6396215f79fe48, 0x8c2d09b0c27e8e9a, 0xf1267dcbfbe529ef, 0x3165d7491ba73efc, 0x2deb3901358bffea, 0xaae3e0b889ae6b51, 0xabc210be414da184, 0x875e5e06cb239a91, 0x3070124bed78ffe4, 0xf282cf3cfa43a59c, 0x43c624a5894a1db8, 0x071971467dee36eb, 0xbd4c2c77cc8647d8, 0xcd31c3e7f707d006, 0x5877ef81d0fef51f]),
+  fnv1a_1024_test_134: U1024, b"http://hvo.wr.usgs.gov/volcanowatch/current_issue.html", U1024::from_words([0x2d7319721e26c9ec, 0x8bb006cfd9b07523, 0xc5adeeba40deeec7, 0xbdf786f00872e1fc, 0x8201feea76e05ce9, 0xf9b9b9c724ae8c14, 0xf070adcf242d4386, 0x836d84958897496a, 0x9d050d2d61db8a7f, 0x1722f7f31cd5ca2c, 0xd76caeade03fbd90, 0xb6d1ace10845a859, 0xa82c311e5b7f2b43, 0x0e36455bb0732e6b, 0xe90255b498374161, 0x9065286b6c42ae10]),
+  fnv1a_1024_test_135: U1024, b"http://neo.jpl.nasa.gov/risk/", U1024::from_words([0xe01b846f904e8894, 0x189a180701dff835, 0x2c7ce79b8206cb61, 0xbb6711be5a055c38, 0x29111bda7cc8060d, 0x8cfac10000000000, 0x0000000000000000, 0x0000000000001878, 0x12040c1dd965b102, 0x7998712e1bf8232b, 0x6b9fd8c43815fa5d, 0x844d4fbfc7157141, 0x03d4ff8ede42bd70, 0xa514b2a3a1f4c693, 0x87a7c6bcea2b5c4f, 0x4e6c009acc510aed]),
+  fnv1a_1024_test_136: U1024, b"http://norvig.com/21-days.html", U1024::from_words([0xa0a6be8717198d71, 0x3c6883e1bd537954, 0x40709cac28227336, 0x11d4971859a8a977, 0xc0ed6860def4605b, 0xf65d070000000000, 0x0000000000000000, 0x000000000025f233, 0xf046ca4a22b380d6, 0x916784815fce8a55, 0xe6e3284afa154306, 0x2be4ac67bc40a1d2, 0xf150508ead7fc7ad, 0xa37a422dc3e8604b, 0x661d2d24edb7810e, 0x5a0f9cc5ea51dea8]),
+  fnv1a_1024_test_137: U1024, b"http://primes.utm.edu/curios/home.php", U1024::from_words([0x75913e60b2b9742c, 0x32cedd71c389bbbd, 0xe9339f4f641fc054, 0xd0b1e756747ede1b, 0x6f0451f3ae47e027, 0x685e880000000000, 0x0000000000033281, 0x1d68c235a6f2844d, 0xb76579b983c1bcbf, 0x1471bfe1927e1ec0, 0xe89b29f39228b907, 0x225a33354e647d21, 0xf64a7564f773645f, 0xaad1af507c95ab46, 0x306ed3db057665ac, 0x6571556302850df5]),
+  fnv1a_1024_test_138: U1024, b"http://slashdot.org/", U1024::from_words([0xb897b5105c6e6e59, 0x7783b1cee11c5a1e, 0xfe09d71e82775d89, 0xc80a21b524033b4d, 0xe84aafaea294d651, 0xdd7b400000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x78c10a52e7afdaf1, 0x67e608756cd3d314, 0x5361343de4d43872, 0xc6d1c526c07e9b42, 0x50b8c9e6e2a568b3, 0x5694276bcd3ba512, 0x8eb77caf26e2ef3c]),
+  fnv1a_1024_test_139: U1024, b"http://tux.wr.usgs.gov/Maps/155.25-19.5.html", U1024::from_words([0xe132ea572cb1e541, 0x5e9ffa2b99c4399d, 0x81de82f7054932a3, 0x9f1decb8f0cbebf2, 0x1ab24e4fd6213252, 0x7951f900000044f7, 0x4980985600aebb81, 0x568c7797e6f9103e, 0xb11dbeadaa581cd1, 0x5b9d11830bc38945, 0x37b23db82e204bf9, 0x261e043f68363a1d, 0x186967b5e7ab4987, 0x380fa40d3e255d93, 0x3277bd8d012e4197, 0x3ba41a48826a6c75]),
+  fnv1a_1024_test_140: U1024, b"http://volcano.wr.usgs.gov/kilaueastatus.php", U1024::from_words([0xe132ea757bdec73c, 0x0d6d44a14dba1d40, 0xb96410a18c1568bd, 0xc170384d9c35d055, 0x00cbdef2034bb3ea, 0x3f867000000044f7, 0x4980985600aebb81, 0x568c7797e6f9103e, 0xb11dbeadaa581cd1, 0x5b9d11830bc38945, 0x37b23db82e204bfa, 0x6ad88b2c7fd1426a, 0x1cf89afb66b2eac2, 0x8e6862a4b43eb667, 0x631392f6ec4aed20, 0x18ddd007ba4f0947]),
+  fnv1a_1024_test_141: U1024, b"http://www.avo.alaska.edu/activity/Redoubt.php", U1024::from_words([0x275b5e87faa9c00b, 0x0d5a04e0dd6734ab, 0x1455e751b300daa5, 0xfe59fc3da683153d, 0x6dc7240c509e3a6e, 0xf0a57b00a5db98d3, 0x73db2c6a37cd7f4d, 0x9608df27d07254e1, 0x61b614df2165f94b, 0xcfc260c582dae77e, 0xf6f0356609d72476, 0x6951ba06721b2e29, 0xd89854b306fa90b0, 0xaee99b5e89699632, 0x7446338c3ff0b92e, 0xaef9d54acc61b185]),
+  fnv1a_1024_test_142: U1024, b"http://www.dilbert.com/fast/", U1024::from_words([0x4b99d3efcef51ea9, 0x5709d3e429b8a007, 0xca12de7e3fdeafae, 0xb2264d86480f458f, 0x465ff1ddb0882c97, 0xb9e0310000000000, 0x0000000000000000, 0x000000000000000f, 0xc74cbee6ed33d7d2, 0x390d47dca6702e84, 0x4d01b13b2ca28dd9, 0x8b9e2dfbf81e5c7f, 0x324e8fcc20cd39de, 0x1ef98c3e167b4e19, 0x217bd55190db1a06, 0xb296ce014c4376f7]),
+  fnv1a_1024_test_143: U1024, b"http://www.fourmilab.ch/gravitation/orbits/", U1024::from_words([0x85abdbdf45413bf7, 0x815e6b8b5936d2fd, 0x889cc7b30ea18d4e, 0xdf238e6d84c72b6e, 0x8a970454f87c10c7, 0xaf5172000000002c, 0x78c4ffbd2756340f, 0x2832a6ab43a50f84, 0x596efc9c7ce1ea25, 0xed87cfae6f961834, 0x67f57c9bde95aef1, 0x272c959fe4eec250, 0x90b8add622b0879a, 0x7f782fb0768eeb29, 0xca832231f85e9121, 0x495326303aa5fbd8]),
+  fnv1a_1024_test_144: U1024, b"http://www.fpoa.net/", U1024::from_words([0xb897b5105c6e6e59, 0x7783b1cee11c5a1e, 0xfe09d71e82775d89, 0xc80a21b5242794ef, 0xfe3497619d18c9f0, 0x254e650000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x78c10a52e7afdaf1, 0x67e608756cd3d314, 0x5361343de4d43872, 0xc6d1c526c07e9b42, 0x50b8c9e6e2a568b3, 0x569875fe43276cf1, 0xd83e685b5f8e4abe]),
+  fnv1a_1024_test_145: U1024, b"http://www.ioccc.org/index.html", U1024::from_words([0x37d87daab74cc056, 0xeeb65803eac3b655, 0x6f339634c4ee6d2a, 0x18ec411225e4ead9, 0x78cbad6c961c642c, 0xf35c2a0000000000, 0x0000000000000000, 0x000000003ad89a8b, 0x9dc7b4f7d05eccbf, 0x7d887ca1934c8737, 0x0e457c45d2f8f092, 0x119f5cdef03af420, 0x398ced430f35a287, 0x4c92d1117b735e3c, 0x7213ebb3eb7aab44, 0x2fde443dd6ce8d87]),
+  fnv1a_1024_test_146: U1024, b"http://www.isthe.com/cgi-bin/number.cgi", U1024::from_words([0xd79c9bbd45573460, 0x33d893c07369d674, 0xb3225a4ee37b916b, 0xaa73c992adb9716e, 0xe39ac651f0058dc2, 0x65a7f20000000000, 0x00000007b07084e3, 0x14b7197bd1f00ae2, 0xd96a06fe6754a441, 0xc30e92d6b123bc26, 0x5036d5eb5162493c, 0x5e90dd84294c3296, 0x257df80c5c53bfd9, 0x6722267870738584, 0x731289e8ead613b5, 0x8f21df6736317615]),
+  fnv1a_1024_test_147: U1024, b"http://www.isthe.com/chongo/bio.html", U1024::from_words([0x0c3e0e61e8c48938, 0xc320b63631cd9625, 0x224309b6d116fe2d, 0x053e92c65a77ca65, 0x99ce109532930887, 0x9a78590000000000, 0x000000000000020f, 0xcd21cb9cd4925807, 0xef0d26ff2d786d73, 0xe8314b48ca33e792, 0xdd1eb2b9ae7954f0, 0x8ab9f0a72599144e, 0x34366c191945c097, 0x300681393470c4ff, 0x1e0994bfc8d69fb2, 0x567d87a6d8f25779]),
+  fnv1a_1024_test_148: U1024, b"http://www.isthe.com/chongo/index.html", U1024::from_words([0x7af6c31783cc5dd9, 0x2f4f095c8fb12c35, 0x01ec84f271975ff7, 0x8be8ab7137a9867a, 0x34ae3ba269757892, 0x78b4cd0000000000, 0x0000000004f5523a, 0x9b752d33e6172c85, 0x685dc4b15371b452, 0xb46690d02d95b128, 0xb8a00eb9a926f010, 0x45e169aa91d6470f, 0x359da84840b7582e, 0x54bbbbbe87b2ed93, 0xd8abee172dde73d2, 0xaed10b1757c0dc7b]),
+  fnv1a_1024_test_149: U1024, b"http://www.isthe.com/chongo/src/calc/lucas-calc", U1024::from_words([0xdfd60f48b91de93c, 0x39db23d36f0f013a, 0xd2823798d6772067, 0x15de04f9669128f7, 0x5a03bd0486d6945f, 0x7fb34c01358bffea, 0xaae3e0b889ae6b51, 0xabc210be414da184, 0x875e5e06cb239a91, 0x3070124bed78ffe4, 0xf282cf3d42a38ba1, 0x5036444285d5c910, 0x369f289e5dc238e4, 0x73365e3c6d6b7088, 0xe2d0916ee9f0794a, 0xfda99a552de07299]),
+  fnv1a_1024_test_150: U1024, b"http://www.isthe.com/chongo/tech/astro/venus2004.html", U1024::from_words([0xe76924502a205a01, 0x1b9600d26065ced7, 0xe7ed41c0c302aeb3, 0xa8c133f4e55c33db, 0xa33eaabad91f6969, 0x121e0277cbdc542e, 0x7b1fe17c54908ead, 0xccbe80a808ba180b, 0x3b135b9995d937ce, 0x05dc9f4118977e56, 0x9a0d2b76dc795acb, 0xcebd7db3f5babfc0, 0x08da7292e3710210, 0xfdc4c69a574365cf, 0x0121e5514482ccb8, 0xdd3b3045198539cd]),
+  fnv1a_1024_test_151: U1024, b"http://www.isthe.com/chongo/tech/astro/vita.html", U1024::from_words([0xc281551749a6f4eb, 0x8e9f9f19d96d8696, 0x36291c77cd217ec7, 0x0192565c2bd6441e, 0x6961bf201030651f, 0x0a49dfe00a1bdeeb, 0x03637e2d837c6da7, 0x5bf3f70b45637a85, 0xed57d0890636b328, 0x1dcc5fbf44a4d60c, 0x14db6200579f8929, 0x6427db298c88ce24, 0xb4d1fd9766323a46, 0xab509aa080f6bfac, 0xed0d41d9f5270695, 0x54f4f6355ea2dce6]),
+  fnv1a_1024_test_152: U1024, b"http://www.isthe.com/chongo/tech/comp/c/expert.html", U1024::from_words([0x0a7ece3610a64bf8, 0x8f2cabb679977dbe, 0xff02b77f8eec9b93, 0x7173fba029dc2ea9, 0x323a478ee537ca75, 0x92361cdc8f231799, 0x7ee5a78d398cd634, 0x184357f3479e7e2a, 0xcb0c90c4630cfc5e, 0x54b8dbb68fea6442, 0x5027d49b9abdb16d, 0xfc55032bdf6c6be0, 0x2b9bc8a46b687954, 0x0c5047ead0c7bba2, 0xc561933b132f6afc, 0xd0c87235405f72fc]),
+  fnv1a_1024_test_153: U1024, b"http://www.isthe.com/chongo/tech/comp/calc/index.html", U1024::from_words([0xe76924502a205a01, 0x1b9600d26065ced7, 0xe7ed41c0c300356f, 0x0305b97776129479, 0x9bf01b09de373ad1, 0x15750c77cbdc542e, 0x7b1fe17c54908ead, 0xccbe80a808ba180b, 0x3b135b9995d937ce, 0x05dc9f4118977e56, 0x9a0d2b76dc795acb, 0xcebd7db3f5babfc0, 0x08da7292e3710210, 0xfdc49593a79ee6a1, 0x2f0a8858d4b963e6, 0x4e8635b1c42e5366]),
+  fnv1a_1024_test_154: U1024, b"http://www.isthe.com/chongo/tech/comp/fnv/index.html", U1024::from_words([0x045737d426e6fc55, 0x74b22a262bb6a59c, 0xdfafe4dcf5352e77, 0xb898da26a07cb42f, 0xea8c0e1124f3b4a9, 0x5ee62109f96b9909, 0xca24d6023f682ec9, 0xa06f644610c9a85c, 0xe27c808d9b235e49, 0x62acba1d2e7d7ad6, 0x4dc4b54ef82c2790, 0x4fd5eb097b234aa3, 0xa09626fa91042757, 0x187f8725c5bdfb6c, 0x21b193c836ebaae0, 0x1ddc075b53788265]),
+  fnv1a_1024_test_155: U1024, b"http://www.isthe.com/chongo/tech/math/number/howhigh.html", U1024::from_words([0x31d77e8d03a66130, 0x469fadfac0d390e6, 0xb3ceecd341684fad, 0xc9a30edb2d3fd74c, 0xc99aa670b3d7aeab, 0x5082d6739ff54ae4, 0x62343cf1bae4b93e, 0x6e2fa005da0f8af2, 0xcb77f7a1a808652e, 0x42dcd2a494520bff, 0xaf7a05897e9a7d65, 0x43d72aa47e5fb3e3, 0x7d7e404a706b2417, 0xa817ae30e77c6b03, 0x041bd41060d45a9c, 0x79e578079301c839]),
+  fnv1a_1024_test_156: U1024, b"http://www.isthe.com/chongo/tech/math/number/number.html", U1024::from_words([0x486805017a906773, 0x36a90737d76b1d9b, 0xa0ec1958d894c182, 0xf7518fdca41947c6, 0x43023e2e75f3fce3, 0x8ed14d9689c0c771, 0x6bef5da79f19b988, 0xdccfc1da9d3e9698, 0xcafdb90c03a5be7b, 0x47bec86e0966bc52, 0x89cac933eee887bb, 0x41f1b5f384827f7c, 0x61afb6a68e25ab9e, 0x0b62b837944026f5, 0xb0b20b7e31e5beca, 0x1eacb6765629aa74]),
+  fnv1a_1024_test_157: U1024, b"http://www.isthe.com/chongo/tech/math/prime/mersenne.html", U1024::from_words([0x31d77e8d03a66130, 0x469fadfac0d390e6, 0xb3ceecd341684ad0, 0x46f8046830216f43, 0xbdaabb688584737c, 0xa320ef739ff54ae4, 0x62343cf1bae4b93e, 0x6e2fa005da0f8af2, 0xcb77f7a1a808652e, 0x42dcd2a494520bff, 0xaf7a05897e9a7d65, 0x43d72aa47e5fb3e3, 0x7d7e404a706b2417, 0xa817adcb0818d2b7, 0x88513b169cfbda82, 0x049a9f33e438dc7a]),
+  fnv1a_1024_test_158: U1024, b"http://www.isthe.com/chongo/tech/math/prime/mersenne.html#largest", U1024::from_words([0xce0387f34b25d448, 0xe7fb861ff18b6bd6, 0xa65bdae484b29fda, 0x4bd5b255c6bfa9d0, 0xd45f8098133db12d, 0xed4d65a7d40f1f6e, 0xa539674ebc053b87, 0x55ff14623ae2d766, 0x17486242ddc2e1f6, 0x7760bb7a07eb10fb, 0xf43f05d808f1c4fb, 0x8fd3e46d040426ed, 0x66b7082a84d2ab75, 0x7ad107ca87bf31ff, 0x8541d403dc73b26e, 0xfc56e6ac178c8847]),
+  fnv1a_1024_test_159: U1024, b"http://www.lavarnd.org/cgi-bin/corpspeak.cgi", U1024::from_words([0xe132ea6592bf9e9f, 0xde7efe616491f86b, 0x5181c64f64ce5b7d, 0xac4a8548ccd6e463, 0xf937442a3bcc8bc7, 0xb02d3600000044f7, 0x4980985600aebb81, 0x568c7797e6f9103e, 0xb11dbeadaa581cd1, 0x5b9d11830bc38945, 0x37b23db82e204bf9, 0xc02408f4dd41f626, 0xe72686f5f9731c69, 0x9813eefaf845599b, 0xba388b306d72323f, 0x358cacc1a5b0408e]),
+  fnv1a_1024_test_160: U1024, b"http://www.lavarnd.org/cgi-bin/haiku.cgi", U1024::from_words([0xc026c0e31919bd59, 0xb30fb796bbae25b6, 0x369f7d5b9bf17bc8, 0x0a83482ba497740c, 0x7447513371410b73, 0xf99f400000000000, 0x00000bec9e7e1427, 0x1ff285049140e1cb, 0x296cd8863e42b9fb, 0x7d99b6f0b46ac76a, 0x6509bded356b929e, 0xa6a785f40b2a72d8, 0x0c986c71887a79d9, 0x0bc6ff8dbee5aaa6, 0x1f97cc72d2770e99, 0x508fd01ead1c6bca]),
+  fnv1a_1024_test_161: U1024, b"http://www.lavarnd.org/cgi-bin/rand-none.cgi", U1024::from_words([0xe132ea6592bf9e9f, 0xde7efe616491f86b, 0x5181c64f64ce5b7d, 0xac4a8548cd7f5f23, 0xe1fbc92c8992bb04, 0x776c6f00000044f7, 0x4980985600aebb81, 0x568c7797e6f9103e, 0xb11dbeadaa581cd1, 0x5b9d11830bc38945, 0x37b23db82e204bf9, 0xc02408f4dd41f626, 0xe72686f5f9731c69, 0x9813eefaf845599b, 0xba4ca8055e8ce58b, 0x3dd4f139552afd1e]),
+  fnv1a_1024_test_162: U1024, b"http://www.lavarnd.org/cgi-bin/randdist.cgi", U1024::from_words([0x85abdbdf45413bf7, 0x71eec14ba5c07974, 0x988d367f0546aad8, 0x8109e0a73ec1f51a, 0xeff8e501a7af72ff, 0x98e4c9000000002c, 0x78c4ffbd2756340f, 0x2832a6ab43a50f84, 0x596efc9c7ce1ea25, 0xed87cfae6f961834, 0x67f57c9bde95aef1, 0x272c959fe42f3d3c, 0x1159159e9abe8443, 0x54363769bd8ff921, 0xec7ab6445dbd53fe, 0x32dd2cf3987946f3]),
+  fnv1a_1024_test_163: U1024, b"http://www.lavarnd.org/index.html", U1024::from_words([0x0df51eb4a9c745f2, 0x7cfd76113e1c99b7, 0xb0fe915e3e33bfe2, 0x2e561223954b00c4, 0xb1ec182ead9fc432, 0x60fd900000000000, 0x0000000000000000, 0x00008d8534436e35, 0xec95cd2c4c7530fc, 0xc2826f87165a86a1, 0x596ac7ec5045b8c3, 0x7a4fe24a37649387, 0x8c04bacee3db5445, 0x22f48b158b03e673, 0xe8f19857b00a16d3, 0xf7923460ed453610]),
+  fnv1a_1024_test_164: U1024, b"http://www.lavarnd.org/what/nist-test.html", U1024::from_words([0x97b6f81bfd576803, 0x9f9d667202e7c3cf, 0x135c03a24765fa01, 0x399bf56df178967d, 0x52ee1ecf79a934a1, 0x892e010000000000, 0x1cad4ff583a54b93, 0xb4a7c8f6c2441fa2, 0xd505703166d7f1c2, 0x30f606539f8e3df7, 0x1ab33cedb13eed18, 0x7764b4ee58ead6e2, 0x7d2455d15392db93, 0xd64c3b72f82d7fd8, 0xd26ac6e2b9191e98, 0x6d87931fd977184c]),
+  fnv1a_1024_test_165: U1024, b"http://www.macosxhints.com/", U1024::from_words([0x62bd286df22a3bf8, 0x14c20abb52d2ed10, 0x2ebb9dd65ce39754, 0x35ca83ef66ce0676, 0x08875c0b913c11e9, 0x4795070000000000, 0x0000000000000000, 0x0000000000000000, 0x0a2cafe057016b96, 0x21acd97da76e8cb1, 0x04b53408882c4221, 0x6f43761ef1261f2f, 0x64b89efdef511f19, 0x1032ed0217453273, 0x71711045ffffa7b6, 0xf2c4e4cd1ff1f0f3]),
+  fnv1a_1024_test_166: U1024, b"http://www.mellis.com/", U1024::from_words([0x4ccde075cc07bee7, 0x7d2c5d0455b223c8, 0x05d4fcee04b8da7a, 0x6c66a504d2e47a48, 0x6b4a49160723dc90, 0xeadfd40000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000012267, 0x773cfb526a1179f7, 0x352dadf5b7ec2cb9, 0x8fac2d95e3b4ff67, 0x311b3f053a90ceb3, 0x3706f3e6fdb8f36c, 0x6e1bb829ac91a470, 0xc84ac19755d1a48e]),
+  fnv1a_1024_test_167: U1024, b"http://www.nature.nps.gov/air/webcams/parks/havoso2alert/havoalert.cfm", U1024::from_words([0x5e09d49a93d8f684, 0x0fb05417ff5941fa, 0x07efacfac87952a1, 0x74355bd86a8752c5, 0xaca0acf6e808ab2f, 0x006cf1aff64499f8, 0x8588491910d2b435, 0xfcba7db5e32c2863, 0xd205403787378545, 0xb065fe8f694e2fbf, 0x29221b926d9e87d3, 0x9f39c1da2baec747, 0x83d1076a6c9e13c7, 0x92053ca33ba8abec, 0x42d6713fe2113c0e, 0x3785c77a7c41d53c]),
+  fnv1a_1024_test_168: U1024, b"http://www.nature.nps.gov/air/webcams/parks/havoso2alert/timelines_24.cfm", U1024::from_words([0x474c9844d71026e7, 0xf55e33a38682110a, 0x951cf3c23a6ecc79, 0x2151548be4a9cd55, 0xcd1f14c56e743389, 0x307a66beb3d69bef, 0x1ab295f0cc554aee, 0xcbe302be710f3917, 0x7ec6066598bad4ca, 0xffb66d8583c851cb, 0xd642286dad070ca3, 0x01e92b26fa147c34, 0x09559cb3cb973bf8, 0xe5715beebce3d0e6, 0x4dcc0b2b796d84af, 0x17446de04a7d05e7]),
+  fnv1a_1024_test_169: U1024, b"http://www.paulnoll.com/", U1024::from_words([0xccdbb286521e08c6, 0x95e4e00a08055cb3, 0xa6529f24c35ec42b, 0xc92fd45413960be2, 0x60ea1261646e7647, 0xbee6850000000000, 0x0000000000000000, 0x0000000000000000, 0x00000002ba6645c1, 0x3704e638018a64f2, 0xd811240eca5b4ea6, 0x7a2d2dfb240d8217, 0xdf622a258a28fd81, 0x0786ef04cfe22f19, 0x47b278bdce76dfa9, 0x80b86d1baffead49]),
+  fnv1a_1024_test_170: U1024, b"http://www.pepysdiary.com/", U1024::from_words([0x38d9e0e27d85cb03, 0xafce0abaa3f635ac, 0x6b1e91f624299dfd, 0x3dbd8eeda7390f72, 0x55008ebb9451a9c0, 0x5522180000000000, 0x0000000000000000, 0x0000000000000000, 0x00068f98bf1737ba, 0x174c86ac7ce00961, 0xd0f055e4f038db69, 0x3149ae6740723b67, 0x4b0e01b0ae105343, 0x204859959b76d2ee, 0x0cde24645cc6578a, 0x9f31b86fbb71e434]),
+  fnv1a_1024_test_171: U1024, b"http://www.sciencenews.org/index/home/activity/view", U1024::from_words([0x9e424c17e17cc3fa, 0x3ab417d2ee044d7f, 0x5927f7e48c085879, 0xe7b0c084709d5a3c, 0x1be3b32fa068cff7, 0xfb691cdc8f231799, 0x7ee5a78d398cd634, 0x184357f3479e7e2a, 0xcb0c90c4630cfc5e, 0x54b8dbb68fea6442, 0x5027d49b8ca44270, 0xec00e0229ca9ae17, 0x629e2cf4d4d802f9, 0x0128c542d4989ea4, 0xd4d11eaf1599cc81, 0xfb17adcff7debfd6]),
+  fnv1a_1024_test_172: U1024, b"http://www.skyandtelescope.com/", U1024::from_words([0x37d87daab74cc056, 0xeeb65803eac3b655, 0x6f339634c4e72688, 0xeac67824a3d385bc, 0x420745e30c6a1137, 0xa007d90000000000, 0x0000000000000000, 0x000000003ad89a8b, 0x9dc7b4f7d05eccbf, 0x7d887ca1934c8737, 0x0e457c45d2f8f092, 0x119f5cdef03af420, 0x398ced430f35a287, 0x4c924105ddf9ab31, 0x57847eee2b65d84a, 0xce216569cd03c3d0]),
+  fnv1a_1024_test_173: U1024, b"http://www.sput.nl/~rob/sirius.html", U1024::from_words([0x52a3927d9170e727, 0x5b88c5df618f732d, 0xaa9d4760d1ece6fe, 0x1623d54cd2cb3d1b, 0x240216c283772031, 0x6931230000000000, 0x0000000000000001, 0x54586d7d7e474cf5, 0x0ec9ddec020c8e23, 0xc20926e13b307e53, 0x8dc6afd87ce493e1, 0xa75cae112fd005da, 0xcbe657f8533c2bde, 0x78c20bc00be33d6e, 0xe35e1296483a28a2, 0xb8359268d9f67fc3]),
+  fnv1a_1024_test_174: U1024, b"http://www.systemexperts.com/", U1024::from_words([0xe01b846f904e8894, 0x189a180701dff835, 0x2c7ce7e805e2affc, 0xb719c88a6c661e5f, 0x6b055a201e037654, 0x2b9f950000000000, 0x0000000000000000, 0x0000000000001878, 0x12040c1dd965b102, 0x7998712e1bf8232b, 0x6b9fd8c43815fa5d, 0x844d4fbfc7157141, 0x03d4ff8ede42bd76, 0x08fe7c48847f1f0d, 0x05664c385428765c, 0xfcefcd6537933ef8]),
+  fnv1a_1024_test_175: U1024, b"http://www.tq-international.com/phpBB3/index.php", U1024::from_words([0xc281544b7031eff4, 0x7e62e444ea47e41f, 0x7a1d06553ffe1019, 0x7f826368c271ba34, 0x8356ee1ce0be39c8, 0xbddb82e00a1bdeeb, 0x03637e2d837c6da7, 0x5bf3f70b45637a85, 0xed57d0890636b328, 0x1dcc5fbf44a4d60c, 0x14db6200579f8920, 0xda06edcda82b482d, 0xb12ec4af548b8ff9, 0x336f350e938f2d22, 0xc45c6079870bfe88, 0x8bfcc307fb64f95a]),
+  fnv1a_1024_test_176: U1024, b"http://www.travelquesttours.com/index.htm", U1024::from_words([0x67abbed494709825, 0xd9d287eeb963f92e, 0x031c5cd62c601ec1, 0x00ac522304d7d723, 0x3828749a9bb10e4a, 0x653bca0000000000, 0x00127df1c98540ac, 0x8b184815419e280f, 0x3dcbc82e8d7a6b01, 0xc760b347c9973ffe, 0xb01b88dbd7d26008, 0x71cabb7550d418cb, 0x0b7bdc57f03bd7da, 0x1af3c21bbf803cae, 0x2e41ded0ee82ee33, 0xa0a9f48bded83b6c]),
+  fnv1a_1024_test_177: U1024, b"http://www.wunderground.com/global/stations/89606.html", U1024::from_words([0x67f6f5c1969dc114, 0x89030b7f6205fcff, 0x4d14236d538ada4d, 0xe4f7f96eb99793f2, 0x4416daf1b5394029, 0x8fd99ac724ae8c14, 0xf070adcf242d4386, 0x836d84958897496a, 0x9d050d2d61db8a7f, 0x1722f7f322eeec4c, 0xe60fe802ea6b132a, 0x09260372fb06b931, 0x261e9068b24c735e, 0xad55de7e047c0521, 0x28cf85b22f929212, 0xdcde3c4b52439e42]),
+  fnv1a_1024_test_178: U1024, &repeat(b"21701", 10), U1024::from_words([0x9a0c589e94c4f529, 0xa26aa1940b0ca218, 0xddefb9cf328078fc, 0x52a264bab365b31f, 0xec5855583117c9ff, 0xdac75c2fa0ecbe49, 0x40cda2ebb8917162, 0xca8a2a22ca039f97, 0xa4f1d8628281a511, 0xa5a90adc92fb2ea0, 0x4c647f079ccddaf9, 0xf79d83e61b1b7980, 0x2363a3551583823e, 0x9c13acd13ae258b0, 0xbe59b18342ba08c0, 0x664761da938d6ae7]),
+  fnv1a_1024_test_179: U1024, &repeat(b"M21701", 10), U1024::from_words([0x34e25091c7580086, 0x84eb35cd34e8fa24, 0xe357d4f492cb2254, 0x28340eafeecf5d53, 0xac7aab66717545bc, 0xe1893c2d23ab4970, 0x8d0db6151711e0ab, 0x475b27cc67d1b6f2, 0xc7c10d2dbf1d5cb1, 0x54e2780e7a69c14a, 0x76d7557a5ffe78c8, 0x63ad49a2adf068ce, 0x0e66302496920b51, 0x2022fa9ebf14f5e6, 0x6146da002f52ff87, 0x433529c32d52e1f3]),
+  fnv1a_1024_test_180: U1024, &repeat(b"2^21701-1", 10), U1024::from_words([0x0f840d7ff83f84b7, 0xe2c63ded905ff1b9, 0xe3406322336472be, 0xae3821ada9b3497f, 0x787fda7b60d89d0c, 0xf97943b09658427f, 0x74b611e650c94c8e, 0xb9760501c0f3a26f, 0x09dbbefcbc1f3d56, 0x2fc3ff1a555c37e6, 0xf79e3670ee518930, 0x7438cbde085090dd, 0x44a7bf5951c1e2da, 0xb30b2025f886acc4, 0x7a564ad681aceae6, 0xf390d32540bfeaaf]),
+  fnv1a_1024_test_181: U1024, &repeat(b"\x54\xc5", 10), U1024::from_words([0xb897b5105c6e6e59, 0x7783b1cee11c5a1e, 0xfe09d71e828702bb, 0x8a2f6bca0b85e7fa, 0x44415d25fae52d93, 0x00c7cb0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x78c10a52e7afdaf1, 0x67e608756cd3d314, 0x5361343de4d43872, 0xc6d1c526c07e9b42, 0x50ba00d71efdf44e, 0xf51351cf899911b2, 0x42471b69e8543343]),
+  fnv1a_1024_test_182: U1024, &repeat(b"\xc5\x54", 10), U1024::from_words([0xb897b5105c6e6e59, 0x7783b1cee11c5a1e, 0xfe09d71e81f64047, 0x71680911f8e07208, 0x19fe9fbe19edef86, 0x1da5430000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x78c10a52e7afdaf1, 0x67e608756cd3d314, 0x5361343de4d43872, 0xc6d1c526c07e9b42, 0x50aec67962ca0b6b, 0xa46fcf157ea78578, 0x9bb3d15da9c1ba63]),
+  fnv1a_1024_test_183: U1024, &repeat(b"23209", 10), U1024::from_words([0xd615b024e0033150, 0x107b09b16a09db5d, 0x9dfd0e5a92891ed6, 0x3dc1a380ff0b6d7f, 0x2f046934f0211a03, 0xe033092fa0ecbe49, 0x40cda2ebb8917162, 0xca8a2a22ca039f97, 0xa4f1d8628281a511, 0xa5a90bca71650391, 0x27ba8727d50cda82, 0x988689b784a9ee6c, 0x871f479d273030c7, 0x6d4f73cdf64c71b5, 0x2b090f88431811b2, 0xd148e983ada613db]),
+  fnv1a_1024_test_184: U1024, &repeat(b"M23209", 10), U1024::from_words([0x223cbce06a4e4fe7, 0x048d65df7c3fdd7a, 0xeb9c03baffb0b536, 0xbcb74d03fc34cf1f, 0x710bafe2d26b5977, 0x91b68a2d23ab4970, 0x8d0db6151711e0ab, 0x475b27cc67d1b6f2, 0xc7fb67866de14552, 0x03a27bfc67fe4d94, 0xcb188c3e2a08edca, 0xebbbd6bec06a58a5, 0x2fcf54c31b852fd4, 0x56785629f97ce92a, 0xcdb6c30331e594d6, 0x2d4a468e0558d0f3]),
+  fnv1a_1024_test_185: U1024, &repeat(b"2^23209-1", 10), U1024::from_words([0x70575f0b0aaae19e, 0xa0872318e38ebe40, 0x9905891fad8a3b6e, 0xae80ea0c172d8331, 0xb0c79073785920ce, 0xb173b5f671b92fe2, 0xd393f6be10937a3a, 0x6aa3a86493f4c30f, 0x7b92cc502b6e2d3f, 0x11867f353b68fd67, 0x2358a5f8425c40d8, 0xbbc5d05dc1f40a83, 0x95b3fb1736331cb7, 0x2d9876e7020bf25b, 0xed88135bd54ece78, 0x1e6ccf5b78c8de53]),
+  fnv1a_1024_test_186: U1024, &repeat(b"\x5a\xa9", 10), U1024::from_words([0xb897b5105c6e6e59, 0x7783b1cee11c5a1e, 0xfe09d71e828978ee, 0xa331b3545e4c07a6, 0x57b2568940cb27f8, 0x14ae6f0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x78c10a52e7afdaf1, 0x67e608756cd3d314, 0x5361343de4d43872, 0xc6d1c526c07e9b42, 0x50ba319dbfa0175b, 0x399d9fd074cdebdb, 0x04cf09fd657701eb]),
+  fnv1a_1024_test_187: U1024, &repeat(b"\xa9\x5a", 10), U1024::from_words([0xb897b5105c6e6e59, 0x7783b1cee11c5a1e, 0xfe09d71e81811e18, 0x9f3d53dd0a732b17, 0x6185855bae6989fa, 0x42ca2f0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x78c10a52e7afdaf1, 0x67e608756cd3d314, 0x5361343de4d43872, 0xc6d1c526c07e9b42, 0x50a5b168697ff772, 0x60707a373c6d836d, 0xd06759c81678d8cb]),
+  fnv1a_1024_test_188: U1024, &repeat(b"391581216093", 10), U1024::from_words([0x0babc1092dbc4a53, 0x613fbc22354ebe5c, 0x8938ac0feaa3e60b, 0xa513fd1118137a06, 0xbab61680d241c0c4, 0xd8e58d9b45ed5b0a, 0x103e7d84ded062bf, 0xfc0417ddebe10ce0, 0x0be953b0f740b74b, 0x7e8ac5edce01dbe6, 0x3efe44ddba696a20, 0x5d831a0526de2ed1, 0x4195a1526e70e5d9, 0x4567ef36026d98dd, 0x234003fd46226b17, 0x6c4f58d730062d0b]),
+  fnv1a_1024_test_189: U1024, &repeat(b"391581*2^216093-1", 10), U1024::from_words([0xc8b648c908dda48b, 0x7c6aaefd761f968a, 0xe822b86281d682d4, 0x45d39a1a89f86302, 0x5587a26fa00e54b8, 0xc2c53acf150483be, 0x94fbe438aa9fd619, 0x467a0ea573c40a1a, 0x6f19a1b7658b8bb9, 0x64e7cf43c9afd22f, 0x72ced60e5774b9f4, 0xed484473a1c64720, 0xc8731ad1003fad72, 0x56a1c0e497751414, 0xe0ee101f362e1b0b, 0xe4b9ca82c8915c2b]),
+  fnv1a_1024_test_190: U1024, &repeat(b"\x05\xf9\x9d\x03\x4c\x81", 10), U1024::from_words([0xa3331891840a5937, 0xb63858c4529db226, 0x4719502c909fa382, 0xa3a7e61f9410289c, 0x209d921ba0198db7, 0x30ed2a2d23ab4970, 0x8d0db6151711e0ab, 0x475b27cc67d1a54b, 0xafd7eec3916a94d8, 0x094598bb12449264, 0xcf7413012c979be0, 0x5fcccaf4f29f1546, 0xb23e0fa544c6f296, 0x89878f9cae629ad2, 0x731becf176973f48, 0x952ab711e043da73]),
+  fnv1a_1024_test_191: U1024, &repeat(b"FEDCBA9876543210", 10), U1024::from_words([0x52da83238873fe80, 0x416244c565db5881, 0xa8b09e264f6869d4, 0x58f0c9ea2d6225bf, 0xfed41207d41b685f, 0x4bb80d98dd847f34, 0x6f2adb09d47d0e03, 0x9593fdd0103e1d38, 0x5a956ff2b07ae707, 0x7f949147a961107d, 0xee8778d86a8b65cf, 0x27ba7da3234bce65, 0x6bd91c8c3d4a14f7, 0xaa79bb0a0e6c7031, 0x8f63f35a788b9035, 0x977d512a08719fb3]),
+  fnv1a_1024_test_192: U1024, &repeat(b"\xfe\xdc\xba\x98\x76\x54\x32\x10", 10), U1024::from_words([0x4b52a829109283ee, 0x868d5e4b512497bb, 0xcb3721e94fe3fd3a, 0x92286bff4bfcb156, 0x063fea8a41c87452, 0xa17069f550b1b122, 0xbea4a54707a74ad9, 0x5984be4295ca64c5, 0xc2644a847279621d, 0x83ace7deb33ebf06, 0xdd1733623b9922d9, 0xb151dd3cc06fd944, 0xa4062bde193b426c, 0xbdda1da61bdd456c, 0x434ac30890cc9364, 0xa3cc972949213493]),
+  fnv1a_1024_test_193: U1024, &repeat(b"EFCDAB8967452301", 10), U1024::from_words([0x38a1311abe302147, 0x7fcc9689bccd8f65, 0x17a4d63aee785179, 0xc9bb61b1ac23932d, 0x8a04a8eb418a6c8d, 0x6231100e71946497, 0xde6f79027a2e659a, 0xa3318b94ce95a316, 0x834f98fcdc9b468a, 0x0087aa812d5cb4b4, 0x5325ef94757be8df, 0x4b3902c83823cc07, 0x99668a84c4d062cc, 0x8a2787b38049f3c8, 0xc6147eecd8233652, 0xa55f910556265db3]),
+  fnv1a_1024_test_194: U1024, &repeat(b"\xef\xcd\xab\x89\x67\x45\x23\x01", 10), U1024::from_words([0x7e00b5031b12383a, 0x7cd334bbccd6785f, 0x8ef66ed89dbe54f9, 0xb383f2535d4124fe, 0xb95399d71c05383d, 0xeb303f52a0edc4e2, 0xfbe57f3b9606516f, 0xb22fed0da9aea181, 0x6681eeb03b49c2fe, 0xbd49f0efdb9f3cb3, 0x77b1249f43a245a4, 0x606c0e64b9c31e3c, 0x1c11d1053b79ea63, 0x62b2146d236e9ef0, 0x0cf2e91e3993e57b, 0xaf4634b113ea31f3]),
+  fnv1a_1024_test_195: U1024, &repeat(b"0123456789ABCDEF", 10), U1024::from_words([0x300bb4308adde909, 0x3d8ae4994e337fa3, 0x9b87c943adac8cdc, 0xcc39ee359c7cecf4, 0xf6666467caace7d5, 0x03240acb27f6927b, 0x955f0425062c3cfc, 0xd3ceaf22e5ed82fa, 0x90dcd4b916b632f9, 0xb42c644309fa81c1, 0x1eb2833861e7e108, 0x4e79a97eb3b3990d, 0x302c084435e27161, 0x808a3b97fa47f24d, 0x2b1e0f15cd3e6ea9, 0xa12c86b88a6f14d3]),
+  fnv1a_1024_test_196: U1024, &repeat(b"\x01\x23\x45\x67\x89\xab\xcd\xef", 10), U1024::from_words([0xd958f9ffecdeaba0, 0xac1cd3e68af42cb5, 0x06166e17bc4869c1, 0x8b9516903b1a2ede, 0x1b0dbf8ae0245f7b, 0xe2edd51fc30d3881, 0x5b49c2adaa5674e4, 0xc9f1e0f5f965fe47, 0xc07bbf3602248d2e, 0xf4abe671b4c8b848, 0xce1eb0300a14fee7, 0xc4fc84d11f7acedb, 0x7dc38ae8face47d1, 0x087eba504ded005f, 0x66f5c39e42ebc29f, 0x53fbe34f03d502f3]),
+  fnv1a_1024_test_197: U1024, &repeat(b"1032547698BADCFE", 10), U1024::from_words([0x7904316581132482, 0xd03a76e694a0d871, 0xf6ef5d4ad4af2bd5, 0x552dd515ed4c8334, 0x4788621cfa390e3b, 0x61cc6e9bc71d7658, 0x4e5ef74ace182a48, 0x4511799265302970, 0x421a4553e74538df, 0x26abd2fdbf74d942, 0x029f4f462cf6766e, 0x1da77946eb171fdf, 0x146e8282f5973a64, 0x3fff6a597192b1b3, 0x5a82abe1ffc292f5, 0x09d5c8f3944c1893]),
+  fnv1a_1024_test_198: U1024, &repeat(b"\x10\x32\x54\x76\x98\xba\xdc\xfe", 10), U1024::from_words([0xc7c555b987ebe5b9, 0x6d7d9e935f6b6dfc, 0x65d3b212237bf9db, 0x408a8c07e345817b, 0x48b4673ec4e78d99, 0x9989de77956d7041, 0x4df4dda714d83591, 0xa7315e4a1202993e, 0x95c8b44f3db0e09b, 0xf4a1e940b6d0d606, 0xa9aaeb45488abc06, 0x87fe1bb5b651bd1c, 0x9230b54b58a66adc, 0x3a48e407965a801e, 0x13ee78ede5b56320, 0x9d1f3c1d9166dc93]),
+  fnv1a_1024_test_199: U1024, &repeat(b"\x00", 500), U1024::from_words([0x09b7ee4023569c0e, 0x8aee21336dd6e667, 0x05a02e53303ff68a, 0x850e159a2318046b, 0xd506a0b2eed1a0bf, 0x72c496c66b27088d, 0x8024aa13ff025604, 0x93b3a40af1be87f5, 0x41131ff1e8e001d0, 0xc72e803b8f0c3fd8, 0x81a49227e0babae4, 0x3bf4c4a421e3205d, 0x8d2a6a3d8812a5df, 0x2e1e852b4a6a3830, 0x7340980ab656984a, 0x31a272a7f84487a3]),
+  fnv1a_1024_test_200: U1024, &repeat(b"\x07", 500), U1024::from_words([0x6adee0ef83e30889, 0x91ec9a990e0a9a24, 0x56a050f47d35f857, 0x01942846ff79e0cf, 0xae35d7ad432c27d9, 0x88edbf91a34de9e1, 0x1ba93ea37cbde0e6, 0x73b5a940d7667ce8, 0x2d405f622effe68f, 0xba4fe7ea3b27770b, 0xefdaa42422c23160, 0x39525fbaa891f928, 0x93cbb204d2f22508, 0x53a8ef60a89e6ba1, 0x2d6b224873d18032, 0x32e6906103d2cb7b]),
+  fnv1a_1024_test_201: U1024, &repeat(b"~", 500), U1024::from_words([0xfbc52f2691041b63, 0x4c23ef769d1b5c0f, 0xbf9af22b4344b748, 0x9f3cdcc044b2ce64, 0xd5ede34d5bba9908, 0x1da6e32d4851d5da, 0xfc4779e5af681e50, 0xf91713ce6a773440, 0x77d2249dbeeb530f, 0xea5f26db8a013219, 0x0ad6a631d43afb4b, 0xb5f5cba6f6a15782, 0xa6eedbaef74468d2, 0xeb663aab1776e97c, 0xaafe33753980d369, 0x6e016199107d59a3]),
+  fnv1a_1024_test_202: U1024, &repeat(b"\x7f", 500), U1024::from_words([0x1ba475e870c32998, 0x0f936d052d1b7d95, 0x154ab1ac5fb0d26c, 0x1f7cb3635af8af13, 0x10724032142d9bd3, 0xe6ac4dc986ffc446, 0x13f04e7c7532cb7b, 0x8d1258c2c35a5ea5, 0x9ea56e4f0c5cb50e, 0xe63bc40d6a84e84d, 0xef0794112ee57cda, 0x969f90e14e6520ed, 0x1b0702f5687d5634, 0x30bd9f616fe73f3b, 0x72c7828f7bbd7d50, 0xbaa40696e695f5db]),
 }
-
-
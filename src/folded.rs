@@ -0,0 +1,156 @@
+//! FNV hashes narrower than 32 bits.
+use core::marker::PhantomData;
+use {Fnv1a, FnvHasher};
+
+/// An FNV hash xor-folded from a 32-bit hash down to `T`.
+///
+/// FNV is not defined for widths below 32 bits, the FNV specification instead
+/// recommends xor-folding the 32-bit hash. `FnvHasher` is implemented for
+/// `Folded<H, u16>` and `Folded<H, u8>` where `H` is any 32-bit FNV hasher.
+///
+/// ```
+/// use lz_fnv::{Fnv1, Folded, FnvHasher};
+///
+/// let mut fnv_hasher = Folded::<Fnv1<u32>, u16>::new();
+/// fnv_hasher.write(b"foobar");
+///
+/// assert_eq!(fnv_hasher.finish(), 0x8392);
+/// ```
+#[derive(Debug, Default)]
+pub struct Folded<H, T> {
+    hasher: H,
+    hash: PhantomData<T>,
+}
+
+/// The FNV-1a hash xor-folded to 16 bits.
+pub type Fnv1a16 = Folded<Fnv1a<u32>, u16>;
+
+/// The FNV-1a hash xor-folded to 8 bits.
+pub type Fnv1a8 = Folded<Fnv1a<u32>, u8>;
+
+impl<H: Default, T> Folded<H, T> {
+    /// Creates a new `Folded<H, T>`.
+    ///
+    /// ```
+    /// use lz_fnv::Fnv1a16;
+    ///
+    /// let fnv_hasher = Fnv1a16::new();
+    /// ```
+    pub fn new() -> Self {
+        Self::from_hasher(H::default())
+    }
+}
+
+impl<H, T> Folded<H, T> {
+    /// Creates a new `Folded<H, T>` which folds the hash of `hasher`.
+    ///
+    /// ```
+    /// use lz_fnv::{Fnv1a, Fnv1a16};
+    ///
+    /// let fnv_hasher = Fnv1a16::from_hasher(Fnv1a::with_key(872u32));
+    /// ```
+    pub fn from_hasher(hasher: H) -> Self {
+        Self {
+            hasher,
+            hash: PhantomData,
+        }
+    }
+}
+
+macro_rules! folded_impl {
+    ($type: ty, $bits: expr) => {
+        impl<H: FnvHasher<Hash = u32>> FnvHasher for Folded<H, $type> {
+            type Hash = $type;
+
+            fn finish(&self) -> Self::Hash {
+                let hash = self.hasher.finish();
+
+                ((hash >> $bits) ^ hash) as $type
+            }
+
+            fn write(&mut self, bytes: &[u8]) {
+                self.hasher.write(bytes);
+            }
+        }
+    };
+}
+
+folded_impl!(u16, 16);
+folded_impl!(u8, 8);
+
+#[cfg(test)]
+mod tests {
+    use super::{Fnv1a16, Fnv1a8, Folded};
+    use {Fnv1, Fnv1a, FnvHasher};
+
+    macro_rules! folded_tests {
+        ($($name: ident: $hasher: ty, $input: expr, $expected_hash: expr,)*) => {
+            $(
+                #[test]
+                fn $name() {
+                    let mut folded = <$hasher>::new();
+
+                    folded.write($input);
+
+                    assert_eq!(folded.finish(), $expected_hash);
+                }
+            )*
+        };
+    }
+
+    // Folded from the 32-bit test cases in `fnv_test_cases.rs`.
+    folded_tests! {
+        fnv1_16_test_0: Folded<Fnv1<u32>, u16>, b"", 0x1cd9,
+        fnv1_16_test_1: Folded<Fnv1<u32>, u16>, b"a", 0x5872,
+        fnv1_16_test_2: Folded<Fnv1<u32>, u16>, b"b", 0x5871,
+        fnv1_16_test_3: Folded<Fnv1<u32>, u16>, b"c", 0x5870,
+        fnv1_16_test_7: Folded<Fnv1<u32>, u16>, b"fo", 0x4e63,
+        fnv1_16_test_8: Folded<Fnv1<u32>, u16>, b"foo", 0x1e9c,
+        fnv1_16_test_9: Folded<Fnv1<u32>, u16>, b"foob", 0xa33a,
+        fnv1_16_test_10: Folded<Fnv1<u32>, u16>, b"fooba", 0xf278,
+        fnv1_16_test_11: Folded<Fnv1<u32>, u16>, b"foobar", 0x8392,
+        fnv1_8_test_0: Folded<Fnv1<u32>, u8>, b"", 0x58,
+        fnv1_8_test_1: Folded<Fnv1<u32>, u8>, b"a", 0x23,
+        fnv1_8_test_2: Folded<Fnv1<u32>, u8>, b"b", 0x20,
+        fnv1_8_test_3: Folded<Fnv1<u32>, u8>, b"c", 0x21,
+        fnv1_8_test_7: Folded<Fnv1<u32>, u8>, b"fo", 0x31,
+        fnv1_8_test_8: Folded<Fnv1<u32>, u8>, b"foo", 0x4d,
+        fnv1_8_test_9: Folded<Fnv1<u32>, u8>, b"foob", 0x9c,
+        fnv1_8_test_10: Folded<Fnv1<u32>, u8>, b"fooba", 0xbf,
+        fnv1_8_test_11: Folded<Fnv1<u32>, u8>, b"foobar", 0xd0,
+        fnv1a_16_test_0: Fnv1a16, b"", 0x1cd9,
+        fnv1a_16_test_1: Fnv1a16, b"a", 0xcd20,
+        fnv1a_16_test_2: Fnv1a16, b"b", 0xcae9,
+        fnv1a_16_test_3: Fnv1a16, b"c", 0xca5e,
+        fnv1a_16_test_7: Fnv1a16, b"fo", 0x8a60,
+        fnv1a_16_test_8: Fnv1a16, b"foo", 0xd724,
+        fnv1a_16_test_9: Fnv1a16, b"foob", 0x49bf,
+        fnv1a_16_test_10: Fnv1a16, b"fooba", 0x9820,
+        fnv1a_16_test_11: Fnv1a16, b"foobar", 0x46f4,
+        fnv1a_8_test_0: Fnv1a8, b"", 0x58,
+        fnv1a_8_test_1: Fnv1a8, b"a", 0x05,
+        fnv1a_8_test_2: Fnv1a8, b"b", 0xc8,
+        fnv1a_8_test_3: Fnv1a8, b"c", 0x7e,
+        fnv1a_8_test_7: Fnv1a8, b"fo", 0xaa,
+        fnv1a_8_test_8: Fnv1a8, b"foo", 0xa9,
+        fnv1a_8_test_9: Fnv1a8, b"foob", 0x99,
+        fnv1a_8_test_10: Fnv1a8, b"fooba", 0x2b,
+        fnv1a_8_test_11: Fnv1a8, b"foobar", 0x91,
+    }
+
+    #[test]
+    fn folded_matches_finish_folded() {
+        for byte in 0..=255u8 {
+            let mut fnv1a = Fnv1a::<u32>::new();
+            let mut fnv1a16 = Fnv1a16::new();
+            let mut fnv1a8 = Fnv1a8::new();
+
+            fnv1a.write(&[byte]);
+            fnv1a16.write(&[byte]);
+            fnv1a8.write(&[byte]);
+
+            assert_eq!(u32::from(fnv1a16.finish()), fnv1a.finish_folded(16));
+            assert_eq!(u32::from(fnv1a8.finish()), fnv1a.finish_folded(8));
+        }
+    }
+}
@@ -1,14 +1,194 @@
 //! The lz_fnv crate implements Fowler-Noll-Vo hashing.
 //!
 //! FNV-0, FNV-1 and FNV-1a hash implementations are supported for various
-//! width integers.
+//! width integers, from 32-bit up to 1024-bit. The widths above 128-bit use the
+//! `U256`, `U512` and `U1024` types provided by this crate.
 //!
-//! The FNV implementations for u64 also implement `Hasher`.
+//! `offset_basis` derives the offset basis of a width from the FNV signature,
+//! `offset_basis_from` derives one from a custom signature. The `prime` module
+//! validates candidate FNV primes and searches for the FNV prime of a width.
+//! The `analysis` module measures the avalanche, bit independence and
+//! distribution of any `FnvHasher`. The `search` module finds colliding inputs
+//! and preimages of the 32-bit hashes, for testing how code handles
+//! collisions, and the `audit` module finds the collisions within a set of
+//! keys.
+//!
+//! The FNV implementations for u32, u64 and u128 also implement `Hasher`. As
+//! `Hasher` produces a u64 hash the u32 hash is zero-extended and the u128 hash
+//! is xor-folded, `hash_value` can be used to get the full width hash of any
+//! `Hash` value.
+//!
+//! The u32, u64 and u128 hashers can also reduce their hash as described by
+//! the FNV specification, `finish_folded` xor-folds the hash to fewer bits and
+//! `finish_in_range` maps the hash to a range without bias.
+//!
+//! ```
+//! use lz_fnv::{Fnv1a, FnvHasher};
+//!
+//! let mut fnv_hasher = Fnv1a::<u32>::new();
+//! fnv_hasher.write(b"foobar");
+//!
+//! assert_eq!(fnv_hasher.finish_folded(24), 0x9c_f9d7);
+//! assert!(fnv_hasher.finish_in_range(50_000) < 50_000);
+//! ```
+//!
+//! `Folded` provides 16-bit and 8-bit hashes by xor-folding a 32-bit hash, the
+//! `Fnv1a16` and `Fnv1a8` aliases fold the FNV-1a hash.
+//!
+//! `hash_many` hashes many inputs at once by interleaving them, using AVX2 for
+//! the 32-bit hashes when it is available at runtime.
+//!
+//! The FNV primes are odd, so each step of the hash can be undone. `unwrite`
+//! rolls a hasher back over the bytes most recently written to it.
+//!
+//! The `state` module saves the state of a hasher as bytes with `to_bytes`,
+//! so a hash can be resumed later with `from_bytes`, and imports and exports
+//! the states of Go's `hash/fnv` hashes. The optional `serde` feature
+//! implements `Serialize` and `Deserialize` for the hashers.
+//!
+//! The `variants` module provides word-at-a-time derivatives of FNV-1a which
+//! are faster for longer inputs but are not compatible with canonical FNV.
+//!
+//! `BuildHasher` implementations and `HashMap`/`HashSet` aliases are provided
+//! for each of the FNV hashers.
+//!
+//! One-shot `const fn` hash functions such as `fnv1a_32` are provided for every
+//! variant and width, these can be used to compute hashes at compile time.
+//!
+//! The `macros` feature, enabled by default, provides the `fnv0!`, `fnv1!` and
+//! `fnv1a!` macros which hash a string or byte string literal at compile time.
+//! The u32, u64 and u128 hashes expand to an integer literal so they can be
+//! used as `match` patterns.
+//!
+//! ```
+//! # #[cfg(feature = "macros")]
+//! # fn main() {
+//! fn command_id(name: &str) -> Option<u32> {
+//!     match lz_fnv::fnv1a_64(name.as_bytes()) {
+//!         lz_fnv::fnv1a!(u64, "start") => Some(1),
+//!         lz_fnv::fnv1a!(u64, "stop") => Some(2),
+//!         _ => None,
+//!     }
+//! }
+//!
+//! assert_eq!(command_id("stop"), Some(2));
+//! # }
+//! #
+//! # #[cfg(not(feature = "macros"))]
+//! # fn main() {}
+//! ```
+//!
+//! Only literals can be hashed by the macros.
+//!
+//! ```compile_fail
+//! let name = "start";
+//! let hash = lz_fnv::fnv1a!(u64, name);
+//! ```
+//!
+//! The `fnv_match!` macro matches a string against string literal patterns by
+//! comparing the FNV-1a hashes, then the strings themselves to guard against
+//! collisions.
+//!
+//! ```
+//! # #[cfg(feature = "macros")]
+//! # fn main() {
+//! fn command_id(name: &str) -> Option<u32> {
+//!     lz_fnv::fnv_match!(match name {
+//!         "start" | "begin" => Some(1),
+//!         "stop" => Some(2),
+//!         _ => None,
+//!     })
+//! }
+//!
+//! assert_eq!(command_id("begin"), Some(1));
+//! assert_eq!(command_id("end"), None);
+//! # }
+//! #
+//! # #[cfg(not(feature = "macros"))]
+//! # fn main() {}
+//! ```
+//!
+//! Patterns whose hashes collide are rejected at compile time.
+//!
+//! ```compile_fail
+//! fn word_id(word: &str) -> Option<u32> {
+//!     lz_fnv::fnv_match!(u32; match word {
+//!         "costarring" => Some(1),
+//!         "liquid" => Some(2),
+//!         _ => None,
+//!     })
+//! }
+//! ```
+//!
+//! `StaticMap` is a map which uses a minimal perfect hash function built from
+//! FNV-1a, the `codegen` module generates the source of a `StaticMap` from a
+//! build script.
+//!
+//! The crate is `no_std` compatible, the `std` feature is enabled by default
+//! and provides the integrations which require the standard library such as
+//! the `HashMap`/`HashSet` aliases.
 #![deny(missing_docs)]
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+#[cfg(any(feature = "std", test))]
+extern crate core;
+#[cfg(feature = "macros")]
+extern crate lz_fnv_macros;
+#[cfg(feature = "serde")]
+extern crate serde;
+#[cfg(all(test, feature = "serde"))]
+extern crate serde_test;
+
+#[cfg(feature = "std")]
+pub mod analysis;
+#[cfg(feature = "std")]
+pub mod audit;
+#[cfg(feature = "std")]
+mod batch;
+mod build_hasher;
+#[cfg(feature = "std")]
+pub mod codegen;
+#[cfg(feature = "std")]
+mod collections;
+mod const_hash;
+mod folded;
+mod offset_basis;
+#[cfg(feature = "std")]
+pub mod prime;
+#[cfg(feature = "std")]
+pub mod search;
+pub mod state;
+mod static_map;
+pub mod variants;
+mod wide;
+
+#[cfg(feature = "std")]
+pub use batch::{hash_many, HashMany};
+pub use build_hasher::{
+    Fnv0BuildHasher, Fnv0KeyedBuildHasher, Fnv1BuildHasher, Fnv1KeyedBuildHasher, Fnv1aBuildHasher,
+    Fnv1aKeyedBuildHasher,
+};
+#[cfg(feature = "std")]
+pub use collections::{
+    hash_map_with_capacity, hash_set_with_capacity, Fnv0HashMap, Fnv0HashSet, Fnv1HashMap,
+    Fnv1HashSet, Fnv1aHashMap, Fnv1aHashSet, FnvHashMap, FnvHashSet,
+};
+pub use const_hash::{
+    fnv0_1024, fnv0_128, fnv0_256, fnv0_32, fnv0_512, fnv0_64, fnv1_1024, fnv1_128, fnv1_256,
+    fnv1_32, fnv1_512, fnv1_64, fnv1a_1024, fnv1a_128, fnv1a_256, fnv1a_32, fnv1a_512, fnv1a_64,
+};
+pub use folded::{Fnv1a16, Fnv1a8, Folded};
+#[cfg(feature = "macros")]
+pub use lz_fnv_macros::{fnv0, fnv1, fnv1a, fnv_match};
+pub use offset_basis::{offset_basis, offset_basis_from, OFFSET_BASIS_SIGNATURE};
+pub use static_map::StaticMap;
+pub use wide::{U1024, U256, U512};
+
+use core::hash::{Hash, Hasher};
 
 /// A trait for all Fowler-Noll-Vo hash implementations.
 ///
-/// This matches the `std::hash::Hasher` definition but for multiple hash
+/// This matches the `core::hash::Hasher` definition but for multiple hash
 /// types.
 pub trait FnvHasher {
     /// The type of the hash.
@@ -21,6 +201,103 @@ pub trait FnvHasher {
     fn write(&mut self, bytes: &[u8]);
 }
 
+/// The parameters of a Fowler-Noll-Vo hash for a single width.
+///
+/// `Fnv0<T>`, `Fnv1<T>` and `Fnv1a<T>` are implemented for any `T` which
+/// implements this trait. It is implemented for `u32`, `u64`, `u128`, `U256`,
+/// `U512` and `U1024` with the official FNV parameters, other widths or
+/// parameter sets can be supported by implementing it for a new type.
+///
+/// ```
+/// use lz_fnv::{Fnv1a, FnvHasher, FnvParameters};
+///
+/// #[derive(Clone, Copy, Debug, PartialEq)]
+/// struct Custom32(u32);
+///
+/// impl FnvParameters for Custom32 {
+///     const OFFSET_BASIS: Self = Custom32(0x811c_9dc5);
+///     const PRIME: Self = Custom32(0x100_01b3);
+///
+///     fn wrapping_mul(self, rhs: Self) -> Self {
+///         Custom32(self.0.wrapping_mul(rhs.0))
+///     }
+///
+///     fn xor_byte(self, byte: u8) -> Self {
+///         Custom32(self.0 ^ u32::from(byte))
+///     }
+/// }
+///
+/// let mut fnv_hasher = Fnv1a::<Custom32>::new();
+/// fnv_hasher.write(b"foobar");
+///
+/// assert_eq!(fnv_hasher.finish(), Custom32(0xd300_f388));
+/// ```
+pub trait FnvParameters: Copy {
+    /// The offset basis, this is the initial hash of FNV-1 and FNV-1a.
+    const OFFSET_BASIS: Self;
+
+    /// The FNV prime which the hash is multiplied by for each byte.
+    const PRIME: Self;
+
+    /// Multiplies two values, wrapping around at the boundary of the type.
+    fn wrapping_mul(self, rhs: Self) -> Self;
+
+    /// Multiplies a value by `PRIME`, wrapping around at the boundary of the
+    /// type.
+    ///
+    /// The FNV primes are sparse, implementations can override this to
+    /// multiply by the prime with shifts and adds rather than a full multiply.
+    fn wrapping_mul_prime(self) -> Self {
+        self.wrapping_mul(Self::PRIME)
+    }
+
+    /// Mixes a byte into the hash by xoring it with the lowest byte.
+    fn xor_byte(self, byte: u8) -> Self;
+}
+
+/// The inverse of the FNV prime for a single width.
+///
+/// `unwrite` is implemented on `Fnv0<T>`, `Fnv1<T>` and `Fnv1a<T>` for any `T`
+/// which implements this trait. It is implemented for `u32`, `u64`, `u128`,
+/// `U256`, `U512` and `U1024`.
+///
+/// ```
+/// use lz_fnv::{Fnv1a, FnvHasher, FnvInverse, FnvParameters};
+///
+/// #[derive(Clone, Copy, Debug, PartialEq)]
+/// struct Custom32(u32);
+///
+/// impl FnvParameters for Custom32 {
+///     const OFFSET_BASIS: Self = Custom32(0x811c_9dc5);
+///     const PRIME: Self = Custom32(0x100_01b3);
+///
+///     fn wrapping_mul(self, rhs: Self) -> Self {
+///         Custom32(self.0.wrapping_mul(rhs.0))
+///     }
+///
+///     fn xor_byte(self, byte: u8) -> Self {
+///         Custom32(self.0 ^ u32::from(byte))
+///     }
+/// }
+///
+/// impl FnvInverse for Custom32 {
+///     const PRIME_INVERSE: Self = Custom32(0x96f6_957b);
+/// }
+///
+/// let mut fnv_hasher = Fnv1a::<Custom32>::new();
+/// fnv_hasher.write(b"foobar");
+/// fnv_hasher.unwrite(b"bar");
+///
+/// assert_eq!(fnv_hasher.finish(), Custom32(0xf57b_9c97));
+/// ```
+pub trait FnvInverse: FnvParameters {
+    /// The multiplicative inverse of `PRIME` modulo 2^n, where n is the width
+    /// of the hash, `PRIME.wrapping_mul(PRIME_INVERSE)` is one.
+    ///
+    /// This is used to roll back the hash over bytes which have been written.
+    const PRIME_INVERSE: Self;
+}
+
 /// The FNV-0 hash.
 ///
 /// This is deprecated except for computing the FNV offset basis for FNV-1 and
@@ -55,6 +332,33 @@ impl<T: Default> Fnv0<T> {
     }
 }
 
+impl<T: FnvInverse> Fnv0<T> {
+    /// Rolls the hash back over `bytes`, as if they had never been written.
+    ///
+    /// `bytes` must be the most recently written bytes, this undoes each
+    /// step of the hash by multiplying by `T::PRIME_INVERSE`.
+    ///
+    /// ```
+    /// use lz_fnv::{Fnv0, FnvHasher};
+    ///
+    /// let mut fnv_hasher = Fnv0::<u64>::new();
+    /// fnv_hasher.write(b"foobar");
+    /// fnv_hasher.unwrite(b"bar");
+    ///
+    /// assert_eq!(fnv_hasher.finish(), 0x015a_8f00_0126_5ec8);
+    /// ```
+    pub fn unwrite(&mut self, bytes: &[u8]) {
+        let mut hash = self.hash;
+
+        for byte in bytes.iter().rev() {
+            hash = hash.xor_byte(*byte);
+            hash = hash.wrapping_mul(T::PRIME_INVERSE);
+        }
+
+        self.hash = hash;
+    }
+}
+
 impl<T> Fnv0<T> {
     /// Creates a new `Fnv0<T>` with the specified key.
     ///
@@ -68,6 +372,46 @@ impl<T> Fnv0<T> {
     }
 }
 
+impl<T: FnvParameters> Fnv1<T> {
+    /// Creates a new `Fnv1<T>`.
+    ///
+    /// ```
+    /// use lz_fnv::Fnv1;
+    ///
+    /// let fnv_hasher = Fnv1::<u32>::new();
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<T: FnvInverse> Fnv1<T> {
+    /// Rolls the hash back over `bytes`, as if they had never been written.
+    ///
+    /// `bytes` must be the most recently written bytes, this undoes each
+    /// step of the hash by multiplying by `T::PRIME_INVERSE`.
+    ///
+    /// ```
+    /// use lz_fnv::{Fnv1, FnvHasher};
+    ///
+    /// let mut fnv_hasher = Fnv1::<u64>::new();
+    /// fnv_hasher.write(b"foobar");
+    /// fnv_hasher.unwrite(b"bar");
+    ///
+    /// assert_eq!(fnv_hasher.finish(), 0xd8cb_c718_6ba1_3533);
+    /// ```
+    pub fn unwrite(&mut self, bytes: &[u8]) {
+        let mut hash = self.hash;
+
+        for byte in bytes.iter().rev() {
+            hash = hash.xor_byte(*byte);
+            hash = hash.wrapping_mul(T::PRIME_INVERSE);
+        }
+
+        self.hash = hash;
+    }
+}
+
 impl<T> Fnv1<T> {
     /// Creates a new `Fnv1<T>` with the specified key.
     ///
@@ -81,6 +425,46 @@ impl<T> Fnv1<T> {
     }
 }
 
+impl<T: FnvParameters> Fnv1a<T> {
+    /// Creates a new `Fnv1a<T>`.
+    ///
+    /// ```
+    /// use lz_fnv::Fnv1a;
+    ///
+    /// let fnv_hasher = Fnv1a::<u32>::new();
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<T: FnvInverse> Fnv1a<T> {
+    /// Rolls the hash back over `bytes`, as if they had never been written.
+    ///
+    /// `bytes` must be the most recently written bytes, this undoes each
+    /// step of the hash by multiplying by `T::PRIME_INVERSE`.
+    ///
+    /// ```
+    /// use lz_fnv::{Fnv1a, FnvHasher};
+    ///
+    /// let mut fnv_hasher = Fnv1a::<u64>::new();
+    /// fnv_hasher.write(b"foobar");
+    /// fnv_hasher.unwrite(b"bar");
+    ///
+    /// assert_eq!(fnv_hasher.finish(), 0xdcb2_7518_fed9_d577);
+    /// ```
+    pub fn unwrite(&mut self, bytes: &[u8]) {
+        let mut hash = self.hash;
+
+        for byte in bytes.iter().rev() {
+            hash = hash.wrapping_mul(T::PRIME_INVERSE);
+            hash = hash.xor_byte(*byte);
+        }
+
+        self.hash = hash;
+    }
+}
+
 impl<T> Fnv1a<T> {
     /// Creates a new `Fnv1a<T>` with the specified key.
     ///
@@ -94,157 +478,367 @@ impl<T> Fnv1a<T> {
     }
 }
 
-macro_rules! fnv0_impl {
-    ($type: ty, $prime: expr, $from_byte: ident) => {
-        impl FnvHasher for Fnv0<$type> {
-            type Hash = $type;
-
-            fn finish(&self) -> Self::Hash {
-                self.hash
-            }
+impl<T: FnvParameters> FnvHasher for Fnv0<T> {
+    type Hash = T;
 
-            fn write(&mut self, bytes: &[u8]) {
-                let mut hash = self.hash;
+    fn finish(&self) -> Self::Hash {
+        self.hash
+    }
 
-                for byte in bytes {
-                    hash = hash.wrapping_mul($prime);
-                    hash ^= ($from_byte)(*byte);
-                }
+    fn write(&mut self, bytes: &[u8]) {
+        let mut hash = self.hash;
 
-                self.hash = hash;
-            }
+        for byte in bytes {
+            hash = hash.wrapping_mul_prime();
+            hash = hash.xor_byte(*byte);
         }
-    };
+
+        self.hash = hash;
+    }
 }
 
-macro_rules! fnv1_impl {
-    ($type: ty, $offset: expr, $prime: expr, $from_byte: ident) => {
-        impl Default for Fnv1<$type> {
-            fn default() -> Self {
-                Self { hash: $offset }
-            }
+impl<T: FnvParameters> Default for Fnv1<T> {
+    fn default() -> Self {
+        Self {
+            hash: T::OFFSET_BASIS,
         }
+    }
+}
 
-        impl Fnv1<$type> {
-            /// Creates a new `Fnv1<T>`.
-            pub fn new() -> Self {
-                Self::default()
-            }
+impl<T: FnvParameters> FnvHasher for Fnv1<T> {
+    type Hash = T;
+
+    fn finish(&self) -> Self::Hash {
+        self.hash
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        let mut hash = self.hash;
+
+        for byte in bytes {
+            hash = hash.wrapping_mul_prime();
+            hash = hash.xor_byte(*byte);
         }
 
-        impl FnvHasher for Fnv1<$type> {
-            type Hash = $type;
+        self.hash = hash;
+    }
+}
 
-            fn finish(&self) -> Self::Hash {
-                self.hash
-            }
+impl<T: FnvParameters> Default for Fnv1a<T> {
+    fn default() -> Self {
+        Self {
+            hash: T::OFFSET_BASIS,
+        }
+    }
+}
 
-            fn write(&mut self, bytes: &[u8]) {
-                let mut hash = self.hash;
+impl<T: FnvParameters> FnvHasher for Fnv1a<T> {
+    type Hash = T;
 
-                for byte in bytes {
-                    hash = hash.wrapping_mul($prime);
-                    hash ^= ($from_byte)(*byte);
-                }
+    fn finish(&self) -> Self::Hash {
+        self.hash
+    }
 
-                self.hash = hash;
-            }
+    fn write(&mut self, bytes: &[u8]) {
+        let mut hash = self.hash;
+
+        for byte in bytes {
+            hash = hash.xor_byte(*byte);
+            hash = hash.wrapping_mul_prime();
         }
-    };
+
+        self.hash = hash;
+    }
+}
+
+/// Hashes a value with the FNV hasher `H`, producing the full width hash.
+///
+/// Unlike `Hasher::finish` the hash is not reduced to a u64.
+///
+/// ```
+/// use lz_fnv::{hash_value, Fnv1a};
+///
+/// let hash: u128 = hash_value::<Fnv1a<u128>, _>(&("foo", 42));
+/// ```
+pub fn hash_value<H, T>(value: &T) -> H::Hash
+where
+    H: FnvHasher + Hasher + Default,
+    T: Hash + ?Sized,
+{
+    let mut hasher = H::default();
+
+    value.hash(&mut hasher);
+
+    FnvHasher::finish(&hasher)
 }
 
-macro_rules! fnv1a_impl {
-    ($type: ty, $offset: expr, $prime: expr, $from_byte: ident) => {
-        impl Default for Fnv1a<$type> {
-            fn default() -> Self {
-                Self { hash: $offset }
+macro_rules! fnv_hasher_impl {
+    ($type: ty, $to_u64: ident) => {
+        impl Hasher for $type {
+            fn finish(&self) -> u64 {
+                ($to_u64)(::FnvHasher::finish(self))
             }
-        }
 
-        impl Fnv1a<$type> {
-            /// Creates a new `Fnv1a<T>`.
-            pub fn new() -> Self {
-                Self::default()
+            fn write(&mut self, bytes: &[u8]) {
+                ::FnvHasher::write(self, bytes);
             }
         }
+    };
+}
+
+macro_rules! fnv_reduce_impl {
+    ($type: ty, $hash: ty) => {
+        impl $type {
+            /// Completes a round of hashing, xor-folding the hash down to the
+            /// lowest `bits` bits.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `bits` is zero or greater than the width of the hash.
+            pub fn finish_folded(&self, bits: u32) -> $hash {
+                assert!(
+                    bits > 0 && bits <= <$hash>::BITS,
+                    "cannot fold a {}-bit hash to {} bits",
+                    <$hash>::BITS,
+                    bits
+                );
 
-        impl FnvHasher for Fnv1a<$type> {
-            type Hash = $type;
+                let hash = ::FnvHasher::finish(self);
 
-            fn finish(&self) -> Self::Hash {
-                self.hash
+                if bits == <$hash>::BITS {
+                    hash
+                } else {
+                    ((hash >> bits) ^ hash) & ((1 << bits) - 1)
+                }
             }
 
-            fn write(&mut self, bytes: &[u8]) {
-                let mut hash = self.hash;
+            /// Completes a round of hashing, reducing the hash to the range
+            /// `0..n` without bias.
+            ///
+            /// Hashes in the incomplete range above the largest multiple of
+            /// `n` are rehashed until they are below it, the "lazy mod"
+            /// retry method described by the FNV specification.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `n` is zero.
+            pub fn finish_in_range(&self, n: $hash) -> $hash {
+                assert!(n > 0, "cannot reduce a hash to an empty range");
 
-                for byte in bytes {
-                    hash ^= ($from_byte)(*byte);
-                    hash = hash.wrapping_mul($prime);
+                let retry_level = (<$hash>::MAX / n) * n;
+                let mut hash = ::FnvHasher::finish(self);
+
+                while hash >= retry_level {
+                    hash = hash
+                        .wrapping_mul_prime()
+                        .wrapping_add(<$hash as FnvParameters>::OFFSET_BASIS);
                 }
 
-                self.hash = hash;
+                hash % n
             }
         }
     };
 }
 
-macro_rules! fnv_hasher_impl {
-    ($type: ty) => {
-        impl ::std::hash::Hasher for $type {
-            fn finish(&self) -> u64 {
-                ::FnvHasher::finish(self)
+macro_rules! fnv_impl {
+    ($type: ty, $offset: expr, $prime: expr, $prime_inverse: expr, $mul_prime: path) => {
+        impl FnvParameters for $type {
+            const OFFSET_BASIS: Self = $offset;
+            const PRIME: Self = $prime;
+
+            #[inline]
+            fn wrapping_mul(self, rhs: Self) -> Self {
+                <$type>::wrapping_mul(self, rhs)
             }
 
-            fn write(&mut self, bytes: &[u8]) {
-                ::FnvHasher::write(self, bytes);
+            #[inline]
+            fn wrapping_mul_prime(self) -> Self {
+                $mul_prime(self)
             }
-        }
-    };
-}
-macro_rules! fnv_impl {
-    (u64, $offset: expr, $prime: expr, $from_byte: ident) => {
-        fnv0_impl!(u64, $prime, $from_byte);
-        fnv_hasher_impl!(Fnv0<u64>);
 
-        fnv1_impl!(u64, $offset, $prime, $from_byte);
-        fnv_hasher_impl!(Fnv1<u64>);
+            #[inline]
+            fn xor_byte(self, byte: u8) -> Self {
+                self ^ Self::from(byte)
+            }
+        }
 
-        fnv1a_impl!(u64, $offset, $prime, $from_byte);
-        fnv_hasher_impl!(Fnv1a<u64>);
-    };
-    ($type: ty, $offset: expr, $prime: expr, $from_byte: ident) => {
-        fnv0_impl!($type, $prime, $from_byte);
-        fnv1_impl!($type, $offset, $prime, $from_byte);
-        fnv1a_impl!($type, $offset, $prime, $from_byte);
+        impl FnvInverse for $type {
+            const PRIME_INVERSE: Self = $prime_inverse;
+        }
     };
 }
 
-fn u32_from_byte(byte: u8) -> u32 {
-    byte.into()
+fn u32_to_u64(hash: u32) -> u64 {
+    hash.into()
 }
 
-fn u64_from_byte(byte: u8) -> u64 {
-    byte.into()
+fn u64_to_u64(hash: u64) -> u64 {
+    hash
 }
 
-fn u128_from_byte(byte: u8) -> u128 {
-    byte.into()
+fn u128_to_u64(hash: u128) -> u64 {
+    ((hash >> 64) ^ hash) as u64
 }
 
-fnv_impl!(u32, 0x811c_9dc5, 0x100_0193, u32_from_byte);
-fnv_impl!(u64, 0xcbf2_9ce4_8422_2325, 0x100_0000_01B3, u64_from_byte);
+fnv_hasher_impl!(Fnv0<u32>, u32_to_u64);
+fnv_hasher_impl!(Fnv1<u32>, u32_to_u64);
+fnv_hasher_impl!(Fnv1a<u32>, u32_to_u64);
+fnv_hasher_impl!(Fnv0<u64>, u64_to_u64);
+fnv_hasher_impl!(Fnv1<u64>, u64_to_u64);
+fnv_hasher_impl!(Fnv1a<u64>, u64_to_u64);
+fnv_hasher_impl!(Fnv0<u128>, u128_to_u64);
+fnv_hasher_impl!(Fnv1<u128>, u128_to_u64);
+fnv_hasher_impl!(Fnv1a<u128>, u128_to_u64);
+
+fnv_reduce_impl!(Fnv0<u32>, u32);
+fnv_reduce_impl!(Fnv1<u32>, u32);
+fnv_reduce_impl!(Fnv1a<u32>, u32);
+fnv_reduce_impl!(Fnv0<u64>, u64);
+fnv_reduce_impl!(Fnv1<u64>, u64);
+fnv_reduce_impl!(Fnv1a<u64>, u64);
+fnv_reduce_impl!(Fnv0<u128>, u128);
+fnv_reduce_impl!(Fnv1<u128>, u128);
+fnv_reduce_impl!(Fnv1a<u128>, u128);
+
+fnv_impl!(
+    u32,
+    0x811c_9dc5,
+    0x100_0193,
+    0x359C_449B,
+    const_hash::u32_mul_prime
+);
+fnv_impl!(
+    u64,
+    0xcbf2_9ce4_8422_2325,
+    0x100_0000_01B3,
+    0xCE96_5057_AFF6_957B,
+    const_hash::u64_mul_prime
+);
 fnv_impl!(
     u128,
     0x6C62_272E_07BB_0142_62B8_2175_6295_C58D,
     0x0000_0000_0100_0000_0000_0000_0000_013B,
-    u128_from_byte
+    0xB104_1AD2_562F_F2FF_2FF2_FF2F_F2FF_2FF3,
+    const_hash::u128_mul_prime
+);
+fnv_impl!(
+    U256,
+    U256::from_words([
+        0xDD26_8DBC_AAC5_5036,
+        0x2D98_C384_C4E5_76CC,
+        0xC8B1_5368_47B6_BBB3,
+        0x1023_B4C8_CAEE_0535,
+    ]),
+    U256::from_words([
+        0x0000_0000_0000_0000,
+        0x0000_0100_0000_0000,
+        0x0000_0000_0000_0000,
+        0x0000_0000_0000_0163,
+    ]),
+    U256::from_words([
+        0x2582_C273_CC7A_1DC0,
+        0x44C5_ED0A_1884_AFF4,
+        0x7643_C931_C1FB_AC59,
+        0x6B72_A8BE_60A1_884B,
+    ]),
+    const_hash::u256_mul_prime
+);
+fnv_impl!(
+    U512,
+    U512::from_words([
+        0xB86D_B0B1_171F_4416,
+        0xDCA1_E50F_3099_90AC,
+        0xAC87_D059_C900_0000,
+        0x0000_0000_0000_0D21,
+        0xE948_F68A_34C1_92F6,
+        0x2EA7_9BC9_42DB_E7CE,
+        0x1820_3641_5F56_E34B,
+        0xAC98_2AAC_4AFE_9FD9,
+    ]),
+    U512::from_words([
+        0x0000_0000_0000_0000,
+        0x0000_0000_0000_0000,
+        0x0000_0000_0100_0000,
+        0x0000_0000_0000_0000,
+        0x0000_0000_0000_0000,
+        0x0000_0000_0000_0000,
+        0x0000_0000_0000_0000,
+        0x0000_0000_0000_0157,
+    ]),
+    U512::from_words([
+        0x3F55_F622_598A_79E6,
+        0xE056_6971_5FCF_53FA,
+        0x1B84_69F8_2DF9_4865,
+        0x811E_99BF_D03B_B55D,
+        0x4B61_C5C8_C509_B3DF,
+        0x290C_B023_D337_FA07,
+        0x76AB_A96C_38B9_18A1,
+        0x367B_E521_9604_7A67,
+    ]),
+    const_hash::u512_mul_prime
+);
+fnv_impl!(
+    U1024,
+    U1024::from_words([
+        0x0000_0000_0000_0000,
+        0x005F_7A76_758E_CC4D,
+        0x32E5_6D5A_5910_28B7,
+        0x4B29_FC42_23FD_ADA1,
+        0x6C3B_F34E_DA36_74DA,
+        0x9A21_D900_0000_0000,
+        0x0000_0000_0000_0000,
+        0x0000_0000_0000_0000,
+        0x0000_0000_0000_0000,
+        0x0000_0000_0000_0000,
+        0x0000_0000_0000_0000,
+        0x0000_0000_0004_C6D7,
+        0xEB6E_7380_2734_510A,
+        0x555F_256C_C005_AE55,
+        0x6BDE_8CC9_C6A9_3B21,
+        0xAFF4_B16C_71EE_90B3,
+    ]),
+    U1024::from_words([
+        0x0000_0000_0000_0000,
+        0x0000_0000_0000_0000,
+        0x0000_0000_0000_0000,
+        0x0000_0000_0000_0000,
+        0x0000_0000_0000_0000,
+        0x0000_0100_0000_0000,
+        0x0000_0000_0000_0000,
+        0x0000_0000_0000_0000,
+        0x0000_0000_0000_0000,
+        0x0000_0000_0000_0000,
+        0x0000_0000_0000_0000,
+        0x0000_0000_0000_0000,
+        0x0000_0000_0000_0000,
+        0x0000_0000_0000_0000,
+        0x0000_0000_0000_0000,
+        0x0000_0000_0000_018D,
+    ]),
+    U1024::from_words([
+        0xB453_3BF6_9945_0602,
+        0x1866_5AEC_6D07_1632,
+        0x1887_9ECA_5FE8_2486,
+        0xD235_CAEC_093B_7C59,
+        0x755C_A09D_5563_BF8A,
+        0xD82E_5302_944F_F5AE,
+        0xC029_44FF_5AEC_0294,
+        0x4FF5_AEC0_2944_FF5A,
+        0xEC02_944F_F5AE_C029,
+        0x44FF_5AEC_0294_4FF5,
+        0xAEC0_2944_FF5A_EC02,
+        0x944F_F5AE_C029_44FF,
+        0x5AEC_0294_4FF5_AEC0,
+        0x2944_FF5A_EC02_944F,
+        0xF5AE_C029_44FF_5AEC,
+        0x0294_4FF5_AEC0_2945,
+    ]),
+    const_hash::u1024_mul_prime
 );
 
 #[cfg(test)]
 mod tests {
-    use std::iter;
-    use {Fnv0, Fnv1, Fnv1a, FnvHasher};
+    use {hash_value, Fnv0, Fnv1, Fnv1a, FnvHasher, FnvInverse, FnvParameters, U1024, U256, U512};
 
     macro_rules! fnv0_tests {
         ($($name: ident: $size: ty, $input: expr, $expected_hash: expr,)*) => {
@@ -297,12 +891,188 @@ mod tests {
     }
 
     fn repeat(slice: &[u8], times: usize) -> Vec<u8> {
-        iter::repeat(slice).take(times).flatten().cloned().collect()
+        slice.repeat(times)
     }
 
+    // Regenerate with `cargo run --example generate_test_cases > src/fnv_test_cases.rs`.
     include!("fnv_test_cases.rs");
 
     fnv0_tests! {
         fnv0_offset_calculation_128_bit: u128, b"chongo <Landon Curt Noll> /\\../\\", 0x6C62_272E_07BB_0142_62B8_2175_6295_C58D,
+        fnv0_offset_calculation_256_bit: U256, b"chongo <Landon Curt Noll> /\\../\\", Fnv1::<U256>::new().finish(),
+        fnv0_offset_calculation_512_bit: U512, b"chongo <Landon Curt Noll> /\\../\\", Fnv1::<U512>::new().finish(),
+        fnv0_offset_calculation_1024_bit: U1024, b"chongo <Landon Curt Noll> /\\../\\", Fnv1::<U1024>::new().finish(),
+    }
+
+    #[test]
+    fn hasher_zero_extends_32_bit_hash() {
+        let mut fnv1a = Fnv1a::<u32>::new();
+
+        ::std::hash::Hasher::write(&mut fnv1a, b"foobar");
+
+        assert_eq!(::std::hash::Hasher::finish(&fnv1a), 0x0000_0000_bf9c_f968);
+    }
+
+    #[test]
+    fn hasher_xor_folds_128_bit_hash() {
+        let mut fnv1a = Fnv1a::<u128>::new();
+
+        ::std::hash::Hasher::write(&mut fnv1a, b"foobar");
+
+        let hash = FnvHasher::finish(&fnv1a);
+
+        assert_eq!(
+            ::std::hash::Hasher::finish(&fnv1a),
+            (hash >> 64) as u64 ^ hash as u64
+        );
+    }
+
+    #[test]
+    fn finish_folded_xor_folds_hash() {
+        let mut fnv1a = Fnv1a::<u32>::new();
+
+        fnv1a.write(b"foobar");
+
+        assert_eq!(fnv1a.finish_folded(16), 0x46f4);
+        assert_eq!(fnv1a.finish_folded(24), 0x9c_f9d7);
+        assert_eq!(fnv1a.finish_folded(32), 0xbf9c_f968);
+    }
+
+    #[test]
+    fn finish_folded_xor_folds_64_bit_hash() {
+        let mut fnv1a = Fnv1a::<u64>::new();
+
+        fnv1a.write(b"foobar");
+
+        assert_eq!(fnv1a.finish_folded(48), 0x4171_f739_e27c);
+    }
+
+    #[test]
+    #[should_panic]
+    fn finish_folded_panics_for_zero_bits() {
+        Fnv1a::<u32>::new().finish_folded(0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn finish_folded_panics_for_too_many_bits() {
+        Fnv1a::<u32>::new().finish_folded(33);
+    }
+
+    #[test]
+    fn finish_in_range_keeps_hash_below_retry_level() {
+        let mut fnv1a = Fnv1a::<u32>::new();
+
+        fnv1a.write(b"foobar");
+
+        assert_eq!(fnv1a.finish_in_range(0xc000_0000), 0xbf9c_f968);
+    }
+
+    #[test]
+    fn finish_in_range_retries_hash_above_retry_level() {
+        // The FNV-1a hash of "c" is 0xe60c_2c52, two retries are needed to
+        // bring it below 0xc000_0000.
+        let mut fnv1a = Fnv1a::<u32>::new();
+
+        fnv1a.write(b"c");
+
+        assert_eq!(fnv1a.finish_in_range(0xc000_0000), 0x32ea_3c86);
+    }
+
+    #[test]
+    fn finish_in_range_produces_hash_in_range() {
+        for byte in 0..=255u8 {
+            let mut fnv1 = Fnv1::<u128>::new();
+
+            fnv1.write(&[byte]);
+
+            assert!(fnv1.finish_in_range(1_000) < 1_000);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn finish_in_range_panics_for_empty_range() {
+        Fnv1a::<u64>::new().finish_in_range(0);
+    }
+
+    #[test]
+    fn hash_value_produces_full_width_hash() {
+        let mut fnv1a = Fnv1a::<u128>::new();
+
+        fnv1a.write(b"foobar\xff");
+
+        assert_eq!(hash_value::<Fnv1a<u128>, _>("foobar"), fnv1a.finish());
+    }
+
+    const UNWRITE_PREFIX: &[u8] = b"chongo <Landon Curt Noll> /\\../\\";
+    const UNWRITE_SUFFIX: &[u8] = b"foobar\x00\x7f\x80\xff";
+
+    macro_rules! unwrite_tests {
+        ($($name: ident: $hasher: ident, $size: ty,)*) => {
+            $(
+                #[test]
+                fn $name() {
+                    let mut hasher = $hasher::<$size>::with_key(<$size>::OFFSET_BASIS);
+                    hasher.write(UNWRITE_PREFIX);
+                    let prefix_hash = hasher.finish();
+
+                    hasher.write(UNWRITE_SUFFIX);
+                    hasher.unwrite(UNWRITE_SUFFIX);
+                    assert_eq!(hasher.finish(), prefix_hash);
+
+                    for split in 0..=UNWRITE_SUFFIX.len() {
+                        hasher.write(UNWRITE_SUFFIX);
+                        hasher.unwrite(&UNWRITE_SUFFIX[split..]);
+                        hasher.unwrite(&UNWRITE_SUFFIX[..split]);
+                        assert_eq!(hasher.finish(), prefix_hash);
+                    }
+
+                    hasher.unwrite(UNWRITE_PREFIX);
+                    assert_eq!(hasher.finish(), <$size>::OFFSET_BASIS);
+                }
+            )*
+        };
+    }
+
+    unwrite_tests! {
+        fnv0_32_unwrite_round_trips: Fnv0, u32,
+        fnv1_32_unwrite_round_trips: Fnv1, u32,
+        fnv1a_32_unwrite_round_trips: Fnv1a, u32,
+        fnv0_64_unwrite_round_trips: Fnv0, u64,
+        fnv1_64_unwrite_round_trips: Fnv1, u64,
+        fnv1a_64_unwrite_round_trips: Fnv1a, u64,
+        fnv0_128_unwrite_round_trips: Fnv0, u128,
+        fnv1_128_unwrite_round_trips: Fnv1, u128,
+        fnv1a_128_unwrite_round_trips: Fnv1a, u128,
+        fnv0_256_unwrite_round_trips: Fnv0, U256,
+        fnv1_256_unwrite_round_trips: Fnv1, U256,
+        fnv1a_256_unwrite_round_trips: Fnv1a, U256,
+        fnv0_512_unwrite_round_trips: Fnv0, U512,
+        fnv1_512_unwrite_round_trips: Fnv1, U512,
+        fnv1a_512_unwrite_round_trips: Fnv1a, U512,
+        fnv0_1024_unwrite_round_trips: Fnv0, U1024,
+        fnv1_1024_unwrite_round_trips: Fnv1, U1024,
+        fnv1a_1024_unwrite_round_trips: Fnv1a, U1024,
+    }
+
+    macro_rules! prime_inverse_tests {
+        ($($name: ident: $size: ty, $one: expr,)*) => {
+            $(
+                #[test]
+                fn $name() {
+                    assert_eq!(<$size>::PRIME.wrapping_mul(<$size>::PRIME_INVERSE), $one);
+                }
+            )*
+        };
+    }
+
+    prime_inverse_tests! {
+        prime_inverse_32_is_inverse: u32, 1,
+        prime_inverse_64_is_inverse: u64, 1,
+        prime_inverse_128_is_inverse: u128, 1,
+        prime_inverse_256_is_inverse: U256, U256::from(1),
+        prime_inverse_512_is_inverse: U512, U512::from(1),
+        prime_inverse_1024_is_inverse: U1024, U1024::from(1),
     }
 }
@@ -0,0 +1,72 @@
+//! Derivation of FNV offset bases.
+use {Fnv0, FnvHasher, FnvParameters};
+
+/// The signature which the official FNV offset bases are derived from.
+pub const OFFSET_BASIS_SIGNATURE: &[u8] = b"chongo <Landon Curt Noll> /\\../\\";
+
+/// Derives the offset basis of `T` from the official FNV signature.
+///
+/// This is the FNV-0 hash of `OFFSET_BASIS_SIGNATURE`, it can be used to
+/// derive the offset basis of a custom `FnvParameters` implementation.
+///
+/// ```
+/// use lz_fnv::{offset_basis, FnvParameters};
+///
+/// assert_eq!(offset_basis::<u64>(), u64::OFFSET_BASIS);
+/// ```
+pub fn offset_basis<T: FnvParameters + Default>() -> T {
+    offset_basis_from(OFFSET_BASIS_SIGNATURE)
+}
+
+/// Derives the offset basis of `T` from a custom signature.
+///
+/// This is the FNV-0 hash of `signature`.
+///
+/// ```
+/// use lz_fnv::offset_basis_from;
+///
+/// let offset_basis = offset_basis_from::<u32>(b"my signature");
+/// ```
+pub fn offset_basis_from<T: FnvParameters + Default>(signature: &[u8]) -> T {
+    let mut fnv0 = Fnv0::<T>::new();
+
+    fnv0.write(signature);
+
+    fnv0.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{offset_basis, offset_basis_from};
+    use {FnvParameters, U1024, U256, U512};
+
+    macro_rules! offset_basis_tests {
+        ($($name: ident: $type: ty,)*) => {
+            $(
+                #[test]
+                fn $name() {
+                    assert_eq!(offset_basis::<$type>(), <$type>::OFFSET_BASIS);
+                }
+            )*
+        };
+    }
+
+    offset_basis_tests! {
+        offset_basis_32_bit: u32,
+        offset_basis_64_bit: u64,
+        offset_basis_128_bit: u128,
+        offset_basis_256_bit: U256,
+        offset_basis_512_bit: U512,
+        offset_basis_1024_bit: U1024,
+    }
+
+    #[test]
+    fn offset_basis_from_empty_signature_is_zero() {
+        assert_eq!(offset_basis_from::<u64>(b""), 0);
+    }
+
+    #[test]
+    fn offset_basis_from_uses_signature() {
+        assert_ne!(offset_basis_from::<u64>(b"my signature"), u64::OFFSET_BASIS);
+    }
+}
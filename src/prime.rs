@@ -0,0 +1,678 @@
+//! Validation of FNV primes and the search for the FNV prime of a width.
+//!
+//! The FNV prime of an `n` bit hash, where `n` is at least 32, is the smallest
+//! prime of the form `256^t + 2^8 + b` where:
+//!
+//! - `t = (5 + n) / 12`, rounded down.
+//! - `0 < b < 2^8`.
+//! - The number of one bits in `b` is 4 or 5.
+//! - `p mod (2^40 - 2^24 - 1) > 2^24 + 2^8 + 2^7`.
+//!
+//! ```
+//! use lz_fnv::prime;
+//!
+//! let fnv_prime = prime::find(64).unwrap();
+//!
+//! assert_eq!(fnv_prime.to_string(), "0x100000001b3");
+//! ```
+use std::cmp::Ordering;
+use std::error::Error;
+use std::fmt;
+
+/// The modulus of the final FNV prime criterion, `2^40 - 2^24 - 1`.
+const CRITERION_MODULUS: u64 = (1 << 40) - (1 << 24) - 1;
+
+/// The value which the prime modulo `CRITERION_MODULUS` must exceed,
+/// `2^24 + 2^8 + 2^7`.
+const CRITERION_MINIMUM: u64 = (1 << 24) + (1 << 8) + (1 << 7);
+
+/// The smallest width which FNV primes are defined for.
+const MIN_BITS: u32 = 32;
+
+/// The largest width which FNV primes are searched for or validated.
+const MAX_BITS: u32 = 1 << 16;
+
+/// The small primes used for trial division.
+const SMALL_PRIMES: &[u32] = &[
+    2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79, 83, 89, 97,
+    101, 103, 107, 109, 113, 127, 131, 137, 139, 149, 151, 157, 163, 167, 173, 179, 181, 191, 193,
+    197, 199, 211, 223, 227, 229, 233, 239, 241, 251,
+];
+
+/// The number of Miller-Rabin rounds, using the first `SMALL_PRIMES` as
+/// witnesses.
+const MILLER_RABIN_ROUNDS: usize = 40;
+
+/// A prime which meets the FNV prime criteria for a width.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FnvPrime {
+    bits: u32,
+    b: u8,
+}
+
+impl FnvPrime {
+    /// Gets the width of the hash this prime is for.
+    pub fn bits(&self) -> u32 {
+        self.bits
+    }
+
+    /// Gets `t`, the prime is `256^t + 2^8 + b`.
+    pub fn t(&self) -> u32 {
+        fnv_t(self.bits)
+    }
+
+    /// Gets `b`, the prime is `256^t + 2^8 + b`.
+    pub fn b(&self) -> u8 {
+        self.b
+    }
+
+    /// Gets the big-endian bytes of the prime, padded to the width of the
+    /// hash.
+    pub fn to_be_bytes(&self) -> Vec<u8> {
+        fnv_form(self.bits, self.b).to_be_bytes(self.bits.div_ceil(8) as usize)
+    }
+}
+
+impl fmt::Display for FnvPrime {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fnv_form(self.bits, self.b).fmt_hex(f)
+    }
+}
+
+/// The reason a candidate prime does not meet the FNV prime criteria.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PrimeError {
+    /// FNV primes are only defined for widths of at least 32 bits, and only
+    /// supported for widths of at most 65536 bits.
+    UnsupportedWidth,
+    /// The candidate is not of the form `256^t + 2^8 + b` with `0 < b < 2^8`.
+    NotFnvForm,
+    /// The number of one bits in `b` is not 4 or 5.
+    InvalidBitCount,
+    /// The candidate modulo `2^40 - 2^24 - 1` is not greater than
+    /// `2^24 + 2^8 + 2^7`.
+    ModulusTooSmall,
+    /// The candidate is not prime.
+    NotPrime,
+    /// No prime meets the FNV prime criteria for the width.
+    NotFound,
+}
+
+impl fmt::Display for PrimeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            PrimeError::UnsupportedWidth => "FNV primes are only supported for 32 to 65536 bits",
+            PrimeError::NotFnvForm => "the candidate is not of the form 256^t + 2^8 + b",
+            PrimeError::InvalidBitCount => "the number of one bits in b is not 4 or 5",
+            PrimeError::ModulusTooSmall => {
+                "the candidate mod (2^40 - 2^24 - 1) is not greater than 2^24 + 2^8 + 2^7"
+            }
+            PrimeError::NotPrime => "the candidate is not prime",
+            PrimeError::NotFound => "no prime meets the FNV prime criteria",
+        })
+    }
+}
+
+impl Error for PrimeError {}
+
+/// Validates that a candidate, given as big-endian bytes, meets the FNV prime
+/// criteria for a width.
+///
+/// The criteria do not require the candidate to be the smallest valid prime,
+/// compare with `find` for that.
+///
+/// ```
+/// use lz_fnv::prime::{self, PrimeError};
+///
+/// assert!(prime::validate(32, &0x0100_0193u32.to_be_bytes()).is_ok());
+/// assert_eq!(
+///     prime::validate(32, &0x0100_01ffu32.to_be_bytes()),
+///     Err(PrimeError::InvalidBitCount)
+/// );
+/// ```
+pub fn validate(bits: u32, candidate: &[u8]) -> Result<FnvPrime, PrimeError> {
+    check_width(bits)?;
+
+    let candidate = Natural::from_be_bytes(candidate);
+    let b = (0..=u8::MAX)
+        .find(|b| fnv_form(bits, *b) == candidate)
+        .filter(|b| *b != 0)
+        .ok_or(PrimeError::NotFnvForm)?;
+
+    check(bits, b)?;
+
+    Ok(FnvPrime { bits, b })
+}
+
+/// Searches for the FNV prime of a width, the smallest prime which meets the
+/// FNV prime criteria.
+///
+/// Returns `PrimeError::UnsupportedWidth` if the width is less than 32 bits
+/// or more than 65536 bits, or `PrimeError::NotFound` if no prime exists.
+pub fn find(bits: u32) -> Result<FnvPrime, PrimeError> {
+    check_width(bits)?;
+
+    (1..=u8::MAX)
+        .find(|b| check(bits, *b).is_ok())
+        .map(|b| FnvPrime { bits, b })
+        .ok_or(PrimeError::NotFound)
+}
+
+fn check_width(bits: u32) -> Result<(), PrimeError> {
+    if !(MIN_BITS..=MAX_BITS).contains(&bits) {
+        return Err(PrimeError::UnsupportedWidth);
+    }
+
+    Ok(())
+}
+
+fn fnv_t(bits: u32) -> u32 {
+    (5 + bits) / 12
+}
+
+/// Gets `256^t + 2^8 + b` for the width.
+fn fnv_form(bits: u32, b: u8) -> Natural {
+    let mut value = Natural::power_of_two(8 * fnv_t(bits));
+    value.0[0] += 0x100 + u32::from(b);
+    value
+}
+
+fn check(bits: u32, b: u8) -> Result<(), PrimeError> {
+    let ones = b.count_ones();
+
+    if ones != 4 && ones != 5 {
+        return Err(PrimeError::InvalidBitCount);
+    }
+
+    let candidate = fnv_form(bits, b);
+
+    if candidate.rem_u64(CRITERION_MODULUS) <= CRITERION_MINIMUM {
+        return Err(PrimeError::ModulusTooSmall);
+    }
+
+    if !is_probable_prime(&candidate) {
+        return Err(PrimeError::NotPrime);
+    }
+
+    Ok(())
+}
+
+/// Tests an odd candidate greater than the small primes for primality with
+/// trial division followed by the Miller-Rabin test.
+fn is_probable_prime(candidate: &Natural) -> bool {
+    if SMALL_PRIMES
+        .iter()
+        .any(|prime| candidate.rem_u64(u64::from(*prime)) == 0)
+    {
+        return false;
+    }
+
+    let montgomery = Montgomery::new(candidate);
+    let one = montgomery.one();
+    let minus_one = montgomery.minus_one();
+
+    let candidate_minus_one = candidate.sub(&Natural::from_u32(1));
+    let shift = candidate_minus_one.trailing_zeros();
+    let d = candidate_minus_one.shr(shift);
+
+    SMALL_PRIMES
+        .iter()
+        .cycle()
+        .take(MILLER_RABIN_ROUNDS)
+        .all(|witness| {
+            let mut x = montgomery.pow(&montgomery.to_montgomery(*witness), &d);
+
+            if x == one || x == minus_one {
+                return true;
+            }
+
+            for _ in 1..shift {
+                x = montgomery.mul(&x, &x);
+
+                if x == minus_one {
+                    return true;
+                }
+            }
+
+            false
+        })
+}
+
+/// An arbitrary precision natural number as little-endian 32-bit words.
+#[derive(Clone, Debug)]
+struct Natural(Vec<u32>);
+
+impl Natural {
+    fn from_u32(value: u32) -> Self {
+        Natural(vec![value])
+    }
+
+    fn power_of_two(exponent: u32) -> Self {
+        let mut words = vec![0; exponent as usize / 32 + 1];
+        words[exponent as usize / 32] = 1 << (exponent % 32);
+        Natural(words)
+    }
+
+    fn from_be_bytes(bytes: &[u8]) -> Self {
+        let words = bytes
+            .rchunks(4)
+            .map(|chunk| {
+                chunk
+                    .iter()
+                    .fold(0u32, |word, byte| word << 8 | u32::from(*byte))
+            })
+            .collect();
+
+        let mut natural = Natural(words);
+        natural.normalize();
+        natural
+    }
+
+    fn to_be_bytes(&self, len: usize) -> Vec<u8> {
+        let mut bytes: Vec<u8> = self
+            .0
+            .iter()
+            .flat_map(|word| word.to_le_bytes())
+            .chain(std::iter::repeat(0))
+            .take(len)
+            .collect();
+
+        bytes.reverse();
+        bytes
+    }
+
+    fn fmt_hex(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut words = self.0.iter().rev();
+
+        write!(f, "0x{:x}", words.next().unwrap_or(&0))?;
+
+        for word in words {
+            write!(f, "{:08x}", word)?;
+        }
+
+        Ok(())
+    }
+
+    /// Removes the most significant zero words.
+    fn normalize(&mut self) {
+        while self.0.len() > 1 && self.0.last() == Some(&0) {
+            self.0.pop();
+        }
+    }
+
+    fn word(&self, index: usize) -> u32 {
+        self.0.get(index).cloned().unwrap_or(0)
+    }
+
+    fn rem_u64(&self, modulus: u64) -> u64 {
+        self.0.iter().rev().fold(0, |remainder, word| {
+            ((u128::from(remainder) << 32 | u128::from(*word)) % u128::from(modulus)) as u64
+        })
+    }
+
+    fn trailing_zeros(&self) -> u32 {
+        let mut zeros = 0;
+
+        for word in &self.0 {
+            if *word != 0 {
+                return zeros + word.trailing_zeros();
+            }
+
+            zeros += 32;
+        }
+
+        zeros
+    }
+
+    fn shr(&self, shift: u32) -> Self {
+        let word_shift = shift as usize / 32;
+        let bit_shift = shift % 32;
+
+        let words = (word_shift..self.0.len())
+            .map(|i| {
+                let low = self.0[i] >> bit_shift;
+                let high = if bit_shift == 0 {
+                    0
+                } else {
+                    self.word(i + 1) << (32 - bit_shift)
+                };
+
+                low | high
+            })
+            .collect();
+
+        let mut natural = Natural(words);
+        natural.normalize();
+        natural
+    }
+
+    /// Subtracts `rhs`, which must not be greater than `self`.
+    fn sub(&self, rhs: &Natural) -> Self {
+        let mut borrow = 0i64;
+
+        let words = self
+            .0
+            .iter()
+            .enumerate()
+            .map(|(i, word)| {
+                let difference = i64::from(*word) - i64::from(rhs.word(i)) - borrow;
+                borrow = if difference < 0 { 1 } else { 0 };
+                difference.rem_euclid(1 << 32) as u32
+            })
+            .collect();
+
+        let mut natural = Natural(words);
+        natural.normalize();
+        natural
+    }
+
+    fn bits(&self) -> u32 {
+        let top = self.0.len() - 1;
+
+        top as u32 * 32 + (32 - self.0[top].leading_zeros())
+    }
+
+    fn bit(&self, index: u32) -> bool {
+        self.word(index as usize / 32) >> (index % 32) & 1 == 1
+    }
+}
+
+impl PartialEq for Natural {
+    fn eq(&self, other: &Natural) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Natural {}
+
+impl PartialOrd for Natural {
+    fn partial_cmp(&self, other: &Natural) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Natural {
+    fn cmp(&self, other: &Natural) -> Ordering {
+        let len = self.0.len().max(other.0.len());
+
+        (0..len)
+            .rev()
+            .map(|i| self.word(i).cmp(&other.word(i)))
+            .find(|ordering| *ordering != Ordering::Equal)
+            .unwrap_or(Ordering::Equal)
+    }
+}
+
+/// Montgomery multiplication modulo an odd number, values in Montgomery form
+/// are stored as exactly `len` words.
+struct Montgomery {
+    modulus: Vec<u32>,
+    /// `-modulus^-1 mod 2^32`.
+    inverse: u32,
+    /// `R^2 mod modulus`, where `R = 2^(32 * len)`.
+    r_squared: Vec<u32>,
+}
+
+impl Montgomery {
+    fn new(modulus: &Natural) -> Self {
+        let modulus = modulus.0.clone();
+
+        let mut inverse = 1u32;
+        for _ in 0..5 {
+            inverse = inverse.wrapping_mul(2u32.wrapping_sub(modulus[0].wrapping_mul(inverse)));
+        }
+
+        let mut r_squared = vec![0; modulus.len()];
+        r_squared[0] = 1;
+
+        for _ in 0..(64 * modulus.len()) {
+            r_squared = Self::double_mod(&r_squared, &modulus);
+        }
+
+        Montgomery {
+            modulus,
+            inverse: inverse.wrapping_neg(),
+            r_squared,
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.modulus.len()
+    }
+
+    /// Computes `2 * value mod modulus` where `value < modulus`.
+    fn double_mod(value: &[u32], modulus: &[u32]) -> Vec<u32> {
+        let mut carry = 0;
+        let mut doubled: Vec<u32> = value
+            .iter()
+            .map(|word| {
+                let shifted = word << 1 | carry;
+                carry = word >> 31;
+                shifted
+            })
+            .collect();
+
+        if carry == 1 || Self::cmp_words(&doubled, modulus) != Ordering::Less {
+            Self::sub_words(&mut doubled, modulus);
+        }
+
+        doubled
+    }
+
+    fn cmp_words(lhs: &[u32], rhs: &[u32]) -> Ordering {
+        lhs.iter().rev().cmp(rhs.iter().rev())
+    }
+
+    /// Subtracts `rhs` from `lhs` in place, wrapping on underflow.
+    fn sub_words(lhs: &mut [u32], rhs: &[u32]) {
+        let mut borrow = 0u64;
+
+        for (word, rhs_word) in lhs.iter_mut().zip(rhs) {
+            let difference = u64::from(*word)
+                .wrapping_sub(u64::from(*rhs_word))
+                .wrapping_sub(borrow);
+            *word = difference as u32;
+            borrow = difference >> 63;
+        }
+    }
+
+    /// Computes `lhs * rhs * R^-1 mod modulus` with the CIOS method.
+    fn mul(&self, lhs: &[u32], rhs: &[u32]) -> Vec<u32> {
+        let len = self.len();
+        let mut t = vec![0u32; len + 2];
+
+        for &rhs_word in rhs {
+            let mut carry = 0u64;
+            for j in 0..len {
+                let sum = u64::from(t[j]) + u64::from(lhs[j]) * u64::from(rhs_word) + carry;
+                t[j] = sum as u32;
+                carry = sum >> 32;
+            }
+            let sum = u64::from(t[len]) + carry;
+            t[len] = sum as u32;
+            t[len + 1] = (sum >> 32) as u32;
+
+            let m = t[0].wrapping_mul(self.inverse);
+            let sum = u64::from(t[0]) + u64::from(m) * u64::from(self.modulus[0]);
+            let mut carry = sum >> 32;
+            for j in 1..len {
+                let sum = u64::from(t[j]) + u64::from(m) * u64::from(self.modulus[j]) + carry;
+                t[j - 1] = sum as u32;
+                carry = sum >> 32;
+            }
+            let sum = u64::from(t[len]) + carry;
+            t[len - 1] = sum as u32;
+            t[len] = t[len + 1] + (sum >> 32) as u32;
+        }
+
+        let overflow = t[len] != 0;
+        t.truncate(len);
+
+        if overflow || Self::cmp_words(&t, &self.modulus) != Ordering::Less {
+            Self::sub_words(&mut t, &self.modulus);
+        }
+
+        t
+    }
+
+    fn to_montgomery(&self, value: u32) -> Vec<u32> {
+        let mut words = vec![0; self.len()];
+        words[0] = value;
+        self.mul(&words, &self.r_squared)
+    }
+
+    fn one(&self) -> Vec<u32> {
+        self.to_montgomery(1)
+    }
+
+    fn minus_one(&self) -> Vec<u32> {
+        let mut minus_one = self.modulus.clone();
+        Self::sub_words(&mut minus_one, &self.one());
+        minus_one
+    }
+
+    fn pow(&self, base: &[u32], exponent: &Natural) -> Vec<u32> {
+        let mut result = self.one();
+
+        for bit in (0..exponent.bits()).rev() {
+            result = self.mul(&result, &result);
+
+            if exponent.bit(bit) {
+                result = self.mul(&result, base);
+            }
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{find, validate, Natural, PrimeError};
+    use {FnvParameters, U1024, U256, U512};
+
+    #[test]
+    fn finds_32_bit_prime() {
+        let prime = find(32).unwrap();
+
+        assert_eq!(prime.to_be_bytes(), u32::PRIME.to_be_bytes());
+    }
+
+    #[test]
+    fn finds_64_bit_prime() {
+        let prime = find(64).unwrap();
+
+        assert_eq!(prime.to_be_bytes(), u64::PRIME.to_be_bytes());
+    }
+
+    #[test]
+    fn finds_128_bit_prime() {
+        let prime = find(128).unwrap();
+
+        assert_eq!(prime.to_be_bytes(), u128::PRIME.to_be_bytes());
+    }
+
+    fn words_to_be_bytes(words: &[u64]) -> Vec<u8> {
+        words.iter().flat_map(|word| word.to_be_bytes()).collect()
+    }
+
+    #[test]
+    fn finds_wide_primes() {
+        assert_eq!(
+            find(256).unwrap().to_be_bytes(),
+            words_to_be_bytes(&U256::PRIME.to_words())
+        );
+        assert_eq!(
+            find(512).unwrap().to_be_bytes(),
+            words_to_be_bytes(&U512::PRIME.to_words())
+        );
+        assert_eq!(
+            find(1024).unwrap().to_be_bytes(),
+            words_to_be_bytes(&U1024::PRIME.to_words())
+        );
+    }
+
+    #[test]
+    fn validates_official_primes() {
+        assert_eq!(
+            validate(32, &u32::PRIME.to_be_bytes()),
+            Ok(find(32).unwrap())
+        );
+        assert_eq!(
+            validate(64, &u64::PRIME.to_be_bytes()),
+            Ok(find(64).unwrap())
+        );
+        assert_eq!(
+            validate(128, &u128::PRIME.to_be_bytes()),
+            Ok(find(128).unwrap())
+        );
+    }
+
+    #[test]
+    fn rejects_small_widths() {
+        assert_eq!(find(16), Err(PrimeError::UnsupportedWidth));
+        assert_eq!(
+            validate(16, &[0x01, 0x93]),
+            Err(PrimeError::UnsupportedWidth)
+        );
+    }
+
+    #[test]
+    fn rejects_large_widths() {
+        assert_eq!(find(65537), Err(PrimeError::UnsupportedWidth));
+        assert_eq!(find(u32::MAX), Err(PrimeError::UnsupportedWidth));
+        assert_eq!(
+            validate(u32::MAX, &[0x01, 0x93]),
+            Err(PrimeError::UnsupportedWidth)
+        );
+    }
+
+    #[test]
+    fn rejects_candidates_not_of_fnv_form() {
+        assert_eq!(
+            validate(64, &0x0100_0193u32.to_be_bytes()),
+            Err(PrimeError::NotFnvForm)
+        );
+        assert_eq!(
+            validate(32, &0x0100_0100u32.to_be_bytes()),
+            Err(PrimeError::NotFnvForm)
+        );
+    }
+
+    #[test]
+    fn rejects_candidates_with_invalid_bit_count() {
+        assert_eq!(
+            validate(32, &0x0100_01ffu32.to_be_bytes()),
+            Err(PrimeError::InvalidBitCount)
+        );
+    }
+
+    #[test]
+    fn rejects_candidates_with_small_modulus() {
+        // 0x0f has 4 one bits but 2^24 + 2^8 + 0x0f is below 2^24 + 2^8 + 2^7.
+        assert_eq!(
+            validate(32, &0x0100_010fu32.to_be_bytes()),
+            Err(PrimeError::ModulusTooSmall)
+        );
+    }
+
+    #[test]
+    fn rejects_composite_candidates() {
+        // 2^24 + 2^8 + 0x8e is even and 2^24 + 2^8 + 0x95 is divisible by 7.
+        assert_eq!(
+            validate(32, &0x0100_018eu32.to_be_bytes()),
+            Err(PrimeError::NotPrime)
+        );
+        assert_eq!(
+            validate(32, &0x0100_0195u32.to_be_bytes()),
+            Err(PrimeError::NotPrime)
+        );
+    }
+
+    #[test]
+    fn natural_round_trips_be_bytes() {
+        let bytes = [0x01, 0x23, 0x45, 0x67, 0x89, 0xab];
+
+        assert_eq!(Natural::from_be_bytes(&bytes).to_be_bytes(6), bytes);
+    }
+}
@@ -0,0 +1,405 @@
+//! Searches for colliding inputs and preimages of the 32-bit FNV hashes.
+//!
+//! FNV is not a cryptographic hash, 32-bit hashes are short enough that
+//! colliding inputs, or inputs with a chosen hash, can be found in moments.
+//! These are useful for testing how code handles collisions.
+//!
+//! Inputs are limited to a fixed length and to bytes from a chosen alphabet,
+//! and are searched in a fixed order so that the results are reproducible.
+//!
+//! ```
+//! use lz_fnv::search::{Algorithm, Search};
+//!
+//! let search = Search::new(Algorithm::Fnv1a, b"abcdefghijklmnopqrstuvwxyz", 6).unwrap();
+//! let preimages = search.preimages(0xbf9c_f968, 4).unwrap();
+//!
+//! assert!(preimages.contains(&b"foobar".to_vec()));
+//!
+//! for preimage in &preimages {
+//!     assert_eq!(Algorithm::Fnv1a.hash(preimage), 0xbf9c_f968);
+//! }
+//! ```
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt;
+use {Fnv1, Fnv1a, FnvHasher, FnvParameters};
+
+/// The largest number of hashes a search will hold in memory.
+const MAX_TABLE_LEN: u64 = 1 << 24;
+
+/// Gets a stride coprime with `count`, so that stepping through `0..count`
+/// by it visits every value once in a scrambled order.
+fn coprime_stride(count: u64) -> u64 {
+    fn gcd(a: u64, b: u64) -> u64 {
+        if b == 0 {
+            a
+        } else {
+            gcd(b, a % b)
+        }
+    }
+
+    // The fractional part of the golden ratio spreads consecutive steps
+    // evenly across the range.
+    let mut stride = (count as f64 * 0.618_033_988_749_895) as u64;
+
+    while gcd(stride, count) != 1 {
+        stride += 1;
+    }
+
+    stride
+}
+
+/// The 32-bit FNV hash to search.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Algorithm {
+    /// The 32-bit FNV-1 hash.
+    Fnv1,
+    /// The 32-bit FNV-1a hash.
+    Fnv1a,
+}
+
+impl Algorithm {
+    /// Hashes `bytes` with this algorithm.
+    pub fn hash(self, bytes: &[u8]) -> u32 {
+        self.write(u32::OFFSET_BASIS, bytes)
+    }
+
+    /// Writes `bytes` to the hash state `hash`.
+    fn write(self, hash: u32, bytes: &[u8]) -> u32 {
+        match self {
+            Algorithm::Fnv1 => {
+                let mut hasher = Fnv1::with_key(hash);
+                hasher.write(bytes);
+                hasher.finish()
+            }
+            Algorithm::Fnv1a => {
+                let mut hasher = Fnv1a::with_key(hash);
+                hasher.write(bytes);
+                hasher.finish()
+            }
+        }
+    }
+
+    /// Rolls the hash state `hash` back over `bytes`.
+    fn unwrite(self, hash: u32, bytes: &[u8]) -> u32 {
+        match self {
+            Algorithm::Fnv1 => {
+                let mut hasher = Fnv1::with_key(hash);
+                hasher.unwrite(bytes);
+                hasher.finish()
+            }
+            Algorithm::Fnv1a => {
+                let mut hasher = Fnv1a::with_key(hash);
+                hasher.unwrite(bytes);
+                hasher.finish()
+            }
+        }
+    }
+}
+
+/// The reason a search cannot be made.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SearchError {
+    /// The alphabet is empty.
+    EmptyAlphabet,
+    /// The alphabet contains the same byte more than once.
+    DuplicateByte(u8),
+    /// The length of the inputs is zero.
+    ZeroLength,
+    /// The search would need to hold too many hashes in memory.
+    TooLarge,
+}
+
+impl fmt::Display for SearchError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SearchError::EmptyAlphabet => f.write_str("the alphabet is empty"),
+            SearchError::DuplicateByte(byte) => write!(
+                f,
+                "the alphabet contains '{}' more than once",
+                byte.escape_ascii()
+            ),
+            SearchError::ZeroLength => f.write_str("the length of the inputs is zero"),
+            SearchError::TooLarge => f.write_str(
+                "the search would hold too many hashes in memory, use a smaller alphabet or length",
+            ),
+        }
+    }
+}
+
+impl Error for SearchError {}
+
+/// A search over the inputs of a fixed length made up of bytes from an
+/// alphabet.
+#[derive(Clone, Debug)]
+pub struct Search {
+    algorithm: Algorithm,
+    alphabet: Vec<u8>,
+    len: usize,
+}
+
+impl Search {
+    /// Creates a search over inputs of `len` bytes from `alphabet`.
+    ///
+    /// The order of the bytes in `alphabet` does not matter, inputs are
+    /// ordered as if the alphabet were sorted.
+    pub fn new(algorithm: Algorithm, alphabet: &[u8], len: usize) -> Result<Self, SearchError> {
+        if alphabet.is_empty() {
+            return Err(SearchError::EmptyAlphabet);
+        }
+
+        if let Some(byte) = (1..alphabet.len()).find_map(|i| {
+            let byte = alphabet[i];
+            alphabet[..i].contains(&byte).then_some(byte)
+        }) {
+            return Err(SearchError::DuplicateByte(byte));
+        }
+
+        if len == 0 {
+            return Err(SearchError::ZeroLength);
+        }
+
+        let mut alphabet = alphabet.to_vec();
+        alphabet.sort_unstable();
+
+        Ok(Search {
+            algorithm,
+            alphabet,
+            len,
+        })
+    }
+
+    /// Gets the hash which this search is over.
+    pub fn algorithm(&self) -> Algorithm {
+        self.algorithm
+    }
+
+    /// Finds up to `limit` inputs which hash to `target`.
+    ///
+    /// This is a meet-in-the-middle search: the hashes of every prefix of
+    /// half the length are stored, then the hash is rolled back from `target`
+    /// over every suffix to find the prefixes it meets.
+    ///
+    /// Returns `SearchError::TooLarge` if there are too many prefixes to hold
+    /// in memory.
+    pub fn preimages(&self, target: u32, limit: usize) -> Result<Vec<Vec<u8>>, SearchError> {
+        let prefix_len = self.len / 2;
+
+        if self.count(prefix_len) > MAX_TABLE_LEN {
+            return Err(SearchError::TooLarge);
+        }
+
+        let mut prefixes: HashMap<u32, Vec<u64>> = HashMap::new();
+
+        for index in 0..self.count(prefix_len) {
+            let prefix = self.input(index, prefix_len);
+
+            prefixes
+                .entry(self.algorithm.hash(&prefix))
+                .or_default()
+                .push(index);
+        }
+
+        let mut preimages = Vec::new();
+
+        for index in 0..self.count(self.len - prefix_len) {
+            if preimages.len() >= limit {
+                break;
+            }
+
+            let suffix = self.input(index, self.len - prefix_len);
+            let middle = self.algorithm.unwrite(target, &suffix);
+
+            for prefix in prefixes.get(&middle).into_iter().flatten() {
+                let mut preimage = self.input(*prefix, prefix_len);
+                preimage.extend_from_slice(&suffix);
+                preimages.push(preimage);
+            }
+        }
+
+        preimages.truncate(limit);
+        Ok(preimages)
+    }
+
+    /// Finds up to `limit` pairs of distinct inputs which have the same hash.
+    ///
+    /// Inputs are drawn in a fixed scrambled order, as nearby inputs in
+    /// lexicographic order rarely collide. Each pair is ordered
+    /// lexicographically. The search gives up once it holds the hashes of
+    /// 2^24 inputs, which is far more than a 32-bit hash typically needs.
+    pub fn collisions(&self, limit: usize) -> Vec<(Vec<u8>, Vec<u8>)> {
+        let count = self.count(self.len);
+        let stride = coprime_stride(count);
+        let mut first_inputs: HashMap<u32, u64> = HashMap::new();
+        let mut collisions = Vec::new();
+
+        for i in 0..count.min(MAX_TABLE_LEN) {
+            if collisions.len() >= limit {
+                break;
+            }
+
+            let index = (u128::from(i) * u128::from(stride) % u128::from(count)) as u64;
+            let input = self.input(index, self.len);
+            let first = *first_inputs
+                .entry(self.algorithm.hash(&input))
+                .or_insert(index);
+
+            if first != index {
+                collisions.push((
+                    self.input(first.min(index), self.len),
+                    self.input(first.max(index), self.len),
+                ));
+            }
+        }
+
+        collisions
+    }
+
+    /// Gets the number of inputs of `len` bytes, saturating at `u64::MAX`.
+    fn count(&self, len: usize) -> u64 {
+        u32::try_from(len)
+            .ok()
+            .and_then(|len| (self.alphabet.len() as u64).checked_pow(len))
+            .unwrap_or(u64::MAX)
+    }
+
+    /// Gets the input of `len` bytes at `index` in lexicographic order.
+    fn input(&self, mut index: u64, len: usize) -> Vec<u8> {
+        let base = self.alphabet.len() as u64;
+        let mut input = vec![0; len];
+
+        for byte in input.iter_mut().rev() {
+            *byte = self.alphabet[(index % base) as usize];
+            index /= base;
+        }
+
+        input
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Algorithm, Search, SearchError};
+    use {Fnv1, Fnv1a, FnvHasher};
+
+    const LOWERCASE: &[u8] = b"abcdefghijklmnopqrstuvwxyz";
+
+    #[test]
+    fn algorithm_hash_matches_hashers() {
+        let mut fnv1 = Fnv1::<u32>::new();
+        let mut fnv1a = Fnv1a::<u32>::new();
+
+        fnv1.write(b"foobar");
+        fnv1a.write(b"foobar");
+
+        assert_eq!(Algorithm::Fnv1.hash(b"foobar"), fnv1.finish());
+        assert_eq!(Algorithm::Fnv1a.hash(b"foobar"), fnv1a.finish());
+    }
+
+    fn assert_preimages(algorithm: Algorithm, input: &[u8]) {
+        let search = Search::new(algorithm, LOWERCASE, input.len()).unwrap();
+        let target = algorithm.hash(input);
+        let preimages = search.preimages(target, usize::MAX).unwrap();
+
+        assert!(preimages.contains(&input.to_vec()));
+
+        for preimage in &preimages {
+            assert_eq!(preimage.len(), input.len());
+            assert!(preimage.iter().all(|byte| LOWERCASE.contains(byte)));
+            assert_eq!(algorithm.hash(preimage), target);
+        }
+    }
+
+    #[test]
+    fn finds_fnv1_preimages() {
+        assert_preimages(Algorithm::Fnv1, b"foobar");
+        assert_preimages(Algorithm::Fnv1, b"fooba");
+        assert_preimages(Algorithm::Fnv1, b"f");
+    }
+
+    #[test]
+    fn finds_fnv1a_preimages() {
+        assert_preimages(Algorithm::Fnv1a, b"foobar");
+        assert_preimages(Algorithm::Fnv1a, b"fooba");
+        assert_preimages(Algorithm::Fnv1a, b"f");
+    }
+
+    #[test]
+    fn preimages_are_limited() {
+        let search = Search::new(Algorithm::Fnv1a, LOWERCASE, 6).unwrap();
+
+        assert_eq!(
+            search.preimages(0xbf9c_f968, 1).unwrap(),
+            search.preimages(0xbf9c_f968, 4).unwrap()[..1]
+        );
+    }
+
+    #[test]
+    fn finds_collisions() {
+        for algorithm in &[Algorithm::Fnv1, Algorithm::Fnv1a] {
+            let search = Search::new(*algorithm, LOWERCASE, 6).unwrap();
+            let collisions = search.collisions(3);
+
+            assert_eq!(collisions.len(), 3);
+
+            for (first, second) in &collisions {
+                assert_ne!(first, second);
+                assert!(first < second);
+                assert_eq!(algorithm.hash(first), algorithm.hash(second));
+            }
+        }
+    }
+
+    #[test]
+    fn orders_collisions_lexicographically_for_any_alphabet() {
+        let mut reversed = LOWERCASE.to_vec();
+        reversed.reverse();
+
+        let search = Search::new(Algorithm::Fnv1a, &reversed, 6).unwrap();
+        let collisions = search.collisions(3);
+
+        assert_eq!(search.algorithm(), Algorithm::Fnv1a);
+        assert_eq!(
+            collisions,
+            Search::new(Algorithm::Fnv1a, LOWERCASE, 6)
+                .unwrap()
+                .collisions(3)
+        );
+
+        for (first, second) in &collisions {
+            assert!(first < second);
+        }
+    }
+
+    #[test]
+    fn finds_no_collisions_when_none_exist() {
+        // Every lowercase input of 4 bytes has a distinct hash.
+        let search = Search::new(Algorithm::Fnv1a, LOWERCASE, 4).unwrap();
+
+        assert!(search.collisions(1).is_empty());
+    }
+
+    #[test]
+    fn rejects_invalid_searches() {
+        assert_eq!(
+            Search::new(Algorithm::Fnv1a, b"", 4).unwrap_err(),
+            SearchError::EmptyAlphabet
+        );
+        assert_eq!(
+            Search::new(Algorithm::Fnv1a, b"abca", 4).unwrap_err(),
+            SearchError::DuplicateByte(b'a')
+        );
+        assert_eq!(
+            Search::new(Algorithm::Fnv1a, b"abc", 0).unwrap_err(),
+            SearchError::ZeroLength
+        );
+    }
+
+    #[test]
+    fn rejects_preimage_searches_with_too_many_prefixes() {
+        let search = Search::new(Algorithm::Fnv1a, LOWERCASE, 12).unwrap();
+
+        assert_eq!(search.preimages(0, 1), Err(SearchError::TooLarge));
+    }
+}
@@ -0,0 +1,1027 @@
+//! Saves and restores the state of the FNV hashers.
+//!
+//! `to_bytes` saves the state of an `Fnv0`, `Fnv1` or `Fnv1a` hasher, and
+//! `from_bytes` restores it, so hashing a long input can be resumed after an
+//! interruption. The saved state is framed as follows, integers are
+//! big-endian:
+//!
+//! | Bytes       | Contents                                                |
+//! |-------------|---------------------------------------------------------|
+//! | 5           | The magic `lzfnv`                                       |
+//! | 1           | The version of the format, currently 1                  |
+//! | 1           | The variant, 0 for FNV-0, 1 for FNV-1 and 2 for FNV-1a  |
+//! | 2           | The width of the hash in bits                           |
+//! | width / 8   | The hash                                                |
+//!
+//! A state can only be restored into a hasher of the same variant and width.
+//!
+//! ```
+//! # #[cfg(feature = "std")]
+//! # fn main() {
+//! use lz_fnv::state::StateError;
+//! use lz_fnv::{Fnv1a, FnvHasher};
+//!
+//! let mut fnv_hasher = Fnv1a::<u64>::new();
+//! fnv_hasher.write(b"foo");
+//! let saved = fnv_hasher.to_bytes();
+//!
+//! let mut fnv_hasher = Fnv1a::<u64>::from_bytes(&saved).unwrap();
+//! fnv_hasher.write(b"bar");
+//! assert_eq!(fnv_hasher.finish(), 0x8594_4171_f739_67e8);
+//!
+//! assert_eq!(
+//!     Fnv1a::<u32>::from_bytes(&saved).unwrap_err(),
+//!     StateError::WrongWidth {
+//!         expected: 32,
+//!         found: 64
+//!     }
+//! );
+//! # }
+//! #
+//! # #[cfg(not(feature = "std"))]
+//! # fn main() {}
+//! ```
+//!
+//! `to_go_bytes` and `from_go_bytes` save and restore the state of the 32, 64
+//! and 128-bit `Fnv1` and `Fnv1a` hashers in the format of the `MarshalBinary`
+//! and `UnmarshalBinary` methods of Go's `hash/fnv` hashes, so a hash can be
+//! started in Go and finished in Rust or the other way round. The format is
+//! the magic `fnv` followed by a byte identifying the hash, 1 to 6 for FNV-1
+//! and FNV-1a at 32, 64 and 128 bits in turn, then the big-endian hash.
+//!
+//! ```
+//! # #[cfg(feature = "std")]
+//! # fn main() {
+//! use lz_fnv::{Fnv1a, FnvHasher};
+//!
+//! // The state of Go's `fnv.New32a()` after writing "foo".
+//! let mut fnv_hasher = Fnv1a::<u32>::from_go_bytes(b"fnv\x02\xa9\xf3\x7e\xd7").unwrap();
+//! fnv_hasher.write(b"bar");
+//!
+//! assert_eq!(fnv_hasher.finish(), 0xbf9c_f968);
+//! assert_eq!(fnv_hasher.to_go_bytes(), b"fnv\x02\xbf\x9c\xf9\x68");
+//! # }
+//! #
+//! # #[cfg(not(feature = "std"))]
+//! # fn main() {}
+//! ```
+//!
+//! The `serde` feature implements `Serialize` and `Deserialize` for the
+//! hashers, as a struct of the variant, the width in bits and the big-endian
+//! bytes of the hash.
+use core::convert::TryInto;
+use core::fmt;
+#[cfg(feature = "std")]
+use std::error::Error;
+use {Fnv0, Fnv1, Fnv1a, FnvParameters, U1024, U256, U512};
+
+/// The magic at the start of a saved state.
+const MAGIC: &[u8; 5] = b"lzfnv";
+
+/// The version of the format written by `to_bytes`.
+pub const VERSION: u8 = 1;
+
+/// The length of the framing before the hash.
+const HEADER_LEN: usize = 9;
+
+/// The length of the largest hash which can be saved.
+#[cfg(feature = "serde")]
+const MAX_HASH_LEN: usize = 128;
+
+/// An FNV variant.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Variant {
+    /// The FNV-0 hash.
+    Fnv0,
+    /// The FNV-1 hash.
+    Fnv1,
+    /// The FNV-1a hash.
+    Fnv1a,
+}
+
+impl Variant {
+    #[cfg(feature = "std")]
+    fn tag(self) -> u8 {
+        match self {
+            Variant::Fnv0 => 0,
+            Variant::Fnv1 => 1,
+            Variant::Fnv1a => 2,
+        }
+    }
+
+    fn from_tag(tag: u8) -> Option<Variant> {
+        match tag {
+            0 => Some(Variant::Fnv0),
+            1 => Some(Variant::Fnv1),
+            2 => Some(Variant::Fnv1a),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Variant {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Variant::Fnv0 => "FNV-0",
+            Variant::Fnv1 => "FNV-1",
+            Variant::Fnv1a => "FNV-1a",
+        })
+    }
+}
+
+/// A hash type whose value can be saved as bytes.
+///
+/// This is implemented for `u32`, `u64`, `u128`, `U256`, `U512` and `U1024`.
+pub trait StateBytes: FnvParameters {
+    /// The width of the hash in bits, a multiple of 8 no greater than 1024.
+    const BITS: u32;
+
+    /// Writes the value to the `BITS / 8` bytes of `bytes`, most significant
+    /// byte first.
+    fn write_be_bytes(self, bytes: &mut [u8]);
+
+    /// Reads a value from the `BITS / 8` bytes of `bytes`, most significant
+    /// byte first.
+    fn read_be_bytes(bytes: &[u8]) -> Self;
+}
+
+macro_rules! state_bytes_impl {
+    ($($type: ty,)*) => {
+        $(
+            impl StateBytes for $type {
+                const BITS: u32 = <$type>::BITS;
+
+                fn write_be_bytes(self, bytes: &mut [u8]) {
+                    bytes.copy_from_slice(&self.to_be_bytes());
+                }
+
+                fn read_be_bytes(bytes: &[u8]) -> Self {
+                    <$type>::from_be_bytes(bytes.try_into().unwrap())
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! wide_state_bytes_impl {
+    ($($type: ident, $words: expr;)*) => {
+        $(
+            impl StateBytes for $type {
+                const BITS: u32 = $words * 64;
+
+                fn write_be_bytes(self, bytes: &mut [u8]) {
+                    for (chunk, word) in bytes.chunks_exact_mut(8).zip(self.to_words().iter()) {
+                        chunk.copy_from_slice(&word.to_be_bytes());
+                    }
+                }
+
+                fn read_be_bytes(bytes: &[u8]) -> Self {
+                    let mut words = [0u64; $words];
+
+                    for (word, chunk) in words.iter_mut().zip(bytes.chunks_exact(8)) {
+                        *word = u64::from_be_bytes(chunk.try_into().unwrap());
+                    }
+
+                    $type::from_words(words)
+                }
+            }
+        )*
+    };
+}
+
+state_bytes_impl! {
+    u32,
+    u64,
+    u128,
+}
+
+wide_state_bytes_impl! {
+    U256, 4;
+    U512, 8;
+    U1024, 16;
+}
+
+/// The reason a saved state cannot be restored.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StateError {
+    /// The bytes do not start with the magic of a saved state.
+    InvalidMagic,
+    /// The state was saved in a version of the format which is not supported.
+    UnsupportedVersion(u8),
+    /// The state was saved by an FNV variant which is not known.
+    UnknownVariant(u8),
+    /// The state was saved by a hasher of a different variant.
+    WrongVariant {
+        /// The variant of the hasher being restored.
+        expected: Variant,
+        /// The variant of the saved state.
+        found: Variant,
+    },
+    /// The state was saved by a hasher of a different width.
+    WrongWidth {
+        /// The width in bits of the hasher being restored.
+        expected: u32,
+        /// The width in bits of the saved state.
+        found: u32,
+    },
+    /// The saved state is truncated or has trailing bytes.
+    InvalidLength {
+        /// The length of the state of the hasher being restored.
+        expected: usize,
+        /// The length of the saved state.
+        found: usize,
+    },
+}
+
+impl fmt::Display for StateError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            StateError::InvalidMagic => f.write_str("the bytes are not a saved FNV state"),
+            StateError::UnsupportedVersion(version) => {
+                write!(f, "version {} of the FNV state is not supported", version)
+            }
+            StateError::UnknownVariant(tag) => write!(f, "unknown FNV variant {}", tag),
+            StateError::WrongVariant { expected, found } => write!(
+                f,
+                "cannot restore an {} state into an {} hasher",
+                found, expected
+            ),
+            StateError::WrongWidth { expected, found } => write!(
+                f,
+                "cannot restore a {}-bit state into a {}-bit hasher",
+                found, expected
+            ),
+            StateError::InvalidLength { expected, found } => write!(
+                f,
+                "the saved state is {} bytes long, expected {}",
+                found, expected
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for StateError {}
+
+#[cfg(feature = "std")]
+fn encode<T: StateBytes>(variant: Variant, hash: T) -> Vec<u8> {
+    let mut bytes = vec![0; HEADER_LEN + T::BITS as usize / 8];
+
+    bytes[..MAGIC.len()].copy_from_slice(MAGIC);
+    bytes[5] = VERSION;
+    bytes[6] = variant.tag();
+    bytes[7..HEADER_LEN].copy_from_slice(&(T::BITS as u16).to_be_bytes());
+    hash.write_be_bytes(&mut bytes[HEADER_LEN..]);
+
+    bytes
+}
+
+/// Checks that a saved state matches the variant and width of the hasher
+/// being restored, then reads the hash.
+fn check<T: StateBytes>(
+    expected: Variant,
+    found: Variant,
+    bits: u32,
+    hash: &[u8],
+) -> Result<T, StateError> {
+    if found != expected {
+        return Err(StateError::WrongVariant { expected, found });
+    }
+
+    if bits != T::BITS {
+        return Err(StateError::WrongWidth {
+            expected: T::BITS,
+            found: bits,
+        });
+    }
+
+    let len = T::BITS as usize / 8;
+
+    if hash.len() != len {
+        return Err(StateError::InvalidLength {
+            expected: len,
+            found: hash.len(),
+        });
+    }
+
+    Ok(T::read_be_bytes(hash))
+}
+
+fn decode<T: StateBytes>(variant: Variant, bytes: &[u8]) -> Result<T, StateError> {
+    if !bytes.starts_with(MAGIC) {
+        return Err(StateError::InvalidMagic);
+    }
+
+    let invalid_length = StateError::InvalidLength {
+        expected: HEADER_LEN + T::BITS as usize / 8,
+        found: bytes.len(),
+    };
+
+    let version = *bytes.get(5).ok_or(invalid_length)?;
+
+    if version != VERSION {
+        return Err(StateError::UnsupportedVersion(version));
+    }
+
+    if bytes.len() < HEADER_LEN {
+        return Err(invalid_length);
+    }
+
+    let found = Variant::from_tag(bytes[6]).ok_or(StateError::UnknownVariant(bytes[6]))?;
+    let bits = u16::from_be_bytes([bytes[7], bytes[8]]);
+
+    check(variant, found, u32::from(bits), &bytes[HEADER_LEN..]).map_err(|err| match err {
+        StateError::InvalidLength { .. } => invalid_length,
+        err => err,
+    })
+}
+
+/// The magic at the start of a Go `hash/fnv` state, followed by a byte
+/// identifying the variant and width.
+const GO_MAGIC: &[u8; 3] = b"fnv";
+
+/// The variants and widths of the Go `hash/fnv` states, in the order of the
+/// bytes identifying them from 1.
+const GO_HASHES: [(Variant, u32); 6] = [
+    (Variant::Fnv1, 32),
+    (Variant::Fnv1a, 32),
+    (Variant::Fnv1, 64),
+    (Variant::Fnv1a, 64),
+    (Variant::Fnv1, 128),
+    (Variant::Fnv1a, 128),
+];
+
+#[cfg(feature = "std")]
+fn encode_go<T: StateBytes>(variant: Variant, hash: T) -> Vec<u8> {
+    let id = GO_HASHES
+        .iter()
+        .position(|go_hash| *go_hash == (variant, T::BITS))
+        .expect("Go has no hash of this variant and width");
+
+    let mut bytes = vec![0; GO_MAGIC.len() + 1 + T::BITS as usize / 8];
+
+    bytes[..GO_MAGIC.len()].copy_from_slice(GO_MAGIC);
+    bytes[3] = id as u8 + 1;
+    hash.write_be_bytes(&mut bytes[4..]);
+
+    bytes
+}
+
+fn decode_go<T: StateBytes>(variant: Variant, bytes: &[u8]) -> Result<T, StateError> {
+    if bytes.len() < 4 || !bytes.starts_with(GO_MAGIC) {
+        return Err(StateError::InvalidMagic);
+    }
+
+    let (found, bits) = usize::from(bytes[3])
+        .checked_sub(1)
+        .and_then(|index| GO_HASHES.get(index))
+        .ok_or(StateError::InvalidMagic)?;
+
+    check(variant, *found, *bits, &bytes[4..]).map_err(|err| match err {
+        StateError::InvalidLength { expected, found } => StateError::InvalidLength {
+            expected: expected + 4,
+            found: found + 4,
+        },
+        err => err,
+    })
+}
+
+macro_rules! state_impl {
+    ($hasher: ident, $variant: ident) => {
+        impl<T: StateBytes> $hasher<T> {
+            /// Saves the state of the hasher, to be restored by `from_bytes`.
+            #[cfg(feature = "std")]
+            pub fn to_bytes(&self) -> Vec<u8> {
+                encode(Variant::$variant, self.hash)
+            }
+
+            /// Restores a hasher from a state saved by `to_bytes`.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the bytes are not a saved state, or were
+            /// saved by a hasher of a different variant or width.
+            pub fn from_bytes(bytes: &[u8]) -> Result<Self, StateError> {
+                decode(Variant::$variant, bytes).map($hasher::with_key)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<T: StateBytes> serde_impls::Serialize for $hasher<T> {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: serde_impls::Serializer,
+            {
+                serde_impls::serialize_state(Variant::$variant, self.hash, serializer)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de, T: StateBytes> serde_impls::Deserialize<'de> for $hasher<T> {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: serde_impls::Deserializer<'de>,
+            {
+                serde_impls::deserialize_state(Variant::$variant, deserializer)
+                    .map($hasher::with_key)
+            }
+        }
+    };
+}
+
+state_impl!(Fnv0, Fnv0);
+state_impl!(Fnv1, Fnv1);
+state_impl!(Fnv1a, Fnv1a);
+
+macro_rules! go_state_impl {
+    ($($hasher: ident<$type: ty>,)*) => {
+        $(
+            impl $hasher<$type> {
+                /// Saves the state of the hasher in the format of the
+                /// `MarshalBinary` method of Go's `hash/fnv` hashes.
+                #[cfg(feature = "std")]
+                pub fn to_go_bytes(&self) -> Vec<u8> {
+                    encode_go(Variant::$hasher, self.hash)
+                }
+
+                /// Restores a hasher from a state saved by the
+                /// `MarshalBinary` method of Go's `hash/fnv` hashes.
+                ///
+                /// # Errors
+                ///
+                /// Returns an error if the bytes are not a Go state, or were
+                /// saved by a hash of a different variant or width.
+                pub fn from_go_bytes(bytes: &[u8]) -> Result<Self, StateError> {
+                    decode_go(Variant::$hasher, bytes).map($hasher::with_key)
+                }
+            }
+        )*
+    };
+}
+
+go_state_impl! {
+    Fnv1<u32>,
+    Fnv1a<u32>,
+    Fnv1<u64>,
+    Fnv1a<u64>,
+    Fnv1<u128>,
+    Fnv1a<u128>,
+}
+
+#[cfg(feature = "serde")]
+mod serde_impls {
+    use super::{check, StateBytes, Variant, MAX_HASH_LEN};
+    use core::fmt;
+    use core::marker::PhantomData;
+    use serde::de::{self, MapAccess, SeqAccess, Unexpected, Visitor};
+    use serde::ser::SerializeStruct;
+    pub use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    const FIELDS: &[&str] = &["variant", "bits", "hash"];
+
+    impl Serialize for Variant {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.collect_str(self)
+        }
+    }
+
+    struct VariantVisitor;
+
+    impl<'de> Visitor<'de> for VariantVisitor {
+        type Value = Variant;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("\"FNV-0\", \"FNV-1\" or \"FNV-1a\"")
+        }
+
+        fn visit_str<E: de::Error>(self, value: &str) -> Result<Variant, E> {
+            match value {
+                "FNV-0" => Ok(Variant::Fnv0),
+                "FNV-1" => Ok(Variant::Fnv1),
+                "FNV-1a" => Ok(Variant::Fnv1a),
+                _ => Err(E::invalid_value(Unexpected::Str(value), &self)),
+            }
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Variant {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Variant, D::Error> {
+            deserializer.deserialize_str(VariantVisitor)
+        }
+    }
+
+    /// The big-endian bytes of a hash, serialized as bytes.
+    struct HashBytes<'a>(&'a [u8]);
+
+    impl<'a> Serialize for HashBytes<'a> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_bytes(self.0)
+        }
+    }
+
+    /// The big-endian bytes of a deserialized hash.
+    struct HashBuf {
+        bytes: [u8; MAX_HASH_LEN],
+        len: usize,
+    }
+
+    struct HashBufVisitor;
+
+    impl<'de> Visitor<'de> for HashBufVisitor {
+        type Value = HashBuf;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "at most {} bytes", MAX_HASH_LEN)
+        }
+
+        fn visit_bytes<E: de::Error>(self, value: &[u8]) -> Result<HashBuf, E> {
+            if value.len() > MAX_HASH_LEN {
+                return Err(E::invalid_length(value.len(), &self));
+            }
+
+            let mut hash = HashBuf {
+                bytes: [0; MAX_HASH_LEN],
+                len: value.len(),
+            };
+            hash.bytes[..value.len()].copy_from_slice(value);
+
+            Ok(hash)
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<HashBuf, A::Error> {
+            let mut hash = HashBuf {
+                bytes: [0; MAX_HASH_LEN],
+                len: 0,
+            };
+
+            while let Some(byte) = seq.next_element()? {
+                if hash.len == MAX_HASH_LEN {
+                    return Err(de::Error::invalid_length(hash.len + 1, &self));
+                }
+
+                hash.bytes[hash.len] = byte;
+                hash.len += 1;
+            }
+
+            Ok(hash)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for HashBuf {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<HashBuf, D::Error> {
+            deserializer.deserialize_bytes(HashBufVisitor)
+        }
+    }
+
+    enum Field {
+        Variant,
+        Bits,
+        Hash,
+    }
+
+    struct FieldVisitor;
+
+    impl<'de> Visitor<'de> for FieldVisitor {
+        type Value = Field;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("`variant`, `bits` or `hash`")
+        }
+
+        fn visit_str<E: de::Error>(self, value: &str) -> Result<Field, E> {
+            match value {
+                "variant" => Ok(Field::Variant),
+                "bits" => Ok(Field::Bits),
+                "hash" => Ok(Field::Hash),
+                _ => Err(E::unknown_field(value, FIELDS)),
+            }
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Field {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Field, D::Error> {
+            deserializer.deserialize_identifier(FieldVisitor)
+        }
+    }
+
+    struct StateVisitor<T> {
+        variant: Variant,
+        marker: PhantomData<T>,
+    }
+
+    impl<T: StateBytes> StateVisitor<T> {
+        fn check<E: de::Error>(&self, variant: Variant, bits: u32, hash: HashBuf) -> Result<T, E> {
+            check(self.variant, variant, bits, &hash.bytes[..hash.len]).map_err(E::custom)
+        }
+    }
+
+    impl<'de, T: StateBytes> Visitor<'de> for StateVisitor<T> {
+        type Value = T;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "the state of a {}-bit {} hasher", T::BITS, self.variant)
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<T, A::Error> {
+            let variant = seq
+                .next_element()?
+                .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+            let bits = seq
+                .next_element()?
+                .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+            let hash = seq
+                .next_element()?
+                .ok_or_else(|| de::Error::invalid_length(2, &self))?;
+
+            self.check(variant, bits, hash)
+        }
+
+        fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<T, A::Error> {
+            let mut variant = None;
+            let mut bits = None;
+            let mut hash = None;
+
+            while let Some(field) = map.next_key()? {
+                match field {
+                    Field::Variant if variant.is_some() => {
+                        return Err(de::Error::duplicate_field("variant"))
+                    }
+                    Field::Variant => variant = Some(map.next_value()?),
+                    Field::Bits if bits.is_some() => {
+                        return Err(de::Error::duplicate_field("bits"))
+                    }
+                    Field::Bits => bits = Some(map.next_value()?),
+                    Field::Hash if hash.is_some() => {
+                        return Err(de::Error::duplicate_field("hash"))
+                    }
+                    Field::Hash => hash = Some(map.next_value()?),
+                }
+            }
+
+            self.check(
+                variant.ok_or_else(|| de::Error::missing_field("variant"))?,
+                bits.ok_or_else(|| de::Error::missing_field("bits"))?,
+                hash.ok_or_else(|| de::Error::missing_field("hash"))?,
+            )
+        }
+    }
+
+    pub fn serialize_state<S, T>(
+        variant: Variant,
+        hash: T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        T: StateBytes,
+    {
+        let mut bytes = [0; MAX_HASH_LEN];
+        let bytes = &mut bytes[..T::BITS as usize / 8];
+        hash.write_be_bytes(bytes);
+
+        let mut state = serializer.serialize_struct("FnvState", FIELDS.len())?;
+        state.serialize_field("variant", &variant)?;
+        state.serialize_field("bits", &T::BITS)?;
+        state.serialize_field("hash", &HashBytes(bytes))?;
+        state.end()
+    }
+
+    pub fn deserialize_state<'de, D, T>(variant: Variant, deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: StateBytes,
+    {
+        deserializer.deserialize_struct(
+            "FnvState",
+            FIELDS,
+            StateVisitor {
+                variant,
+                marker: PhantomData,
+            },
+        )
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::{StateBytes, StateError, Variant, VERSION};
+    use {Fnv0, Fnv1, Fnv1a, FnvHasher, FnvParameters, U1024, U256, U512};
+
+    macro_rules! round_trip_tests {
+        ($($name: ident: $type: ty,)*) => {
+            $(
+                #[test]
+                fn $name() {
+                    let mut fnv0 = Fnv0::<$type>::new();
+                    let mut fnv1 = Fnv1::<$type>::new();
+                    let mut fnv1a = Fnv1a::<$type>::new();
+                    fnv0.write(b"foo");
+                    fnv1.write(b"foo");
+                    fnv1a.write(b"foo");
+
+                    let mut fnv0 = Fnv0::<$type>::from_bytes(&fnv0.to_bytes()).unwrap();
+                    let mut fnv1 = Fnv1::<$type>::from_bytes(&fnv1.to_bytes()).unwrap();
+                    let mut fnv1a = Fnv1a::<$type>::from_bytes(&fnv1a.to_bytes()).unwrap();
+                    fnv0.write(b"bar");
+                    fnv1.write(b"bar");
+                    fnv1a.write(b"bar");
+
+                    let mut expected_fnv0 = Fnv0::<$type>::new();
+                    let mut expected_fnv1 = Fnv1::<$type>::new();
+                    let mut expected_fnv1a = Fnv1a::<$type>::new();
+                    expected_fnv0.write(b"foobar");
+                    expected_fnv1.write(b"foobar");
+                    expected_fnv1a.write(b"foobar");
+
+                    assert_eq!(fnv0.finish(), expected_fnv0.finish());
+                    assert_eq!(fnv1.finish(), expected_fnv1.finish());
+                    assert_eq!(fnv1a.finish(), expected_fnv1a.finish());
+                }
+            )*
+        };
+    }
+
+    round_trip_tests! {
+        round_trips_32: u32,
+        round_trips_64: u64,
+        round_trips_128: u128,
+        round_trips_256: U256,
+        round_trips_512: U512,
+        round_trips_1024: U1024,
+    }
+
+    #[test]
+    fn frames_state() {
+        let mut fnv1a = Fnv1a::<u32>::new();
+        fnv1a.write(b"foobar");
+
+        assert_eq!(
+            fnv1a.to_bytes(),
+            b"lzfnv\x01\x02\x00\x20\xbf\x9c\xf9\x68".to_vec()
+        );
+        assert_eq!(
+            Fnv0::<U256>::new().to_bytes(),
+            [&b"lzfnv\x01\x00\x01\x00"[..], &[0; 32]].concat()
+        );
+    }
+
+    #[test]
+    fn writes_wide_hashes_most_significant_byte_first() {
+        let mut bytes = [0; 32];
+        U256::from_words([1, 2, 3, 4]).write_be_bytes(&mut bytes);
+
+        assert_eq!(bytes[7], 1);
+        assert_eq!(bytes[31], 4);
+        assert_eq!(U256::read_be_bytes(&bytes), U256::from_words([1, 2, 3, 4]));
+        assert_eq!(U256::BITS, 256);
+    }
+
+    #[test]
+    fn rejects_wrong_variant() {
+        let saved = Fnv1::<u64>::new().to_bytes();
+
+        assert_eq!(
+            Fnv1a::<u64>::from_bytes(&saved).unwrap_err(),
+            StateError::WrongVariant {
+                expected: Variant::Fnv1a,
+                found: Variant::Fnv1,
+            }
+        );
+        assert_eq!(
+            Fnv0::<u64>::from_bytes(&saved).unwrap_err(),
+            StateError::WrongVariant {
+                expected: Variant::Fnv0,
+                found: Variant::Fnv1,
+            }
+        );
+    }
+
+    #[test]
+    fn rejects_wrong_width() {
+        let saved = Fnv1a::<U512>::new().to_bytes();
+
+        assert_eq!(
+            Fnv1a::<U1024>::from_bytes(&saved).unwrap_err(),
+            StateError::WrongWidth {
+                expected: 1024,
+                found: 512,
+            }
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_framing() {
+        let saved = Fnv1a::<u32>::new().to_bytes();
+
+        assert_eq!(
+            Fnv1a::<u32>::from_bytes(b"").unwrap_err(),
+            StateError::InvalidMagic
+        );
+        assert_eq!(
+            Fnv1a::<u32>::from_bytes(&saved[1..]).unwrap_err(),
+            StateError::InvalidMagic
+        );
+        assert_eq!(
+            Fnv1a::<u32>::from_bytes(&[&saved[..5], &[VERSION + 1]].concat()).unwrap_err(),
+            StateError::UnsupportedVersion(VERSION + 1)
+        );
+        assert_eq!(
+            Fnv1a::<u32>::from_bytes(&[&saved[..6], &[3, 0, 32]].concat()).unwrap_err(),
+            StateError::UnknownVariant(3)
+        );
+
+        for len in 5..saved.len() {
+            assert_eq!(
+                Fnv1a::<u32>::from_bytes(&saved[..len]).unwrap_err(),
+                StateError::InvalidLength {
+                    expected: 13,
+                    found: len,
+                }
+            );
+        }
+
+        assert_eq!(
+            Fnv1a::<u32>::from_bytes(&[&saved[..], &[0]].concat()).unwrap_err(),
+            StateError::InvalidLength {
+                expected: 13,
+                found: 14,
+            }
+        );
+    }
+
+    #[test]
+    fn restores_keyed_hashers() {
+        let saved = Fnv1a::with_key(u128::OFFSET_BASIS ^ 1).to_bytes();
+
+        assert_eq!(
+            Fnv1a::<u128>::from_bytes(&saved).unwrap().finish(),
+            u128::OFFSET_BASIS ^ 1
+        );
+    }
+
+    #[test]
+    fn identifies_go_states() {
+        assert_eq!(Fnv1::<u32>::new().to_go_bytes()[..4], b"fnv\x01"[..]);
+        assert_eq!(Fnv1a::<u32>::new().to_go_bytes()[..4], b"fnv\x02"[..]);
+        assert_eq!(Fnv1::<u64>::new().to_go_bytes()[..4], b"fnv\x03"[..]);
+        assert_eq!(Fnv1a::<u64>::new().to_go_bytes()[..4], b"fnv\x04"[..]);
+        assert_eq!(Fnv1::<u128>::new().to_go_bytes()[..4], b"fnv\x05"[..]);
+        assert_eq!(Fnv1a::<u128>::new().to_go_bytes()[..4], b"fnv\x06"[..]);
+        assert_eq!(
+            Fnv1::<u128>::new().to_go_bytes(),
+            b"fnv\x05\x6c\x62\x27\x2e\x07\xbb\x01\x42\x62\xb8\x21\x75\x62\x95\xc5\x8d".to_vec()
+        );
+    }
+
+    #[test]
+    fn rejects_go_states_of_other_hashes() {
+        let saved = Fnv1a::<u64>::new().to_go_bytes();
+
+        assert_eq!(
+            Fnv1::<u64>::from_go_bytes(&saved).unwrap_err(),
+            StateError::WrongVariant {
+                expected: Variant::Fnv1,
+                found: Variant::Fnv1a,
+            }
+        );
+        assert_eq!(
+            Fnv1a::<u32>::from_go_bytes(&saved).unwrap_err(),
+            StateError::WrongWidth {
+                expected: 32,
+                found: 64,
+            }
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_go_framing() {
+        let saved = Fnv1a::<u64>::new().to_go_bytes();
+
+        for invalid in &[&b""[..], b"fnv", b"fnv\x00", b"fnv\x07", b"lzfnv\x01"] {
+            assert_eq!(
+                Fnv1a::<u64>::from_go_bytes(invalid).unwrap_err(),
+                StateError::InvalidMagic
+            );
+        }
+
+        assert_eq!(
+            Fnv1a::<u64>::from_go_bytes(&Fnv1a::<u64>::new().to_bytes()).unwrap_err(),
+            StateError::InvalidMagic
+        );
+        assert_eq!(
+            Fnv1a::<u64>::from_bytes(&saved).unwrap_err(),
+            StateError::InvalidMagic
+        );
+        assert_eq!(
+            Fnv1a::<u64>::from_go_bytes(&saved[..11]).unwrap_err(),
+            StateError::InvalidLength {
+                expected: 12,
+                found: 11,
+            }
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    mod serde_tests {
+        use serde::{Deserialize, Deserializer, Serialize, Serializer};
+        use serde_test::{assert_de_tokens, assert_de_tokens_error, assert_tokens, Token};
+        use state::{StateError, Variant};
+        use {Fnv1, Fnv1a, FnvHasher};
+
+        /// Compares hashers by their hash for `serde_test`.
+        #[derive(Debug)]
+        struct Hashed(Fnv1a<u32>);
+
+        impl PartialEq for Hashed {
+            fn eq(&self, other: &Hashed) -> bool {
+                self.0.finish() == other.0.finish()
+            }
+        }
+
+        impl Serialize for Hashed {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                self.0.serialize(serializer)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for Hashed {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Hashed, D::Error> {
+                Fnv1a::deserialize(deserializer).map(Hashed)
+            }
+        }
+
+        fn foobar() -> Hashed {
+            let mut fnv1a = Fnv1a::new();
+            fnv1a.write(b"foobar");
+            Hashed(fnv1a)
+        }
+
+        fn state_tokens(variant: &'static str, bits: u32, hash: &'static [u8]) -> Vec<Token> {
+            vec![
+                Token::Struct {
+                    name: "FnvState",
+                    len: 3,
+                },
+                Token::Str("variant"),
+                Token::Str(variant),
+                Token::Str("bits"),
+                Token::U32(bits),
+                Token::Str("hash"),
+                Token::Bytes(hash),
+                Token::StructEnd,
+            ]
+        }
+
+        #[test]
+        fn serializes_variant_width_and_hash() {
+            assert_tokens(&foobar(), &state_tokens("FNV-1a", 32, b"\xbf\x9c\xf9\x68"));
+        }
+
+        #[test]
+        fn deserializes_sequences() {
+            assert_de_tokens(
+                &foobar(),
+                &[
+                    Token::Seq { len: Some(3) },
+                    Token::Str("FNV-1a"),
+                    Token::U32(32),
+                    Token::Seq { len: Some(4) },
+                    Token::U8(0xbf),
+                    Token::U8(0x9c),
+                    Token::U8(0xf9),
+                    Token::U8(0x68),
+                    Token::SeqEnd,
+                    Token::SeqEnd,
+                ],
+            );
+        }
+
+        #[test]
+        fn rejects_wrong_variant_width_and_length() {
+            assert_de_tokens_error::<Fnv1<u32>>(
+                &state_tokens("FNV-1a", 32, b"\xbf\x9c\xf9\x68"),
+                &StateError::WrongVariant {
+                    expected: Variant::Fnv1,
+                    found: Variant::Fnv1a,
+                }
+                .to_string(),
+            );
+            assert_de_tokens_error::<Fnv1a<u64>>(
+                &state_tokens("FNV-1a", 32, b"\xbf\x9c\xf9\x68"),
+                &StateError::WrongWidth {
+                    expected: 64,
+                    found: 32,
+                }
+                .to_string(),
+            );
+            assert_de_tokens_error::<Fnv1a<u32>>(
+                &state_tokens("FNV-1a", 32, b"\xbf\x9c\xf9"),
+                &StateError::InvalidLength {
+                    expected: 4,
+                    found: 3,
+                }
+                .to_string(),
+            );
+        }
+    }
+}
@@ -0,0 +1,110 @@
+//! A static map which uses a perfect hash function built from FNV-1a.
+use {Fnv1a, FnvHasher};
+
+/// The hashes used to place a key in a `StaticMap`.
+pub(crate) struct KeyHashes {
+    pub(crate) bucket: u32,
+    pub(crate) f1: u32,
+    pub(crate) f2: u32,
+}
+
+/// Hashes a key with the 128-bit FNV-1a hash keyed with `seed`.
+///
+/// A change to the final byte of a key only reaches the lowest and highest
+/// bits of an FNV hash, so the upper 32-bit words of the hash are xor-folded
+/// with the lowest word.
+pub(crate) fn key_hashes(seed: u128, key: &[u8]) -> KeyHashes {
+    let mut hasher = Fnv1a::with_key(seed);
+    hasher.write(key);
+    let hash = hasher.finish();
+    let lowest = hash as u32;
+
+    KeyHashes {
+        bucket: (hash >> 96) as u32 ^ lowest,
+        f1: (hash >> 64) as u32 ^ lowest,
+        f2: (hash >> 32) as u32 ^ lowest,
+    }
+}
+
+/// Gets the index of a key from its hashes and the displacements of its
+/// bucket.
+pub(crate) fn displace(hashes: &KeyHashes, d1: u32, d2: u32, len: usize) -> usize {
+    let index = d2
+        .wrapping_add(hashes.f1.wrapping_mul(d1))
+        .wrapping_add(hashes.f2);
+
+    index as usize % len
+}
+
+/// An immutable map from string keys to values which requires no allocation.
+///
+/// The entries are placed with a minimal perfect hash function so a lookup
+/// hashes the key once and compares it with a single entry. A `StaticMap` is
+/// created with `codegen::MapGenerator`, usually from a build script.
+#[derive(Debug)]
+pub struct StaticMap<V: 'static> {
+    seed: u128,
+    displacements: &'static [(u32, u32)],
+    entries: &'static [(&'static str, V)],
+}
+
+impl<V> StaticMap<V> {
+    /// Creates a new `StaticMap` from its generated parts.
+    ///
+    /// This should only be called by code generated by
+    /// `codegen::MapGenerator`, the map will not work with any other
+    /// parameters.
+    pub const fn new(
+        seed: u128,
+        displacements: &'static [(u32, u32)],
+        entries: &'static [(&'static str, V)],
+    ) -> Self {
+        Self {
+            seed,
+            displacements,
+            entries,
+        }
+    }
+
+    /// Gets the value of the specified key.
+    pub fn get(&self, key: &str) -> Option<&V> {
+        self.get_entry(key).map(|(_, value)| value)
+    }
+
+    /// Gets the entry of the specified key.
+    pub fn get_entry(&self, key: &str) -> Option<(&str, &V)> {
+        if self.entries.is_empty() {
+            return None;
+        }
+
+        let hashes = key_hashes(self.seed, key.as_bytes());
+        let (d1, d2) = self.displacements[hashes.bucket as usize % self.displacements.len()];
+        let (entry_key, value) = &self.entries[displace(&hashes, d1, d2, self.entries.len())];
+
+        if *entry_key == key {
+            Some((entry_key, value))
+        } else {
+            None
+        }
+    }
+
+    /// Returns whether the map contains the specified key.
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.get_entry(key).is_some()
+    }
+
+    /// Gets the number of entries in the map.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns whether the map is empty.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Gets an iterator over the entries of the map, in an arbitrary order.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &V)> {
+        self.entries.iter().map(|(key, value)| (*key, value))
+    }
+}
@@ -0,0 +1,578 @@
+//! Word-at-a-time derivatives of the 32-bit FNV-1a hash.
+//!
+//! These hashes mix 4 or 8 bytes per multiply rather than a single byte, they
+//! are faster than FNV-1a for longer inputs but they are **not** compatible
+//! with canonical FNV and produce different hashes to `Fnv1a<u32>`.
+//!
+//! Each hash matches the published C reference implementation compiled for
+//! x86: words are read little-endian and, as the reference reads bytes through
+//! a `char` pointer, a final odd byte is sign-extended before it is mixed.
+//!
+//! The hashes mix whole blocks of input at once, so writes are buffered until
+//! a complete block is available. As with `Fnv1a<u32>`, splitting an input
+//! across several writes produces the same hash as a single write.
+//!
+//! Mixing several bytes per multiply also weakens the hashes. Keys which
+//! differ in only a few characters at fixed positions, such as
+//! `user:00000042:session`, collide far more often than with FNV-1a, and
+//! `Jesteress` and `Meiyan` distribute the low bits poorly for keys which
+//! differ only in the high bits of each word. These hashes are best suited to
+//! keys which are not so structured.
+//!
+//! ```
+//! use lz_fnv::variants::Jesteress;
+//! use lz_fnv::FnvHasher;
+//!
+//! let mut fnv_hasher = Jesteress::new();
+//! fnv_hasher.write(b"foobar");
+//!
+//! assert_eq!(fnv_hasher.finish(), 0x4ad8_c674);
+//! ```
+use core::hash::Hasher;
+use {FnvHasher, FnvParameters};
+
+/// The prime which the word-at-a-time hashes multiply by.
+const PRIME: u32 = 709_607;
+
+/// Buffers written bytes into blocks of `N` bytes.
+#[derive(Clone, Copy, Debug)]
+struct Blocks<const N: usize> {
+    buffer: [u8; N],
+    len: usize,
+}
+
+impl<const N: usize> Default for Blocks<N> {
+    fn default() -> Self {
+        Blocks {
+            buffer: [0; N],
+            len: 0,
+        }
+    }
+}
+
+impl<const N: usize> Blocks<N> {
+    /// Buffers `bytes`, calling `mix` with each completed block.
+    fn write<F: FnMut(&[u8])>(&mut self, mut bytes: &[u8], mut mix: F) {
+        if self.len > 0 {
+            let needed = (N - self.len).min(bytes.len());
+            self.buffer[self.len..self.len + needed].copy_from_slice(&bytes[..needed]);
+            self.len += needed;
+            bytes = &bytes[needed..];
+
+            if self.len < N {
+                return;
+            }
+
+            mix(&self.buffer);
+            self.len = 0;
+        }
+
+        let mut blocks = bytes.chunks_exact(N);
+
+        for block in &mut blocks {
+            mix(block);
+        }
+
+        let remainder = blocks.remainder();
+        self.buffer[..remainder.len()].copy_from_slice(remainder);
+        self.len = remainder.len();
+    }
+
+    /// Gets the bytes which do not yet make a complete block.
+    fn remainder(&self) -> &[u8] {
+        &self.buffer[..self.len]
+    }
+}
+
+fn read_u16(bytes: &[u8], offset: usize) -> u32 {
+    u32::from(u16::from_le_bytes([bytes[offset], bytes[offset + 1]]))
+}
+
+fn read_u32(bytes: &[u8], offset: usize) -> u32 {
+    u32::from_le_bytes([
+        bytes[offset],
+        bytes[offset + 1],
+        bytes[offset + 2],
+        bytes[offset + 3],
+    ])
+}
+
+fn mix(hash: u32, value: u32) -> u32 {
+    (hash ^ value).wrapping_mul(PRIME)
+}
+
+/// Mixes an 8-byte block as two words.
+fn mix_block(hash: u32, block: &[u8], offset: usize) -> u32 {
+    mix(
+        hash,
+        read_u32(block, offset).rotate_left(5) ^ read_u32(block, offset + 4),
+    )
+}
+
+/// Mixes a final odd byte, sign-extended as the reference implementation does.
+fn mix_last_byte(hash: u32, byte: u8) -> u32 {
+    mix(hash, byte as i8 as u32)
+}
+
+fn finish_hash(hash: u32) -> u32 {
+    hash ^ (hash >> 16)
+}
+
+macro_rules! hasher_impl {
+    ($type: ty) => {
+        impl $type {
+            /// Creates a new hasher.
+            pub fn new() -> Self {
+                Self::default()
+            }
+        }
+
+        impl Hasher for $type {
+            fn finish(&self) -> u64 {
+                FnvHasher::finish(self).into()
+            }
+
+            fn write(&mut self, bytes: &[u8]) {
+                FnvHasher::write(self, bytes);
+            }
+        }
+    };
+}
+
+/// The FNV-1a-YT (Yoshimitsu TRIAD) hash, which mixes 24-byte blocks into
+/// three independent states.
+///
+/// This is not compatible with canonical FNV.
+#[derive(Clone, Copy, Debug)]
+pub struct Fnv1aYt {
+    hashes: [u32; 3],
+    mixed_block: bool,
+    blocks: Blocks<24>,
+}
+
+impl Default for Fnv1aYt {
+    fn default() -> Self {
+        Fnv1aYt {
+            hashes: [u32::OFFSET_BASIS; 3],
+            mixed_block: false,
+            blocks: Blocks::default(),
+        }
+    }
+}
+
+impl FnvHasher for Fnv1aYt {
+    type Hash = u32;
+
+    fn finish(&self) -> u32 {
+        let [mut hash, mut hash_b, hash_c] = self.hashes;
+
+        if self.mixed_block {
+            hash = mix(hash, hash_c.rotate_left(5));
+        }
+
+        let tail = self.blocks.remainder();
+        let mut offset = 0;
+
+        if tail.len() & 16 != 0 {
+            hash = mix_block(hash, tail, offset);
+            hash_b = mix_block(hash_b, tail, offset + 8);
+            offset += 16;
+        }
+
+        if tail.len() & 8 != 0 {
+            hash = mix(hash, read_u32(tail, offset));
+            hash_b = mix(hash_b, read_u32(tail, offset + 4));
+            offset += 8;
+        }
+
+        if tail.len() & 4 != 0 {
+            hash = mix(hash, read_u16(tail, offset));
+            hash_b = mix(hash_b, read_u16(tail, offset + 2));
+            offset += 4;
+        }
+
+        if tail.len() & 2 != 0 {
+            hash = mix(hash, read_u16(tail, offset));
+            offset += 2;
+        }
+
+        if tail.len() & 1 != 0 {
+            hash = mix_last_byte(hash, tail[offset]);
+        }
+
+        finish_hash(mix(hash, hash_b.rotate_left(5)))
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        let hashes = &mut self.hashes;
+        let mixed_block = &mut self.mixed_block;
+
+        self.blocks.write(bytes, |block| {
+            hashes[0] = mix_block(hashes[0], block, 0);
+            hashes[1] = mix_block(hashes[1], block, 8);
+            hashes[2] = mix_block(hashes[2], block, 16);
+            *mixed_block = true;
+        });
+    }
+}
+
+hasher_impl!(Fnv1aYt);
+
+/// The FNV-1a Jesteress hash, which mixes 8-byte blocks.
+///
+/// This is not compatible with canonical FNV.
+#[derive(Clone, Copy, Debug)]
+pub struct Jesteress {
+    hash: u32,
+    blocks: Blocks<8>,
+}
+
+impl Default for Jesteress {
+    fn default() -> Self {
+        Jesteress {
+            hash: u32::OFFSET_BASIS,
+            blocks: Blocks::default(),
+        }
+    }
+}
+
+impl FnvHasher for Jesteress {
+    type Hash = u32;
+
+    fn finish(&self) -> u32 {
+        let mut hash = self.hash;
+        let tail = self.blocks.remainder();
+        let mut offset = 0;
+
+        if tail.len() & 4 != 0 {
+            hash = mix(hash, read_u32(tail, offset));
+            offset += 4;
+        }
+
+        if tail.len() & 2 != 0 {
+            hash = mix(hash, read_u16(tail, offset));
+            offset += 2;
+        }
+
+        if tail.len() & 1 != 0 {
+            hash = mix_last_byte(hash, tail[offset]);
+        }
+
+        finish_hash(hash)
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        let hash = &mut self.hash;
+
+        self.blocks
+            .write(bytes, |block| *hash = mix_block(*hash, block, 0));
+    }
+}
+
+hasher_impl!(Jesteress);
+
+/// The FNV-1a Meiyan hash, which mixes 8-byte blocks.
+///
+/// This differs from `Jesteress` only in mixing a final 4 bytes as two 2-byte
+/// words. This is not compatible with canonical FNV.
+#[derive(Clone, Copy, Debug)]
+pub struct Meiyan {
+    hash: u32,
+    blocks: Blocks<8>,
+}
+
+impl Default for Meiyan {
+    fn default() -> Self {
+        Meiyan {
+            hash: u32::OFFSET_BASIS,
+            blocks: Blocks::default(),
+        }
+    }
+}
+
+impl FnvHasher for Meiyan {
+    type Hash = u32;
+
+    fn finish(&self) -> u32 {
+        let mut hash = self.hash;
+        let tail = self.blocks.remainder();
+        let mut offset = 0;
+
+        if tail.len() & 4 != 0 {
+            hash = mix(hash, read_u16(tail, offset));
+            hash = mix(hash, read_u16(tail, offset + 2));
+            offset += 4;
+        }
+
+        if tail.len() & 2 != 0 {
+            hash = mix(hash, read_u16(tail, offset));
+            offset += 2;
+        }
+
+        if tail.len() & 1 != 0 {
+            hash = mix_last_byte(hash, tail[offset]);
+        }
+
+        finish_hash(hash)
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        let hash = &mut self.hash;
+
+        self.blocks
+            .write(bytes, |block| *hash = mix_block(*hash, block, 0));
+    }
+}
+
+hasher_impl!(Meiyan);
+
+#[cfg(test)]
+mod tests {
+    use super::{Fnv1aYt, Jesteress, Meiyan};
+    use std::collections::HashSet;
+    use {Fnv1a, FnvHasher};
+
+    const TEXT: &[u8] = b"The quick brown fox jumps over the lazy dog. 0123";
+
+    // (FNV-1a-YT, Jesteress, Meiyan) of each prefix of `TEXT`, indexed by length.
+    const PREFIX_HASHES: &[(u32, u32, u32)] = &[
+        (0x219c_1a0f, 0x811c_1cd9, 0x811c_1cd9),
+        (0xe659_9fa8, 0x3e5a_8e8d, 0x3e5a_8e8d),
+        (0x5912_f8e3, 0xf732_efe5, 0xf732_efe5),
+        (0x7e3a_43b8, 0x4f82_b11c, 0x4f82_b11c),
+        (0x9b09_73d5, 0xd21d_caca, 0xa9ff_7761),
+        (0x2b11_d8de, 0x8a0e_85c4, 0xfff7_d95e),
+        (0xcf89_6f46, 0x2269_e0a3, 0xd7c0_f669),
+        (0x5df8_0c92, 0xd338_292d, 0x5baf_efef),
+        (0x7ed0_028c, 0x1e01_b751, 0x1e01_b751),
+        (0x6100_5fa1, 0x0e49_5b74, 0x0e49_5b74),
+        (0x3441_eae0, 0xb3cc_c6f1, 0xb3cc_c6f1),
+        (0x1126_a549, 0x57f1_6248, 0x57f1_6248),
+        (0x03e1_4c15, 0x343a_4107, 0xb0e3_435a),
+        (0xebbc_989f, 0xe4a6_9658, 0x0606_6e1c),
+        (0x0bb6_9395, 0x0775_aa8b, 0x5425_6b3f),
+        (0x659d_8e96, 0x80dd_cc2d, 0x1b70_c6dc),
+        (0x0615_8288, 0x65e1_14f6, 0x65e1_14f6),
+        (0x0b97_1ea0, 0x2d57_52a0, 0x2d57_52a0),
+        (0x810f_8138, 0xaaa3_3054, 0xaaa3_3054),
+        (0x48c2_8dc1, 0xdb9a_8f93, 0xdb9a_8f93),
+        (0xd710_3b15, 0x31ab_ab5c, 0x3617_021e),
+        (0x65be_5bd1, 0x4c69_a6c2, 0xfafb_24ae),
+        (0xd0c2_11ad, 0xe4c5_796e, 0x87be_1eeb),
+        (0x058a_7b64, 0x7917_f6bd, 0x9175_f8fd),
+        (0x045c_6201, 0x1543_6cac, 0x1543_6cac),
+        (0x0211_d2c3, 0x8d36_c2f2, 0x8d36_c2f2),
+        (0xce27_fef5, 0x32b9_5d7d, 0x32b9_5d7d),
+        (0x6968_1683, 0xf90b_4d46, 0xf90b_4d46),
+        (0x0258_c6a9, 0x8af8_e53c, 0x55dc_4f91),
+        (0x9c05_c96b, 0x8678_eb3f, 0xe8ba_7aa2),
+        (0xd089_f7e7, 0x0e98_61df, 0x354a_c152),
+        (0x02f6_d958, 0x2964_4395, 0x681c_ee94),
+        (0x4039_a008, 0x3299_0e25, 0x3299_0e25),
+        (0xebfc_1cd3, 0x55b0_cafc, 0x55b0_cafc),
+        (0x201e_6c31, 0x8fb1_45fd, 0x8fb1_45fd),
+        (0x88b2_eaeb, 0x1b7c_b008, 0x1b7c_b008),
+        (0xb23a_efec, 0xc091_0add, 0x95b0_bac4),
+        (0xef6f_1fae, 0xd2f2_5769, 0x1f9a_d569),
+        (0x4cab_e66a, 0xb96d_b6f6, 0xbb0f_affc),
+        (0x1e79_43c1, 0xd454_4eba, 0x05ed_436b),
+        (0x7d9e_4103, 0xac45_94f5, 0xac45_94f5),
+        (0x4ab7_d088, 0xb895_bbd9, 0xb895_bbd9),
+        (0xa960_245f, 0x083d_f471, 0x083d_f471),
+        (0x7adb_29a9, 0x6073_9bbe, 0x6073_9bbe),
+        (0x4efd_93b1, 0x29a8_d5e4, 0x99b0_8c7d),
+        (0x3ebd_e1e3, 0xbbc0_72b4, 0xab38_b4e3),
+        (0x0e13_014d, 0xb405_cd71, 0x5876_d7ad),
+        (0xf56b_1b2e, 0xb096_fcd5, 0x2abd_909b),
+        (0x5a39_2635, 0x48ac_555f, 0x48ac_555f),
+    ];
+
+    fn hashes(input: &[u8]) -> (u32, u32, u32) {
+        let mut yt = Fnv1aYt::new();
+        let mut jesteress = Jesteress::new();
+        let mut meiyan = Meiyan::new();
+
+        yt.write(input);
+        jesteress.write(input);
+        meiyan.write(input);
+
+        (yt.finish(), jesteress.finish(), meiyan.finish())
+    }
+
+    #[test]
+    fn matches_reference_hashes() {
+        for (len, expected) in PREFIX_HASHES.iter().enumerate() {
+            assert_eq!(hashes(&TEXT[..len]), *expected, "length {}", len);
+        }
+    }
+
+    #[test]
+    fn sign_extends_final_byte() {
+        assert_eq!(hashes(b"\xff"), (0x3b72_e2f8, 0xbf67_cf31, 0xbf67_cf31));
+        assert_eq!(hashes(b"abc\x80"), (0x04d8_ca12, 0xd69b_0e67, 0xecbb_e8c2));
+        assert_eq!(
+            hashes(b"\xfe\xdc\xba\x98\x76\x54\x32"),
+            (0x5533_e2f3, 0x496d_8814, 0x834d_9278)
+        );
+    }
+
+    #[test]
+    fn split_writes_match_single_write() {
+        for split in 0..=TEXT.len() {
+            let mut yt = Fnv1aYt::new();
+            let mut jesteress = Jesteress::new();
+            let mut meiyan = Meiyan::new();
+
+            for part in TEXT[..split].chunks(3).chain(Some(&TEXT[split..])) {
+                yt.write(part);
+                jesteress.write(part);
+                meiyan.write(part);
+            }
+
+            assert_eq!(
+                (yt.finish(), jesteress.finish(), meiyan.finish()),
+                hashes(TEXT)
+            );
+        }
+    }
+
+    /// The number of buckets hashes are distributed into.
+    const BUCKETS: usize = 1024;
+
+    /// The critical value of the chi-squared distribution with 1023 degrees of
+    /// freedom at a significance of 0.001.
+    const CHI_SQUARED_CRITICAL: f64 = 1168.0;
+
+    /// The distribution of the hashes of a set of keys.
+    #[derive(Debug)]
+    struct Distribution {
+        /// The chi-squared statistic of the lowest bits over `BUCKETS` buckets.
+        low: f64,
+        /// The chi-squared statistic of the highest bits over `BUCKETS` buckets.
+        high: f64,
+        /// The number of keys which have the same hash as an earlier key.
+        collisions: usize,
+    }
+
+    fn distribution<H: FnvHasher<Hash = u32> + Default>(keys: &[Vec<u8>]) -> Distribution {
+        let mut low = vec![0usize; BUCKETS];
+        let mut high = vec![0usize; BUCKETS];
+        let mut hashes = HashSet::new();
+        let mut collisions = 0;
+
+        for key in keys {
+            let mut hasher = H::default();
+            hasher.write(key);
+            let hash = hasher.finish();
+
+            low[hash as usize % BUCKETS] += 1;
+            high[(hash >> 22) as usize] += 1;
+
+            if !hashes.insert(hash) {
+                collisions += 1;
+            }
+        }
+
+        let expected = keys.len() as f64 / BUCKETS as f64;
+        let chi_squared = |counts: &[usize]| {
+            counts
+                .iter()
+                .map(|count| (*count as f64 - expected).powi(2) / expected)
+                .sum()
+        };
+
+        Distribution {
+            low: chi_squared(&low),
+            high: chi_squared(&high),
+            collisions,
+        }
+    }
+
+    /// Gets the distributions of `Fnv1a<u32>` and then of each variant.
+    fn distributions(keys: &[Vec<u8>]) -> (Distribution, [Distribution; 3]) {
+        (
+            distribution::<Fnv1a<u32>>(keys),
+            [
+                distribution::<Fnv1aYt>(keys),
+                distribution::<Jesteress>(keys),
+                distribution::<Meiyan>(keys),
+            ],
+        )
+    }
+
+    fn random_keys() -> Vec<Vec<u8>> {
+        let mut state = 0x2545_f491_4f6c_dd1du64;
+
+        (0..100_000)
+            .map(|i| {
+                (0..4 + i % 64)
+                    .map(|_| {
+                        state ^= state << 13;
+                        state ^= state >> 7;
+                        state ^= state << 17;
+                        state as u8
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn distributes_random_keys_as_well_as_fnv1a() {
+        let (fnv1a, variants) = distributions(&random_keys());
+
+        assert!(fnv1a.low < CHI_SQUARED_CRITICAL && fnv1a.high < CHI_SQUARED_CRITICAL);
+
+        for variant in &variants {
+            assert!(
+                variant.low < CHI_SQUARED_CRITICAL && variant.high < CHI_SQUARED_CRITICAL,
+                "{:?} is not uniform, FNV-1a is {:?}",
+                variant,
+                fnv1a
+            );
+            // 100,000 random 32-bit hashes are expected to have ~1.2 collisions.
+            assert!(variant.collisions <= 8, "{:?}", variant);
+        }
+    }
+
+    #[test]
+    fn decimal_keys_rarely_collide() {
+        let keys: Vec<Vec<u8>> = (0..100_000u32)
+            .map(|i| i.to_string().into_bytes())
+            .collect();
+        let (fnv1a, variants) = distributions(&keys);
+
+        assert!(fnv1a.collisions <= 8);
+
+        for variant in &variants {
+            assert!(variant.collisions <= 8, "{:?}", variant);
+        }
+    }
+
+    #[test]
+    fn prefixed_keys_collide_unlike_fnv1a() {
+        let keys: Vec<Vec<u8>> = (0..100_000u32)
+            .map(|i| format!("user:{:08}:session", i).into_bytes())
+            .collect();
+        let (fnv1a, variants) = distributions(&keys);
+
+        assert_eq!(fnv1a.collisions, 0);
+
+        for variant in &variants {
+            assert!(variant.collisions > 1_000, "{:?}", variant);
+        }
+    }
+
+    #[test]
+    fn binary_keys_bias_low_bits_unlike_fnv1a() {
+        let keys: Vec<Vec<u8>> = (0..100_000u64)
+            .map(|i| (i * 4096).to_le_bytes().to_vec())
+            .collect();
+        let (fnv1a, [yt, jesteress, meiyan]) = distributions(&keys);
+
+        assert!(fnv1a.low < CHI_SQUARED_CRITICAL);
+        assert!(yt.low < CHI_SQUARED_CRITICAL);
+        assert!(jesteress.low > CHI_SQUARED_CRITICAL);
+        assert!(meiyan.low > CHI_SQUARED_CRITICAL);
+    }
+}
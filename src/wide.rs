@@ -0,0 +1,250 @@
+//! Fixed-width unsigned integers wider than `u128`.
+//!
+//! Rust has no built-in integer types wider than 128 bits, these types provide
+//! just enough arithmetic to support the FNV hashes.
+use core::fmt;
+use core::ops::{BitXor, BitXorAssign};
+
+macro_rules! wide_uint {
+    ($(#[$attr: meta])* $name: ident, $words: expr) => {
+        $(#[$attr])*
+        #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+        pub struct $name([u64; $words]);
+
+        impl $name {
+            /// Creates a new value from its 64-bit words, most significant
+            /// word first.
+            pub const fn from_words(words: [u64; $words]) -> Self {
+                let mut le_words = [0u64; $words];
+                let mut i = 0;
+                while i < $words {
+                    le_words[i] = words[$words - 1 - i];
+                    i += 1;
+                }
+                $name(le_words)
+            }
+
+            /// Returns the 64-bit words of this value, most significant word
+            /// first.
+            pub const fn to_words(self) -> [u64; $words] {
+                let mut be_words = [0u64; $words];
+                let mut i = 0;
+                while i < $words {
+                    be_words[i] = self.0[$words - 1 - i];
+                    i += 1;
+                }
+                be_words
+            }
+
+            /// Wrapping (modular) multiplication.
+            pub const fn wrapping_mul(self, rhs: Self) -> Self {
+                let mut result = [0u64; $words];
+                let mut i = 0;
+
+                while i < $words {
+                    let mut carry = 0u128;
+                    let mut j = 0;
+
+                    while i + j < $words {
+                        let product = (self.0[i] as u128) * (rhs.0[j] as u128)
+                            + (result[i + j] as u128)
+                            + carry;
+
+                        result[i + j] = product as u64;
+                        carry = product >> 64;
+                        j += 1;
+                    }
+
+                    i += 1;
+                }
+
+                $name(result)
+            }
+
+            /// Wrapping multiplication by the sparse value `2^shift + low`.
+            ///
+            /// This is linear in the number of words, unlike `wrapping_mul`
+            /// which is quadratic.
+            #[inline]
+            pub(crate) const fn wrapping_mul_sparse(self, shift: u32, low: u64) -> Self {
+                let mut result = [0u64; $words];
+                let mut carry = 0u128;
+                let mut i = 0;
+
+                while i < $words {
+                    let product = (self.0[i] as u128) * (low as u128) + carry;
+
+                    result[i] = product as u64;
+                    carry = product >> 64;
+                    i += 1;
+                }
+
+                let word_shift = (shift / 64) as usize;
+                let bit_shift = shift % 64;
+                let mut carry = 0u64;
+                let mut i = word_shift;
+
+                while i < $words {
+                    let mut shifted = self.0[i - word_shift] << bit_shift;
+
+                    if bit_shift > 0 && i > word_shift {
+                        shifted |= self.0[i - word_shift - 1] >> (64 - bit_shift);
+                    }
+
+                    let (sum, overflow) = result[i].overflowing_add(shifted);
+                    let (sum, carry_overflow) = sum.overflowing_add(carry);
+
+                    result[i] = sum;
+                    carry = (overflow | carry_overflow) as u64;
+                    i += 1;
+                }
+
+                $name(result)
+            }
+
+            /// Xors a byte with the lowest byte of this value.
+            #[inline]
+            pub(crate) const fn xor_byte(mut self, byte: u8) -> Self {
+                self.0[0] ^= byte as u64;
+                self
+            }
+        }
+
+        impl BitXor for $name {
+            type Output = Self;
+
+            #[inline]
+            fn bitxor(mut self, rhs: Self) -> Self {
+                self ^= rhs;
+                self
+            }
+        }
+
+        impl BitXorAssign for $name {
+            #[inline]
+            fn bitxor_assign(&mut self, rhs: Self) {
+                for (word, rhs_word) in self.0.iter_mut().zip(rhs.0.iter()) {
+                    *word ^= *rhs_word;
+                }
+            }
+        }
+
+        impl From<u8> for $name {
+            #[inline]
+            fn from(value: u8) -> Self {
+                let mut words = [0u64; $words];
+                words[0] = value.into();
+                $name(words)
+            }
+        }
+
+        impl fmt::LowerHex for $name {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                fmt_hex(&self.0, f, false)
+            }
+        }
+
+        impl fmt::UpperHex for $name {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                fmt_hex(&self.0, f, true)
+            }
+        }
+    };
+}
+
+fn fmt_hex(le_words: &[u64], f: &mut fmt::Formatter, upper: bool) -> fmt::Result {
+    if f.alternate() {
+        f.write_str("0x")?;
+    }
+
+    let mut words = le_words.iter().rev().skip_while(|word| **word == 0);
+
+    match words.next() {
+        Some(word) if upper => write!(f, "{:X}", word)?,
+        Some(word) => write!(f, "{:x}", word)?,
+        None => return f.write_str("0"),
+    }
+
+    for word in words {
+        if upper {
+            write!(f, "{:016X}", word)?;
+        } else {
+            write!(f, "{:016x}", word)?;
+        }
+    }
+
+    Ok(())
+}
+
+wide_uint!(
+    /// A 256-bit unsigned integer.
+    U256,
+    4
+);
+wide_uint!(
+    /// A 512-bit unsigned integer.
+    U512,
+    8
+);
+wide_uint!(
+    /// A 1024-bit unsigned integer.
+    U1024,
+    16
+);
+
+#[cfg(test)]
+mod tests {
+    use super::U256;
+
+    #[test]
+    fn wrapping_mul_carries_between_words() {
+        let lhs = U256::from_words([0, 0, 0, u64::MAX]);
+        let rhs = U256::from_words([0, 0, 0, 2]);
+
+        assert_eq!(
+            lhs.wrapping_mul(rhs),
+            U256::from_words([0, 0, 1, u64::MAX - 1])
+        );
+    }
+
+    #[test]
+    fn wrapping_mul_discards_overflow() {
+        let lhs = U256::from_words([1 << 63, 0, 0, 0]);
+        let rhs = U256::from_words([0, 0, 0, 2]);
+
+        assert_eq!(lhs.wrapping_mul(rhs), U256::default());
+    }
+
+    #[test]
+    fn wrapping_mul_sparse_matches_wrapping_mul() {
+        let value = U256::from_words([
+            0xDD26_8DBC_AAC5_5036,
+            0x2D98_C384_C4E5_76CC,
+            0xC8B1_5368_47B6_BBB3,
+            0x1023_B4C8_CAEE_0535,
+        ]);
+
+        for &(shift, low) in &[
+            (8, 0x13),
+            (64, 0x163),
+            (168, 0x163),
+            (200, u64::MAX),
+            (255, 1),
+        ] {
+            let mut words = [0, 0, 0, low];
+            words[3 - (shift / 64) as usize] |= 1 << (shift % 64);
+
+            assert_eq!(
+                value.wrapping_mul_sparse(shift, low),
+                value.wrapping_mul(U256::from_words(words))
+            );
+        }
+    }
+
+    #[test]
+    fn lower_hex_skips_leading_zero_words() {
+        let value = U256::from_words([0, 0, 0xab, 0x1]);
+
+        assert_eq!(format!("{:#x}", value), "0xab0000000000000001");
+    }
+}
@@ -0,0 +1,57 @@
+// Generates fixtures.txt from the MarshalBinary states of Go's hash/fnv
+// hashes, run with:
+//
+//	go run main.go > fixtures.txt
+//
+// Each line holds the name of the hash, then the hex of the input, the number
+// of bytes written before the state was marshaled, the hex of the state and
+// the hex of the sum of the whole input, separated by tabs.
+package main
+
+import (
+	"encoding"
+	"fmt"
+	"hash"
+	"hash/fnv"
+)
+
+var inputs = []string{
+	"",
+	"a",
+	"foobar",
+	"chongo was here!\n",
+	"\xff\x00\xfe\x01\x80\x7f",
+	"The quick brown fox jumps over the lazy dog",
+}
+
+func main() {
+	hashes := []struct {
+		name string
+		new  func() hash.Hash
+	}{
+		{"fnv1_32", func() hash.Hash { return fnv.New32() }},
+		{"fnv1a_32", func() hash.Hash { return fnv.New32a() }},
+		{"fnv1_64", func() hash.Hash { return fnv.New64() }},
+		{"fnv1a_64", func() hash.Hash { return fnv.New64a() }},
+		{"fnv1_128", func() hash.Hash { return fnv.New128() }},
+		{"fnv1a_128", func() hash.Hash { return fnv.New128a() }},
+	}
+
+	for _, h := range hashes {
+		for _, input := range inputs {
+			split := len(input) / 2
+
+			hasher := h.new()
+			hasher.Write([]byte(input[:split]))
+
+			state, err := hasher.(encoding.BinaryMarshaler).MarshalBinary()
+			if err != nil {
+				panic(err)
+			}
+
+			hasher.Write([]byte(input[split:]))
+
+			fmt.Printf("%s\t%x\t%d\t%x\t%x\n", h.name, input, split, state, hasher.Sum(nil))
+		}
+	}
+}
@@ -0,0 +1,79 @@
+extern crate lz_fnv;
+
+use lz_fnv::{Fnv1, Fnv1a, FnvHasher};
+
+/// The states of Go's `hash/fnv` hashes, see `data/go_fnv/main.go`.
+const FIXTURES: &str = include_str!("data/go_fnv/fixtures.txt");
+
+struct Fixture {
+    hash: String,
+    input: Vec<u8>,
+    split: usize,
+    state: Vec<u8>,
+    sum: Vec<u8>,
+}
+
+fn parse_hex(hex: &str) -> Vec<u8> {
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+fn fixtures() -> Vec<Fixture> {
+    FIXTURES
+        .lines()
+        .map(|line| {
+            let fields: Vec<&str> = line.split('\t').collect();
+
+            Fixture {
+                hash: fields[0].to_owned(),
+                input: parse_hex(fields[1]),
+                split: fields[2].parse().unwrap(),
+                state: parse_hex(fields[3]),
+                sum: parse_hex(fields[4]),
+            }
+        })
+        .collect()
+}
+
+macro_rules! go_tests {
+    ($($name: ident: $hash: expr, $hasher: ident<$type: ty>,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let fixtures: Vec<Fixture> = fixtures()
+                    .into_iter()
+                    .filter(|fixture| fixture.hash == $hash)
+                    .collect();
+
+                assert_eq!(fixtures.len(), 6);
+
+                for fixture in fixtures {
+                    let (head, tail) = fixture.input.split_at(fixture.split);
+
+                    // Rust saves the state Go saves.
+                    let mut fnv_hasher = $hasher::<$type>::new();
+                    fnv_hasher.write(head);
+
+                    assert_eq!(fnv_hasher.to_go_bytes(), fixture.state);
+
+                    // Rust finishes the hash Go started.
+                    let mut fnv_hasher = $hasher::<$type>::from_go_bytes(&fixture.state).unwrap();
+                    fnv_hasher.write(tail);
+
+                    assert_eq!(fnv_hasher.finish().to_be_bytes().to_vec(), fixture.sum);
+                }
+            }
+        )*
+    };
+}
+
+go_tests! {
+    go_fnv1_32_states_are_compatible: "fnv1_32", Fnv1<u32>,
+    go_fnv1a_32_states_are_compatible: "fnv1a_32", Fnv1a<u32>,
+    go_fnv1_64_states_are_compatible: "fnv1_64", Fnv1<u64>,
+    go_fnv1a_64_states_are_compatible: "fnv1a_64", Fnv1a<u64>,
+    go_fnv1_128_states_are_compatible: "fnv1_128", Fnv1<u128>,
+    go_fnv1a_128_states_are_compatible: "fnv1a_128", Fnv1a<u128>,
+}
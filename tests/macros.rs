@@ -0,0 +1,149 @@
+extern crate lz_fnv;
+
+use lz_fnv::{Fnv0, Fnv1, Fnv1a, FnvHasher, U1024, U256, U512};
+use std::ffi::CString;
+
+macro_rules! macro_tests {
+    ($($name: ident: $macro: ident, $hasher: ident<$type: ident>,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let cases: Vec<(_, Vec<u8>)> = vec![
+                    (lz_fnv::$macro!($type, ""), b"".to_vec()),
+                    (lz_fnv::$macro!($type, "a"), b"a".to_vec()),
+                    (lz_fnv::$macro!($type, "foobar"), b"foobar".to_vec()),
+                    (lz_fnv::$macro!($type, b"\xfe\xdc\xba\x98"), b"\xfe\xdc\xba\x98".to_vec()),
+                    (
+                        lz_fnv::$macro!($type, "chongo was here!\n", nul),
+                        CString::new("chongo was here!\n").unwrap().into_bytes_with_nul(),
+                    ),
+                    (
+                        lz_fnv::$macro!($type, "", nul),
+                        CString::new("").unwrap().into_bytes_with_nul(),
+                    ),
+                ];
+
+                for (hash, input) in cases {
+                    let mut hasher = $hasher::<$type>::default();
+
+                    hasher.write(&input);
+
+                    assert_eq!(hash, hasher.finish());
+                }
+            }
+        )*
+    };
+}
+
+macro_tests! {
+    fnv0_32_macro_matches_hasher: fnv0, Fnv0<u32>,
+    fnv1_32_macro_matches_hasher: fnv1, Fnv1<u32>,
+    fnv1a_32_macro_matches_hasher: fnv1a, Fnv1a<u32>,
+    fnv0_64_macro_matches_hasher: fnv0, Fnv0<u64>,
+    fnv1_64_macro_matches_hasher: fnv1, Fnv1<u64>,
+    fnv1a_64_macro_matches_hasher: fnv1a, Fnv1a<u64>,
+    fnv0_128_macro_matches_hasher: fnv0, Fnv0<u128>,
+    fnv1_128_macro_matches_hasher: fnv1, Fnv1<u128>,
+    fnv1a_128_macro_matches_hasher: fnv1a, Fnv1a<u128>,
+    fnv0_256_macro_matches_hasher: fnv0, Fnv0<U256>,
+    fnv1_256_macro_matches_hasher: fnv1, Fnv1<U256>,
+    fnv1a_256_macro_matches_hasher: fnv1a, Fnv1a<U256>,
+    fnv0_512_macro_matches_hasher: fnv0, Fnv0<U512>,
+    fnv1_512_macro_matches_hasher: fnv1, Fnv1<U512>,
+    fnv1a_512_macro_matches_hasher: fnv1a, Fnv1a<U512>,
+    fnv0_1024_macro_matches_hasher: fnv0, Fnv0<U1024>,
+    fnv1_1024_macro_matches_hasher: fnv1, Fnv1<U1024>,
+    fnv1a_1024_macro_matches_hasher: fnv1a, Fnv1a<U1024>,
+}
+
+#[test]
+fn macro_expands_to_pattern() {
+    let hash = lz_fnv::fnv1a_32(b"foobar");
+
+    match hash {
+        lz_fnv::fnv1a!(u32, "foo") => panic!("matched the wrong pattern"),
+        lz_fnv::fnv1a!(u32, "foobar") => {}
+        _ => panic!("no pattern matched"),
+    }
+}
+
+#[test]
+fn macro_can_be_used_in_const() {
+    const HASH: u64 = lz_fnv::fnv1!(u64, "foobar");
+
+    assert_eq!(HASH, 0x340d8765a4dda9c2);
+}
+
+fn command_id(name: &str) -> Option<u32> {
+    lz_fnv::fnv_match!(match name {
+        "start" | "begin" => Some(1),
+        "stop" => Some(2),
+        "" => Some(3),
+        _ => None,
+    })
+}
+
+#[test]
+fn fnv_match_matches_literals() {
+    assert_eq!(command_id("start"), Some(1));
+    assert_eq!(command_id("begin"), Some(1));
+    assert_eq!(command_id("stop"), Some(2));
+    assert_eq!(command_id(""), Some(3));
+    assert_eq!(command_id("halt"), None);
+}
+
+#[test]
+fn fnv_match_compares_strings_with_matching_hash() {
+    // "costarring" and "liquid" collide with the 32-bit FNV-1a hash.
+    let matched = |word: &str| {
+        lz_fnv::fnv_match!(u32; match word {
+            "costarring" => true,
+            _ => false,
+        })
+    };
+
+    assert!(matched("costarring"));
+    assert!(!matched("liquid"));
+}
+
+#[test]
+fn fnv_match_applies_guards() {
+    let matched = |word: String, enabled: bool| {
+        lz_fnv::fnv_match!(u128; match word {
+            "start" if enabled => Some(1),
+            "start" => Some(2),
+            _ if enabled => Some(3),
+            _ => None,
+        })
+    };
+
+    assert_eq!(matched("start".to_owned(), true), Some(1));
+    assert_eq!(matched("start".to_owned(), false), Some(2));
+    assert_eq!(matched("stop".to_owned(), true), Some(3));
+    assert_eq!(matched("stop".to_owned(), false), None);
+}
+
+#[test]
+fn fnv_match_accepts_temporary_scrutinees() {
+    let matched = |word: &str| {
+        lz_fnv::fnv_match!(match word.to_lowercase() {
+            "start" => Some(1),
+            _ => None,
+        })
+    };
+
+    assert_eq!(matched("START"), Some(1));
+    assert_eq!(matched("Stop"), None);
+}
+
+#[test]
+fn fnv_match_borrows_the_scrutinee() {
+    let word = String::from("stop");
+    let id = lz_fnv::fnv_match!(match word {
+        "stop" => Some(2),
+        _ => None,
+    });
+
+    assert_eq!(id, Some(2));
+    assert_eq!(word, "stop");
+}
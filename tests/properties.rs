@@ -0,0 +1,228 @@
+extern crate lz_fnv;
+extern crate proptest;
+
+use lz_fnv::variants::{Fnv1aYt, Jesteress, Meiyan};
+use lz_fnv::{
+    Fnv0, Fnv1, Fnv1a, Fnv1a16, Fnv1a8, FnvHasher, FnvParameters, Folded, U1024, U256, U512,
+};
+use proptest::collection::vec;
+use proptest::prelude::*;
+use std::hash::Hasher;
+
+/// Splits `input` at each of `splits`, modulo the length of the input.
+fn chunks<'a>(input: &'a [u8], splits: &[usize]) -> Vec<&'a [u8]> {
+    let mut splits: Vec<usize> = splits
+        .iter()
+        .map(|split| split % (input.len() + 1))
+        .collect();
+    splits.sort();
+
+    let mut chunks = Vec::new();
+    let mut start = 0;
+
+    for split in splits {
+        chunks.push(&input[start..split]);
+        start = split;
+    }
+
+    chunks.push(&input[start..]);
+    chunks
+}
+
+fn hash<H: FnvHasher + Default>(input: &[u8]) -> H::Hash {
+    let mut hasher = H::default();
+    hasher.write(input);
+    hasher.finish()
+}
+
+fn hash_chunks<H: FnvHasher + Default>(chunks: &[&[u8]]) -> H::Hash {
+    let mut hasher = H::default();
+
+    for chunk in chunks {
+        hasher.write(chunk);
+    }
+
+    hasher.finish()
+}
+
+fn input() -> impl Strategy<Value = Vec<u8>> {
+    vec(any::<u8>(), 0..256)
+}
+
+macro_rules! chunking_tests {
+    ($($name: ident: $hasher: ty,)*) => {
+        proptest! {
+            $(
+                #[test]
+                fn $name(input in input(), splits in vec(any::<usize>(), 0..8)) {
+                    prop_assert_eq!(
+                        hash_chunks::<$hasher>(&chunks(&input, &splits)),
+                        hash::<$hasher>(&input)
+                    );
+                }
+            )*
+        }
+    };
+}
+
+chunking_tests! {
+    fnv0_32_chunking_is_invariant: Fnv0<u32>,
+    fnv1_32_chunking_is_invariant: Fnv1<u32>,
+    fnv1a_32_chunking_is_invariant: Fnv1a<u32>,
+    fnv0_64_chunking_is_invariant: Fnv0<u64>,
+    fnv1_64_chunking_is_invariant: Fnv1<u64>,
+    fnv1a_64_chunking_is_invariant: Fnv1a<u64>,
+    fnv0_128_chunking_is_invariant: Fnv0<u128>,
+    fnv1_128_chunking_is_invariant: Fnv1<u128>,
+    fnv1a_128_chunking_is_invariant: Fnv1a<u128>,
+    fnv0_256_chunking_is_invariant: Fnv0<U256>,
+    fnv1_256_chunking_is_invariant: Fnv1<U256>,
+    fnv1a_256_chunking_is_invariant: Fnv1a<U256>,
+    fnv0_512_chunking_is_invariant: Fnv0<U512>,
+    fnv1_512_chunking_is_invariant: Fnv1<U512>,
+    fnv1a_512_chunking_is_invariant: Fnv1a<U512>,
+    fnv0_1024_chunking_is_invariant: Fnv0<U1024>,
+    fnv1_1024_chunking_is_invariant: Fnv1<U1024>,
+    fnv1a_1024_chunking_is_invariant: Fnv1a<U1024>,
+    fnv1a16_chunking_is_invariant: Fnv1a16,
+    fnv1a8_chunking_is_invariant: Fnv1a8,
+    fnv1a_yt_chunking_is_invariant: Fnv1aYt,
+    jesteress_chunking_is_invariant: Jesteress,
+    meiyan_chunking_is_invariant: Meiyan,
+}
+
+macro_rules! key_tests {
+    ($($name: ident: $type: ty,)*) => {
+        proptest! {
+            $(
+                #[test]
+                fn $name(prefix in input(), suffix in input()) {
+                    let mut input = prefix.clone();
+                    input.extend_from_slice(&suffix);
+
+                    let mut fnv0 = Fnv0::with_key(<$type>::default());
+                    let mut fnv1 = Fnv1::with_key(<$type>::OFFSET_BASIS);
+                    let mut fnv1a = Fnv1a::with_key(<$type>::OFFSET_BASIS);
+                    FnvHasher::write(&mut fnv0, &input);
+                    FnvHasher::write(&mut fnv1, &input);
+                    FnvHasher::write(&mut fnv1a, &input);
+
+                    prop_assert_eq!(FnvHasher::finish(&fnv0), hash::<Fnv0<$type>>(&input));
+                    prop_assert_eq!(FnvHasher::finish(&fnv1), hash::<Fnv1<$type>>(&input));
+                    prop_assert_eq!(FnvHasher::finish(&fnv1a), hash::<Fnv1a<$type>>(&input));
+
+                    // The key is the hash state, so keying with the hash of a
+                    // prefix continues the hash.
+                    let mut fnv0 = Fnv0::with_key(hash::<Fnv0<$type>>(&prefix));
+                    let mut fnv1 = Fnv1::with_key(hash::<Fnv1<$type>>(&prefix));
+                    let mut fnv1a = Fnv1a::with_key(hash::<Fnv1a<$type>>(&prefix));
+                    FnvHasher::write(&mut fnv0, &suffix);
+                    FnvHasher::write(&mut fnv1, &suffix);
+                    FnvHasher::write(&mut fnv1a, &suffix);
+
+                    prop_assert_eq!(FnvHasher::finish(&fnv0), hash::<Fnv0<$type>>(&input));
+                    prop_assert_eq!(FnvHasher::finish(&fnv1), hash::<Fnv1<$type>>(&input));
+                    prop_assert_eq!(FnvHasher::finish(&fnv1a), hash::<Fnv1a<$type>>(&input));
+                }
+            )*
+        }
+    };
+}
+
+key_tests! {
+    keys_32_are_equivalent: u32,
+    keys_64_are_equivalent: u64,
+    keys_128_are_equivalent: u128,
+    keys_256_are_equivalent: U256,
+    keys_512_are_equivalent: U512,
+    keys_1024_are_equivalent: U1024,
+}
+
+macro_rules! unwrite_tests {
+    ($($name: ident: $type: ty,)*) => {
+        proptest! {
+            $(
+                #[test]
+                fn $name(prefix in input(), suffix in input()) {
+                    let mut fnv1 = Fnv1::<$type>::new();
+                    let mut fnv1a = Fnv1a::<$type>::new();
+                    FnvHasher::write(&mut fnv1, &prefix);
+                    FnvHasher::write(&mut fnv1a, &prefix);
+                    FnvHasher::write(&mut fnv1, &suffix);
+                    FnvHasher::write(&mut fnv1a, &suffix);
+                    fnv1.unwrite(&suffix);
+                    fnv1a.unwrite(&suffix);
+
+                    prop_assert_eq!(FnvHasher::finish(&fnv1), hash::<Fnv1<$type>>(&prefix));
+                    prop_assert_eq!(FnvHasher::finish(&fnv1a), hash::<Fnv1a<$type>>(&prefix));
+                }
+            )*
+        }
+    };
+}
+
+unwrite_tests! {
+    unwrite_32_undoes_write: u32,
+    unwrite_64_undoes_write: u64,
+    unwrite_128_undoes_write: u128,
+    unwrite_256_undoes_write: U256,
+    unwrite_512_undoes_write: U512,
+    unwrite_1024_undoes_write: U1024,
+}
+
+proptest! {
+    #[test]
+    fn folded_hashers_match_finish_folded(input in input()) {
+        let mut fnv1 = Fnv1::<u32>::new();
+        let mut fnv1a = Fnv1a::<u32>::new();
+        FnvHasher::write(&mut fnv1, &input);
+        FnvHasher::write(&mut fnv1a, &input);
+
+        prop_assert_eq!(
+            u32::from(hash::<Folded<Fnv1<u32>, u16>>(&input)),
+            fnv1.finish_folded(16)
+        );
+        prop_assert_eq!(
+            u32::from(hash::<Folded<Fnv1<u32>, u8>>(&input)),
+            fnv1.finish_folded(8)
+        );
+        prop_assert_eq!(u32::from(hash::<Fnv1a16>(&input)), fnv1a.finish_folded(16));
+        prop_assert_eq!(u32::from(hash::<Fnv1a8>(&input)), fnv1a.finish_folded(8));
+    }
+
+    #[test]
+    fn finish_folded_to_full_width_is_finish(input in input()) {
+        let mut fnv1a32 = Fnv1a::<u32>::new();
+        let mut fnv1a64 = Fnv1a::<u64>::new();
+        let mut fnv1a128 = Fnv1a::<u128>::new();
+        FnvHasher::write(&mut fnv1a32, &input);
+        FnvHasher::write(&mut fnv1a64, &input);
+        FnvHasher::write(&mut fnv1a128, &input);
+
+        prop_assert_eq!(fnv1a32.finish_folded(32), FnvHasher::finish(&fnv1a32));
+        prop_assert_eq!(fnv1a64.finish_folded(64), FnvHasher::finish(&fnv1a64));
+        prop_assert_eq!(fnv1a128.finish_folded(128), FnvHasher::finish(&fnv1a128));
+    }
+
+    #[test]
+    fn finish_folded_fits_in_bits(input in input(), bits in 1..128u32) {
+        let mut fnv1a = Fnv1a::<u128>::new();
+        FnvHasher::write(&mut fnv1a, &input);
+
+        prop_assert!(fnv1a.finish_folded(bits) >> bits == 0);
+    }
+
+    #[test]
+    fn hasher_finish_folds_to_64_bits(input in input()) {
+        let mut fnv1a32 = Fnv1a::<u32>::new();
+        let mut fnv1a64 = Fnv1a::<u64>::new();
+        let mut fnv1a128 = Fnv1a::<u128>::new();
+        FnvHasher::write(&mut fnv1a32, &input);
+        FnvHasher::write(&mut fnv1a64, &input);
+        FnvHasher::write(&mut fnv1a128, &input);
+
+        prop_assert_eq!(Hasher::finish(&fnv1a32), u64::from(FnvHasher::finish(&fnv1a32)));
+        prop_assert_eq!(Hasher::finish(&fnv1a64), FnvHasher::finish(&fnv1a64));
+        prop_assert_eq!(Hasher::finish(&fnv1a128), fnv1a128.finish_folded(64) as u64);
+    }
+}